@@ -6,6 +6,7 @@ use crate::definition_table;
 use crate::generic_inference_table;
 use crate::handlers::*;
 use crate::ir::{Ir, IrResult};
+use crate::lint;
 use crate::msb_table;
 use crate::namespace::Namespace;
 use crate::reference_table;
@@ -116,6 +117,7 @@ fn create_prop_namespace_symbol(project_name: StrId) -> Symbol {
 
 impl Analyzer {
     pub fn new(metadata: &Metadata) -> Self {
+        lint::set_rules(&metadata.lint.rules);
         let prj = insert_namespace_symbol(&metadata.project.name, true);
         insert_project_property_symbols(prj, &metadata.properties);
         // A package whose manifest is not available (never built nor
@@ -265,6 +267,7 @@ impl Analyzer {
         let mut ret = Vec::new();
 
         ret.append(&mut symbol_table::check_unused_variable());
+        ret.append(&mut symbol_table::check_unused_parameter());
         ret.append(&mut symbol_table::check_wavedrom());
         ret.append(&mut comb_loop_detect::check(ir));
 
@@ -1,3 +1,4 @@
+use crate::fix::Fix;
 use crate::multi_sources::{MultiSources, Source};
 use miette::{self, Diagnostic, Severity, SourceSpan};
 use serde::{Deserialize, Serialize};
@@ -110,6 +111,10 @@ pub enum AnalyzerError {
         token_source: TokenSource,
     },
 
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    DeniedLint(DeniedLint),
+
     #[diagnostic(
         severity(Error),
         code(duplicate_argument),
@@ -191,6 +196,24 @@ pub enum AnalyzerError {
         token_source: TokenSource,
     },
 
+    #[diagnostic(
+        severity(Warning),
+        code(implicit_truncation),
+        help("select the bits to keep explicitly"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#{}", self.code().unwrap())
+    )]
+    #[error("{src_width}-bit value is truncated to {dst_width}-bit \"{identifier}\"")]
+    ImplicitTruncation {
+        identifier: String,
+        dst_width: usize,
+        src_width: usize,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token_source: TokenSource,
+    },
+
     #[diagnostic(
         severity(Error),
         code(include_failure),
@@ -457,6 +480,7 @@ pub enum AnalyzerError {
         #[label("Error location")]
         error_location: SourceSpan,
         token_source: TokenSource,
+        fix: Option<Fix>,
     },
 
     #[diagnostic(
@@ -786,6 +810,23 @@ pub enum AnalyzerError {
         token_source: TokenSource,
     },
 
+    #[diagnostic(
+        severity(Error),
+        code(invalid_temporal),
+        help("{help}"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#{}", self.code().unwrap())
+    )]
+    #[error("{kind}")]
+    InvalidTemporal {
+        kind: InvalidTemporalKind,
+        help: String,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token_source: TokenSource,
+    },
+
     #[diagnostic(
         severity(Error),
         code(invalid_test),
@@ -940,6 +981,22 @@ pub enum AnalyzerError {
         token_source: TokenSource,
     },
 
+    #[diagnostic(
+        severity(Warning),
+        code(magic_number),
+        help("give the value a name by `const` or `param`"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#{}", self.code().unwrap())
+    )]
+    #[error("magic number \"{number}\" in always_ff")]
+    MagicNumber {
+        number: String,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token_source: TokenSource,
+    },
+
     #[diagnostic(
         severity(Error),
         code(member_access_on_array),
@@ -1168,6 +1225,22 @@ pub enum AnalyzerError {
         token_source: TokenSource,
     },
 
+    #[diagnostic(
+        severity(Warning),
+        code(missing_default),
+        help("add `default` arm"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#{}", self.code().unwrap())
+    )]
+    #[error("{kind} statement has no default arm")]
+    MissingDefault {
+        kind: String,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token_source: TokenSource,
+    },
+
     #[diagnostic(
         severity(Error),
         code(missing_default_argument),
@@ -1261,6 +1334,7 @@ pub enum AnalyzerError {
         #[label("Error location")]
         error_location: SourceSpan,
         token_source: TokenSource,
+        fix: Option<Fix>,
     },
 
     #[diagnostic(
@@ -1292,6 +1366,7 @@ pub enum AnalyzerError {
         #[label(collection, "Not reset")]
         error_locations: Vec<SourceSpan>,
         token_source: TokenSource,
+        fix: Option<Fix>,
     },
 
     #[diagnostic(
@@ -1376,6 +1451,23 @@ pub enum AnalyzerError {
         token_source: TokenSource,
     },
 
+    #[diagnostic(
+        severity(Warning),
+        code(nested_if),
+        help("flatten the conditions or move the inner logic to a function"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#{}", self.code().unwrap())
+    )]
+    #[error("if statement is nested {depth} levels deep, more than {limit}")]
+    NestedIf {
+        depth: usize,
+        limit: usize,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token_source: TokenSource,
+    },
+
     #[diagnostic(
         severity(Error),
         code(private_member),
@@ -1454,6 +1546,7 @@ pub enum AnalyzerError {
         #[label("Error location")]
         error_location: SourceSpan,
         token_source: TokenSource,
+        fix: Option<Fix>,
     },
 
     #[diagnostic(
@@ -1818,6 +1911,22 @@ pub enum AnalyzerError {
         token_source: TokenSource,
     },
 
+    #[diagnostic(
+        severity(Warning),
+        code(unused_parameter),
+        help("remove unused parameter or add prefix `_` to its name"),
+        url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#{}", self.code().unwrap())
+    )]
+    #[error("\"{identifier}\" is unused")]
+    UnusedParameter {
+        identifier: String,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+        token_source: TokenSource,
+    },
+
     #[diagnostic(
         severity(Warning),
         code(unused_return),
@@ -1848,6 +1957,7 @@ pub enum AnalyzerError {
         #[label("Error location")]
         error_location: SourceSpan,
         token_source: TokenSource,
+        fix: Option<Fix>,
     },
 
     #[diagnostic(
@@ -2049,11 +2159,63 @@ impl Diagnostic for CachedDiagnostic {
     }
 }
 
+/// A lint warning reported as an error because its rule is `deny` in
+/// `[lint.rules]`.  Everything but the severity is the warning's own.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DeniedLint(pub Box<AnalyzerError>);
+
+impl fmt::Display for DeniedLint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for DeniedLint {}
+
+impl Diagnostic for DeniedLint {
+    fn severity(&self) -> Option<Severity> {
+        Some(Severity::Error)
+    }
+
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        self.0.code()
+    }
+
+    fn url(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        self.0.url()
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        self.0.help()
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        self.0.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        self.0.labels()
+    }
+}
+
 impl AnalyzerError {
     pub fn is_error(&self) -> bool {
         matches!(self.severity(), Some(Severity::Error) | None)
     }
 
+    /// The mechanical fix suggested for this diagnostic, if any.
+    pub fn fix(&self) -> Option<&Fix> {
+        match self {
+            AnalyzerError::DeniedLint(x) => x.0.fix(),
+            AnalyzerError::InvalidIdentifier { fix, .. }
+            | AnalyzerError::MissingPort { fix, .. }
+            | AnalyzerError::MissingResetStatement { fix, .. }
+            | AnalyzerError::SvKeywordUsage { fix, .. }
+            | AnalyzerError::UnusedVariable { fix, .. } => fix.as_ref(),
+            _ => None,
+        }
+    }
+
     /// The `#[source_code]` (`input: MultiSources`) carried by every variant.
     /// Exposed so `CachedDiagnostic` can capture the source snippet.
     pub fn input_sources(&self) -> &MultiSources {
@@ -2064,11 +2226,13 @@ impl AnalyzerError {
             AnalyzerError::CallNonFunction { input, .. } => input,
             AnalyzerError::CombinationalLoop { input, .. } => input,
             AnalyzerError::CyclicTypeDependency { input, .. } => input,
+            AnalyzerError::DeniedLint(x) => x.0.input_sources(),
             AnalyzerError::DuplicateArgument { input, .. } => input,
             AnalyzerError::DuplicatedIdentifier { input, .. } => input,
             AnalyzerError::DuplicateEnumVariant { input, .. } => input,
             AnalyzerError::ExceedLimit { input, .. } => input,
             AnalyzerError::FixedTypeWithSignedModifier { input, .. } => input,
+            AnalyzerError::ImplicitTruncation { input, .. } => input,
             AnalyzerError::ForLoopOverflow { input, .. } => input,
             AnalyzerError::GenericInferenceFailed { input, .. } => input,
             AnalyzerError::ImplicitClockConversion { input, .. } => input,
@@ -2106,6 +2270,7 @@ impl AnalyzerError {
             AnalyzerError::InvalidSizeType { input, .. } => input,
             AnalyzerError::InvalidStatement { input, .. } => input,
             AnalyzerError::InvalidTbUsage { input, .. } => input,
+            AnalyzerError::InvalidTemporal { input, .. } => input,
             AnalyzerError::InvalidTest { input, .. } => input,
             AnalyzerError::InvalidTypeDeclaration { input, .. } => input,
             AnalyzerError::InvalidUnsizedLiteral { input, .. } => input,
@@ -2113,6 +2278,7 @@ impl AnalyzerError {
             AnalyzerError::InvisibleIndentifier { input, .. } => input,
             AnalyzerError::LastItemWithDefine { input, .. } => input,
             AnalyzerError::LastItemWithDefineInModport { input, .. } => input,
+            AnalyzerError::MagicNumber { input, .. } => input,
             AnalyzerError::MemberAccessOnArray { input, .. } => input,
             AnalyzerError::MismatchAssignment { input, .. } => input,
             AnalyzerError::MismatchAttributeArgs { input, .. } => input,
@@ -2123,6 +2289,7 @@ impl AnalyzerError {
             AnalyzerError::MismatchType { input, .. } => input,
             AnalyzerError::MissingClockDomain { input, .. } => input,
             AnalyzerError::MissingClockSignal { input, .. } => input,
+            AnalyzerError::MissingDefault { input, .. } => input,
             AnalyzerError::MissingDefaultArgument { input, .. } => input,
             AnalyzerError::MissingIfReset { input, .. } => input,
             AnalyzerError::MissingPort { input, .. } => input,
@@ -2134,6 +2301,7 @@ impl AnalyzerError {
             AnalyzerError::MixedStructUnionMember { input, .. } => input,
             AnalyzerError::MultipleAssignment { input, .. } => input,
             AnalyzerError::MultipleDefault { input, .. } => input,
+            AnalyzerError::NestedIf { input, .. } => input,
             AnalyzerError::NonConstantSelectWidth { input, .. } => input,
             AnalyzerError::NonPositiveValue { input, .. } => input,
             AnalyzerError::PrivateMember { input, .. } => input,
@@ -2166,6 +2334,7 @@ impl AnalyzerError {
             AnalyzerError::ComponentInterfaceMismatch { input, .. } => input,
             AnalyzerError::UnknownUnsafe { input, .. } => input,
             AnalyzerError::UnresolvableGenericExpression { input, .. } => input,
+            AnalyzerError::UnusedParameter { input, .. } => input,
             AnalyzerError::UnsignedArithShift { input, .. } => input,
             AnalyzerError::UnusedReturn { input, .. } => input,
             AnalyzerError::UnusedVariable { input, .. } => input,
@@ -2182,11 +2351,13 @@ impl AnalyzerError {
             AnalyzerError::CallNonFunction { token_source, .. } => *token_source,
             AnalyzerError::CombinationalLoop { token_source, .. } => *token_source,
             AnalyzerError::CyclicTypeDependency { token_source, .. } => *token_source,
+            AnalyzerError::DeniedLint(x) => x.0.token_source(),
             AnalyzerError::DuplicateArgument { token_source, .. } => *token_source,
             AnalyzerError::DuplicateEnumVariant { token_source, .. } => *token_source,
             AnalyzerError::DuplicatedIdentifier { token_source, .. } => *token_source,
             AnalyzerError::ExceedLimit { token_source, .. } => *token_source,
             AnalyzerError::FixedTypeWithSignedModifier { token_source, .. } => *token_source,
+            AnalyzerError::ImplicitTruncation { token_source, .. } => *token_source,
             AnalyzerError::ForLoopOverflow { token_source, .. } => *token_source,
             AnalyzerError::IncludeFailure { token_source, .. } => *token_source,
             AnalyzerError::IncompatProto { token_source, .. } => *token_source,
@@ -2227,11 +2398,13 @@ impl AnalyzerError {
             AnalyzerError::MissingTbPort { token_source, .. } => *token_source,
             AnalyzerError::UnknownTbPort { token_source, .. } => *token_source,
             AnalyzerError::ComponentInterfaceMismatch { token_source, .. } => *token_source,
+            AnalyzerError::InvalidTemporal { token_source, .. } => *token_source,
             AnalyzerError::InvalidTest { token_source, .. } => *token_source,
             AnalyzerError::InvalidTypeDeclaration { token_source, .. } => *token_source,
             AnalyzerError::InvisibleIndentifier { token_source, .. } => *token_source,
             AnalyzerError::LastItemWithDefine { token_source, .. } => *token_source,
             AnalyzerError::LastItemWithDefineInModport { token_source, .. } => *token_source,
+            AnalyzerError::MagicNumber { token_source, .. } => *token_source,
             AnalyzerError::MemberAccessOnArray { token_source, .. } => *token_source,
             AnalyzerError::MismatchAssignment { token_source, .. } => *token_source,
             AnalyzerError::ImplicitClockConversion { token_source, .. } => *token_source,
@@ -2245,6 +2418,7 @@ impl AnalyzerError {
             AnalyzerError::MismatchType { token_source, .. } => *token_source,
             AnalyzerError::MissingClockDomain { token_source, .. } => *token_source,
             AnalyzerError::MissingClockSignal { token_source, .. } => *token_source,
+            AnalyzerError::MissingDefault { token_source, .. } => *token_source,
             AnalyzerError::MissingDefaultArgument { token_source, .. } => *token_source,
             AnalyzerError::MissingIfReset { token_source, .. } => *token_source,
             AnalyzerError::GenericInferenceFailed { token_source, .. } => *token_source,
@@ -2258,6 +2432,7 @@ impl AnalyzerError {
             AnalyzerError::MixedStructUnionMember { token_source, .. } => *token_source,
             AnalyzerError::MultipleAssignment { token_source, .. } => *token_source,
             AnalyzerError::MultipleDefault { token_source, .. } => *token_source,
+            AnalyzerError::NestedIf { token_source, .. } => *token_source,
             AnalyzerError::PrivateMember { token_source, .. } => *token_source,
             AnalyzerError::PrivateNamespace { token_source, .. } => *token_source,
             AnalyzerError::ReferringBeforeDefinition { token_source, .. } => *token_source,
@@ -2285,6 +2460,7 @@ impl AnalyzerError {
             AnalyzerError::UnknownPort { token_source, .. } => *token_source,
             AnalyzerError::UnknownUnsafe { token_source, .. } => *token_source,
             AnalyzerError::UnresolvableGenericExpression { token_source, .. } => *token_source,
+            AnalyzerError::UnusedParameter { token_source, .. } => *token_source,
             AnalyzerError::UnusedReturn { token_source, .. } => *token_source,
             AnalyzerError::UnusedVariable { token_source, .. } => *token_source,
             AnalyzerError::WrongSeparator { token_source, .. } => *token_source,
@@ -2348,6 +2524,9 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
+    pub fn denied_lint(error: AnalyzerError) -> Self {
+        AnalyzerError::DeniedLint(DeniedLint(Box::new(error)))
+    }
     pub fn duplicate_argument(identifier: &str, token: &TokenRange) -> Self {
         AnalyzerError::DuplicateArgument {
             identifier: identifier.to_string(),
@@ -2393,6 +2572,21 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
+    pub fn implicit_truncation(
+        identifier: &str,
+        dst_width: usize,
+        src_width: usize,
+        token: &TokenRange,
+    ) -> Self {
+        AnalyzerError::ImplicitTruncation {
+            identifier: identifier.to_string(),
+            dst_width,
+            src_width,
+            input: source(token),
+            error_location: token.into(),
+            token_source: token.source(),
+        }
+    }
     pub fn include_failure(name: &str, cause: &str, token: &TokenRange) -> Self {
         AnalyzerError::IncludeFailure {
             name: name.to_string(),
@@ -2519,13 +2713,19 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
-    pub fn invalid_identifier(identifier: &str, rule: &str, token: &TokenRange) -> Self {
+    pub fn invalid_identifier(
+        identifier: &str,
+        rule: &str,
+        token: &TokenRange,
+        fix: Option<Fix>,
+    ) -> Self {
         AnalyzerError::InvalidIdentifier {
             identifier: identifier.to_string(),
             rule: rule.to_string(),
             input: source(token),
             error_location: token.into(),
             token_source: token.source(),
+            fix,
         }
     }
     pub fn invalid_import(token: &TokenRange) -> Self {
@@ -2719,6 +2919,15 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
+    pub fn invalid_temporal(kind: InvalidTemporalKind, token: &TokenRange) -> Self {
+        AnalyzerError::InvalidTemporal {
+            help: kind.help().to_string(),
+            kind,
+            input: source(token),
+            error_location: token.into(),
+            token_source: token.source(),
+        }
+    }
     pub fn invalid_test(cause: InvalidTestKind, token: &TokenRange) -> Self {
         AnalyzerError::InvalidTest {
             cause,
@@ -2812,6 +3021,14 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
+    pub fn magic_number(number: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MagicNumber {
+            number: number.to_string(),
+            input: source(token),
+            error_location: token.into(),
+            token_source: token.source(),
+        }
+    }
     pub fn member_access_on_array(
         name: &str,
         member: &str,
@@ -2961,6 +3178,14 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
+    pub fn missing_default(kind: &str, token: &TokenRange) -> Self {
+        AnalyzerError::MissingDefault {
+            kind: kind.to_string(),
+            input: source(token),
+            error_location: token.into(),
+            token_source: token.source(),
+        }
+    }
     pub fn missing_default_argument(identifier: &str, token: &TokenRange) -> Self {
         AnalyzerError::MissingDefaultArgument {
             identifier: identifier.into(),
@@ -2999,13 +3224,14 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
-    pub fn missing_port(name: &str, port: &str, token: &TokenRange) -> Self {
+    pub fn missing_port(name: &str, port: &str, token: &TokenRange, fix: Option<Fix>) -> Self {
         AnalyzerError::MissingPort {
             name: name.to_string(),
             port: port.to_string(),
             input: source(token),
             error_location: token.into(),
             token_source: token.source(),
+            fix,
         }
     }
     pub fn missing_reset_signal(token: &TokenRange) -> Self {
@@ -3015,12 +3241,18 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
-    pub fn missing_reset_statement(name: &str, token: &TokenRange, tokens: &[TokenRange]) -> Self {
+    pub fn missing_reset_statement(
+        name: &str,
+        token: &TokenRange,
+        tokens: &[TokenRange],
+        fix: Option<Fix>,
+    ) -> Self {
         AnalyzerError::MissingResetStatement {
             name: name.to_string(),
             input: source(token),
             error_locations: tokens.iter().map(|x| x.into()).collect(),
             token_source: token.source(),
+            fix,
         }
     }
     pub fn missing_tri(token: &TokenRange) -> Self {
@@ -3069,6 +3301,15 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
+    pub fn nested_if(depth: usize, limit: usize, token: &TokenRange) -> Self {
+        AnalyzerError::NestedIf {
+            depth,
+            limit,
+            input: source(token),
+            error_location: token.into(),
+            token_source: token.source(),
+        }
+    }
     pub fn private_member(name: &str, token: &TokenRange) -> Self {
         AnalyzerError::PrivateMember {
             name: name.to_string(),
@@ -3102,11 +3343,13 @@ impl AnalyzerError {
         }
     }
     pub fn sv_keyword_usage(identifier: &str, token: &TokenRange) -> Self {
+        let name = identifier.strip_prefix("r#").unwrap_or(identifier);
         AnalyzerError::SvKeywordUsage {
             identifier: identifier.to_string(),
             input: source(token),
             error_location: token.into(),
             token_source: token.source(),
+            fix: Some(Fix::rename(&token.beg, &format!("{name}_"))),
         }
     }
     pub fn sv_with_implicit_reset(token: &TokenRange) -> Self {
@@ -3302,6 +3545,14 @@ impl AnalyzerError {
             token_source: token.source(),
         }
     }
+    pub fn unused_parameter(identifier: &str, token: &TokenRange) -> Self {
+        AnalyzerError::UnusedParameter {
+            identifier: identifier.to_string(),
+            input: source(token),
+            error_location: token.into(),
+            token_source: token.source(),
+        }
+    }
     pub fn unused_return(identifier: &str, token: &TokenRange) -> Self {
         AnalyzerError::UnusedReturn {
             identifier: identifier.to_string(),
@@ -3316,6 +3567,7 @@ impl AnalyzerError {
             input: source(token),
             error_location: token.into(),
             token_source: token.source(),
+            fix: Some(Fix::rename(&token.beg, &format!("_{identifier}"))),
         }
     }
     pub fn invalid_wavedrom(cause: InvalidWavedromKind, token: &TokenRange) -> Self {
@@ -3821,6 +4073,44 @@ impl fmt::Display for InvalidForStepKind {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InvalidTemporalKind {
+    /// An implication outside the condition of `$assert`/`$assert_continue`.
+    Property,
+    /// A temporal assertion or sampled value function outside `always_ff`.
+    Unclocked,
+    /// A cycle delay that is not a constant `##n` or `##[m:n]` with `m <= n`.
+    Delay,
+}
+
+impl InvalidTemporalKind {
+    fn help(&self) -> &'static str {
+        match self {
+            InvalidTemporalKind::Property => {
+                "use the implication as the first argument of `$assert` or `$assert_continue`"
+            }
+            InvalidTemporalKind::Unclocked => "move it into an `always_ff` block",
+            InvalidTemporalKind::Delay => "use a constant delay such as `##2` or `##[1:3]`",
+        }
+    }
+}
+
+impl fmt::Display for InvalidTemporalKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidTemporalKind::Property => {
+                "temporal property is only allowed as an assertion condition".fmt(f)
+            }
+            InvalidTemporalKind::Unclocked => {
+                "temporal assertion needs a clock and is only allowed in always_ff".fmt(f)
+            }
+            InvalidTemporalKind::Delay => {
+                "cycle delay is not a constant range of at most 64 cycles".fmt(f)
+            }
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum InvalidTestKind {
     NoTopModuleCocotb,
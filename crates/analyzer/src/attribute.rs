@@ -3,9 +3,10 @@ use std::cell::RefCell;
 use std::fmt;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
+use veryl_metadata::LintRule;
 use veryl_parser::resource_table::{self, StrId};
 use veryl_parser::veryl_grammar_trait::{
-    AttributeOpt, DescriptionGroup, DescriptionGroupGroup, DescriptionItem,
+    AttributeOpt, DescriptionGroup, DescriptionGroupGroup, DescriptionItem, StatementBlockGroup,
 };
 use veryl_parser::veryl_token::Token;
 
@@ -25,6 +26,7 @@ pub enum Attribute {
     Format(Vec<FormatItem>),
     Expand(Vec<ExpandItem>),
     Ignore,
+    Fork,
 }
 
 impl Attribute {
@@ -98,6 +100,7 @@ impl fmt::Display for Attribute {
                 format!("expand({arg})")
             }
             Attribute::Ignore => String::from("ignore"),
+            Attribute::Fork => String::from("fork"),
         };
         text.fmt(f)
     }
@@ -259,6 +262,7 @@ struct Pattern {
     pub expand: StrId,
     pub modport: StrId,
     pub ignore: StrId,
+    pub fork: StrId,
 }
 
 impl Pattern {
@@ -294,6 +298,7 @@ impl Pattern {
             expand: resource_table::insert_str("expand"),
             modport: resource_table::insert_str("modport"),
             ignore: resource_table::insert_str("ignore"),
+            fork: resource_table::insert_str("fork"),
         }
     }
 }
@@ -311,6 +316,17 @@ pub fn has_test_attribute(group: &DescriptionGroup) -> bool {
     })
 }
 
+/// Syntactic check for a `#[fork]` statement group, used while the
+/// statements convert.
+pub fn has_fork_attribute(group: &StatementBlockGroup) -> bool {
+    PAT.with_borrow(|pat| {
+        group
+            .statement_block_group_list
+            .iter()
+            .any(|x| x.attribute.identifier.identifier_token.token.text == pat.fork)
+    })
+}
+
 /// Flattens a description group like `From<&DescriptionGroup> for
 /// Vec<&DescriptionItem>`, but drops `#[test]`-marked subgroups.
 pub fn description_items_excluding_tests(group: &DescriptionGroup) -> Vec<&DescriptionItem> {
@@ -391,7 +407,10 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                         x if x == pat.unassign_variable => {
                             Ok(Attribute::Allow(AllowItem::UnassignVariable))
                         }
-                        _ => Err(err),
+                        x => match LintRule::from_name(&x.to_string()) {
+                            Some(rule) => Ok(Attribute::Allow(AllowItem::Lint(rule))),
+                            None => Err(err),
+                        },
                     }
                 } else {
                     Err(err)
@@ -568,20 +587,39 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                     Ok(Attribute::Ignore)
                 }
             }
+            x if x == pat.fork => {
+                if value.attribute_opt.is_some() {
+                    Err(AttributeError::MismatchArgs("no argument".to_string()))
+                } else {
+                    Ok(Attribute::Fork)
+                }
+            }
             _ => Err(AttributeError::UnknownAttribute),
         })
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AllowItem {
     MissingPort,
     MissingResetStatement,
     UnusedVariable,
     UnassignVariable,
+    Lint(LintRule),
 }
 
 impl AllowItem {
+    pub fn iter() -> impl Iterator<Item = Self> {
+        [
+            AllowItem::MissingPort,
+            AllowItem::MissingResetStatement,
+            AllowItem::UnusedVariable,
+            AllowItem::UnassignVariable,
+        ]
+        .into_iter()
+        .chain(LintRule::ALL.into_iter().map(AllowItem::Lint))
+    }
+
     pub fn available() -> String {
         let mut ret = String::new();
         for (i, x) in Self::iter().enumerate() {
@@ -601,6 +639,7 @@ impl fmt::Display for AllowItem {
             AllowItem::MissingResetStatement => "missing_reset_statement",
             AllowItem::UnusedVariable => "unused_variable",
             AllowItem::UnassignVariable => "unassign_variable",
+            AllowItem::Lint(x) => return x.fmt(f),
         };
         text.fmt(f)
     }
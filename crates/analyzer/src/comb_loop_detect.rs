@@ -580,6 +580,7 @@ fn collect_statement_spans(
             Statement::SystemFunctionCall(_)
             | Statement::IfReset(_)
             | Statement::TbMethodCall(_)
+            | Statement::Fork(_)
             | Statement::Break
             | Statement::Unsupported(_)
             | Statement::Null => {}
@@ -695,6 +696,7 @@ fn collect_statement_transfers(
             Statement::SystemFunctionCall(_)
             | Statement::IfReset(_)
             | Statement::TbMethodCall(_)
+            | Statement::Fork(_)
             | Statement::Break
             | Statement::Unsupported(_)
             | Statement::Null => {}
@@ -1297,7 +1299,15 @@ impl InstanceActualAnalysis<'_, '_, '_> {
                     | SystemFunctionKind::Display(_)
                     | SystemFunctionKind::Write(_)
                     | SystemFunctionKind::Assert { .. }
-                    | SystemFunctionKind::Finish => {}
+                    | SystemFunctionKind::Cover { .. }
+                    | SystemFunctionKind::CoverBins { .. }
+                    | SystemFunctionKind::Finish
+                    | SystemFunctionKind::DumpOn
+                    | SystemFunctionKind::DumpOff
+                    | SystemFunctionKind::Wait { .. }
+                    | SystemFunctionKind::WaitUntil { .. }
+                    | SystemFunctionKind::Force { .. }
+                    | SystemFunctionKind::Release { .. } => {}
                 },
                 _ => {}
             },
@@ -603,6 +603,7 @@ impl<'a, 's> ProcedureAnalysis<'a, 's> {
             Statement::Break => true,
             Statement::IfReset(_)
             | Statement::TbMethodCall(_)
+            | Statement::Fork(_)
             | Statement::Unsupported(_)
             | Statement::Null => false,
         }
@@ -1404,7 +1405,9 @@ impl<'a, 's> ProcedureAnalysis<'a, 's> {
             SystemFunctionKind::Bits(_)
             | SystemFunctionKind::Size(_)
             | SystemFunctionKind::Clog2(_)
-            | SystemFunctionKind::Finish => Vec::new(),
+            | SystemFunctionKind::Finish
+            | SystemFunctionKind::DumpOn
+            | SystemFunctionKind::DumpOff => Vec::new(),
             SystemFunctionKind::Onehot(input)
             | SystemFunctionKind::Signed(input)
             | SystemFunctionKind::Unsigned(input) => self.eval_expr(&input.0),
@@ -1428,6 +1431,26 @@ impl<'a, 's> ProcedureAnalysis<'a, 's> {
                 }
                 Vec::new()
             }
+            SystemFunctionKind::Cover { cond: input, .. }
+            | SystemFunctionKind::CoverBins { value: input, .. }
+            | SystemFunctionKind::Wait {
+                duration: input, ..
+            }
+            | SystemFunctionKind::WaitUntil {
+                condition: input, ..
+            } => {
+                self.eval_expr(&input.0);
+                Vec::new()
+            }
+            SystemFunctionKind::Force { target, value } => {
+                self.eval_expr(&target.0);
+                self.eval_expr(&value.0);
+                Vec::new()
+            }
+            SystemFunctionKind::Release { target } => {
+                self.eval_expr(&target.0);
+                Vec::new()
+            }
         }
     }
 }
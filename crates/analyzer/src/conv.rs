@@ -5,6 +5,7 @@ pub mod declaration;
 pub mod expression;
 pub mod instance;
 pub mod ir;
+pub mod property;
 pub mod statement;
 pub mod utils;
 pub mod var;
@@ -3,11 +3,13 @@ use crate::attribute::AllowItem;
 use crate::attribute::Attribute as Attr;
 use crate::attribute_table;
 use crate::conv::Context;
-use crate::symbol::{Symbol, SymbolId, SymbolKind};
+use crate::fix::{self, Fix, TextEdit};
+use crate::symbol::{Direction, Port, Symbol, SymbolId, SymbolKind};
 use crate::symbol_path::SymbolPathNamespace;
 use crate::symbol_table;
 use veryl_parser::Stringifier;
 use veryl_parser::resource_table;
+use veryl_parser::token_range::TokenRange;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::Token;
 use veryl_parser::veryl_walker::VerylWalker;
@@ -166,18 +168,26 @@ pub fn check_inst(
         }
 
         if check_port_connection {
-            for port in &ports {
-                if !connected_ports.contains(&port.name())
-                    && port.property().default_value.is_none()
-                    && !attribute_table::contains(header_token, Attr::Allow(AllowItem::MissingPort))
-                {
-                    let port = resource_table::get_str_value(port.name()).unwrap();
-                    context.insert_error(AnalyzerError::missing_port(
-                        name,
-                        &port,
-                        &arg.identifier.as_ref().into(),
-                    ));
-                }
+            let missing: Vec<_> = ports
+                .iter()
+                .filter(|port| {
+                    !connected_ports.contains(&port.name())
+                        && port.property().default_value.is_none()
+                        && !attribute_table::contains(
+                            header_token,
+                            Attr::Allow(AllowItem::MissingPort),
+                        )
+                })
+                .collect();
+            let fix = missing_port_fix(arg, &missing);
+            for port in &missing {
+                let port = resource_table::get_str_value(port.name()).unwrap();
+                context.insert_error(AnalyzerError::missing_port(
+                    name,
+                    &port,
+                    &arg.identifier.as_ref().into(),
+                    fix.clone(),
+                ));
             }
             for param in &connected_params {
                 if !params.iter().any(|x| &x.name == param) {
@@ -202,3 +212,70 @@ pub fn check_inst(
         }
     }
 }
+
+/// Connects every missing input to `0` and output to `_`, in the style of
+/// the existing port list: one port per line when it spans lines, inline
+/// otherwise, or as a new port list when there is none.
+fn missing_port_fix(arg: &ComponentInstantiation, missing: &[&Port]) -> Option<Fix> {
+    let mut connections = Vec::new();
+    for port in missing {
+        let value = match port.property().direction {
+            Direction::Input => "0",
+            Direction::Output => "_",
+            _ => continue,
+        };
+        connections.push(format!("{}: {value}", port.name()));
+    }
+    if connections.is_empty() {
+        return None;
+    }
+
+    let mut edits = Vec::new();
+    if let Some(x) = &arg.component_instantiation_opt2 {
+        let l_paren = &x.inst_port.l_paren.l_paren_token.token;
+        let r_paren = &x.inst_port.r_paren.r_paren_token.token;
+        let list = x
+            .inst_port
+            .inst_port_opt
+            .as_ref()
+            .map(|x| &x.inst_port_list);
+        let trailing_comma = list.is_some_and(|x| x.inst_port_list_opt.is_some());
+
+        if l_paren.line != r_paren.line {
+            if let Some(list) = list
+                && !trailing_comma
+            {
+                let last: TokenRange = list.as_ref().into();
+                let offset = (last.end.pos + last.end.length) as usize;
+                edits.push(TextEdit::insert(&last.end, offset, ",")?);
+            }
+            let text = r_paren.source.get_text();
+            let (offset, indent) = fix::line_start(&text, r_paren.pos as usize);
+            let text: String = connections
+                .iter()
+                .map(|x| format!("{indent}    {x},\n"))
+                .collect();
+            edits.push(TextEdit::insert(r_paren, offset, &text)?);
+        } else {
+            let separator = match (list.is_some(), trailing_comma) {
+                (false, _) => "",
+                (true, true) => " ",
+                (true, false) => ", ",
+            };
+            let text = format!("{separator}{}", connections.join(", "));
+            edits.push(TextEdit::insert(r_paren, r_paren.pos as usize, &text)?);
+        }
+    } else {
+        let range: TokenRange = arg.into();
+        let offset = (range.end.pos + range.end.length) as usize;
+        let text = format!(" ({})", connections.join(", "));
+        edits.push(TextEdit::insert(&range.end, offset, &text)?);
+    }
+
+    let title = if connections.len() == 1 {
+        format!("connect {}", connections[0])
+    } else {
+        format!("connect {} missing ports", connections.len())
+    };
+    Some(Fix::new(&title, edits))
+}
@@ -12,12 +12,12 @@ use crate::symbol::{Affiliation, ClockDomain, Direction, GenericMap, SymbolId};
 use crate::symbol_path::GenericSymbolPath;
 use crate::value::MaskCache;
 use crate::{HashMap, HashSet};
-use miette::Result;
+use miette::{Result, SourceSpan};
 use std::sync::{Arc, Mutex};
 use veryl_parser::resource_table::StrId;
 use veryl_parser::token_range::TokenRange;
 use veryl_parser::veryl_grammar_trait::{ComponentInstantiation, Identifier};
-use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_token::{Token, TokenSource};
 
 #[derive(Clone)]
 pub struct Config {
@@ -97,6 +97,18 @@ pub struct Context {
     pub tb_hoist: Option<Vec<crate::ir::Statement>>,
     /// Names hoist temporaries uniquely within a module.
     pub tb_hoist_count: usize,
+    /// Sink for the history-register updates of the sampled value functions
+    /// and temporal assertions in the current `always_ff`; `Some` only while
+    /// an `always_ff` converts. The updates run at the end of the block.
+    pub temporal_history: Option<Vec<crate::ir::Statement>>,
+    /// Names history registers uniquely within a module.
+    pub temporal_count: usize,
+    /// Set while the arguments of a checker (`$assert`, `$cover`, ...)
+    /// convert: history registers declared meanwhile only serve the check.
+    pub in_checker: bool,
+    /// History registers of the module that only checkers read; see
+    /// `ir::Module::verification_vars`.
+    pub verification_vars: HashSet<VarId>,
     /// Resolved numeric parameters of the external component instances
     /// declared so far, for evaluating declared method width expressions.
     pub tb_component_params:
@@ -117,6 +129,8 @@ pub struct Context {
     pub mask_cache: MaskCache,
     pub tb_reset_cycles: HashMap<StrId, Expression>,
     pub tb_clock_period: HashMap<StrId, Expression>,
+    /// `$tb::clock_gen` instances declared so far, in declaration order.
+    pub tb_clocks: Vec<StrId>,
     pub tb_reset_clock: HashMap<StrId, StrId>,
     /// Sparse rollback state used by analyses that reuse this otherwise-large
     /// context across independent procedures. Empty during normal conversion.
@@ -147,6 +161,19 @@ struct AnalysisTransaction {
     errors: Vec<AnalyzerError>,
 }
 
+/// Where an implicit truncation, warned or denied, is reported.
+fn truncation_location(error: &AnalyzerError) -> Option<(SourceSpan, TokenSource)> {
+    match error {
+        AnalyzerError::ImplicitTruncation {
+            error_location,
+            token_source,
+            ..
+        } => Some((*error_location, *token_source)),
+        AnalyzerError::DeniedLint(x) => truncation_location(&x.0),
+        _ => None,
+    }
+}
+
 impl Context {
     pub(crate) fn begin_analysis_transaction(&mut self) {
         self.analysis_transactions.push(AnalysisTransaction {
@@ -380,6 +407,15 @@ impl Context {
             }
         }
 
+        // A generic component is converted once per instance; report a
+        // truncation once per assignment rather than once per width.
+        if let Some(location) = truncation_location(&error) {
+            replaced = self
+                .errors
+                .iter()
+                .any(|x| truncation_location(x) == Some(location));
+        }
+
         if !replaced && !self.errors.contains(&error) {
             self.errors.push(error);
         }
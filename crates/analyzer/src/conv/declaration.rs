@@ -662,13 +662,18 @@ impl Conv<&AlwaysFfDeclaration> for ir::Declaration {
         context.current_clock = Some(clock.comptime.clone());
 
         context.push_affiliation(Affiliation::AlwaysFf);
+        context.temporal_history = Some(Vec::new());
 
         let statements: IrResult<ir::StatementBlock> =
             context.block(|c| Conv::conv(c, value.statement_block.as_ref()));
 
+        let history = context.temporal_history.take().unwrap_or_default();
         context.pop_affiliation();
 
-        Ok(ir::Declaration::new_ff(clock, reset, statements?.0))
+        let mut statements = statements?.0;
+        statements.extend(history);
+
+        Ok(ir::Declaration::new_ff(clock, reset, statements))
     }
 }
 
@@ -1447,6 +1452,10 @@ impl Conv<&InstDeclaration> for ir::Declaration {
                 inst_token,
             );
 
+            if matches!(tb_prop.kind, TbComponentKind::ClockGen) {
+                context.tb_clocks.push(inst_name);
+            }
+
             // Suppress UnassignVariable for $tb component variables
             attribute_table::insert(inst_token, Attribute::Allow(AllowItem::UnassignVariable));
 
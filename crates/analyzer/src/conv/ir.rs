@@ -7,7 +7,7 @@ use crate::conv::{Affiliation, Context, Conv};
 use crate::ir::{self, IrResult, VarPath};
 use crate::symbol::SymbolKind;
 use crate::symbol_table;
-use crate::{HashMap, ir_error};
+use crate::{HashMap, HashSet, ir_error};
 use veryl_parser::token_range::TokenRange;
 use veryl_parser::veryl_grammar_trait::*;
 
@@ -316,6 +316,7 @@ impl Conv<(&ModuleDeclaration, bool)> for ir::Module {
             per_decl_refs: HashMap::default(),
             assign_tokens: HashMap::default(),
             ff_table: ir::FfTable::default(),
+            verification_vars: std::mem::take(&mut context.verification_vars),
         })
     }
 }
@@ -540,6 +541,7 @@ impl Conv<&ProtoModuleDeclaration> for ir::Module {
             per_decl_refs: HashMap::default(),
             assign_tokens: HashMap::default(),
             ff_table: ir::FfTable::default(),
+            verification_vars: HashSet::default(),
         })
     }
 }
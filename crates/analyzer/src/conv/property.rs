@@ -0,0 +1,445 @@
+//! Temporal assertions and sampled value functions.
+//!
+//! Both lower to plain `always_ff` logic over hidden history registers, so
+//! every consumer of the IR checks them on each clock without knowing about
+//! them: `$past(x, n)` reads the last of `n` registers shifting `x`, and
+//! `a |-> ##[m:n] b` keeps one register bit per cycle an attempt started by
+//! `a` may still be waiting for `b`. The emitter keeps the source form and
+//! writes SVA instead. Registers only a checker reads are recorded as
+//! verification-only, so synthesis leaves them out.
+
+use crate::analyzer_error::{AnalyzerError, InvalidTemporalKind};
+use crate::conv::utils::eval_expr;
+use crate::conv::{Context, Conv};
+use crate::ir::{
+    self, Comptime, IrResult, Op, Shape, VarId, VarIndex, VarKind, VarPath, VarPathSelect,
+    VarSelect, Variable,
+};
+use crate::ir_error;
+use crate::symbol::{Affiliation, ClockDomain, SymbolKind};
+use crate::symbol_table;
+use crate::value::Value;
+use veryl_parser::resource_table::{self, StrId};
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_grammar_trait::*;
+
+/// Longest cycle delay (and so attempt register) a property may use.
+const MAX_DELAY: usize = 64;
+
+/// Sampled value functions lower only inside `always_ff`; elsewhere they
+/// stay SystemVerilog pass-through calls.
+pub fn is_sampled_value_call(context: &Context, name: StrId) -> bool {
+    context.temporal_history.is_some()
+        && matches!(
+            name.to_string().as_str(),
+            "$past" | "$rose" | "$fell" | "$stable"
+        )
+}
+
+/// Properties need the clock of the enclosing `always_ff`.
+fn check_clocked(context: &mut Context, token: TokenRange) -> IrResult<()> {
+    if context.temporal_history.is_some() {
+        Ok(())
+    } else {
+        context.insert_error(AnalyzerError::invalid_temporal(
+            InvalidTemporalKind::Unclocked,
+            &token,
+        ));
+        Err(ir_error!(token))
+    }
+}
+
+/// Whether `identifier` calls a checker (`$assert`, `$cover`, ...), whose
+/// arguments check the design and are no part of it.
+pub fn is_checker_call(identifier: &ExpressionIdentifier) -> bool {
+    checker_name(identifier).is_some()
+}
+
+fn checker_name(identifier: &ExpressionIdentifier) -> Option<String> {
+    let symbol = symbol_table::resolve(identifier).ok()?;
+    let name = symbol.found.token.to_string();
+    let checker = matches!(
+        name.as_str(),
+        "$assert" | "$assert_continue" | "$cover" | "$cover_bins"
+    );
+    (matches!(symbol.found.kind, SymbolKind::SystemFunction(_)) && checker).then_some(name)
+}
+
+struct History {
+    id: VarId,
+    path: VarPath,
+    comptime: Comptime,
+    width: usize,
+}
+
+impl History {
+    /// Declares a hidden `width`-bit register, reset to 0 at start. It is
+    /// verification-only if a checker's arguments are converting.
+    fn new(
+        context: &mut Context,
+        prefix: &str,
+        width: usize,
+        signed: bool,
+        clock_domain: ClockDomain,
+        token: TokenRange,
+    ) -> Self {
+        let name = format!("__{prefix}_{}", context.temporal_count);
+        context.temporal_count += 1;
+        let path = VarPath::new(resource_table::insert_str(&name));
+        let mut r#type = ir::Type::new(ir::TypeKind::Logic);
+        r#type.set_concrete_width(Shape::new(vec![Some(width)]));
+        r#type.signed = signed;
+
+        let comptime = Comptime::from_type(r#type.clone(), clock_domain, token);
+        let id = context.insert_var_path(path.clone(), comptime.clone());
+        let array_limit = context.config.evaluate_array_limit;
+        let variable = Variable::new(
+            id,
+            path.clone(),
+            VarKind::Variable,
+            r#type,
+            vec![Value::new(0, width, signed)],
+            Affiliation::Module,
+            &token,
+            array_limit,
+        );
+        context.insert_variable(id, variable);
+        if context.in_checker {
+            context.verification_vars.insert(id);
+        }
+
+        Self {
+            id,
+            path,
+            comptime,
+            width,
+        }
+    }
+
+    fn read(&self) -> ir::Expression {
+        ir::Expression::Term(Box::new(ir::Factor::Variable(
+            self.id,
+            VarIndex::default(),
+            VarSelect::default(),
+            self.comptime.clone(),
+        )))
+    }
+
+    fn assign(&self, context: &mut Context, mut expr: ir::Expression) -> ir::Statement {
+        let token = self.comptime.token;
+        let _ = expr.eval_comptime(context, Some(self.width));
+        ir::Statement::Assign(ir::AssignStatement {
+            dst: vec![ir::AssignDestination {
+                id: self.id,
+                path: self.path.clone(),
+                index: VarIndex::default(),
+                select: VarSelect::default(),
+                comptime: self.comptime.clone(),
+                token,
+            }],
+            width: Some(self.width),
+            expr,
+            token,
+        })
+    }
+}
+
+fn value(value: u64, width: usize, token: TokenRange) -> ir::Expression {
+    ir::Expression::create_value(Value::new(value, width, false), token)
+}
+
+fn unary(op: Op, x: ir::Expression, token: TokenRange) -> ir::Expression {
+    let comptime = Box::new(Comptime::create_unknown(token));
+    ir::Expression::Unary(op, Box::new(x), comptime)
+}
+
+fn binary(x: ir::Expression, op: Op, y: ir::Expression, token: TokenRange) -> ir::Expression {
+    let comptime = Box::new(Comptime::create_unknown(token));
+    ir::Expression::Binary(Box::new(x), op, Box::new(y), comptime)
+}
+
+/// Bit 0 of `x` as a 1-bit operand of the logical operators.
+fn lsb(x: ir::Expression, width: usize, token: TokenRange) -> ir::Expression {
+    let bit = binary(x, Op::BitAnd, value(1, width, token), token);
+    binary(bit, Op::Ne, value(0, width, token), token)
+}
+
+fn const_usize(comptime: &Comptime) -> Option<usize> {
+    if !comptime.is_const {
+        return None;
+    }
+    comptime.get_value().ok()?.to_usize()
+}
+
+/// `$past(x[, n])`, `$rose(x)`, `$fell(x)` or `$stable(x)` in `always_ff`:
+/// the call reads registers that the end of the block updates from `x`.
+pub fn sampled_value_call(
+    context: &mut Context,
+    name: StrId,
+    mut args: Vec<(ir::Expression, Vec<VarPathSelect>, TokenRange)>,
+    token: TokenRange,
+) -> IrResult<ir::Expression> {
+    let name_str = name.to_string();
+    let arity = if name_str == "$past" { 1..=2 } else { 1..=1 };
+    if !arity.contains(&args.len()) {
+        context.insert_error(AnalyzerError::mismatch_function_arity(
+            &name_str,
+            args.len().clamp(*arity.start(), *arity.end()),
+            args.len(),
+            &token,
+        ));
+        return Err(ir_error!(token));
+    }
+
+    let (mut x, _, _) = args.remove(0);
+    let comptime = x.eval_comptime(context, None).clone();
+    let width = comptime
+        .r#type
+        .total_width()
+        .ok_or_else(|| ir_error!(token))?;
+    let signed = comptime.r#type.signed;
+
+    let depth = if let Some((mut n, _, n_token)) = args.pop() {
+        let n = n.eval_comptime(context, None).clone();
+        match const_usize(&n) {
+            Some(n) if n >= 1 => n,
+            _ => {
+                context.insert_error(AnalyzerError::unevaluable_value(
+                    crate::analyzer_error::UnevaluableValueKind::SystemFunctionArg("$past"),
+                    &n_token,
+                ));
+                return Err(ir_error!(token));
+            }
+        }
+    } else {
+        1
+    };
+
+    // `$past(x, n)` is the last of a chain of `n` registers fed by `x`.
+    let mut src = x.clone();
+    let mut last = None;
+    for _ in 0..depth {
+        let reg = History::new(context, "past", width, signed, comptime.clock_domain, token);
+        let update = reg.assign(context, src);
+        context.temporal_history.as_mut().unwrap().push(update);
+        src = reg.read();
+        last = Some(reg);
+    }
+    let past = last.unwrap().read();
+
+    let ret = match name_str.as_str() {
+        "$past" => past,
+        "$rose" => binary(
+            lsb(x, width, token),
+            Op::LogicAnd,
+            unary(Op::LogicNot, lsb(past, width, token), token),
+            token,
+        ),
+        "$fell" => binary(
+            unary(Op::LogicNot, lsb(x, width, token), token),
+            Op::LogicAnd,
+            lsb(past, width, token),
+            token,
+        ),
+        "$stable" => binary(x, Op::Eq, past, token),
+        _ => unreachable!(),
+    };
+    Ok(ret)
+}
+
+/// The `##n`/`##[m:n]` of a property, in cycles after the antecedent.
+fn cycle_delay(context: &mut Context, value: &CycleDelay) -> IrResult<(usize, usize)> {
+    let token: TokenRange = value.into();
+    let range = match value.cycle_delay_group.as_ref() {
+        CycleDelayGroup::Number(x) => {
+            let n: Comptime = Conv::conv(context, x.number.as_ref())?;
+            const_usize(&n).map(|n| (n, n))
+        }
+        CycleDelayGroup::LBracketExpressionColonExpressionRBracket(x) => {
+            let (m, _) = eval_expr(context, None, &x.expression, false)?;
+            let (n, _) = eval_expr(context, None, &x.expression0, false)?;
+            const_usize(&m).zip(const_usize(&n))
+        }
+    };
+    match range {
+        Some((m, n)) if m <= n && n <= MAX_DELAY => Ok((m, n)),
+        _ => {
+            context.insert_error(AnalyzerError::invalid_temporal(
+                InvalidTemporalKind::Delay,
+                &token,
+            ));
+            Err(ir_error!(token))
+        }
+    }
+}
+
+/// `$assert(a |-> ##[m:n] b, ...)`/`$assert_continue(...)`: lowers to an
+/// immediate assertion that fails when an attempt runs out of cycles, plus
+/// the update of the attempt register. `None` when the call is not an
+/// assertion of a property.
+///
+/// Attempts advance only on edges where the assertion is reached, so one
+/// under `if_reset`'s else branch is suspended (not aborted) by the reset.
+pub fn assert_property(
+    context: &mut Context,
+    identifier: &ExpressionIdentifier,
+    list: &ArgumentList,
+    token: TokenRange,
+) -> IrResult<Option<ir::StatementBlock>> {
+    let items: Vec<&ArgumentItem> = list.into();
+    let Some(property) = &items[0].argument_expression.argument_expression_opt else {
+        return Ok(None);
+    };
+    let property = property.property_implication.as_ref();
+
+    if !matches!(
+        checker_name(identifier).as_deref(),
+        Some("$assert" | "$assert_continue")
+    ) {
+        return Ok(None);
+    }
+    let name = symbol_table::resolve(identifier)
+        .map_err(|_| ir_error!(token))?
+        .found
+        .token
+        .text;
+
+    let property_token: TokenRange = items[0].into();
+    check_clocked(context, property_token)?;
+
+    let in_checker = std::mem::replace(&mut context.in_checker, true);
+    let ret = lower_property(context, name, &items, property, property_token, token);
+    context.in_checker = in_checker;
+    ret.map(Some)
+}
+
+fn lower_property(
+    context: &mut Context,
+    name: StrId,
+    items: &[&ArgumentItem],
+    property: &PropertyImplication,
+    property_token: TokenRange,
+    token: TokenRange,
+) -> IrResult<ir::StatementBlock> {
+    let (_, antecedent) = eval_expr(
+        context,
+        None,
+        &items[0].argument_expression.expression,
+        false,
+    )?;
+    let (_, consequent) = eval_expr(context, None, &property.expression, false)?;
+
+    let (mut min, mut max) = match &property.property_implication_opt {
+        Some(x) => cycle_delay(context, &x.cycle_delay)?,
+        None => (0, 0),
+    };
+    // `a |=> b` is `a |-> ##1 b`.
+    if property.implication.implication_token.to_string() == "|=>" {
+        min += 1;
+        max += 1;
+    }
+    if max > MAX_DELAY {
+        context.insert_error(AnalyzerError::invalid_temporal(
+            InvalidTemporalKind::Delay,
+            &property_token,
+        ));
+        return Err(ir_error!(token));
+    }
+
+    let mut statements = vec![];
+    let cond = if max == 0 {
+        // `a |-> b`: both in this cycle.
+        binary(
+            unary(Op::LogicNot, antecedent, property_token),
+            Op::LogicOr,
+            consequent,
+            property_token,
+        )
+    } else {
+        // Bit `k - 1` of the register: an attempt started `k` cycles ago
+        // that has not seen the consequent yet.
+        let pending = History::new(
+            context,
+            "property",
+            max,
+            false,
+            ClockDomain::None,
+            property_token,
+        );
+        let all = if max == 64 {
+            u64::MAX
+        } else {
+            (1u64 << max) - 1
+        };
+        let window = all & !((1u64 << (min.max(1) - 1)) - 1);
+
+        // The oldest attempt fails unless the consequent holds now.
+        let fail = binary(
+            lsb(
+                binary(
+                    pending.read(),
+                    Op::LogicShiftR,
+                    value((max - 1) as u64, 32, property_token),
+                    property_token,
+                ),
+                max,
+                property_token,
+            ),
+            Op::LogicAnd,
+            unary(Op::LogicNot, consequent.clone(), property_token),
+            property_token,
+        );
+
+        // The consequent ends the attempts inside the window; the rest age
+        // by one cycle and a new one starts on the antecedent.
+        let keep = ir::Expression::Ternary(
+            Box::new(consequent.clone()),
+            Box::new(value(all & !window, max, property_token)),
+            Box::new(value(all, max, property_token)),
+            Box::new(Comptime::create_unknown(property_token)),
+        );
+        let start = if min == 0 {
+            binary(
+                antecedent,
+                Op::LogicAnd,
+                unary(Op::LogicNot, consequent, property_token),
+                property_token,
+            )
+        } else {
+            antecedent
+        };
+        let next = binary(
+            binary(
+                binary(pending.read(), Op::BitAnd, keep, property_token),
+                Op::LogicShiftL,
+                value(1, 32, property_token),
+                property_token,
+            ),
+            Op::BitOr,
+            start,
+            property_token,
+        );
+        statements.push(pending.assign(context, next));
+
+        unary(Op::LogicNot, fail, property_token)
+    };
+
+    let mut args = vec![(cond, vec![], property_token)];
+    for item in &items[1..] {
+        let arg_token: TokenRange = item.argument_expression.expression.as_ref().into();
+        if item.argument_expression.argument_expression_opt.is_some() {
+            context.insert_error(AnalyzerError::invalid_temporal(
+                InvalidTemporalKind::Property,
+                &(*item).into(),
+            ));
+        }
+        let expr = Conv::conv(context, item.argument_expression.expression.as_ref())?;
+        let dst: Vec<VarPathSelect> =
+            Conv::conv(context, item.argument_expression.expression.as_ref())?;
+        args.push((expr, dst, arg_token));
+    }
+
+    let call = ir::SystemFunctionCall::new(context, name, args, token)?;
+    statements.insert(0, ir::Statement::SystemFunctionCall(Box::new(call)));
+    Ok(ir::StatementBlock(statements))
+}
@@ -1,4 +1,6 @@
 use crate::analyzer_error::{ComponentInterfaceMismatchKind, MismatchTypeKind};
+use crate::attribute::has_fork_attribute;
+use crate::conv::property::{assert_property, is_checker_call};
 use crate::conv::utils::{
     TbMethodCallPosition, TypePosition, argument_list, assign_rhs_context_type, build_for_range,
     build_for_statement, case_patterns, check_assign_before_definition, check_assign_clock_domain,
@@ -15,30 +17,82 @@ use crate::namespace::DefineContext;
 use crate::symbol::SymbolKind;
 use crate::symbol_table;
 use crate::{AnalyzerError, ir_error};
-use veryl_parser::token_range::TokenRange;
+use veryl_parser::token_range::{TokenExt, TokenRange};
 use veryl_parser::veryl_grammar_trait::*;
 
 impl Conv<&StatementBlock> for ir::StatementBlock {
     fn conv(context: &mut Context, value: &StatementBlock) -> IrResult<Self> {
-        let statements: Vec<_> = value.into();
         let mut ret = vec![];
-        for s in statements {
-            let x: IrResult<ir::StatementBlock> = Conv::conv(context, s);
-            match x {
-                Ok(x) => {
-                    ret.append(&mut x.0.into_iter().filter(|x| !x.is_null()).collect());
-                }
-                Err(e) => {
-                    if !context.in_generic {
-                        ret.push(ir::Statement::Unsupported(e.token));
-                    }
-                }
-            }
+        for x in &value.statement_block_list {
+            statement_block_group(context, &x.statement_block_group, &mut ret);
         }
         Ok(ir::StatementBlock(ret))
     }
 }
 
+/// Converts `group` into `ret`, flattening `block { ... }` groups. A group
+/// marked `#[fork]` becomes a fork statement instead, with each of its child
+/// groups as one thread.
+fn statement_block_group(
+    context: &mut Context,
+    group: &StatementBlockGroup,
+    ret: &mut Vec<ir::Statement>,
+) {
+    if has_fork_attribute(group) {
+        let token = group.range();
+        if !context.in_tb_block {
+            context.insert_error(AnalyzerError::invalid_statement("fork", &token));
+        }
+        let threads = match &*group.statement_block_group_group {
+            StatementBlockGroupGroup::BlockLBraceStatementBlockGroupGroupListRBrace(x) => x
+                .statement_block_group_group_list
+                .iter()
+                .map(|x| {
+                    let mut thread = vec![];
+                    statement_block_group(context, &x.statement_block_group, &mut thread);
+                    thread
+                })
+                .collect(),
+            StatementBlockGroupGroup::StatementBlockItem(x) => {
+                let mut thread = vec![];
+                statement_block_item(context, &x.statement_block_item, &mut thread);
+                vec![thread]
+            }
+        };
+        ret.push(ir::Statement::Fork(ir::ForkStatement { threads, token }));
+        return;
+    }
+
+    match &*group.statement_block_group_group {
+        StatementBlockGroupGroup::BlockLBraceStatementBlockGroupGroupListRBrace(x) => {
+            for x in &x.statement_block_group_group_list {
+                statement_block_group(context, &x.statement_block_group, ret);
+            }
+        }
+        StatementBlockGroupGroup::StatementBlockItem(x) => {
+            statement_block_item(context, &x.statement_block_item, ret);
+        }
+    }
+}
+
+fn statement_block_item(
+    context: &mut Context,
+    item: &StatementBlockItem,
+    ret: &mut Vec<ir::Statement>,
+) {
+    let x: IrResult<ir::StatementBlock> = Conv::conv(context, item);
+    match x {
+        Ok(x) => {
+            ret.append(&mut x.0.into_iter().filter(|x| !x.is_null()).collect());
+        }
+        Err(e) => {
+            if !context.in_generic {
+                ret.push(ir::Statement::Unsupported(e.token));
+            }
+        }
+    }
+}
+
 /// Runs `f` with a fresh testbench hoist sink and splices the component
 /// method calls hoisted out of the converted construct in front of its
 /// statements. Giving each statement-level construct its own sink keeps
@@ -457,8 +511,23 @@ impl Conv<&IdentifierStatement> for ir::StatementBlock {
                     return Ok(ir::StatementBlock(vec![tb_stmt]));
                 }
 
+                if let Some(x) = &x.function_call.function_call_opt
+                    && let Some(block) = assert_property(
+                        context,
+                        value.expression_identifier.as_ref(),
+                        x.argument_list.as_ref(),
+                        token,
+                    )?
+                {
+                    return Ok(block);
+                }
+
                 let args = if let Some(x) = &x.function_call.function_call_opt {
-                    argument_list(context, x.argument_list.as_ref())?
+                    let checker = is_checker_call(value.expression_identifier.as_ref());
+                    let in_checker = std::mem::replace(&mut context.in_checker, checker);
+                    let args = argument_list(context, x.argument_list.as_ref());
+                    context.in_checker = in_checker;
+                    args?
                 } else {
                     ir::Arguments::Null
                 };
@@ -755,6 +824,7 @@ impl Conv<&IfResetStatement> for ir::StatementBlock {
             true_side,
             false_side,
             token: value.into(),
+            true_side_token: value.statement_block.as_ref().into(),
         });
         Ok(ir::StatementBlock(vec![statement]))
     }
@@ -1,11 +1,13 @@
 use crate::analyzer_error::{
     AnalyzerError, ComponentInterfaceMismatchKind, ExceedLimitKind, InvalidForRangeKind,
-    InvalidForStepKind, MismatchTypeKind, MultipleDefaultKind, UnevaluableValueKind,
+    InvalidForStepKind, InvalidTemporalKind, MismatchTypeKind, MultipleDefaultKind,
+    UnevaluableValueKind,
 };
 use crate::conv::checker::anonymous::check_anonymous;
 use crate::conv::checker::clock_domain::check_clock_domain;
 use crate::conv::checker::generic::check_generic_refereence;
 use crate::conv::instance::InstanceHistoryError;
+use crate::conv::property::{is_sampled_value_call, sampled_value_call};
 use crate::conv::{Context, Conv};
 use crate::definition_table::{self, Definition, DefinitionId};
 use crate::ir::{
@@ -13,6 +15,8 @@ use crate::ir::{
     Signature, SystemFunctionInput, TbMethod, TbMethodCall, ValueVariant, VarIndex, VarKind,
     VarPath, VarPathSelect, VarSelect, Variable,
 };
+use crate::lint;
+use crate::scope;
 use crate::symbol::{
     self, Affiliation, ClockDomain, EnumMemberValue, GenericBoundKind, GenericMap, ProtoBound,
     Symbol, SymbolKind, TbComponentKind, TypeKind,
@@ -25,6 +29,7 @@ use crate::value::Value;
 use crate::{HashMap, HashSet, ir_error};
 use std::rc::Rc;
 use std::sync::Arc;
+use veryl_metadata::LintRule;
 use veryl_parser::resource_table::{self, StrId};
 use veryl_parser::token_range::TokenRange;
 use veryl_parser::veryl_grammar_trait::*;
@@ -626,6 +631,80 @@ pub fn check_assign_clock_domain(
     }
 }
 
+fn check_implicit_truncation(
+    context: &mut Context,
+    dst: &ir::AssignDestination,
+    src: &ir::Expression,
+    dst_width: Option<usize>,
+    token: &TokenRange,
+) {
+    // Dependencies (std included) are linted by their own projects. One
+    // instantiated from here is converted in this file's context, so go by
+    // the project it is declared in.
+    let in_dependency = context.in_dependency
+        || context
+            .current_namespace()
+            .and_then(|x| x.paths.first().copied())
+            .is_some_and(scope::is_dependency_project);
+
+    // Generic widths are unknown until instantiation.
+    if context.in_generic
+        || in_dependency
+        || !lint::is_enabled(LintRule::ImplicitTruncation)
+        || !matches!(
+            src.comptime().r#type.kind,
+            ir::TypeKind::Logic | ir::TypeKind::Bit
+        )
+    {
+        return;
+    }
+    if let Some(dst_width) = dst_width
+        && let Some(src_width) = operand_width(src)
+        && src_width > dst_width
+        && let Some(error) = lint::report(
+            LintRule::ImplicitTruncation,
+            &token.beg,
+            AnalyzerError::implicit_truncation(&dst.path.to_string(), dst_width, src_width, token),
+        )
+    {
+        context.insert_error(error);
+    }
+}
+
+/// The width `expr` needs to keep every bit of its non-constant operands.
+/// Constants take the width of the assignment, so `a + 1` fits a variable as
+/// wide as `a` even though `1` is 32-bit.
+fn operand_width(expr: &ir::Expression) -> Option<usize> {
+    let comptime = expr.comptime();
+    if comptime.is_const {
+        return None;
+    }
+    match expr {
+        ir::Expression::Unary(ir::Op::Add | ir::Op::Sub | ir::Op::BitNot, x, _) => operand_width(x),
+        ir::Expression::Unary(..) => Some(1),
+        ir::Expression::Binary(x, op, y, _) => match op {
+            ir::Op::Add
+            | ir::Op::Sub
+            | ir::Op::Mul
+            | ir::Op::Div
+            | ir::Op::Rem
+            | ir::Op::BitAnd
+            | ir::Op::BitOr
+            | ir::Op::BitXor
+            | ir::Op::BitXnor => operand_width(x).max(operand_width(y)),
+            ir::Op::ArithShiftL
+            | ir::Op::ArithShiftR
+            | ir::Op::LogicShiftL
+            | ir::Op::LogicShiftR
+            | ir::Op::Pow => operand_width(x),
+            ir::Op::As => comptime.r#type.total_width(),
+            _ => Some(1),
+        },
+        ir::Expression::Ternary(_, x, y, _) => operand_width(x).max(operand_width(y)),
+        _ => comptime.r#type.total_width(),
+    }
+}
+
 pub fn eval_assign_statement(
     context: &mut Context,
     dst: &mut ir::AssignDestination,
@@ -666,6 +745,7 @@ pub fn eval_assign_statement(
         }
     } else {
         check_reset_non_elaborative(context, expr);
+        check_implicit_truncation(context, dst, expr, width, &token);
 
         let statement = ir::Statement::Assign(ir::AssignStatement {
             dst: vec![dst.clone()],
@@ -1316,7 +1396,9 @@ pub fn eval_type(
                     };
                     ir::TypeKind::Enum(Arc::new(ir::TypeKindEnum {
                         id: symbol.found.id,
+                        name: symbol.found.token.text,
                         r#type: Box::new(r#type),
+                        variants: enum_variants(x),
                     }))
                 }
                 SymbolKind::Modport(_) => {
@@ -1574,6 +1656,24 @@ pub fn eval_type(
     Ok(r#type)
 }
 
+/// The variants of `r#enum`, in declaration order.
+fn enum_variants(r#enum: &symbol::EnumProperty) -> Vec<ir::TypeKindEnumVariant> {
+    r#enum
+        .members
+        .iter()
+        .filter_map(|x| {
+            let symbol = symbol_table::get(*x)?;
+            let SymbolKind::EnumMember(member) = symbol.kind else {
+                return None;
+            };
+            Some(ir::TypeKindEnumVariant {
+                name: symbol.token.text,
+                value: member.value.value().cloned(),
+            })
+        })
+        .collect()
+}
+
 fn check_struct_union_members(
     context: &mut Context,
     members: &[ir::TypeKindMember],
@@ -1994,6 +2094,9 @@ pub fn eval_function_call(
             SymbolKind::SystemFunction(_) => {
                 let name = symbol.found.token.text;
                 let args = args.to_system_function_args(context, &symbol.found);
+                if is_sampled_value_call(context, name) {
+                    return sampled_value_call(context, name, args, token);
+                }
                 let ret = ir::SystemFunctionCall::new(context, name, args, token)?;
                 Ok(ir::Expression::Term(Box::new(
                     ir::Factor::SystemFunctionCall(ret),
@@ -2928,7 +3031,9 @@ pub fn eval_factor_symbol(
                 let enum_type = factor.comptime().r#type.clone();
                 let type_kind = ir::TypeKind::Enum(Arc::new(ir::TypeKindEnum {
                     id: enum_symbol.id,
+                    name: enum_symbol.token.text,
                     r#type: Box::new(enum_type),
+                    variants: enum_variants(&r#enum),
                 }));
 
                 let factor_comptime = factor.comptime_mut();
@@ -3283,6 +3388,12 @@ pub fn argument_list(context: &mut Context, value: &ArgumentList) -> IrResult<Ar
     let mut seen_named = HashSet::default();
     let x: Vec<_> = value.into();
     for arg in x {
+        if arg.argument_expression.argument_expression_opt.is_some() {
+            context.insert_error(AnalyzerError::invalid_temporal(
+                InvalidTemporalKind::Property,
+                &arg.argument_expression.as_ref().into(),
+            ));
+        }
         if let Some(x) = &arg.argument_item_opt {
             if let Some(name) = arg.argument_expression.expression.unwrap_identifier() {
                 let name_token = name.identifier().token;
@@ -4604,6 +4715,7 @@ pub fn tb_method_call(
         ret_strict: false,
         ret_width,
         ret_signed,
+        token,
     })))
 }
 
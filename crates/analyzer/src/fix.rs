@@ -0,0 +1,338 @@
+//! Mechanical fixes suggested by diagnostics. `veryl fix` applies them in
+//! bulk and the language server offers them as quick fixes.
+
+use crate::HashSet;
+use crate::symbol::{Symbol, SymbolKind};
+use crate::symbol_table;
+use std::path::PathBuf;
+use veryl_parser::resource_table;
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_token::{Token, TokenSource};
+
+/// Replaces `len` bytes at byte `offset` of `path` with `text`; `len == 0`
+/// inserts. The range is also given as 1-based line/column (in characters)
+/// for editors.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+    pub path: PathBuf,
+    pub offset: usize,
+    pub len: usize,
+    pub beg_line: u32,
+    pub beg_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    pub text: String,
+}
+
+impl TextEdit {
+    /// Replaces `token` with `text`.
+    pub fn replace(token: &Token, text: &str) -> Option<Self> {
+        let path = token_path(token)?;
+        let chars = token.to_string().chars().count() as u32;
+        Some(Self {
+            path,
+            offset: token.pos as usize,
+            len: token.length as usize,
+            beg_line: token.line,
+            beg_column: token.column,
+            end_line: token.line,
+            end_column: token.column + chars,
+            text: text.to_string(),
+        })
+    }
+
+    /// Inserts `text` at byte `offset` of the file holding `token`.
+    pub fn insert(token: &Token, offset: usize, text: &str) -> Option<Self> {
+        let path = token_path(token)?;
+        let (line, column) = line_column(&token.source.get_text(), offset)?;
+        Some(Self {
+            path,
+            offset,
+            len: 0,
+            beg_line: line,
+            beg_column: column,
+            end_line: line,
+            end_column: column,
+            text: text.to_string(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum FixAction {
+    Edits(Vec<TextEdit>),
+    /// Renames the identifier at `token` along with its declaration and every
+    /// reference. They are collected when the fix is applied, since pass1
+    /// diagnostics are reported before references are recorded.
+    Rename {
+        token: Token,
+        name: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fix {
+    pub title: String,
+    action: FixAction,
+}
+
+impl Fix {
+    pub fn new(title: &str, edits: Vec<TextEdit>) -> Self {
+        Self {
+            title: title.to_string(),
+            action: FixAction::Edits(edits),
+        }
+    }
+
+    pub fn rename(token: &Token, name: &str) -> Self {
+        Self {
+            title: format!("rename \"{token}\" to \"{name}\""),
+            action: FixAction::Rename {
+                token: *token,
+                name: name.to_string(),
+            },
+        }
+    }
+
+    pub fn edits(&self) -> Vec<TextEdit> {
+        match &self.action {
+            FixAction::Edits(x) => x.clone(),
+            FixAction::Rename { token, name } => rename_edits(token, name),
+        }
+    }
+
+    /// Identifier this fix renames, if it is a rename.
+    fn renamed(&self) -> Option<String> {
+        match &self.action {
+            FixAction::Edits(_) => None,
+            FixAction::Rename { token, .. } => Some(token.to_string()),
+        }
+    }
+
+    /// Whether the text this fix writes spells the identifier `name`.
+    fn spells(&self, name: &str) -> bool {
+        match &self.action {
+            FixAction::Edits(x) => x.iter().any(|x| spells(&x.text, name)),
+            FixAction::Rename { .. } => false,
+        }
+    }
+}
+
+/// Whether `text` holds `ident` as a whole identifier.
+fn spells(text: &str, ident: &str) -> bool {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '$';
+    text.match_indices(ident).any(|(i, _)| {
+        !text[..i].chars().next_back().is_some_and(is_ident)
+            && !text[i + ident.len()..].chars().next().is_some_and(is_ident)
+    })
+}
+
+fn rename_edits(token: &Token, name: &str) -> Vec<TextEdit> {
+    if let Ok(symbol) = symbol_table::resolve(token) {
+        rename(&symbol.found, name)
+    } else {
+        TextEdit::replace(token, name).into_iter().collect()
+    }
+}
+
+/// Edits renaming `symbol` to `name`: its declaration, every reference, and
+/// for a port or parameter the names connecting to it in instantiations.
+/// An implicit connection (`inst u: M (a)`) is spelled out as `name: a` when
+/// its port is renamed and as `a: name` when its variable is.
+pub fn rename(symbol: &Symbol, name: &str) -> Vec<TextEdit> {
+    let text = symbol.token.text;
+    let mut tokens = vec![symbol.token];
+    tokens.extend(symbol_table::get_references(symbol.id).unwrap_or_default());
+
+    let owner = match symbol.kind {
+        SymbolKind::Port(_) | SymbolKind::Parameter(_) => {
+            symbol_table::get_namespace_symbol(&symbol.namespace)
+        }
+        _ => None,
+    };
+    let mut connects = Vec::new();
+    let mut implicits = HashSet::default();
+    for x in symbol_table::get_all() {
+        let SymbolKind::Instance(ref inst) = x.kind else {
+            continue;
+        };
+        let is_target = owner.as_ref().is_some_and(|owner| {
+            symbol_table::resolve((&inst.type_name.generic_path(), &x.namespace))
+                .is_ok_and(|x| x.found.id == owner.id)
+        });
+        for (port, target) in inst.port_connects.iter().chain(&inst.parameter_connects) {
+            let expression: TokenRange = (&target.expression).into();
+            let implicit = expression.beg.pos == port.pos && expression.beg.source == port.source;
+            if implicit {
+                implicits.insert((port.source, port.pos));
+            }
+            if is_target && port.text == text {
+                connects.push((*port, implicit));
+            }
+        }
+    }
+
+    let old = symbol.token.to_string();
+    let mut visited = HashSet::default();
+    let mut ret = Vec::new();
+    for x in &tokens {
+        // Each path segment is recorded separately; skip tokens that only
+        // stand for the symbol (e.g. generated modport members).
+        if x.text != text
+            || !matches!(x.source, TokenSource::File { .. })
+            || !visited.insert((x.source, x.pos))
+        {
+            continue;
+        }
+        let edit = if implicits.contains(&(x.source, x.pos)) {
+            TextEdit::replace(x, &format!("{old}: {name}"))
+        } else {
+            TextEdit::replace(x, name)
+        };
+        ret.extend(edit);
+    }
+    for (x, implicit) in connects {
+        if !visited.insert((x.source, x.pos)) {
+            continue;
+        }
+        let edit = if implicit {
+            TextEdit::replace(&x, &format!("{name}: {old}"))
+        } else {
+            TextEdit::replace(&x, name)
+        };
+        ret.extend(edit);
+    }
+    ret
+}
+
+fn token_path(token: &Token) -> Option<PathBuf> {
+    token
+        .source
+        .get_path()
+        .and_then(resource_table::get_path_value)
+}
+
+/// 1-based line and column (in characters) of byte `offset`.
+fn line_column(text: &str, offset: usize) -> Option<(u32, u32)> {
+    let before = text.get(..offset)?;
+    let line_start = before.rfind('\n').map(|x| x + 1).unwrap_or(0);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    Some((line as u32, column as u32))
+}
+
+/// Byte offset of the start of the line holding byte `offset`, and that
+/// line's indentation.
+pub fn line_start(text: &str, offset: usize) -> (usize, &str) {
+    let start = text
+        .get(..offset)
+        .and_then(|x| x.rfind('\n'))
+        .map(|x| x + 1)
+        .unwrap_or(0);
+    let line = &text[start..];
+    let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+    (start, indent)
+}
+
+/// Whether `edit` collides with one of `taken`: overlapping replacements, or
+/// an insertion strictly inside a replaced range. Identical edits don't.
+pub fn conflicts<'a>(taken: impl IntoIterator<Item = &'a TextEdit>, edit: &TextEdit) -> bool {
+    taken.into_iter().any(|x| {
+        if x == edit || x.path != edit.path {
+            false
+        } else if edit.len == 0 || x.len == 0 {
+            (x.offset < edit.offset && edit.offset < x.offset + x.len)
+                || (edit.offset < x.offset && x.offset < edit.offset + edit.len)
+        } else {
+            edit.offset < x.offset + x.len && x.offset < edit.offset + edit.len
+        }
+    })
+}
+
+/// Whether `fix` and one of `taken` touch the same symbol: one renames an
+/// identifier the other writes (e.g. an inserted `a = 0;` and the rename of
+/// `a`), which would leave the written one naming the old identifier.
+pub fn conflicts_by_name<'a>(taken: impl IntoIterator<Item = &'a Fix>, fix: &Fix) -> bool {
+    let clash = |x: &Fix, y: &Fix| x.renamed().is_some_and(|name| y.spells(&name));
+    taken.into_iter().any(|x| clash(x, fix) || clash(fix, x))
+}
+
+/// Applies `edits` (all for one file) to `text`. Identical edits apply once;
+/// an edit conflicting with one already taken is skipped, to be retried on a
+/// fresh analysis. Returns the new text and the number of edits applied.
+pub fn apply(text: &str, edits: &[TextEdit]) -> (String, usize) {
+    let mut taken: Vec<&TextEdit> = Vec::new();
+    for edit in edits {
+        if !taken.contains(&edit)
+            && text.get(..edit.offset + edit.len).is_some()
+            && !conflicts(taken.iter().copied(), edit)
+        {
+            taken.push(edit);
+        }
+    }
+
+    // Stable, so insertions at one offset keep their order.
+    taken.sort_by_key(|x| (x.offset, x.len));
+    let mut ret = String::with_capacity(text.len());
+    let mut pos = 0;
+    for edit in &taken {
+        ret.push_str(&text[pos..edit.offset]);
+        ret.push_str(&edit.text);
+        pos = edit.offset + edit.len;
+    }
+    ret.push_str(&text[pos..]);
+    (ret, taken.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(offset: usize, len: usize, text: &str) -> TextEdit {
+        TextEdit {
+            path: PathBuf::from("a.veryl"),
+            offset,
+            len,
+            beg_line: 1,
+            beg_column: offset as u32 + 1,
+            end_line: 1,
+            end_column: (offset + len) as u32 + 1,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn apply_edits() {
+        let text = "let a: logic = b;";
+        let edits = [
+            edit(4, 1, "_a"),
+            edit(4, 1, "_a"),
+            edit(15, 0, "c & "),
+            edit(15, 0, "d & "),
+            edit(15, 1, "e"),
+        ];
+        let (ret, applied) = apply(text, &edits);
+        assert_eq!(ret, "let _a: logic = c & d & e;");
+        assert_eq!(applied, 4);
+
+        // A second replacement of the same range waits for the next round.
+        let (ret, applied) = apply(text, &[edit(4, 1, "x"), edit(4, 1, "y")]);
+        assert_eq!(ret, "let x: logic = b;");
+        assert_eq!(applied, 1);
+    }
+
+    #[test]
+    fn spells_identifier() {
+        assert!(spells("myCount = 0;", "myCount"));
+        assert!(!spells("myCount2 = 0;", "myCount"));
+        assert!(!spells("a_myCount = 0;", "myCount"));
+    }
+
+    #[test]
+    fn line_start_indent() {
+        let text = "a {\n    b\n  }\n";
+        assert_eq!(line_start(text, 8), (4, "    "));
+        assert_eq!(line_start(text, 12), (10, "  "));
+        assert_eq!(line_column(text, 12), Some((3, 3)));
+    }
+}
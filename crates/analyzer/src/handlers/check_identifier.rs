@@ -1,4 +1,5 @@
 use crate::analyzer_error::AnalyzerError;
+use crate::fix::Fix;
 use crate::symbol::Direction as SymDirection;
 use crate::symbol::{Affiliation, SymbolKind};
 use crate::symbol_table;
@@ -237,6 +238,7 @@ impl CheckIdentifier {
                 &identifier,
                 &format!("prefix: {prefix}"),
                 &token.into(),
+                Some(Fix::rename(token, &format!("{prefix}{identifier}"))),
             ));
         }
         if let Some(suffix) = suffix
@@ -246,6 +248,7 @@ impl CheckIdentifier {
                 &identifier,
                 &format!("suffix: {suffix}"),
                 &token.into(),
+                Some(Fix::rename(token, &format!("{identifier}{suffix}"))),
             ));
         }
         if let Some(case) = case {
@@ -256,10 +259,13 @@ impl CheckIdentifier {
                 Case::LowerCamel => is_lower_camel_case(&identifier),
             };
             if !pass {
+                let recased = to_case(&identifier, case);
+                let fix = (recased != identifier).then(|| Fix::rename(token, &recased));
                 self.errors.push(AnalyzerError::invalid_identifier(
                     &identifier,
                     &format!("case: {case}"),
                     &token.into(),
+                    fix,
                 ));
             }
         }
@@ -274,6 +280,7 @@ impl CheckIdentifier {
                     &identifier,
                     &format!("re_required: {re_required}"),
                     &token.into(),
+                    None,
                 ));
             }
         }
@@ -288,6 +295,7 @@ impl CheckIdentifier {
                     &identifier,
                     &format!("re_forbidden: {re_forbidden}"),
                     &token.into(),
+                    None,
                 ));
             }
         }
@@ -531,6 +539,70 @@ fn is_screaming_snake_case(text: &str) -> bool {
     text.to_ascii_uppercase() == text
 }
 
+/// `text` recased to `case`, splitting words at `_` and at case changes
+/// (`HTTPServer` is `HTTP` and `Server`). Leading underscores are kept.
+fn to_case(text: &str, case: &Case) -> String {
+    let body = text.trim_start_matches('_');
+    let leading = &text[..text.len() - body.len()];
+
+    let chars: Vec<char> = body.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if *c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if c.is_ascii_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|x| x.is_ascii_lowercase());
+            if !prev.is_ascii_uppercase() || next_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(*c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    let capitalize = |x: &String| {
+        let lower = x.to_ascii_lowercase();
+        let mut chars = lower.chars();
+        chars
+            .next()
+            .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+            .unwrap_or_default()
+    };
+    let body = match case {
+        Case::Snake => words
+            .iter()
+            .map(|x| x.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        Case::ScreamingSnake => words
+            .iter()
+            .map(|x| x.to_ascii_uppercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        Case::UpperCamel => words.iter().map(capitalize).collect(),
+        Case::LowerCamel => words
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                if i == 0 {
+                    x.to_ascii_lowercase()
+                } else {
+                    capitalize(x)
+                }
+            })
+            .collect(),
+    };
+    format!("{leading}{body}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -582,4 +654,14 @@ mod tests {
         assert!(!is_screaming_snake_case("a_B_c"));
         assert!(is_screaming_snake_case("A_B_C"));
     }
+
+    #[test]
+    fn recase() {
+        assert_eq!(to_case("my_module", &Case::UpperCamel), "MyModule");
+        assert_eq!(to_case("HTTPServer", &Case::Snake), "http_server");
+        assert_eq!(to_case("fooBar2", &Case::ScreamingSnake), "FOO_BAR2");
+        assert_eq!(to_case("Foo_bar", &Case::LowerCamel), "fooBar");
+        assert_eq!(to_case("_tmpVal", &Case::Snake), "_tmp_val");
+        assert_eq!(to_case("ABC", &Case::Snake), "abc");
+    }
 }
@@ -0,0 +1,167 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::lint;
+use crate::value::Value;
+use veryl_metadata::LintRule;
+use veryl_parser::ParolError;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+
+#[derive(Default)]
+pub struct CheckLint {
+    pub errors: Vec<AnalyzerError>,
+    point: HandlerPoint,
+    is_dependency: bool,
+    in_always_ff: bool,
+    /// Nesting of selects, widths, array sizes and ranges, where numbers are
+    /// indices and sizes rather than magic numbers.
+    in_index: usize,
+    if_depth: usize,
+}
+
+impl CheckLint {
+    pub fn new(is_dependency: bool) -> Self {
+        Self {
+            is_dependency,
+            ..Default::default()
+        }
+    }
+
+    fn report(&mut self, rule: LintRule, token: &Token, error: AnalyzerError) {
+        if self.is_dependency {
+            return;
+        }
+        if let Some(error) = lint::report(rule, token, error) {
+            self.errors.push(error);
+        }
+    }
+
+    fn check_magic_number(&mut self, token: &Token, value: &Value) {
+        if self.in_always_ff
+            && self.in_index == 0
+            && !value.is_xz()
+            && value.to_usize().is_none_or(|x| x > 1)
+        {
+            self.report(
+                LintRule::MagicNumber,
+                token,
+                AnalyzerError::magic_number(&token.to_string(), &token.into()),
+            );
+        }
+    }
+
+    fn enter_index(&mut self) {
+        match self.point {
+            HandlerPoint::Before => self.in_index += 1,
+            HandlerPoint::After => self.in_index -= 1,
+        }
+    }
+}
+
+impl Handler for CheckLint {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckLint {
+    fn based(&mut self, arg: &Based) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_magic_number(&arg.based_token.token, &arg.into());
+        }
+        Ok(())
+    }
+
+    fn base_less(&mut self, arg: &BaseLess) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.check_magic_number(&arg.base_less_token.token, &arg.into());
+        }
+        Ok(())
+    }
+
+    fn select(&mut self, _arg: &Select) -> Result<(), ParolError> {
+        self.enter_index();
+        Ok(())
+    }
+
+    fn width(&mut self, _arg: &Width) -> Result<(), ParolError> {
+        self.enter_index();
+        Ok(())
+    }
+
+    fn array(&mut self, _arg: &Array) -> Result<(), ParolError> {
+        self.enter_index();
+        Ok(())
+    }
+
+    fn range(&mut self, _arg: &Range) -> Result<(), ParolError> {
+        self.enter_index();
+        Ok(())
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                self.if_depth += 1;
+                if self.if_depth > LintRule::NESTED_IF_LIMIT {
+                    let token = &arg.r#if.if_token.token;
+                    self.report(
+                        LintRule::NestedIf,
+                        token,
+                        AnalyzerError::nested_if(
+                            self.if_depth,
+                            LintRule::NESTED_IF_LIMIT,
+                            &token.into(),
+                        ),
+                    );
+                }
+            }
+            HandlerPoint::After => self.if_depth -= 1,
+        }
+        Ok(())
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let has_default = arg
+                .case_statement_list
+                .iter()
+                .any(|x| matches!(*x.case_item.case_item_group, CaseItemGroup::Defaul(_)));
+            if !has_default {
+                let token = &arg.case.case_token.token;
+                self.report(
+                    LintRule::MissingDefault,
+                    token,
+                    AnalyzerError::missing_default("case", &token.into()),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn switch_statement(&mut self, arg: &SwitchStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let has_default = arg
+                .switch_statement_list
+                .iter()
+                .any(|x| matches!(*x.switch_item.switch_item_group, SwitchItemGroup::Defaul(_)));
+            if !has_default {
+                let token = &arg.switch.switch_token.token;
+                self.report(
+                    LintRule::MissingDefault,
+                    token,
+                    AnalyzerError::missing_default("switch", &token.into()),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn always_ff_declaration(&mut self, _arg: &AlwaysFfDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_always_ff = true,
+            HandlerPoint::After => self.in_always_ff = false,
+        }
+        Ok(())
+    }
+}
@@ -21,7 +21,7 @@ mod write_count;
 pub use assign_table::ReferencedEntry;
 pub use comptime::{
     Comptime, ExpressionContext, InstanceKind, PartSelectPath, Type, TypeKind, TypeKindEnum,
-    TypeKindMember, TypeKindStruct, TypeKindUnion, ValueVariant,
+    TypeKindEnumVariant, TypeKindMember, TypeKindStruct, TypeKindUnion, ValueVariant,
 };
 pub use declaration::{
     CombDeclaration, Declaration, DeclarationBlock, ExternalConnect, ExternalDeclaration,
@@ -39,8 +39,8 @@ pub use shape::{Shape, ShapeRef};
 pub use signature::Signature;
 pub use statement::{
     AssignDestination, AssignStatement, CaseArm, CasePattern, CaseStatement, ControlFlow, ForBound,
-    ForRange, ForStatement, IfResetStatement, IfStatement, Statement, StatementBlock, TbMethod,
-    TbMethodCall,
+    ForRange, ForStatement, ForkStatement, IfResetStatement, IfStatement, Statement,
+    StatementBlock, TbMethod, TbMethodCall,
 };
 pub use system_function::{
     AssertKind, Input as SystemFunctionInput, SystemFunctionCall, SystemFunctionKind,
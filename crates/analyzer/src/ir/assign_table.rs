@@ -1,9 +1,12 @@
 use crate::HashSet;
 use crate::conv::Context;
+use crate::fix::{self, Fix, TextEdit};
 use crate::ir::{Shape, ShapeRef, VarId, VarPath, Variable, VariableInfo};
+use crate::lint;
 use crate::symbol::Affiliation;
 use crate::{AnalyzerError, BigUint, HashMap};
 use std::borrow::Cow;
+use veryl_metadata::LintRule;
 use veryl_parser::token_range::TokenRange;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -464,12 +467,18 @@ impl AssignTable {
                         } else {
                             (&src_val.mask[i], &tgt_val.mask[i])
                         };
-                        if src ^ tgt != 0u32.into() {
-                            context.insert_error(AnalyzerError::uncovered_branch(
-                                &src_val.path.to_string(),
-                                &tokens[0],
-                                &tokens,
-                            ));
+                        if src ^ tgt != 0u32.into()
+                            && let Some(error) = lint::report(
+                                LintRule::LatchInference,
+                                &tokens[0].beg,
+                                AnalyzerError::uncovered_branch(
+                                    &src_val.path.to_string(),
+                                    &tokens[0],
+                                    &tokens,
+                                ),
+                            )
+                        {
+                            context.insert_error(error);
                         }
                     }
                 }
@@ -563,18 +572,29 @@ impl AssignTable {
 
             for i in 0..array {
                 let target = &union_mask[i];
-                if per_branch.iter().any(|m| m[i] != *target) {
-                    context.insert_error(AnalyzerError::uncovered_branch(
-                        &sample.path.to_string(),
-                        &tokens[0],
-                        &tokens,
-                    ));
+                if per_branch.iter().any(|m| m[i] != *target)
+                    && let Some(error) = lint::report(
+                        LintRule::LatchInference,
+                        &tokens[0].beg,
+                        AnalyzerError::uncovered_branch(
+                            &sample.path.to_string(),
+                            &tokens[0],
+                            &tokens,
+                        ),
+                    )
+                {
+                    context.insert_error(error);
                 }
             }
         }
     }
 
-    pub fn check_missing_reset(&self, context: &mut Context, false_side: &AssignTable) {
+    pub fn check_missing_reset(
+        &self,
+        context: &mut Context,
+        false_side: &AssignTable,
+        reset_block: &TokenRange,
+    ) {
         for (key, tgt_val) in &false_side.table {
             // skip variables defined in always_ff
             if tgt_val.affiliation == Affiliation::AlwaysFf {
@@ -594,10 +614,12 @@ impl AssignTable {
                             let mut tokens = src_val.tokens.clone();
                             tokens.sort();
                             tokens.dedup();
+                            let name = src_val.path.to_string();
                             context.insert_error(AnalyzerError::missing_reset_statement(
-                                &src_val.path.to_string(),
+                                &name,
                                 &tokens[0],
                                 &tokens,
+                                missing_reset_fix(&name, &src_val.array, reset_block),
                             ));
                         }
                     }
@@ -606,16 +628,38 @@ impl AssignTable {
                 let mut tokens = tgt_val.tokens.clone();
                 tokens.sort();
                 tokens.dedup();
+                let name = tgt_val.path.to_string();
                 context.insert_error(AnalyzerError::missing_reset_statement(
-                    &tgt_val.path.to_string(),
+                    &name,
                     &tokens[0],
                     &tokens,
+                    missing_reset_fix(&name, &tgt_val.array, reset_block),
                 ));
             }
         }
     }
 }
 
+/// Resets `name` to `0` at the end of `reset_block`; an unpacked array
+/// takes `'{default: 0}` instead.
+fn missing_reset_fix(name: &str, array: &Shape, reset_block: &TokenRange) -> Option<Fix> {
+    let value = if array.is_empty() {
+        "0"
+    } else {
+        "'{default: 0}"
+    };
+    let r_brace = &reset_block.end;
+    let (offset, text) = if reset_block.beg.line != r_brace.line {
+        let source = r_brace.source.get_text();
+        let (offset, indent) = fix::line_start(&source, r_brace.pos as usize);
+        (offset, format!("{indent}    {name} = {value};\n"))
+    } else {
+        (r_brace.pos as usize, format!("{name} = {value}; "))
+    };
+    let edit = TextEdit::insert(r_brace, offset, &text)?;
+    Some(Fix::new(&format!("reset {name} to {value}"), vec![edit]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
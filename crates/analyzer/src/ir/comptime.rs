@@ -10,6 +10,7 @@ use crate::literal::TypeLiteral;
 use crate::symbol::ClockDomain;
 use crate::symbol::{Direction, SymbolId};
 use crate::value::Value;
+use num_bigint::BigUint;
 use std::fmt;
 use std::sync::{Arc, LazyLock};
 use veryl_parser::resource_table::StrId;
@@ -1250,7 +1251,16 @@ impl fmt::Display for TypeKindMember {
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TypeKindEnum {
     pub id: SymbolId,
+    pub name: StrId,
     pub r#type: Box<Type>,
+    pub variants: Vec<TypeKindEnumVariant>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TypeKindEnumVariant {
+    pub name: StrId,
+    /// `None` when the variant's value can't be evaluated.
+    pub value: Option<BigUint>,
 }
 
 impl TypeKindEnum {
@@ -1,5 +1,4 @@
 use crate::BigUint;
-use crate::HashMap;
 use crate::attribute::{AllowItem, Attribute};
 use crate::attribute_table;
 use crate::conv::Context;
@@ -9,6 +8,7 @@ use crate::ir::{
 };
 use crate::symbol::ClockDomain;
 use crate::value::ValueBigUint;
+use crate::{HashMap, HashSet};
 use indent::indent_all_by;
 use std::fmt;
 use veryl_parser::resource_table::StrId;
@@ -44,6 +44,10 @@ pub struct Module {
     /// that need to mutate (e.g. simulator's `force_all_ff` or post-hoist
     /// rebuild) should clone first.
     pub ff_table: FfTable,
+    /// Hidden history registers that only checkers read (`$past` in an
+    /// `$assert`, the attempts of a temporal property). The simulator and
+    /// `veryl prove` evaluate them; synthesis leaves them out.
+    pub verification_vars: HashSet<VarId>,
 }
 
 impl Module {
@@ -34,6 +34,7 @@ pub enum Statement {
     SystemFunctionCall(Box<SystemFunctionCall>),
     FunctionCall(Box<FunctionCall>),
     TbMethodCall(TbMethodCall),
+    Fork(ForkStatement),
     Break,
     Unsupported(TokenRange),
     Null,
@@ -49,6 +50,14 @@ pub struct ForStatement {
     pub token: TokenRange,
 }
 
+/// `#[fork] block { ... }` in a testbench: every child statement group runs
+/// as a thread of its own, and the statement completes once all of them have.
+#[derive(Clone)]
+pub struct ForkStatement {
+    pub threads: Vec<Vec<Statement>>,
+    pub token: TokenRange,
+}
+
 #[derive(Clone, Debug)]
 pub enum ForBound {
     Const(usize),
@@ -258,6 +267,7 @@ pub struct TbMethodCall {
     /// Signedness of the returned value. Only `$tb::random::<iN>` returns a
     /// signed value; component/file methods leave this `false`.
     pub ret_signed: bool,
+    pub token: TokenRange,
 }
 
 #[derive(Clone)]
@@ -363,6 +373,16 @@ impl Statement {
                 ControlFlow::Continue
             }
             Statement::TbMethodCall(_) => ControlFlow::Continue,
+            Statement::Fork(x) => {
+                for thread in &x.threads {
+                    for s in thread {
+                        if s.eval_value(context) == ControlFlow::Break {
+                            break;
+                        }
+                    }
+                }
+                ControlFlow::Continue
+            }
             Statement::Break => ControlFlow::Break,
             Statement::Unsupported(_) => ControlFlow::Continue,
             Statement::Null => ControlFlow::Continue,
@@ -398,6 +418,11 @@ impl Statement {
                     ret.eval_assign(context, assign_table, assign_context);
                 }
             }
+            Statement::Fork(x) => {
+                for s in x.threads.iter().flatten() {
+                    s.eval_assign(context, assign_table, assign_context, base_tables);
+                }
+            }
             Statement::Break => (),
             Statement::Unsupported(_) => (),
             Statement::Null => (),
@@ -417,6 +442,11 @@ impl Statement {
                     s.gather_ff(context, table, decl);
                 }
             }
+            Statement::Fork(x) => {
+                for s in x.threads.iter().flatten() {
+                    s.gather_ff(context, table, decl);
+                }
+            }
             Statement::TbMethodCall(_)
             | Statement::Break
             | Statement::Unsupported(_)
@@ -456,6 +486,11 @@ impl Statement {
                     s.gather_ff_comb_assign(context, table, decl);
                 }
             }
+            Statement::Fork(x) => {
+                for s in x.threads.iter().flatten() {
+                    s.gather_ff_comb_assign(context, table, decl);
+                }
+            }
             _ => (),
         }
     }
@@ -473,6 +508,11 @@ impl Statement {
                     s.set_index(index);
                 }
             }
+            Statement::Fork(x) => {
+                for s in x.threads.iter_mut().flatten() {
+                    s.set_index(index);
+                }
+            }
             Statement::TbMethodCall(_) | Statement::Break => (),
             Statement::Unsupported(_) => (),
             Statement::Null => (),
@@ -576,6 +616,17 @@ impl fmt::Display for Statement {
                 }
                 write!(f, "}}")
             }
+            Statement::Fork(x) => {
+                writeln!(f, "#[fork] block {{")?;
+                for thread in &x.threads {
+                    writeln!(f, "  block {{")?;
+                    for s in thread {
+                        writeln!(f, "    {s}")?;
+                    }
+                    writeln!(f, "  }}")?;
+                }
+                write!(f, "}}")
+            }
             Statement::Break => "break;".fmt(f),
             Statement::Unsupported(_) => "/* unsupported */".fmt(f),
             Statement::Null => "".fmt(f),
@@ -974,6 +1025,8 @@ pub struct IfResetStatement {
     pub true_side: Vec<Statement>,
     pub false_side: Vec<Statement>,
     pub token: TokenRange,
+    /// The reset block, where a missing reset statement is added by its fix.
+    pub true_side_token: TokenRange,
 }
 
 impl IfResetStatement {
@@ -1001,7 +1054,7 @@ impl IfResetStatement {
         }
 
         if !allow_missing_reset_statement(&self.token) {
-            true_table.check_missing_reset(context, &false_table);
+            true_table.check_missing_reset(context, &false_table, &self.true_side_token);
         }
 
         true_table.merge_by_or(context, &mut false_table, false);
@@ -78,7 +78,46 @@ pub enum SystemFunctionKind {
         cond: Input,
         args: Vec<Input>,
     },
+    /// `$cover(cond, "name")`: counts the executions that see `cond` true.
+    Cover {
+        cond: Input,
+        name: Input,
+    },
+    /// `$cover_bins(value, "name"[, bins])`: sorts each sample of `value`
+    /// into one of `bins` equal ranges.
+    CoverBins {
+        value: Input,
+        name: Input,
+        bins: Option<Input>,
+    },
     Finish,
+    /// `$dumpon()`: resumes waveform recording stopped by `$dumpoff()`.
+    DumpOn,
+    /// `$dumpoff()`: stops waveform recording until `$dumpon()`.
+    DumpOff,
+    /// `$wait(duration)`: advances the testbench by `duration` time units
+    /// while every `$tb::clock_gen` of `clocks` (with its `period`, if
+    /// given) keeps running.
+    Wait {
+        duration: Input,
+        clocks: Vec<(StrId, Option<Expression>)>,
+    },
+    /// `$wait_until(condition)`: suspends the testbench thread until
+    /// `condition` holds, re-checking it after every edge of `clocks`.
+    WaitUntil {
+        condition: Input,
+        clocks: Vec<(StrId, Option<Expression>)>,
+    },
+    /// `$force(target, value)`: holds `target` at `value` against its own
+    /// drivers until `$release(target)`.
+    Force {
+        target: Input,
+        value: Input,
+    },
+    /// `$release(target)`: ends a `$force` of `target`.
+    Release {
+        target: Input,
+    },
     Signed(Input),
     Unsigned(Input),
 }
@@ -133,6 +172,26 @@ fn create_output(
     Output(dst)
 }
 
+/// The name of a cover point, which has to be a string literal.
+fn create_cover_name(
+    context: &mut Context,
+    name: StrId,
+    arg: (Expression, Vec<VarPathSelect>, TokenRange),
+) -> Input {
+    let (mut expr, _, token) = arg;
+
+    let comptime = expr.eval_comptime(context, None);
+    if comptime.r#type.kind != TypeKind::String {
+        context.insert_error(AnalyzerError::mismatch_function_arg(
+            &name.to_string(),
+            &comptime.r#type.to_string(),
+            &token,
+        ));
+    }
+
+    Input(expr)
+}
+
 impl SystemFunctionCall {
     pub fn new(
         context: &mut Context,
@@ -298,6 +357,60 @@ impl SystemFunctionCall {
                     comptime,
                 })
             }
+            "$cover" => {
+                if args.len() != 2 {
+                    context.insert_error(AnalyzerError::mismatch_function_arity(
+                        "$cover",
+                        2,
+                        args.len(),
+                        &token,
+                    ));
+                    return Err(ir_error!(token));
+                }
+                let cond = create_input(context, name, None, args.remove(0));
+                let cover_name = create_cover_name(context, name, args.remove(0));
+                Ok(SystemFunctionCall {
+                    kind: SystemFunctionKind::Cover {
+                        cond,
+                        name: cover_name,
+                    },
+                    comptime,
+                })
+            }
+            "$cover_bins" => {
+                if !(2..=3).contains(&args.len()) {
+                    context.insert_error(AnalyzerError::mismatch_function_arity(
+                        "$cover_bins",
+                        args.len().clamp(2, 3),
+                        args.len(),
+                        &token,
+                    ));
+                    return Err(ir_error!(token));
+                }
+                let value = create_input(context, name, None, args.remove(0));
+                let cover_name = create_cover_name(context, name, args.remove(0));
+                let bins = if args.is_empty() {
+                    None
+                } else {
+                    let bins = create_input(context, name, None, args.remove(0));
+                    if !bins.0.comptime().is_const {
+                        context.insert_error(AnalyzerError::unevaluable_value(
+                            UnevaluableValueKind::SystemFunctionArg("$cover_bins"),
+                            &bins.0.token_range(),
+                        ));
+                        return Err(ir_error!(token));
+                    }
+                    Some(bins)
+                };
+                Ok(SystemFunctionCall {
+                    kind: SystemFunctionKind::CoverBins {
+                        value,
+                        name: cover_name,
+                        bins,
+                    },
+                    comptime,
+                })
+            }
             "$finish" => {
                 if !args.is_empty() {
                     return Err(ir_error!(token));
@@ -308,6 +421,101 @@ impl SystemFunctionCall {
                     comptime,
                 })
             }
+            "$dumpon" | "$dumpoff" => {
+                let name_str = name.to_string();
+                // Elsewhere these are the SystemVerilog tasks of the same
+                // name, left to the simulator the code is emitted for.
+                if !context.in_tb_block {
+                    return Err(ir_error!(token));
+                }
+                if !args.is_empty() {
+                    context.insert_error(AnalyzerError::mismatch_function_arity(
+                        &name_str,
+                        0,
+                        args.len(),
+                        &token,
+                    ));
+                    return Err(ir_error!(token));
+                }
+                let kind = if name_str == "$dumpon" {
+                    SystemFunctionKind::DumpOn
+                } else {
+                    SystemFunctionKind::DumpOff
+                };
+                Ok(SystemFunctionCall { kind, comptime })
+            }
+            "$wait" => {
+                if !context.in_tb_block {
+                    context.insert_error(AnalyzerError::invalid_statement("$wait", &token));
+                }
+                if args.len() != 1 {
+                    context.insert_error(AnalyzerError::mismatch_function_arity(
+                        "$wait",
+                        1,
+                        args.len(),
+                        &token,
+                    ));
+                    return Err(ir_error!(token));
+                }
+                let duration = create_input(context, name, None, args.remove(0));
+                let clocks = context
+                    .tb_clocks
+                    .iter()
+                    .map(|x| (*x, context.tb_clock_period.get(x).cloned()))
+                    .collect();
+                Ok(SystemFunctionCall {
+                    kind: SystemFunctionKind::Wait { duration, clocks },
+                    comptime,
+                })
+            }
+            "$wait_until" => {
+                if !context.in_tb_block {
+                    context.insert_error(AnalyzerError::invalid_statement("$wait_until", &token));
+                }
+                if args.len() != 1 {
+                    context.insert_error(AnalyzerError::mismatch_function_arity(
+                        "$wait_until",
+                        1,
+                        args.len(),
+                        &token,
+                    ));
+                    return Err(ir_error!(token));
+                }
+                let condition = create_input(context, name, None, args.remove(0));
+                let clocks = context
+                    .tb_clocks
+                    .iter()
+                    .map(|x| (*x, context.tb_clock_period.get(x).cloned()))
+                    .collect();
+                Ok(SystemFunctionCall {
+                    kind: SystemFunctionKind::WaitUntil { condition, clocks },
+                    comptime,
+                })
+            }
+            "$force" | "$release" => {
+                let name_str = name.to_string();
+                let arity = if name_str == "$force" { 2 } else { 1 };
+                if !context.in_tb_block {
+                    context.insert_error(AnalyzerError::invalid_statement(&name_str, &token));
+                }
+                if args.len() != arity {
+                    context.insert_error(AnalyzerError::mismatch_function_arity(
+                        &name_str,
+                        arity,
+                        args.len(),
+                        &token,
+                    ));
+                    return Err(ir_error!(token));
+                }
+                let target = create_input(context, name, None, args.remove(0));
+                let kind = if arity == 2 {
+                    let value = create_input(context, name, None, args.remove(0));
+                    SystemFunctionKind::Force { target, value }
+                } else {
+                    SystemFunctionKind::Release { target }
+                };
+                Ok(SystemFunctionCall { kind, comptime })
+            }
             "$signed" => {
                 if args.len() != 1 {
                     return Err(ir_error!(token));
@@ -404,7 +612,12 @@ impl SystemFunctionCall {
             SystemFunctionKind::Display(_) => None,
             SystemFunctionKind::Write(_) => None,
             SystemFunctionKind::Assert { .. } => None,
+            SystemFunctionKind::Cover { .. } => None,
+            SystemFunctionKind::CoverBins { .. } => None,
             SystemFunctionKind::Finish => None,
+            SystemFunctionKind::DumpOn | SystemFunctionKind::DumpOff => None,
+            SystemFunctionKind::Wait { .. } | SystemFunctionKind::WaitUntil { .. } => None,
+            SystemFunctionKind::Force { .. } | SystemFunctionKind::Release { .. } => None,
             SystemFunctionKind::Signed(x) | SystemFunctionKind::Unsigned(x) => {
                 x.0.eval_value(context)
             }
@@ -428,7 +641,14 @@ impl SystemFunctionCall {
             SystemFunctionKind::Display(_) => self.comptime.clone(),
             SystemFunctionKind::Write(_) => self.comptime.clone(),
             SystemFunctionKind::Assert { .. } => self.comptime.clone(),
+            SystemFunctionKind::Cover { .. } => self.comptime.clone(),
+            SystemFunctionKind::CoverBins { .. } => self.comptime.clone(),
             SystemFunctionKind::Finish => self.comptime.clone(),
+            SystemFunctionKind::DumpOn | SystemFunctionKind::DumpOff => self.comptime.clone(),
+            SystemFunctionKind::Wait { .. } => self.comptime.clone(),
+            SystemFunctionKind::WaitUntil { .. } => self.comptime.clone(),
+            SystemFunctionKind::Force { .. } => self.comptime.clone(),
+            SystemFunctionKind::Release { .. } => self.comptime.clone(),
             SystemFunctionKind::Signed(_) | SystemFunctionKind::Unsigned(_) => {
                 let mut ret = self.comptime.clone();
                 if let Some(x) = value {
@@ -478,7 +698,22 @@ impl fmt::Display for SystemFunctionCall {
                     format!("{name}({cond}, {})", args_str.join(", ")).fmt(f)
                 }
             }
+            SystemFunctionKind::Cover { cond, name } => format!("$cover({cond}, {name})").fmt(f),
+            SystemFunctionKind::CoverBins { value, name, bins } => match bins {
+                Some(bins) => format!("$cover_bins({value}, {name}, {bins})").fmt(f),
+                None => format!("$cover_bins({value}, {name})").fmt(f),
+            },
             SystemFunctionKind::Finish => "$finish()".fmt(f),
+            SystemFunctionKind::DumpOn => "$dumpon()".fmt(f),
+            SystemFunctionKind::DumpOff => "$dumpoff()".fmt(f),
+            SystemFunctionKind::Wait { duration, .. } => format!("$wait({duration})").fmt(f),
+            SystemFunctionKind::WaitUntil { condition, .. } => {
+                format!("$wait_until({condition})").fmt(f)
+            }
+            SystemFunctionKind::Force { target, value } => {
+                format!("$force({target}, {value})").fmt(f)
+            }
+            SystemFunctionKind::Release { target } => format!("$release({target})").fmt(f),
             SystemFunctionKind::Signed(x) => format!("$signed({x})").fmt(f),
             SystemFunctionKind::Unsigned(x) => format!("$unsigned({x})").fmt(f),
         }
@@ -520,7 +755,28 @@ impl SystemFunctionCall {
                     process_input(arg, context, table);
                 }
             }
-            SystemFunctionKind::Finish => {}
+            SystemFunctionKind::Cover { cond, .. } => {
+                process_input(cond, context, table);
+            }
+            SystemFunctionKind::CoverBins { value, .. } => {
+                process_input(value, context, table);
+            }
+            SystemFunctionKind::Wait { duration, .. } => {
+                process_input(duration, context, table);
+            }
+            SystemFunctionKind::WaitUntil { condition, .. } => {
+                process_input(condition, context, table);
+            }
+            SystemFunctionKind::Force { target, value } => {
+                process_input(target, context, table);
+                process_input(value, context, table);
+            }
+            SystemFunctionKind::Release { target } => {
+                process_input(target, context, table);
+            }
+            SystemFunctionKind::Finish
+            | SystemFunctionKind::DumpOn
+            | SystemFunctionKind::DumpOff => {}
         }
     }
 }
@@ -134,6 +134,11 @@ fn walk_one(
                 walk_seq(&x.body, decl, context, written, out);
             }
         }
+        Statement::Fork(x) => {
+            for thread in &x.threads {
+                walk_seq(thread, decl, context, written, out);
+            }
+        }
         Statement::FunctionCall(x) => {
             // The body is opaque here, so an output may read back bits a
             // write has already reached.
@@ -209,6 +214,11 @@ fn collect_writes(
             }
             Statement::Case(x) => collect_writes(&x.lower_to_nested_if(), context, out),
             Statement::For(x) => collect_writes(&x.body, context, out),
+            Statement::Fork(x) => {
+                for thread in &x.threads {
+                    collect_writes(thread, context, out);
+                }
+            }
             Statement::FunctionCall(x) => {
                 for outputs in x.outputs.values() {
                     for dst in outputs {
@@ -7,11 +7,13 @@ pub mod component_manifest_table;
 pub mod connect_operation_table;
 pub mod conv;
 pub mod definition_table;
+pub mod fix;
 pub mod fragment_cache;
 pub mod fragment_codec;
 pub mod generic_inference_table;
 pub mod handlers;
 pub mod ir;
+pub mod lint;
 pub mod literal;
 pub mod literal_table;
 pub mod msb_table;
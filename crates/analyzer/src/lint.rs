@@ -0,0 +1,34 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::attribute::{AllowItem, Attribute};
+use crate::attribute_table;
+use std::cell::RefCell;
+use veryl_metadata::{LintLevel, LintRule, LintRules};
+use veryl_parser::veryl_token::Token;
+
+thread_local!(static LINT_RULES: RefCell<LintRules> = RefCell::new(LintRules::default()));
+
+pub fn set_rules(rules: &LintRules) {
+    LINT_RULES.with(|f| *f.borrow_mut() = rules.clone())
+}
+
+pub fn level(rule: LintRule) -> LintLevel {
+    LINT_RULES.with(|f| f.borrow().level(rule))
+}
+
+pub fn is_enabled(rule: LintRule) -> bool {
+    level(rule) != LintLevel::Allow
+}
+
+/// `error` as `rule` reports it at `token`: dropped when the rule is allowed
+/// by `[lint.rules]` or an enclosing `#[allow(rule)]`, promoted to an error
+/// when it is denied.
+pub fn report(rule: LintRule, token: &Token, error: AnalyzerError) -> Option<AnalyzerError> {
+    if attribute_table::contains(token, Attribute::Allow(AllowItem::Lint(rule))) {
+        return None;
+    }
+    match level(rule) {
+        LintLevel::Allow => None,
+        LintLevel::Warn => Some(error),
+        LintLevel::Deny => Some(AnalyzerError::denied_lint(error)),
+    }
+}
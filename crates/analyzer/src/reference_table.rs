@@ -290,7 +290,7 @@ impl ReferenceTable {
                 Ok(symbol) => {
                     if !impoted_path {
                         self.check_pacakge_reference(&symbol.found, &path.range);
-                        symbol_table::add_reference(symbol.found.id, &path.paths[0].base);
+                        symbol_table::add_reference(symbol.found.id, &path.paths[i].base);
                     }
 
                     // A user-defined component takes its parameters as
@@ -535,6 +535,11 @@ impl ScopeArena {
             .is_some_and(|&id| self.scopes[id as usize].kind == ScopeKind::Project)
     }
 
+    fn is_dependency_project(&self, name: StrId) -> bool {
+        let name = resource_table::canonical_str_id(name);
+        self.root_project.is_some_and(|x| x != name)
+    }
+
     fn root_project_name(&self) -> StrId {
         self.root_project.unwrap()
     }
@@ -787,6 +792,11 @@ pub fn match_project_name(name: StrId) -> bool {
     SCOPE_ARENA.with(|f| f.borrow().match_project_name(name))
 }
 
+/// Whether `name` is a project other than the root one (e.g. `$std`).
+pub fn is_dependency_project(name: StrId) -> bool {
+    SCOPE_ARENA.with(|f| f.borrow().is_dependency_project(name))
+}
+
 pub fn root_project_name() -> StrId {
     SCOPE_ARENA.with(|f| f.borrow().root_project_name())
 }
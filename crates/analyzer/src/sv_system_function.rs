@@ -108,6 +108,12 @@ pub fn sv_system_functions() -> Vec<SvSystemFunction> {
             // it has optional args but not supported
             &[],
         ),
+        SvSystemFunction::new("$cover", &[]),
+        SvSystemFunction::new("$cover_bins", &[]),
+        SvSystemFunction::new("$wait", &[]),
+        SvSystemFunction::new("$wait_until", &[]),
+        SvSystemFunction::new("$force", &[]),
+        SvSystemFunction::new("$release", &[]),
         // Simulation time system functions
         SvSystemFunction::new("$time", &[]),
         SvSystemFunction::new("$stime", &[]),
@@ -6,8 +6,9 @@ mod msb;
 use crate::analyzer_error::DuplicatedIdentifierKind;
 use crate::attribute::IfdefCondition;
 use crate::attribute_table;
+use crate::lint;
 use crate::namespace::{DefineContext, Namespace};
-use crate::scope;
+use crate::scope::{self, ScopeId};
 use crate::sv_system_function;
 use crate::symbol::{
     ConnectTarget, Direction, DocComment, GenericBoundKind, GenericMap, GenericTable,
@@ -28,6 +29,7 @@ use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
+use veryl_metadata::LintRule;
 use veryl_parser::resource_table::{self, PathId, StrId};
 use veryl_parser::token_collector::TokenCollector;
 use veryl_parser::token_range::TokenRange;
@@ -2636,6 +2638,58 @@ impl SymbolTable {
         ret
     }
 
+    fn check_unused_parameter(&self) -> Vec<AnalyzerError> {
+        if !lint::is_enabled(LintRule::UnusedParameter) {
+            return vec![];
+        }
+
+        let root = scope::root_project_name();
+        let mut ret = vec![];
+        for symbol in self.symbol_table.values() {
+            if let SymbolKind::Parameter(x) = &symbol.kind
+                && !x.is_proto
+                && self
+                    .reference_table
+                    .get(&symbol.id)
+                    .is_none_or(|v| v.is_empty())
+                && !symbol.token.to_string().starts_with('_')
+                && scope::project_of(symbol.scope) == Some(root)
+                && self.in_module_or_interface(symbol.scope)
+                && let Some(error) = lint::report(
+                    LintRule::UnusedParameter,
+                    &symbol.token,
+                    AnalyzerError::unused_parameter(
+                        &symbol.token.to_string(),
+                        &symbol.token.into(),
+                    ),
+                )
+            {
+                ret.push(error);
+            }
+        }
+        ret
+    }
+
+    /// Whether `scope` is inside a module or interface, whose parameters are
+    /// only referenced from within.  Package items are referenced from other
+    /// files, so they are never reported as unused.
+    fn in_module_or_interface(&self, scope: ScopeId) -> bool {
+        let mut scope = Some(scope);
+        while let Some(x) = scope {
+            if let Some(owner) = scope::owner_of(x)
+                && let Some(symbol) = self.symbol_table.get(&owner)
+            {
+                match symbol.kind {
+                    SymbolKind::Module(_) | SymbolKind::Interface(_) => return true,
+                    SymbolKind::Package(_) => return false,
+                    _ => (),
+                }
+            }
+            scope = scope::parent(x);
+        }
+        false
+    }
+
     fn get_tests(&self, project_name: &str) -> Vec<(StrId, TestProperty)> {
         self.symbol_table
             .values()
@@ -3563,6 +3617,10 @@ pub fn check_unused_variable() -> Vec<AnalyzerError> {
     SYMBOL_TABLE.with(|f| f.borrow().check_unused_variable())
 }
 
+pub fn check_unused_parameter() -> Vec<AnalyzerError> {
+    SYMBOL_TABLE.with(|f| f.borrow().check_unused_parameter())
+}
+
 pub fn get_tests(project_name: &str) -> Vec<(StrId, TestProperty)> {
     SYMBOL_TABLE.with(|f| f.borrow().get_tests(project_name))
 }
@@ -1,9 +1,10 @@
 use crate::conv::Context;
 use crate::ir::Ir;
-use crate::{Analyzer, AnalyzerError, attribute_table, symbol_table};
+use crate::{Analyzer, AnalyzerError, attribute_table, fix, symbol_table};
+use miette::Diagnostic;
 use std::collections::HashMap;
 use std::thread;
-use veryl_metadata::{Lint, Metadata, ProjectProperty};
+use veryl_metadata::{Case, Lint, LintLevel, LintRule, Metadata, ProjectProperty};
 use veryl_parser::Parser;
 use veryl_parser::doc_comment_table;
 
@@ -2108,6 +2109,65 @@ fn invalid_statement() {
 
     let errors = analyze(code);
     assert!(matches!(errors[0], AnalyzerError::InvalidStatement { .. }));
+
+    let code = r#"
+    module ModuleA (
+        a: input  logic,
+        b: output logic,
+    ) {
+        always_comb {
+            #[fork]
+            block {
+                b = a;
+            }
+        }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(errors[0], AnalyzerError::InvalidStatement { .. }));
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_a  : input logic,
+    ) {
+        var a: logic;
+        always_ff {
+            $force(a, i_a);
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert!(matches!(errors[0], AnalyzerError::InvalidStatement { .. }));
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+    ) {
+        always_ff {
+            $wait(10);
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert!(matches!(errors[0], AnalyzerError::InvalidStatement { .. }));
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_a  : input logic,
+    ) {
+        always_ff {
+            $wait_until(i_a == 1);
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert!(matches!(errors[0], AnalyzerError::InvalidStatement { .. }));
 }
 
 #[test]
@@ -2541,6 +2601,39 @@ fn mismatch_function_arg() {
 
     let errors = analyze(code);
     assert!(errors.is_empty());
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_a  : input logic<4>,
+    ) {
+        always_ff {
+            $cover(i_a == 0, 1);
+        }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchFunctionArg { .. }
+    ));
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_a  : input logic<4>,
+    ) {
+        always_ff {
+            $cover(i_a == 0, "zero");
+            $cover_bins(i_a, "a");
+            $cover_bins(i_a, "a_halves", 2);
+        }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(errors.is_empty());
 }
 
 #[test]
@@ -11718,6 +11811,91 @@ fn invalid_cast() {
     assert!(errors.is_empty());
 }
 
+#[test]
+fn invalid_temporal() {
+    let code = r#"
+    module ModuleA (
+        clk: input clock,
+        a  : input logic,
+        b  : input logic,
+    ) {
+        always_ff {
+            $assert(a |-> ##[1:3] b);
+            $assert_continue(a |=> $stable(b) && $past(a, 2));
+        }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(errors.is_empty());
+
+    let code = r#"
+    module ModuleA (
+        clk: input clock,
+        a  : input logic,
+        b  : input logic,
+    ) {
+        always_ff {
+            $display("%d", a |-> b);
+        }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::InvalidTemporal {
+            kind: crate::analyzer_error::InvalidTemporalKind::Property,
+            ..
+        }
+    ));
+
+    let code = r#"
+    module ModuleA (
+        a: input logic,
+        b: input logic,
+    ) {
+        always_comb {
+            $assert(a |-> b);
+        }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::InvalidTemporal {
+            kind: crate::analyzer_error::InvalidTemporalKind::Unclocked,
+            ..
+        }
+    ));
+
+    let code = r#"
+    module ModuleA (
+        clk: input clock,
+        a  : input logic,
+        b  : input logic<2>,
+    ) {
+        always_ff {
+            $assert(a |-> ##[3:1] b[0]);
+            $assert(a |-> ##[1:b] b[0]);
+        }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert_eq!(errors.len(), 2);
+    for error in errors {
+        assert!(matches!(
+            error,
+            AnalyzerError::InvalidTemporal {
+                kind: crate::analyzer_error::InvalidTemporalKind::Delay,
+                ..
+            }
+        ));
+    }
+}
+
 #[test]
 fn invalid_test() {
     let code = r#"
@@ -16049,6 +16227,111 @@ fn mismatch_function_arity_system_function() {
         errors[0],
         AnalyzerError::MismatchFunctionArity { .. }
     ));
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_a  : input logic,
+    ) {
+        always_ff {
+            $cover(i_a);
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchFunctionArity { .. }
+    ));
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_a  : input logic,
+    ) {
+        always_ff {
+            $cover_bins(i_a, "a", 2, 3);
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchFunctionArity { .. }
+    ));
+
+    let code = r#"
+    #[test(test_a)]
+    module ModuleA {
+        inst clk: $tb::clock_gen;
+
+        initial {
+            $wait(10, 20);
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchFunctionArity { .. }
+    ));
+
+    let code = r#"
+    #[test(test_a)]
+    module ModuleA {
+        inst clk: $tb::clock_gen;
+
+        initial {
+            $wait_until();
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchFunctionArity { .. }
+    ));
+
+    let code = r#"
+    #[test(test_a)]
+    module ModuleA {
+        var a: logic;
+
+        initial {
+            $force(a);
+            $release(a, 1);
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert_eq!(
+        errors
+            .iter()
+            .filter(|e| matches!(e, AnalyzerError::MismatchFunctionArity { .. }))
+            .count(),
+        2,
+        "{errors:?}"
+    );
+
+    let code = r#"
+    #[test(test_a)]
+    module ModuleA {
+        initial {
+            $dumpoff(1);
+        }
+    }
+    "#;
+
+    let errors = analyze_with_ir(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchFunctionArity { .. }
+    ));
 }
 
 #[test]
@@ -16240,6 +16523,273 @@ fn local_var_in_always_comb_uses_var_naming() {
     );
 }
 
+#[test]
+fn lint_rules() {
+    fn lint_with(rule: LintRule, level: LintLevel) -> Lint {
+        let mut lint = Lint::default();
+        let level = Some(level);
+        match rule {
+            LintRule::ImplicitTruncation => lint.rules.implicit_truncation = level,
+            LintRule::LatchInference => lint.rules.latch_inference = level,
+            LintRule::MagicNumber => lint.rules.magic_number = level,
+            LintRule::MissingDefault => lint.rules.missing_default = level,
+            LintRule::NestedIf => lint.rules.nested_if = level,
+            LintRule::UnusedParameter => lint.rules.unused_parameter = level,
+        }
+        lint
+    }
+
+    fn codes(errors: &[AnalyzerError]) -> Vec<String> {
+        errors
+            .iter()
+            .filter_map(|e| e.code().map(|x| x.to_string()))
+            .collect()
+    }
+
+    let code = r#"
+    module ModuleA #(
+        param P : u32 = 2,
+        param _Q: u32 = 3,
+    ) (
+        i_clk: input  clock,
+        i_rst: input  reset,
+        i_a  : input  logic<8>,
+        o_b  : output logic<4>,
+    ) {
+        const R: u32 = 4;
+
+        var c: logic<4>;
+
+        assign o_b = c + R;
+
+        always_ff {
+            if_reset {
+                c = 0;
+            } else {
+                c    = i_a;
+                c[3] = 1;
+                c    = c + 1;
+                c    = c + R;
+            }
+        }
+    }
+    "#;
+
+    let errors = analyze_with_lint(code, Lint::default());
+    assert!(errors.is_empty());
+
+    let errors = analyze_with_lint(code, lint_with(LintRule::UnusedParameter, LintLevel::Warn));
+    assert!(matches!(
+        errors.as_slice(),
+        [AnalyzerError::UnusedParameter { identifier, .. }] if identifier == "P"
+    ));
+
+    let errors = analyze_with_lint(
+        code,
+        lint_with(LintRule::ImplicitTruncation, LintLevel::Warn),
+    );
+    assert!(matches!(
+        errors.as_slice(),
+        [AnalyzerError::ImplicitTruncation {
+            dst_width: 4,
+            src_width: 8,
+            ..
+        }]
+    ));
+
+    let code = r#"
+    module ModuleB (
+        i_clk: input  clock,
+        i_rst: input  reset,
+        i_a  : input  logic<2>,
+        o_b  : output logic<4>,
+    ) {
+        always_ff {
+            if_reset {
+                o_b = 0;
+            } else {
+                case i_a {
+                    0: o_b = 1;
+                    1: o_b = 3;
+                }
+            }
+        }
+    }
+    "#;
+
+    let errors = analyze_with_lint(code, lint_with(LintRule::MagicNumber, LintLevel::Warn));
+    assert_eq!(codes(&errors), ["magic_number"]);
+    assert!(matches!(&errors[0], AnalyzerError::MagicNumber { number, .. } if number == "3"));
+
+    let errors = analyze_with_lint(code, lint_with(LintRule::MissingDefault, LintLevel::Deny));
+    assert_eq!(codes(&errors), ["missing_default"]);
+    assert!(errors[0].is_error());
+
+    let code = r#"
+    module ModuleC (
+        i_a: input  logic<4>,
+        o_b: output logic,
+    ) {
+        always_comb {
+            o_b = 0;
+            if i_a[0] {
+                if i_a[1] {
+                    if i_a[2] {
+                        #[allow(nested_if)]
+                        if i_a[3] {
+                            o_b = 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        always_comb {
+            if i_a[0] {
+                if i_a[1] {
+                    if i_a[2] {
+                        if i_a[3] {
+                            o_b = 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    "#;
+
+    let errors = analyze_with_lint(code, lint_with(LintRule::NestedIf, LintLevel::Warn));
+    assert!(matches!(
+        errors.as_slice(),
+        [AnalyzerError::NestedIf { depth: 4, .. }, ..]
+    ));
+    assert_eq!(
+        codes(&errors).iter().filter(|x| *x == "nested_if").count(),
+        1
+    );
+
+    // latch_inference is the only rule reported by default.
+    let code = r#"
+    module ModuleD (
+        i_a: input  logic,
+        o_b: output logic,
+    ) {
+        always_comb {
+            if i_a {
+                o_b = 1;
+            }
+        }
+    }
+    "#;
+
+    let errors = analyze_with_lint(code, Lint::default());
+    assert!(matches!(
+        errors.as_slice(),
+        [AnalyzerError::UncoveredBranch { .. }]
+    ));
+    assert!(!errors[0].is_error());
+
+    let errors = analyze_with_lint(code, lint_with(LintRule::LatchInference, LintLevel::Deny));
+    assert_eq!(codes(&errors), ["uncovered_branch"]);
+    assert!(errors[0].is_error());
+
+    let errors = analyze_with_lint(code, lint_with(LintRule::LatchInference, LintLevel::Allow));
+    assert!(errors.is_empty());
+
+    let code = r#"
+    module ModuleE (
+        i_a: input  logic,
+        o_b: output logic,
+    ) {
+        #[allow(latch_inference)]
+        always_comb {
+            if i_a {
+                o_b = 1;
+            }
+        }
+    }
+    "#;
+
+    let errors = analyze_with_lint(code, Lint::default());
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn implicit_truncation_once_in_project() {
+    let mut lint = Lint::default();
+    lint.rules.implicit_truncation = Some(LintLevel::Warn);
+
+    // Each instance converts the assignment again; it is reported once.
+    let code = r#"
+    module ModuleA #(
+        param W: u32 = 8,
+    ) (
+        i_a: input  logic<W>,
+        o_b: output logic<4>,
+    ) {
+        assign o_b = i_a;
+    }
+
+    module ModuleB (
+        i_a: input  logic<16>,
+        o_b: output logic<4> ,
+        o_c: output logic<4> ,
+    ) {
+        inst u0: ModuleA #(W: 8) (i_a: i_a[7:0], o_b);
+        inst u1: ModuleA #(W: 16) (i_a, o_b: o_c);
+    }
+    "#;
+
+    let errors = analyze_with_lint(code, lint.clone());
+    assert!(matches!(
+        errors.as_slice(),
+        [AnalyzerError::ImplicitTruncation { .. }]
+    ));
+
+    // std, like any dependency, is not linted, even when instantiated here.
+    let std = r#"
+    pub module ModuleC (
+        i_a: input  logic<8>,
+        o_b: output logic<4>,
+    ) {
+        assign o_b = i_a;
+    }
+    "#;
+    let code = r#"
+    module ModuleD (
+        i_a: input  logic<8>,
+        o_b: output logic<4>,
+    ) {
+        inst u: $std::ModuleC (i_a, o_b);
+    }
+    "#;
+
+    symbol_table::clear();
+    attribute_table::clear();
+    doc_comment_table::clear();
+
+    let mut metadata = Metadata::create_default("prj").unwrap();
+    metadata.lint = lint;
+    let analyzer = Analyzer::new(&metadata);
+    let inputs = [
+        ("$std", Parser::parse(std, &"std.veryl").unwrap()),
+        ("prj", Parser::parse(code, &"prj.veryl").unwrap()),
+    ];
+
+    let mut errors = vec![];
+    for (prj, parser) in &inputs {
+        errors.append(&mut analyzer.analyze_pass1(prj, &parser.veryl));
+    }
+    errors.append(&mut Analyzer::analyze_post_pass1());
+    let mut context = Context::default();
+    for (prj, parser) in &inputs {
+        context.set_project_name(prj);
+        errors.append(&mut analyzer.analyze_pass2(&parser.veryl, &mut context, None));
+    }
+    dbg!(&errors);
+    assert!(errors.is_empty());
+}
+
 #[test]
 fn regression_gray_enum_encoding() {
     // Correct Gray-coded explicit values (0,1,3,2) must be accepted.
@@ -19161,3 +19711,284 @@ fn orphan_else_across_scopes() {
         "{errors:?}"
     );
 }
+
+#[test]
+fn suggested_fixes() {
+    // Applies every fix of `errors` to `code` whole, skipping conflicting ones.
+    fn apply_fixes(code: &str, errors: &[AnalyzerError]) -> String {
+        let mut taken = Vec::new();
+        let mut fixes = Vec::new();
+        for x in errors.iter().filter_map(|x| x.fix()) {
+            let edits = x.edits();
+            if !edits.iter().any(|x| fix::conflicts(&taken, x))
+                && !fix::conflicts_by_name(fixes.iter().copied(), x)
+            {
+                taken.extend(edits);
+                fixes.push(x);
+            }
+        }
+        fix::apply(code, &taken).0
+    }
+
+    let code = r#"
+    module ModuleA {
+        var a: logic;
+        var always: logic;
+        assign always = 1;
+        let _b: logic = always;
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert_eq!(
+        apply_fixes(code, &errors),
+        r#"
+    module ModuleA {
+        var _a: logic;
+        var always_: logic;
+        assign always_ = 1;
+        let _b: logic = always_;
+    }
+    "#
+    );
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_rst: input reset,
+        i_a  : input logic,
+        o_b  : output logic,
+    ) {
+        var r: logic;
+        always_ff {
+            if_reset {
+            } else {
+                r = i_a;
+            }
+        }
+        assign o_b = r;
+    }
+    module ModuleB (
+        i_clk: input clock,
+        i_rst: input reset,
+    ) {
+        inst u0: ModuleA (
+            i_clk,
+            i_rst,
+        );
+        inst u1: ModuleA (i_clk, i_rst);
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert_eq!(
+        apply_fixes(code, &errors),
+        r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_rst: input reset,
+        i_a  : input logic,
+        o_b  : output logic,
+    ) {
+        var r: logic;
+        always_ff {
+            if_reset {
+                r = 0;
+            } else {
+                r = i_a;
+            }
+        }
+        assign o_b = r;
+    }
+    module ModuleB (
+        i_clk: input clock,
+        i_rst: input reset,
+    ) {
+        inst u0: ModuleA (
+            i_clk,
+            i_rst,
+            i_a: 0,
+            o_b: _,
+        );
+        inst u1: ModuleA (i_clk, i_rst, i_a: 0, o_b: _);
+    }
+    "#
+    );
+
+    // The reset fix matches the variable's type, so the result checks clean.
+    let code = r#"
+    module ModuleA (
+        i_clk: input  clock,
+        i_rst: input  reset,
+        i_a  : input  logic<4>,
+        o_b  : output logic<4>,
+    ) {
+        var r: logic<4>;
+        var m: logic<4> [2, 3];
+        always_ff {
+            if_reset {
+            } else {
+                r       = i_a;
+                m[0][0] = i_a;
+                m[1][2] = i_a;
+            }
+        }
+        assign o_b = r ^ m[0][0] ^ m[1][2];
+    }
+    "#;
+
+    let errors = analyze(code);
+    let fixed = apply_fixes(code, &errors);
+    assert!(fixed.contains("r = 0;"), "{fixed}");
+    assert!(fixed.contains("m = '{default: 0};"), "{fixed}");
+    let errors = analyze(&fixed);
+    assert!(errors.is_empty(), "{errors:?}");
+
+    let code = r#"
+    module ModuleA {
+        var fooBar: logic;
+        assign fooBar = 1;
+        let _c: logic = fooBar;
+    }
+    "#;
+
+    let mut lint = Lint::default();
+    lint.naming.case_var = Some(Case::Snake);
+    let errors = analyze_with_lint(code, lint);
+    assert_eq!(
+        apply_fixes(code, &errors),
+        r#"
+    module ModuleA {
+        var foo_bar: logic;
+        assign foo_bar = 1;
+        let _c: logic = foo_bar;
+    }
+    "#
+    );
+
+    // A reset inserted for a variable being renamed waits for the rename, so
+    // it names the new identifier.
+    let code = r#"
+    module ModuleA (
+        i_clk: input clock,
+        i_rst: input reset,
+    ) {
+        var myCount: logic;
+        always_ff {
+            if_reset {
+            } else {
+                myCount = 1;
+            }
+        }
+        let _c: logic = myCount;
+    }
+    "#;
+
+    let mut lint = Lint::default();
+    lint.naming.case_var = Some(Case::Snake);
+    let errors = analyze_with_lint(code, lint.clone());
+    let fixed = apply_fixes(code, &errors);
+    assert!(!fixed.contains("myCount = 0;"), "{fixed}");
+    let errors = analyze_with_lint(&fixed, lint.clone());
+    let fixed = apply_fixes(&fixed, &errors);
+    assert!(fixed.contains("my_count = 0;"), "{fixed}");
+    assert!(!fixed.contains("myCount"), "{fixed}");
+    let errors = analyze_with_lint(&fixed, lint);
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn rename_symbol() {
+    // Renames the symbol named `from` declared at `line` (1-based) to `to`.
+    fn rename(code: &str, from: &str, line: u32, to: &str) -> String {
+        analyze(code);
+        let symbol = symbol_table::get_all()
+            .into_iter()
+            .find(|x| x.token.to_string() == from && x.token.line == line)
+            .unwrap();
+        fix::apply(code, &fix::rename(&symbol, to)).0
+    }
+
+    let code = r#"package PkgA {
+    struct StructA {
+        a: logic,
+    }
+    enum EnumA: logic {
+        X,
+        Y,
+    }
+    const C: u32 = 1;
+}
+module ModuleA (
+    i_a: input logic,
+    o_b: output logic,
+) {
+    import PkgA::C;
+    var s: PkgA::StructA;
+    assign s.a = i_a;
+    assign o_b = s.a & (PkgA::EnumA::X == PkgA::EnumA::Y) & C;
+}
+module ModuleB {
+    var i_a: logic;
+    let _b: logic = 0;
+    assign i_a = 1;
+    inst u0: ModuleA (i_a, o_b: _);
+    inst u1: ModuleA (i_a: i_a, o_b: _);
+}
+"#;
+
+    // A port, along with the connections naming it; an implicit one is
+    // spelled out.
+    let ret = rename(code, "i_a", 12, "i_x");
+    assert!(ret.contains("    i_x: input logic,"), "{ret}");
+    assert!(ret.contains("    assign s.a = i_x;"), "{ret}");
+    assert!(
+        ret.contains("inst u0: ModuleA (i_x: i_a, o_b: _);"),
+        "{ret}"
+    );
+    assert!(
+        ret.contains("inst u1: ModuleA (i_x: i_a, o_b: _);"),
+        "{ret}"
+    );
+    assert!(ret.contains("    var i_a: logic;"), "{ret}");
+
+    // A variable connected implicitly.
+    let ret = rename(code, "i_a", 21, "x");
+    assert!(ret.contains("    var x: logic;"), "{ret}");
+    assert!(ret.contains("    assign x = 1;"), "{ret}");
+    assert!(ret.contains("inst u0: ModuleA (i_a: x, o_b: _);"), "{ret}");
+    assert!(ret.contains("inst u1: ModuleA (i_a: x, o_b: _);"), "{ret}");
+    assert!(ret.contains("    i_a: input logic,"), "{ret}");
+
+    // A struct member, an enum variant and an imported package item.
+    let ret = rename(code, "a", 3, "m");
+    assert!(ret.contains("        m: logic,"), "{ret}");
+    assert!(ret.contains("    assign s.m = i_a;"), "{ret}");
+    assert!(ret.contains("assign o_b = s.m &"), "{ret}");
+
+    let ret = rename(code, "X", 6, "Z");
+    assert!(ret.contains("        Z,"), "{ret}");
+    assert!(ret.contains("(PkgA::EnumA::Z == PkgA::EnumA::Y)"), "{ret}");
+
+    let ret = rename(code, "C", 9, "D");
+    assert!(ret.contains("    const D: u32 = 1;"), "{ret}");
+    assert!(ret.contains("    import PkgA::D;"), "{ret}");
+    assert!(ret.contains("PkgA::EnumA::Y) & D;"), "{ret}");
+
+    let ret = rename(code, "ModuleA", 11, "ModuleC");
+    assert!(ret.contains("module ModuleC ("), "{ret}");
+    assert!(ret.contains("inst u0: ModuleC (i_a, o_b: _);"), "{ret}");
+
+    // An item reached through a wildcard import.
+    let code = r#"package PkgA {
+    const C: u32 = 1;
+}
+module ModuleA {
+    import PkgA::*;
+    let _a: u32 = C;
+}
+"#;
+    let ret = rename(code, "C", 2, "D");
+    assert!(ret.contains("    const D: u32 = 1;"), "{ret}");
+    assert!(ret.contains("    let _a: u32 = D;"), "{ret}");
+}
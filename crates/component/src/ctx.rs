@@ -179,6 +179,14 @@ impl RawCtx {
         self.api().size >= sys::VRL_HOST_API_TRACE_SIZE
     }
 
+    fn state_write(&mut self, data: &[u8]) -> bool {
+        if self.api().size < sys::VRL_HOST_API_STATE_SIZE {
+            return false;
+        }
+        unsafe { (self.api().state_write)(self.ctx, data.as_ptr(), data.len()) };
+        true
+    }
+
     /// Direct pointers into a port's host buffers, or all-null when the
     /// transport cannot share memory (wasm) or the host omits the entry.
     /// Queried once per port at `create`, so the null check is off the hot
@@ -325,6 +333,14 @@ impl SimCtx {
         }
     }
 
+    /// Hands a `save_state` result to the host's checkpoint writer.
+    pub(crate) fn write_state(&mut self, data: &[u8]) -> Result<()> {
+        if !self.raw.state_write(data) {
+            bail!("the simulator does not support component checkpoints");
+        }
+        Ok(())
+    }
+
     /// Reads the pre-edge value of an input port (clock and reset ports
     /// convert into 1-bit input ports). Under a four-state simulation the
     /// returned value carries an X/Z mask; otherwise the mask is all-zero.
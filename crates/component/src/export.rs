@@ -111,6 +111,32 @@ pub unsafe extern "C" fn on_finish<T: Component>(state: *mut c_void, ctx: *mut s
     unsafe { run_hook::<T>(state, ctx, |c, sim| c.on_finish(sim)) }
 }
 
+pub unsafe extern "C" fn save_state<T: Component>(
+    state: *mut c_void,
+    ctx: *mut sys::VrlCtx,
+) -> i32 {
+    unsafe {
+        run_hook::<T>(state, ctx, |c, sim| {
+            let data = c.save_state(sim)?;
+            sim.write_state(&data)
+        })
+    }
+}
+
+pub unsafe extern "C" fn restore_state<T: Component>(
+    state: *mut c_void,
+    ctx: *mut sys::VrlCtx,
+    data: *const u8,
+    len: usize,
+) -> i32 {
+    let data = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }
+    };
+    unsafe { run_hook::<T>(state, ctx, |c, sim| c.restore_state(data, sim)) }
+}
+
 /// Writes a method return value into the host-provided slot. The host
 /// pre-allocates `ret.words` with `ret.nwords` capacity; the guest shrinks
 /// `nwords` to the actual count.
@@ -205,6 +231,8 @@ pub const fn vtable<T: Component>() -> sys::VrlComponentVTable {
         on_clock: on_clock::<T>,
         call_method: call_method::<T>,
         on_finish: on_finish::<T>,
+        save_state: save_state::<T>,
+        restore_state: restore_state::<T>,
     }
 }
 
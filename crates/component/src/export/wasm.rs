@@ -50,6 +50,7 @@ mod host {
         pub fn file_close(handle: i32);
         pub fn trace_var(name_ptr: *const u8, name_len: usize, width: u32) -> i32;
         pub fn trace_write(handle: i32, words: *const u64);
+        pub fn state_write(data: *const u8, len: usize);
     }
 }
 
@@ -180,6 +181,10 @@ unsafe extern "C" fn port_direct(_: *mut sys::VrlCtx, _: u32, _: *mut sys::VrlPo
     0
 }
 
+unsafe extern "C" fn state_write(_: *mut sys::VrlCtx, data: *const u8, len: usize) {
+    unsafe { host::state_write(data, len) }
+}
+
 static HOST_API: sys::VrlHostApi = sys::VrlHostApi {
     size: size_of::<sys::VrlHostApi>(),
     port_index,
@@ -203,6 +208,7 @@ static HOST_API: sys::VrlHostApi = sys::VrlHostApi {
     trace_write,
     is_4state,
     port_direct,
+    state_write,
 };
 
 /// One live component instance; its box pointer is the `u32` handle
@@ -307,6 +313,21 @@ pub unsafe fn on_finish(handle: u32) -> i32 {
     unsafe { (instance.vtable.on_finish)(instance.state, std::ptr::null_mut()) }
 }
 
+/// # Safety
+/// `handle` must be a live handle from [`create`].
+pub unsafe fn save_state(handle: u32) -> i32 {
+    let instance = unsafe { &*(handle as usize as *const Instance) };
+    unsafe { (instance.vtable.save_state)(instance.state, std::ptr::null_mut()) }
+}
+
+/// # Safety
+/// `handle` must be a live handle from [`create`]; `ptr`/`len` must
+/// reference valid guest memory.
+pub unsafe fn restore_state(handle: u32, ptr: *const u8, len: usize) -> i32 {
+    let instance = unsafe { &*(handle as usize as *const Instance) };
+    unsafe { (instance.vtable.restore_state)(instance.state, std::ptr::null_mut(), ptr, len) }
+}
+
 /// # Safety
 /// `handle` must be a live handle from [`create`]; `name`, `args` and `ret`
 /// must reference valid guest memory, `ret.words` pre-allocated with
@@ -125,6 +125,19 @@ pub trait Component: Send + 'static {
     fn on_finish(&mut self, _ctx: &mut SimCtx) -> Result<()> {
         Ok(())
     }
+
+    /// Serializes the instance state into a simulator checkpoint. The
+    /// default saves nothing, so a resumed run keeps the state `on_init`
+    /// left behind.
+    fn save_state(&mut self, _ctx: &mut SimCtx) -> Result<Vec<u8>> {
+        Ok(Vec::new())
+    }
+
+    /// Restores the bytes `save_state` returned. Called after `on_init`
+    /// when a run resumes from a checkpoint.
+    fn restore_state(&mut self, _state: &[u8], _ctx: &mut SimCtx) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// Exports component types from a cdylib.
@@ -260,6 +273,20 @@ macro_rules! veryl_component_export {
                 unsafe { $crate::export::wasm::on_finish(handle) }
             }
 
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn veryl_component_save_state(handle: u32) -> i32 {
+                unsafe { $crate::export::wasm::save_state(handle) }
+            }
+
+            #[unsafe(no_mangle)]
+            unsafe extern "C" fn veryl_component_restore_state(
+                handle: u32,
+                ptr: *const u8,
+                len: usize,
+            ) -> i32 {
+                unsafe { $crate::export::wasm::restore_state(handle, ptr, len) }
+            }
+
             #[unsafe(no_mangle)]
             unsafe extern "C" fn veryl_component_call_method(
                 handle: u32,
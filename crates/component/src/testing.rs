@@ -209,6 +209,18 @@ impl MockSim {
         component.on_finish(&mut ctx)
     }
 
+    /// Drives the `save_state` hook, returning the checkpoint bytes.
+    pub fn save_state<T: Component>(&mut self, component: &mut T) -> Result<Vec<u8>> {
+        let mut ctx = unsafe { SimCtx::new(self.as_ctx(), &MOCK_API, self.four_state) };
+        component.save_state(&mut ctx)
+    }
+
+    /// Drives the `restore_state` hook with bytes from [`Self::save_state`].
+    pub fn restore_state<T: Component>(&mut self, component: &mut T, state: &[u8]) -> Result<()> {
+        let mut ctx = unsafe { SimCtx::new(self.as_ctx(), &MOCK_API, self.four_state) };
+        component.restore_state(state, &mut ctx)
+    }
+
     /// Calls a zero-time method.
     pub fn call<T: Component>(
         &mut self,
@@ -535,6 +547,11 @@ extern "C" fn mock_trace_write(ctx: *mut sys::VrlCtx, handle: i32, words: *const
     }
 }
 
+extern "C" fn mock_state_write(_: *mut sys::VrlCtx, _: *const u8, _: usize) {
+    // The harness calls `Component::save_state` directly and returns its
+    // bytes, so nothing reaches the host writer.
+}
+
 static MOCK_API: sys::VrlHostApi = sys::VrlHostApi {
     size: size_of::<sys::VrlHostApi>(),
     port_index: mock_port_index,
@@ -558,6 +575,7 @@ static MOCK_API: sys::VrlHostApi = sys::VrlHostApi {
     trace_write: mock_trace_write,
     is_4state: mock_is_4state,
     port_direct: mock_port_direct,
+    state_write: mock_state_write,
 };
 
 #[cfg(test)]
@@ -14,7 +14,7 @@
 use std::ffi::c_void;
 
 /// Incremented on any breaking layout change. Checked at load time.
-pub const VRL_COMPONENT_ABI_VERSION: u32 = 1;
+pub const VRL_COMPONENT_ABI_VERSION: u32 = 2;
 
 /// The component crate family's version, for tooling that pins a matching
 /// `veryl-component` dependency (e.g. the `veryl new --component` scaffold).
@@ -249,6 +249,9 @@ pub struct VrlHostApi {
     /// lifetime, so the guest caches them at `create` time and then reads or
     /// writes the buffers without a per-access host call.
     pub port_direct: unsafe extern "C" fn(*mut VrlCtx, idx: u32, out: *mut VrlPortDirect) -> u32,
+    /// Appends `len` bytes to the instance's checkpoint record. Only
+    /// meaningful inside `save_state`; ignored elsewhere.
+    pub state_write: unsafe extern "C" fn(*mut VrlCtx, data: *const u8, len: usize),
 }
 
 /// Least [`VrlHostApi::size`] that includes the trace entries. Derived from
@@ -263,6 +266,10 @@ pub const VRL_HOST_API_TRACE_SIZE: usize = std::mem::offset_of!(VrlHostApi, trac
 pub const VRL_HOST_API_DIRECT_SIZE: usize = std::mem::offset_of!(VrlHostApi, port_direct)
     + size_of::<unsafe extern "C" fn(*mut VrlCtx, u32, *mut VrlPortDirect) -> u32>();
 
+/// Least [`VrlHostApi::size`] that includes [`VrlHostApi::state_write`].
+pub const VRL_HOST_API_STATE_SIZE: usize = std::mem::offset_of!(VrlHostApi, state_write)
+    + size_of::<unsafe extern "C" fn(*mut VrlCtx, *const u8, usize)>();
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,9 +292,15 @@ mod tests {
 
     #[test]
     fn direct_size_covers_port_direct() {
-        assert_eq!(VRL_HOST_API_DIRECT_SIZE, size_of::<VrlHostApi>());
+        assert!(VRL_HOST_API_DIRECT_SIZE <= size_of::<VrlHostApi>());
         assert!(VRL_HOST_API_DIRECT_SIZE > std::mem::offset_of!(VrlHostApi, port_direct));
     }
+
+    #[test]
+    fn state_size_covers_state_write() {
+        assert_eq!(VRL_HOST_API_STATE_SIZE, size_of::<VrlHostApi>());
+        assert!(VRL_HOST_API_STATE_SIZE > std::mem::offset_of!(VrlHostApi, state_write));
+    }
 }
 
 /// Per-component-type entry points. Hook return value: 0 = ok, non-zero =
@@ -316,6 +329,13 @@ pub struct VrlComponentVTable {
         ret: *mut VrlValue,
     ) -> i32,
     pub on_finish: unsafe extern "C" fn(*mut c_void, *mut VrlCtx) -> i32,
+    /// Writes the instance state through `VrlHostApi::state_write` when the
+    /// simulator saves a checkpoint.
+    pub save_state: unsafe extern "C" fn(*mut c_void, *mut VrlCtx) -> i32,
+    /// Replaces the instance state with the bytes an earlier `save_state`
+    /// wrote. Called after `on_init` when a run resumes from a checkpoint.
+    pub restore_state:
+        unsafe extern "C" fn(*mut c_void, *mut VrlCtx, data: *const u8, len: usize) -> i32,
 }
 
 /// Name of the symbol every component library exports:
@@ -0,0 +1,201 @@
+//! Generates `veryl_cosim.h` into `OUT_DIR` from the public constants,
+//! opaque structs and `extern "C"` functions of `src/capi.rs`. The copy
+//! checked in as `include/veryl_cosim.h` is kept in sync by a unit test, so
+//! the build never writes to the source tree.
+//!
+//! The integration tests host VPI applications, which resolve the `vpi_*`
+//! functions against the executable, so their binaries export them.
+
+use std::fs;
+
+const SOURCE: &str = "src/capi.rs";
+
+fn c_type(ty: &str) -> String {
+    let ty = ty.trim();
+    if let Some(inner) = ty.strip_prefix("*const ") {
+        let inner = c_type(inner);
+        if inner.ends_with('*') {
+            format!("{inner} const *")
+        } else {
+            format!("const {inner} *")
+        }
+    } else if let Some(inner) = ty.strip_prefix("*mut ") {
+        let inner = c_type(inner);
+        if inner.ends_with('*') {
+            format!("{inner}*")
+        } else {
+            format!("{inner} *")
+        }
+    } else {
+        match ty {
+            "c_char" => "char",
+            "bool" => "bool",
+            "u8" => "uint8_t",
+            "u32" => "uint32_t",
+            "u64" => "uint64_t",
+            "i32" => "int32_t",
+            "i64" => "int64_t",
+            "usize" => "size_t",
+            x => x,
+        }
+        .to_string()
+    }
+}
+
+fn declaration(ty: &str, name: &str) -> String {
+    let ty = c_type(ty);
+    if ty.ends_with('*') {
+        format!("{ty}{name}")
+    } else {
+        format!("{ty} {name}")
+    }
+}
+
+fn comment(out: &mut String, doc: &[String]) {
+    match doc {
+        [] => {}
+        [line] => out.push_str(&format!("/** {line} */\n")),
+        lines => {
+            out.push_str("/**\n");
+            for line in lines {
+                if line.is_empty() {
+                    out.push_str(" *\n");
+                } else {
+                    out.push_str(&format!(" * {line}\n"));
+                }
+            }
+            out.push_str(" */\n");
+        }
+    }
+}
+
+/// `pub [unsafe] extern "C" fn name(params) [-> ret] {`, joined onto one line.
+fn function(signature: &str) -> String {
+    let (head, rest) = signature.split_once('(').expect("function parameters");
+    let name = head.rsplit(' ').next().unwrap();
+    let (params, tail) = rest.rsplit_once(')').expect("function parameters");
+    let ret = tail
+        .split_once("->")
+        .map(|(_, x)| c_type(x.trim_end_matches('{')))
+        .unwrap_or_else(|| "void".to_string());
+
+    let params: Vec<String> = params
+        .split(',')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            let (name, ty) = x.split_once(':').expect("parameter type");
+            declaration(ty, name.trim())
+        })
+        .collect();
+    let params = if params.is_empty() {
+        "void".to_string()
+    } else {
+        params.join(", ")
+    };
+    let ret = if ret.ends_with('*') {
+        ret
+    } else {
+        format!("{ret} ")
+    };
+    format!("{ret}{name}({params});\n")
+}
+
+fn generate(source: &str) -> String {
+    let mut body = String::new();
+    let mut doc: Vec<String> = Vec::new();
+    let mut lines = source.lines().map(str::trim);
+
+    while let Some(line) = lines.next() {
+        if let Some(x) = line.strip_prefix("///") {
+            doc.push(x.strip_prefix(' ').unwrap_or(x).to_string());
+        } else if line.starts_with("#[") {
+            continue;
+        } else if let Some(x) = line.strip_prefix("pub const ") {
+            let (name, rest) = x.split_once(':').expect("constant type");
+            let value = rest
+                .split_once('=')
+                .expect("constant value")
+                .1
+                .trim()
+                .trim_end_matches(';');
+            let value = if value.starts_with('-') {
+                format!("({value})")
+            } else {
+                value.to_string()
+            };
+            comment(&mut body, &doc);
+            body.push_str(&format!("#define {name} {value}\n\n"));
+            doc.clear();
+        } else if let Some(x) = line.strip_prefix("pub struct ") {
+            let name = x.split([' ', '{', ';']).next().unwrap();
+            comment(&mut body, &doc);
+            body.push_str(&format!("typedef struct {name} {name};\n\n"));
+            doc.clear();
+        } else if line.starts_with("pub extern \"C\" fn ")
+            || line.starts_with("pub unsafe extern \"C\" fn ")
+        {
+            let mut signature = line.to_string();
+            while !signature.ends_with('{') {
+                let next = lines.next().expect("function body");
+                if !signature.ends_with('(') && !next.starts_with(')') {
+                    signature.push(' ');
+                }
+                signature.push_str(next);
+            }
+            comment(&mut body, &doc);
+            body.push_str(&function(&signature));
+            body.push('\n');
+            doc.clear();
+        } else {
+            doc.clear();
+        }
+    }
+
+    format!(
+        "/* Generated by build.rs from {SOURCE}; do not edit. */\n\
+         #ifndef VERYL_COSIM_H\n\
+         #define VERYL_COSIM_H\n\
+         \n\
+         #include <stdbool.h>\n\
+         #include <stddef.h>\n\
+         #include <stdint.h>\n\
+         \n\
+         #ifdef __cplusplus\n\
+         extern \"C\" {{\n\
+         #endif\n\
+         \n\
+         {body}\
+         #ifdef __cplusplus\n\
+         }}\n\
+         #endif\n\
+         \n\
+         #endif /* VERYL_COSIM_H */\n"
+    )
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={SOURCE}");
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let source = fs::read_to_string(SOURCE).expect("read src/capi.rs");
+    let header = std::path::Path::new(&out_dir).join("veryl_cosim.h");
+    fs::write(header, generate(&source)).expect("write veryl_cosim.h");
+
+    // `vpi_printf` and `vpi_mcd_printf` are C-variadic; see src/vpi.rs.
+    if std::env::var("CARGO_CFG_TARGET_FAMILY").as_deref() == Ok("unix") {
+        println!("cargo:rerun-if-changed=src/vpi_printf.c");
+        cc::Build::new()
+            .file("src/vpi_printf.c")
+            .compile("veryl_vpi_printf");
+    }
+
+    if std::env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("linux") {
+        let list = std::path::Path::new(&out_dir).join("vpi.list");
+        fs::write(&list, "{ vpi_*; };\n").expect("write vpi.list");
+        println!(
+            "cargo:rustc-link-arg-tests=-Wl,--dynamic-list={}",
+            list.display()
+        );
+    }
+}
@@ -0,0 +1,193 @@
+/* Generated by build.rs from src/capi.rs; do not edit. */
+#ifndef VERYL_COSIM_H
+#define VERYL_COSIM_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/** Version of this API; bumped on any incompatible change. */
+#define VERYL_COSIM_ABI_VERSION 1
+
+/** Success. */
+#define VERYL_COSIM_OK 0
+
+/** A null pointer, a string that is not UTF-8 or an unknown handle. */
+#define VERYL_COSIM_ERR_ARGUMENT (-1)
+
+/** The project failed to load or analyze. */
+#define VERYL_COSIM_ERR_LOAD (-2)
+
+/** No module, port, variable, clock or reset of that name. */
+#define VERYL_COSIM_ERR_NOT_FOUND (-3)
+
+/** A file could not be read or written. */
+#define VERYL_COSIM_ERR_IO (-4)
+
+/** The simulator panicked; close it. */
+#define VERYL_COSIM_ERR_PANIC (-5)
+
+/** `veryl_cosim_open` flag: simulate X and Z. */
+#define VERYL_COSIM_FOUR_STATE 1
+
+/**
+ * `veryl_cosim_open` flag: allow `veryl_cosim_force` on comb variables
+ * too, at some cost in speed.
+ */
+#define VERYL_COSIM_FORCE 2
+
+/** An open simulator. Use it only from the thread that opened it. */
+typedef struct VerylCosim VerylCosim;
+
+/** Returns `VERYL_COSIM_ABI_VERSION` of the loaded library. */
+uint32_t veryl_cosim_abi_version(void);
+
+/**
+ * Describes the last failed call on this thread. The string stays valid
+ * until the next failure on the thread.
+ */
+const char *veryl_cosim_last_error(void);
+
+/**
+ * Opens module `top` of the Veryl project `project` belongs to (its
+ * `Veryl.toml`, or any file or directory inside it), analyzing its sources
+ * and dependencies. `flags` is a set of `VERYL_COSIM_*` flags.
+ *
+ * # Safety
+ * `project` and `top` must be NUL-terminated strings; `out` must be
+ * writable.
+ */
+int32_t veryl_cosim_open(const char *project, const char *top, uint32_t flags, VerylCosim **out);
+
+/**
+ * Closes `sim`, finishing its waveform. A null `sim` is ignored.
+ *
+ * # Safety
+ * `sim` must come from `veryl_cosim_open` and not be used afterwards.
+ */
+void veryl_cosim_close(VerylCosim *sim);
+
+/**
+ * Resolves top-level port `name` to a signal handle.
+ *
+ * # Safety
+ * `sim` must be open, `name` NUL-terminated and `out` writable.
+ */
+int32_t veryl_cosim_port(VerylCosim *sim, const char *name, uint32_t *out);
+
+/**
+ * Resolves a variable by hierarchical path (e.g. "u_core.cnt") to a signal
+ * handle.
+ *
+ * # Safety
+ * `sim` must be open, `path` NUL-terminated and `out` writable.
+ */
+int32_t veryl_cosim_var(VerylCosim *sim, const char *path, uint32_t *out);
+
+/**
+ * Stores the bit width of `signal`.
+ *
+ * # Safety
+ * `sim` must be open and `out` writable.
+ */
+int32_t veryl_cosim_width(VerylCosim *sim, uint32_t signal, uint32_t *out);
+
+/**
+ * Resolves clock port `name` to a clock handle.
+ *
+ * # Safety
+ * `sim` must be open, `name` NUL-terminated and `out` writable.
+ */
+int32_t veryl_cosim_clock(VerylCosim *sim, const char *name, uint32_t *out);
+
+/**
+ * Resolves reset port `name` to a reset handle.
+ *
+ * # Safety
+ * `sim` must be open, `name` NUL-terminated and `out` writable.
+ */
+int32_t veryl_cosim_reset(VerylCosim *sim, const char *name, uint32_t *out);
+
+/**
+ * Advances one active edge of `clock`.
+ *
+ * # Safety
+ * `sim` must be open.
+ */
+int32_t veryl_cosim_step(VerylCosim *sim, uint32_t clock);
+
+/**
+ * Takes one edge of every clock with `reset` asserted.
+ *
+ * # Safety
+ * `sim` must be open.
+ */
+int32_t veryl_cosim_step_reset(VerylCosim *sim, uint32_t reset);
+
+/**
+ * Writes `signal` from `nwords` LSB-first 64-bit words, truncated to its
+ * width. `mask_xz` marks X/Z bits (a set `words` bit makes Z, a clear one
+ * X) and may be null for a 2-state value.
+ *
+ * # Safety
+ * `sim` must be open; `words`, and `mask_xz` unless null, must hold
+ * `nwords` words.
+ */
+int32_t veryl_cosim_set(VerylCosim *sim, uint32_t signal, const uint64_t *words, const uint64_t *mask_xz, size_t nwords);
+
+/**
+ * Reads `signal` into `nwords` LSB-first 64-bit words; words above its
+ * width are zeroed. `mask_xz`, unless null, receives the X/Z bits.
+ *
+ * # Safety
+ * `sim` must be open; `words`, and `mask_xz` unless null, must have room
+ * for `nwords` words.
+ */
+int32_t veryl_cosim_get(VerylCosim *sim, uint32_t signal, uint64_t *words, uint64_t *mask_xz, size_t nwords);
+
+/**
+ * Holds `signal` at `nwords` LSB-first 64-bit words, as `veryl_cosim_set`
+ * writes them, until `veryl_cosim_release`. Forcing a comb variable
+ * requires `VERYL_COSIM_FORCE`.
+ *
+ * # Safety
+ * `sim` must be open; `words`, and `mask_xz` unless null, must hold
+ * `nwords` words.
+ */
+int32_t veryl_cosim_force(VerylCosim *sim, uint32_t signal, const uint64_t *words, const uint64_t *mask_xz, size_t nwords);
+
+/**
+ * Ends a `veryl_cosim_force` of `signal`. A comb variable follows its
+ * drivers again; an FF keeps the forced value until its next update.
+ *
+ * # Safety
+ * `sim` must be open.
+ */
+int32_t veryl_cosim_release(VerylCosim *sim, uint32_t signal);
+
+/**
+ * Starts a waveform of every variable at `path`: FST for a `.fst`
+ * extension, VCD otherwise. Replaces an earlier waveform.
+ *
+ * # Safety
+ * `sim` must be open and `path` NUL-terminated.
+ */
+int32_t veryl_cosim_dump_open(VerylCosim *sim, const char *path);
+
+/**
+ * Records every variable into the waveform at `time`.
+ *
+ * # Safety
+ * `sim` must be open.
+ */
+int32_t veryl_cosim_dump(VerylCosim *sim, uint64_t time);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* VERYL_COSIM_H */
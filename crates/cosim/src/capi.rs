@@ -0,0 +1,425 @@
+//! The C API. `build.rs` generates `veryl_cosim.h` from this file:
+//! public constants, opaque structs and `extern "C"` functions along with
+//! their doc comments, so every exported item here must use types the
+//! generator maps (integers, `c_char` and pointers to those or to an opaque
+//! struct). The generated header is checked in as `include/veryl_cosim.h`;
+//! after changing this file, refresh it with
+//! `VERYL_COSIM_UPDATE_HEADER=1 cargo test -p veryl-cosim header`.
+//!
+//! Functions returning `i32` return `VERYL_COSIM_OK` or a negative error
+//! code, with a description available from `veryl_cosim_last_error`.
+
+use crate::{Cosim, CosimError};
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::Path;
+use veryl_simulator::Config;
+use veryl_simulator::ir::Event;
+use veryl_simulator::simulator::SignalRef;
+
+/// Version of this API; bumped on any incompatible change.
+pub const VERYL_COSIM_ABI_VERSION: u32 = 1;
+
+/// Success.
+pub const VERYL_COSIM_OK: i32 = 0;
+/// A null pointer, a string that is not UTF-8 or an unknown handle.
+pub const VERYL_COSIM_ERR_ARGUMENT: i32 = -1;
+/// The project failed to load or analyze.
+pub const VERYL_COSIM_ERR_LOAD: i32 = -2;
+/// No module, port, variable, clock or reset of that name.
+pub const VERYL_COSIM_ERR_NOT_FOUND: i32 = -3;
+/// A file could not be read or written.
+pub const VERYL_COSIM_ERR_IO: i32 = -4;
+/// The simulator panicked; close it.
+pub const VERYL_COSIM_ERR_PANIC: i32 = -5;
+
+/// `veryl_cosim_open` flag: simulate X and Z.
+pub const VERYL_COSIM_FOUR_STATE: u32 = 1;
+/// `veryl_cosim_open` flag: allow `veryl_cosim_force` on comb variables
+/// too, at some cost in speed.
+pub const VERYL_COSIM_FORCE: u32 = 2;
+
+/// An open simulator. Use it only from the thread that opened it.
+pub struct VerylCosim {
+    cosim: Cosim,
+    signals: Vec<SignalRef>,
+    clocks: Vec<Event>,
+    resets: Vec<Event>,
+}
+
+thread_local!(static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default()));
+
+fn set_last_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|x| *x.borrow_mut() = message);
+}
+
+/// Runs an entry point, turning an error or a panic into a return code.
+fn guard(f: impl FnOnce() -> Result<(), CosimError>) -> i32 {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => VERYL_COSIM_OK,
+        Ok(Err(e)) => {
+            set_last_error(e.to_string());
+            e.code()
+        }
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|x| x.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            set_last_error(format!("simulator panicked: {message}"));
+            VERYL_COSIM_ERR_PANIC
+        }
+    }
+}
+
+fn argument(what: &str) -> CosimError {
+    CosimError::Argument(format!("invalid {what}"))
+}
+
+unsafe fn str_arg<'a>(ptr: *const c_char, what: &str) -> Result<&'a str, CosimError> {
+    if ptr.is_null() {
+        return Err(argument(what));
+    }
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| argument(what))
+}
+
+unsafe fn sim_arg<'a>(sim: *mut VerylCosim) -> Result<&'a mut VerylCosim, CosimError> {
+    unsafe { sim.as_mut() }.ok_or_else(|| argument("simulator"))
+}
+
+unsafe fn out_arg<'a, T>(out: *mut T) -> Result<&'a mut T, CosimError> {
+    unsafe { out.as_mut() }.ok_or_else(|| argument("output pointer"))
+}
+
+fn handle<T>(table: &[T], handle: u32, what: &str) -> Result<T, CosimError>
+where
+    T: Clone,
+{
+    table
+        .get(handle as usize)
+        .cloned()
+        .ok_or_else(|| argument(what))
+}
+
+fn push<T>(table: &mut Vec<T>, x: T) -> u32 {
+    table.push(x);
+    (table.len() - 1) as u32
+}
+
+/// Returns `VERYL_COSIM_ABI_VERSION` of the loaded library.
+#[unsafe(no_mangle)]
+pub extern "C" fn veryl_cosim_abi_version() -> u32 {
+    VERYL_COSIM_ABI_VERSION
+}
+
+/// Describes the last failed call on this thread. The string stays valid
+/// until the next failure on the thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn veryl_cosim_last_error() -> *const c_char {
+    LAST_ERROR.with(|x| x.borrow().as_ptr())
+}
+
+/// Opens module `top` of the Veryl project `project` belongs to (its
+/// `Veryl.toml`, or any file or directory inside it), analyzing its sources
+/// and dependencies. `flags` is a set of `VERYL_COSIM_*` flags.
+///
+/// # Safety
+/// `project` and `top` must be NUL-terminated strings; `out` must be
+/// writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_open(
+    project: *const c_char,
+    top: *const c_char,
+    flags: u32,
+    out: *mut *mut VerylCosim,
+) -> i32 {
+    guard(|| {
+        let project = unsafe { str_arg(project, "project path")? };
+        let top = unsafe { str_arg(top, "top module name")? };
+        let out = unsafe { out_arg(out)? };
+        let config = Config {
+            use_4state: flags & VERYL_COSIM_FOUR_STATE != 0,
+            force: flags & VERYL_COSIM_FORCE != 0,
+            ..Config::default()
+        };
+        let cosim = Cosim::open_project_with_config(Path::new(project), top, config)?;
+        *out = Box::into_raw(Box::new(VerylCosim {
+            cosim,
+            signals: Vec::new(),
+            clocks: Vec::new(),
+            resets: Vec::new(),
+        }));
+        Ok(())
+    })
+}
+
+/// Closes `sim`, finishing its waveform. A null `sim` is ignored.
+///
+/// # Safety
+/// `sim` must come from `veryl_cosim_open` and not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_close(sim: *mut VerylCosim) {
+    if !sim.is_null() {
+        let _ = catch_unwind(AssertUnwindSafe(|| {
+            drop(unsafe { Box::from_raw(sim) });
+        }));
+    }
+}
+
+/// Resolves top-level port `name` to a signal handle.
+///
+/// # Safety
+/// `sim` must be open, `name` NUL-terminated and `out` writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_port(
+    sim: *mut VerylCosim,
+    name: *const c_char,
+    out: *mut u32,
+) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let name = unsafe { str_arg(name, "port name")? };
+        let out = unsafe { out_arg(out)? };
+        let signal = sim.cosim.port(name)?;
+        *out = push(&mut sim.signals, signal);
+        Ok(())
+    })
+}
+
+/// Resolves a variable by hierarchical path (e.g. "u_core.cnt") to a signal
+/// handle.
+///
+/// # Safety
+/// `sim` must be open, `path` NUL-terminated and `out` writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_var(
+    sim: *mut VerylCosim,
+    path: *const c_char,
+    out: *mut u32,
+) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let path = unsafe { str_arg(path, "variable path")? };
+        let out = unsafe { out_arg(out)? };
+        let signal = sim.cosim.var(path)?;
+        *out = push(&mut sim.signals, signal);
+        Ok(())
+    })
+}
+
+/// Stores the bit width of `signal`.
+///
+/// # Safety
+/// `sim` must be open and `out` writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_width(
+    sim: *mut VerylCosim,
+    signal: u32,
+    out: *mut u32,
+) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let out = unsafe { out_arg(out)? };
+        *out = handle(&sim.signals, signal, "signal handle")?.width() as u32;
+        Ok(())
+    })
+}
+
+/// Resolves clock port `name` to a clock handle.
+///
+/// # Safety
+/// `sim` must be open, `name` NUL-terminated and `out` writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_clock(
+    sim: *mut VerylCosim,
+    name: *const c_char,
+    out: *mut u32,
+) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let name = unsafe { str_arg(name, "clock name")? };
+        let out = unsafe { out_arg(out)? };
+        let clock = sim.cosim.clock(name)?;
+        *out = push(&mut sim.clocks, clock);
+        Ok(())
+    })
+}
+
+/// Resolves reset port `name` to a reset handle.
+///
+/// # Safety
+/// `sim` must be open, `name` NUL-terminated and `out` writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_reset(
+    sim: *mut VerylCosim,
+    name: *const c_char,
+    out: *mut u32,
+) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let name = unsafe { str_arg(name, "reset name")? };
+        let out = unsafe { out_arg(out)? };
+        let reset = sim.cosim.reset(name)?;
+        *out = push(&mut sim.resets, reset);
+        Ok(())
+    })
+}
+
+/// Advances one active edge of `clock`.
+///
+/// # Safety
+/// `sim` must be open.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_step(sim: *mut VerylCosim, clock: u32) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let clock = handle(&sim.clocks, clock, "clock handle")?;
+        sim.cosim.step(&clock);
+        Ok(())
+    })
+}
+
+/// Takes one edge of every clock with `reset` asserted.
+///
+/// # Safety
+/// `sim` must be open.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_step_reset(sim: *mut VerylCosim, reset: u32) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let reset = handle(&sim.resets, reset, "reset handle")?;
+        sim.cosim.step_reset(&reset);
+        Ok(())
+    })
+}
+
+/// Writes `signal` from `nwords` LSB-first 64-bit words, truncated to its
+/// width. `mask_xz` marks X/Z bits (a set `words` bit makes Z, a clear one
+/// X) and may be null for a 2-state value.
+///
+/// # Safety
+/// `sim` must be open; `words`, and `mask_xz` unless null, must hold
+/// `nwords` words.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_set(
+    sim: *mut VerylCosim,
+    signal: u32,
+    words: *const u64,
+    mask_xz: *const u64,
+    nwords: usize,
+) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let signal = handle(&sim.signals, signal, "signal handle")?;
+        if words.is_null() {
+            return Err(argument("word array"));
+        }
+        let words = unsafe { std::slice::from_raw_parts(words, nwords) };
+        let mask_xz =
+            (!mask_xz.is_null()).then(|| unsafe { std::slice::from_raw_parts(mask_xz, nwords) });
+        sim.cosim.set(&signal, words, mask_xz);
+        Ok(())
+    })
+}
+
+/// Reads `signal` into `nwords` LSB-first 64-bit words; words above its
+/// width are zeroed. `mask_xz`, unless null, receives the X/Z bits.
+///
+/// # Safety
+/// `sim` must be open; `words`, and `mask_xz` unless null, must have room
+/// for `nwords` words.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_get(
+    sim: *mut VerylCosim,
+    signal: u32,
+    words: *mut u64,
+    mask_xz: *mut u64,
+    nwords: usize,
+) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let signal = handle(&sim.signals, signal, "signal handle")?;
+        if words.is_null() {
+            return Err(argument("word array"));
+        }
+        let words = unsafe { std::slice::from_raw_parts_mut(words, nwords) };
+        let mask_xz = (!mask_xz.is_null())
+            .then(|| unsafe { std::slice::from_raw_parts_mut(mask_xz, nwords) });
+        sim.cosim.get(&signal, words, mask_xz);
+        Ok(())
+    })
+}
+
+/// Holds `signal` at `nwords` LSB-first 64-bit words, as `veryl_cosim_set`
+/// writes them, until `veryl_cosim_release`. Forcing a comb variable
+/// requires `VERYL_COSIM_FORCE`.
+///
+/// # Safety
+/// `sim` must be open; `words`, and `mask_xz` unless null, must hold
+/// `nwords` words.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_force(
+    sim: *mut VerylCosim,
+    signal: u32,
+    words: *const u64,
+    mask_xz: *const u64,
+    nwords: usize,
+) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let signal = handle(&sim.signals, signal, "signal handle")?;
+        if words.is_null() {
+            return Err(argument("word array"));
+        }
+        let words = unsafe { std::slice::from_raw_parts(words, nwords) };
+        let mask_xz =
+            (!mask_xz.is_null()).then(|| unsafe { std::slice::from_raw_parts(mask_xz, nwords) });
+        sim.cosim.force(&signal, words, mask_xz)
+    })
+}
+
+/// Ends a `veryl_cosim_force` of `signal`. A comb variable follows its
+/// drivers again; an FF keeps the forced value until its next update.
+///
+/// # Safety
+/// `sim` must be open.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_release(sim: *mut VerylCosim, signal: u32) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let signal = handle(&sim.signals, signal, "signal handle")?;
+        sim.cosim.release(&signal)
+    })
+}
+
+/// Starts a waveform of every variable at `path`: FST for a `.fst`
+/// extension, VCD otherwise. Replaces an earlier waveform.
+///
+/// # Safety
+/// `sim` must be open and `path` NUL-terminated.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_dump_open(sim: *mut VerylCosim, path: *const c_char) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        let path = unsafe { str_arg(path, "waveform path")? };
+        sim.cosim.open_dump(Path::new(path))
+    })
+}
+
+/// Records every variable into the waveform at `time`.
+///
+/// # Safety
+/// `sim` must be open.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn veryl_cosim_dump(sim: *mut VerylCosim, time: u64) -> i32 {
+    guard(|| {
+        let sim = unsafe { sim_arg(sim)? };
+        sim.cosim.dump(time);
+        Ok(())
+    })
+}
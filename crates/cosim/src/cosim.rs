@@ -0,0 +1,178 @@
+use crate::CosimError;
+use crate::project;
+use std::path::Path;
+use veryl_simulator::ir::{Event, VarId};
+use veryl_simulator::simulator::SignalRef;
+use veryl_simulator::wave_dumper::WaveDumper;
+use veryl_simulator::{Config, Simulator};
+
+/// A simulator driven from outside: by the C API, the SystemVerilog DPI
+/// bridge or another host language.
+///
+/// The analyzer and string tables the design is resolved against are per
+/// thread, so a `Cosim` must stay on the thread that opened it.
+pub struct Cosim {
+    sim: Simulator,
+}
+
+impl Cosim {
+    /// Opens `top` from the project `path` belongs to; see
+    /// [`project::load_project`].
+    pub fn open_project(path: &Path, top: &str, four_state: bool) -> Result<Self, CosimError> {
+        Self::new(project::load_project(path, top, four_state)?, top)
+    }
+
+    /// Like [`Cosim::open_project`], with the simulator settings in
+    /// `config`; see [`project::load_project_with_config`].
+    pub fn open_project_with_config(
+        path: &Path,
+        top: &str,
+        config: Config,
+    ) -> Result<Self, CosimError> {
+        Self::new(project::load_project_with_config(path, top, config)?, top)
+    }
+
+    /// Opens `top` from a single source file; see [`project::load_file`].
+    pub fn open_file(path: &Path, top: &str, four_state: bool) -> Result<Self, CosimError> {
+        Self::new(project::load_file(path, top, four_state)?, top)
+    }
+
+    fn new(ir: veryl_simulator::ir::Ir, top: &str) -> Result<Self, CosimError> {
+        let mut sim = Simulator::new(ir, None);
+        sim.init_components(0, top)
+            .map_err(|e| CosimError::Load(e.to_string()))?;
+        Ok(Self { sim })
+    }
+
+    pub fn simulator(&mut self) -> &mut Simulator {
+        &mut self.sim
+    }
+
+    pub fn port(&self, name: &str) -> Result<SignalRef, CosimError> {
+        self.sim
+            .resolve_port(name)
+            .ok_or_else(|| not_found("port", name))
+    }
+
+    /// Resolves a variable by hierarchical path (e.g., "u_core.cnt").
+    pub fn var(&self, path: &str) -> Result<SignalRef, CosimError> {
+        self.sim
+            .resolve_var(path)
+            .ok_or_else(|| not_found("variable", path))
+    }
+
+    pub fn clock(&self, name: &str) -> Result<Event, CosimError> {
+        self.sim
+            .get_clock(name)
+            .ok_or_else(|| not_found("clock", name))
+    }
+
+    pub fn reset(&self, name: &str) -> Result<Event, CosimError> {
+        self.sim
+            .get_reset(name)
+            .ok_or_else(|| not_found("reset", name))
+    }
+
+    pub fn step(&mut self, clock: &Event) {
+        self.sim.step(clock);
+    }
+
+    /// Takes one reset step on every clock domain.
+    pub fn step_reset(&mut self, reset: &Event) {
+        let sim = &mut self.sim;
+        // A reset step is a clock edge taken with the net asserted. The caller
+        // names only the reset, so EVERY clock takes one such edge: a
+        // synchronous `if_reset` is reached by its own domain's edge and by no
+        // other.
+        let mut clocks: Vec<VarId> = sim
+            .ir
+            .event_statements
+            .keys()
+            .filter_map(|e| match e {
+                Event::Clock(id) => Some(*id),
+                _ => None,
+            })
+            .collect();
+        clocks.sort_unstable();
+        if let Some(id) = reset.var_id() {
+            sim.set_reset_level(&id, true);
+        }
+        for (i, clock) in clocks.iter().enumerate() {
+            // The assertion edge is the reset's own event, not one clock's.
+            sim.step_in_reset(&Event::Clock(*clock), reset, i == 0);
+        }
+        if let Some(id) = reset.var_id() {
+            sim.set_reset_level(&id, false);
+        }
+    }
+
+    pub fn set(&mut self, signal: &SignalRef, words: &[u64], mask_xz: Option<&[u64]>) {
+        self.sim.write_signal_words(signal, words, mask_xz);
+    }
+
+    pub fn get(&mut self, signal: &SignalRef, words: &mut [u64], mask_xz: Option<&mut [u64]>) {
+        self.sim.read_signal_words(signal, words, mask_xz);
+    }
+
+    /// Holds `signal` at `words` until [`Cosim::release`]; a comb variable
+    /// can be forced only when opened with `Config::force`.
+    pub fn force(
+        &mut self,
+        signal: &SignalRef,
+        words: &[u64],
+        mask_xz: Option<&[u64]>,
+    ) -> Result<(), CosimError> {
+        self.sim
+            .force_signal_words(signal, words, mask_xz)
+            .map_err(|e| CosimError::Argument(e.to_string()))
+    }
+
+    /// Ends a [`Cosim::force`] of `signal`.
+    pub fn release(&mut self, signal: &SignalRef) -> Result<(), CosimError> {
+        self.sim
+            .release_signal(signal)
+            .map_err(|e| CosimError::Argument(e.to_string()))
+    }
+
+    /// Starts dumping every variable to `path`: FST for a `.fst` extension,
+    /// VCD otherwise. Replaces an earlier dump.
+    pub fn open_dump(&mut self, path: &Path) -> Result<(), CosimError> {
+        self.open_dump_with_timescale(path, -6)
+    }
+
+    /// Like [`Cosim::open_dump`], with a time unit of `10^exponent` s instead
+    /// of 1us.
+    pub fn open_dump_with_timescale(
+        &mut self,
+        path: &Path,
+        exponent: i8,
+    ) -> Result<(), CosimError> {
+        let dumper = if path.extension().is_some_and(|x| x == "fst") {
+            WaveDumper::new_fst_with_timescale(&path.to_string_lossy(), exponent)
+        } else {
+            let file = std::fs::File::create(path).map_err(|e| {
+                CosimError::Io(format!(
+                    "failed to create waveform file {}: {e}",
+                    path.display()
+                ))
+            })?;
+            WaveDumper::new_vcd_with_timescale(Box::new(std::io::BufWriter::new(file)), exponent)
+        };
+        self.sim.attach_dump(dumper.with_path(path.to_path_buf()));
+        self.sim.dump_start();
+        Ok(())
+    }
+
+    /// Records every variable's value at `time`; a no-op without a dump.
+    pub fn dump(&mut self, time: u64) {
+        self.sim.time = time;
+        self.sim.dump_variables();
+    }
+}
+
+fn not_found(kind: &'static str, name: &str) -> CosimError {
+    CosimError::NotFound {
+        kind,
+        name: name.to_string(),
+    }
+}
@@ -0,0 +1,118 @@
+//! SystemVerilog DPI-C bridge declared by `sv/cosim.sv`: ports by name,
+//! values as 128-bit `logic`. DPI functions can't report failure, so errors
+//! are printed and the call does nothing; a failed `cosim_open` returns a
+//! null handle.
+
+use crate::{Cosim, CosimError};
+use std::ffi::{CStr, c_char};
+use std::path::Path;
+use veryl_analyzer::value::{SvLogicVecVal, Value};
+
+fn report<T>(result: Result<T, CosimError>) -> Option<T> {
+    result.map_err(|e| eprintln!("cosim: {e}")).ok()
+}
+
+unsafe fn str_arg<'a>(ptr: *const c_char) -> Result<&'a str, CosimError> {
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| CosimError::Argument("string is not UTF-8".to_string()))
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn cosim_open(
+    path: *const c_char,
+    top: *const c_char,
+    use_4state: bool,
+) -> *mut Cosim {
+    let cosim = report((|| {
+        let path = unsafe { str_arg(path)? };
+        let top = unsafe { str_arg(top)? };
+        Cosim::open_file(Path::new(path), top, use_4state)
+    })());
+    match cosim {
+        Some(x) => Box::into_raw(Box::new(x)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn cosim_close(handle: *mut Cosim) {
+    if !handle.is_null() {
+        drop(unsafe { Box::from_raw(handle) });
+    }
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn cosim_step_reset(handle: *mut Cosim, name: *const c_char) {
+    let Some(cosim) = (unsafe { handle.as_mut() }) else {
+        return;
+    };
+    report((|| {
+        let reset = cosim.reset(unsafe { str_arg(name)? })?;
+        cosim.step_reset(&reset);
+        Ok(())
+    })());
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn cosim_step_clock(handle: *mut Cosim, name: *const c_char) {
+    let Some(cosim) = (unsafe { handle.as_mut() }) else {
+        return;
+    };
+    report((|| {
+        let clock = cosim.clock(unsafe { str_arg(name)? })?;
+        cosim.step(&clock);
+        Ok(())
+    })());
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn cosim_set(
+    handle: *mut Cosim,
+    name: *const c_char,
+    value: &[SvLogicVecVal; 4],
+) {
+    let Some(cosim) = (unsafe { handle.as_mut() }) else {
+        return;
+    };
+    report((|| {
+        let port = cosim.port(unsafe { str_arg(name)? })?;
+        let value: Value = value.as_slice().into();
+        cosim.simulator().write_signal(&port, value);
+        Ok(())
+    })());
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn cosim_get(
+    handle: *mut Cosim,
+    name: *const c_char,
+    value: &mut [SvLogicVecVal; 4],
+) {
+    let Some(cosim) = (unsafe { handle.as_mut() }) else {
+        return;
+    };
+    let Some(ret) = report((|| {
+        let port = cosim.port(unsafe { str_arg(name)? })?;
+        Ok(cosim.simulator().read_signal(&port))
+    })()) else {
+        return;
+    };
+    let ret: Vec<SvLogicVecVal> = (&ret).into();
+
+    for (i, val) in value.iter_mut().enumerate() {
+        if let Some(x) = ret.get(i) {
+            val.aval = x.aval;
+            val.bval = x.bval;
+        } else {
+            val.aval = 0;
+            val.bval = 0;
+        }
+    }
+}
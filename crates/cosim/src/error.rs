@@ -0,0 +1,40 @@
+use crate::capi;
+use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CosimError {
+    #[error("{0}")]
+    Argument(String),
+
+    #[error("{0}")]
+    Load(String),
+
+    #[error("{kind} \"{name}\" not found")]
+    NotFound { kind: &'static str, name: String },
+
+    #[error("{0}")]
+    Io(String),
+}
+
+impl CosimError {
+    /// Load failure rendered with its source snippet, as the CLI prints it.
+    pub(crate) fn load(diagnostic: &dyn Diagnostic) -> Self {
+        let mut message = String::new();
+        let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+        if handler.render_report(&mut message, diagnostic).is_err() {
+            message = diagnostic.to_string();
+        }
+        Self::Load(message.trim_end().to_string())
+    }
+
+    /// The C API return code.
+    pub fn code(&self) -> i32 {
+        match self {
+            CosimError::Argument(_) => capi::VERYL_COSIM_ERR_ARGUMENT,
+            CosimError::Load(_) => capi::VERYL_COSIM_ERR_LOAD,
+            CosimError::NotFound { .. } => capi::VERYL_COSIM_ERR_NOT_FOUND,
+            CosimError::Io(_) => capi::VERYL_COSIM_ERR_IO,
+        }
+    }
+}
@@ -1,145 +1,21 @@
-use std::ffi::{CStr, c_char};
-use std::ptr::NonNull;
-use veryl_analyzer::ir as air;
-use veryl_analyzer::value::{SvLogicVecVal, Value};
-use veryl_analyzer::{Analyzer, Context};
-use veryl_metadata::Metadata;
-use veryl_parser::Parser;
-use veryl_simulator::ir as sir;
-use veryl_simulator::ir::{Event, VarId};
-use veryl_simulator::{Config, Simulator};
-
-fn build_ir(code: &str, top: &str, config: &Config) -> sir::Ir {
-    let metadata = Metadata::create_default("prj").unwrap();
-    let parser = Parser::parse(code, &"").unwrap();
-    let analyzer = Analyzer::new(&metadata);
-    let mut context = Context::default();
-
-    let mut ir = air::Ir::default();
-    analyzer.analyze_pass1("prj", &parser.veryl);
-    Analyzer::analyze_post_pass1();
-    analyzer.analyze_pass2(&parser.veryl, &mut context, Some(&mut ir));
-
-    sir::build_ir(&ir, top.into(), config).expect("Failed to build IR")
-}
-
-#[unsafe(no_mangle)]
-#[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn cosim_open(
-    path: *const c_char,
-    top: *const c_char,
-    use_4state: bool,
-) -> NonNull<Simulator> {
-    let path = unsafe { CStr::from_ptr(path) };
-    let path = path.to_str().unwrap();
-    let code = std::fs::read_to_string(path).unwrap();
-
-    let top = unsafe { CStr::from_ptr(top) };
-    let top = top.to_str().unwrap();
-
-    let config = Config {
-        use_4state,
-        ..Default::default()
-    };
-
-    let ir = build_ir(&code, top, &config);
-
-    let sim = Box::new(Simulator::new(ir, None));
-    let sim = Box::<Simulator>::into_raw(sim);
-    NonNull::new(sim).unwrap()
-}
-
-#[unsafe(no_mangle)]
-#[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn cosim_close(handle: NonNull<Simulator>) {
-    let _sim = unsafe { Box::<Simulator>::from_raw(handle.as_ptr()) };
-}
-
-#[unsafe(no_mangle)]
-#[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn cosim_step_reset(handle: NonNull<Simulator>, name: *const c_char) {
-    let sim = unsafe { &mut *handle.as_ptr() };
-
-    let name = unsafe { CStr::from_ptr(name) };
-    let name = name.to_str().unwrap();
-
-    let reset = sim.get_reset(name).unwrap();
-    // A reset step is a clock edge taken with the net asserted.  The DPI entry
-    // names only the reset, so EVERY clock takes one such edge: a synchronous
-    // `if_reset` is reached by its own domain's edge and by no other.
-    let mut clocks: Vec<VarId> = sim
-        .ir
-        .event_statements
-        .keys()
-        .filter_map(|e| match e {
-            Event::Clock(id) => Some(*id),
-            _ => None,
-        })
-        .collect();
-    clocks.sort_unstable();
-    if let Some(id) = reset.var_id() {
-        sim.set_reset_level(&id, true);
-    }
-    for (i, clock) in clocks.iter().enumerate() {
-        // The assertion edge is the reset's own event, not one clock's.
-        sim.step_in_reset(&Event::Clock(*clock), &reset, i == 0);
-    }
-    if let Some(id) = reset.var_id() {
-        sim.set_reset_level(&id, false);
-    }
-}
-
-#[unsafe(no_mangle)]
-#[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn cosim_step_clock(handle: NonNull<Simulator>, name: *const c_char) {
-    let sim = unsafe { &mut *handle.as_ptr() };
-
-    let name = unsafe { CStr::from_ptr(name) };
-    let name = name.to_str().unwrap();
-
-    let reset = sim.get_clock(name).unwrap();
-    sim.step(&reset);
-}
-
-#[unsafe(no_mangle)]
-#[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn cosim_set(
-    handle: NonNull<Simulator>,
-    name: *const c_char,
-    value: &[SvLogicVecVal; 4],
-) {
-    let sim = unsafe { &mut *handle.as_ptr() };
-
-    let name = unsafe { CStr::from_ptr(name) };
-    let name = name.to_str().unwrap();
-
-    let value: Value = value.as_slice().into();
-
-    sim.set(name, value);
-}
-
-#[unsafe(no_mangle)]
-#[allow(clippy::missing_safety_doc)]
-pub unsafe extern "C" fn cosim_get(
-    handle: NonNull<Simulator>,
-    name: *const c_char,
-    value: &mut [SvLogicVecVal; 4],
-) {
-    let sim = unsafe { &mut *handle.as_ptr() };
-
-    let name = unsafe { CStr::from_ptr(name) };
-    let name = name.to_str().unwrap();
-
-    let ret = sim.get(name).unwrap();
-    let ret: Vec<SvLogicVecVal> = (&ret).into();
-
-    for (i, val) in value.iter_mut().enumerate() {
-        if let Some(x) = ret.get(i) {
-            val.aval = x.aval;
-            val.bval = x.bval;
-        } else {
-            val.aval = 0;
-            val.bval = 0;
-        }
-    }
-}
+//! Drives the Veryl simulator from other languages and simulators.
+//!
+//! [`capi`] is the stable C API (`include/veryl_cosim.h`); [`dpi`] is the
+//! SystemVerilog DPI-C bridge; [`vpi`] hosts VPI applications such as
+//! cocotb. All build on [`Cosim`].
+
+pub mod capi;
+mod cosim;
+pub mod dpi;
+mod error;
+pub mod project;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+#[cfg(unix)]
+pub mod vpi;
+
+pub use cosim::Cosim;
+pub use error::CosimError;
+
+#[cfg(test)]
+mod tests;
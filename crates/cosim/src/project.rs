@@ -0,0 +1,134 @@
+//! Design loading: the analysis `veryl build` runs over a project, stopped
+//! at the simulator IR.
+
+use crate::CosimError;
+use std::path::{Path, PathBuf};
+use veryl_analyzer::ir as air;
+use veryl_analyzer::{Analyzer, AnalyzerError, Context};
+use veryl_metadata::{Metadata, ResetType};
+use veryl_parser::Parser;
+use veryl_simulator::ir as sir;
+use veryl_simulator::{Config, SimulatorError};
+
+struct Source {
+    prj: String,
+    path: PathBuf,
+    text: String,
+}
+
+/// Loads the project `path` belongs to: a `Veryl.toml`, or any file or
+/// directory inside the project. Dependencies and the standard library are
+/// analyzed along with the project sources.
+pub fn load_project(path: &Path, top: &str, four_state: bool) -> Result<sir::Ir, CosimError> {
+    let config = Config {
+        use_4state: four_state,
+        ..Config::default()
+    };
+    load_project_with_config(path, top, config)
+}
+
+/// Like [`load_project`], with the simulator settings in `config`; the reset
+/// polarity and synchronicity still come from the project's `Veryl.toml`.
+pub fn load_project_with_config(
+    path: &Path,
+    top: &str,
+    config: Config,
+) -> Result<sir::Ir, CosimError> {
+    let toml = if path.file_name().is_some_and(|x| x == "Veryl.toml") {
+        path.to_path_buf()
+    } else {
+        Metadata::search_from(path).map_err(|e| CosimError::load(&e))?
+    };
+    let mut metadata = Metadata::load(&toml).map_err(|e| CosimError::load(&e))?;
+    let paths = metadata
+        .paths::<&Path>(&[], true, true)
+        .map_err(|e| CosimError::load(&e))?;
+
+    let mut sources = Vec::new();
+    for path in paths {
+        let text = std::fs::read_to_string(&path.src)
+            .map_err(|e| CosimError::Io(format!("{}: {e}", path.src.display())))?;
+        sources.push(Source {
+            prj: path.prj,
+            path: path.src,
+            text,
+        });
+    }
+
+    let config = Config {
+        abstract_reset_active_high: matches!(
+            metadata.build.reset_type,
+            ResetType::AsyncHigh | ResetType::SyncHigh
+        ),
+        abstract_reset_sync: matches!(
+            metadata.build.reset_type,
+            ResetType::SyncHigh | ResetType::SyncLow
+        ),
+        ..config
+    };
+    analyze(&metadata, &sources, top, &config)
+}
+
+/// Loads a single source file as a project of its own, without
+/// dependencies or the standard library.
+pub fn load_file(path: &Path, top: &str, four_state: bool) -> Result<sir::Ir, CosimError> {
+    let metadata = Metadata::create_default("prj").map_err(|e| CosimError::load(&e))?;
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| CosimError::Io(format!("{}: {e}", path.display())))?;
+    let sources = [Source {
+        prj: "prj".to_string(),
+        path: path.to_path_buf(),
+        text,
+    }];
+    let config = Config {
+        use_4state: four_state,
+        ..Config::default()
+    };
+    analyze(&metadata, &sources, top, &config)
+}
+
+fn check(errors: Vec<AnalyzerError>) -> Result<(), CosimError> {
+    match errors.iter().find(|x| x.is_error()) {
+        Some(x) => Err(CosimError::load(x)),
+        None => Ok(()),
+    }
+}
+
+fn analyze(
+    metadata: &Metadata,
+    sources: &[Source],
+    top: &str,
+    config: &Config,
+) -> Result<sir::Ir, CosimError> {
+    // The analyzer tables are per thread; drop whatever an earlier load left.
+    let analyzer = Analyzer::new(metadata);
+    analyzer.clear();
+
+    let mut parsers = Vec::new();
+    let mut errors = Vec::new();
+    for source in sources {
+        let parser = Parser::parse(&source.text, &source.path).map_err(|e| CosimError::load(&e))?;
+        errors.append(&mut analyzer.analyze_pass1(&source.prj, &parser.veryl));
+        parsers.push(parser);
+    }
+    errors.append(&mut Analyzer::analyze_post_pass1());
+    check(errors)?;
+
+    let mut context = Context::default();
+    let mut ir = air::Ir::default();
+    let mut errors = Vec::new();
+    for (source, parser) in sources.iter().zip(&parsers) {
+        context.set_project_name(&source.prj);
+        errors.append(&mut analyzer.analyze_pass2(&parser.veryl, &mut context, Some(&mut ir)));
+    }
+    errors.append(&mut Analyzer::analyze_post_pass2(&ir));
+    check(errors)?;
+
+    sir::build_ir(&ir, top.into(), config).map_err(|e| match e {
+        SimulatorError::TopModuleNotFound { module_name } => CosimError::NotFound {
+            kind: "module",
+            name: module_name,
+        },
+        e => CosimError::load(&e),
+    })
+}
@@ -0,0 +1,27 @@
+//! Scratch projects for tests, enabled by the `test-utils` feature.
+
+use tempfile::TempDir;
+
+/// A project in a temporary directory whose only source, `src/top.veryl`,
+/// is `top`.
+pub fn project(top: &str) -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("Veryl.toml"),
+        r#"
+[project]
+name = "test_project"
+version = "0.1.0"
+
+[build]
+clock_type = "posedge"
+reset_type = "async_low"
+sources = ["src"]
+target = {type = "directory", path = "target"}
+"#,
+    )
+    .unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/top.veryl"), top).unwrap();
+    dir
+}
@@ -0,0 +1,252 @@
+use crate::capi::*;
+use crate::test_utils::project;
+use std::ffi::{CStr, CString};
+use std::path::Path;
+use std::ptr::null_mut;
+
+fn c(x: &str) -> CString {
+    CString::new(x).unwrap()
+}
+
+fn last_error() -> String {
+    unsafe { CStr::from_ptr(veryl_cosim_last_error()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn open(path: &Path, top: &str, flags: u32) -> *mut VerylCosim {
+    let path = c(path.to_str().unwrap());
+    let mut sim = null_mut();
+    let ret = unsafe { veryl_cosim_open(path.as_ptr(), c(top).as_ptr(), flags, &mut sim) };
+    assert_eq!(ret, VERYL_COSIM_OK, "{}", last_error());
+    sim
+}
+
+fn handle(
+    f: unsafe extern "C" fn(*mut VerylCosim, *const std::ffi::c_char, *mut u32) -> i32,
+    sim: *mut VerylCosim,
+    name: &str,
+) -> u32 {
+    let mut h = 0;
+    let ret = unsafe { f(sim, c(name).as_ptr(), &mut h) };
+    assert_eq!(ret, VERYL_COSIM_OK, "{}", last_error());
+    h
+}
+
+const COUNTER: &str = r#"
+module Sub (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    o_cnt: output logic<8>,
+) {
+    var cnt: logic<8>;
+    always_ff {
+        if_reset {
+            cnt = 0;
+        } else {
+            cnt += 1;
+        }
+    }
+    assign o_cnt = cnt;
+}
+
+module Top (
+    i_clk : input  clock,
+    i_rst : input  reset,
+    i_wide: input  logic<100>,
+    o_wide: output logic<100>,
+    o_cnt : output logic<8>,
+) {
+    inst u_sub: Sub (
+        i_clk,
+        i_rst,
+        o_cnt,
+    );
+    assign o_wide = i_wide;
+}
+"#;
+
+#[test]
+fn project_ports_and_hierarchy() {
+    let dir = project(COUNTER);
+    let sim = open(dir.path(), "Top", 0);
+
+    let clk = handle(veryl_cosim_clock, sim, "i_clk");
+    let rst = handle(veryl_cosim_reset, sim, "i_rst");
+    let cnt = handle(veryl_cosim_var, sim, "u_sub.cnt");
+    let i_wide = handle(veryl_cosim_port, sim, "i_wide");
+    let o_wide = handle(veryl_cosim_port, sim, "o_wide");
+
+    let mut width = 0;
+    assert_eq!(
+        unsafe { veryl_cosim_width(sim, o_wide, &mut width) },
+        VERYL_COSIM_OK
+    );
+    assert_eq!(width, 100);
+
+    unsafe {
+        assert_eq!(veryl_cosim_step_reset(sim, rst), VERYL_COSIM_OK);
+        for _ in 0..3 {
+            assert_eq!(veryl_cosim_step(sim, clk), VERYL_COSIM_OK);
+        }
+    }
+    let mut words = [0u64; 2];
+    let ret = unsafe { veryl_cosim_get(sim, cnt, words.as_mut_ptr(), null_mut(), 2) };
+    assert_eq!(ret, VERYL_COSIM_OK);
+    assert_eq!(words, [3, 0]);
+
+    // Wide values span words; bits above the width are dropped.
+    let input = [0x0123_4567_89ab_cdef, u64::MAX];
+    unsafe {
+        veryl_cosim_set(sim, i_wide, input.as_ptr(), std::ptr::null(), 2);
+        veryl_cosim_get(sim, o_wide, words.as_mut_ptr(), null_mut(), 2);
+    }
+    assert_eq!(words, [0x0123_4567_89ab_cdef, 0xf_ffff_ffff]);
+
+    unsafe { veryl_cosim_close(sim) };
+}
+
+#[test]
+fn four_state_values() {
+    let dir = project(COUNTER);
+    let sim = open(
+        &dir.path().join("Veryl.toml"),
+        "Top",
+        VERYL_COSIM_FOUR_STATE,
+    );
+    let i_wide = handle(veryl_cosim_port, sim, "i_wide");
+    let o_wide = handle(veryl_cosim_port, sim, "o_wide");
+
+    // Bit 0 is X, bit 65 is Z.
+    let words = [0b10, 1 << 1];
+    let mask_xz = [0b01, 1 << 1];
+    let mut out = [0u64; 2];
+    let mut out_mask = [0u64; 2];
+    unsafe {
+        veryl_cosim_set(sim, i_wide, words.as_ptr(), mask_xz.as_ptr(), 2);
+        veryl_cosim_get(sim, o_wide, out.as_mut_ptr(), out_mask.as_mut_ptr(), 2);
+        veryl_cosim_close(sim);
+    }
+    assert_eq!(out, words);
+    assert_eq!(out_mask, mask_xz);
+}
+
+#[test]
+fn force_and_release() {
+    let dir = project(COUNTER);
+    let sim = open(dir.path(), "Top", VERYL_COSIM_FORCE);
+    let clk = handle(veryl_cosim_clock, sim, "i_clk");
+    let rst = handle(veryl_cosim_reset, sim, "i_rst");
+    let cnt = handle(veryl_cosim_var, sim, "u_sub.cnt");
+    let o_cnt = handle(veryl_cosim_port, sim, "o_cnt");
+    let i_wide = handle(veryl_cosim_port, sim, "i_wide");
+    let o_wide = handle(veryl_cosim_port, sim, "o_wide");
+    let get = |signal| {
+        let mut words = [0u64; 2];
+        unsafe { veryl_cosim_get(sim, signal, words.as_mut_ptr(), null_mut(), 2) };
+        words
+    };
+
+    // A forced comb variable ignores its driver until released.
+    let forced = [0x55, 1];
+    let input = [7, 0];
+    unsafe {
+        let ret = veryl_cosim_force(sim, o_wide, forced.as_ptr(), std::ptr::null(), 2);
+        assert_eq!(ret, VERYL_COSIM_OK, "{}", last_error());
+        veryl_cosim_set(sim, i_wide, input.as_ptr(), std::ptr::null(), 2);
+    }
+    assert_eq!(get(o_wide), forced);
+    assert_eq!(unsafe { veryl_cosim_release(sim, o_wide) }, VERYL_COSIM_OK);
+    assert_eq!(get(o_wide), input);
+
+    // A forced FF holds across clock edges and counts on once released.
+    let value = [100u64];
+    unsafe {
+        veryl_cosim_step_reset(sim, rst);
+        veryl_cosim_force(sim, cnt, value.as_ptr(), std::ptr::null(), 1);
+        veryl_cosim_step(sim, clk);
+        veryl_cosim_step(sim, clk);
+    }
+    assert_eq!(get(o_cnt), [100, 0]);
+    unsafe {
+        veryl_cosim_release(sim, cnt);
+        veryl_cosim_step(sim, clk);
+    }
+    assert_eq!(get(o_cnt), [101, 0]);
+    unsafe { veryl_cosim_close(sim) };
+
+    // Without `VERYL_COSIM_FORCE` only FFs can be forced.
+    let sim = open(dir.path(), "Top", 0);
+    let o_wide = handle(veryl_cosim_port, sim, "o_wide");
+    let ret = unsafe { veryl_cosim_force(sim, o_wide, forced.as_ptr(), std::ptr::null(), 2) };
+    assert_eq!(ret, VERYL_COSIM_ERR_ARGUMENT);
+    assert!(last_error().contains("o_wide"), "{}", last_error());
+    unsafe { veryl_cosim_close(sim) };
+}
+
+#[test]
+fn errors_are_return_codes() {
+    let dir = project(COUNTER);
+
+    let mut sim = null_mut();
+    let path = c(dir.path().to_str().unwrap());
+    let ret = unsafe { veryl_cosim_open(path.as_ptr(), c("Missing").as_ptr(), 0, &mut sim) };
+    assert_eq!(ret, VERYL_COSIM_ERR_NOT_FOUND);
+    assert!(last_error().contains("Missing"));
+    assert!(sim.is_null());
+
+    let broken = project("module Top { var a: logic; assign a = b; }");
+    let path = c(broken.path().to_str().unwrap());
+    let ret = unsafe { veryl_cosim_open(path.as_ptr(), c("Top").as_ptr(), 0, &mut sim) };
+    assert_eq!(ret, VERYL_COSIM_ERR_LOAD);
+    assert!(last_error().contains('b'));
+
+    let sim = open(dir.path(), "Top", 0);
+    let mut h = 0;
+    let ret = unsafe { veryl_cosim_port(sim, c("nope").as_ptr(), &mut h) };
+    assert_eq!(ret, VERYL_COSIM_ERR_NOT_FOUND);
+    assert_eq!(last_error(), "port \"nope\" not found");
+    let ret = unsafe { veryl_cosim_step(sim, 7) };
+    assert_eq!(ret, VERYL_COSIM_ERR_ARGUMENT);
+    let ret = unsafe { veryl_cosim_port(sim, std::ptr::null(), &mut h) };
+    assert_eq!(ret, VERYL_COSIM_ERR_ARGUMENT);
+    unsafe { veryl_cosim_close(sim) };
+}
+
+#[test]
+fn waveform_records_dumped_times() {
+    let dir = project(COUNTER);
+    let sim = open(dir.path(), "Top", 0);
+    let clk = handle(veryl_cosim_clock, sim, "i_clk");
+    let rst = handle(veryl_cosim_reset, sim, "i_rst");
+    let vcd = dir.path().join("wave.vcd");
+
+    unsafe {
+        let path = c(vcd.to_str().unwrap());
+        assert_eq!(veryl_cosim_dump_open(sim, path.as_ptr()), VERYL_COSIM_OK);
+        veryl_cosim_step_reset(sim, rst);
+        for time in 1..=4 {
+            veryl_cosim_step(sim, clk);
+            assert_eq!(veryl_cosim_dump(sim, time * 10), VERYL_COSIM_OK);
+        }
+        veryl_cosim_close(sim);
+    }
+    let vcd = std::fs::read_to_string(vcd).unwrap();
+    assert!(vcd.contains("$var"));
+    assert!(vcd.contains("#40"));
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/veryl_cosim.h"));
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/veryl_cosim.h");
+    if std::env::var_os("VERYL_COSIM_UPDATE_HEADER").is_some() {
+        std::fs::write(&path, generated).unwrap();
+    }
+    let checked_in = std::fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        checked_in == generated,
+        "include/veryl_cosim.h is out of date; \
+         rerun with VERYL_COSIM_UPDATE_HEADER=1 to refresh it"
+    );
+}
@@ -0,0 +1,1487 @@
+//! IEEE 1364 VPI on top of [`Cosim`], enough of it for cocotb's GPI to drive
+//! the native simulator: the design hierarchy as modules, nets (top-level
+//! ports) and regs, value get/put in the usual formats, and the time,
+//! value-change and synchronization callbacks.
+//!
+//! [`run`] loads a VPI application (cocotb's `libcocotbvpi_icarus.so`), calls
+//! its startup routines and simulates until nothing is scheduled or it calls
+//! `vpi_control(vpiFinish)`. The application resolves the `vpi_*` functions
+//! against the host executable, so the host has to export them.
+//!
+//! The native simulator is cycle based: a write taking a clock port to its
+//! active level steps the design one edge, and a write asserting an
+//! asynchronous reset fires it. Value-change callbacks see the write before
+//! the edge is taken, so a `RisingEdge` reads the flops' old values as on an
+//! event-driven simulator. Time is in picoseconds. Arrays, bit selects and
+//! delayed writes are not supported.
+
+use crate::{Cosim, CosimError};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::{CStr, CString, c_char, c_int, c_void};
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+use veryl_analyzer::ir::TypeKind;
+use veryl_simulator::ir::{Event, VarId};
+use veryl_simulator::simulator::SignalRef;
+
+pub type Handle = *mut c_void;
+
+const VPI_CONSTANT: i32 = 7;
+const VPI_ITERATOR: i32 = 27;
+const VPI_MODULE: i32 = 32;
+const VPI_NET: i32 = 36;
+const VPI_REG: i32 = 48;
+const VPI_USER_SYSTF: i32 = 67;
+const VPI_CALLBACK: i32 = 107;
+
+const VPI_UNDEFINED: i32 = -1;
+const VPI_TYPE: i32 = 1;
+const VPI_NAME: i32 = 2;
+const VPI_FULL_NAME: i32 = 3;
+const VPI_SIZE: i32 = 4;
+const VPI_TOP_MODULE: i32 = 7;
+const VPI_DEF_NAME: i32 = 9;
+const VPI_TIME_UNIT: i32 = 11;
+const VPI_TIME_PRECISION: i32 = 12;
+const VPI_SCALAR: i32 = 17;
+const VPI_VECTOR: i32 = 18;
+const VPI_CONST_TYPE: i32 = 40;
+const VPI_SIGNED: i32 = 65;
+const VPI_LEFT_RANGE: i32 = 79;
+const VPI_RIGHT_RANGE: i32 = 83;
+const VPI_SCOPE: i32 = 84;
+const VPI_DEC_CONST: i32 = 1;
+
+const VPI_BIN_STR_VAL: i32 = 1;
+const VPI_OCT_STR_VAL: i32 = 2;
+const VPI_DEC_STR_VAL: i32 = 3;
+const VPI_HEX_STR_VAL: i32 = 4;
+const VPI_SCALAR_VAL: i32 = 5;
+const VPI_INT_VAL: i32 = 6;
+const VPI_REAL_VAL: i32 = 7;
+const VPI_STRING_VAL: i32 = 8;
+const VPI_VECTOR_VAL: i32 = 9;
+const VPI_OBJ_TYPE_VAL: i32 = 12;
+const VPI_SUPPRESS_VAL: i32 = 13;
+
+const VPI_0: i32 = 0;
+const VPI_1: i32 = 1;
+const VPI_Z: i32 = 2;
+const VPI_X: i32 = 3;
+const VPI_H: i32 = 4;
+const VPI_L: i32 = 5;
+
+const VPI_SCALED_REAL_TIME: i32 = 1;
+const VPI_SUPPRESS_TIME: i32 = 3;
+
+const VPI_FORCE_FLAG: i32 = 5;
+const VPI_RELEASE_FLAG: i32 = 6;
+
+const CB_VALUE_CHANGE: i32 = 1;
+const CB_AT_START_OF_SIM_TIME: i32 = 5;
+const CB_READ_WRITE_SYNCH: i32 = 6;
+const CB_READ_ONLY_SYNCH: i32 = 7;
+const CB_NEXT_SIM_TIME: i32 = 8;
+const CB_AFTER_DELAY: i32 = 9;
+const CB_END_OF_COMPILE: i32 = 10;
+const CB_START_OF_SIMULATION: i32 = 11;
+const CB_END_OF_SIMULATION: i32 = 12;
+
+const VPI_STOP: i32 = 66;
+const VPI_FINISH: i32 = 67;
+
+const VPI_RUN: i32 = 3;
+const VPI_ERROR: i32 = 3;
+
+/// Delta cycles a time step may take before it is cut off as oscillating.
+const MAX_DELTAS: usize = 10_000;
+
+#[repr(C)]
+pub struct VpiTime {
+    pub r#type: i32,
+    pub high: u32,
+    pub low: u32,
+    pub real: f64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VpiVecVal {
+    pub aval: i32,
+    pub bval: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union VpiValueUnion {
+    pub str: *mut c_char,
+    pub scalar: i32,
+    pub integer: i32,
+    pub real: f64,
+    pub time: *mut VpiTime,
+    pub vector: *mut VpiVecVal,
+    pub misc: *mut c_char,
+}
+
+#[repr(C)]
+pub struct VpiValue {
+    pub format: i32,
+    pub value: VpiValueUnion,
+}
+
+pub type CbRoutine = unsafe extern "C" fn(*mut CbData) -> i32;
+
+#[repr(C)]
+pub struct CbData {
+    pub reason: i32,
+    pub cb_rtn: Option<CbRoutine>,
+    pub obj: Handle,
+    pub time: *mut VpiTime,
+    pub value: *mut VpiValue,
+    pub index: i32,
+    pub user_data: *mut c_char,
+}
+
+#[repr(C)]
+pub struct VlogInfo {
+    pub argc: i32,
+    pub argv: *mut *mut c_char,
+    pub product: *mut c_char,
+    pub version: *mut c_char,
+}
+
+#[repr(C)]
+pub struct ErrorInfo {
+    pub state: i32,
+    pub level: i32,
+    pub message: *mut c_char,
+    pub product: *mut c_char,
+    pub code: *mut c_char,
+    pub file: *mut c_char,
+    pub line: i32,
+}
+
+/// How [`run`] hosts a VPI application.
+#[derive(Clone, Debug, Default)]
+pub struct VpiConfig {
+    /// The VPI application, e.g. cocotb's `libcocotbvpi_icarus.so`.
+    pub library: PathBuf,
+    /// Name of the top module in the VPI hierarchy.
+    pub toplevel: String,
+    /// Whether a plain `clock` is active on its falling edge
+    /// (`[build] clock_type = "negedge"`).
+    pub negedge_clock: bool,
+    /// `argv` reported by `vpi_get_vlog_info`, plusargs included.
+    pub args: Vec<String>,
+    /// Waveform to dump, FST for a `.fst` extension and VCD otherwise.
+    pub wave: Option<PathBuf>,
+}
+
+/// Simulates `cosim` under the VPI application `config.library` until it
+/// finishes the simulation or schedules nothing more.
+pub fn run(mut cosim: Cosim, config: &VpiConfig) -> Result<(), CosimError> {
+    if let Some(path) = &config.wave {
+        cosim.open_dump_with_timescale(path, -12)?;
+    }
+    let kernel = Kernel::new(cosim, config);
+    KERNEL.with(|k| *k.borrow_mut() = Some(kernel));
+
+    let ret = unsafe { start(config) }.map(|_| {
+        fire_queued(CB_END_OF_COMPILE);
+        fire_queued(CB_START_OF_SIMULATION);
+        simulate();
+        fire_queued(CB_END_OF_SIMULATION);
+    });
+
+    KERNEL.with(|k| k.borrow_mut().take());
+    ret
+}
+
+unsafe fn start(config: &VpiConfig) -> Result<(), CosimError> {
+    let path = CString::new(config.library.as_os_str().as_bytes())
+        .map_err(|_| CosimError::Argument("library path contains NUL".to_string()))?;
+    let lib = unsafe { libc::dlopen(path.as_ptr(), libc::RTLD_LAZY | libc::RTLD_GLOBAL) };
+    if lib.is_null() {
+        let message = unsafe { CStr::from_ptr(libc::dlerror()) };
+        return Err(CosimError::Load(message.to_string_lossy().into_owned()));
+    }
+
+    let bootstrap = unsafe { libc::dlsym(lib, c"vlog_startup_routines_bootstrap".as_ptr()) };
+    if !bootstrap.is_null() {
+        let bootstrap: extern "C" fn() = unsafe { std::mem::transmute(bootstrap) };
+        bootstrap();
+        return Ok(());
+    }
+
+    let routines = unsafe { libc::dlsym(lib, c"vlog_startup_routines".as_ptr()) };
+    if routines.is_null() {
+        return Err(CosimError::Load(format!(
+            "{} has no vlog_startup_routines",
+            config.library.display()
+        )));
+    }
+    let mut routine = routines as *const Option<extern "C" fn()>;
+    while let Some(f) = unsafe { *routine } {
+        f();
+        routine = unsafe { routine.add(1) };
+    }
+    Ok(())
+}
+
+fn simulate() {
+    loop {
+        // Active events until the time step is quiet, then read-only.
+        loop {
+            for cb in call(Kernel::take_due).unwrap_or_default() {
+                fire(cb);
+            }
+            settle();
+            let read_write = call(|k| k.take_queued(CB_READ_WRITE_SYNCH)).unwrap_or_default();
+            if read_write.is_empty() && !call(|k| k.has_due()).unwrap_or(false) {
+                break;
+            }
+            for cb in read_write {
+                fire(cb);
+            }
+            settle();
+            if finished() {
+                return;
+            }
+        }
+        call(Kernel::dump);
+        while let Some(read_only) = call(|k| k.take_queued(CB_READ_ONLY_SYNCH))
+            && !read_only.is_empty()
+        {
+            for cb in read_only {
+                fire(cb);
+            }
+        }
+        if finished() {
+            return;
+        }
+        match call(Kernel::advance).flatten() {
+            Some(true) => fire_queued(CB_NEXT_SIM_TIME),
+            Some(false) => {}
+            None => return,
+        }
+    }
+}
+
+/// Runs value-change callbacks and takes the edges written so far until
+/// neither is left.
+fn settle() {
+    for _ in 0..MAX_DELTAS {
+        let changed = call(Kernel::changed).unwrap_or_default();
+        for &cb in &changed {
+            fire(cb);
+        }
+        let stepped = call(Kernel::step).unwrap_or(false);
+        if changed.is_empty() && !stepped {
+            return;
+        }
+    }
+    eprintln!("vpi: time step did not settle after {MAX_DELTAS} delta cycles");
+}
+
+fn finished() -> bool {
+    call(|k| k.finished).unwrap_or(true)
+}
+
+fn fire_queued(reason: i32) {
+    for cb in call(|k| k.take_queued(reason)).unwrap_or_default() {
+        fire(cb);
+    }
+}
+
+/// Calls callback `cb`, unless it was removed in the meantime. The kernel is
+/// not borrowed during the call, so the routine may call back into VPI.
+fn fire(cb: usize) {
+    let Some(Some(mut fire)) = call(|k| k.prepare(cb)) else {
+        return;
+    };
+    fire.data.time = &mut fire.time;
+    if fire.has_value {
+        fire.storage.store(&mut fire.value);
+        fire.data.value = &mut fire.value;
+    }
+    unsafe { (fire.routine)(&mut fire.data) };
+}
+
+struct Fire {
+    routine: CbRoutine,
+    data: CbData,
+    time: VpiTime,
+    has_value: bool,
+    value: VpiValue,
+    storage: Formatted,
+}
+
+thread_local! {
+    static KERNEL: RefCell<Option<Kernel>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` on the running kernel with the error status reset, as every VPI
+/// call but `vpi_chk_error` does.
+fn call<R>(f: impl FnOnce(&mut Kernel) -> R) -> Option<R> {
+    KERNEL.with(|k| {
+        k.borrow_mut().as_mut().map(|k| {
+            k.error = None;
+            f(k)
+        })
+    })
+}
+
+fn handle(index: usize) -> Handle {
+    (index + 1) as Handle
+}
+
+fn index(handle: Handle) -> Option<usize> {
+    (handle as usize).checked_sub(1)
+}
+
+unsafe fn str_arg<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+    }
+}
+
+enum Object {
+    Scope(usize),
+    Signal(usize),
+    Const(i32),
+    Iterator { items: Vec<Handle>, next: usize },
+    Callback(usize),
+    Systf,
+    Freed,
+}
+
+struct Scope {
+    name: CString,
+    full_name: CString,
+    def_name: CString,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    signals: Vec<usize>,
+    handle: Handle,
+}
+
+struct Signal {
+    name: CString,
+    full_name: CString,
+    scope: usize,
+    signal: SignalRef,
+    port: bool,
+    signed: bool,
+    edge: Option<Edge>,
+    handle: Handle,
+}
+
+/// What writing a top-level clock or reset port drives besides its value.
+#[derive(Clone, Copy)]
+enum Edge {
+    Clock { id: VarId, active_high: bool },
+    AsyncReset { id: VarId, active_high: bool },
+}
+
+struct Callback {
+    reason: i32,
+    routine: Option<CbRoutine>,
+    obj: Handle,
+    user_data: *mut c_char,
+    time_type: i32,
+    value_format: Option<i32>,
+    /// The value a `cbValueChange` last reported.
+    last: Option<Bits>,
+    timer: Option<(u64, u64)>,
+    live: bool,
+}
+
+struct Kernel {
+    cosim: Cosim,
+    time: u64,
+    objects: Vec<Object>,
+    scopes: Vec<Scope>,
+    signals: Vec<Signal>,
+    constants: HashMap<i32, Handle>,
+    callbacks: Vec<Callback>,
+    /// `cbAfterDelay` and `cbAtStartOfSimTime`, by time and registration.
+    timers: BTreeMap<(u64, u64), usize>,
+    seq: u64,
+    watches: Vec<usize>,
+    queued: HashMap<i32, Vec<usize>>,
+    /// Clock and reset events written but not yet taken.
+    edges: Vec<Event>,
+    undumped: bool,
+    finished: bool,
+    error: Option<CString>,
+    /// What the last `vpi_get_value` result points to.
+    returned: Formatted,
+    args: Vec<CString>,
+    argv: Vec<*mut c_char>,
+    product: CString,
+    version: CString,
+}
+
+impl Kernel {
+    fn new(cosim: Cosim, config: &VpiConfig) -> Self {
+        let mut kernel = Self {
+            cosim,
+            time: 0,
+            objects: Vec::new(),
+            scopes: Vec::new(),
+            signals: Vec::new(),
+            constants: HashMap::new(),
+            callbacks: Vec::new(),
+            timers: BTreeMap::new(),
+            seq: 0,
+            watches: Vec::new(),
+            queued: HashMap::new(),
+            edges: Vec::new(),
+            undumped: false,
+            finished: false,
+            error: None,
+            returned: Formatted::None,
+            args: Vec::new(),
+            argv: Vec::new(),
+            product: c"Veryl".to_owned(),
+            version: CString::new(env!("CARGO_PKG_VERSION")).unwrap(),
+        };
+
+        let args = std::iter::once("veryl".to_string()).chain(config.args.iter().cloned());
+        kernel.args = args.filter_map(|x| CString::new(x).ok()).collect();
+        kernel.argv = kernel.args.iter().map(|x| x.as_ptr() as *mut _).collect();
+
+        let sim = kernel.cosim.simulator();
+        let ir = &sim.ir;
+        let mut scopes = Vec::new();
+        let mut signals = Vec::new();
+        let mut objects = Vec::new();
+        let ports: HashMap<VarId, ()> = ir.ports.values().map(|x| (*x, ())).collect();
+        let mut stack = vec![(&ir.module_variables, None::<usize>, config.toplevel.clone())];
+        while let Some((module, parent, full_name)) = stack.pop() {
+            let scope = scopes.len();
+            let name = match parent {
+                Some(_) => module.name.to_string(),
+                None => config.toplevel.clone(),
+            };
+            objects.push(Object::Scope(scope));
+            scopes.push(Scope {
+                name: CString::new(name).unwrap(),
+                full_name: CString::new(full_name.clone()).unwrap(),
+                def_name: CString::new(module.module.to_string()).unwrap(),
+                parent,
+                children: Vec::new(),
+                signals: Vec::new(),
+                handle: handle(objects.len() - 1),
+            });
+            if let Some(parent) = parent {
+                scopes[parent].children.push(scope);
+            }
+
+            let mut vars: Vec<_> = module
+                .variables
+                .iter()
+                .filter(|(_, var)| var.current_values.len() == 1)
+                .map(|(id, var)| (var.path.to_string(), id, var))
+                .collect();
+            vars.sort_by(|a, b| a.0.cmp(&b.0));
+            for (name, id, var) in vars {
+                let port = parent.is_none() && ports.contains_key(id);
+                let edge = if !port {
+                    None
+                } else if var.r#type.is_clock() {
+                    let negedge = match var.r#type.kind {
+                        TypeKind::ClockNegedge => true,
+                        TypeKind::ClockPosedge => false,
+                        _ => config.negedge_clock,
+                    };
+                    Some(Edge::Clock {
+                        id: *id,
+                        active_high: !negedge,
+                    })
+                } else if var.r#type.is_reset()
+                    && ir.event_statements.contains_key(&Event::Reset(*id))
+                {
+                    Some(Edge::AsyncReset {
+                        id: *id,
+                        active_high: !ir.reset_active_low(id),
+                    })
+                } else {
+                    None
+                };
+                let signal = signals.len();
+                objects.push(Object::Signal(signal));
+                signals.push(Signal {
+                    full_name: CString::new(format!("{full_name}.{name}")).unwrap(),
+                    name: CString::new(name).unwrap(),
+                    scope,
+                    signal: SignalRef::new(var),
+                    port,
+                    signed: var.r#type.signed,
+                    edge,
+                    handle: handle(objects.len() - 1),
+                });
+                scopes[scope].signals.push(signal);
+            }
+
+            // Reversed, so children come off the stack in declaration order.
+            for child in module.children.iter().rev() {
+                let child_name = format!("{full_name}.{}", child.name);
+                stack.push((child, Some(scope), child_name));
+            }
+        }
+
+        kernel.scopes = scopes;
+        kernel.signals = signals;
+        kernel.objects = objects;
+        kernel
+    }
+
+    fn object(&self, handle: Handle) -> Option<&Object> {
+        index(handle).and_then(|x| self.objects.get(x))
+    }
+
+    fn signal(&self, handle: Handle) -> Option<usize> {
+        match self.object(handle) {
+            Some(Object::Signal(x)) => Some(*x),
+            _ => None,
+        }
+    }
+
+    fn push(&mut self, object: Object) -> Handle {
+        self.objects.push(object);
+        handle(self.objects.len() - 1)
+    }
+
+    fn fail(&mut self, message: impl Into<String>) {
+        self.error = CString::new(message.into()).ok();
+    }
+
+    fn constant(&mut self, value: i32) -> Handle {
+        if let Some(x) = self.constants.get(&value) {
+            return *x;
+        }
+        let handle = self.push(Object::Const(value));
+        self.constants.insert(value, handle);
+        handle
+    }
+
+    fn read(&mut self, signal: usize) -> Bits {
+        let signal = self.signals[signal].signal;
+        let mut bits = Bits::new(signal.width());
+        self.cosim
+            .get(&signal, &mut bits.words, Some(&mut bits.mask));
+        bits
+    }
+
+    fn write(&mut self, index: usize, bits: Bits) {
+        let signal = self.signals[index].signal;
+        let edge = self.signals[index].edge;
+        let before = edge.map(|_| self.read(index).get(0));
+        self.cosim.set(&signal, &bits.words, Some(&bits.mask));
+        self.undumped = true;
+
+        let level = |(value, xz): (bool, bool), high: bool| !xz && value == high;
+        let after = bits.get(0);
+        match (edge, before) {
+            (Some(Edge::Clock { id, active_high }), Some(before))
+                if !level(before, active_high) && level(after, active_high) =>
+            {
+                self.edges.push(Event::Clock(id));
+            }
+            (Some(Edge::AsyncReset { id, active_high }), Some(before))
+                if !level(before, active_high) && level(after, active_high) =>
+            {
+                self.edges.push(Event::Reset(id));
+            }
+            _ => {}
+        }
+    }
+
+    /// Takes the clock and reset edges written so far.
+    fn step(&mut self) -> bool {
+        if self.edges.is_empty() {
+            return false;
+        }
+        let sim = self.cosim.simulator();
+        sim.time = self.time;
+        for event in std::mem::take(&mut self.edges) {
+            sim.step(&event);
+        }
+        self.undumped = false;
+        true
+    }
+
+    fn dump(&mut self) {
+        if self.undumped {
+            let time = self.time;
+            self.cosim.dump(time);
+            self.undumped = false;
+        }
+    }
+
+    /// Value-change callbacks whose signal changed since they last fired.
+    fn changed(&mut self) -> Vec<usize> {
+        let callbacks = &self.callbacks;
+        self.watches.retain(|&x| callbacks[x].live);
+        let mut changed = Vec::new();
+        for cb in self.watches.clone() {
+            let Some(signal) = self.signal(self.callbacks[cb].obj) else {
+                continue;
+            };
+            let bits = self.read(signal);
+            if self.callbacks[cb].last.as_ref() != Some(&bits) {
+                self.callbacks[cb].last = Some(bits);
+                changed.push(cb);
+            }
+        }
+        changed
+    }
+
+    fn take_queued(&mut self, reason: i32) -> Vec<usize> {
+        self.queued.remove(&reason).unwrap_or_default()
+    }
+
+    fn has_due(&self) -> bool {
+        self.timers
+            .first_key_value()
+            .is_some_and(|(&(time, _), _)| time <= self.time)
+    }
+
+    fn take_due(&mut self) -> Vec<usize> {
+        let mut due = Vec::new();
+        while self.has_due() {
+            let (_, cb) = self.timers.pop_first().unwrap();
+            self.callbacks[cb].timer = None;
+            due.push(cb);
+        }
+        due
+    }
+
+    /// Moves to the next time anything is scheduled at: `Some(true)` when
+    /// time advanced, `Some(false)` when synchronization callbacks are left at
+    /// the current time and `None` when nothing is scheduled.
+    fn advance(&mut self) -> Option<bool> {
+        let pending = |reason| self.queued.get(&reason).is_some_and(|x| !x.is_empty());
+        if pending(CB_READ_WRITE_SYNCH) || pending(CB_READ_ONLY_SYNCH) {
+            return Some(false);
+        }
+        let (&(time, _), _) = self.timers.first_key_value()?;
+        self.time = time;
+        Some(true)
+    }
+
+    /// The call of callback `cb`; one-shot callbacks are used up by it.
+    fn prepare(&mut self, cb: usize) -> Option<Fire> {
+        let callback = &mut self.callbacks[cb];
+        if !callback.live {
+            return None;
+        }
+        let routine = callback.routine?;
+        if callback.reason != CB_VALUE_CHANGE {
+            callback.live = false;
+        }
+        let (reason, obj, user_data) = (callback.reason, callback.obj, callback.user_data);
+        let (time_type, value_format) = (callback.time_type, callback.value_format);
+
+        let storage = match (value_format, self.signal(obj)) {
+            (Some(format), Some(signal)) if format != VPI_SUPPRESS_VAL => {
+                let width = self.signals[signal].signal.width();
+                let bits = self.read(signal);
+                format_value(&bits, width, format).unwrap_or((format, Formatted::None))
+            }
+            (format, _) => (format.unwrap_or(VPI_SUPPRESS_VAL), Formatted::None),
+        };
+        Some(Fire {
+            routine,
+            data: CbData {
+                reason,
+                cb_rtn: Some(routine),
+                obj,
+                time: std::ptr::null_mut(),
+                value: std::ptr::null_mut(),
+                index: 0,
+                user_data,
+            },
+            time: self.vpi_time(time_type),
+            has_value: value_format.is_some(),
+            value: VpiValue {
+                format: storage.0,
+                value: VpiValueUnion { integer: 0 },
+            },
+            storage: storage.1,
+        })
+    }
+
+    fn vpi_time(&self, r#type: i32) -> VpiTime {
+        VpiTime {
+            r#type,
+            high: (self.time >> 32) as u32,
+            low: self.time as u32,
+            real: self.time as f64,
+        }
+    }
+
+    fn lookup(&self, name: &str, scope: Option<usize>) -> Handle {
+        let full_name = match scope.and_then(|x| self.objects.get(x)) {
+            Some(Object::Scope(x)) => {
+                format!("{}.{name}", self.scopes[*x].full_name.to_string_lossy())
+            }
+            _ => name.to_string(),
+        };
+        let root = self.scopes[0].full_name.to_bytes();
+        if full_name.as_bytes() == root {
+            return self.scopes[0].handle;
+        }
+        full_name
+            .as_bytes()
+            .strip_prefix(root)
+            .and_then(|x| x.strip_prefix(b"."))
+            .and_then(|x| self.find(0, x))
+            .unwrap_or(std::ptr::null_mut())
+    }
+
+    fn find(&self, scope: usize, path: &[u8]) -> Option<Handle> {
+        let scope = &self.scopes[scope];
+        if let Some(&x) = scope
+            .signals
+            .iter()
+            .find(|&&x| self.signals[x].name.to_bytes() == path)
+        {
+            return Some(self.signals[x].handle);
+        }
+        let child = |name: &[u8]| {
+            scope
+                .children
+                .iter()
+                .copied()
+                .find(|&x| self.scopes[x].name.to_bytes() == name)
+        };
+        if let Some(x) = child(path) {
+            return Some(self.scopes[x].handle);
+        }
+        let dot = path.iter().position(|&x| x == b'.')?;
+        self.find(child(&path[..dot])?, &path[dot + 1..])
+    }
+
+    fn iterate(&mut self, r#type: i32, reference: Handle) -> Handle {
+        let scope = match self.object(reference) {
+            Some(Object::Scope(x)) => Some(*x),
+            _ => None,
+        };
+        let items: Vec<Handle> = match (r#type, scope) {
+            (VPI_MODULE, None) if reference.is_null() => vec![self.scopes[0].handle],
+            (VPI_MODULE, Some(x)) => self.scopes[x]
+                .children
+                .iter()
+                .map(|&x| self.scopes[x].handle)
+                .collect(),
+            (VPI_NET | VPI_REG, Some(x)) => self.scopes[x]
+                .signals
+                .iter()
+                .map(|&x| &self.signals[x])
+                .filter(|x| x.port == (r#type == VPI_NET))
+                .map(|x| x.handle)
+                .collect(),
+            _ => Vec::new(),
+        };
+        if items.is_empty() {
+            std::ptr::null_mut()
+        } else {
+            self.push(Object::Iterator { items, next: 0 })
+        }
+    }
+
+    fn scan(&mut self, iterator: Handle) -> Handle {
+        let Some(object) = index(iterator).and_then(|x| self.objects.get_mut(x)) else {
+            return std::ptr::null_mut();
+        };
+        if let Object::Iterator { items, next } = object {
+            if let Some(&item) = items.get(*next) {
+                *next += 1;
+                return item;
+            }
+            // An exhausted iterator frees itself.
+            *object = Object::Freed;
+        }
+        std::ptr::null_mut()
+    }
+
+    fn get(&self, property: i32, object: Handle) -> i32 {
+        let object = self.object(object);
+        match (property, object) {
+            (VPI_TIME_UNIT | VPI_TIME_PRECISION, _) => -12,
+            (VPI_TYPE, Some(x)) => match x {
+                Object::Scope(_) => VPI_MODULE,
+                Object::Signal(x) if self.signals[*x].port => VPI_NET,
+                Object::Signal(_) => VPI_REG,
+                Object::Const(_) => VPI_CONSTANT,
+                Object::Iterator { .. } => VPI_ITERATOR,
+                Object::Callback(_) => VPI_CALLBACK,
+                Object::Systf => VPI_USER_SYSTF,
+                Object::Freed => VPI_UNDEFINED,
+            },
+            (VPI_SIZE, Some(Object::Signal(x))) => self.signals[*x].signal.width() as i32,
+            (VPI_SIZE, Some(Object::Const(_))) => 32,
+            (VPI_SCALAR, Some(Object::Signal(x))) => (self.signals[*x].signal.width() == 1) as i32,
+            (VPI_VECTOR, Some(Object::Signal(x))) => (self.signals[*x].signal.width() > 1) as i32,
+            (VPI_SIGNED, Some(Object::Signal(x))) => self.signals[*x].signed as i32,
+            (VPI_SIGNED, Some(Object::Const(_))) => 1,
+            (VPI_TOP_MODULE, Some(Object::Scope(x))) => self.scopes[*x].parent.is_none() as i32,
+            (VPI_CONST_TYPE, Some(Object::Const(_))) => VPI_DEC_CONST,
+            _ => VPI_UNDEFINED,
+        }
+    }
+
+    fn get_str(&self, property: i32, object: Handle) -> *mut c_char {
+        let name: Option<&CStr> = match (property, self.object(object)) {
+            (VPI_NAME, Some(Object::Scope(x))) => Some(&self.scopes[*x].name),
+            (VPI_FULL_NAME, Some(Object::Scope(x))) => Some(&self.scopes[*x].full_name),
+            (VPI_DEF_NAME, Some(Object::Scope(x))) => Some(&self.scopes[*x].def_name),
+            (VPI_NAME, Some(Object::Signal(x))) => Some(&self.signals[*x].name),
+            (VPI_FULL_NAME, Some(Object::Signal(x))) => Some(&self.signals[*x].full_name),
+            (VPI_TYPE, Some(_)) => Some(match self.get(VPI_TYPE, object) {
+                VPI_MODULE => c"vpiModule",
+                VPI_NET => c"vpiNet",
+                VPI_REG => c"vpiReg",
+                VPI_CONSTANT => c"vpiConstant",
+                VPI_ITERATOR => c"vpiIterator",
+                VPI_CALLBACK => c"vpiCallback",
+                _ => c"vpiUndefined",
+            }),
+            _ => None,
+        };
+        name.map(|x| x.as_ptr() as *mut _)
+            .unwrap_or(std::ptr::null_mut())
+    }
+
+    fn get_value(&mut self, object: Handle, value: &mut VpiValue) {
+        let (bits, width) = match self.object(object) {
+            Some(&Object::Signal(x)) => (self.read(x), self.signals[x].signal.width()),
+            Some(&Object::Const(x)) => {
+                let mut bits = Bits::new(32);
+                bits.words[0] = x as u32 as u64;
+                (bits, 32)
+            }
+            _ => return self.fail("vpi_get_value: object has no value"),
+        };
+        match format_value(&bits, width, value.format) {
+            Some((format, formatted)) => {
+                self.returned = formatted;
+                value.format = format;
+                self.returned.store(value);
+            }
+            None => self.fail(format!(
+                "vpi_get_value: unsupported format {}",
+                value.format
+            )),
+        }
+    }
+
+    fn put_value(&mut self, object: Handle, value: &VpiValue, flags: i32) {
+        let Some(signal) = self.signal(object) else {
+            return self.fail("vpi_put_value: object is not a signal");
+        };
+        if flags & 0xfff == VPI_RELEASE_FLAG {
+            let released = self.signals[signal].signal;
+            if let Err(e) = self.cosim.release(&released) {
+                self.fail(format!("vpi_put_value: {e}"));
+            }
+            self.undumped = true;
+            return;
+        }
+        let width = self.signals[signal].signal.width();
+        match unsafe { parse_value(value, width) } {
+            Some(bits) if flags & 0xfff == VPI_FORCE_FLAG => {
+                let forced = self.signals[signal].signal;
+                if let Err(e) = self.cosim.force(&forced, &bits.words, Some(&bits.mask)) {
+                    self.fail(format!("vpi_put_value: {e}"));
+                }
+                self.undumped = true;
+            }
+            Some(bits) => self.write(signal, bits),
+            None => self.fail(format!(
+                "vpi_put_value: unsupported format {}",
+                value.format
+            )),
+        }
+    }
+
+    fn register_cb(&mut self, data: &CbData) -> Handle {
+        let time = unsafe { data.time.as_ref() };
+        let value = unsafe { data.value.as_ref() };
+        let mut callback = Callback {
+            reason: data.reason,
+            routine: data.cb_rtn,
+            obj: data.obj,
+            user_data: data.user_data,
+            time_type: time.map(|x| x.r#type).unwrap_or(VPI_SUPPRESS_TIME),
+            value_format: value.map(|x| x.format),
+            last: None,
+            timer: None,
+            live: true,
+        };
+        let cb = self.callbacks.len();
+        let ticks = |x: &VpiTime| match x.r#type {
+            VPI_SCALED_REAL_TIME => x.real.max(0.0) as u64,
+            _ => ((x.high as u64) << 32) | x.low as u64,
+        };
+
+        match data.reason {
+            CB_VALUE_CHANGE => {
+                let Some(signal) = self.signal(data.obj) else {
+                    self.fail("cbValueChange: object is not a signal");
+                    return std::ptr::null_mut();
+                };
+                callback.last = Some(self.read(signal));
+                self.watches.push(cb);
+            }
+            CB_AFTER_DELAY | CB_AT_START_OF_SIM_TIME => {
+                let Some(time) = time else {
+                    self.fail("callback without a time");
+                    return std::ptr::null_mut();
+                };
+                let at = if data.reason == CB_AFTER_DELAY {
+                    self.time + ticks(time)
+                } else {
+                    ticks(time).max(self.time)
+                };
+                let key = (at, self.seq);
+                self.seq += 1;
+                self.timers.insert(key, cb);
+                callback.timer = Some(key);
+            }
+            CB_READ_WRITE_SYNCH
+            | CB_READ_ONLY_SYNCH
+            | CB_NEXT_SIM_TIME
+            | CB_END_OF_COMPILE
+            | CB_START_OF_SIMULATION
+            | CB_END_OF_SIMULATION => {
+                self.queued.entry(data.reason).or_default().push(cb);
+            }
+            x => {
+                self.fail(format!("unsupported callback reason {x}"));
+                return std::ptr::null_mut();
+            }
+        }
+        self.callbacks.push(callback);
+        self.push(Object::Callback(cb))
+    }
+
+    fn remove_cb(&mut self, handle: Handle) -> bool {
+        let Some(&Object::Callback(cb)) = self.object(handle) else {
+            return false;
+        };
+        let callback = &mut self.callbacks[cb];
+        callback.live = false;
+        if let Some(key) = callback.timer.take() {
+            self.timers.remove(&key);
+        }
+        true
+    }
+}
+
+/// A value as LSB-first words, with the X/Z bits in `mask` (Z where the
+/// value bit is also set), as the simulator reads and writes it.
+#[derive(Clone, PartialEq, Eq)]
+struct Bits {
+    words: Vec<u64>,
+    mask: Vec<u64>,
+}
+
+impl Bits {
+    fn new(width: usize) -> Self {
+        let nwords = width.div_ceil(64).max(1);
+        Self {
+            words: vec![0; nwords],
+            mask: vec![0; nwords],
+        }
+    }
+
+    fn get(&self, i: usize) -> (bool, bool) {
+        let bit = |x: &[u64]| x.get(i / 64).is_some_and(|x| (x >> (i % 64)) & 1 == 1);
+        (bit(&self.words), bit(&self.mask))
+    }
+
+    fn set(&mut self, i: usize, value: bool, xz: bool) {
+        if i / 64 < self.words.len() {
+            let bit = 1 << (i % 64);
+            if value {
+                self.words[i / 64] |= bit;
+            }
+            if xz {
+                self.mask[i / 64] |= bit;
+            }
+        }
+    }
+
+    fn has_xz(&self) -> bool {
+        self.mask.iter().any(|x| *x != 0)
+    }
+}
+
+/// Storage behind a value handed out in `s_vpi_value`.
+enum Formatted {
+    None,
+    Str(CString),
+    Scalar(i32),
+    Int(i32),
+    Real(f64),
+    Vector(Vec<VpiVecVal>),
+}
+
+impl Formatted {
+    fn store(&mut self, value: &mut VpiValue) {
+        value.value = match self {
+            Formatted::None => VpiValueUnion { integer: 0 },
+            Formatted::Str(x) => VpiValueUnion {
+                str: x.as_ptr() as *mut _,
+            },
+            Formatted::Scalar(x) => VpiValueUnion { scalar: *x },
+            Formatted::Int(x) => VpiValueUnion { integer: *x },
+            Formatted::Real(x) => VpiValueUnion { real: *x },
+            Formatted::Vector(x) => VpiValueUnion {
+                vector: x.as_mut_ptr(),
+            },
+        };
+    }
+}
+
+/// Digits of `1 << log2` radix, MSB first: `x` for a digit with an X or Z
+/// bit, `z` for an all-Z one.
+fn radix_string(bits: &Bits, width: usize, log2: usize) -> String {
+    let digits = width.div_ceil(log2).max(1);
+    (0..digits)
+        .rev()
+        .map(|digit| {
+            let mut value = 0;
+            let mut xz = false;
+            let mut all_z = true;
+            for b in 0..log2 {
+                let i = digit * log2 + b;
+                if i >= width {
+                    continue;
+                }
+                match bits.get(i) {
+                    (v, true) => {
+                        xz = true;
+                        all_z &= v;
+                    }
+                    (v, false) => {
+                        all_z = false;
+                        value |= (v as u32) << b;
+                    }
+                }
+            }
+            match (xz, all_z) {
+                (true, true) => 'z',
+                (true, false) => 'x',
+                _ => char::from_digit(value, 16).unwrap(),
+            }
+        })
+        .collect()
+}
+
+fn decimal_string(bits: &Bits) -> String {
+    if bits.has_xz() {
+        return "x".to_string();
+    }
+    let mut words = bits.words.clone();
+    let mut digits = Vec::new();
+    while words.iter().any(|x| *x != 0) || digits.is_empty() {
+        let mut rem = 0u128;
+        for word in words.iter_mut().rev() {
+            let cur = (rem << 64) | *word as u128;
+            *word = (cur / 10) as u64;
+            rem = cur % 10;
+        }
+        digits.push(b'0' + rem as u8);
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+fn format_value(bits: &Bits, width: usize, format: i32) -> Option<(i32, Formatted)> {
+    let string = |x: String| Formatted::Str(CString::new(x).unwrap());
+    let formatted = match format {
+        VPI_BIN_STR_VAL => string(radix_string(bits, width, 1)),
+        VPI_OCT_STR_VAL => string(radix_string(bits, width, 3)),
+        VPI_HEX_STR_VAL => string(radix_string(bits, width, 4)),
+        VPI_DEC_STR_VAL => string(decimal_string(bits)),
+        VPI_SCALAR_VAL => Formatted::Scalar(match bits.get(0) {
+            (false, false) => VPI_0,
+            (true, false) => VPI_1,
+            (false, true) => VPI_X,
+            (true, true) => VPI_Z,
+        }),
+        VPI_INT_VAL => Formatted::Int(bits.words[0] as u32 as i32),
+        VPI_REAL_VAL => Formatted::Real(
+            bits.words
+                .iter()
+                .rev()
+                .fold(0.0, |acc, x| acc * 2f64.powi(64) + *x as f64),
+        ),
+        VPI_STRING_VAL => {
+            let bytes: Vec<u8> = (0..width.div_ceil(8))
+                .rev()
+                .map(|i| (bits.words[i / 8] >> (i % 8 * 8)) as u8)
+                .filter(|x| *x != 0)
+                .collect();
+            Formatted::Str(CString::new(bytes).unwrap())
+        }
+        VPI_VECTOR_VAL | VPI_OBJ_TYPE_VAL => {
+            let vector = (0..width.div_ceil(32).max(1))
+                .map(|i| {
+                    let half = |x: &[u64]| (x[i / 2] >> (i % 2 * 32)) as u32;
+                    let (value, mask) = (half(&bits.words), half(&bits.mask));
+                    VpiVecVal {
+                        aval: (value ^ mask) as i32,
+                        bval: mask as i32,
+                    }
+                })
+                .collect();
+            return Some((VPI_VECTOR_VAL, Formatted::Vector(vector)));
+        }
+        VPI_SUPPRESS_VAL => Formatted::None,
+        _ => return None,
+    };
+    Some((format, formatted))
+}
+
+/// `value` as `width` bits; a signed integer or real is sign-extended.
+unsafe fn parse_value(value: &VpiValue, width: usize) -> Option<Bits> {
+    let mut bits = Bits::new(width);
+    let signed = |bits: &mut Bits, x: i64| {
+        for i in 0..width {
+            bits.set(i, if i < 64 { (x >> i) & 1 == 1 } else { x < 0 }, false);
+        }
+    };
+    match value.format {
+        VPI_BIN_STR_VAL | VPI_OCT_STR_VAL | VPI_HEX_STR_VAL => {
+            let log2 = match value.format {
+                VPI_BIN_STR_VAL => 1,
+                VPI_OCT_STR_VAL => 3,
+                _ => 4,
+            };
+            let text = unsafe { str_arg(value.value.str) }?;
+            let digits = text.bytes().rev().filter(|x| *x != b'_');
+            for (d, c) in digits.enumerate() {
+                let (v, xz) = match c {
+                    b'x' | b'X' => (0, true),
+                    b'z' | b'Z' | b'?' => (u32::MAX, true),
+                    c => ((c as char).to_digit(1 << log2)?, false),
+                };
+                for b in 0..log2 {
+                    bits.set(d * log2 + b, (v >> b) & 1 == 1, xz);
+                }
+            }
+        }
+        VPI_DEC_STR_VAL => {
+            let text = unsafe { str_arg(value.value.str) }?;
+            if text.eq_ignore_ascii_case("x") {
+                bits.mask.fill(u64::MAX);
+            } else {
+                for c in text.bytes().filter(|x| *x != b'_') {
+                    let mut carry = (c as char).to_digit(10)? as u128;
+                    for word in bits.words.iter_mut() {
+                        let cur = *word as u128 * 10 + carry;
+                        *word = cur as u64;
+                        carry = cur >> 64;
+                    }
+                }
+            }
+        }
+        VPI_SCALAR_VAL => match unsafe { value.value.scalar } {
+            VPI_0 | VPI_L => {}
+            VPI_1 | VPI_H => bits.set(0, true, false),
+            VPI_Z => bits.set(0, true, true),
+            _ => bits.set(0, false, true),
+        },
+        VPI_INT_VAL => signed(&mut bits, unsafe { value.value.integer } as i64),
+        VPI_REAL_VAL => signed(&mut bits, unsafe { value.value.real }.round() as i64),
+        VPI_STRING_VAL => {
+            let text = unsafe { str_arg(value.value.str) }?;
+            for (i, c) in text.bytes().rev().enumerate() {
+                for b in 0..8 {
+                    bits.set(i * 8 + b, (c >> b) & 1 == 1, false);
+                }
+            }
+        }
+        VPI_VECTOR_VAL => {
+            let vector = unsafe { value.value.vector };
+            if vector.is_null() {
+                return None;
+            }
+            for i in 0..width.div_ceil(32) {
+                let VpiVecVal { aval, bval } = unsafe { *vector.add(i) };
+                let (mask, value) = (bval as u32, (aval ^ bval) as u32);
+                for b in 0..32 {
+                    bits.set(i * 32 + b, (value >> b) & 1 == 1, (mask >> b) & 1 == 1);
+                }
+            }
+        }
+        _ => return None,
+    }
+    let top = width % 64;
+    if top != 0 {
+        let last = bits.words.len() - 1;
+        bits.words[last] &= (1 << top) - 1;
+        bits.mask[last] &= (1 << top) - 1;
+    }
+    Some(bits)
+}
+
+// The VPI routines. Outside `run` they find no design and return nothing.
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_register_cb(data: *mut CbData) -> Handle {
+    let Some(data) = (unsafe { data.as_ref() }) else {
+        return std::ptr::null_mut();
+    };
+    call(|k| k.register_cb(data)).unwrap_or(std::ptr::null_mut())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_remove_cb(cb: Handle) -> i32 {
+    call(|k| k.remove_cb(cb)).unwrap_or(false) as i32
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_get_cb_info(cb: Handle, data: *mut CbData) {
+    let Some(data) = (unsafe { data.as_mut() }) else {
+        return;
+    };
+    call(|k| {
+        if let Some(&Object::Callback(x)) = k.object(cb) {
+            let callback = &k.callbacks[x];
+            data.reason = callback.reason;
+            data.cb_rtn = callback.routine;
+            data.obj = callback.obj;
+            data.user_data = callback.user_data;
+        }
+    });
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_handle_by_name(name: *mut c_char, scope: Handle) -> Handle {
+    let Some(name) = (unsafe { str_arg(name) }) else {
+        return std::ptr::null_mut();
+    };
+    call(|k| k.lookup(name, index(scope))).unwrap_or(std::ptr::null_mut())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_handle_by_index(_object: Handle, _index: i32) -> Handle {
+    call(|k| k.fail("vpi_handle_by_index: selects are not supported"));
+    std::ptr::null_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_handle(r#type: i32, reference: Handle) -> Handle {
+    call(|k| {
+        let (signal, scope) = match k.object(reference) {
+            Some(&Object::Signal(x)) => (Some(x), None),
+            Some(&Object::Scope(x)) => (None, Some(x)),
+            _ => (None, None),
+        };
+        match (r#type, signal, scope) {
+            (VPI_LEFT_RANGE, Some(x), _) => k.constant(k.signals[x].signal.width() as i32 - 1),
+            (VPI_RIGHT_RANGE, Some(_), _) => k.constant(0),
+            (VPI_MODULE | VPI_SCOPE, Some(x), _) => k.scopes[k.signals[x].scope].handle,
+            (VPI_MODULE | VPI_SCOPE, _, Some(x)) => k.scopes[x]
+                .parent
+                .map(|x| k.scopes[x].handle)
+                .unwrap_or(std::ptr::null_mut()),
+            _ => std::ptr::null_mut(),
+        }
+    })
+    .unwrap_or(std::ptr::null_mut())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_iterate(r#type: i32, reference: Handle) -> Handle {
+    call(|k| k.iterate(r#type, reference)).unwrap_or(std::ptr::null_mut())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_scan(iterator: Handle) -> Handle {
+    call(|k| k.scan(iterator)).unwrap_or(std::ptr::null_mut())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_free_object(object: Handle) -> i32 {
+    call(|k| {
+        if let Some(x) = index(object).and_then(|x| k.objects.get_mut(x))
+            && matches!(x, Object::Iterator { .. })
+        {
+            *x = Object::Freed;
+        }
+    })
+    .is_some() as i32
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_release_handle(object: Handle) -> i32 {
+    vpi_free_object(object)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_compare_objects(a: Handle, b: Handle) -> i32 {
+    (a == b) as i32
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_get(property: i32, object: Handle) -> i32 {
+    call(|k| k.get(property, object)).unwrap_or(VPI_UNDEFINED)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_get_str(property: i32, object: Handle) -> *mut c_char {
+    call(|k| k.get_str(property, object)).unwrap_or(std::ptr::null_mut())
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_get_value(object: Handle, value: *mut VpiValue) {
+    if let Some(value) = unsafe { value.as_mut() } {
+        call(|k| k.get_value(object, value));
+    }
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_put_value(
+    object: Handle,
+    value: *mut VpiValue,
+    _time: *mut VpiTime,
+    flags: i32,
+) -> Handle {
+    if let Some(value) = unsafe { value.as_ref() } {
+        call(|k| k.put_value(object, value, flags));
+    }
+    std::ptr::null_mut()
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_get_time(_object: Handle, time: *mut VpiTime) {
+    if let Some(time) = unsafe { time.as_mut() } {
+        call(|k| *time = k.vpi_time(time.r#type));
+    }
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_get_vlog_info(info: *mut VlogInfo) -> i32 {
+    let Some(info) = (unsafe { info.as_mut() }) else {
+        return 0;
+    };
+    call(|k| {
+        info.argc = k.argv.len() as i32;
+        info.argv = k.argv.as_mut_ptr();
+        info.product = k.product.as_ptr() as *mut _;
+        info.version = k.version.as_ptr() as *mut _;
+    })
+    .is_some() as i32
+}
+
+/// `vpi_control(operation, ...)`; the variadic arguments are ignored.
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_control(operation: i32) -> i32 {
+    call(|k| match operation {
+        VPI_STOP | VPI_FINISH => {
+            k.finished = true;
+            1
+        }
+        _ => 0,
+    })
+    .unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_chk_error(info: *mut ErrorInfo) -> i32 {
+    KERNEL.with(|k| {
+        let k = k.borrow();
+        let Some(error) = k.as_ref().and_then(|k| k.error.as_ref().map(|x| (k, x))) else {
+            return 0;
+        };
+        if let Some(info) = unsafe { info.as_mut() } {
+            info.state = VPI_RUN;
+            info.level = VPI_ERROR;
+            info.message = error.1.as_ptr() as *mut _;
+            info.product = error.0.product.as_ptr() as *mut _;
+            info.code = std::ptr::null_mut();
+            info.file = std::ptr::null_mut();
+            info.line = 0;
+        }
+        VPI_ERROR
+    })
+}
+
+/// System tasks are accepted for the application's bookkeeping; a Veryl
+/// design never calls them.
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_register_systf(_data: *mut c_void) -> Handle {
+    call(|k| k.push(Object::Systf)).unwrap_or(std::ptr::null_mut())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_get_userdata(_object: Handle) -> *mut c_void {
+    std::ptr::null_mut()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_put_userdata(_object: Handle, _data: *mut c_void) -> i32 {
+    0
+}
+
+unsafe extern "C" {
+    fn vprintf(format: *const c_char, args: *mut c_void) -> c_int;
+
+    // Defined in `src/vpi_printf.c`: stable Rust can't define a C-variadic
+    // function, so they forward to `vpi_vprintf` and `vpi_mcd_vprintf`.
+    fn vpi_printf(format: *const c_char, ...) -> c_int;
+    fn vpi_mcd_printf(mcd: u32, format: *const c_char, ...) -> c_int;
+}
+
+/// Pulls the C `vpi_printf` and `vpi_mcd_printf` into the host executable,
+/// which nothing else references, so it can export them.
+#[used]
+static VARIADIC: (
+    unsafe extern "C" fn(*const c_char, ...) -> c_int,
+    unsafe extern "C" fn(u32, *const c_char, ...) -> c_int,
+) = (vpi_printf, vpi_mcd_printf);
+
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_vprintf(format: *const c_char, args: *mut c_void) -> i32 {
+    unsafe { vprintf(format, args) }
+}
+
+/// Every descriptor is stdout.
+#[unsafe(no_mangle)]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn vpi_mcd_vprintf(
+    _mcd: u32,
+    format: *const c_char,
+    args: *mut c_void,
+) -> i32 {
+    unsafe { vprintf(format, args) }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_flush() -> i32 {
+    unsafe { libc::fflush(std::ptr::null_mut()) }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vpi_mcd_flush(_mcd: u32) -> i32 {
+    vpi_flush()
+}
@@ -0,0 +1,27 @@
+/*
+ * The C-variadic `vpi_printf` and `vpi_mcd_printf`. Stable Rust can't
+ * define a variadic function, so these take the arguments the caller's ABI
+ * passes and hand them on as a `va_list` to the Rust `vpi_vprintf` and
+ * `vpi_mcd_vprintf` (src/vpi.rs).
+ */
+
+#include <stdarg.h>
+
+int vpi_vprintf(const char *format, va_list args);
+int vpi_mcd_vprintf(unsigned int mcd, const char *format, va_list args);
+
+int vpi_printf(const char *format, ...) {
+    va_list args;
+    va_start(args, format);
+    int ret = vpi_vprintf(format, args);
+    va_end(args);
+    return ret;
+}
+
+int vpi_mcd_printf(unsigned int mcd, const char *format, ...) {
+    va_list args;
+    va_start(args, format);
+    int ret = vpi_mcd_vprintf(mcd, format, args);
+    va_end(args);
+    return ret;
+}
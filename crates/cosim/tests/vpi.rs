@@ -0,0 +1,419 @@
+//! Hosts small VPI applications, compiled with the system C compiler, the
+//! way cocotb's VPI library is hosted.
+#![cfg(target_os = "linux")]
+
+use std::process::Command;
+use veryl_cosim::Cosim;
+use veryl_cosim::test_utils::project;
+use veryl_cosim::vpi::{self, VpiConfig};
+use veryl_simulator::Config;
+
+/// The `vpi_user.h` subset the applications use.
+const VPI_USER: &str = r#"
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+typedef void *vpiHandle;
+typedef struct { int32_t type; uint32_t high, low; double real; } s_vpi_time;
+typedef struct { int32_t aval, bval; } s_vpi_vecval;
+typedef struct {
+    int32_t format;
+    union { char *str; int32_t scalar; int32_t integer; double real; void *time; s_vpi_vecval *vector; char *misc; } value;
+} s_vpi_value;
+typedef struct t_cb_data {
+    int32_t reason;
+    int32_t (*cb_rtn)(struct t_cb_data *);
+    vpiHandle obj;
+    s_vpi_time *time;
+    s_vpi_value *value;
+    int32_t index;
+    char *user_data;
+} s_cb_data;
+typedef struct { int32_t argc; char **argv; char *product; char *version; } s_vpi_vlog_info;
+
+vpiHandle vpi_register_cb(s_cb_data *);
+vpiHandle vpi_handle_by_name(char *, vpiHandle);
+vpiHandle vpi_handle(int32_t, vpiHandle);
+vpiHandle vpi_iterate(int32_t, vpiHandle);
+vpiHandle vpi_scan(vpiHandle);
+int32_t vpi_get(int32_t, vpiHandle);
+char *vpi_get_str(int32_t, vpiHandle);
+void vpi_get_value(vpiHandle, s_vpi_value *);
+vpiHandle vpi_put_value(vpiHandle, s_vpi_value *, s_vpi_time *, int32_t);
+void vpi_get_time(vpiHandle, s_vpi_time *);
+int32_t vpi_get_vlog_info(s_vpi_vlog_info *);
+int32_t vpi_control(int32_t, ...);
+int32_t vpi_printf(char *, ...);
+
+static FILE *out;
+
+static void open_log(void) {
+    s_vpi_vlog_info info;
+    vpi_get_vlog_info(&info);
+    for (int i = 0; i < info.argc; i++) {
+        if (strncmp(info.argv[i], "+log=", 5) == 0) {
+            out = fopen(info.argv[i] + 5, "w");
+        }
+    }
+    fprintf(out, "%s\n", info.product);
+}
+
+static unsigned long long now(void) {
+    s_vpi_time t = {2};
+    vpi_get_time(NULL, &t);
+    return ((unsigned long long)t.high << 32) | t.low;
+}
+
+static const char *get_str(vpiHandle h, int32_t format) {
+    static s_vpi_value v;
+    v.format = format;
+    vpi_get_value(h, &v);
+    return v.value.str;
+}
+
+static void put_int(vpiHandle h, int32_t x) {
+    s_vpi_value v = {6};
+    v.value.integer = x;
+    vpi_put_value(h, &v, NULL, 1);
+}
+
+static void put_str(vpiHandle h, int32_t format, char *x) {
+    s_vpi_value v = {format};
+    v.value.str = x;
+    vpi_put_value(h, &v, NULL, 1);
+}
+
+static void callback(int32_t reason, int32_t (*f)(s_cb_data *), vpiHandle obj, unsigned long long delay, int32_t format) {
+    s_vpi_time t = {2, (uint32_t)(delay >> 32), (uint32_t)delay, 0};
+    s_vpi_value v = {format};
+    s_cb_data cb = {reason, f, obj, &t, format ? &v : NULL, 0, NULL};
+    vpi_register_cb(&cb);
+}
+"#;
+
+/// Drives a clock and reset like a cocotb testbench: writes from timers and
+/// from read-write synchronization, reads at edges and read-only
+/// synchronization.
+const CLOCKED: &str = r#"
+static vpiHandle clk, rst, d, q, cnt;
+static int rises, level;
+
+static int32_t read_only(s_cb_data *cb) {
+    fprintf(out, "%llu ro q=%s", now(), get_str(q, 4));
+    fprintf(out, " cnt=%s\n", get_str(cnt, 4));
+    return 0;
+}
+
+static int32_t read_write(s_cb_data *cb) {
+    s_vpi_vecval vec = {0x10 + rises, 0};
+    s_vpi_value v = {9};
+    v.value.vector = &vec;
+    vpi_put_value(d, &v, NULL, 1);
+    return 0;
+}
+
+static int32_t clk_change(s_cb_data *cb) {
+    if (cb->value->value.integer != 1) {
+        return 0;
+    }
+    fprintf(out, "%llu rise q=%s\n", now(), get_str(q, 4));
+    if (++rises == 4) {
+        vpi_control(67, 1);
+        return 0;
+    }
+    callback(6, read_write, NULL, 0, 0);
+    callback(7, read_only, NULL, 0, 0);
+    return 0;
+}
+
+static int32_t sum_change(s_cb_data *cb) {
+    fprintf(out, "%llu sum=%s\n", now(), cb->value->value.str);
+    return 0;
+}
+
+static int32_t toggle(s_cb_data *cb) {
+    level = !level;
+    put_int(clk, level);
+    callback(9, toggle, NULL, 5000, 0);
+    return 0;
+}
+
+static int32_t release_reset(s_cb_data *cb) {
+    fprintf(out, "%llu release\n", now());
+    put_int(rst, 1);
+    return 0;
+}
+
+static void list(const char *what, int32_t type, vpiHandle scope) {
+    vpiHandle it = vpi_iterate(type, scope);
+    fprintf(out, "%s:", what);
+    for (vpiHandle h = it ? vpi_scan(it) : NULL; h; h = vpi_scan(it)) {
+        fprintf(out, " %s", vpi_get_str(2, h));
+    }
+    fprintf(out, "\n");
+}
+
+static int32_t start(s_cb_data *cb) {
+    open_log();
+    vpiHandle top = vpi_scan(vpi_iterate(32, NULL));
+    fprintf(out, "top %s %s\n", vpi_get_str(3, top), vpi_get_str(9, top));
+    list("modules", 32, top);
+    list("nets", 36, top);
+    vpiHandle sub = vpi_handle_by_name("u_sub", top);
+    fprintf(out, "sub %s %d\n", vpi_get_str(3, sub), vpi_handle(32, sub) == top);
+
+    clk = vpi_handle_by_name("Top.i_clk", NULL);
+    rst = vpi_handle_by_name("Top.i_rst", NULL);
+    d = vpi_handle_by_name("i_d", top);
+    q = vpi_handle_by_name("Top.o_q", NULL);
+    cnt = vpi_handle_by_name("Top.u_sub.cnt", NULL);
+    s_vpi_value left = {6};
+    vpi_get_value(vpi_handle(79, q), &left);
+    fprintf(out, "q type=%d size=%d vector=%d left=%d\n", vpi_get(1, q), vpi_get(4, q), vpi_get(18, q), left.value.integer);
+    fprintf(out, "cnt %s type=%d\n", vpi_get_str(3, cnt), vpi_get(1, cnt));
+    fprintf(out, "missing %d\n", vpi_handle_by_name("Top.nope", NULL) == NULL);
+
+    put_int(rst, 0);
+    put_str(d, 1, "10000");
+    put_int(clk, 0);
+    callback(1, clk_change, clk, 0, 6);
+    callback(1, sum_change, vpi_handle_by_name("Top.o_sum", NULL), 0, 1);
+    callback(9, toggle, NULL, 5000, 0);
+    callback(9, release_reset, NULL, 12000, 0);
+    return 0;
+}
+
+static int32_t end(s_cb_data *cb) {
+    fprintf(out, "%llu end\n", now());
+    fclose(out);
+    return 0;
+}
+
+static void boot(void) {
+    callback(11, start, NULL, 0, 0);
+    callback(12, end, NULL, 0, 0);
+}
+
+void (*vlog_startup_routines[])(void) = {boot, NULL};
+"#;
+
+/// Puts and gets four-state and wide values in every format.
+const FORMATS: &str = r#"
+static int32_t start(s_cb_data *cb) {
+    open_log();
+    vpiHandle a = vpi_handle_by_name("Top.i_a", NULL);
+    vpiHandle y = vpi_handle_by_name("Top.o_y", NULL);
+    vpiHandle w = vpi_handle_by_name("Top.i_w", NULL);
+    vpiHandle v = vpi_handle_by_name("Top.o_v", NULL);
+
+    put_str(a, 1, "1x0z");
+    fprintf(out, "bin %s\n", get_str(y, 1));
+    fprintf(out, "hex %s\n", get_str(y, 4));
+    s_vpi_value vec = {9};
+    vpi_get_value(y, &vec);
+    fprintf(out, "vec %x %x\n", vec.value.vector[0].aval, vec.value.vector[0].bval);
+    s_vpi_value scalar = {5};
+    vpi_get_value(y, &scalar);
+    fprintf(out, "scalar %d\n", scalar.value.scalar);
+
+    put_str(w, 3, "1180591620717411303423");
+    fprintf(out, "dec %s\n", get_str(v, 3));
+    fprintf(out, "hex %s\n", get_str(v, 4));
+    put_int(w, -2);
+    fprintf(out, "int %s\n", get_str(v, 4));
+    put_str(w, 8, "AB");
+    fprintf(out, "string %s\n", get_str(v, 8));
+    fprintf(out, "printf %d\n", vpi_printf("%s %d %.1f\n", "ab", 42, 1234.5));
+    fprintf(out, "end\n");
+    fclose(out);
+    return 0;
+}
+
+static void boot(void) {
+    callback(11, start, NULL, 0, 0);
+}
+
+void (*vlog_startup_routines[])(void) = {boot, NULL};
+"#;
+
+/// Forces an output over its driver with `vpiForceFlag`, then releases it.
+const FORCED: &str = r#"
+static int32_t start(s_cb_data *cb) {
+    open_log();
+    vpiHandle a = vpi_handle_by_name("Top.i_a", NULL);
+    vpiHandle y = vpi_handle_by_name("Top.o_y", NULL);
+
+    s_vpi_value v = {1};
+    v.value.str = "1111";
+    vpi_put_value(y, &v, NULL, 5);
+    put_str(a, 1, "0101");
+    fprintf(out, "force %s\n", get_str(y, 1));
+    vpi_put_value(y, &v, NULL, 6);
+    fprintf(out, "release %s\n", get_str(y, 1));
+    fclose(out);
+    return 0;
+}
+
+static void boot(void) {
+    callback(11, start, NULL, 0, 0);
+}
+
+void (*vlog_startup_routines[])(void) = {boot, NULL};
+"#;
+
+/// Simulates `top` under the application `app`, returning what it logged;
+/// `None` without a C compiler.
+fn host(top: &str, app: &str, config: Config) -> Option<String> {
+    let dir = project(top);
+    let source = dir.path().join("app.c");
+    let library = dir.path().join("app.so");
+    std::fs::write(&source, format!("{VPI_USER}{app}")).unwrap();
+    let compiled = Command::new("cc")
+        .args(["-shared", "-fPIC", "-o"])
+        .arg(&library)
+        .arg(&source)
+        .status();
+    if !compiled.is_ok_and(|x| x.success()) {
+        eprintln!("skipped: no C compiler");
+        return None;
+    }
+
+    let cosim = Cosim::open_project_with_config(dir.path(), "Top", config).unwrap();
+    let log = dir.path().join("log.txt");
+    let config = VpiConfig {
+        library,
+        toplevel: "Top".to_string(),
+        args: vec![format!("+log={}", log.display())],
+        ..Default::default()
+    };
+    vpi::run(cosim, &config).unwrap();
+    Some(std::fs::read_to_string(log).unwrap())
+}
+
+#[test]
+fn clocked() {
+    let top = r#"
+module Sub (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    o_cnt: output logic<8>,
+) {
+    var cnt: logic<8>;
+    always_ff {
+        if_reset {
+            cnt = 0;
+        } else {
+            cnt += 1;
+        }
+    }
+    assign o_cnt = cnt;
+}
+
+module Top (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    i_d  : input  logic<8>,
+    o_q  : output logic<8>,
+    o_sum: output logic<8>,
+    o_cnt: output logic<8>,
+) {
+    always_ff {
+        if_reset {
+            o_q = 0;
+        } else {
+            o_q = i_d;
+        }
+    }
+    assign o_sum = i_d + 1;
+    inst u_sub: Sub (
+        i_clk,
+        i_rst,
+        o_cnt,
+    );
+}
+"#;
+    let Some(log) = host(top, CLOCKED, Config::default()) else {
+        return;
+    };
+    let expected = "\
+Veryl
+top Top Top
+modules: u_sub
+nets: i_clk i_d i_rst o_cnt o_q o_sum
+sub Top.u_sub 1
+q type=36 size=8 vector=1 left=7
+cnt Top.u_sub.cnt type=48
+missing 1
+5000 rise q=00
+5000 sum=00010010
+5000 ro q=00 cnt=00
+12000 release
+15000 rise q=00
+15000 sum=00010011
+15000 ro q=11 cnt=01
+25000 rise q=11
+25000 sum=00010100
+25000 ro q=12 cnt=02
+35000 rise q=12
+35000 end
+";
+    assert_eq!(log, expected);
+}
+
+#[test]
+fn formats() {
+    let top = r#"
+module Top (
+    i_a: input  logic<4>,
+    o_y: output logic<4>,
+    i_w: input  logic<70>,
+    o_v: output logic<70>,
+) {
+    assign o_y = i_a;
+    assign o_v = i_w;
+}
+"#;
+    let config = Config {
+        use_4state: true,
+        ..Config::default()
+    };
+    let Some(log) = host(top, FORMATS, config) else {
+        return;
+    };
+    let expected = "\
+Veryl
+bin 1x0z
+hex x
+vec c 5
+scalar 2
+dec 1180591620717411303423
+hex 3fffffffffffffffff
+int 3ffffffffffffffffe
+string AB
+printf 13
+end
+";
+    assert_eq!(log, expected);
+}
+
+#[test]
+fn forced() {
+    let top = r#"
+module Top (
+    i_a: input  logic<4>,
+    o_y: output logic<4>,
+) {
+    assign o_y = i_a;
+}
+"#;
+    let config = Config {
+        force: true,
+        ..Config::default()
+    };
+    let Some(log) = host(top, FORCED, config) else {
+        return;
+    };
+    assert_eq!(log, "Veryl\nforce 1111\nrelease 0101\n");
+}
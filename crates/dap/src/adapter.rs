@@ -0,0 +1,490 @@
+//! One debug session: configure, run the test under the simulator's
+//! [`Debugger`] and report how it ended.
+//!
+//! The test runs on the calling thread while a reader thread queues the
+//! client's requests.  Stopped, the [`Frontend`] answers requests until one
+//! resumes the run; running, it only takes `pause`, `setBreakpoints` and
+//! `threads` off the front of the queue, so requests are always answered in
+//! the order they came.
+
+use crate::protocol::{Client, Request, read_message};
+use serde_json::{Value, json};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver};
+use veryl_cosim::project::load_project_with_config;
+use veryl_simulator::debugger::{self, DebugState, Debugger, Frontend, Resume, Stop};
+use veryl_simulator::ir::Ir;
+use veryl_simulator::testbench::{CheckpointOptions, TestResult, run_native_testbench_debug};
+use veryl_simulator::{Config, Simulator, SimulatorError, output_buffer};
+
+/// The testbench is one thread to the client, forks included.
+const THREAD_ID: i64 = 1;
+
+/// Serves one session over `input` and `output`, returning once the client
+/// disconnects or goes away.
+pub fn run<R, W>(mut input: R, output: W)
+where
+    R: BufRead + Send + 'static,
+    W: Write + 'static,
+{
+    let (sender, requests) = mpsc::channel();
+    std::thread::spawn(move || {
+        while let Ok(Some(message)) = read_message(&mut input) {
+            if let Some(request) = Request::new(&message)
+                && sender.send(request).is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    let session = Rc::new(RefCell::new(Session::new(Client::new(output), requests)));
+    let Some(ir) = session.borrow_mut().configure() else {
+        return;
+    };
+    let module_name = ir.name.to_string();
+
+    // `$display` output would corrupt the protocol on stdout; it goes to the
+    // client as output events instead.
+    output_buffer::enable();
+    let result = run_native_testbench_debug(
+        ir,
+        None,
+        module_name,
+        &CheckpointOptions::default(),
+        Debugger::new(Box::new(Adapter(session.clone()))),
+    );
+    session.borrow_mut().finish(result);
+}
+
+struct Session<W> {
+    client: Client<W>,
+    requests: Receiver<Request>,
+    /// Requests read while the run went on and not answered yet.
+    queue: VecDeque<Request>,
+    /// Line breakpoints by source path, as the client last set them.
+    lines: BTreeMap<String, Vec<u32>>,
+    /// Whether `lines` changed since the last `sync`.
+    dirty: bool,
+    /// The debugger breakpoints `lines` became.
+    line_ids: Vec<u32>,
+    /// The test module, once launched.
+    name: Option<String>,
+    stop_on_entry: bool,
+    /// Instance paths behind the variable references handed out since the
+    /// last stop; reference `n` is `scopes[n - 1]`.
+    scopes: Vec<String>,
+    disconnected: bool,
+}
+
+impl<W: Write> Session<W> {
+    fn new(client: Client<W>, requests: Receiver<Request>) -> Self {
+        Self {
+            client,
+            requests,
+            queue: VecDeque::new(),
+            lines: BTreeMap::new(),
+            dirty: false,
+            line_ids: Vec::new(),
+            name: None,
+            stop_on_entry: false,
+            scopes: Vec::new(),
+            disconnected: false,
+        }
+    }
+
+    fn next(&mut self) -> Option<Request> {
+        self.queue.pop_front().or_else(|| self.requests.recv().ok())
+    }
+
+    /// Answers requests until the test is launched and configured; `None`
+    /// if the client leaves first.
+    fn configure(&mut self) -> Option<Ir> {
+        let mut ir = None;
+        let mut configured = false;
+        while ir.is_none() || !configured {
+            let request = self.next()?;
+            match request.command.as_str() {
+                "initialize" => {
+                    self.client.respond(
+                        &request,
+                        json!({
+                            "supportsConfigurationDoneRequest": true,
+                            "supportsSetVariable": true,
+                            "supportsEvaluateForHovers": true,
+                            "supportsTerminateRequest": true,
+                        }),
+                    );
+                    self.client.event("initialized", json!({}));
+                }
+                "launch" => match self.launch(&request.arguments) {
+                    Ok(x) => {
+                        self.client.respond(&request, json!({}));
+                        ir = Some(x);
+                    }
+                    Err(msg) => self.client.fail(&request, &msg),
+                },
+                "configurationDone" => {
+                    self.client.respond(&request, json!({}));
+                    configured = true;
+                }
+                "disconnect" | "terminate" => {
+                    self.client.respond(&request, json!({}));
+                    return None;
+                }
+                _ => self.handle_any(&request),
+            }
+        }
+        ir
+    }
+
+    /// Loads the test named by the `launch` arguments: `program`, a
+    /// `Veryl.toml` or any file in the project; `test`, the test module;
+    /// and optionally `fourState` and `stopOnEntry`.
+    fn launch(&mut self, args: &Value) -> Result<Ir, String> {
+        let (Some(program), Some(test)) = (args["program"].as_str(), args["test"].as_str()) else {
+            return Err("launch needs `program` and `test`".to_string());
+        };
+        let config = Config {
+            use_4state: args["fourState"].as_bool().unwrap_or(false),
+            debug: true,
+            ..Config::default()
+        };
+        let ir = load_project_with_config(Path::new(program), test, config)
+            .map_err(|e| e.to_string())?;
+        self.name = Some(test.to_string());
+        self.stop_on_entry = args["stopOnEntry"].as_bool().unwrap_or(false);
+        Ok(ir)
+    }
+
+    /// Reports how the run ended, then waits for the client to disconnect.
+    fn finish(&mut self, result: Result<TestResult, SimulatorError>) {
+        self.flush_output();
+        let code = match result {
+            Ok(TestResult::Pass) => 0,
+            Ok(TestResult::Fail(_)) => 1,
+            Err(e) => {
+                self.client.output("stderr", &format!("{e}\n"));
+                1
+            }
+        };
+        self.client.event("exited", json!({ "exitCode": code }));
+        self.client.event("terminated", json!({}));
+        while !self.disconnected
+            && let Some(request) = self.next()
+        {
+            match request.command.as_str() {
+                "disconnect" => {
+                    self.client.respond(&request, json!({}));
+                    self.disconnected = true;
+                }
+                "threads" | "setBreakpoints" => self.handle_any(&request),
+                _ => self.client.fail(&request, "the test has ended"),
+            }
+        }
+    }
+
+    /// Requests answered the same way whether or not the test runs.
+    fn handle_any(&mut self, request: &Request) {
+        match request.command.as_str() {
+            "threads" => {
+                let threads: Vec<_> = self
+                    .name
+                    .iter()
+                    .map(|name| json!({ "id": THREAD_ID, "name": name }))
+                    .collect();
+                self.client.respond(request, json!({ "threads": threads }));
+            }
+            "setBreakpoints" => self.set_breakpoints(request),
+            command => {
+                let msg = format!("`{command}` is not supported");
+                self.client.fail(request, &msg);
+            }
+        }
+    }
+
+    fn set_breakpoints(&mut self, request: &Request) {
+        let args = &request.arguments;
+        let Some(path) = args["source"]["path"].as_str() else {
+            self.client.fail(request, "breakpoints need a source path");
+            return;
+        };
+        // Sources are analyzed under their canonical paths.
+        let path = std::fs::canonicalize(path)
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_else(|_| path.to_string());
+        let lines: Vec<u32> = args["breakpoints"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|x| x["line"].as_u64())
+            .map(|x| x as u32)
+            .collect();
+        let breakpoints: Vec<_> = lines
+            .iter()
+            .map(|line| json!({ "verified": true, "line": line }))
+            .collect();
+        if lines.is_empty() {
+            self.lines.remove(&path);
+        } else {
+            self.lines.insert(path, lines);
+        }
+        self.dirty = true;
+        self.client
+            .respond(request, json!({ "breakpoints": breakpoints }));
+    }
+
+    /// Brings the debugger's line breakpoints in line with the client's.
+    fn sync(&mut self, state: &mut DebugState) {
+        if !self.dirty {
+            return;
+        }
+        for id in self.line_ids.drain(..) {
+            state.remove(id);
+        }
+        for (file, lines) in &self.lines {
+            for line in lines {
+                self.line_ids
+                    .push(state.add_line(Some(file.clone()), *line));
+            }
+        }
+        self.dirty = false;
+    }
+
+    fn flush_output(&mut self) {
+        let text = output_buffer::take();
+        output_buffer::enable();
+        if !text.is_empty() {
+            self.client.output("stdout", &text);
+        }
+    }
+
+    /// The variable reference of the instance at `inst`.
+    fn reference(&mut self, inst: &str) -> usize {
+        let index = match self.scopes.iter().position(|x| x == inst) {
+            Some(index) => index,
+            None => {
+                self.scopes.push(inst.to_string());
+                self.scopes.len() - 1
+            }
+        };
+        index + 1
+    }
+
+    /// Answers one request at a stop; `Some` resumes the run.
+    fn handle_stopped(
+        &mut self,
+        request: &Request,
+        state: &mut DebugState,
+        sim: &mut Simulator,
+    ) -> Option<Resume> {
+        let result = match request.command.as_str() {
+            "continue" => {
+                self.client
+                    .respond(request, json!({ "allThreadsContinued": true }));
+                return Some(Resume::Continue);
+            }
+            "next" => {
+                self.client.respond(request, json!({}));
+                return Some(Resume::Statement);
+            }
+            "stepIn" => {
+                self.client.respond(request, json!({}));
+                return Some(Resume::Cycles(1));
+            }
+            "disconnect" | "terminate" => {
+                self.client.respond(request, json!({}));
+                self.disconnected = request.command == "disconnect";
+                return Some(Resume::Quit);
+            }
+            "pause" => Ok(json!({})),
+            "stackTrace" => Ok(self.stack_trace(state, sim)),
+            "scopes" => {
+                let reference = self.reference("");
+                Ok(json!({
+                    "scopes": [{
+                        "name": "Signals",
+                        "variablesReference": reference,
+                        "expensive": false,
+                    }],
+                }))
+            }
+            "variables" => self.variables(&request.arguments, sim),
+            "setVariable" => self.set_variable(&request.arguments, sim),
+            "evaluate" => {
+                let path = request.arguments["expression"]
+                    .as_str()
+                    .unwrap_or("")
+                    .trim();
+                debugger::resolve(sim, path).map(|signal| {
+                    let value = sim.read_signal(&signal);
+                    json!({ "result": format!("{value:x}"), "variablesReference": 0 })
+                })
+            }
+            _ => {
+                self.handle_any(request);
+                if request.command == "setBreakpoints" {
+                    self.sync(state);
+                }
+                return None;
+            }
+        };
+        match result {
+            Ok(body) => self.client.respond(request, body),
+            Err(msg) => self.client.fail(request, &msg),
+        }
+        None
+    }
+
+    fn stack_trace(&self, state: &DebugState, sim: &Simulator) -> Value {
+        let name = self.name.as_deref().unwrap_or_default();
+        let mut frame = json!({
+            "id": 1,
+            "name": format!("{name} (time {}, cycle {})", sim.time, sim.cycle_count),
+            "line": 0,
+            "column": 0,
+        });
+        if let Some(token) = state.location() {
+            frame["line"] = json!(token.beg.line);
+            frame["column"] = json!(token.beg.column);
+            if let Some(path) = debugger::source_path(token) {
+                let file = path.file_name().map(|x| x.to_string_lossy().into_owned());
+                frame["source"] = json!({ "name": file, "path": path });
+            }
+        }
+        json!({ "stackFrames": [frame], "totalFrames": 1 })
+    }
+
+    /// The child instances, then the variables, of a scope.
+    fn variables(&mut self, args: &Value, sim: &mut Simulator) -> Result<Value, String> {
+        let inst = self.scope(args)?;
+        let module = if inst.is_empty() {
+            &sim.ir.module_variables
+        } else {
+            debugger::find_scope(&sim.ir.module_variables, &inst)?
+        };
+        let mut children: Vec<_> = module
+            .children
+            .iter()
+            .map(|x| (x.name.to_string(), x.module.to_string()))
+            .collect();
+        children.sort();
+        let signals = debugger::variables(module);
+
+        let mut ret = Vec::new();
+        for (name, module) in children {
+            let reference = self.reference(&qualify(&inst, &name));
+            ret.push(json!({
+                "name": name,
+                "value": module,
+                "type": "instance",
+                "variablesReference": reference,
+            }));
+        }
+        for (path, signal) in signals {
+            let value = sim.read_signal(&signal);
+            let name = path.to_string();
+            ret.push(json!({
+                "name": name,
+                "value": format!("{value:x}"),
+                "evaluateName": qualify(&inst, &name),
+                "variablesReference": 0,
+            }));
+        }
+        Ok(json!({ "variables": ret }))
+    }
+
+    fn set_variable(&mut self, args: &Value, sim: &mut Simulator) -> Result<Value, String> {
+        let inst = self.scope(args)?;
+        let name = args["name"].as_str().unwrap_or_default();
+        let value = args["value"].as_str().unwrap_or_default().trim();
+        let signal = debugger::resolve(sim, &qualify(&inst, name))?;
+        sim.write_signal(&signal, debugger::parse_value(value)?);
+        let value = sim.read_signal(&signal);
+        Ok(json!({ "value": format!("{value:x}") }))
+    }
+
+    /// The instance path behind the request's `variablesReference`.
+    fn scope(&self, args: &Value) -> Result<String, String> {
+        args["variablesReference"]
+            .as_u64()
+            .and_then(|x| self.scopes.get((x as usize).checked_sub(1)?))
+            .cloned()
+            .ok_or_else(|| "unknown variable reference".to_string())
+    }
+}
+
+fn qualify(inst: &str, name: &str) -> String {
+    if inst.is_empty() {
+        name.to_string()
+    } else {
+        format!("{inst}.{name}")
+    }
+}
+
+/// The session as the debugger sees it.
+struct Adapter<W>(Rc<RefCell<Session<W>>>);
+
+impl<W: Write> Frontend for Adapter<W> {
+    fn stopped(&mut self, state: &mut DebugState, sim: &mut Simulator, stop: &Stop) -> Resume {
+        let mut session = self.0.borrow_mut();
+        session.sync(state);
+        session.flush_output();
+        let reason = match stop {
+            Stop::Start if !session.stop_on_entry => return Resume::Continue,
+            Stop::Start => "entry",
+            Stop::Step => "step",
+            Stop::Pause => "pause",
+            Stop::Breakpoint { .. } => "breakpoint",
+            Stop::End { .. } => {
+                session.client.output("console", &format!("{stop}\n"));
+                return Resume::Continue;
+            }
+        };
+        session.scopes.clear();
+        session.client.event(
+            "stopped",
+            json!({
+                "reason": reason,
+                "description": stop.to_string(),
+                "threadId": THREAD_ID,
+                "allThreadsStopped": true,
+            }),
+        );
+        while let Some(request) = session.next() {
+            if let Some(resume) = session.handle_stopped(&request, state, sim) {
+                return resume;
+            }
+        }
+        Resume::Quit
+    }
+
+    fn poll(&mut self, state: &mut DebugState, _sim: &mut Simulator) -> bool {
+        let mut session = self.0.borrow_mut();
+        while let Ok(request) = session.requests.try_recv() {
+            session.queue.push_back(request);
+        }
+        while let Some(request) = session.queue.front() {
+            match request.command.as_str() {
+                "pause" => {
+                    let request = session.queue.pop_front().unwrap();
+                    session.client.respond(&request, json!({}));
+                    return true;
+                }
+                // Answered at the stop this makes.
+                "disconnect" | "terminate" => return true,
+                "threads" | "setBreakpoints" => {
+                    let request = session.queue.pop_front().unwrap();
+                    session.handle_any(&request);
+                    session.sync(state);
+                }
+                _ => break,
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,13 @@
+//! `veryl-dap`: a Debug Adapter Protocol server that runs a Veryl test on
+//! the native simulator, for editors to set breakpoints in `.veryl` files,
+//! step clock cycles and inspect the instance hierarchy.
+
+mod adapter;
+mod protocol;
+#[cfg(test)]
+mod tests;
+
+fn main() {
+    let stdin = std::io::BufReader::new(std::io::stdin());
+    adapter::run(stdin, std::io::stdout());
+}
@@ -0,0 +1,121 @@
+//! Debug Adapter Protocol framing: JSON messages behind a `Content-Length`
+//! header, as the Language Server Protocol sends them.
+
+use serde_json::{Value, json};
+use std::io::{self, BufRead, Write};
+
+/// Reads the next message; `None` at the end of the input.
+pub fn read_message(input: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            length = value.trim().parse::<usize>().ok();
+        }
+    }
+    let Some(length) = length else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "message without Content-Length",
+        ));
+    };
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Writes one message.
+pub fn write_message(output: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(output, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    output.flush()
+}
+
+/// A request from the client.
+pub struct Request {
+    pub seq: i64,
+    pub command: String,
+    pub arguments: Value,
+}
+
+impl Request {
+    pub fn new(message: &Value) -> Option<Self> {
+        if message["type"] != "request" {
+            return None;
+        }
+        Some(Self {
+            seq: message["seq"].as_i64()?,
+            command: message["command"].as_str()?.to_string(),
+            arguments: message["arguments"].clone(),
+        })
+    }
+}
+
+/// The sending side of the connection, numbering what it sends.
+pub struct Client<W> {
+    output: W,
+    seq: i64,
+}
+
+impl<W: Write> Client<W> {
+    pub fn new(output: W) -> Self {
+        Self { output, seq: 0 }
+    }
+
+    fn send(&mut self, mut message: Value) {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+        // A client that went away is noticed as the end of its input.
+        let _ = write_message(&mut self.output, &message);
+    }
+
+    pub fn respond(&mut self, request: &Request, body: Value) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "success": true,
+            "command": request.command,
+            "body": body,
+        }));
+    }
+
+    pub fn fail(&mut self, request: &Request, message: &str) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "success": false,
+            "command": request.command,
+            "message": message,
+        }));
+    }
+
+    pub fn event(&mut self, event: &str, body: Value) {
+        self.send(json!({
+            "type": "event",
+            "event": event,
+            "body": body,
+        }));
+    }
+
+    /// Text for the client's debug console.
+    pub fn output(&mut self, category: &str, text: &str) {
+        self.event(
+            "output",
+            json!({
+                "category": category,
+                "output": text,
+            }),
+        );
+    }
+}
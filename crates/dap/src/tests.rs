@@ -0,0 +1,273 @@
+use crate::adapter;
+use crate::protocol::{read_message, write_message};
+use serde_json::{Value, json};
+use std::cell::RefCell;
+use std::io::{Cursor, Write};
+use std::path::Path;
+use std::rc::Rc;
+use veryl_cosim::test_utils::project;
+
+const TOP: &str = r#"module Counter (
+    clk: input  clock     ,
+    rst: input  reset     ,
+    cnt: output logic<32> ,
+) {
+    always_ff {
+        if_reset { cnt = 0; }
+        else     { cnt += 1; }
+    }
+}
+
+#[test(test_dap)]
+module test_dap {
+    inst clk: $tb::clock_gen;
+    inst rst: $tb::reset_gen (clk);
+
+    var cnt: logic<32>;
+    var x  : logic<8> ;
+
+    inst u: Counter (clk, rst, cnt);
+
+    initial {
+        x = 0;
+        rst.assert();
+        clk.next(10);
+        x = 7;
+        $display("x = %d", x);
+        $assert(cnt == 32'd10);
+        $finish();
+    }
+}
+"#;
+
+fn source(dir: &Path) -> String {
+    dir.join("src/top.veryl")
+        .canonicalize()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// 1-based line of the first line of `TOP` containing `pat`.
+fn line_of(pat: &str) -> u32 {
+    TOP.lines().position(|x| x.contains(pat)).unwrap() as u32 + 1
+}
+
+/// Client output the test reads back after the session.
+#[derive(Clone, Default)]
+struct Output(Rc<RefCell<Vec<u8>>>);
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs a session sending `requests` in order and returns everything the
+/// adapter sent back.
+fn session(requests: &[(&str, Value)]) -> Vec<Value> {
+    let mut input = Vec::new();
+    for (seq, (command, arguments)) in requests.iter().enumerate() {
+        let message = json!({
+            "seq": seq + 1,
+            "type": "request",
+            "command": command,
+            "arguments": arguments,
+        });
+        write_message(&mut input, &message).unwrap();
+    }
+    let output = Output::default();
+    adapter::run(Cursor::new(input), output.clone());
+
+    let bytes = output.0.borrow().clone();
+    let mut reader = Cursor::new(bytes);
+    let mut messages = Vec::new();
+    while let Some(message) = read_message(&mut reader).unwrap() {
+        messages.push(message);
+    }
+    messages
+}
+
+/// The body of the response to `command`, after `at`; `at` moves past it.
+fn response<'a>(messages: &'a [Value], at: &mut usize, command: &str) -> &'a Value {
+    let index = messages[*at..]
+        .iter()
+        .position(|x| x["type"] == "response" && x["command"] == command)
+        .unwrap_or_else(|| panic!("no `{command}` response in {messages:#?}"))
+        + *at;
+    *at = index + 1;
+    let message = &messages[index];
+    assert_eq!(message["success"], true, "{message}");
+    &message["body"]
+}
+
+/// The body of the next `event` at or after `at`; `at` moves past it.
+fn event<'a>(messages: &'a [Value], at: &mut usize, event: &str) -> &'a Value {
+    let index = messages[*at..]
+        .iter()
+        .position(|x| x["type"] == "event" && x["event"] == event)
+        .unwrap_or_else(|| panic!("no `{event}` event in {messages:#?}"))
+        + *at;
+    *at = index + 1;
+    &messages[index]["body"]
+}
+
+fn variable<'a>(body: &'a Value, name: &str) -> &'a Value {
+    body["variables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|x| x["name"] == name)
+        .unwrap_or_else(|| panic!("no variable `{name}` in {body}"))
+}
+
+#[test]
+fn breakpoints_step_and_inspect() {
+    let dir = project(TOP);
+    let path = source(dir.path());
+    let line = line_of("x = 7;");
+    let messages = session(&[
+        ("initialize", json!({ "adapterID": "veryl" })),
+        (
+            "launch",
+            json!({ "program": dir.path().join("Veryl.toml"), "test": "test_dap" }),
+        ),
+        (
+            "setBreakpoints",
+            json!({ "source": { "path": path }, "breakpoints": [{ "line": line }] }),
+        ),
+        ("configurationDone", json!({})),
+        ("threads", json!({})),
+        ("stackTrace", json!({ "threadId": 1 })),
+        ("scopes", json!({ "frameId": 1 })),
+        ("variables", json!({ "variablesReference": 1 })),
+        ("variables", json!({ "variablesReference": 2 })),
+        (
+            "setVariable",
+            json!({ "variablesReference": 1, "name": "x", "value": "5" }),
+        ),
+        ("evaluate", json!({ "expression": "u.cnt" })),
+        ("next", json!({ "threadId": 1 })),
+        ("evaluate", json!({ "expression": "x" })),
+        // No `disconnect`: sent now, it would stop the run before the end.
+        ("continue", json!({ "threadId": 1 })),
+    ]);
+
+    let mut at = 0;
+    let caps = response(&messages, &mut at, "initialize");
+    assert_eq!(caps["supportsSetVariable"], true);
+    event(&messages, &mut at, "initialized");
+    response(&messages, &mut at, "launch");
+    let bps = response(&messages, &mut at, "setBreakpoints");
+    assert_eq!(bps["breakpoints"][0]["verified"], true);
+    response(&messages, &mut at, "configurationDone");
+    // Answered while the test runs up to the breakpoint.
+    let threads = response(&messages, &mut at, "threads");
+    assert_eq!(threads["threads"][0]["name"], "test_dap");
+
+    let stopped = event(&messages, &mut at, "stopped");
+    assert_eq!(stopped["reason"], "breakpoint");
+    let frame = &response(&messages, &mut at, "stackTrace")["stackFrames"][0];
+    assert_eq!(frame["line"], line);
+    assert_eq!(frame["source"]["path"], path.as_str());
+    assert!(
+        frame["name"].as_str().unwrap().ends_with(", cycle 10)"),
+        "{frame}"
+    );
+    let scopes = response(&messages, &mut at, "scopes");
+    assert_eq!(scopes["scopes"][0]["variablesReference"], 1);
+
+    // The top scope lists the instance `u` first, expandable as reference 2.
+    let top = response(&messages, &mut at, "variables");
+    assert_eq!(top["variables"][0]["name"], "u");
+    let u = variable(top, "u");
+    assert_eq!(u["value"], "Counter");
+    assert_eq!(u["variablesReference"], 2);
+    assert_eq!(variable(top, "cnt")["value"], "32'h0000000a");
+    assert_eq!(variable(top, "x")["value"], "8'h00");
+    let sub = response(&messages, &mut at, "variables");
+    assert_eq!(variable(sub, "cnt")["evaluateName"], "u.cnt");
+
+    let set = response(&messages, &mut at, "setVariable");
+    assert_eq!(set["value"], "8'h05");
+    let eval = response(&messages, &mut at, "evaluate");
+    assert_eq!(eval["result"], "32'h0000000a");
+
+    response(&messages, &mut at, "next");
+    let stopped = event(&messages, &mut at, "stopped");
+    assert_eq!(stopped["reason"], "step");
+    let eval = response(&messages, &mut at, "evaluate");
+    assert_eq!(eval["result"], "8'h07");
+
+    response(&messages, &mut at, "continue");
+    let output = event(&messages, &mut at, "output");
+    assert_eq!(output["category"], "stdout");
+    assert_eq!(output["output"], "x = 7\n");
+    let output = event(&messages, &mut at, "output");
+    assert_eq!(output["output"], "test passed\n");
+    let exited = event(&messages, &mut at, "exited");
+    assert_eq!(exited["exitCode"], 0);
+    event(&messages, &mut at, "terminated");
+}
+
+#[test]
+fn step_in_advances_one_cycle() {
+    let dir = project(TOP);
+    let messages = session(&[
+        ("initialize", json!({})),
+        (
+            "launch",
+            json!({ "program": source(dir.path()), "test": "test_dap", "stopOnEntry": true }),
+        ),
+        ("configurationDone", json!({})),
+        ("stackTrace", json!({ "threadId": 1 })),
+        ("stepIn", json!({ "threadId": 1 })),
+        ("stepIn", json!({ "threadId": 1 })),
+        ("stackTrace", json!({ "threadId": 1 })),
+        ("terminate", json!({})),
+        ("disconnect", json!({})),
+    ]);
+
+    let mut at = 0;
+    let stopped = event(&messages, &mut at, "stopped");
+    assert_eq!(stopped["reason"], "entry");
+    let frame = &response(&messages, &mut at, "stackTrace")["stackFrames"][0];
+    assert_eq!(frame["name"], "test_dap (time 0, cycle 0)");
+    for _ in 0..2 {
+        response(&messages, &mut at, "stepIn");
+        let stopped = event(&messages, &mut at, "stopped");
+        assert_eq!(stopped["reason"], "step");
+    }
+    let frame = &response(&messages, &mut at, "stackTrace")["stackFrames"][0];
+    assert!(
+        frame["name"].as_str().unwrap().ends_with(", cycle 2)"),
+        "{frame}"
+    );
+    // Two cycles in, the run is inside `clk.next(10)`.
+    assert_eq!(frame["line"], line_of("clk.next(10);"));
+    response(&messages, &mut at, "terminate");
+    let exited = event(&messages, &mut at, "exited");
+    assert_eq!(exited["exitCode"], 1);
+    response(&messages, &mut at, "disconnect");
+}
+
+#[test]
+fn launch_errors_are_reported() {
+    let dir = project(TOP);
+    let messages = session(&[
+        ("initialize", json!({})),
+        (
+            "launch",
+            json!({ "program": dir.path().join("Veryl.toml"), "test": "no_such_test" }),
+        ),
+        ("disconnect", json!({})),
+    ]);
+    let launch = messages.iter().find(|x| x["command"] == "launch").unwrap();
+    assert_eq!(launch["success"], false);
+    assert_eq!(launch["message"], "module \"no_such_test\" not found");
+}
@@ -2665,6 +2665,101 @@ impl Emitter {
         }
     }
 
+    /// `$assert(prop, fmt, args...)` in `always_ff` becomes a concurrent
+    /// assertion clocked by the block when `prop` is a property expression
+    /// (`|->`/`|=>`, optionally with `##` delays); `$assert_continue`
+    /// reports with `$error` instead of `$fatal`. Plain conditions keep the
+    /// immediate form.
+    fn emit_assert_property(&mut self, arg: &IdentifierStatement) -> bool {
+        if !self.in_always_ff {
+            return false;
+        }
+        let IdentifierStatementGroup::FunctionCall(x) = &*arg.identifier_statement_group else {
+            return false;
+        };
+        let Some(list) = &x.function_call.function_call_opt else {
+            return false;
+        };
+        let identifier = arg.expression_identifier.identifier();
+        let fatal = match identifier.to_string().as_str() {
+            "$assert" => true,
+            "$assert_continue" => false,
+            _ => return false,
+        };
+
+        let items: Vec<&ArgumentItem> = list.argument_list.as_ref().into();
+        if items[0]
+            .argument_expression
+            .argument_expression_opt
+            .is_none()
+        {
+            return false;
+        }
+        self.token(&identifier.replace("assert property"));
+        self.space(1);
+        self.l_paren(&x.function_call.l_paren);
+        self.argument_expression(&items[0].argument_expression);
+        self.str(")");
+        let r_paren = &x.function_call.r_paren.r_paren_token;
+        if fatal {
+            self.str(" else $fatal(1");
+        } else if items.len() > 1 {
+            self.str(" else $error(");
+        }
+        for (i, item) in items[1..].iter().enumerate() {
+            if fatal || i != 0 {
+                self.str(", ");
+            }
+            self.argument_expression(&item.argument_expression);
+        }
+        if fatal || items.len() > 1 {
+            self.token(r_paren);
+        } else {
+            self.token(&r_paren.replace(""));
+        }
+        self.semicolon(&arg.semicolon);
+        true
+    }
+
+    /// `$cover(cond, "name")` becomes a cover statement, concurrent and
+    /// clocked by the block in `always_ff` and immediate elsewhere; the name
+    /// only labels the native coverage report. SV has no statement for the
+    /// bins of `$cover_bins`, so it is commented out.
+    fn emit_cover(&mut self, arg: &IdentifierStatement) -> bool {
+        let IdentifierStatementGroup::FunctionCall(x) = &*arg.identifier_statement_group else {
+            return false;
+        };
+        let identifier = arg.expression_identifier.identifier();
+        match identifier.to_string().as_str() {
+            "$cover" => (),
+            "$cover_bins" => {
+                self.str("// ");
+                self.expression_identifier(&arg.expression_identifier);
+                self.emit_function_call(&arg.expression_identifier, &x.function_call);
+                self.semicolon(&arg.semicolon);
+                return true;
+            }
+            _ => return false,
+        }
+        let Some(list) = &x.function_call.function_call_opt else {
+            return false;
+        };
+
+        let items: Vec<&ArgumentItem> = list.argument_list.as_ref().into();
+        let keyword = if self.in_always_ff {
+            "cover property"
+        } else {
+            "cover"
+        };
+        self.token(&identifier.replace(keyword));
+        self.space(1);
+        self.l_paren(&x.function_call.l_paren);
+        self.argument_expression(&items[0].argument_expression);
+        self.r_paren(&x.function_call.r_paren);
+        self.semicolon(&arg.semicolon);
+        true
+    }
+
     fn emit_imports_in_interface(&mut self, import_declarations: &[ImportDeclaration]) {
         if import_declarations.is_empty() {
             return;
@@ -4190,7 +4285,7 @@ impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'IdentifierStatement'
     fn identifier_statement(&mut self, arg: &IdentifierStatement) {
         let connect_statement_emitted = self.emit_connect_statement(arg);
-        if !connect_statement_emitted {
+        if !connect_statement_emitted && !self.emit_assert_property(arg) && !self.emit_cover(arg) {
             // Aligning a call statement would pad its `(` out to the widest name.
             let align = matches!(
                 &*arg.identifier_statement_group,
@@ -4216,6 +4311,18 @@ impl VerylWalker for Emitter {
         }
     }
 
+    /// Semantic action for non-terminal 'PropertyImplication'
+    fn property_implication(&mut self, arg: &PropertyImplication) {
+        self.space(1);
+        self.implication(&arg.implication);
+        self.space(1);
+        if let Some(ref x) = arg.property_implication_opt {
+            self.cycle_delay(&x.cycle_delay);
+            self.space(1);
+        }
+        self.expression(&arg.expression);
+    }
+
     /// Semantic action for non-terminal 'ConcatenationAssignment'
     fn concatenation_assignment(&mut self, arg: &ConcatenationAssignment) {
         self.align_start(align_kind::IDENTIFIER);
@@ -4841,3 +4841,99 @@ fn struct_member_ifdef_keeps_semicolon_inside_guard() {
         "no stray ';' after `endif:\n{ret}"
     );
 }
+
+#[test]
+fn assert_property() {
+    let metadata = Metadata::create_default("prj").unwrap();
+
+    let code = r#"module M (
+    i_clk  : input clock,
+    i_rst  : input reset,
+    i_valid: input logic,
+    i_ready: input logic,
+    i_data : input logic<8>,
+) {
+    always_ff {
+        if_reset {} else {
+            $assert(i_valid |-> ##[0:3] i_ready, "no ready");
+            $assert_continue(i_valid && !i_ready |=> $stable(i_data));
+            $assert(!$rose(i_ready) || $past(i_valid, 2));
+        }
+    }
+}
+"#;
+    let ret = emit(&metadata, code);
+    assert!(
+        ret.contains(
+            r#"assert property (i_valid |-> ##[0:3] i_ready) else $fatal(1, "no ready");"#
+        ),
+        "{ret}"
+    );
+    assert!(
+        ret.contains("assert property (i_valid && !i_ready |=> $stable(i_data));"),
+        "{ret}"
+    );
+    // A plain condition isn't a property and keeps the immediate form.
+    assert!(
+        ret.contains("        $assert(!$rose(i_ready) || $past(i_valid, 2));\n"),
+        "{ret}"
+    );
+}
+
+#[test]
+fn cover() {
+    let metadata = Metadata::create_default("prj").unwrap();
+
+    let code = r#"module M (
+    i_clk: input clock,
+    i_a  : input logic<4>,
+) {
+    always_ff {
+        $cover(i_a == 0, "zero");
+        $cover_bins(i_a, "a", 2);
+        $cover(i_a == 15, "full");
+    }
+    always_comb {
+        $cover_bins(i_a, "a");
+        $cover(i_a[0], "odd");
+    }
+    always_ff {
+        if i_a == 1 {
+            $cover_bins(i_a, "b");
+        }
+        #[ifdef(COVER)]
+        $cover_bins(i_a, "c");
+        $cover(i_a == 2, "two");
+    }
+}
+"#;
+
+    let expect = r#"module prj_M (
+    input var logic         i_clk,
+    input var logic [4-1:0] i_a  
+);
+    always_ff @ (posedge i_clk) begin
+        cover property (i_a == 0);
+        // $cover_bins(i_a, "a", 2);
+        cover property (i_a == 15);
+    end
+    always_comb begin
+        // $cover_bins(i_a, "a");
+        cover (i_a[0]);
+    end
+    always_ff @ (posedge i_clk) begin
+        if (i_a == 1) begin
+            // $cover_bins(i_a, "b");
+        end
+        `ifdef COVER
+        // $cover_bins(i_a, "c");
+        `endif
+        cover property (i_a == 2);
+    end
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let ret = emit(&metadata, code);
+    assert_eq!(ret, expect);
+}
@@ -1021,6 +1021,18 @@ impl VerylWalker for Formatter {
         }
     }
 
+    /// Semantic action for non-terminal 'PropertyImplication'
+    fn property_implication(&mut self, arg: &PropertyImplication) {
+        self.space(1);
+        self.implication(&arg.implication);
+        self.space(1);
+        if let Some(ref x) = arg.property_implication_opt {
+            self.cycle_delay(&x.cycle_delay);
+            self.space(1);
+        }
+        self.expression(&arg.expression);
+    }
+
     /// Semantic action for non-terminal 'StructConstructor'
     fn struct_constructor(&mut self, arg: &StructConstructor) {
         // Width-driven stacked-or-flat layout.
@@ -5,7 +5,7 @@ use crate::server::{
 };
 use async_channel::{Receiver, Sender, unbounded};
 use serde_json::Value;
-use tower_lsp_server::jsonrpc::Result;
+use tower_lsp_server::jsonrpc::{Error, Result};
 use tower_lsp_server::ls_types::Uri as Url;
 use tower_lsp_server::ls_types::*;
 use tower_lsp_server::{Client, LanguageServer};
@@ -108,6 +108,18 @@ impl LanguageServer for Backend {
                 workspace_symbol_provider: Some(OneOf::Left(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        work_done_progress_options: WorkDoneProgressOptions::default(),
+                        resolve_provider: Some(false),
+                    },
+                )),
+                inlay_hint_provider: Some(OneOf::Left(true)),
                 semantic_tokens_provider: Some(
                     SemanticTokensServerCapabilities::SemanticTokensOptions(
                         SemanticTokensOptions {
@@ -331,6 +343,79 @@ impl LanguageServer for Backend {
         Ok(None)
     }
 
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let url = params.text_document.uri;
+        let line = params.position.line as usize + 1;
+        let column = params.position.character as usize + 1;
+
+        self.send(MsgToServer::PrepareRename { url, line, column })
+            .await;
+
+        // Dispose unexpected messages
+        while let Some(x) = self.recv().await {
+            if let MsgFromServer::PrepareRename(x) = x {
+                return Ok(x);
+            }
+        }
+        Ok(None)
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let url = params.text_document_position.text_document.uri;
+        let line = params.text_document_position.position.line as usize + 1;
+        let column = params.text_document_position.position.character as usize + 1;
+        let new_name = params.new_name;
+
+        self.send(MsgToServer::Rename {
+            url,
+            line,
+            column,
+            new_name,
+        })
+        .await;
+
+        // Dispose unexpected messages
+        while let Some(x) = self.recv().await {
+            if let MsgFromServer::Rename(x) = x {
+                return x.map_err(Error::invalid_params);
+            }
+        }
+        Ok(None)
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let url = params.text_document.uri;
+        let range = params.range;
+
+        self.send(MsgToServer::CodeAction { url, range }).await;
+
+        // Dispose unexpected messages
+        while let Some(x) = self.recv().await {
+            if let MsgFromServer::CodeAction(x) = x {
+                return Ok(Some(x));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let url = params.text_document.uri;
+        let range = params.range;
+
+        self.send(MsgToServer::InlayHint { url, range }).await;
+
+        // Dispose unexpected messages
+        while let Some(x) = self.recv().await {
+            if let MsgFromServer::InlayHint(x) = x {
+                return Ok(Some(x));
+            }
+        }
+        Ok(None)
+    }
+
     async fn semantic_tokens_full(
         &self,
         params: SemanticTokensParams,
@@ -0,0 +1,462 @@
+//! Inlay hints, collected when a document is analyzed: the evaluated width
+//! of declarations whose type doesn't spell it out and of the right-hand
+//! side of assignments, the type inferred for declarations without one, the
+//! value of `msb`, inferred generic arguments, and the parameter names of
+//! positional function and generic arguments.
+
+use std::collections::HashMap;
+use tower_lsp_server::ls_types::*;
+use veryl_analyzer::ir::{self, Component, Ir};
+use veryl_analyzer::symbol::{Symbol, SymbolKind, Type, TypeKind};
+use veryl_analyzer::symbol_path::GenericSymbolPath;
+use veryl_analyzer::{generic_inference_table, msb_table, resolved_type_table, symbol_table};
+use veryl_parser::resource_table::{PathId, TokenId};
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, TokenSource};
+use veryl_parser::veryl_walker::VerylWalker;
+
+pub fn collect(veryl: &Veryl, ir: &Ir, path: PathId) -> Vec<InlayHint> {
+    // Struct variables are expanded into their members, which share the
+    // declaration token; only whole variables are taken.
+    let mut types: HashMap<TokenId, ir::Type> = HashMap::new();
+    for component in &ir.components {
+        let variables = match component {
+            Component::Module(x) => &x.variables,
+            Component::Interface(x) => &x.variables,
+            Component::SystemVerilog(_) => continue,
+        };
+        for x in variables.values() {
+            if x.path.0.len() == 1 {
+                types.insert(x.token.beg.id, x.r#type.clone());
+            }
+        }
+    }
+
+    let mut widths = HashMap::new();
+    for component in &ir.components {
+        if let Component::Module(x) = component {
+            for x in &x.declarations {
+                let statements = match x {
+                    ir::Declaration::Comb(x) => &x.statements,
+                    ir::Declaration::Ff(x) => &x.statements,
+                    ir::Declaration::Initial(x) => &x.statements,
+                    ir::Declaration::Final(x) => &x.statements,
+                    _ => continue,
+                };
+                assign_widths(statements, &mut widths);
+            }
+        }
+    }
+
+    let mut collector = Collector {
+        hints: declaration_hints(&types, path),
+        types,
+        widths,
+        selected: Vec::new(),
+    };
+    collector.veryl(veryl);
+
+    let mut ret = collector.hints;
+    ret.sort_by_key(|x| x.position);
+    ret
+}
+
+fn declaration_hints(types: &HashMap<TokenId, ir::Type>, path: PathId) -> Vec<InlayHint> {
+    let mut ret = Vec::new();
+    for symbol in symbol_table::get_all() {
+        if !matches!(symbol.token.source, TokenSource::File { path: x, .. } if x == path) {
+            continue;
+        }
+        let r#type = match &symbol.kind {
+            SymbolKind::Variable(x) => &x.r#type,
+            SymbolKind::Port(x) => &x.r#type,
+            SymbolKind::Parameter(x) => &x.r#type,
+            _ => continue,
+        };
+
+        let label = if r#type.is_inferred() {
+            resolved_type_table::get(&symbol.token.id).map(|x| format!(": {x}"))
+        } else if !is_width_evident(r#type) {
+            types
+                .get(&symbol.token.id)
+                .and_then(|x| x.total_width())
+                .map(bits)
+        } else {
+            None
+        };
+        if let Some(label) = label {
+            ret.push(hint(end_of(&symbol.token), label, InlayHintKind::TYPE));
+        }
+    }
+    ret
+}
+
+/// The width of each non-constant right-hand side in `statements`, keyed by
+/// its first and its last token: a call's range may stop at its name, and a
+/// parenthesized expression's may start inside. Constants take the width of the assignment and need no
+/// hint.
+fn assign_widths(statements: &[ir::Statement], widths: &mut HashMap<TokenId, usize>) {
+    for x in statements {
+        match x {
+            ir::Statement::Assign(x) => {
+                let comptime = x.expr.comptime();
+                if !comptime.is_const
+                    && let Some(width) = comptime.r#type.total_width()
+                {
+                    let range = x.expr.token_range();
+                    widths.insert(range.beg.id, width);
+                    widths.insert(range.end.id, width);
+                }
+            }
+            ir::Statement::If(x) => {
+                assign_widths(&x.true_side, widths);
+                assign_widths(&x.false_side, widths);
+            }
+            ir::Statement::IfReset(x) => {
+                assign_widths(&x.true_side, widths);
+                assign_widths(&x.false_side, widths);
+            }
+            ir::Statement::Case(x) => {
+                for arm in &x.arms {
+                    assign_widths(&arm.body, widths);
+                }
+                assign_widths(&x.default, widths);
+            }
+            ir::Statement::For(x) => assign_widths(&x.body, widths),
+            _ => (),
+        }
+    }
+}
+
+fn bits(width: usize) -> String {
+    format!(": {width} bit{}", if width == 1 { "" } else { "s" })
+}
+
+/// Whether the type spells out its width: a fixed-width type, or
+/// `logic`/`bit` with literal widths only.
+fn is_width_evident(r#type: &Type) -> bool {
+    match r#type.kind {
+        TypeKind::Logic | TypeKind::Bit => r#type.width.iter().all(|x| {
+            let range: TokenRange = x.into();
+            range.beg.id == range.end.id
+                && range
+                    .beg
+                    .to_string()
+                    .starts_with(|x: char| x.is_ascii_digit())
+        }),
+        TypeKind::UserDefined(_) => false,
+        _ => true,
+    }
+}
+
+/// The value `msb` stands for in dimension `dimension` (1-based, unpacked
+/// dimensions first; 0 for the whole struct) of `r#type`.
+fn msb_value(r#type: &ir::Type, dimension: usize) -> Option<usize> {
+    let width = if let Some(dimension) = dimension.checked_sub(1) {
+        let array = r#type.array.as_slice();
+        if let Some(x) = array.get(dimension) {
+            *x
+        } else {
+            *r#type.width().as_slice().get(dimension - array.len())?
+        }
+    } else {
+        r#type.total_width()
+    };
+    width?.checked_sub(1)
+}
+
+struct Collector {
+    types: HashMap<TokenId, ir::Type>,
+    /// Right-hand side widths from [`assign_widths`].
+    widths: HashMap<TokenId, usize>,
+    /// Declarations of the identifiers whose selects are being walked.
+    selected: Vec<Option<TokenId>>,
+    hints: Vec<InlayHint>,
+}
+
+impl Collector {
+    fn push_name(&mut self, name: &str, arg: TokenRange) {
+        // `f(a)` calling `function f(a: ...)` needs no hint.
+        if arg.beg.id == arg.end.id && arg.beg.to_string() == name {
+            return;
+        }
+        let mut hint = hint(
+            start_of(&arg.beg),
+            format!("{name}:"),
+            InlayHintKind::PARAMETER,
+        );
+        hint.padding_left = None;
+        hint.padding_right = Some(true);
+        self.hints.push(hint);
+    }
+
+    /// The width of `rhs`, put before the `;` ending it, unless it is a lone
+    /// identifier or number whose width is evident from its declaration.
+    fn rhs_width(&mut self, rhs: &Expression, semicolon: &Semicolon) {
+        let range: TokenRange = rhs.into();
+        if range.beg.id == range.end.id {
+            return;
+        }
+        let width = self
+            .widths
+            .get(&range.end.id)
+            .or_else(|| self.widths.get(&range.beg.id));
+        if let Some(width) = width {
+            let position = start_of(&semicolon.semicolon_token.token);
+            self.hints
+                .push(hint(position, bits(*width), InlayHintKind::TYPE));
+        }
+    }
+
+    fn function_call(&mut self, callee: &ExpressionIdentifier, call: &FunctionCall) {
+        let token = callee.identifier().token;
+        if let Some(args) = generic_inference_table::get_inferred(token.id) {
+            let args: Vec<_> = args
+                .iter()
+                .map(|x| {
+                    x.paths
+                        .iter()
+                        .map(|x| x.base.to_string())
+                        .collect::<Vec<_>>()
+                        .join("::")
+                })
+                .collect();
+            let mut hint = hint(
+                end_of(&token),
+                format!("::<{}>", args.join(", ")),
+                InlayHintKind::TYPE,
+            );
+            hint.padding_left = None;
+            self.hints.push(hint);
+        }
+
+        let Some(list) = &call.function_call_opt else {
+            return;
+        };
+        let path: GenericSymbolPath = callee.into();
+        let Some(symbol) = resolve_last(&path) else {
+            return;
+        };
+        let function = match &symbol.kind {
+            SymbolKind::ModportFunctionMember(x) => symbol_table::get(x.function),
+            _ => Some(symbol),
+        };
+        let Some(SymbolKind::Function(function)) = function.map(|x| x.kind) else {
+            return;
+        };
+
+        let list = &list.argument_list;
+        let items = std::iter::once(list.argument_item.as_ref()).chain(
+            list.argument_list_list
+                .iter()
+                .map(|x| x.argument_item.as_ref()),
+        );
+        for (item, port) in items.zip(&function.ports) {
+            // Named arguments carry their names already.
+            if item.argument_item_opt.is_none() {
+                self.push_name(&port.name().to_string(), item.into());
+            }
+        }
+    }
+
+    fn generic_arguments(&mut self, path: &GenericSymbolPath, i: usize, arg: &WithGenericArgument) {
+        let Some(list) = &arg.with_generic_argument_opt else {
+            return;
+        };
+        let Ok(symbol) = symbol_table::resolve_base_path(path, i, path.paths[0].base.id) else {
+            return;
+        };
+
+        let list = &list.with_generic_argument_list;
+        let items = std::iter::once(list.with_generic_argument_item.as_ref()).chain(
+            list.with_generic_argument_list_list
+                .iter()
+                .map(|x| x.with_generic_argument_item.as_ref()),
+        );
+        for (item, (name, _)) in items.zip(symbol.found.generic_parameters()) {
+            self.push_name(&name.to_string(), item.into());
+        }
+    }
+}
+
+impl VerylWalker for Collector {
+    /// Semantic action for non-terminal 'ExpressionIdentifier'
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) {
+        // `msb` in a member's select depends on the member type; only plain
+        // variables are followed.
+        let selected = if arg.expression_identifier_list0.is_empty() {
+            let path: GenericSymbolPath = arg.into();
+            resolve_last(&path).map(|x| x.token.id)
+        } else {
+            None
+        };
+        self.selected.push(selected);
+
+        self.scoped_identifier(&arg.scoped_identifier);
+        if let Some(ref x) = arg.expression_identifier_opt {
+            self.width(&x.width);
+        }
+        for x in &arg.expression_identifier_list {
+            self.select(&x.select);
+        }
+        for x in &arg.expression_identifier_list0 {
+            self.dot(&x.dot);
+            self.identifier(&x.identifier);
+            for x in &x.expression_identifier_list0_list {
+                self.select(&x.select);
+            }
+        }
+
+        self.selected.pop();
+    }
+
+    /// Semantic action for non-terminal 'Msb'
+    fn msb(&mut self, arg: &Msb) {
+        let token = arg.msb_token.token;
+        let value = self
+            .selected
+            .last()
+            .copied()
+            .flatten()
+            .and_then(|x| self.types.get(&x))
+            .zip(msb_table::get(token.id))
+            .and_then(|(r#type, dimension)| msb_value(r#type, dimension));
+        if let Some(value) = value {
+            self.hints.push(hint(
+                end_of(&token),
+                format!("= {value}"),
+                InlayHintKind::TYPE,
+            ));
+        }
+    }
+
+    /// Semantic action for non-terminal 'ScopedIdentifier'
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) {
+        let path: GenericSymbolPath = arg.into();
+        match &*arg.scoped_identifier_group {
+            ScopedIdentifierGroup::IdentifierScopedIdentifierOpt(x) => {
+                self.identifier(&x.identifier);
+                if let Some(ref x) = x.scoped_identifier_opt {
+                    self.generic_arguments(&path, 0, &x.with_generic_argument);
+                    self.with_generic_argument(&x.with_generic_argument);
+                }
+            }
+            ScopedIdentifierGroup::DollarIdentifier(x) => {
+                self.dollar_identifier(&x.dollar_identifier)
+            }
+        }
+        for (i, x) in arg.scoped_identifier_list.iter().enumerate() {
+            self.colon_colon(&x.colon_colon);
+            self.identifier(&x.identifier);
+            if let Some(ref x) = x.scoped_identifier_opt0 {
+                self.generic_arguments(&path, i + 1, &x.with_generic_argument);
+                self.with_generic_argument(&x.with_generic_argument);
+            }
+        }
+    }
+
+    /// Semantic action for non-terminal 'IdentifierFactor'
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) {
+        self.expression_identifier(&arg.expression_identifier);
+        if let Some(ref x) = arg.identifier_factor_opt {
+            match x.identifier_factor_opt_group.as_ref() {
+                IdentifierFactorOptGroup::FunctionCall(x) => {
+                    self.function_call(&arg.expression_identifier, &x.function_call);
+                    VerylWalker::function_call(self, &x.function_call);
+                }
+                IdentifierFactorOptGroup::StructConstructor(x) => {
+                    self.struct_constructor(&x.struct_constructor);
+                }
+            }
+        }
+    }
+
+    /// Semantic action for non-terminal 'IdentifierStatement'
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) {
+        self.expression_identifier(&arg.expression_identifier);
+        match &*arg.identifier_statement_group {
+            IdentifierStatementGroup::FunctionCall(x) => {
+                self.function_call(&arg.expression_identifier, &x.function_call);
+                VerylWalker::function_call(self, &x.function_call);
+            }
+            IdentifierStatementGroup::Assignment(x) => {
+                self.assignment(&x.assignment);
+                self.rhs_width(&x.assignment.expression, &arg.semicolon);
+            }
+        }
+        self.semicolon(&arg.semicolon);
+    }
+
+    /// Semantic action for non-terminal 'LetStatement'
+    fn let_statement(&mut self, arg: &LetStatement) {
+        self.r#let(&arg.r#let);
+        self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.let_statement_opt {
+            self.colon(&x.colon);
+            if let Some(ref y) = x.let_statement_opt0 {
+                self.clock_domain(&y.clock_domain);
+            }
+            self.array_type(&x.array_type);
+        }
+        self.equ(&arg.equ);
+        self.expression(&arg.expression);
+        self.rhs_width(&arg.expression, &arg.semicolon);
+        self.semicolon(&arg.semicolon);
+    }
+
+    /// Semantic action for non-terminal 'LetDeclaration'
+    fn let_declaration(&mut self, arg: &LetDeclaration) {
+        self.r#let(&arg.r#let);
+        self.identifier(&arg.identifier);
+        if let Some(ref x) = arg.let_declaration_opt {
+            self.colon(&x.colon);
+            if let Some(ref y) = x.let_declaration_opt0 {
+                self.clock_domain(&y.clock_domain);
+            }
+            self.array_type(&x.array_type);
+        }
+        self.equ(&arg.equ);
+        self.expression(&arg.expression);
+        self.rhs_width(&arg.expression, &arg.semicolon);
+        self.semicolon(&arg.semicolon);
+    }
+
+    /// Semantic action for non-terminal 'AssignDeclaration'
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) {
+        self.assign(&arg.assign);
+        self.assign_destination(&arg.assign_destination);
+        self.equ(&arg.equ);
+        self.expression(&arg.expression);
+        self.rhs_width(&arg.expression, &arg.semicolon);
+        self.semicolon(&arg.semicolon);
+    }
+}
+
+fn resolve_last(path: &GenericSymbolPath) -> Option<Symbol> {
+    let last = path.len().checked_sub(1)?;
+    let symbol = symbol_table::resolve_base_path(path, last, path.paths[0].base.id).ok()?;
+    Some((*symbol.found).clone())
+}
+
+fn start_of(token: &Token) -> Position {
+    Position::new(token.line - 1, token.column - 1)
+}
+
+fn end_of(token: &Token) -> Position {
+    let chars = token.to_string().chars().count() as u32;
+    Position::new(token.line - 1, token.column - 1 + chars)
+}
+
+fn hint(position: Position, label: String, kind: InlayHintKind) -> InlayHint {
+    InlayHint {
+        position,
+        label: InlayHintLabel::String(label),
+        kind: Some(kind),
+        text_edits: None,
+        tooltip: None,
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    }
+}
@@ -2,6 +2,7 @@
 
 mod backend;
 mod incremental;
+mod inlay_hint;
 mod keyword;
 mod server;
 #[cfg(test)]
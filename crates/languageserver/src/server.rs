@@ -1,15 +1,17 @@
 use crate::incremental::LsIncrementalMap;
+use crate::inlay_hint;
 use crate::keyword::KEYWORDS;
 use async_channel::{Receiver, Sender};
 use dashmap::DashMap;
 use futures::executor::block_on;
 use ropey::Rope;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 use tower_lsp_server::Client;
 use tower_lsp_server::ls_types::ClientCapabilities;
 use tower_lsp_server::ls_types::Uri as Url;
 use tower_lsp_server::ls_types::*;
+use veryl_analyzer::fix::{self, Fix};
 use veryl_analyzer::namespace::Namespace;
 use veryl_analyzer::symbol::SymbolKind as VerylSymbolKind;
 use veryl_analyzer::symbol::{Symbol, TbComponentKind, TypeKind};
@@ -20,7 +22,7 @@ use veryl_analyzer::{
 use veryl_formatter::Formatter;
 use veryl_metadata::{ComponentManifest, Metadata};
 use veryl_parser::resource_table;
-use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_token::{Token, TokenSource};
 use veryl_parser::veryl_walker::VerylWalker;
 use veryl_parser::{Finder, Parser, ParserError};
 use veryl_path::PathSet;
@@ -90,6 +92,25 @@ pub enum MsgToServer {
         line: usize,
         column: usize,
     },
+    CodeAction {
+        url: Url,
+        range: Range,
+    },
+    InlayHint {
+        url: Url,
+        range: Range,
+    },
+    PrepareRename {
+        url: Url,
+        line: usize,
+        column: usize,
+    },
+    Rename {
+        url: Url,
+        line: usize,
+        column: usize,
+        new_name: String,
+    },
     SemanticTokens {
         url: Url,
     },
@@ -104,6 +125,10 @@ pub enum MsgFromServer {
     Symbol(Vec<SymbolInformation>),
     Hover(Option<Hover>),
     References(Vec<Location>),
+    CodeAction(CodeActionResponse),
+    InlayHint(Vec<InlayHint>),
+    PrepareRename(Option<PrepareRenameResponse>),
+    Rename(Result<Option<WorkspaceEdit>, String>),
     SemanticTokens(Option<SemanticTokensResult>),
     Formatting(Option<Vec<TextEdit>>),
 }
@@ -139,6 +164,8 @@ pub struct Server {
     document_map: DashMap<PathBuf, Rope>,
     parser_map: DashMap<PathBuf, Parser>,
     metadata_map: DashMap<PathBuf, Metadata>,
+    fix_map: DashMap<PathBuf, Vec<(Diagnostic, Fix)>>,
+    inlay_hint_map: DashMap<PathBuf, Vec<InlayHint>>,
     cache_dir: PathBuf,
     lsp_token: i32,
     background_tasks: VecDeque<BackgroundTask>,
@@ -158,6 +185,8 @@ impl Server {
             document_map: DashMap::new(),
             parser_map: DashMap::new(),
             metadata_map: DashMap::new(),
+            fix_map: DashMap::new(),
+            inlay_hint_map: DashMap::new(),
             cache_dir: veryl_path::cache_path(),
             lsp_token: 0,
             background_tasks: VecDeque::new(),
@@ -202,6 +231,17 @@ impl Server {
                     MsgToServer::References { url, line, column } => {
                         self.references(&url, line, column)
                     }
+                    MsgToServer::CodeAction { url, range } => self.code_action(&url, &range),
+                    MsgToServer::InlayHint { url, range } => self.inlay_hint(&url, &range),
+                    MsgToServer::PrepareRename { url, line, column } => {
+                        self.prepare_rename(&url, line, column)
+                    }
+                    MsgToServer::Rename {
+                        url,
+                        line,
+                        column,
+                        new_name,
+                    } => self.rename(&url, line, column, &new_name),
                     MsgToServer::SemanticTokens { url } => self.semantic_tokens(&url),
                     MsgToServer::Formatting { url } => self.formatting(&url),
                 }
@@ -365,38 +405,11 @@ impl Server {
     }
 
     fn goto_definition(&mut self, url: &Url, line: usize, column: usize) {
-        if let Some(path) = url.to_file_path()
-            && let Some(parser) = self.parser_map.get(path.as_ref())
-        {
-            let mut finder = Finder::new();
-            finder.line = line as u32;
-            finder.column = column as u32;
-            finder.veryl(&parser.veryl);
-
-            if let Some(token) = finder.token
-                && let Some((scope, define_context)) = scope::token_scope(token.id)
-            {
-                let path = if finder.token_group.is_empty() {
-                    SymbolPath::new(&[token.text])
-                } else {
-                    SymbolPath::from(finder.token_group.as_slice())
-                };
-                if let Ok(symbol) = symbol_table::resolve(SymbolPathNamespace::from_scope(
-                    path,
-                    scope,
-                    define_context,
-                )) {
-                    let location = to_location(&symbol.found.token);
-                    self.snd
-                        .send_blocking(MsgFromServer::GotoDefinition(location))
-                        .unwrap();
-                    return;
-                }
-            }
-        }
-
+        let location = self
+            .symbol_at(url, line, column)
+            .and_then(|(_, symbol)| to_location(&symbol.token));
         self.snd
-            .send_blocking(MsgFromServer::GotoDefinition(None))
+            .send_blocking(MsgFromServer::GotoDefinition(location))
             .unwrap();
     }
 
@@ -497,38 +510,93 @@ impl Server {
     }
 
     fn references(&mut self, url: &Url, line: usize, column: usize) {
+        let mut ret = Vec::new();
+        if let Some((_, symbol)) = self.symbol_at(url, line, column) {
+            let refs = symbol_table::get_references(symbol.id).unwrap_or_default();
+            for reference in &refs {
+                if let Some(location) = to_location(reference) {
+                    ret.push(location);
+                }
+            }
+        }
+        self.snd
+            .send_blocking(MsgFromServer::References(ret))
+            .unwrap();
+    }
+
+    fn prepare_rename(&mut self, url: &Url, line: usize, column: usize) {
+        let ret = self
+            .symbol_at(url, line, column)
+            .filter(|(token, symbol)| self.is_renamable(token, symbol))
+            .and_then(|(token, _)| to_location(&token))
+            .map(|x| PrepareRenameResponse::Range(x.range));
+        self.snd
+            .send_blocking(MsgFromServer::PrepareRename(ret))
+            .unwrap();
+    }
+
+    fn rename(&mut self, url: &Url, line: usize, column: usize, new_name: &str) {
+        let ret = match self.symbol_at(url, line, column) {
+            Some((token, symbol)) if self.is_renamable(&token, &symbol) => {
+                check_new_name(&symbol, new_name).map(|_| {
+                    // Dependencies are read-only; they can't refer to the
+                    // project anyway.
+                    let edits = fix::rename(&symbol, new_name)
+                        .into_iter()
+                        .filter(|x| !x.path.starts_with(&self.cache_dir))
+                        .collect();
+                    Some(to_workspace_edit(edits))
+                })
+            }
+            Some((token, _)) => Err(format!("\"{token}\" can't be renamed")),
+            None => Ok(None),
+        };
+        self.snd.send_blocking(MsgFromServer::Rename(ret)).unwrap();
+    }
+
+    fn code_action(&mut self, url: &Url, range: &Range) {
         let mut ret = Vec::new();
         if let Some(path) = url.to_file_path()
-            && let Some(parser) = self.parser_map.get(path.as_ref())
+            && let Some(fixes) = self.fix_map.get(path.as_ref())
         {
-            let mut finder = Finder::new();
-            finder.line = line as u32;
-            finder.column = column as u32;
-            finder.veryl(&parser.veryl);
-            if let Some(token) = finder.token
-                && let Some((scope, define_context)) = scope::token_scope(token.id)
-            {
-                let path = if finder.token_group.is_empty() {
-                    SymbolPath::new(&[token.text])
-                } else {
-                    SymbolPath::from(finder.token_group.as_slice())
-                };
-                if let Ok(symbol) = symbol_table::resolve(SymbolPathNamespace::from_scope(
-                    path,
-                    scope,
-                    define_context,
-                )) {
-                    let refs = symbol_table::get_references(symbol.found.id).unwrap_or_default();
-                    for reference in &refs {
-                        if let Some(location) = to_location(reference) {
-                            ret.push(location);
-                        }
-                    }
+            for (diag, fix) in fixes.iter() {
+                if diag.range.start > range.end || range.start > diag.range.end {
+                    continue;
                 }
+
+                let edit = to_workspace_edit(fix.edits());
+                if edit.changes.as_ref().is_none_or(|x| x.is_empty()) {
+                    continue;
+                }
+
+                ret.push(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: fix.title.clone(),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diag.clone()]),
+                    edit: Some(edit),
+                    is_preferred: Some(true),
+                    ..Default::default()
+                }));
             }
         }
         self.snd
-            .send_blocking(MsgFromServer::References(ret))
+            .send_blocking(MsgFromServer::CodeAction(ret))
+            .unwrap();
+    }
+
+    fn inlay_hint(&mut self, url: &Url, range: &Range) {
+        let mut ret = Vec::new();
+        if let Some(path) = url.to_file_path()
+            && let Some(hints) = self.inlay_hint_map.get(path.as_ref())
+        {
+            ret = hints
+                .iter()
+                .filter(|x| range.start <= x.position && x.position <= range.end)
+                .cloned()
+                .collect();
+        }
+        self.snd
+            .send_blocking(MsgFromServer::InlayHint(ret))
             .unwrap();
     }
 
@@ -745,6 +813,45 @@ impl Server {
         }
     }
 
+    /// The token under the cursor and the symbol it refers to.
+    fn symbol_at(&self, url: &Url, line: usize, column: usize) -> Option<(Token, Symbol)> {
+        let path = url.to_file_path()?;
+        let parser = self.parser_map.get(path.as_ref())?;
+
+        let mut finder = Finder::new();
+        finder.line = line as u32;
+        finder.column = column as u32;
+        finder.veryl(&parser.veryl);
+
+        let token = finder.token?;
+        let (scope, define_context) = scope::token_scope(token.id)?;
+        let path = if finder.token_group.is_empty() {
+            SymbolPath::new(&[token.text])
+        } else {
+            SymbolPath::from(finder.token_group.as_slice())
+        };
+        let symbol =
+            symbol_table::resolve(SymbolPathNamespace::from_scope(path, scope, define_context))
+                .ok()?;
+        Some((token, (*symbol.found).clone()))
+    }
+
+    /// Only symbols declared in the sources of an open project can be
+    /// renamed; builtins and dependencies are read-only.
+    fn is_renamable(&self, token: &Token, symbol: &Symbol) -> bool {
+        let TokenSource::File { path, .. } = symbol.token.source else {
+            return false;
+        };
+        let in_cache =
+            resource_table::get_path_value(path).is_none_or(|x| x.starts_with(&self.cache_dir));
+        token.text == symbol.token.text
+            && !in_cache
+            && !matches!(
+                symbol.kind,
+                VerylSymbolKind::Namespace | VerylSymbolKind::SystemVerilog
+            )
+    }
+
     fn get_metadata(&mut self, url: &Url) -> Option<Metadata> {
         if let Some(path) = url.to_file_path() {
             if let Some(metadata) = self.metadata_map.get(path.as_ref()) {
@@ -774,6 +881,8 @@ impl Server {
                 if let Some(path_id) = resource_table::get_path_id(path.to_path_buf()) {
                     Analyzer::drop_file(path_id, Some(prj.into()));
                 }
+                let mut fixes = Vec::new();
+                let mut hints = Vec::new();
                 let diag = match Parser::parse(text, &path) {
                     Ok(x) => {
                         let path_id = resource_table::get_path_id(path.to_path_buf());
@@ -812,10 +921,18 @@ impl Server {
                             // Filter errors caused by background sources
                             .filter(|x| x.token_source() == path_id)
                             .map(|x| {
+                                let fix = x.fix().cloned();
                                 let x: miette::ErrReport = x.into();
-                                to_diag(x, &rope)
+                                let diag = to_diag(x, &rope);
+                                if let Some(fix) = fix {
+                                    fixes.push((diag.clone(), fix));
+                                }
+                                diag
                             })
                             .collect();
+                        if let Some(path_id) = path_id {
+                            hints = inlay_hint::collect(&x.veryl, &ir, path_id);
+                        }
                         self.parser_map.insert(path.to_path_buf(), x);
                         ret
                     }
@@ -825,6 +942,8 @@ impl Server {
                     }
                 };
 
+                self.fix_map.insert(path.to_path_buf(), fixes);
+                self.inlay_hint_map.insert(path.to_path_buf(), hints);
                 block_on(
                     self.client
                         .publish_diagnostics(url.clone(), diag, Some(version)),
@@ -841,6 +960,10 @@ impl Server {
     }
 
     fn on_remove(&mut self, url: Url) {
+        if let Some(path) = url.to_file_path() {
+            self.fix_map.remove(path.as_ref());
+            self.inlay_hint_map.remove(path.as_ref());
+        }
         if let Some(path) = url.to_file_path()
             && let Some(path_id) = resource_table::get_path_id(path.to_path_buf())
         {
@@ -952,6 +1075,52 @@ fn label_to_related(
     })
 }
 
+fn to_workspace_edit(edits: Vec<fix::TextEdit>) -> WorkspaceEdit {
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    for edit in edits {
+        if let Some(uri) = Url::from_file_path(&edit.path) {
+            let range = Range::new(
+                Position::new(edit.beg_line - 1, edit.beg_column - 1),
+                Position::new(edit.end_line - 1, edit.end_column - 1),
+            );
+            changes
+                .entry(uri)
+                .or_default()
+                .push(TextEdit::new(range, edit.text));
+        }
+    }
+    WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    }
+}
+
+/// Rejects a name which isn't an identifier or is taken in the namespace of
+/// `symbol`.
+fn check_new_name(symbol: &Symbol, name: &str) -> Result<(), String> {
+    let body = name.strip_prefix("r#").unwrap_or(name);
+    let mut chars = body.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|x| x.is_ascii_alphabetic() || x == '_')
+        && chars.all(|x| x.is_ascii_alphanumeric() || x == '_' || x == '$');
+    if !is_identifier {
+        return Err(format!("\"{name}\" is not a valid identifier"));
+    }
+    if body == name && KEYWORDS.contains(&name) {
+        return Err(format!("\"{name}\" is a keyword"));
+    }
+
+    let path = SymbolPath::new(&[resource_table::insert_str(name)]);
+    if let Ok(x) = symbol_table::resolve((&path, &symbol.namespace))
+        && x.found.namespace == symbol.namespace
+        && x.found.id != symbol.id
+    {
+        return Err(format!("\"{name}\" is already defined"));
+    }
+    Ok(())
+}
+
 fn to_location(token: &Token) -> Option<Location> {
     let line = token.line - 1;
     let column = token.column - 1;
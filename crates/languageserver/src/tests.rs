@@ -93,6 +93,18 @@ impl TestServer {
         serde_json::from_str(&res).unwrap()
     }
 
+    /// Receives until the response to request `id` arrives, skipping
+    /// interleaved notifications.
+    async fn recv_response_to(&mut self, id: i64) -> Value {
+        loop {
+            let msg = self.recv_message().await;
+            let value: Value = serde_json::from_str(&msg).unwrap();
+            if value["id"] == json!(id) && value.get("method").is_none() {
+                return value;
+            }
+        }
+    }
+
     /// Receives until a response (a message carrying `result`) arrives,
     /// skipping interleaved notifications.
     async fn recv_response_skipping(&mut self) -> Value {
@@ -203,6 +215,76 @@ async fn completion_tb_component_method() {
     );
 }
 
+fn build_rename(id: i64, line: u32, character: u32, new_name: Option<&str>) -> Request {
+    let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    path.pop();
+    path.pop();
+    path.push("test.veryl");
+    let uri = Url::from_file_path(path).unwrap();
+
+    let position = TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier { uri },
+        position: Position { line, character },
+    };
+
+    if let Some(new_name) = new_name {
+        let params = RenameParams {
+            text_document_position: position,
+            new_name: new_name.to_string(),
+            work_done_progress_params: Default::default(),
+        };
+        Request::build("textDocument/rename")
+            .params(json!(params))
+            .id(id)
+            .finish()
+    } else {
+        Request::build("textDocument/prepareRename")
+            .params(json!(position))
+            .id(id)
+            .finish()
+    }
+}
+
+fn build_code_action(id: i64, range: Range) -> Request {
+    let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    path.pop();
+    path.pop();
+    path.push("test.veryl");
+    let uri = Url::from_file_path(path).unwrap();
+
+    let params = CodeActionParams {
+        text_document: TextDocumentIdentifier { uri },
+        range,
+        context: CodeActionContext::default(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+
+    Request::build("textDocument/codeAction")
+        .params(json!(params))
+        .id(id)
+        .finish()
+}
+
+fn build_inlay_hint(id: i64, range: Range) -> Request {
+    let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    path.pop();
+    path.pop();
+    path.push("test.veryl");
+    let uri = Url::from_file_path(path).unwrap();
+
+    let params = InlayHintParams {
+        text_document: TextDocumentIdentifier { uri },
+        range,
+        work_done_progress_params: Default::default(),
+    };
+
+    Request::build("textDocument/inlayHint")
+        .params(json!(params))
+        .id(id)
+        .finish()
+}
+
 #[tokio::test]
 #[ntest::timeout(60000)]
 async fn did_open() {
@@ -306,6 +388,224 @@ async fn combinational_loop_diagnostic() {
     );
 }
 
+#[tokio::test]
+#[ntest::timeout(60000)]
+async fn code_action() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let _ = server.recv_response().await;
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let code = "module A {\n    var always: logic;\n    assign always = 1;\n}\n";
+    let req = build_did_open(code);
+    server.send_request(req).await;
+
+    // did_open log
+    let _ = server.recv_notification().await;
+
+    // diagnostics
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+
+    let range = Range::new(Position::new(1, 10), Position::new(1, 10));
+    let req = build_code_action(2, range);
+    server.send_request(req).await;
+
+    let res = server.recv_response_skipping().await;
+    let actions = res["result"].as_array().unwrap();
+    let action = actions
+        .iter()
+        .find(|x| x["title"] == "rename \"always\" to \"always_\"")
+        .unwrap_or_else(|| panic!("expected a rename quick fix, got: {actions:?}"));
+    assert_eq!(action["kind"], "quickfix");
+    assert_eq!(action["diagnostics"][0]["code"], "sv_keyword_usage");
+
+    let changes = action["edit"]["changes"].as_object().unwrap();
+    let edits = changes.values().next().unwrap().as_array().unwrap();
+    let mut lines: Vec<_> = edits
+        .iter()
+        .map(|x| {
+            assert_eq!(x["newText"], "always_");
+            x["range"]["start"]["line"].as_u64().unwrap()
+        })
+        .collect();
+    lines.sort();
+    assert_eq!(lines, vec![1, 2]);
+}
+
+#[tokio::test]
+#[ntest::timeout(60000)]
+async fn rename() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let _ = server.recv_response().await;
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let code = "module A (\n    i_a: input logic,\n) {\n    let _b: logic = i_a;\n}\nmodule B {\n    var i_a: logic;\n    assign i_a = 1;\n    inst u: A (i_a);\n}\n";
+    let req = build_did_open(code);
+    server.send_request(req).await;
+
+    // did_open log
+    let _ = server.recv_notification().await;
+
+    // diagnostics
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+
+    // The port `i_a` of `A`, renamed from its reference.
+    let req = build_rename(2, 3, 21, None);
+    server.send_request(req).await;
+    let res = server.recv_response_skipping().await;
+    assert_eq!(res["result"]["start"], json!({"line": 3, "character": 20}));
+    assert_eq!(res["result"]["end"], json!({"line": 3, "character": 23}));
+
+    let req = build_rename(3, 3, 21, Some("i_x"));
+    server.send_request(req).await;
+    let res = server.recv_response_skipping().await;
+    let changes = res["result"]["changes"].as_object().unwrap();
+    let mut edits: Vec<_> = changes
+        .values()
+        .next()
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| {
+            (
+                x["range"]["start"]["line"].as_u64().unwrap(),
+                x["newText"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    edits.sort();
+    assert_eq!(
+        edits,
+        vec![
+            (1, "i_x".to_string()),
+            (3, "i_x".to_string()),
+            (8, "i_x: i_a".to_string()),
+        ]
+    );
+
+    // Keywords and names taken in the same scope are rejected.
+    let req = build_rename(4, 6, 8, Some("module"));
+    server.send_request(req).await;
+    let res = server.recv_response_to(4).await;
+    assert_eq!(res["error"]["code"], json!(-32602));
+
+    let req = build_rename(5, 6, 8, Some("u"));
+    server.send_request(req).await;
+    let res = server.recv_response_to(5).await;
+    assert_eq!(res["error"]["message"], "\"u\" is already defined");
+}
+
+#[tokio::test]
+#[ntest::timeout(60000)]
+async fn inlay_hint() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let _ = server.recv_response().await;
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let code = r#"module A #(
+    param W: u32 = 4,
+) (
+    i_a: input logic<W>,
+) {
+    function F::<T: u32> (
+        x: input logic<T>,
+    ) -> logic<T> {
+        return x;
+    }
+
+    let _b          = i_a;
+    let _c: logic   = i_a[msb];
+    let _d: logic<W> = F(i_a);
+    let _e: logic<8> = 1;
+    let _f: logic<8> = F(_e);
+    let _g: logic<8> = F::<8>(_e);
+
+    var _h: logic<8>;
+    var _i: logic<8>;
+    assign _h = {i_a, i_a};
+    always_comb {
+        _i = i_a & i_a;
+    }
+}
+"#;
+    let req = build_did_open(code);
+    server.send_request(req).await;
+
+    // did_open log
+    let _ = server.recv_notification().await;
+
+    // diagnostics
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+
+    let range = Range::new(Position::new(0, 0), Position::new(30, 0));
+    let req = build_inlay_hint(2, range);
+    server.send_request(req).await;
+
+    let res = server.recv_response_skipping().await;
+    let hints: Vec<_> = res["result"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| {
+            (
+                x["position"]["line"].as_u64().unwrap(),
+                x["position"]["character"].as_u64().unwrap(),
+                x["label"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    let hint = |line, character, label: &str| (line, character, label.to_string());
+    assert_eq!(
+        hints,
+        vec![
+            hint(3, 7, ": 4 bits"),
+            hint(11, 10, ": logic<4>"),
+            hint(12, 29, "= 3"),
+            hint(12, 30, ": 1 bit"),
+            hint(13, 10, ": 4 bits"),
+            hint(13, 25, "x:"),
+            hint(15, 24, "::<8>"),
+            hint(15, 25, "x:"),
+            hint(16, 27, "T:"),
+            hint(16, 30, "x:"),
+            hint(16, 33, ": 8 bits"),
+            hint(20, 26, ": 8 bits"),
+            hint(22, 22, ": 4 bits"),
+        ]
+    );
+
+    // Hints outside the range are left out.
+    let range = Range::new(Position::new(12, 0), Position::new(12, 40));
+    let req = build_inlay_hint(3, range);
+    server.send_request(req).await;
+    let res = server.recv_response_skipping().await;
+    assert_eq!(res["result"].as_array().unwrap().len(), 2);
+}
+
 #[tokio::test]
 #[ntest::timeout(60000)]
 async fn progress() {
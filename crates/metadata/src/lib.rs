@@ -31,7 +31,7 @@ pub use component_manifest::{
 pub use doc::Doc;
 pub use format::{Format, NewlineStyle};
 pub use git::Git;
-pub use lint::{Case, Lint};
+pub use lint::{Case, Lint, LintLevel, LintRule, LintRules};
 pub use lockfile::{DependencyComponents, LockSource, Lockfile};
 pub use metadata::{BumpKind, Metadata, ProjectProperty, UrlPath, check_project_name};
 pub use metadata_error::MetadataError;
@@ -42,7 +42,7 @@ pub use project::Project;
 pub use pubfile::{Pubfile, Release};
 pub use publish::Publish;
 pub use semver;
-pub use synth::{Library, Synth};
+pub use synth::{ClockConstraint, Library, Synth, TimingConstraints};
 pub use test::{ComponentBackendKind, SimType, Test, WaveFormFormat, WaveFormTarget};
 pub use wasm_section::{append_wasm_custom_section, wasm_custom_section};
 
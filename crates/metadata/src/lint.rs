@@ -7,6 +7,109 @@ use std::fmt;
 pub struct Lint {
     #[serde(default)]
     pub naming: LintNaming,
+    #[serde(default)]
+    pub rules: LintRules,
+}
+
+/// Severity of each lint rule; an unset rule takes `LintRule::default_level`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintRules {
+    #[serde(default)]
+    pub implicit_truncation: Option<LintLevel>,
+    #[serde(default)]
+    pub latch_inference: Option<LintLevel>,
+    #[serde(default)]
+    pub magic_number: Option<LintLevel>,
+    #[serde(default)]
+    pub missing_default: Option<LintLevel>,
+    #[serde(default)]
+    pub nested_if: Option<LintLevel>,
+    #[serde(default)]
+    pub unused_parameter: Option<LintLevel>,
+}
+
+impl LintRules {
+    pub fn level(&self, rule: LintRule) -> LintLevel {
+        let level = match rule {
+            LintRule::ImplicitTruncation => self.implicit_truncation,
+            LintRule::LatchInference => self.latch_inference,
+            LintRule::MagicNumber => self.magic_number,
+            LintRule::MissingDefault => self.missing_default,
+            LintRule::NestedIf => self.nested_if,
+            LintRule::UnusedParameter => self.unused_parameter,
+        };
+        level.unwrap_or(rule.default_level())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintLevel {
+    /// Not reported.
+    Allow,
+    /// Reported as a warning.
+    Warn,
+    /// Reported as an error.
+    Deny,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintRule {
+    /// An assignment drops upper bits of a wider expression.
+    ImplicitTruncation,
+    /// A variable isn't assigned in every branch of a combinational block.
+    LatchInference,
+    /// A number other than 0 or 1 is written in `always_ff`.
+    MagicNumber,
+    /// A `case` or `switch` statement has no `default` arm.
+    MissingDefault,
+    /// `if` statements are nested deeper than `NESTED_IF_LIMIT`.
+    NestedIf,
+    /// A parameter is never referenced.
+    UnusedParameter,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 6] = [
+        LintRule::ImplicitTruncation,
+        LintRule::LatchInference,
+        LintRule::MagicNumber,
+        LintRule::MissingDefault,
+        LintRule::NestedIf,
+        LintRule::UnusedParameter,
+    ];
+
+    /// Levels of `if` statements allowed before `NestedIf` reports.
+    pub const NESTED_IF_LIMIT: usize = 3;
+
+    /// The level of a rule `[lint.rules]` leaves unset.  Only rules the
+    /// analyzer reported before they were configurable are on by default.
+    pub fn default_level(&self) -> LintLevel {
+        match self {
+            LintRule::LatchInference => LintLevel::Warn,
+            _ => LintLevel::Allow,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|x| x.to_string() == name)
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            LintRule::ImplicitTruncation => "implicit_truncation",
+            LintRule::LatchInference => "latch_inference",
+            LintRule::MagicNumber => "magic_number",
+            LintRule::MissingDefault => "missing_default",
+            LintRule::NestedIf => "nested_if",
+            LintRule::UnusedParameter => "unused_parameter",
+        };
+        text.fmt(f)
+    }
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -19,6 +21,10 @@ pub struct Synth {
     /// Built-in cell library / PDK to use.
     #[serde(default)]
     pub library: Library,
+    /// Liberty (`.lib`) files, relative to the project root, to build the
+    /// cell library from instead of `library`.
+    #[serde(default)]
+    pub liberty: Vec<PathBuf>,
     /// Smallest array, in stored bits, inferred as a RAM instead of flip-flops.
     #[serde(default = "default_ram_min_bits")]
     pub ram_min_bits: usize,
@@ -32,6 +38,57 @@ pub struct Synth {
     /// inference; a larger dynamically-indexed array is rejected, not expanded.
     #[serde(default = "default_ram_max_ff_bits")]
     pub ram_max_ff_bits: usize,
+    /// Clock periods and I/O delays the timing report checks slack against.
+    #[serde(default)]
+    pub constraints: TimingConstraints,
+}
+
+/// `[synth.constraints]`. All times are in ns.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TimingConstraints {
+    #[serde(default)]
+    pub clocks: Vec<ClockConstraint>,
+    /// External delay before data reaches an input port, relative to the
+    /// clock capturing it.
+    #[serde(default)]
+    pub input_delay: f64,
+    /// External delay after an output port before the receiving flop,
+    /// relative to the clock launching it.
+    #[serde(default)]
+    pub output_delay: f64,
+    /// Per-port overrides of `input_delay`, keyed by port name.
+    #[serde(default)]
+    pub input_delays: BTreeMap<String, f64>,
+    /// Per-port overrides of `output_delay`, keyed by port name.
+    #[serde(default)]
+    pub output_delays: BTreeMap<String, f64>,
+}
+
+impl TimingConstraints {
+    pub fn input_delay(&self, port: &str) -> f64 {
+        self.input_delays
+            .get(port)
+            .copied()
+            .unwrap_or(self.input_delay)
+    }
+
+    pub fn output_delay(&self, port: &str) -> f64 {
+        self.output_delays
+            .get(port)
+            .copied()
+            .unwrap_or(self.output_delay)
+    }
+}
+
+/// A clock driven by a top-level port.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClockConstraint {
+    /// Clock input port of the top module.
+    pub port: String,
+    /// Clock period (ns).
+    pub period: f64,
 }
 
 /// Built-in PDK library identifiers. Each variant maps to a hard-coded
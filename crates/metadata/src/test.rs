@@ -18,6 +18,21 @@ pub struct Test {
     pub waveform_target: WaveFormTarget,
     #[serde(default)]
     pub waveform_format: WaveFormFormat,
+    /// Native-simulator waveforms record only these instance scopes (paths
+    /// from the test module, e.g. `u_soc.u_core`) and the instances below
+    /// them. Empty records the whole design.
+    #[serde(default)]
+    pub wave_scopes: Vec<String>,
+    /// Instance levels recorded from each scope, `1` being the scope alone.
+    /// Unset records every level.
+    #[serde(default)]
+    pub wave_depth: Option<usize>,
+    /// Testbench clock cycle the native-simulator waveform starts at.
+    #[serde(default)]
+    pub wave_start: Option<u64>,
+    /// Testbench clock cycle the native-simulator waveform stops at.
+    #[serde(default)]
+    pub wave_stop: Option<u64>,
     #[serde(default)]
     pub include_files: Vec<PathBuf>,
     #[serde(default)]
@@ -54,6 +69,8 @@ pub enum SimType {
     Dsim,
     #[serde(rename = "vivado")]
     Vivado,
+    #[serde(rename = "native")]
+    Native,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
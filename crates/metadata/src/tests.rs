@@ -325,6 +325,65 @@ four_state = true
     assert!(metadata.test.four_state);
 }
 
+#[test]
+fn wave_selection_parses() {
+    let metadata: Metadata = toml::from_str(TEST_TOML).unwrap();
+    assert!(metadata.test.wave_scopes.is_empty());
+    assert!(metadata.test.wave_depth.is_none());
+
+    let toml = r#"
+[project]
+name = "test"
+version = "0.1.0"
+
+[test]
+wave_scopes = ["u_soc.u_core", "u_mem"]
+wave_depth = 2
+wave_start = 1000
+wave_stop = 2000
+"#;
+    let metadata: Metadata = toml::from_str(toml).unwrap();
+    assert_eq!(metadata.test.wave_scopes, ["u_soc.u_core", "u_mem"]);
+    assert_eq!(metadata.test.wave_depth, Some(2));
+    assert_eq!(metadata.test.wave_start, Some(1000));
+    assert_eq!(metadata.test.wave_stop, Some(2000));
+}
+
+#[test]
+fn lint_rules_parse() {
+    let metadata: Metadata = toml::from_str(TEST_TOML).unwrap();
+    let rules = &metadata.lint.rules;
+    assert_eq!(rules.level(LintRule::LatchInference), LintLevel::Warn);
+    assert_eq!(rules.level(LintRule::MagicNumber), LintLevel::Allow);
+
+    let toml = r#"
+[project]
+name = "test"
+version = "0.1.0"
+
+[lint.rules]
+latch_inference = "deny"
+magic_number = "warn"
+nested_if = "allow"
+"#;
+    let metadata: Metadata = toml::from_str(toml).unwrap();
+    let rules = &metadata.lint.rules;
+    assert_eq!(rules.level(LintRule::LatchInference), LintLevel::Deny);
+    assert_eq!(rules.level(LintRule::MagicNumber), LintLevel::Warn);
+    assert_eq!(rules.level(LintRule::NestedIf), LintLevel::Allow);
+    assert_eq!(rules.level(LintRule::MissingDefault), LintLevel::Allow);
+
+    let toml = r#"
+[project]
+name = "test"
+version = "0.1.0"
+
+[lint.rules]
+no_such_rule = "warn"
+"#;
+    assert!(toml::from_str::<Metadata>(toml).is_err());
+}
+
 #[test]
 fn synth_ram_thresholds_default_and_override() {
     // Omitted RAM thresholds fall back to the built-in defaults.
@@ -353,6 +412,56 @@ ram_max_ff_bits = 4096
     assert_eq!(metadata.synth.ram_max_ff_bits, 4096);
 }
 
+#[test]
+fn synth_timing_constraints() {
+    let metadata: Metadata = toml::from_str(TEST_TOML).unwrap();
+    assert!(metadata.synth.constraints.clocks.is_empty());
+
+    let toml = r#"
+[project]
+name = "test"
+version = "0.1.0"
+
+[synth.constraints]
+clocks = [{ port = "clk_a", period = 10.0 }, { port = "clk_b", period = 4.0 }]
+input_delay = 0.5
+
+[synth.constraints.output_delays]
+dout = 1.5
+"#;
+    let metadata: Metadata = toml::from_str(toml).unwrap();
+    let constraints = &metadata.synth.constraints;
+    assert_eq!(constraints.clocks.len(), 2);
+    assert_eq!(constraints.clocks[1].port, "clk_b");
+    assert_eq!(constraints.clocks[1].period, 4.0);
+    assert_eq!(constraints.input_delay("din"), 0.5);
+    assert_eq!(constraints.output_delay("dout"), 1.5);
+    assert_eq!(constraints.output_delay("other"), 0.0);
+}
+
+#[test]
+fn synth_liberty_defaults_empty_and_parses() {
+    let metadata: Metadata = toml::from_str(TEST_TOML).unwrap();
+    assert!(metadata.synth.liberty.is_empty());
+
+    let toml = r#"
+[project]
+name = "test"
+version = "0.1.0"
+
+[synth]
+liberty = ["pdk/stdcells.lib", "pdk/ff.lib"]
+"#;
+    let metadata: Metadata = toml::from_str(toml).unwrap();
+    assert_eq!(
+        metadata.synth.liberty,
+        vec![
+            PathBuf::from("pdk/stdcells.lib"),
+            PathBuf::from("pdk/ff.lib")
+        ]
+    );
+}
+
 #[test]
 fn load_extension_namespace_metadata() {
     let tempdir = tempfile::tempdir().unwrap();
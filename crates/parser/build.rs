@@ -31,6 +31,7 @@ fn generate_token_type() {
         ("AllBit", "number"),
         ("BaseLess", "number"),
         ("AssignmentOperator", "assignment operator"),
+        ("Implication", "implication"),
         ("Operator08", "operator"),
         ("Operator07", "operator"),
         ("Operator06", "operator"),
@@ -11,6 +11,7 @@ pub enum TokenType {
     MinusGT,
     LTMinus,
     PlusColon,
+    Implication,
     AssignmentOperator,
     DiamondOperator,
     Operator08,
@@ -30,6 +31,7 @@ pub enum TokenType {
     DotDot,
     Dot,
     Equ,
+    HashHash,
     HashLBracket,
     Hash,
     LAngle,
@@ -247,6 +249,7 @@ impl From<&str> for TokenType {
             "MinusGTTerm" => TokenType::MinusGT,
             "LTMinusTerm" => TokenType::LTMinus,
             "PlusColonTerm" => TokenType::PlusColon,
+            "ImplicationTerm" => TokenType::Implication,
             "AssignmentOperatorTerm" => TokenType::AssignmentOperator,
             "DiamondOperatorTerm" => TokenType::DiamondOperator,
             "Operator08Term" => TokenType::Operator08,
@@ -266,6 +269,7 @@ impl From<&str> for TokenType {
             "DotDotTerm" => TokenType::DotDot,
             "DotTerm" => TokenType::Dot,
             "EquTerm" => TokenType::Equ,
+            "HashHashTerm" => TokenType::HashHash,
             "HashLBracketTerm" => TokenType::HashLBracket,
             "HashTerm" => TokenType::Hash,
             "LAngleTerm" => TokenType::LAngle,
@@ -392,6 +396,7 @@ impl std::fmt::Display for TokenType {
             TokenType::MinusGT => "->",
             TokenType::LTMinus => "<-",
             TokenType::PlusColon => "+:",
+            TokenType::Implication => "implication",
             TokenType::AssignmentOperator => "assignment operator",
             TokenType::DiamondOperator => "<>",
             TokenType::Operator08 => "operator",
@@ -411,6 +416,7 @@ impl std::fmt::Display for TokenType {
             TokenType::DotDot => "..",
             TokenType::Dot => ".",
             TokenType::Equ => "=",
+            TokenType::HashHash => "##",
             TokenType::HashLBracket => "#[",
             TokenType::Hash => "#",
             TokenType::LAngle => "<",
@@ -74,6 +74,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ImplicationTerm'
+    fn implication_term(&mut self, _arg: &ImplicationTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignmentOperatorTerm'
     fn assignment_operator_term(&mut self, _arg: &AssignmentOperatorTerm) -> Result<()> {
         Ok(())
@@ -169,6 +174,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'HashHashTerm'
+    fn hash_hash_term(&mut self, _arg: &HashHashTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'HashLBracketTerm'
     fn hash_l_bracket_term(&mut self, _arg: &HashLBracketTerm) -> Result<()> {
         Ok(())
@@ -754,6 +764,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ImplicationToken'
+    fn implication_token(&mut self, _arg: &ImplicationToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Operator01Token'
     fn operator01_token(&mut self, _arg: &Operator01Token) -> Result<()> {
         Ok(())
@@ -839,6 +854,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'HashHashToken'
+    fn hash_hash_token(&mut self, _arg: &HashHashToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'HashLBracketToken'
     fn hash_l_bracket_token(&mut self, _arg: &HashLBracketToken) -> Result<()> {
         Ok(())
@@ -1449,6 +1469,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Implication'
+    fn implication(&mut self, _arg: &Implication) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Operator01'
     fn operator01(&mut self, _arg: &Operator01) -> Result<()> {
         Ok(())
@@ -1534,6 +1559,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'HashHash'
+    fn hash_hash(&mut self, _arg: &HashHash) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'HashLBracket'
     fn hash_l_bracket(&mut self, _arg: &HashLBracket) -> Result<()> {
         Ok(())
@@ -2204,6 +2234,16 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'PropertyImplication'
+    fn property_implication(&mut self, _arg: &PropertyImplication) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'CycleDelay'
+    fn cycle_delay(&mut self, _arg: &CycleDelay) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'StructConstructor'
     fn struct_constructor(&mut self, _arg: &StructConstructor) -> Result<()> {
         Ok(())
@@ -2995,7 +3035,7 @@ pub trait VerylGrammarTrait {
 //
 
 ///
-/// Type derived for production 418
+/// Type derived for production 424
 ///
 /// `Number: IntegralNumber;`
 ///
@@ -3006,7 +3046,7 @@ pub struct NumberIntegralNumber {
 }
 
 ///
-/// Type derived for production 419
+/// Type derived for production 425
 ///
 /// `Number: RealNumber;`
 ///
@@ -3017,7 +3057,7 @@ pub struct NumberRealNumber {
 }
 
 ///
-/// Type derived for production 420
+/// Type derived for production 426
 ///
 /// `IntegralNumber: Based;`
 ///
@@ -3028,7 +3068,7 @@ pub struct IntegralNumberBased {
 }
 
 ///
-/// Type derived for production 421
+/// Type derived for production 427
 ///
 /// `IntegralNumber: BaseLess;`
 ///
@@ -3039,7 +3079,7 @@ pub struct IntegralNumberBaseLess {
 }
 
 ///
-/// Type derived for production 422
+/// Type derived for production 428
 ///
 /// `IntegralNumber: AllBit;`
 ///
@@ -3050,7 +3090,7 @@ pub struct IntegralNumberAllBit {
 }
 
 ///
-/// Type derived for production 423
+/// Type derived for production 429
 ///
 /// `RealNumber: FixedPoint;`
 ///
@@ -3061,7 +3101,7 @@ pub struct RealNumberFixedPoint {
 }
 
 ///
-/// Type derived for production 424
+/// Type derived for production 430
 ///
 /// `RealNumber: Exponent;`
 ///
@@ -3072,7 +3112,7 @@ pub struct RealNumberExponent {
 }
 
 ///
-/// Type derived for production 433
+/// Type derived for production 439
 ///
 /// `ScopedIdentifierGroup: DollarIdentifier;`
 ///
@@ -3083,7 +3123,7 @@ pub struct ScopedIdentifierGroupDollarIdentifier {
 }
 
 ///
-/// Type derived for production 434
+/// Type derived for production 440
 ///
 /// `ScopedIdentifierGroup: Identifier ScopedIdentifierOpt /* Option */;`
 ///
@@ -3095,7 +3135,7 @@ pub struct ScopedIdentifierGroupIdentifierScopedIdentifierOpt {
 }
 
 ///
-/// Type derived for production 465
+/// Type derived for production 471
 ///
 /// `Expression01Op: Operator01;`
 ///
@@ -3106,7 +3146,7 @@ pub struct Expression01OpOperator01 {
 }
 
 ///
-/// Type derived for production 466
+/// Type derived for production 472
 ///
 /// `Expression01Op: Operator02;`
 ///
@@ -3117,7 +3157,7 @@ pub struct Expression01OpOperator02 {
 }
 
 ///
-/// Type derived for production 467
+/// Type derived for production 473
 ///
 /// `Expression01Op: Operator03;`
 ///
@@ -3128,7 +3168,7 @@ pub struct Expression01OpOperator03 {
 }
 
 ///
-/// Type derived for production 468
+/// Type derived for production 474
 ///
 /// `Expression01Op: Operator04;`
 ///
@@ -3139,7 +3179,7 @@ pub struct Expression01OpOperator04 {
 }
 
 ///
-/// Type derived for production 469
+/// Type derived for production 475
 ///
 /// `Expression01Op: Operator05;`
 ///
@@ -3150,7 +3190,7 @@ pub struct Expression01OpOperator05 {
 }
 
 ///
-/// Type derived for production 470
+/// Type derived for production 476
 ///
 /// `Expression01Op: Operator06;`
 ///
@@ -3161,7 +3201,7 @@ pub struct Expression01OpOperator06 {
 }
 
 ///
-/// Type derived for production 471
+/// Type derived for production 477
 ///
 /// `Expression01Op: Operator07;`
 ///
@@ -3172,7 +3212,7 @@ pub struct Expression01OpOperator07 {
 }
 
 ///
-/// Type derived for production 472
+/// Type derived for production 478
 ///
 /// `Expression01Op: Star;`
 ///
@@ -3183,7 +3223,7 @@ pub struct Expression01OpStar {
 }
 
 ///
-/// Type derived for production 473
+/// Type derived for production 479
 ///
 /// `Expression01Op: Operator08;`
 ///
@@ -3194,7 +3234,7 @@ pub struct Expression01OpOperator08 {
 }
 
 ///
-/// Type derived for production 474
+/// Type derived for production 480
 ///
 /// `Expression02Op: UnaryOperator;`
 ///
@@ -3205,7 +3245,7 @@ pub struct Expression02OpUnaryOperator {
 }
 
 ///
-/// Type derived for production 475
+/// Type derived for production 481
 ///
 /// `Expression02Op: Operator06;`
 ///
@@ -3216,7 +3256,7 @@ pub struct Expression02OpOperator06 {
 }
 
 ///
-/// Type derived for production 476
+/// Type derived for production 482
 ///
 /// `Expression02Op: Operator05;`
 ///
@@ -3227,7 +3267,7 @@ pub struct Expression02OpOperator05 {
 }
 
 ///
-/// Type derived for production 477
+/// Type derived for production 483
 ///
 /// `Expression02Op: Operator03;`
 ///
@@ -3238,7 +3278,7 @@ pub struct Expression02OpOperator03 {
 }
 
 ///
-/// Type derived for production 478
+/// Type derived for production 484
 ///
 /// `Expression02Op: Operator04;`
 ///
@@ -3249,7 +3289,7 @@ pub struct Expression02OpOperator04 {
 }
 
 ///
-/// Type derived for production 479
+/// Type derived for production 485
 ///
 /// `Factor: Number;`
 ///
@@ -3260,7 +3300,7 @@ pub struct FactorNumber {
 }
 
 ///
-/// Type derived for production 480
+/// Type derived for production 486
 ///
 /// `Factor: BooleanLiteral;`
 ///
@@ -3271,7 +3311,7 @@ pub struct FactorBooleanLiteral {
 }
 
 ///
-/// Type derived for production 481
+/// Type derived for production 487
 ///
 /// `Factor: IdentifierFactor;`
 ///
@@ -3282,7 +3322,7 @@ pub struct FactorIdentifierFactor {
 }
 
 ///
-/// Type derived for production 482
+/// Type derived for production 488
 ///
 /// `Factor: LParen Expression RParen;`
 ///
@@ -3295,7 +3335,7 @@ pub struct FactorLParenExpressionRParen {
 }
 
 ///
-/// Type derived for production 483
+/// Type derived for production 489
 ///
 /// `Factor: LBrace ConcatenationList RBrace;`
 ///
@@ -3308,7 +3348,7 @@ pub struct FactorLBraceConcatenationListRBrace {
 }
 
 ///
-/// Type derived for production 484
+/// Type derived for production 490
 ///
 /// `Factor: QuoteLBrace ArrayLiteralList RBrace;`
 ///
@@ -3321,7 +3361,7 @@ pub struct FactorQuoteLBraceArrayLiteralListRBrace {
 }
 
 ///
-/// Type derived for production 485
+/// Type derived for production 491
 ///
 /// `Factor: CaseExpression;`
 ///
@@ -3332,7 +3372,7 @@ pub struct FactorCaseExpression {
 }
 
 ///
-/// Type derived for production 486
+/// Type derived for production 492
 ///
 /// `Factor: SwitchExpression;`
 ///
@@ -3343,7 +3383,7 @@ pub struct FactorSwitchExpression {
 }
 
 ///
-/// Type derived for production 487
+/// Type derived for production 493
 ///
 /// `Factor: StringLiteral;`
 ///
@@ -3354,7 +3394,7 @@ pub struct FactorStringLiteral {
 }
 
 ///
-/// Type derived for production 488
+/// Type derived for production 494
 ///
 /// `Factor: FactorGroup;`
 ///
@@ -3365,7 +3405,7 @@ pub struct FactorFactorGroup {
 }
 
 ///
-/// Type derived for production 489
+/// Type derived for production 495
 ///
 /// `FactorGroup: Msb;`
 ///
@@ -3376,7 +3416,7 @@ pub struct FactorGroupMsb {
 }
 
 ///
-/// Type derived for production 490
+/// Type derived for production 496
 ///
 /// `FactorGroup: Lsb;`
 ///
@@ -3387,7 +3427,7 @@ pub struct FactorGroupLsb {
 }
 
 ///
-/// Type derived for production 491
+/// Type derived for production 497
 ///
 /// `Factor: InsideExpression;`
 ///
@@ -3398,7 +3438,7 @@ pub struct FactorInsideExpression {
 }
 
 ///
-/// Type derived for production 492
+/// Type derived for production 498
 ///
 /// `Factor: OutsideExpression;`
 ///
@@ -3409,7 +3449,7 @@ pub struct FactorOutsideExpression {
 }
 
 ///
-/// Type derived for production 493
+/// Type derived for production 499
 ///
 /// `Factor: TypeExpression;`
 ///
@@ -3420,7 +3460,7 @@ pub struct FactorTypeExpression {
 }
 
 ///
-/// Type derived for production 494
+/// Type derived for production 500
 ///
 /// `Factor: FactorTypeFactor;`
 ///
@@ -3431,7 +3471,7 @@ pub struct FactorFactorTypeFactor {
 }
 
 ///
-/// Type derived for production 495
+/// Type derived for production 501
 ///
 /// `BooleanLiteral: True;`
 ///
@@ -3442,7 +3482,7 @@ pub struct BooleanLiteralTrue {
 }
 
 ///
-/// Type derived for production 496
+/// Type derived for production 502
 ///
 /// `BooleanLiteral: False;`
 ///
@@ -3453,7 +3493,7 @@ pub struct BooleanLiteralFalse {
 }
 
 ///
-/// Type derived for production 499
+/// Type derived for production 505
 ///
 /// `IdentifierFactorOptGroup: FunctionCall;`
 ///
@@ -3464,7 +3504,7 @@ pub struct IdentifierFactorOptGroupFunctionCall {
 }
 
 ///
-/// Type derived for production 500
+/// Type derived for production 506
 ///
 /// `IdentifierFactorOptGroup: StructConstructor;`
 ///
@@ -3475,7 +3515,33 @@ pub struct IdentifierFactorOptGroupStructConstructor {
 }
 
 ///
-/// Type derived for production 540
+/// Type derived for production 529
+///
+/// `CycleDelayGroup: Number;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CycleDelayGroupNumber {
+    pub number: Box<Number>,
+}
+
+///
+/// Type derived for production 530
+///
+/// `CycleDelayGroup: LBracket Expression Colon Expression RBracket;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CycleDelayGroupLBracketExpressionColonExpressionRBracket {
+    pub l_bracket: Box<LBracket>,
+    pub expression: Box<Expression>,
+    pub colon: Box<Colon>,
+    pub expression0: Box<Expression>,
+    pub r_bracket: Box<RBracket>,
+}
+
+///
+/// Type derived for production 554
 ///
 /// `ArrayLiteralItemGroup: Expression ArrayLiteralItemOpt /* Option */;`
 ///
@@ -3487,7 +3553,7 @@ pub struct ArrayLiteralItemGroupExpressionArrayLiteralItemOpt {
 }
 
 ///
-/// Type derived for production 541
+/// Type derived for production 555
 ///
 /// `ArrayLiteralItemGroup: Defaul Colon Expression;`
 ///
@@ -3500,7 +3566,7 @@ pub struct ArrayLiteralItemGroupDefaulColonExpression {
 }
 
 ///
-/// Type derived for production 566
+/// Type derived for production 580
 ///
 /// `SelectOperator: Colon;`
 ///
@@ -3511,7 +3577,7 @@ pub struct SelectOperatorColon {
 }
 
 ///
-/// Type derived for production 567
+/// Type derived for production 581
 ///
 /// `SelectOperator: PlusColon;`
 ///
@@ -3522,7 +3588,7 @@ pub struct SelectOperatorPlusColon {
 }
 
 ///
-/// Type derived for production 568
+/// Type derived for production 582
 ///
 /// `SelectOperator: MinusColon;`
 ///
@@ -3533,7 +3599,7 @@ pub struct SelectOperatorMinusColon {
 }
 
 ///
-/// Type derived for production 569
+/// Type derived for production 583
 ///
 /// `SelectOperator: Step;`
 ///
@@ -3544,7 +3610,7 @@ pub struct SelectOperatorStep {
 }
 
 ///
-/// Type derived for production 579
+/// Type derived for production 593
 ///
 /// `RangeOperator: DotDot;`
 ///
@@ -3555,7 +3621,7 @@ pub struct RangeOperatorDotDot {
 }
 
 ///
-/// Type derived for production 580
+/// Type derived for production 594
 ///
 /// `RangeOperator: DotDotEqu;`
 ///
@@ -3566,7 +3632,7 @@ pub struct RangeOperatorDotDotEqu {
 }
 
 ///
-/// Type derived for production 581
+/// Type derived for production 595
 ///
 /// `FixedType: P8;`
 ///
@@ -3577,7 +3643,7 @@ pub struct FixedTypeP8 {
 }
 
 ///
-/// Type derived for production 582
+/// Type derived for production 596
 ///
 /// `FixedType: P16;`
 ///
@@ -3588,7 +3654,7 @@ pub struct FixedTypeP16 {
 }
 
 ///
-/// Type derived for production 583
+/// Type derived for production 597
 ///
 /// `FixedType: P32;`
 ///
@@ -3599,7 +3665,7 @@ pub struct FixedTypeP32 {
 }
 
 ///
-/// Type derived for production 584
+/// Type derived for production 598
 ///
 /// `FixedType: P64;`
 ///
@@ -3610,7 +3676,7 @@ pub struct FixedTypeP64 {
 }
 
 ///
-/// Type derived for production 585
+/// Type derived for production 599
 ///
 /// `FixedType: U8;`
 ///
@@ -3621,7 +3687,7 @@ pub struct FixedTypeU8 {
 }
 
 ///
-/// Type derived for production 586
+/// Type derived for production 600
 ///
 /// `FixedType: U16;`
 ///
@@ -3632,7 +3698,7 @@ pub struct FixedTypeU16 {
 }
 
 ///
-/// Type derived for production 587
+/// Type derived for production 601
 ///
 /// `FixedType: U32;`
 ///
@@ -3643,7 +3709,7 @@ pub struct FixedTypeU32 {
 }
 
 ///
-/// Type derived for production 588
+/// Type derived for production 602
 ///
 /// `FixedType: U64;`
 ///
@@ -3654,7 +3720,7 @@ pub struct FixedTypeU64 {
 }
 
 ///
-/// Type derived for production 589
+/// Type derived for production 603
 ///
 /// `FixedType: I8;`
 ///
@@ -3665,7 +3731,7 @@ pub struct FixedTypeI8 {
 }
 
 ///
-/// Type derived for production 590
+/// Type derived for production 604
 ///
 /// `FixedType: I16;`
 ///
@@ -3676,7 +3742,7 @@ pub struct FixedTypeI16 {
 }
 
 ///
-/// Type derived for production 591
+/// Type derived for production 605
 ///
 /// `FixedType: I32;`
 ///
@@ -3687,7 +3753,7 @@ pub struct FixedTypeI32 {
 }
 
 ///
-/// Type derived for production 592
+/// Type derived for production 606
 ///
 /// `FixedType: I64;`
 ///
@@ -3698,7 +3764,7 @@ pub struct FixedTypeI64 {
 }
 
 ///
-/// Type derived for production 593
+/// Type derived for production 607
 ///
 /// `FixedType: F32;`
 ///
@@ -3709,7 +3775,7 @@ pub struct FixedTypeF32 {
 }
 
 ///
-/// Type derived for production 594
+/// Type derived for production 608
 ///
 /// `FixedType: F64;`
 ///
@@ -3720,7 +3786,7 @@ pub struct FixedTypeF64 {
 }
 
 ///
-/// Type derived for production 595
+/// Type derived for production 609
 ///
 /// `FixedType: BBool;`
 ///
@@ -3731,7 +3797,7 @@ pub struct FixedTypeBBool {
 }
 
 ///
-/// Type derived for production 596
+/// Type derived for production 610
 ///
 /// `FixedType: LBool;`
 ///
@@ -3742,7 +3808,7 @@ pub struct FixedTypeLBool {
 }
 
 ///
-/// Type derived for production 597
+/// Type derived for production 611
 ///
 /// `FixedType: Strin;`
 ///
@@ -3753,7 +3819,7 @@ pub struct FixedTypeStrin {
 }
 
 ///
-/// Type derived for production 598
+/// Type derived for production 612
 ///
 /// `VariableType: Clock;`
 ///
@@ -3764,7 +3830,7 @@ pub struct VariableTypeClock {
 }
 
 ///
-/// Type derived for production 599
+/// Type derived for production 613
 ///
 /// `VariableType: ClockPosedge;`
 ///
@@ -3775,7 +3841,7 @@ pub struct VariableTypeClockPosedge {
 }
 
 ///
-/// Type derived for production 600
+/// Type derived for production 614
 ///
 /// `VariableType: ClockNegedge;`
 ///
@@ -3786,7 +3852,7 @@ pub struct VariableTypeClockNegedge {
 }
 
 ///
-/// Type derived for production 601
+/// Type derived for production 615
 ///
 /// `VariableType: Reset;`
 ///
@@ -3797,7 +3863,7 @@ pub struct VariableTypeReset {
 }
 
 ///
-/// Type derived for production 602
+/// Type derived for production 616
 ///
 /// `VariableType: ResetAsyncHigh;`
 ///
@@ -3808,7 +3874,7 @@ pub struct VariableTypeResetAsyncHigh {
 }
 
 ///
-/// Type derived for production 603
+/// Type derived for production 617
 ///
 /// `VariableType: ResetAsyncLow;`
 ///
@@ -3819,7 +3885,7 @@ pub struct VariableTypeResetAsyncLow {
 }
 
 ///
-/// Type derived for production 604
+/// Type derived for production 618
 ///
 /// `VariableType: ResetSyncHigh;`
 ///
@@ -3830,7 +3896,7 @@ pub struct VariableTypeResetSyncHigh {
 }
 
 ///
-/// Type derived for production 605
+/// Type derived for production 619
 ///
 /// `VariableType: ResetSyncLow;`
 ///
@@ -3841,7 +3907,7 @@ pub struct VariableTypeResetSyncLow {
 }
 
 ///
-/// Type derived for production 606
+/// Type derived for production 620
 ///
 /// `VariableType: Logic;`
 ///
@@ -3852,7 +3918,7 @@ pub struct VariableTypeLogic {
 }
 
 ///
-/// Type derived for production 607
+/// Type derived for production 621
 ///
 /// `VariableType: Bit;`
 ///
@@ -3863,7 +3929,7 @@ pub struct VariableTypeBit {
 }
 
 ///
-/// Type derived for production 609
+/// Type derived for production 623
 ///
 /// `TypeModifier: Tri;`
 ///
@@ -3874,7 +3940,7 @@ pub struct TypeModifierTri {
 }
 
 ///
-/// Type derived for production 610
+/// Type derived for production 624
 ///
 /// `TypeModifier: Signed;`
 ///
@@ -3885,7 +3951,7 @@ pub struct TypeModifierSigned {
 }
 
 ///
-/// Type derived for production 611
+/// Type derived for production 625
 ///
 /// `TypeModifier: Defaul;`
 ///
@@ -3896,7 +3962,7 @@ pub struct TypeModifierDefaul {
 }
 
 ///
-/// Type derived for production 613
+/// Type derived for production 627
 ///
 /// `FactorTypeGroup: VariableType FactorTypeOpt /* Option */;`
 ///
@@ -3908,7 +3974,7 @@ pub struct FactorTypeGroupVariableTypeFactorTypeOpt {
 }
 
 ///
-/// Type derived for production 614
+/// Type derived for production 628
 ///
 /// `FactorTypeGroup: FixedType;`
 ///
@@ -3919,7 +3985,7 @@ pub struct FactorTypeGroupFixedType {
 }
 
 ///
-/// Type derived for production 618
+/// Type derived for production 632
 ///
 /// `ScalarTypeGroup: UserDefinedType ScalarTypeOpt /* Option */;`
 ///
@@ -3931,7 +3997,7 @@ pub struct ScalarTypeGroupUserDefinedTypeScalarTypeOpt {
 }
 
 ///
-/// Type derived for production 619
+/// Type derived for production 633
 ///
 /// `ScalarTypeGroup: FactorType;`
 ///
@@ -3942,7 +4008,7 @@ pub struct ScalarTypeGroupFactorType {
 }
 
 ///
-/// Type derived for production 627
+/// Type derived for production 641
 ///
 /// `CastingType: U8;`
 ///
@@ -3953,7 +4019,7 @@ pub struct CastingTypeU8 {
 }
 
 ///
-/// Type derived for production 628
+/// Type derived for production 642
 ///
 /// `CastingType: U16;`
 ///
@@ -3964,7 +4030,7 @@ pub struct CastingTypeU16 {
 }
 
 ///
-/// Type derived for production 629
+/// Type derived for production 643
 ///
 /// `CastingType: U32;`
 ///
@@ -3975,7 +4041,7 @@ pub struct CastingTypeU32 {
 }
 
 ///
-/// Type derived for production 630
+/// Type derived for production 644
 ///
 /// `CastingType: U64;`
 ///
@@ -3986,7 +4052,7 @@ pub struct CastingTypeU64 {
 }
 
 ///
-/// Type derived for production 631
+/// Type derived for production 645
 ///
 /// `CastingType: P8;`
 ///
@@ -3997,7 +4063,7 @@ pub struct CastingTypeP8 {
 }
 
 ///
-/// Type derived for production 632
+/// Type derived for production 646
 ///
 /// `CastingType: P16;`
 ///
@@ -4008,7 +4074,7 @@ pub struct CastingTypeP16 {
 }
 
 ///
-/// Type derived for production 633
+/// Type derived for production 647
 ///
 /// `CastingType: P32;`
 ///
@@ -4019,7 +4085,7 @@ pub struct CastingTypeP32 {
 }
 
 ///
-/// Type derived for production 634
+/// Type derived for production 648
 ///
 /// `CastingType: P64;`
 ///
@@ -4030,7 +4096,7 @@ pub struct CastingTypeP64 {
 }
 
 ///
-/// Type derived for production 635
+/// Type derived for production 649
 ///
 /// `CastingType: I8;`
 ///
@@ -4041,7 +4107,7 @@ pub struct CastingTypeI8 {
 }
 
 ///
-/// Type derived for production 636
+/// Type derived for production 650
 ///
 /// `CastingType: I16;`
 ///
@@ -4052,7 +4118,7 @@ pub struct CastingTypeI16 {
 }
 
 ///
-/// Type derived for production 637
+/// Type derived for production 651
 ///
 /// `CastingType: I32;`
 ///
@@ -4063,7 +4129,7 @@ pub struct CastingTypeI32 {
 }
 
 ///
-/// Type derived for production 638
+/// Type derived for production 652
 ///
 /// `CastingType: I64;`
 ///
@@ -4074,7 +4140,7 @@ pub struct CastingTypeI64 {
 }
 
 ///
-/// Type derived for production 639
+/// Type derived for production 653
 ///
 /// `CastingType: F32;`
 ///
@@ -4085,7 +4151,7 @@ pub struct CastingTypeF32 {
 }
 
 ///
-/// Type derived for production 640
+/// Type derived for production 654
 ///
 /// `CastingType: F64;`
 ///
@@ -4096,7 +4162,7 @@ pub struct CastingTypeF64 {
 }
 
 ///
-/// Type derived for production 641
+/// Type derived for production 655
 ///
 /// `CastingType: BBool;`
 ///
@@ -4107,7 +4173,7 @@ pub struct CastingTypeBBool {
 }
 
 ///
-/// Type derived for production 642
+/// Type derived for production 656
 ///
 /// `CastingType: LBool;`
 ///
@@ -4118,7 +4184,7 @@ pub struct CastingTypeLBool {
 }
 
 ///
-/// Type derived for production 643
+/// Type derived for production 657
 ///
 /// `CastingType: Clock;`
 ///
@@ -4129,7 +4195,7 @@ pub struct CastingTypeClock {
 }
 
 ///
-/// Type derived for production 644
+/// Type derived for production 658
 ///
 /// `CastingType: ClockPosedge;`
 ///
@@ -4140,7 +4206,7 @@ pub struct CastingTypeClockPosedge {
 }
 
 ///
-/// Type derived for production 645
+/// Type derived for production 659
 ///
 /// `CastingType: ClockNegedge;`
 ///
@@ -4151,7 +4217,7 @@ pub struct CastingTypeClockNegedge {
 }
 
 ///
-/// Type derived for production 646
+/// Type derived for production 660
 ///
 /// `CastingType: Reset;`
 ///
@@ -4162,7 +4228,7 @@ pub struct CastingTypeReset {
 }
 
 ///
-/// Type derived for production 647
+/// Type derived for production 661
 ///
 /// `CastingType: ResetAsyncHigh;`
 ///
@@ -4173,7 +4239,7 @@ pub struct CastingTypeResetAsyncHigh {
 }
 
 ///
-/// Type derived for production 648
+/// Type derived for production 662
 ///
 /// `CastingType: ResetAsyncLow;`
 ///
@@ -4184,7 +4250,7 @@ pub struct CastingTypeResetAsyncLow {
 }
 
 ///
-/// Type derived for production 649
+/// Type derived for production 663
 ///
 /// `CastingType: ResetSyncHigh;`
 ///
@@ -4195,7 +4261,7 @@ pub struct CastingTypeResetSyncHigh {
 }
 
 ///
-/// Type derived for production 650
+/// Type derived for production 664
 ///
 /// `CastingType: ResetSyncLow;`
 ///
@@ -4206,7 +4272,7 @@ pub struct CastingTypeResetSyncLow {
 }
 
 ///
-/// Type derived for production 651
+/// Type derived for production 665
 ///
 /// `CastingType: UserDefinedType;`
 ///
@@ -4217,7 +4283,7 @@ pub struct CastingTypeUserDefinedType {
 }
 
 ///
-/// Type derived for production 652
+/// Type derived for production 666
 ///
 /// `CastingType: Based;`
 ///
@@ -4228,7 +4294,7 @@ pub struct CastingTypeBased {
 }
 
 ///
-/// Type derived for production 653
+/// Type derived for production 667
 ///
 /// `CastingType: BaseLess;`
 ///
@@ -4239,7 +4305,7 @@ pub struct CastingTypeBaseLess {
 }
 
 ///
-/// Type derived for production 659
+/// Type derived for production 673
 ///
 /// `StatementBlockGroupGroup: Block LBrace StatementBlockGroupGroupList /* Vec */ RBrace;`
 ///
@@ -4253,7 +4319,7 @@ pub struct StatementBlockGroupGroupBlockLBraceStatementBlockGroupGroupListRBrace
 }
 
 ///
-/// Type derived for production 662
+/// Type derived for production 676
 ///
 /// `StatementBlockGroupGroup: StatementBlockItem;`
 ///
@@ -4264,7 +4330,7 @@ pub struct StatementBlockGroupGroupStatementBlockItem {
 }
 
 ///
-/// Type derived for production 665
+/// Type derived for production 679
 ///
 /// `StatementBlockItem: VarDeclaration;`
 ///
@@ -4275,7 +4341,7 @@ pub struct StatementBlockItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 666
+/// Type derived for production 680
 ///
 /// `StatementBlockItem: LetStatement;`
 ///
@@ -4286,7 +4352,7 @@ pub struct StatementBlockItemLetStatement {
 }
 
 ///
-/// Type derived for production 667
+/// Type derived for production 681
 ///
 /// `StatementBlockItem: ConstDeclaration;`
 ///
@@ -4297,7 +4363,7 @@ pub struct StatementBlockItemConstDeclaration {
 }
 
 ///
-/// Type derived for production 668
+/// Type derived for production 682
 ///
 /// `StatementBlockItem: GenDeclaration;`
 ///
@@ -4308,7 +4374,7 @@ pub struct StatementBlockItemGenDeclaration {
 }
 
 ///
-/// Type derived for production 669
+/// Type derived for production 683
 ///
 /// `StatementBlockItem: Statement;`
 ///
@@ -4319,7 +4385,7 @@ pub struct StatementBlockItemStatement {
 }
 
 ///
-/// Type derived for production 670
+/// Type derived for production 684
 ///
 /// `StatementBlockItem: ConcatenationAssignment;`
 ///
@@ -4330,7 +4396,7 @@ pub struct StatementBlockItemConcatenationAssignment {
 }
 
 ///
-/// Type derived for production 671
+/// Type derived for production 685
 ///
 /// `Statement: IdentifierStatement;`
 ///
@@ -4341,7 +4407,7 @@ pub struct StatementIdentifierStatement {
 }
 
 ///
-/// Type derived for production 672
+/// Type derived for production 686
 ///
 /// `Statement: IfStatement;`
 ///
@@ -4352,7 +4418,7 @@ pub struct StatementIfStatement {
 }
 
 ///
-/// Type derived for production 673
+/// Type derived for production 687
 ///
 /// `Statement: IfResetStatement;`
 ///
@@ -4363,7 +4429,7 @@ pub struct StatementIfResetStatement {
 }
 
 ///
-/// Type derived for production 674
+/// Type derived for production 688
 ///
 /// `Statement: ReturnStatement;`
 ///
@@ -4374,7 +4440,7 @@ pub struct StatementReturnStatement {
 }
 
 ///
-/// Type derived for production 675
+/// Type derived for production 689
 ///
 /// `Statement: BreakStatement;`
 ///
@@ -4385,7 +4451,7 @@ pub struct StatementBreakStatement {
 }
 
 ///
-/// Type derived for production 676
+/// Type derived for production 690
 ///
 /// `Statement: ForStatement;`
 ///
@@ -4396,7 +4462,7 @@ pub struct StatementForStatement {
 }
 
 ///
-/// Type derived for production 677
+/// Type derived for production 691
 ///
 /// `Statement: CaseStatement;`
 ///
@@ -4407,7 +4473,7 @@ pub struct StatementCaseStatement {
 }
 
 ///
-/// Type derived for production 678
+/// Type derived for production 692
 ///
 /// `Statement: SwitchStatement;`
 ///
@@ -4418,7 +4484,7 @@ pub struct StatementSwitchStatement {
 }
 
 ///
-/// Type derived for production 685
+/// Type derived for production 699
 ///
 /// `IdentifierStatementGroup: FunctionCall;`
 ///
@@ -4429,7 +4495,7 @@ pub struct IdentifierStatementGroupFunctionCall {
 }
 
 ///
-/// Type derived for production 686
+/// Type derived for production 700
 ///
 /// `IdentifierStatementGroup: Assignment;`
 ///
@@ -4440,7 +4506,7 @@ pub struct IdentifierStatementGroupAssignment {
 }
 
 ///
-/// Type derived for production 689
+/// Type derived for production 703
 ///
 /// `AssignmentGroup: Equ;`
 ///
@@ -4451,7 +4517,7 @@ pub struct AssignmentGroupEqu {
 }
 
 ///
-/// Type derived for production 690
+/// Type derived for production 704
 ///
 /// `AssignmentGroup: AssignmentOperator;`
 ///
@@ -4462,7 +4528,7 @@ pub struct AssignmentGroupAssignmentOperator {
 }
 
 ///
-/// Type derived for production 691
+/// Type derived for production 705
 ///
 /// `AssignmentGroup: DiamondOperator;`
 ///
@@ -4473,7 +4539,7 @@ pub struct AssignmentGroupDiamondOperator {
 }
 
 ///
-/// Type derived for production 713
+/// Type derived for production 727
 ///
 /// `CaseItemGroup0: Statement;`
 ///
@@ -4484,7 +4550,7 @@ pub struct CaseItemGroup0Statement {
 }
 
 ///
-/// Type derived for production 714
+/// Type derived for production 728
 ///
 /// `CaseItemGroup0: StatementBlock;`
 ///
@@ -4495,7 +4561,7 @@ pub struct CaseItemGroup0StatementBlock {
 }
 
 ///
-/// Type derived for production 715
+/// Type derived for production 729
 ///
 /// `CaseItemGroup: CaseCondition;`
 ///
@@ -4506,7 +4572,7 @@ pub struct CaseItemGroupCaseCondition {
 }
 
 ///
-/// Type derived for production 716
+/// Type derived for production 730
 ///
 /// `CaseItemGroup: Defaul;`
 ///
@@ -4517,7 +4583,7 @@ pub struct CaseItemGroupDefaul {
 }
 
 ///
-/// Type derived for production 724
+/// Type derived for production 738
 ///
 /// `SwitchItemGroup0: Statement;`
 ///
@@ -4528,7 +4594,7 @@ pub struct SwitchItemGroup0Statement {
 }
 
 ///
-/// Type derived for production 725
+/// Type derived for production 739
 ///
 /// `SwitchItemGroup0: StatementBlock;`
 ///
@@ -4539,7 +4605,7 @@ pub struct SwitchItemGroup0StatementBlock {
 }
 
 ///
-/// Type derived for production 726
+/// Type derived for production 740
 ///
 /// `SwitchItemGroup: SwitchCondition;`
 ///
@@ -4550,7 +4616,7 @@ pub struct SwitchItemGroupSwitchCondition {
 }
 
 ///
-/// Type derived for production 727
+/// Type derived for production 741
 ///
 /// `SwitchItemGroup: Defaul;`
 ///
@@ -4561,7 +4627,7 @@ pub struct SwitchItemGroupDefaul {
 }
 
 ///
-/// Type derived for production 739
+/// Type derived for production 753
 ///
 /// `AttributeItem: Identifier;`
 ///
@@ -4572,7 +4638,7 @@ pub struct AttributeItemIdentifier {
 }
 
 ///
-/// Type derived for production 740
+/// Type derived for production 754
 ///
 /// `AttributeItem: StringLiteral;`
 ///
@@ -4583,7 +4649,7 @@ pub struct AttributeItemStringLiteral {
 }
 
 ///
-/// Type derived for production 753
+/// Type derived for production 767
 ///
 /// `ConstDeclarationOptGroup: ArrayType;`
 ///
@@ -4594,7 +4660,7 @@ pub struct ConstDeclarationOptGroupArrayType {
 }
 
 ///
-/// Type derived for production 754
+/// Type derived for production 768
 ///
 /// `ConstDeclarationOptGroup: Type;`
 ///
@@ -4605,7 +4671,7 @@ pub struct ConstDeclarationOptGroupType {
 }
 
 ///
-/// Type derived for production 757
+/// Type derived for production 771
 ///
 /// `GenDeclarationGroup: GenericProtoBound;`
 ///
@@ -4616,7 +4682,7 @@ pub struct GenDeclarationGroupGenericProtoBound {
 }
 
 ///
-/// Type derived for production 758
+/// Type derived for production 772
 ///
 /// `GenDeclarationGroup: Type;`
 ///
@@ -4627,7 +4693,7 @@ pub struct GenDeclarationGroupType {
 }
 
 ///
-/// Type derived for production 770
+/// Type derived for production 784
 ///
 /// `AssignDestination: HierarchicalIdentifier;`
 ///
@@ -4638,7 +4704,7 @@ pub struct AssignDestinationHierarchicalIdentifier {
 }
 
 ///
-/// Type derived for production 771
+/// Type derived for production 785
 ///
 /// `AssignDestination: LBrace AssignConcatenationList RBrace;`
 ///
@@ -4651,7 +4717,7 @@ pub struct AssignDestinationLBraceAssignConcatenationListRBrace {
 }
 
 ///
-/// Type derived for production 790
+/// Type derived for production 804
 ///
 /// `ModportGroupGroup: LBrace ModportList RBrace;`
 ///
@@ -4664,7 +4730,7 @@ pub struct ModportGroupGroupLBraceModportListRBrace {
 }
 
 ///
-/// Type derived for production 791
+/// Type derived for production 805
 ///
 /// `ModportGroupGroup: ModportItem;`
 ///
@@ -4675,7 +4741,7 @@ pub struct ModportGroupGroupModportItem {
 }
 
 ///
-/// Type derived for production 795
+/// Type derived for production 809
 ///
 /// `ModportDefault: Input;`
 ///
@@ -4686,7 +4752,7 @@ pub struct ModportDefaultInput {
 }
 
 ///
-/// Type derived for production 796
+/// Type derived for production 810
 ///
 /// `ModportDefault: Output;`
 ///
@@ -4697,7 +4763,7 @@ pub struct ModportDefaultOutput {
 }
 
 ///
-/// Type derived for production 797
+/// Type derived for production 811
 ///
 /// `ModportDefault: Same LParen ModportDefaultList RParen;`
 ///
@@ -4711,7 +4777,7 @@ pub struct ModportDefaultSameLParenModportDefaultListRParen {
 }
 
 ///
-/// Type derived for production 798
+/// Type derived for production 812
 ///
 /// `ModportDefault: Converse LParen ModportDefaultList RParen;`
 ///
@@ -4725,7 +4791,7 @@ pub struct ModportDefaultConverseLParenModportDefaultListRParen {
 }
 
 ///
-/// Type derived for production 813
+/// Type derived for production 827
 ///
 /// `EnumGroupGroup: LBrace EnumList RBrace;`
 ///
@@ -4738,7 +4804,7 @@ pub struct EnumGroupGroupLBraceEnumListRBrace {
 }
 
 ///
-/// Type derived for production 814
+/// Type derived for production 828
 ///
 /// `EnumGroupGroup: EnumItem;`
 ///
@@ -4749,7 +4815,7 @@ pub struct EnumGroupGroupEnumItem {
 }
 
 ///
-/// Type derived for production 820
+/// Type derived for production 834
 ///
 /// `StructUnion: Struct;`
 ///
@@ -4760,7 +4826,7 @@ pub struct StructUnionStruct {
 }
 
 ///
-/// Type derived for production 821
+/// Type derived for production 835
 ///
 /// `StructUnion: Union;`
 ///
@@ -4771,7 +4837,7 @@ pub struct StructUnionUnion {
 }
 
 ///
-/// Type derived for production 831
+/// Type derived for production 845
 ///
 /// `StructUnionGroupGroup: LBrace StructUnionList RBrace;`
 ///
@@ -4784,7 +4850,7 @@ pub struct StructUnionGroupGroupLBraceStructUnionListRBrace {
 }
 
 ///
-/// Type derived for production 832
+/// Type derived for production 846
 ///
 /// `StructUnionGroupGroup: StructUnionItem;`
 ///
@@ -4795,7 +4861,7 @@ pub struct StructUnionGroupGroupStructUnionItem {
 }
 
 ///
-/// Type derived for production 858
+/// Type derived for production 872
 ///
 /// `InstParameterGroupGroup: LBrace InstParameterList RBrace;`
 ///
@@ -4808,7 +4874,7 @@ pub struct InstParameterGroupGroupLBraceInstParameterListRBrace {
 }
 
 ///
-/// Type derived for production 859
+/// Type derived for production 873
 ///
 /// `InstParameterGroupGroup: InstParameterItem;`
 ///
@@ -4819,7 +4885,7 @@ pub struct InstParameterGroupGroupInstParameterItem {
 }
 
 ///
-/// Type derived for production 874
+/// Type derived for production 888
 ///
 /// `InstPortGroupGroup: LBrace InstPortList RBrace;`
 ///
@@ -4832,7 +4898,7 @@ pub struct InstPortGroupGroupLBraceInstPortListRBrace {
 }
 
 ///
-/// Type derived for production 875
+/// Type derived for production 889
 ///
 /// `InstPortGroupGroup: InstPortItem;`
 ///
@@ -4843,7 +4909,7 @@ pub struct InstPortGroupGroupInstPortItem {
 }
 
 ///
-/// Type derived for production 890
+/// Type derived for production 904
 ///
 /// `WithParameterGroupGroup: LBrace WithParameterList RBrace;`
 ///
@@ -4856,7 +4922,7 @@ pub struct WithParameterGroupGroupLBraceWithParameterListRBrace {
 }
 
 ///
-/// Type derived for production 891
+/// Type derived for production 905
 ///
 /// `WithParameterGroupGroup: WithParameterItem;`
 ///
@@ -4867,7 +4933,7 @@ pub struct WithParameterGroupGroupWithParameterItem {
 }
 
 ///
-/// Type derived for production 895
+/// Type derived for production 909
 ///
 /// `WithParameterItemGroup0: ArrayType;`
 ///
@@ -4878,7 +4944,7 @@ pub struct WithParameterItemGroup0ArrayType {
 }
 
 ///
-/// Type derived for production 896
+/// Type derived for production 910
 ///
 /// `WithParameterItemGroup0: Type;`
 ///
@@ -4889,7 +4955,7 @@ pub struct WithParameterItemGroup0Type {
 }
 
 ///
-/// Type derived for production 897
+/// Type derived for production 911
 ///
 /// `WithParameterItemGroup: Param;`
 ///
@@ -4900,7 +4966,7 @@ pub struct WithParameterItemGroupParam {
 }
 
 ///
-/// Type derived for production 898
+/// Type derived for production 912
 ///
 /// `WithParameterItemGroup: Const;`
 ///
@@ -4911,7 +4977,7 @@ pub struct WithParameterItemGroupConst {
 }
 
 ///
-/// Type derived for production 901
+/// Type derived for production 915
 ///
 /// `GenericBound: Type;`
 ///
@@ -4922,7 +4988,7 @@ pub struct GenericBoundType {
 }
 
 ///
-/// Type derived for production 902
+/// Type derived for production 916
 ///
 /// `GenericBound: Inst ScopedIdentifier;`
 ///
@@ -4934,7 +5000,7 @@ pub struct GenericBoundInstScopedIdentifier {
 }
 
 ///
-/// Type derived for production 903
+/// Type derived for production 917
 ///
 /// `GenericBound: GenericProtoBound;`
 ///
@@ -4945,7 +5011,7 @@ pub struct GenericBoundGenericProtoBound {
 }
 
 ///
-/// Type derived for production 913
+/// Type derived for production 927
 ///
 /// `GenericProtoBound: ScopedIdentifier;`
 ///
@@ -4956,7 +5022,7 @@ pub struct GenericProtoBoundScopedIdentifier {
 }
 
 ///
-/// Type derived for production 914
+/// Type derived for production 928
 ///
 /// `GenericProtoBound: FixedType;`
 ///
@@ -4967,7 +5033,7 @@ pub struct GenericProtoBoundFixedType {
 }
 
 ///
-/// Type derived for production 923
+/// Type derived for production 937
 ///
 /// `WithGenericArgumentItem: GenericArgIdentifier;`
 ///
@@ -4978,7 +5044,7 @@ pub struct WithGenericArgumentItemGenericArgIdentifier {
 }
 
 ///
-/// Type derived for production 924
+/// Type derived for production 938
 ///
 /// `WithGenericArgumentItem: FixedType;`
 ///
@@ -4989,7 +5055,7 @@ pub struct WithGenericArgumentItemFixedType {
 }
 
 ///
-/// Type derived for production 925
+/// Type derived for production 939
 ///
 /// `WithGenericArgumentItem: Number;`
 ///
@@ -5000,7 +5066,7 @@ pub struct WithGenericArgumentItemNumber {
 }
 
 ///
-/// Type derived for production 926
+/// Type derived for production 940
 ///
 /// `WithGenericArgumentItem: BooleanLiteral;`
 ///
@@ -5011,7 +5077,7 @@ pub struct WithGenericArgumentItemBooleanLiteral {
 }
 
 ///
-/// Type derived for production 936
+/// Type derived for production 950
 ///
 /// `PortDeclarationGroupGroup: LBrace PortDeclarationList RBrace;`
 ///
@@ -5024,7 +5090,7 @@ pub struct PortDeclarationGroupGroupLBracePortDeclarationListRBrace {
 }
 
 ///
-/// Type derived for production 937
+/// Type derived for production 951
 ///
 /// `PortDeclarationGroupGroup: PortDeclarationItem;`
 ///
@@ -5035,7 +5101,7 @@ pub struct PortDeclarationGroupGroupPortDeclarationItem {
 }
 
 ///
-/// Type derived for production 941
+/// Type derived for production 955
 ///
 /// `PortDeclarationItemGroup: PortTypeConcrete;`
 ///
@@ -5046,7 +5112,7 @@ pub struct PortDeclarationItemGroupPortTypeConcrete {
 }
 
 ///
-/// Type derived for production 942
+/// Type derived for production 956
 ///
 /// `PortDeclarationItemGroup: PortTypeAbstract;`
 ///
@@ -5057,7 +5123,7 @@ pub struct PortDeclarationItemGroupPortTypeAbstract {
 }
 
 ///
-/// Type derived for production 956
+/// Type derived for production 970
 ///
 /// `Direction: Input;`
 ///
@@ -5068,7 +5134,7 @@ pub struct DirectionInput {
 }
 
 ///
-/// Type derived for production 957
+/// Type derived for production 971
 ///
 /// `Direction: Output;`
 ///
@@ -5079,7 +5145,7 @@ pub struct DirectionOutput {
 }
 
 ///
-/// Type derived for production 958
+/// Type derived for production 972
 ///
 /// `Direction: Inout;`
 ///
@@ -5090,7 +5156,7 @@ pub struct DirectionInout {
 }
 
 ///
-/// Type derived for production 959
+/// Type derived for production 973
 ///
 /// `Direction: Modport;`
 ///
@@ -5101,7 +5167,7 @@ pub struct DirectionModport {
 }
 
 ///
-/// Type derived for production 960
+/// Type derived for production 974
 ///
 /// `Direction: Import;`
 ///
@@ -5112,7 +5178,7 @@ pub struct DirectionImport {
 }
 
 ///
-/// Type derived for production 970
+/// Type derived for production 984
 ///
 /// `ImportDeclarationOptGroup: Star;`
 ///
@@ -5123,7 +5189,7 @@ pub struct ImportDeclarationOptGroupStar {
 }
 
 ///
-/// Type derived for production 971
+/// Type derived for production 985
 ///
 /// `ImportDeclarationOptGroup: MultipleImportList;`
 ///
@@ -5134,7 +5200,7 @@ pub struct ImportDeclarationOptGroupMultipleImportList {
 }
 
 ///
-/// Type derived for production 995
+/// Type derived for production 1009
 ///
 /// `ModuleGroupGroup: LBrace ModuleGroupGroupList /* Vec */ RBrace;`
 ///
@@ -5147,7 +5213,7 @@ pub struct ModuleGroupGroupLBraceModuleGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 998
+/// Type derived for production 1012
 ///
 /// `ModuleGroupGroup: ModuleItem;`
 ///
@@ -5158,7 +5224,7 @@ pub struct ModuleGroupGroupModuleItem {
 }
 
 ///
-/// Type derived for production 1012
+/// Type derived for production 1026
 ///
 /// `InterfaceGroupGroup: LBrace InterfaceGroupGroupList /* Vec */ RBrace;`
 ///
@@ -5171,7 +5237,7 @@ pub struct InterfaceGroupGroupLBraceInterfaceGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 1015
+/// Type derived for production 1029
 ///
 /// `InterfaceGroupGroup: InterfaceItem;`
 ///
@@ -5182,7 +5248,7 @@ pub struct InterfaceGroupGroupInterfaceItem {
 }
 
 ///
-/// Type derived for production 1018
+/// Type derived for production 1032
 ///
 /// `InterfaceItem: GenerateItem;`
 ///
@@ -5193,7 +5259,7 @@ pub struct InterfaceItemGenerateItem {
 }
 
 ///
-/// Type derived for production 1019
+/// Type derived for production 1033
 ///
 /// `InterfaceItem: MixinDeclaration;`
 ///
@@ -5204,7 +5270,7 @@ pub struct InterfaceItemMixinDeclaration {
 }
 
 ///
-/// Type derived for production 1020
+/// Type derived for production 1034
 ///
 /// `InterfaceItem: ModportDeclaration;`
 ///
@@ -5215,7 +5281,7 @@ pub struct InterfaceItemModportDeclaration {
 }
 
 ///
-/// Type derived for production 1041
+/// Type derived for production 1055
 ///
 /// `GenerateGroupGroup: LBrace GenerateGroupGroupList /* Vec */ RBrace;`
 ///
@@ -5228,7 +5294,7 @@ pub struct GenerateGroupGroupLBraceGenerateGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 1044
+/// Type derived for production 1058
 ///
 /// `GenerateGroupGroup: GenerateItem;`
 ///
@@ -5239,7 +5305,7 @@ pub struct GenerateGroupGroupGenerateItem {
 }
 
 ///
-/// Type derived for production 1047
+/// Type derived for production 1061
 ///
 /// `GenerateItem: LetDeclaration;`
 ///
@@ -5250,7 +5316,7 @@ pub struct GenerateItemLetDeclaration {
 }
 
 ///
-/// Type derived for production 1048
+/// Type derived for production 1062
 ///
 /// `GenerateItem: VarDeclaration;`
 ///
@@ -5261,7 +5327,7 @@ pub struct GenerateItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 1049
+/// Type derived for production 1063
 ///
 /// `GenerateItem: InstDeclaration;`
 ///
@@ -5272,7 +5338,7 @@ pub struct GenerateItemInstDeclaration {
 }
 
 ///
-/// Type derived for production 1050
+/// Type derived for production 1064
 ///
 /// `GenerateItem: BindDeclaration;`
 ///
@@ -5283,7 +5349,7 @@ pub struct GenerateItemBindDeclaration {
 }
 
 ///
-/// Type derived for production 1051
+/// Type derived for production 1065
 ///
 /// `GenerateItem: ConstDeclaration;`
 ///
@@ -5294,7 +5360,7 @@ pub struct GenerateItemConstDeclaration {
 }
 
 ///
-/// Type derived for production 1052
+/// Type derived for production 1066
 ///
 /// `GenerateItem: GenDeclaration;`
 ///
@@ -5305,7 +5371,7 @@ pub struct GenerateItemGenDeclaration {
 }
 
 ///
-/// Type derived for production 1053
+/// Type derived for production 1067
 ///
 /// `GenerateItem: AlwaysFfDeclaration;`
 ///
@@ -5316,7 +5382,7 @@ pub struct GenerateItemAlwaysFfDeclaration {
 }
 
 ///
-/// Type derived for production 1054
+/// Type derived for production 1068
 ///
 /// `GenerateItem: AlwaysCombDeclaration;`
 ///
@@ -5327,7 +5393,7 @@ pub struct GenerateItemAlwaysCombDeclaration {
 }
 
 ///
-/// Type derived for production 1055
+/// Type derived for production 1069
 ///
 /// `GenerateItem: AssignDeclaration;`
 ///
@@ -5338,7 +5404,7 @@ pub struct GenerateItemAssignDeclaration {
 }
 
 ///
-/// Type derived for production 1056
+/// Type derived for production 1070
 ///
 /// `GenerateItem: ConnectDeclaration;`
 ///
@@ -5349,7 +5415,7 @@ pub struct GenerateItemConnectDeclaration {
 }
 
 ///
-/// Type derived for production 1057
+/// Type derived for production 1071
 ///
 /// `GenerateItem: FunctionDeclaration;`
 ///
@@ -5360,7 +5426,7 @@ pub struct GenerateItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 1058
+/// Type derived for production 1072
 ///
 /// `GenerateItem: GenerateIfDeclaration;`
 ///
@@ -5371,7 +5437,7 @@ pub struct GenerateItemGenerateIfDeclaration {
 }
 
 ///
-/// Type derived for production 1059
+/// Type derived for production 1073
 ///
 /// `GenerateItem: GenerateForDeclaration;`
 ///
@@ -5382,7 +5448,7 @@ pub struct GenerateItemGenerateForDeclaration {
 }
 
 ///
-/// Type derived for production 1060
+/// Type derived for production 1074
 ///
 /// `GenerateItem: GenerateBlockDeclaration;`
 ///
@@ -5393,7 +5459,7 @@ pub struct GenerateItemGenerateBlockDeclaration {
 }
 
 ///
-/// Type derived for production 1061
+/// Type derived for production 1075
 ///
 /// `GenerateItem: TypeDefDeclaration;`
 ///
@@ -5404,7 +5470,7 @@ pub struct GenerateItemTypeDefDeclaration {
 }
 
 ///
-/// Type derived for production 1062
+/// Type derived for production 1076
 ///
 /// `GenerateItem: EnumDeclaration;`
 ///
@@ -5415,7 +5481,7 @@ pub struct GenerateItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 1063
+/// Type derived for production 1077
 ///
 /// `GenerateItem: StructUnionDeclaration;`
 ///
@@ -5426,7 +5492,7 @@ pub struct GenerateItemStructUnionDeclaration {
 }
 
 ///
-/// Type derived for production 1064
+/// Type derived for production 1078
 ///
 /// `GenerateItem: ImportDeclaration;`
 ///
@@ -5437,7 +5503,7 @@ pub struct GenerateItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1065
+/// Type derived for production 1079
 ///
 /// `GenerateItem: AliasDeclaration;`
 ///
@@ -5448,7 +5514,7 @@ pub struct GenerateItemAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1066
+/// Type derived for production 1080
 ///
 /// `GenerateItem: InitialDeclaration;`
 ///
@@ -5459,7 +5525,7 @@ pub struct GenerateItemInitialDeclaration {
 }
 
 ///
-/// Type derived for production 1067
+/// Type derived for production 1081
 ///
 /// `GenerateItem: FinalDeclaration;`
 ///
@@ -5470,7 +5536,7 @@ pub struct GenerateItemFinalDeclaration {
 }
 
 ///
-/// Type derived for production 1068
+/// Type derived for production 1082
 ///
 /// `GenerateItem: UnsafeBlock;`
 ///
@@ -5481,7 +5547,7 @@ pub struct GenerateItemUnsafeBlock {
 }
 
 ///
-/// Type derived for production 1069
+/// Type derived for production 1083
 ///
 /// `GenerateItem: EmbedDeclaration;`
 ///
@@ -5492,7 +5558,7 @@ pub struct GenerateItemEmbedDeclaration {
 }
 
 ///
-/// Type derived for production 1078
+/// Type derived for production 1092
 ///
 /// `PackageGroupGroup: LBrace PackageGroupGroupList /* Vec */ RBrace;`
 ///
@@ -5505,7 +5571,7 @@ pub struct PackageGroupGroupLBracePackageGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 1081
+/// Type derived for production 1095
 ///
 /// `PackageGroupGroup: PackageItem;`
 ///
@@ -5516,7 +5582,7 @@ pub struct PackageGroupGroupPackageItem {
 }
 
 ///
-/// Type derived for production 1084
+/// Type derived for production 1098
 ///
 /// `PackageItem: ConstDeclaration;`
 ///
@@ -5527,7 +5593,7 @@ pub struct PackageItemConstDeclaration {
 }
 
 ///
-/// Type derived for production 1085
+/// Type derived for production 1099
 ///
 /// `PackageItem: GenDeclaration;`
 ///
@@ -5538,7 +5604,7 @@ pub struct PackageItemGenDeclaration {
 }
 
 ///
-/// Type derived for production 1086
+/// Type derived for production 1100
 ///
 /// `PackageItem: TypeDefDeclaration;`
 ///
@@ -5549,7 +5615,7 @@ pub struct PackageItemTypeDefDeclaration {
 }
 
 ///
-/// Type derived for production 1087
+/// Type derived for production 1101
 ///
 /// `PackageItem: EnumDeclaration;`
 ///
@@ -5560,7 +5626,7 @@ pub struct PackageItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 1088
+/// Type derived for production 1102
 ///
 /// `PackageItem: StructUnionDeclaration;`
 ///
@@ -5571,7 +5637,7 @@ pub struct PackageItemStructUnionDeclaration {
 }
 
 ///
-/// Type derived for production 1089
+/// Type derived for production 1103
 ///
 /// `PackageItem: FunctionDeclaration;`
 ///
@@ -5582,7 +5648,7 @@ pub struct PackageItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 1090
+/// Type derived for production 1104
 ///
 /// `PackageItem: ImportDeclaration;`
 ///
@@ -5593,7 +5659,7 @@ pub struct PackageItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1091
+/// Type derived for production 1105
 ///
 /// `PackageItem: AliasDeclaration;`
 ///
@@ -5604,7 +5670,7 @@ pub struct PackageItemAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1092
+/// Type derived for production 1106
 ///
 /// `PackageItem: EmbedDeclaration;`
 ///
@@ -5615,7 +5681,7 @@ pub struct PackageItemEmbedDeclaration {
 }
 
 ///
-/// Type derived for production 1094
+/// Type derived for production 1108
 ///
 /// `AliasDeclarationGroup: Module;`
 ///
@@ -5626,7 +5692,7 @@ pub struct AliasDeclarationGroupModule {
 }
 
 ///
-/// Type derived for production 1095
+/// Type derived for production 1109
 ///
 /// `AliasDeclarationGroup: Interface;`
 ///
@@ -5637,7 +5703,7 @@ pub struct AliasDeclarationGroupInterface {
 }
 
 ///
-/// Type derived for production 1096
+/// Type derived for production 1110
 ///
 /// `AliasDeclarationGroup: Package;`
 ///
@@ -5648,7 +5714,7 @@ pub struct AliasDeclarationGroupPackage {
 }
 
 ///
-/// Type derived for production 1098
+/// Type derived for production 1112
 ///
 /// `ProtoDeclarationGroup: ProtoModuleDeclaration;`
 ///
@@ -5659,7 +5725,7 @@ pub struct ProtoDeclarationGroupProtoModuleDeclaration {
 }
 
 ///
-/// Type derived for production 1099
+/// Type derived for production 1113
 ///
 /// `ProtoDeclarationGroup: ProtoInterfaceDeclaration;`
 ///
@@ -5670,7 +5736,7 @@ pub struct ProtoDeclarationGroupProtoInterfaceDeclaration {
 }
 
 ///
-/// Type derived for production 1100
+/// Type derived for production 1114
 ///
 /// `ProtoDeclarationGroup: ProtoPackageDeclaration;`
 ///
@@ -5681,7 +5747,7 @@ pub struct ProtoDeclarationGroupProtoPackageDeclaration {
 }
 
 ///
-/// Type derived for production 1111
+/// Type derived for production 1125
 ///
 /// `ProtoInterfaceItem: VarDeclaration;`
 ///
@@ -5692,7 +5758,7 @@ pub struct ProtoInterfaceItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 1112
+/// Type derived for production 1126
 ///
 /// `ProtoInterfaceItem: ProtoConstDeclaration;`
 ///
@@ -5703,7 +5769,7 @@ pub struct ProtoInterfaceItemProtoConstDeclaration {
 }
 
 ///
-/// Type derived for production 1113
+/// Type derived for production 1127
 ///
 /// `ProtoInterfaceItem: ProtoFunctionDeclaration;`
 ///
@@ -5714,7 +5780,7 @@ pub struct ProtoInterfaceItemProtoFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 1114
+/// Type derived for production 1128
 ///
 /// `ProtoInterfaceItem: ProtoTypeDefDeclaration;`
 ///
@@ -5725,7 +5791,7 @@ pub struct ProtoInterfaceItemProtoTypeDefDeclaration {
 }
 
 ///
-/// Type derived for production 1115
+/// Type derived for production 1129
 ///
 /// `ProtoInterfaceItem: ProtoAliasDeclaration;`
 ///
@@ -5736,7 +5802,7 @@ pub struct ProtoInterfaceItemProtoAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1116
+/// Type derived for production 1130
 ///
 /// `ProtoInterfaceItem: ModportDeclaration;`
 ///
@@ -5747,7 +5813,7 @@ pub struct ProtoInterfaceItemModportDeclaration {
 }
 
 ///
-/// Type derived for production 1117
+/// Type derived for production 1131
 ///
 /// `ProtoInterfaceItem: ImportDeclaration;`
 ///
@@ -5758,7 +5824,7 @@ pub struct ProtoInterfaceItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1121
+/// Type derived for production 1135
 ///
 /// `ProtoPacakgeItem: ProtoConstDeclaration;`
 ///
@@ -5769,7 +5835,7 @@ pub struct ProtoPacakgeItemProtoConstDeclaration {
 }
 
 ///
-/// Type derived for production 1122
+/// Type derived for production 1136
 ///
 /// `ProtoPacakgeItem: ProtoTypeDefDeclaration;`
 ///
@@ -5780,7 +5846,7 @@ pub struct ProtoPacakgeItemProtoTypeDefDeclaration {
 }
 
 ///
-/// Type derived for production 1123
+/// Type derived for production 1137
 ///
 /// `ProtoPacakgeItem: EnumDeclaration;`
 ///
@@ -5791,7 +5857,7 @@ pub struct ProtoPacakgeItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 1124
+/// Type derived for production 1138
 ///
 /// `ProtoPacakgeItem: StructUnionDeclaration;`
 ///
@@ -5802,7 +5868,7 @@ pub struct ProtoPacakgeItemStructUnionDeclaration {
 }
 
 ///
-/// Type derived for production 1125
+/// Type derived for production 1139
 ///
 /// `ProtoPacakgeItem: ProtoFunctionDeclaration;`
 ///
@@ -5813,7 +5879,7 @@ pub struct ProtoPacakgeItemProtoFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 1126
+/// Type derived for production 1140
 ///
 /// `ProtoPacakgeItem: ProtoAliasDeclaration;`
 ///
@@ -5824,7 +5890,7 @@ pub struct ProtoPacakgeItemProtoAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1127
+/// Type derived for production 1141
 ///
 /// `ProtoPacakgeItem: ImportDeclaration;`
 ///
@@ -5835,7 +5901,7 @@ pub struct ProtoPacakgeItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1129
+/// Type derived for production 1143
 ///
 /// `ProtoConstDeclarationGroup: ArrayType;`
 ///
@@ -5846,7 +5912,7 @@ pub struct ProtoConstDeclarationGroupArrayType {
 }
 
 ///
-/// Type derived for production 1130
+/// Type derived for production 1144
 ///
 /// `ProtoConstDeclarationGroup: Type;`
 ///
@@ -5857,7 +5923,7 @@ pub struct ProtoConstDeclarationGroupType {
 }
 
 ///
-/// Type derived for production 1142
+/// Type derived for production 1156
 ///
 /// `ProtoAliasDeclarationGroup: Module;`
 ///
@@ -5868,7 +5934,7 @@ pub struct ProtoAliasDeclarationGroupModule {
 }
 
 ///
-/// Type derived for production 1143
+/// Type derived for production 1157
 ///
 /// `ProtoAliasDeclarationGroup: Interface;`
 ///
@@ -5879,7 +5945,7 @@ pub struct ProtoAliasDeclarationGroupInterface {
 }
 
 ///
-/// Type derived for production 1144
+/// Type derived for production 1158
 ///
 /// `ProtoAliasDeclarationGroup: Package;`
 ///
@@ -5890,7 +5956,7 @@ pub struct ProtoAliasDeclarationGroupPackage {
 }
 
 ///
-/// Type derived for production 1150
+/// Type derived for production 1164
 ///
 /// `EmbedItem: EmbedLBrace EmbedItemList /* Vec */ EmbedRBrace;`
 ///
@@ -5903,7 +5969,7 @@ pub struct EmbedItemEmbedLBraceEmbedItemListEmbedRBrace {
 }
 
 ///
-/// Type derived for production 1153
+/// Type derived for production 1167
 ///
 /// `EmbedItem: EmbedScopedIdentifier;`
 ///
@@ -5914,7 +5980,7 @@ pub struct EmbedItemEmbedScopedIdentifier {
 }
 
 ///
-/// Type derived for production 1154
+/// Type derived for production 1168
 ///
 /// `EmbedItem: Any;`
 ///
@@ -5925,7 +5991,7 @@ pub struct EmbedItemAny {
 }
 
 ///
-/// Type derived for production 1157
+/// Type derived for production 1171
 ///
 /// `DescriptionGroupGroup: LBrace DescriptionGroupGroupList /* Vec */ RBrace;`
 ///
@@ -5938,7 +6004,7 @@ pub struct DescriptionGroupGroupLBraceDescriptionGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 1160
+/// Type derived for production 1174
 ///
 /// `DescriptionGroupGroup: DescriptionItem;`
 ///
@@ -5949,7 +6015,7 @@ pub struct DescriptionGroupGroupDescriptionItem {
 }
 
 ///
-/// Type derived for production 1163
+/// Type derived for production 1177
 ///
 /// `DescriptionItem: DescriptionItemOpt /* Option */ PublicDescriptionItem;`
 ///
@@ -5961,7 +6027,7 @@ pub struct DescriptionItemDescriptionItemOptPublicDescriptionItem {
 }
 
 ///
-/// Type derived for production 1164
+/// Type derived for production 1178
 ///
 /// `DescriptionItem: ImportDeclaration;`
 ///
@@ -5972,7 +6038,7 @@ pub struct DescriptionItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1165
+/// Type derived for production 1179
 ///
 /// `DescriptionItem: BindDeclaration;`
 ///
@@ -5983,7 +6049,7 @@ pub struct DescriptionItemBindDeclaration {
 }
 
 ///
-/// Type derived for production 1166
+/// Type derived for production 1180
 ///
 /// `DescriptionItem: EmbedDeclaration;`
 ///
@@ -5994,7 +6060,7 @@ pub struct DescriptionItemEmbedDeclaration {
 }
 
 ///
-/// Type derived for production 1167
+/// Type derived for production 1181
 ///
 /// `DescriptionItem: IncludeDeclaration;`
 ///
@@ -6005,7 +6071,7 @@ pub struct DescriptionItemIncludeDeclaration {
 }
 
 ///
-/// Type derived for production 1170
+/// Type derived for production 1184
 ///
 /// `PublicDescriptionItem: ModuleDeclaration;`
 ///
@@ -6016,7 +6082,7 @@ pub struct PublicDescriptionItemModuleDeclaration {
 }
 
 ///
-/// Type derived for production 1171
+/// Type derived for production 1185
 ///
 /// `PublicDescriptionItem: InterfaceDeclaration;`
 ///
@@ -6027,7 +6093,7 @@ pub struct PublicDescriptionItemInterfaceDeclaration {
 }
 
 ///
-/// Type derived for production 1172
+/// Type derived for production 1186
 ///
 /// `PublicDescriptionItem: PackageDeclaration;`
 ///
@@ -6038,7 +6104,7 @@ pub struct PublicDescriptionItemPackageDeclaration {
 }
 
 ///
-/// Type derived for production 1173
+/// Type derived for production 1187
 ///
 /// `PublicDescriptionItem: AliasDeclaration;`
 ///
@@ -6049,7 +6115,7 @@ pub struct PublicDescriptionItemAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1174
+/// Type derived for production 1188
 ///
 /// `PublicDescriptionItem: ProtoDeclaration;`
 ///
@@ -6060,7 +6126,7 @@ pub struct PublicDescriptionItemProtoDeclaration {
 }
 
 ///
-/// Type derived for production 1175
+/// Type derived for production 1189
 ///
 /// `PublicDescriptionItem: FunctionDeclaration;`
 ///
@@ -6316,6 +6382,16 @@ pub struct AnyToken {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ArgumentExpression {
     pub expression: Box<Expression>,
+    pub argument_expression_opt: Option<ArgumentExpressionOpt>,
+}
+
+///
+/// Type derived for non-terminal ArgumentExpressionOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ArgumentExpressionOpt {
+    pub property_implication: Box<PropertyImplication>,
 }
 
 ///
@@ -7584,6 +7660,28 @@ pub struct ConverseToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal CycleDelay
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CycleDelay {
+    pub hash_hash: Box<HashHash>,
+    pub cycle_delay_group: Box<CycleDelayGroup>,
+}
+
+///
+/// Type derived for non-terminal CycleDelayGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum CycleDelayGroup {
+    Number(CycleDelayGroupNumber),
+    LBracketExpressionColonExpressionRBracket(
+        CycleDelayGroupLBracketExpressionColonExpressionRBracket,
+    ),
+}
+
 ///
 /// Type derived for non-terminal Defaul
 ///
@@ -9075,6 +9173,34 @@ pub struct Hash {
     pub hash_token: crate::veryl_token::VerylToken,
 }
 
+///
+/// Type derived for non-terminal HashHash
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HashHash {
+    pub hash_hash_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal HashHashTerm
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HashHashTerm {
+    pub hash_hash_term: crate::veryl_token::Token, /* ## */
+}
+
+///
+/// Type derived for non-terminal HashHashToken
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HashHashToken {
+    pub hash_hash_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal HashLBracket
 ///
@@ -9500,6 +9626,34 @@ pub struct IfToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Implication
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Implication {
+    pub implication_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal ImplicationTerm
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ImplicationTerm {
+    pub implication_term: crate::veryl_token::Token, /* \|->|\|=> */
+}
+
+///
+/// Type derived for non-terminal ImplicationToken
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ImplicationToken {
+    pub implication_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Import
 ///
@@ -11717,6 +11871,26 @@ pub struct PortTypeConcreteOpt0 {
     pub port_default_value: Box<PortDefaultValue>,
 }
 
+///
+/// Type derived for non-terminal PropertyImplication
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PropertyImplication {
+    pub implication: Box<Implication>,
+    pub property_implication_opt: Option<PropertyImplicationOpt>,
+    pub expression: Box<Expression>,
+}
+
+///
+/// Type derived for non-terminal PropertyImplicationOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PropertyImplicationOpt {
+    pub cycle_delay: Box<CycleDelay>,
+}
+
 ///
 /// Type derived for non-terminal Proto
 ///
@@ -14112,6 +14286,7 @@ pub enum ASTType {
     AnyTerm(AnyTerm),
     AnyToken(AnyToken),
     ArgumentExpression(ArgumentExpression),
+    ArgumentExpressionOpt(Option<ArgumentExpressionOpt>),
     ArgumentItem(ArgumentItem),
     ArgumentItemOpt(Option<ArgumentItemOpt>),
     ArgumentList(ArgumentList),
@@ -14237,6 +14412,8 @@ pub enum ASTType {
     Converse(Converse),
     ConverseTerm(ConverseTerm),
     ConverseToken(ConverseToken),
+    CycleDelay(CycleDelay),
+    CycleDelayGroup(CycleDelayGroup),
     Defaul(Defaul),
     DefaultTerm(DefaultTerm),
     DefaultToken(DefaultToken),
@@ -14382,6 +14559,9 @@ pub enum ASTType {
     GenericBound(GenericBound),
     GenericProtoBound(GenericProtoBound),
     Hash(Hash),
+    HashHash(HashHash),
+    HashHashTerm(HashHashTerm),
+    HashHashToken(HashHashToken),
     HashLBracket(HashLBracket),
     HashLBracketTerm(HashLBracketTerm),
     HashLBracketToken(HashLBracketToken),
@@ -14425,6 +14605,9 @@ pub enum ASTType {
     IfStatementOpt(Option<IfStatementOpt>),
     IfTerm(IfTerm),
     IfToken(IfToken),
+    Implication(Implication),
+    ImplicationTerm(ImplicationTerm),
+    ImplicationToken(ImplicationToken),
     Import(Import),
     ImportDeclaration(ImportDeclaration),
     ImportDeclarationOpt(Option<ImportDeclarationOpt>),
@@ -14651,6 +14834,8 @@ pub enum ASTType {
     PortTypeConcrete(PortTypeConcrete),
     PortTypeConcreteOpt(Option<PortTypeConcreteOpt>),
     PortTypeConcreteOpt0(Option<PortTypeConcreteOpt0>),
+    PropertyImplication(PropertyImplication),
+    PropertyImplicationOpt(Option<PropertyImplicationOpt>),
     Proto(Proto),
     ProtoAliasDeclaration(ProtoAliasDeclaration),
     ProtoAliasDeclarationGroup(ProtoAliasDeclarationGroup),
@@ -15171,6 +15356,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 11:
     ///
+    /// `ImplicationTerm: "\|->|\|=>" : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn implication_term(&mut self, implication_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let implication_term = implication_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let implication_term_built = ImplicationTerm { implication_term };
+        // Calling user action here
+        self.user_grammar
+            .implication_term(&implication_term_built)?;
+        self.push(ASTType::ImplicationTerm(implication_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 12:
+    ///
     /// `AssignmentOperatorTerm: "\+=|-=|\*=|/=|%=|&=|\|=|\^=|<<=|>>=|<<<=|>>>=" : Token;`
     ///
     #[parol_runtime::function_name::named]
@@ -15197,7 +15402,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 12:
+    /// Semantic action for production 13:
     ///
     /// `DiamondOperatorTerm: '<>' : Token;`
     ///
@@ -15222,7 +15427,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 13:
+    /// Semantic action for production 14:
     ///
     /// `Operator08Term: "\*\*" : Token;`
     ///
@@ -15241,7 +15446,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 14:
+    /// Semantic action for production 15:
     ///
     /// `Operator07Term: "/|%" : Token;`
     ///
@@ -15260,7 +15465,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 15:
+    /// Semantic action for production 16:
     ///
     /// `Operator06Term: "\+|-" : Token;`
     ///
@@ -15279,7 +15484,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 16:
+    /// Semantic action for production 17:
     ///
     /// `Operator02Term: "<<<|>>>|<<|>>|==\?|!=\?|==|!=|<=|>=|<:|>:" : Token;`
     ///
@@ -15298,7 +15503,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 17:
+    /// Semantic action for production 18:
     ///
     /// `Operator01Term: "\|\||&&" : Token;`
     ///
@@ -15317,7 +15522,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 18:
+    /// Semantic action for production 19:
     ///
     /// `Operator05Term: "&" : Token;`
     ///
@@ -15336,7 +15541,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 19:
+    /// Semantic action for production 20:
     ///
     /// `Operator04Term: "\^|~\^" : Token;`
     ///
@@ -15355,7 +15560,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 20:
+    /// Semantic action for production 21:
     ///
     /// `Operator03Term: "\|" : Token;`
     ///
@@ -15374,7 +15579,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 21:
+    /// Semantic action for production 22:
     ///
     /// `UnaryOperatorTerm: "~&|~\||!|~" : Token;`
     ///
@@ -15399,7 +15604,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 22:
+    /// Semantic action for production 23:
     ///
     /// `ColonColonLAngleTerm: <INITIAL, Generic>'::<' : Token;`
     ///
@@ -15427,7 +15632,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 23:
+    /// Semantic action for production 24:
     ///
     /// `ColonColonTerm: <INITIAL, Generic>'::' : Token;`
     ///
@@ -15447,7 +15652,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 24:
+    /// Semantic action for production 25:
     ///
     /// `ColonTerm: <INITIAL, Generic>':' : Token;`
     ///
@@ -15466,7 +15671,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 25:
+    /// Semantic action for production 26:
     ///
     /// `CommaTerm: <INITIAL, Generic, Attr>',' : Token;`
     ///
@@ -15485,7 +15690,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 26:
+    /// Semantic action for production 27:
     ///
     /// `DotDotEquTerm: '..=' : Token;`
     ///
@@ -15505,7 +15710,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 27:
+    /// Semantic action for production 28:
     ///
     /// `DotDotTerm: '..' : Token;`
     ///
@@ -15524,7 +15729,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 28:
+    /// Semantic action for production 29:
     ///
     /// `DotTerm: <INITIAL, Generic>'.' : Token;`
     ///
@@ -15543,7 +15748,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 29:
+    /// Semantic action for production 30:
     ///
     /// `EquTerm: <INITIAL, Generic>'=' : Token;`
     ///
@@ -15562,7 +15767,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 30:
+    /// Semantic action for production 31:
+    ///
+    /// `HashHashTerm: '##' : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn hash_hash_term(&mut self, hash_hash_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let hash_hash_term = hash_hash_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let hash_hash_term_built = HashHashTerm { hash_hash_term };
+        // Calling user action here
+        self.user_grammar.hash_hash_term(&hash_hash_term_built)?;
+        self.push(ASTType::HashHashTerm(hash_hash_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 32:
     ///
     /// `HashLBracketTerm: '#[' : Token;`
     ///
@@ -15587,7 +15811,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 31:
+    /// Semantic action for production 33:
     ///
     /// `HashTerm: '#' : Token;`
     ///
@@ -15606,7 +15830,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 32:
+    /// Semantic action for production 34:
     ///
     /// `LAngleTerm: '<' : Token;`
     ///
@@ -15625,7 +15849,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 33:
+    /// Semantic action for production 35:
     ///
     /// `QuestionTerm: '?' : Token;`
     ///
@@ -15644,7 +15868,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 34:
+    /// Semantic action for production 36:
     ///
     /// `QuoteLBraceTerm: "'\{" : Token;`
     ///
@@ -15664,7 +15888,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 35:
+    /// Semantic action for production 37:
     ///
     /// `QuoteTerm: "'" : Token;`
     ///
@@ -15683,7 +15907,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 36:
+    /// Semantic action for production 38:
     ///
     /// `EscapedLBraceTerm: <EmbedBody, EmbedBodyInner>'\{' : Token;`
     ///
@@ -15708,7 +15932,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 37:
+    /// Semantic action for production 39:
     ///
     /// `TripleLBraceTerm: <EmbedHeader>'{{{' : Token;`
     ///
@@ -15733,7 +15957,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 40:
     ///
     /// `LBraceTerm: <INITIAL, EmbedBody, EmbedBodyInner, Attr>'{' : Token;`
     ///
@@ -15752,7 +15976,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 41:
     ///
     /// `LBracketTerm: <INITIAL, Attr>'[' : Token;`
     ///
@@ -15771,7 +15995,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 42:
     ///
     /// `LParenTerm: <INITIAL, EmbedHeader, Attr>'(' : Token;`
     ///
@@ -15790,7 +16014,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 43:
     ///
     /// `RAngleTerm: <INITIAL, Generic>'>' : Token;`
     ///
@@ -15809,7 +16033,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 44:
     ///
     /// `EscapedRBraceTerm: '\}' : Token;`
     ///
@@ -15834,7 +16058,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 45:
     ///
     /// `TripleRBraceTerm: <EmbedBody>'}}}' : Token;`
     ///
@@ -15859,7 +16083,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 46:
     ///
     /// `RBraceTerm: <INITIAL, EmbedBodyInner, Attr>'}' : Token;`
     ///
@@ -15878,7 +16102,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 47:
     ///
     /// `RBracketTerm: <INITIAL, Attr>']' : Token;`
     ///
@@ -15897,7 +16121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 48:
     ///
     /// `RParenTerm: <INITIAL, EmbedHeader, Attr>')' : Token;`
     ///
@@ -15916,7 +16140,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 49:
     ///
     /// `SemicolonTerm: ';' : Token;`
     ///
@@ -15935,7 +16159,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 50:
     ///
     /// `StarTerm: '*' : Token;`
     ///
@@ -15954,7 +16178,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 51:
     ///
     /// `AliasTerm: <INITIAL, Generic>'alias' : Token;`
     ///
@@ -15973,7 +16197,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 52:
     ///
     /// `AlwaysCombTerm: <INITIAL, Generic>'always_comb' : Token;`
     ///
@@ -15993,7 +16217,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 53:
     ///
     /// `AlwaysFfTerm: <INITIAL, Generic>'always_ff' : Token;`
     ///
@@ -16012,7 +16236,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 54:
     ///
     /// `AssignTerm: <INITIAL, Generic>'assign' : Token;`
     ///
@@ -16031,7 +16255,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 55:
     ///
     /// `AsTerm: <INITIAL, Generic>'as' : Token;`
     ///
@@ -16050,7 +16274,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 56:
     ///
     /// `BindTerm: <INITIAL, Generic>'bind' : Token;`
     ///
@@ -16069,7 +16293,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 57:
     ///
     /// `BitTerm: <INITIAL, Generic>'bit' : Token;`
     ///
@@ -16088,7 +16312,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 58:
     ///
     /// `BlockTerm: <INITIAL, Generic>'block' : Token;`
     ///
@@ -16107,7 +16331,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 59:
     ///
     /// `BBoolTerm: <INITIAL, Generic>'bbool' : Token;`
     ///
@@ -16126,7 +16350,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 60:
     ///
     /// `LBoolTerm: <INITIAL, Generic>'lbool' : Token;`
     ///
@@ -16145,7 +16369,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 61:
     ///
     /// `CaseTerm: <INITIAL, Generic>'case' : Token;`
     ///
@@ -16164,7 +16388,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 62:
     ///
     /// `ClockTerm: <INITIAL, Generic>'clock' : Token;`
     ///
@@ -16183,7 +16407,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 63:
     ///
     /// `ClockPosedgeTerm: <INITIAL, Generic>'clock_posedge' : Token;`
     ///
@@ -16203,7 +16427,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 64:
     ///
     /// `ClockNegedgeTerm: <INITIAL, Generic>'clock_negedge' : Token;`
     ///
@@ -16223,7 +16447,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 65:
     ///
     /// `ConnectTerm: <INITIAL, Generic>'connect' : Token;`
     ///
@@ -16242,7 +16466,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 66:
     ///
     /// `ConstTerm: <INITIAL, Generic>'const' : Token;`
     ///
@@ -16261,7 +16485,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 67:
     ///
     /// `ConverseTerm: <INITIAL, Generic>'converse' : Token;`
     ///
@@ -16280,7 +16504,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 68:
     ///
     /// `DefaultTerm: <INITIAL, Generic>'default' : Token;`
     ///
@@ -16299,7 +16523,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 69:
     ///
     /// `ElseTerm: <INITIAL, Generic>'else' : Token;`
     ///
@@ -16318,7 +16542,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 70:
     ///
     /// `EmbedTerm: <INITIAL, Generic>'embed' : Token;`
     ///
@@ -16337,7 +16561,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 71:
     ///
     /// `EnumTerm: <INITIAL, Generic>'enum' : Token;`
     ///
@@ -16356,7 +16580,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 72:
     ///
     /// `F32Term: <INITIAL, Generic>'f32' : Token;`
     ///
@@ -16375,7 +16599,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 73:
     ///
     /// `F64Term: <INITIAL, Generic>'f64' : Token;`
     ///
@@ -16394,7 +16618,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 74:
     ///
     /// `FalseTerm: <INITIAL, Generic>'false' : Token;`
     ///
@@ -16413,7 +16637,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 75:
     ///
     /// `FinalTerm: <INITIAL, Generic>'final' : Token;`
     ///
@@ -16432,7 +16656,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 76:
     ///
     /// `ForTerm: <INITIAL, Generic>'for' : Token;`
     ///
@@ -16451,7 +16675,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 77:
     ///
     /// `FunctionTerm: <INITIAL, Generic>'function' : Token;`
     ///
@@ -16470,7 +16694,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 78:
     ///
     /// `GenTerm: <INITIAL, Generic>'gen' : Token;`
     ///
@@ -16489,7 +16713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 79:
     ///
     /// `I8Term: <INITIAL, Generic>'i8' : Token;`
     ///
@@ -16508,7 +16732,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 80:
     ///
     /// `I16Term: <INITIAL, Generic>'i16' : Token;`
     ///
@@ -16527,7 +16751,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 81:
     ///
     /// `I32Term: <INITIAL, Generic>'i32' : Token;`
     ///
@@ -16546,7 +16770,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 82:
     ///
     /// `I64Term: <INITIAL, Generic>'i64' : Token;`
     ///
@@ -16565,7 +16789,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 83:
     ///
     /// `IfResetTerm: <INITIAL, Generic>'if_reset' : Token;`
     ///
@@ -16584,7 +16808,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 84:
     ///
     /// `IfTerm: <INITIAL, Generic>'if' : Token;`
     ///
@@ -16603,7 +16827,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 85:
     ///
     /// `ImportTerm: <INITIAL, Generic>'import' : Token;`
     ///
@@ -16622,7 +16846,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 86:
     ///
     /// `IncludeTerm: <INITIAL, Generic>'include' : Token;`
     ///
@@ -16641,7 +16865,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 87:
     ///
     /// `InitialTerm: <INITIAL, Generic>'initial' : Token;`
     ///
@@ -16660,7 +16884,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 86:
+    /// Semantic action for production 88:
     ///
     /// `InoutTerm: <INITIAL, Generic>'inout' : Token;`
     ///
@@ -16679,7 +16903,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 87:
+    /// Semantic action for production 89:
     ///
     /// `InputTerm: <INITIAL, Generic>'input' : Token;`
     ///
@@ -16698,7 +16922,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 88:
+    /// Semantic action for production 90:
     ///
     /// `InsideTerm: <INITIAL, Generic>'inside' : Token;`
     ///
@@ -16717,7 +16941,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 89:
+    /// Semantic action for production 91:
     ///
     /// `InstTerm: <INITIAL, Generic>'inst' : Token;`
     ///
@@ -16736,7 +16960,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 90:
+    /// Semantic action for production 92:
     ///
     /// `InterfaceTerm: <INITIAL, Generic>'interface' : Token;`
     ///
@@ -16755,7 +16979,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 91:
+    /// Semantic action for production 93:
     ///
     /// `InTerm: <INITIAL, Generic>'in' : Token;`
     ///
@@ -16774,7 +16998,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 92:
+    /// Semantic action for production 94:
     ///
     /// `LetTerm: <INITIAL, Generic>'let' : Token;`
     ///
@@ -16793,7 +17017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 93:
+    /// Semantic action for production 95:
     ///
     /// `LogicTerm: <INITIAL, Generic>'logic' : Token;`
     ///
@@ -16812,7 +17036,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 94:
+    /// Semantic action for production 96:
     ///
     /// `LsbTerm: <INITIAL, Generic>'lsb' : Token;`
     ///
@@ -16831,7 +17055,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 95:
+    /// Semantic action for production 97:
     ///
     /// `MixinTerm: <INITIAL, Generic>'mixin' : Token;`
     ///
@@ -16850,7 +17074,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 96:
+    /// Semantic action for production 98:
     ///
     /// `ModportTerm: <INITIAL, Generic>'modport' : Token;`
     ///
@@ -16869,7 +17093,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 97:
+    /// Semantic action for production 99:
     ///
     /// `ModuleTerm: <INITIAL, Generic>'module' : Token;`
     ///
@@ -16888,7 +17112,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 98:
+    /// Semantic action for production 100:
     ///
     /// `MsbTerm: <INITIAL, Generic>'msb' : Token;`
     ///
@@ -16907,7 +17131,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 99:
+    /// Semantic action for production 101:
     ///
     /// `OutputTerm: <INITIAL, Generic>'output' : Token;`
     ///
@@ -16926,7 +17150,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 100:
+    /// Semantic action for production 102:
     ///
     /// `OutsideTerm: <INITIAL, Generic>'outside' : Token;`
     ///
@@ -16945,7 +17169,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 101:
+    /// Semantic action for production 103:
     ///
     /// `PackageTerm: <INITIAL, Generic>'package' : Token;`
     ///
@@ -16964,7 +17188,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 102:
+    /// Semantic action for production 104:
     ///
     /// `ParamTerm: <INITIAL, Generic>'param' : Token;`
     ///
@@ -16983,7 +17207,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 103:
+    /// Semantic action for production 105:
     ///
     /// `ProtoTerm: <INITIAL, Generic>'proto' : Token;`
     ///
@@ -17002,7 +17226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 104:
+    /// Semantic action for production 106:
     ///
     /// `PubTerm: <INITIAL, Generic>'pub' : Token;`
     ///
@@ -17021,7 +17245,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 105:
+    /// Semantic action for production 107:
     ///
     /// `RepeatTerm: <INITIAL, Generic>'repeat' : Token;`
     ///
@@ -17040,7 +17264,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 106:
+    /// Semantic action for production 108:
     ///
     /// `ResetTerm: <INITIAL, Generic>'reset' : Token;`
     ///
@@ -17059,7 +17283,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 107:
+    /// Semantic action for production 109:
     ///
     /// `ResetAsyncHighTerm: <INITIAL, Generic>'reset_async_high' : Token;`
     ///
@@ -17084,7 +17308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 108:
+    /// Semantic action for production 110:
     ///
     /// `ResetAsyncLowTerm: <INITIAL, Generic>'reset_async_low' : Token;`
     ///
@@ -17109,7 +17333,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 109:
+    /// Semantic action for production 111:
     ///
     /// `ResetSyncHighTerm: <INITIAL, Generic>'reset_sync_high' : Token;`
     ///
@@ -17134,7 +17358,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 110:
+    /// Semantic action for production 112:
     ///
     /// `ResetSyncLowTerm: <INITIAL, Generic>'reset_sync_low' : Token;`
     ///
@@ -17159,7 +17383,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 111:
+    /// Semantic action for production 113:
     ///
     /// `ReturnTerm: <INITIAL, Generic>'return' : Token;`
     ///
@@ -17178,7 +17402,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 112:
+    /// Semantic action for production 114:
     ///
     /// `RevTerm: <INITIAL, Generic>'rev' : Token;`
     ///
@@ -17197,7 +17421,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 113:
+    /// Semantic action for production 115:
     ///
     /// `BreakTerm: <INITIAL, Generic>'break' : Token;`
     ///
@@ -17216,7 +17440,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 114:
+    /// Semantic action for production 116:
     ///
     /// `SameTerm: <INITIAL, Generic>'same' : Token;`
     ///
@@ -17235,7 +17459,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 115:
+    /// Semantic action for production 117:
     ///
     /// `SignedTerm: <INITIAL, Generic>'signed' : Token;`
     ///
@@ -17254,7 +17478,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 116:
+    /// Semantic action for production 118:
     ///
     /// `StepTerm: <INITIAL, Generic>'step' : Token;`
     ///
@@ -17273,7 +17497,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 117:
+    /// Semantic action for production 119:
     ///
     /// `StringTerm: <INITIAL, Generic>'string' : Token;`
     ///
@@ -17292,7 +17516,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 118:
+    /// Semantic action for production 120:
     ///
     /// `StructTerm: <INITIAL, Generic>'struct' : Token;`
     ///
@@ -17311,7 +17535,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 119:
+    /// Semantic action for production 121:
     ///
     /// `SwitchTerm: <INITIAL, Generic>'switch' : Token;`
     ///
@@ -17330,7 +17554,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 120:
+    /// Semantic action for production 122:
     ///
     /// `TriTerm: <INITIAL, Generic>'tri' : Token;`
     ///
@@ -17349,7 +17573,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 121:
+    /// Semantic action for production 123:
     ///
     /// `TrueTerm: <INITIAL, Generic>'true' : Token;`
     ///
@@ -17368,7 +17592,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 122:
+    /// Semantic action for production 124:
     ///
     /// `TypeTerm: <INITIAL, Generic>'type' : Token;`
     ///
@@ -17387,7 +17611,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 123:
+    /// Semantic action for production 125:
     ///
     /// `P8Term: <INITIAL, Generic>'p8' : Token;`
     ///
@@ -17406,7 +17630,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 124:
+    /// Semantic action for production 126:
     ///
     /// `P16Term: <INITIAL, Generic>'p16' : Token;`
     ///
@@ -17425,7 +17649,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 125:
+    /// Semantic action for production 127:
     ///
     /// `P32Term: <INITIAL, Generic>'p32' : Token;`
     ///
@@ -17444,7 +17668,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 126:
+    /// Semantic action for production 128:
     ///
     /// `P64Term: <INITIAL, Generic>'p64' : Token;`
     ///
@@ -17463,7 +17687,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 127:
+    /// Semantic action for production 129:
     ///
     /// `U8Term: <INITIAL, Generic>'u8' : Token;`
     ///
@@ -17482,7 +17706,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 128:
+    /// Semantic action for production 130:
     ///
     /// `U16Term: <INITIAL, Generic>'u16' : Token;`
     ///
@@ -17501,7 +17725,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 129:
+    /// Semantic action for production 131:
     ///
     /// `U32Term: <INITIAL, Generic>'u32' : Token;`
     ///
@@ -17520,7 +17744,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 130:
+    /// Semantic action for production 132:
     ///
     /// `U64Term: <INITIAL, Generic>'u64' : Token;`
     ///
@@ -17539,7 +17763,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 131:
+    /// Semantic action for production 133:
     ///
     /// `UnionTerm: <INITIAL, Generic>'union' : Token;`
     ///
@@ -17558,7 +17782,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 132:
+    /// Semantic action for production 134:
     ///
     /// `UnsafeTerm: <INITIAL, Generic>'unsafe' : Token;`
     ///
@@ -17577,7 +17801,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 133:
+    /// Semantic action for production 135:
     ///
     /// `VarTerm: <INITIAL, Generic>'var' : Token;`
     ///
@@ -17596,7 +17820,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 134:
+    /// Semantic action for production 136:
     ///
     /// `DollarIdentifierTerm: <INITIAL, Generic>/\$[a-zA-Z_][0-9a-zA-Z_$]*/ : Token;`
     ///
@@ -17621,7 +17845,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 135:
+    /// Semantic action for production 137:
     ///
     /// `IdentifierTerm: <INITIAL, EmbedHeader, Generic, Attr>/(?:r#)?[a-zA-Z_][0-9a-zA-Z_$]*/ : Token;`
     ///
@@ -17640,7 +17864,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 136:
+    /// Semantic action for production 138:
     ///
     /// `AnyTerm: <EmbedBody, EmbedBodyInner>/(?:[^{}\\]|\\[^{])+/ : Token;`
     ///
@@ -17659,7 +17883,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 137:
+    /// Semantic action for production 139:
     ///
     /// `Comments: CommentsOpt /* Option */;`
     ///
@@ -17675,7 +17899,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 138:
+    /// Semantic action for production 140:
     ///
     /// `CommentsOpt /* Option<T>::Some */: CommentsTerm;`
     ///
@@ -17691,7 +17915,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 139:
+    /// Semantic action for production 141:
     ///
     /// `CommentsOpt /* Option<T>::None */: ;`
     ///
@@ -17703,7 +17927,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 140:
+    /// Semantic action for production 142:
     ///
     /// `StartToken: Comments;`
     ///
@@ -17721,7 +17945,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 141:
+    /// Semantic action for production 143:
     ///
     /// `StringLiteralToken: StringLiteralTerm : Token Comments;`
     ///
@@ -17751,7 +17975,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 142:
+    /// Semantic action for production 144:
     ///
     /// `ExponentToken: ExponentTerm : Token Comments;`
     ///
@@ -17777,7 +18001,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 143:
+    /// Semantic action for production 145:
     ///
     /// `FixedPointToken: FixedPointTerm : Token Comments;`
     ///
@@ -17804,7 +18028,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 144:
+    /// Semantic action for production 146:
     ///
     /// `BasedToken: BasedTerm : Token Comments;`
     ///
@@ -17830,7 +18054,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 145:
+    /// Semantic action for production 147:
     ///
     /// `BaseLessToken: BaseLessTerm : Token Comments;`
     ///
@@ -17856,7 +18080,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 146:
+    /// Semantic action for production 148:
     ///
     /// `AllBitToken: AllBitTerm : Token Comments;`
     ///
@@ -17882,7 +18106,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 147:
+    /// Semantic action for production 149:
     ///
     /// `AssignmentOperatorToken: AssignmentOperatorTerm : Token Comments;`
     ///
@@ -17917,7 +18141,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 148:
+    /// Semantic action for production 150:
     ///
     /// `DiamondOperatorToken: DiamondOperatorTerm : Token Comments;`
     ///
@@ -17948,7 +18172,34 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 149:
+    /// Semantic action for production 151:
+    ///
+    /// `ImplicationToken: ImplicationTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn implication_token(
+        &mut self,
+        _implication_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let implication_term = pop_item!(self, implication_term, ImplicationTerm, context);
+        let implication_token_built = ImplicationToken {
+            implication_term: (&implication_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar
+            .implication_token(&implication_token_built)?;
+        self.push(ASTType::ImplicationToken(implication_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 152:
     ///
     /// `Operator01Token: Operator01Term : Token Comments;`
     ///
@@ -17975,7 +18226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 150:
+    /// Semantic action for production 153:
     ///
     /// `Operator02Token: Operator02Term : Token Comments;`
     ///
@@ -18002,7 +18253,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 151:
+    /// Semantic action for production 154:
     ///
     /// `Operator03Token: Operator03Term : Token Comments;`
     ///
@@ -18029,7 +18280,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 152:
+    /// Semantic action for production 155:
     ///
     /// `Operator04Token: Operator04Term : Token Comments;`
     ///
@@ -18056,7 +18307,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 153:
+    /// Semantic action for production 156:
     ///
     /// `Operator05Token: Operator05Term : Token Comments;`
     ///
@@ -18083,7 +18334,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 154:
+    /// Semantic action for production 157:
     ///
     /// `Operator06Token: Operator06Term : Token Comments;`
     ///
@@ -18110,7 +18361,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 155:
+    /// Semantic action for production 158:
     ///
     /// `Operator07Token: Operator07Term : Token Comments;`
     ///
@@ -18137,7 +18388,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 156:
+    /// Semantic action for production 159:
     ///
     /// `Operator08Token: Operator08Term : Token Comments;`
     ///
@@ -18164,7 +18415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 157:
+    /// Semantic action for production 160:
     ///
     /// `UnaryOperatorToken: UnaryOperatorTerm : Token Comments;`
     ///
@@ -18194,7 +18445,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 158:
+    /// Semantic action for production 161:
     ///
     /// `ColonToken: ColonTerm : Token Comments;`
     ///
@@ -18220,7 +18471,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 159:
+    /// Semantic action for production 162:
     ///
     /// `ColonColonLAngleToken: ColonColonLAngleTerm : Token Comments;`
     ///
@@ -18255,7 +18506,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 160:
+    /// Semantic action for production 163:
     ///
     /// `ColonColonToken: ColonColonTerm : Token Comments;`
     ///
@@ -18282,7 +18533,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 161:
+    /// Semantic action for production 164:
     ///
     /// `CommaToken: CommaTerm : Token Comments;`
     ///
@@ -18308,7 +18559,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 162:
+    /// Semantic action for production 165:
     ///
     /// `DotDotToken: DotDotTerm : Token Comments;`
     ///
@@ -18334,7 +18585,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 163:
+    /// Semantic action for production 166:
     ///
     /// `DotDotEquToken: DotDotEquTerm : Token Comments;`
     ///
@@ -18361,7 +18612,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 164:
+    /// Semantic action for production 167:
     ///
     /// `DotToken: DotTerm : Token Comments;`
     ///
@@ -18387,7 +18638,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 165:
+    /// Semantic action for production 168:
     ///
     /// `EquToken: EquTerm : Token Comments;`
     ///
@@ -18413,7 +18664,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 166:
+    /// Semantic action for production 169:
+    ///
+    /// `HashHashToken: HashHashTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn hash_hash_token(
+        &mut self,
+        _hash_hash_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let hash_hash_term = pop_item!(self, hash_hash_term, HashHashTerm, context);
+        let hash_hash_token_built = HashHashToken {
+            hash_hash_term: (&hash_hash_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.hash_hash_token(&hash_hash_token_built)?;
+        self.push(ASTType::HashHashToken(hash_hash_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 170:
     ///
     /// `HashLBracketToken: HashLBracketTerm : Token Comments;`
     ///
@@ -18443,7 +18720,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 167:
+    /// Semantic action for production 171:
     ///
     /// `HashToken: HashTerm : Token Comments;`
     ///
@@ -18469,7 +18746,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 168:
+    /// Semantic action for production 172:
     ///
     /// `QuestionToken: QuestionTerm : Token Comments;`
     ///
@@ -18495,7 +18772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 169:
+    /// Semantic action for production 173:
     ///
     /// `QuoteLBraceToken: QuoteLBraceTerm : Token Comments;`
     ///
@@ -18525,7 +18802,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 170:
+    /// Semantic action for production 174:
     ///
     /// `QuoteToken: QuoteTerm : Token Comments;`
     ///
@@ -18551,7 +18828,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 171:
+    /// Semantic action for production 175:
     ///
     /// `LAngleToken: LAngleTerm : Token Comments;`
     ///
@@ -18577,7 +18854,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 172:
+    /// Semantic action for production 176:
     ///
     /// `EmbedLBraceToken: LBraceTerm : Token;`
     ///
@@ -18601,7 +18878,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 173:
+    /// Semantic action for production 177:
     ///
     /// `EscapedLBraceToken: EscapedLBraceTerm : Token;`
     ///
@@ -18626,7 +18903,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 174:
+    /// Semantic action for production 178:
     ///
     /// `TripleLBraceToken: TripleLBraceTerm : Token;`
     ///
@@ -18650,7 +18927,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 175:
+    /// Semantic action for production 179:
     ///
     /// `LBraceToken: LBraceTerm : Token Comments;`
     ///
@@ -18676,7 +18953,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 176:
+    /// Semantic action for production 180:
     ///
     /// `LBracketToken: LBracketTerm : Token Comments;`
     ///
@@ -18702,7 +18979,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 177:
+    /// Semantic action for production 181:
     ///
     /// `LParenToken: LParenTerm : Token Comments;`
     ///
@@ -18728,7 +19005,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 178:
+    /// Semantic action for production 182:
     ///
     /// `LTMinusToken: LTMinusTerm : Token Comments;`
     ///
@@ -18754,7 +19031,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 179:
+    /// Semantic action for production 183:
     ///
     /// `MinusColonToken: MinusColonTerm : Token Comments;`
     ///
@@ -18781,7 +19058,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 180:
+    /// Semantic action for production 184:
     ///
     /// `MinusGTToken: MinusGTTerm : Token Comments;`
     ///
@@ -18807,7 +19084,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 181:
+    /// Semantic action for production 185:
     ///
     /// `PlusColonToken: PlusColonTerm : Token Comments;`
     ///
@@ -18834,7 +19111,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 182:
+    /// Semantic action for production 186:
     ///
     /// `RAngleToken: RAngleTerm : Token Comments;`
     ///
@@ -18860,7 +19137,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 183:
+    /// Semantic action for production 187:
     ///
     /// `EmbedRBraceToken: RBraceTerm : Token;`
     ///
@@ -18884,7 +19161,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 184:
+    /// Semantic action for production 188:
     ///
     /// `EscapedRBraceToken: EscapedRBraceTerm : Token;`
     ///
@@ -18909,7 +19186,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 185:
+    /// Semantic action for production 189:
     ///
     /// `TripleRBraceToken: TripleRBraceTerm : Token Comments;`
     ///
@@ -18939,7 +19216,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 186:
+    /// Semantic action for production 190:
     ///
     /// `RBraceToken: RBraceTerm : Token Comments;`
     ///
@@ -18965,7 +19242,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 187:
+    /// Semantic action for production 191:
     ///
     /// `RBracketToken: RBracketTerm : Token Comments;`
     ///
@@ -18991,7 +19268,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 188:
+    /// Semantic action for production 192:
     ///
     /// `RParenToken: RParenTerm : Token Comments;`
     ///
@@ -19017,7 +19294,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 189:
+    /// Semantic action for production 193:
     ///
     /// `SemicolonToken: SemicolonTerm : Token Comments;`
     ///
@@ -19043,7 +19320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 190:
+    /// Semantic action for production 194:
     ///
     /// `StarToken: StarTerm : Token Comments;`
     ///
@@ -19069,7 +19346,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 191:
+    /// Semantic action for production 195:
     ///
     /// `AliasToken: AliasTerm : Token Comments;`
     ///
@@ -19095,7 +19372,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 192:
+    /// Semantic action for production 196:
     ///
     /// `AlwaysCombToken: AlwaysCombTerm : Token Comments;`
     ///
@@ -19122,7 +19399,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 193:
+    /// Semantic action for production 197:
     ///
     /// `AlwaysFfToken: AlwaysFfTerm : Token Comments;`
     ///
@@ -19148,7 +19425,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 194:
+    /// Semantic action for production 198:
     ///
     /// `AsToken: AsTerm : Token Comments;`
     ///
@@ -19174,7 +19451,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 195:
+    /// Semantic action for production 199:
     ///
     /// `AssignToken: AssignTerm : Token Comments;`
     ///
@@ -19200,7 +19477,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 196:
+    /// Semantic action for production 200:
     ///
     /// `BindToken: BindTerm : Token Comments;`
     ///
@@ -19226,7 +19503,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 197:
+    /// Semantic action for production 201:
     ///
     /// `BitToken: BitTerm : Token Comments;`
     ///
@@ -19252,7 +19529,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 198:
+    /// Semantic action for production 202:
     ///
     /// `BlockToken: BlockTerm : Token Comments;`
     ///
@@ -19278,7 +19555,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 199:
+    /// Semantic action for production 203:
     ///
     /// `BBoolToken: BBoolTerm : Token Comments;`
     ///
@@ -19304,7 +19581,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 200:
+    /// Semantic action for production 204:
     ///
     /// `LBoolToken: LBoolTerm : Token Comments;`
     ///
@@ -19330,7 +19607,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 201:
+    /// Semantic action for production 205:
     ///
     /// `CaseToken: CaseTerm : Token Comments;`
     ///
@@ -19356,7 +19633,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 202:
+    /// Semantic action for production 206:
     ///
     /// `ClockToken: ClockTerm : Token Comments;`
     ///
@@ -19382,7 +19659,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 203:
+    /// Semantic action for production 207:
     ///
     /// `ClockPosedgeToken: ClockPosedgeTerm : Token Comments;`
     ///
@@ -19412,7 +19689,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 204:
+    /// Semantic action for production 208:
     ///
     /// `ClockNegedgeToken: ClockNegedgeTerm : Token Comments;`
     ///
@@ -19442,7 +19719,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 205:
+    /// Semantic action for production 209:
     ///
     /// `ConnectToken: ConnectTerm : Token Comments;`
     ///
@@ -19468,7 +19745,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 206:
+    /// Semantic action for production 210:
     ///
     /// `ConstToken: ConstTerm : Token Comments;`
     ///
@@ -19494,7 +19771,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 207:
+    /// Semantic action for production 211:
     ///
     /// `ConverseToken: ConverseTerm : Token Comments;`
     ///
@@ -19520,7 +19797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 208:
+    /// Semantic action for production 212:
     ///
     /// `DefaultToken: DefaultTerm : Token Comments;`
     ///
@@ -19546,7 +19823,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 209:
+    /// Semantic action for production 213:
     ///
     /// `ElseToken: ElseTerm : Token Comments;`
     ///
@@ -19572,7 +19849,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 210:
+    /// Semantic action for production 214:
     ///
     /// `EmbedToken: EmbedTerm : Token Comments;`
     ///
@@ -19598,7 +19875,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 211:
+    /// Semantic action for production 215:
     ///
     /// `EnumToken: EnumTerm : Token Comments;`
     ///
@@ -19624,7 +19901,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 212:
+    /// Semantic action for production 216:
     ///
     /// `F32Token: F32Term : Token Comments;`
     ///
@@ -19650,7 +19927,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 213:
+    /// Semantic action for production 217:
     ///
     /// `F64Token: F64Term : Token Comments;`
     ///
@@ -19676,7 +19953,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 214:
+    /// Semantic action for production 218:
     ///
     /// `FalseToken: FalseTerm : Token Comments;`
     ///
@@ -19702,7 +19979,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 215:
+    /// Semantic action for production 219:
     ///
     /// `FinalToken: FinalTerm : Token Comments;`
     ///
@@ -19728,7 +20005,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 216:
+    /// Semantic action for production 220:
     ///
     /// `ForToken: ForTerm : Token Comments;`
     ///
@@ -19754,7 +20031,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 217:
+    /// Semantic action for production 221:
     ///
     /// `FunctionToken: FunctionTerm : Token Comments;`
     ///
@@ -19780,7 +20057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 218:
+    /// Semantic action for production 222:
     ///
     /// `GenToken: GenTerm : Token Comments;`
     ///
@@ -19806,7 +20083,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 219:
+    /// Semantic action for production 223:
     ///
     /// `I8Token: I8Term : Token Comments;`
     ///
@@ -19832,7 +20109,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 220:
+    /// Semantic action for production 224:
     ///
     /// `I16Token: I16Term : Token Comments;`
     ///
@@ -19858,7 +20135,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 221:
+    /// Semantic action for production 225:
     ///
     /// `I32Token: I32Term : Token Comments;`
     ///
@@ -19884,7 +20161,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 222:
+    /// Semantic action for production 226:
     ///
     /// `I64Token: I64Term : Token Comments;`
     ///
@@ -19910,7 +20187,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 223:
+    /// Semantic action for production 227:
     ///
     /// `IfResetToken: IfResetTerm : Token Comments;`
     ///
@@ -19936,7 +20213,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 224:
+    /// Semantic action for production 228:
     ///
     /// `IfToken: IfTerm : Token Comments;`
     ///
@@ -19962,7 +20239,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 225:
+    /// Semantic action for production 229:
     ///
     /// `ImportToken: ImportTerm : Token Comments;`
     ///
@@ -19988,7 +20265,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 226:
+    /// Semantic action for production 230:
     ///
     /// `IncludeToken: IncludeTerm : Token Comments;`
     ///
@@ -20014,7 +20291,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 227:
+    /// Semantic action for production 231:
     ///
     /// `InitialToken: InitialTerm : Token Comments;`
     ///
@@ -20040,7 +20317,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 228:
+    /// Semantic action for production 232:
     ///
     /// `InoutToken: InoutTerm : Token Comments;`
     ///
@@ -20066,7 +20343,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 229:
+    /// Semantic action for production 233:
     ///
     /// `InputToken: InputTerm : Token Comments;`
     ///
@@ -20092,7 +20369,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 230:
+    /// Semantic action for production 234:
     ///
     /// `InsideToken: InsideTerm : Token Comments;`
     ///
@@ -20118,7 +20395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 231:
+    /// Semantic action for production 235:
     ///
     /// `InstToken: InstTerm : Token Comments;`
     ///
@@ -20144,7 +20421,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 232:
+    /// Semantic action for production 236:
     ///
     /// `InterfaceToken: InterfaceTerm : Token Comments;`
     ///
@@ -20170,7 +20447,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 233:
+    /// Semantic action for production 237:
     ///
     /// `InToken: InTerm : Token Comments;`
     ///
@@ -20196,7 +20473,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 234:
+    /// Semantic action for production 238:
     ///
     /// `LetToken: LetTerm : Token Comments;`
     ///
@@ -20222,7 +20499,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 235:
+    /// Semantic action for production 239:
     ///
     /// `LogicToken: LogicTerm : Token Comments;`
     ///
@@ -20248,7 +20525,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 236:
+    /// Semantic action for production 240:
     ///
     /// `LsbToken: LsbTerm : Token Comments;`
     ///
@@ -20274,7 +20551,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 237:
+    /// Semantic action for production 241:
     ///
     /// `MixinToken: MixinTerm : Token Comments;`
     ///
@@ -20300,7 +20577,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 238:
+    /// Semantic action for production 242:
     ///
     /// `ModportToken: ModportTerm : Token Comments;`
     ///
@@ -20326,7 +20603,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 239:
+    /// Semantic action for production 243:
     ///
     /// `ModuleToken: ModuleTerm : Token Comments;`
     ///
@@ -20352,7 +20629,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 240:
+    /// Semantic action for production 244:
     ///
     /// `MsbToken: MsbTerm : Token Comments;`
     ///
@@ -20378,7 +20655,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 241:
+    /// Semantic action for production 245:
     ///
     /// `OutputToken: OutputTerm : Token Comments;`
     ///
@@ -20404,7 +20681,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 242:
+    /// Semantic action for production 246:
     ///
     /// `OutsideToken: OutsideTerm : Token Comments;`
     ///
@@ -20430,7 +20707,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 243:
+    /// Semantic action for production 247:
     ///
     /// `PackageToken: PackageTerm : Token Comments;`
     ///
@@ -20456,7 +20733,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 244:
+    /// Semantic action for production 248:
     ///
     /// `ParamToken: ParamTerm : Token Comments;`
     ///
@@ -20482,7 +20759,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 245:
+    /// Semantic action for production 249:
     ///
     /// `ProtoToken: ProtoTerm : Token Comments;`
     ///
@@ -20508,7 +20785,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 246:
+    /// Semantic action for production 250:
     ///
     /// `PubToken: PubTerm : Token Comments;`
     ///
@@ -20534,7 +20811,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 247:
+    /// Semantic action for production 251:
     ///
     /// `RepeatToken: RepeatTerm : Token Comments;`
     ///
@@ -20560,7 +20837,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 248:
+    /// Semantic action for production 252:
     ///
     /// `ResetToken: ResetTerm : Token Comments;`
     ///
@@ -20586,7 +20863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 249:
+    /// Semantic action for production 253:
     ///
     /// `ResetAsyncHighToken: ResetAsyncHighTerm : Token Comments;`
     ///
@@ -20617,7 +20894,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 250:
+    /// Semantic action for production 254:
     ///
     /// `ResetAsyncLowToken: ResetAsyncLowTerm : Token Comments;`
     ///
@@ -20648,7 +20925,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 251:
+    /// Semantic action for production 255:
     ///
     /// `ResetSyncHighToken: ResetSyncHighTerm : Token Comments;`
     ///
@@ -20679,7 +20956,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 252:
+    /// Semantic action for production 256:
     ///
     /// `ResetSyncLowToken: ResetSyncLowTerm : Token Comments;`
     ///
@@ -20709,7 +20986,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 253:
+    /// Semantic action for production 257:
     ///
     /// `ReturnToken: ReturnTerm : Token Comments;`
     ///
@@ -20735,7 +21012,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 254:
+    /// Semantic action for production 258:
     ///
     /// `RevToken: RevTerm : Token Comments;`
     ///
@@ -20761,7 +21038,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 255:
+    /// Semantic action for production 259:
     ///
     /// `BreakToken: BreakTerm : Token Comments;`
     ///
@@ -20787,7 +21064,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 256:
+    /// Semantic action for production 260:
     ///
     /// `SameToken: SameTerm : Token Comments;`
     ///
@@ -20813,7 +21090,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 257:
+    /// Semantic action for production 261:
     ///
     /// `SignedToken: SignedTerm : Token Comments;`
     ///
@@ -20839,7 +21116,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 258:
+    /// Semantic action for production 262:
     ///
     /// `StepToken: StepTerm : Token Comments;`
     ///
@@ -20865,7 +21142,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 259:
+    /// Semantic action for production 263:
     ///
     /// `StringToken: StringTerm : Token Comments;`
     ///
@@ -20891,7 +21168,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 260:
+    /// Semantic action for production 264:
     ///
     /// `StructToken: StructTerm : Token Comments;`
     ///
@@ -20917,7 +21194,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 261:
+    /// Semantic action for production 265:
     ///
     /// `SwitchToken: SwitchTerm : Token Comments;`
     ///
@@ -20943,7 +21220,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 262:
+    /// Semantic action for production 266:
     ///
     /// `TriToken: TriTerm : Token Comments;`
     ///
@@ -20969,7 +21246,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 263:
+    /// Semantic action for production 267:
     ///
     /// `TrueToken: TrueTerm : Token Comments;`
     ///
@@ -20995,7 +21272,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 264:
+    /// Semantic action for production 268:
     ///
     /// `TypeToken: TypeTerm : Token Comments;`
     ///
@@ -21021,7 +21298,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 265:
+    /// Semantic action for production 269:
     ///
     /// `P8Token: P8Term : Token Comments;`
     ///
@@ -21047,7 +21324,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 266:
+    /// Semantic action for production 270:
     ///
     /// `P16Token: P16Term : Token Comments;`
     ///
@@ -21073,7 +21350,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 267:
+    /// Semantic action for production 271:
     ///
     /// `P32Token: P32Term : Token Comments;`
     ///
@@ -21099,7 +21376,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 268:
+    /// Semantic action for production 272:
     ///
     /// `P64Token: P64Term : Token Comments;`
     ///
@@ -21125,7 +21402,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 269:
+    /// Semantic action for production 273:
     ///
     /// `U8Token: U8Term : Token Comments;`
     ///
@@ -21151,7 +21428,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 270:
+    /// Semantic action for production 274:
     ///
     /// `U16Token: U16Term : Token Comments;`
     ///
@@ -21177,7 +21454,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 271:
+    /// Semantic action for production 275:
     ///
     /// `U32Token: U32Term : Token Comments;`
     ///
@@ -21203,7 +21480,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 272:
+    /// Semantic action for production 276:
     ///
     /// `U64Token: U64Term : Token Comments;`
     ///
@@ -21229,7 +21506,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 273:
+    /// Semantic action for production 277:
     ///
     /// `UnionToken: UnionTerm : Token Comments;`
     ///
@@ -21255,7 +21532,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 274:
+    /// Semantic action for production 278:
     ///
     /// `UnsafeToken: UnsafeTerm : Token Comments;`
     ///
@@ -21281,7 +21558,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 275:
+    /// Semantic action for production 279:
     ///
     /// `VarToken: VarTerm : Token Comments;`
     ///
@@ -21307,7 +21584,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 276:
+    /// Semantic action for production 280:
     ///
     /// `DollarIdentifierToken: DollarIdentifierTerm : Token Comments;`
     ///
@@ -21338,7 +21615,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 277:
+    /// Semantic action for production 281:
     ///
     /// `IdentifierToken: IdentifierTerm : Token Comments;`
     ///
@@ -21365,7 +21642,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 278:
+    /// Semantic action for production 282:
     ///
     /// `AnyToken: AnyTerm : Token;`
     ///
@@ -21385,7 +21662,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 279:
+    /// Semantic action for production 283:
     ///
     /// `Start: StartToken : VerylToken;`
     ///
@@ -21405,7 +21682,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 280:
+    /// Semantic action for production 284:
     ///
     /// `StringLiteral: StringLiteralToken : VerylToken;`
     ///
@@ -21426,7 +21703,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 281:
+    /// Semantic action for production 285:
     ///
     /// `Exponent: ExponentToken : VerylToken;`
     ///
@@ -21446,7 +21723,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 282:
+    /// Semantic action for production 286:
     ///
     /// `FixedPoint: FixedPointToken : VerylToken;`
     ///
@@ -21466,7 +21743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 283:
+    /// Semantic action for production 287:
     ///
     /// `Based: BasedToken : VerylToken;`
     ///
@@ -21486,7 +21763,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 284:
+    /// Semantic action for production 288:
     ///
     /// `BaseLess: BaseLessToken : VerylToken;`
     ///
@@ -21506,7 +21783,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 285:
+    /// Semantic action for production 289:
     ///
     /// `AllBit: AllBitToken : VerylToken;`
     ///
@@ -21526,7 +21803,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 286:
+    /// Semantic action for production 290:
     ///
     /// `AssignmentOperator: AssignmentOperatorToken : VerylToken;`
     ///
@@ -21558,7 +21835,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 287:
+    /// Semantic action for production 291:
     ///
     /// `DiamondOperator: DiamondOperatorToken : VerylToken;`
     ///
@@ -21580,7 +21857,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 288:
+    /// Semantic action for production 292:
+    ///
+    /// `Implication: ImplicationToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn implication(&mut self, _implication_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let implication_token = pop_item!(self, implication_token, ImplicationToken, context);
+        let implication_built = Implication {
+            implication_token: (&implication_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.implication(&implication_built)?;
+        self.push(ASTType::Implication(implication_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 293:
     ///
     /// `Operator01: Operator01Token : VerylToken;`
     ///
@@ -21600,7 +21897,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 289:
+    /// Semantic action for production 294:
     ///
     /// `Operator02: Operator02Token : VerylToken;`
     ///
@@ -21620,7 +21917,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 290:
+    /// Semantic action for production 295:
     ///
     /// `Operator03: Operator03Token : VerylToken;`
     ///
@@ -21640,7 +21937,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 291:
+    /// Semantic action for production 296:
     ///
     /// `Operator04: Operator04Token : VerylToken;`
     ///
@@ -21660,7 +21957,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 292:
+    /// Semantic action for production 297:
     ///
     /// `Operator05: Operator05Token : VerylToken;`
     ///
@@ -21680,7 +21977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 293:
+    /// Semantic action for production 298:
     ///
     /// `Operator06: Operator06Token : VerylToken;`
     ///
@@ -21700,7 +21997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 294:
+    /// Semantic action for production 299:
     ///
     /// `Operator07: Operator07Token : VerylToken;`
     ///
@@ -21720,7 +22017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 295:
+    /// Semantic action for production 300:
     ///
     /// `Operator08: Operator08Token : VerylToken;`
     ///
@@ -21740,7 +22037,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 296:
+    /// Semantic action for production 301:
     ///
     /// `UnaryOperator: UnaryOperatorToken : VerylToken;`
     ///
@@ -21761,7 +22058,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 297:
+    /// Semantic action for production 302:
     ///
     /// `Colon: ColonToken : VerylToken;`
     ///
@@ -21781,7 +22078,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 298:
+    /// Semantic action for production 303:
     ///
     /// `ColonColonLAngle: ColonColonLAngleToken : VerylToken;`
     ///
@@ -21813,7 +22110,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 299:
+    /// Semantic action for production 304:
     ///
     /// `ColonColon: ColonColonToken : VerylToken;`
     ///
@@ -21833,7 +22130,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 300:
+    /// Semantic action for production 305:
     ///
     /// `Comma: CommaToken : VerylToken;`
     ///
@@ -21853,7 +22150,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 301:
+    /// Semantic action for production 306:
     ///
     /// `DotDot: DotDotToken : VerylToken;`
     ///
@@ -21873,7 +22170,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 302:
+    /// Semantic action for production 307:
     ///
     /// `DotDotEqu: DotDotEquToken : VerylToken;`
     ///
@@ -21893,7 +22190,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 303:
+    /// Semantic action for production 308:
     ///
     /// `Dot: DotToken : VerylToken;`
     ///
@@ -21913,7 +22210,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 304:
+    /// Semantic action for production 309:
     ///
     /// `Equ: EquToken : VerylToken;`
     ///
@@ -21933,7 +22230,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 305:
+    /// Semantic action for production 310:
+    ///
+    /// `HashHash: HashHashToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn hash_hash(&mut self, _hash_hash_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let hash_hash_token = pop_item!(self, hash_hash_token, HashHashToken, context);
+        let hash_hash_built = HashHash {
+            hash_hash_token: (&hash_hash_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.hash_hash(&hash_hash_built)?;
+        self.push(ASTType::HashHash(hash_hash_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 311:
     ///
     /// `HashLBracket: HashLBracketToken : VerylToken;`
     ///
@@ -21954,7 +22271,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 306:
+    /// Semantic action for production 312:
     ///
     /// `Hash: HashToken : VerylToken;`
     ///
@@ -21974,7 +22291,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 307:
+    /// Semantic action for production 313:
     ///
     /// `Question: QuestionToken : VerylToken;`
     ///
@@ -21994,7 +22311,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 308:
+    /// Semantic action for production 314:
     ///
     /// `QuoteLBrace: QuoteLBraceToken : VerylToken;`
     ///
@@ -22014,7 +22331,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 309:
+    /// Semantic action for production 315:
     ///
     /// `Quote: QuoteToken : VerylToken;`
     ///
@@ -22034,7 +22351,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 310:
+    /// Semantic action for production 316:
     ///
     /// `LAngle: LAngleToken : VerylToken;`
     ///
@@ -22054,7 +22371,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 311:
+    /// Semantic action for production 317:
     ///
     /// `EmbedLBrace: EmbedLBraceToken : VerylToken;`
     ///
@@ -22074,7 +22391,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 312:
+    /// Semantic action for production 318:
     ///
     /// `EscapedLBrace: EscapedLBraceToken : VerylToken;`
     ///
@@ -22095,7 +22412,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 313:
+    /// Semantic action for production 319:
     ///
     /// `TripleLBrace: TripleLBraceToken : VerylToken;`
     ///
@@ -22116,7 +22433,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 314:
+    /// Semantic action for production 320:
     ///
     /// `LBrace: LBraceToken : VerylToken;`
     ///
@@ -22136,7 +22453,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 315:
+    /// Semantic action for production 321:
     ///
     /// `LBracket: LBracketToken : VerylToken;`
     ///
@@ -22156,7 +22473,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 316:
+    /// Semantic action for production 322:
     ///
     /// `LParen: LParenToken : VerylToken;`
     ///
@@ -22176,7 +22493,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 317:
+    /// Semantic action for production 323:
     ///
     /// `LTMinus: LTMinusToken : VerylToken;`
     ///
@@ -22196,7 +22513,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 318:
+    /// Semantic action for production 324:
     ///
     /// `MinusColon: MinusColonToken : VerylToken;`
     ///
@@ -22216,7 +22533,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 319:
+    /// Semantic action for production 325:
     ///
     /// `MinusGT: MinusGTToken : VerylToken;`
     ///
@@ -22236,7 +22553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 320:
+    /// Semantic action for production 326:
     ///
     /// `PlusColon: PlusColonToken : VerylToken;`
     ///
@@ -22256,7 +22573,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 321:
+    /// Semantic action for production 327:
     ///
     /// `RAngle: RAngleToken : VerylToken;`
     ///
@@ -22276,7 +22593,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 322:
+    /// Semantic action for production 328:
     ///
     /// `EmbedRBrace: EmbedRBraceToken : VerylToken;`
     ///
@@ -22296,7 +22613,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 323:
+    /// Semantic action for production 329:
     ///
     /// `EscapedRBrace: EscapedRBraceToken : VerylToken;`
     ///
@@ -22317,7 +22634,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 324:
+    /// Semantic action for production 330:
     ///
     /// `TripleRBrace: TripleRBraceToken : VerylToken;`
     ///
@@ -22338,7 +22655,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 325:
+    /// Semantic action for production 331:
     ///
     /// `RBrace: RBraceToken : VerylToken;`
     ///
@@ -22358,7 +22675,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 326:
+    /// Semantic action for production 332:
     ///
     /// `RBracket: RBracketToken : VerylToken;`
     ///
@@ -22378,7 +22695,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 327:
+    /// Semantic action for production 333:
     ///
     /// `RParen: RParenToken : VerylToken;`
     ///
@@ -22398,7 +22715,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 328:
+    /// Semantic action for production 334:
     ///
     /// `Semicolon: SemicolonToken : VerylToken;`
     ///
@@ -22418,7 +22735,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 329:
+    /// Semantic action for production 335:
     ///
     /// `Star: StarToken : VerylToken;`
     ///
@@ -22438,7 +22755,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 330:
+    /// Semantic action for production 336:
     ///
     /// `Alias: AliasToken : VerylToken;`
     ///
@@ -22458,7 +22775,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 331:
+    /// Semantic action for production 337:
     ///
     /// `AlwaysComb: AlwaysCombToken : VerylToken;`
     ///
@@ -22478,7 +22795,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 332:
+    /// Semantic action for production 338:
     ///
     /// `AlwaysFf: AlwaysFfToken : VerylToken;`
     ///
@@ -22498,7 +22815,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 333:
+    /// Semantic action for production 339:
     ///
     /// `As: AsToken : VerylToken;`
     ///
@@ -22518,7 +22835,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 334:
+    /// Semantic action for production 340:
     ///
     /// `Assign: AssignToken : VerylToken;`
     ///
@@ -22538,7 +22855,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 335:
+    /// Semantic action for production 341:
     ///
     /// `Bind: BindToken : VerylToken;`
     ///
@@ -22558,7 +22875,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 336:
+    /// Semantic action for production 342:
     ///
     /// `Bit: BitToken : VerylToken;`
     ///
@@ -22578,7 +22895,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 337:
+    /// Semantic action for production 343:
     ///
     /// `Block: BlockToken : VerylToken;`
     ///
@@ -22598,7 +22915,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 338:
+    /// Semantic action for production 344:
     ///
     /// `BBool: BBoolToken : VerylToken;`
     ///
@@ -22618,7 +22935,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 339:
+    /// Semantic action for production 345:
     ///
     /// `LBool: LBoolToken : VerylToken;`
     ///
@@ -22638,7 +22955,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 340:
+    /// Semantic action for production 346:
     ///
     /// `Break: BreakToken : VerylToken;`
     ///
@@ -22658,7 +22975,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 341:
+    /// Semantic action for production 347:
     ///
     /// `Case: CaseToken : VerylToken;`
     ///
@@ -22678,7 +22995,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 342:
+    /// Semantic action for production 348:
     ///
     /// `Clock: ClockToken : VerylToken;`
     ///
@@ -22698,7 +23015,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 343:
+    /// Semantic action for production 349:
     ///
     /// `ClockPosedge: ClockPosedgeToken : VerylToken;`
     ///
@@ -22718,7 +23035,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 344:
+    /// Semantic action for production 350:
     ///
     /// `ClockNegedge: ClockNegedgeToken : VerylToken;`
     ///
@@ -22738,7 +23055,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 345:
+    /// Semantic action for production 351:
     ///
     /// `Connect: ConnectToken : VerylToken;`
     ///
@@ -22758,7 +23075,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 346:
+    /// Semantic action for production 352:
     ///
     /// `Const: ConstToken : VerylToken;`
     ///
@@ -22778,7 +23095,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 347:
+    /// Semantic action for production 353:
     ///
     /// `Converse: ConverseToken : VerylToken;`
     ///
@@ -22798,7 +23115,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 348:
+    /// Semantic action for production 354:
     ///
     /// `Defaul: DefaultToken : VerylToken;`
     ///
@@ -22818,7 +23135,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 349:
+    /// Semantic action for production 355:
     ///
     /// `Else: ElseToken : VerylToken;`
     ///
@@ -22838,7 +23155,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 350:
+    /// Semantic action for production 356:
     ///
     /// `Embed: EmbedToken : VerylToken;`
     ///
@@ -22858,7 +23175,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 351:
+    /// Semantic action for production 357:
     ///
     /// `Enum: EnumToken : VerylToken;`
     ///
@@ -22878,7 +23195,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 352:
+    /// Semantic action for production 358:
     ///
     /// `F32: F32Token : VerylToken;`
     ///
@@ -22898,7 +23215,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 353:
+    /// Semantic action for production 359:
     ///
     /// `F64: F64Token : VerylToken;`
     ///
@@ -22918,7 +23235,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 354:
+    /// Semantic action for production 360:
     ///
     /// `False: FalseToken : VerylToken;`
     ///
@@ -22938,7 +23255,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 355:
+    /// Semantic action for production 361:
     ///
     /// `Final: FinalToken : VerylToken;`
     ///
@@ -22958,7 +23275,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 356:
+    /// Semantic action for production 362:
     ///
     /// `For: ForToken : VerylToken;`
     ///
@@ -22978,7 +23295,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 357:
+    /// Semantic action for production 363:
     ///
     /// `Function: FunctionToken : VerylToken;`
     ///
@@ -22998,7 +23315,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 358:
+    /// Semantic action for production 364:
     ///
     /// `Gen: GenToken : VerylToken;`
     ///
@@ -23018,7 +23335,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 359:
+    /// Semantic action for production 365:
     ///
     /// `I8: I8Token : VerylToken;`
     ///
@@ -23038,7 +23355,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 360:
+    /// Semantic action for production 366:
     ///
     /// `I16: I16Token : VerylToken;`
     ///
@@ -23058,7 +23375,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 361:
+    /// Semantic action for production 367:
     ///
     /// `I32: I32Token : VerylToken;`
     ///
@@ -23078,7 +23395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 362:
+    /// Semantic action for production 368:
     ///
     /// `I64: I64Token : VerylToken;`
     ///
@@ -23098,7 +23415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 363:
+    /// Semantic action for production 369:
     ///
     /// `If: IfToken : VerylToken;`
     ///
@@ -23118,7 +23435,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 364:
+    /// Semantic action for production 370:
     ///
     /// `IfReset: IfResetToken : VerylToken;`
     ///
@@ -23138,7 +23455,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 365:
+    /// Semantic action for production 371:
     ///
     /// `Import: ImportToken : VerylToken;`
     ///
@@ -23158,7 +23475,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 366:
+    /// Semantic action for production 372:
     ///
     /// `In: InToken : VerylToken;`
     ///
@@ -23178,7 +23495,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 367:
+    /// Semantic action for production 373:
     ///
     /// `Include: IncludeToken : VerylToken;`
     ///
@@ -23198,7 +23515,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 368:
+    /// Semantic action for production 374:
     ///
     /// `Initial: InitialToken : VerylToken;`
     ///
@@ -23218,7 +23535,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 369:
+    /// Semantic action for production 375:
     ///
     /// `Inout: InoutToken : VerylToken;`
     ///
@@ -23238,7 +23555,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 370:
+    /// Semantic action for production 376:
     ///
     /// `Input: InputToken : VerylToken;`
     ///
@@ -23258,7 +23575,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 371:
+    /// Semantic action for production 377:
     ///
     /// `Inside: InsideToken : VerylToken;`
     ///
@@ -23278,7 +23595,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 372:
+    /// Semantic action for production 378:
     ///
     /// `Inst: InstToken : VerylToken;`
     ///
@@ -23298,7 +23615,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 373:
+    /// Semantic action for production 379:
     ///
     /// `Interface: InterfaceToken : VerylToken;`
     ///
@@ -23318,7 +23635,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 374:
+    /// Semantic action for production 380:
     ///
     /// `Let: LetToken : VerylToken;`
     ///
@@ -23338,7 +23655,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 375:
+    /// Semantic action for production 381:
     ///
     /// `Logic: LogicToken : VerylToken;`
     ///
@@ -23358,7 +23675,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 376:
+    /// Semantic action for production 382:
     ///
     /// `Lsb: LsbToken : VerylToken;`
     ///
@@ -23378,7 +23695,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 377:
+    /// Semantic action for production 383:
     ///
     /// `Mixin: MixinToken : VerylToken;`
     ///
@@ -23398,7 +23715,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 378:
+    /// Semantic action for production 384:
     ///
     /// `Modport: ModportToken : VerylToken;`
     ///
@@ -23418,7 +23735,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 379:
+    /// Semantic action for production 385:
     ///
     /// `Module: ModuleToken : VerylToken;`
     ///
@@ -23438,7 +23755,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 380:
+    /// Semantic action for production 386:
     ///
     /// `Msb: MsbToken : VerylToken;`
     ///
@@ -23458,7 +23775,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 381:
+    /// Semantic action for production 387:
     ///
     /// `Output: OutputToken : VerylToken;`
     ///
@@ -23478,7 +23795,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 382:
+    /// Semantic action for production 388:
     ///
     /// `Outside: OutsideToken : VerylToken;`
     ///
@@ -23498,7 +23815,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 383:
+    /// Semantic action for production 389:
     ///
     /// `Package: PackageToken : VerylToken;`
     ///
@@ -23518,7 +23835,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 384:
+    /// Semantic action for production 390:
     ///
     /// `Param: ParamToken : VerylToken;`
     ///
@@ -23538,7 +23855,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 385:
+    /// Semantic action for production 391:
     ///
     /// `Proto: ProtoToken : VerylToken;`
     ///
@@ -23558,7 +23875,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 386:
+    /// Semantic action for production 392:
     ///
     /// `Pub: PubToken : VerylToken;`
     ///
@@ -23578,7 +23895,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 387:
+    /// Semantic action for production 393:
     ///
     /// `Repeat: RepeatToken : VerylToken;`
     ///
@@ -23598,7 +23915,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 388:
+    /// Semantic action for production 394:
     ///
     /// `Reset: ResetToken : VerylToken;`
     ///
@@ -23618,7 +23935,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 389:
+    /// Semantic action for production 395:
     ///
     /// `ResetAsyncHigh: ResetAsyncHighToken : VerylToken;`
     ///
@@ -23640,7 +23957,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 390:
+    /// Semantic action for production 396:
     ///
     /// `ResetAsyncLow: ResetAsyncLowToken : VerylToken;`
     ///
@@ -23661,7 +23978,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 391:
+    /// Semantic action for production 397:
     ///
     /// `ResetSyncHigh: ResetSyncHighToken : VerylToken;`
     ///
@@ -23682,7 +23999,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 392:
+    /// Semantic action for production 398:
     ///
     /// `ResetSyncLow: ResetSyncLowToken : VerylToken;`
     ///
@@ -23703,7 +24020,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 393:
+    /// Semantic action for production 399:
     ///
     /// `Return: ReturnToken : VerylToken;`
     ///
@@ -23723,7 +24040,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 394:
+    /// Semantic action for production 400:
     ///
     /// `Rev: RevToken : VerylToken;`
     ///
@@ -23743,7 +24060,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 395:
+    /// Semantic action for production 401:
     ///
     /// `Same: SameToken : VerylToken;`
     ///
@@ -23763,7 +24080,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 396:
+    /// Semantic action for production 402:
     ///
     /// `Signed: SignedToken : VerylToken;`
     ///
@@ -23783,7 +24100,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 397:
+    /// Semantic action for production 403:
     ///
     /// `Step: StepToken : VerylToken;`
     ///
@@ -23803,7 +24120,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 398:
+    /// Semantic action for production 404:
     ///
     /// `Strin: StringToken : VerylToken;`
     ///
@@ -23823,7 +24140,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 399:
+    /// Semantic action for production 405:
     ///
     /// `Struct: StructToken : VerylToken;`
     ///
@@ -23843,7 +24160,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 400:
+    /// Semantic action for production 406:
     ///
     /// `Switch: SwitchToken : VerylToken;`
     ///
@@ -23863,7 +24180,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 401:
+    /// Semantic action for production 407:
     ///
     /// `Tri: TriToken : VerylToken;`
     ///
@@ -23883,7 +24200,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 402:
+    /// Semantic action for production 408:
     ///
     /// `True: TrueToken : VerylToken;`
     ///
@@ -23903,7 +24220,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 403:
+    /// Semantic action for production 409:
     ///
     /// `Type: TypeToken : VerylToken;`
     ///
@@ -23923,7 +24240,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 404:
+    /// Semantic action for production 410:
     ///
     /// `P8: P8Token : VerylToken;`
     ///
@@ -23943,7 +24260,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 405:
+    /// Semantic action for production 411:
     ///
     /// `P16: P16Token : VerylToken;`
     ///
@@ -23963,7 +24280,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 406:
+    /// Semantic action for production 412:
     ///
     /// `P32: P32Token : VerylToken;`
     ///
@@ -23983,7 +24300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 407:
+    /// Semantic action for production 413:
     ///
     /// `P64: P64Token : VerylToken;`
     ///
@@ -24003,7 +24320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 408:
+    /// Semantic action for production 414:
     ///
     /// `U8: U8Token : VerylToken;`
     ///
@@ -24023,7 +24340,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 409:
+    /// Semantic action for production 415:
     ///
     /// `U16: U16Token : VerylToken;`
     ///
@@ -24043,7 +24360,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 410:
+    /// Semantic action for production 416:
     ///
     /// `U32: U32Token : VerylToken;`
     ///
@@ -24063,7 +24380,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 411:
+    /// Semantic action for production 417:
     ///
     /// `U64: U64Token : VerylToken;`
     ///
@@ -24083,7 +24400,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 412:
+    /// Semantic action for production 418:
     ///
     /// `Union: UnionToken : VerylToken;`
     ///
@@ -24103,7 +24420,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 413:
+    /// Semantic action for production 419:
     ///
     /// `Unsafe: UnsafeToken : VerylToken;`
     ///
@@ -24123,7 +24440,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 414:
+    /// Semantic action for production 420:
     ///
     /// `Var: VarToken : VerylToken;`
     ///
@@ -24143,7 +24460,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 415:
+    /// Semantic action for production 421:
     ///
     /// `DollarIdentifier: DollarIdentifierToken : VerylToken;`
     ///
@@ -24169,7 +24486,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 416:
+    /// Semantic action for production 422:
     ///
     /// `Identifier: IdentifierToken : VerylToken;`
     ///
@@ -24189,7 +24506,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 417:
+    /// Semantic action for production 423:
     ///
     /// `Any: AnyToken : VerylToken;`
     ///
@@ -24209,7 +24526,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 418:
+    /// Semantic action for production 424:
     ///
     /// `Number: IntegralNumber;`
     ///
@@ -24228,7 +24545,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 419:
+    /// Semantic action for production 425:
     ///
     /// `Number: RealNumber;`
     ///
@@ -24247,7 +24564,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 420:
+    /// Semantic action for production 426:
     ///
     /// `IntegralNumber: Based;`
     ///
@@ -24267,7 +24584,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 421:
+    /// Semantic action for production 427:
     ///
     /// `IntegralNumber: BaseLess;`
     ///
@@ -24287,7 +24604,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 422:
+    /// Semantic action for production 428:
     ///
     /// `IntegralNumber: AllBit;`
     ///
@@ -24307,7 +24624,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 423:
+    /// Semantic action for production 429:
     ///
     /// `RealNumber: FixedPoint;`
     ///
@@ -24326,7 +24643,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 424:
+    /// Semantic action for production 430:
     ///
     /// `RealNumber: Exponent;`
     ///
@@ -24345,7 +24662,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 425:
+    /// Semantic action for production 431:
     ///
     /// `HierarchicalIdentifier: Identifier HierarchicalIdentifierList /* Vec */ HierarchicalIdentifierList0 /* Vec */;`
     ///
@@ -24386,7 +24703,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 426:
+    /// Semantic action for production 432:
     ///
     /// `HierarchicalIdentifierList0 /* Vec<T>::Push */: Dot Identifier HierarchicalIdentifierList0List /* Vec */ HierarchicalIdentifierList0;`
     ///
@@ -24428,7 +24745,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 427:
+    /// Semantic action for production 433:
     ///
     /// `HierarchicalIdentifierList0List /* Vec<T>::Push */: Select HierarchicalIdentifierList0List;`
     ///
@@ -24459,7 +24776,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 428:
+    /// Semantic action for production 434:
     ///
     /// `HierarchicalIdentifierList0List /* Vec<T>::New */: ;`
     ///
@@ -24475,7 +24792,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 429:
+    /// Semantic action for production 435:
     ///
     /// `HierarchicalIdentifierList0 /* Vec<T>::New */: ;`
     ///
@@ -24491,7 +24808,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 430:
+    /// Semantic action for production 436:
     ///
     /// `HierarchicalIdentifierList /* Vec<T>::Push */: Select HierarchicalIdentifierList;`
     ///
@@ -24522,7 +24839,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 431:
+    /// Semantic action for production 437:
     ///
     /// `HierarchicalIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -24538,7 +24855,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 432:
+    /// Semantic action for production 438:
     ///
     /// `ScopedIdentifier: ScopedIdentifierGroup ScopedIdentifierList /* Vec */;`
     ///
@@ -24569,7 +24886,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 433:
+    /// Semantic action for production 439:
     ///
     /// `ScopedIdentifierGroup: DollarIdentifier;`
     ///
@@ -24590,7 +24907,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 434:
+    /// Semantic action for production 440:
     ///
     /// `ScopedIdentifierGroup: Identifier ScopedIdentifierOpt /* Option */;`
     ///
@@ -24618,7 +24935,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 435:
+    /// Semantic action for production 441:
     ///
     /// `ScopedIdentifierList /* Vec<T>::Push */: ColonColon Identifier ScopedIdentifierOpt0 /* Option */ ScopedIdentifierList;`
     ///
@@ -24652,7 +24969,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 436:
+    /// Semantic action for production 442:
     ///
     /// `ScopedIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -24668,7 +24985,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 437:
+    /// Semantic action for production 443:
     ///
     /// `ScopedIdentifierOpt0 /* Option<T>::Some */: WithGenericArgument;`
     ///
@@ -24691,7 +25008,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 438:
+    /// Semantic action for production 444:
     ///
     /// `ScopedIdentifierOpt0 /* Option<T>::None */: ;`
     ///
@@ -24703,7 +25020,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 439:
+    /// Semantic action for production 445:
     ///
     /// `ScopedIdentifierOpt /* Option<T>::Some */: WithGenericArgument;`
     ///
@@ -24726,7 +25043,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 440:
+    /// Semantic action for production 446:
     ///
     /// `ScopedIdentifierOpt /* Option<T>::None */: ;`
     ///
@@ -24738,7 +25055,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 441:
+    /// Semantic action for production 447:
     ///
     /// `ExpressionIdentifier: ScopedIdentifier ExpressionIdentifierOpt /* Option */ ExpressionIdentifierList /* Vec */ ExpressionIdentifierList0 /* Vec */;`
     ///
@@ -24787,7 +25104,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 442:
+    /// Semantic action for production 448:
     ///
     /// `ExpressionIdentifierList0 /* Vec<T>::Push */: Dot Identifier ExpressionIdentifierList0List /* Vec */ ExpressionIdentifierList0;`
     ///
@@ -24829,7 +25146,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 443:
+    /// Semantic action for production 449:
     ///
     /// `ExpressionIdentifierList0List /* Vec<T>::Push */: Select ExpressionIdentifierList0List;`
     ///
@@ -24860,7 +25177,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 444:
+    /// Semantic action for production 450:
     ///
     /// `ExpressionIdentifierList0List /* Vec<T>::New */: ;`
     ///
@@ -24876,7 +25193,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 445:
+    /// Semantic action for production 451:
     ///
     /// `ExpressionIdentifierList0 /* Vec<T>::New */: ;`
     ///
@@ -24892,7 +25209,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 446:
+    /// Semantic action for production 452:
     ///
     /// `ExpressionIdentifierList /* Vec<T>::Push */: Select ExpressionIdentifierList;`
     ///
@@ -24923,7 +25240,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 447:
+    /// Semantic action for production 453:
     ///
     /// `ExpressionIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -24939,7 +25256,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 448:
+    /// Semantic action for production 454:
     ///
     /// `ExpressionIdentifierOpt /* Option<T>::Some */: Width;`
     ///
@@ -24958,7 +25275,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 449:
+    /// Semantic action for production 455:
     ///
     /// `ExpressionIdentifierOpt /* Option<T>::None */: ;`
     ///
@@ -24970,7 +25287,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 450:
+    /// Semantic action for production 456:
     ///
     /// `GenericArgIdentifier: ScopedIdentifier GenericArgIdentifierList /* Vec */;`
     ///
@@ -25003,7 +25320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 451:
+    /// Semantic action for production 457:
     ///
     /// `GenericArgIdentifierList /* Vec<T>::Push */: Dot Identifier GenericArgIdentifierList;`
     ///
@@ -25037,7 +25354,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 452:
+    /// Semantic action for production 458:
     ///
     /// `GenericArgIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -25053,7 +25370,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 453:
+    /// Semantic action for production 459:
     ///
     /// `Expression: IfExpression;`
     ///
@@ -25071,7 +25388,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 454:
+    /// Semantic action for production 460:
     ///
     /// `IfExpression: IfExpressionList /* Vec */ Expression01;`
     ///
@@ -25096,7 +25413,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 455:
+    /// Semantic action for production 461:
     ///
     /// `IfExpressionList /* Vec<T>::Push */: If Expression Question Expression Colon IfExpressionList;`
     ///
@@ -25131,7 +25448,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 456:
+    /// Semantic action for production 462:
     ///
     /// `IfExpressionList /* Vec<T>::New */: ;`
     ///
@@ -25147,7 +25464,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 457:
+    /// Semantic action for production 463:
     ///
     /// `Expression01: Expression02 Expression01List /* Vec */;`
     ///
@@ -25172,7 +25489,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 458:
+    /// Semantic action for production 464:
     ///
     /// `Expression01List /* Vec<T>::Push */: Expression01Op Expression02 Expression01List;`
     ///
@@ -25198,7 +25515,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 459:
+    /// Semantic action for production 465:
     ///
     /// `Expression01List /* Vec<T>::New */: ;`
     ///
@@ -25214,7 +25531,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 460:
+    /// Semantic action for production 466:
     ///
     /// `Expression02: Expression02List /* Vec */ Factor Expression02Opt /* Option */;`
     ///
@@ -25242,7 +25559,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 461:
+    /// Semantic action for production 467:
     ///
     /// `Expression02List /* Vec<T>::Push */: Expression02Op Expression02List;`
     ///
@@ -25265,7 +25582,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 462:
+    /// Semantic action for production 468:
     ///
     /// `Expression02List /* Vec<T>::New */: ;`
     ///
@@ -25281,7 +25598,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 463:
+    /// Semantic action for production 469:
     ///
     /// `Expression02Opt /* Option<T>::Some */: As CastingType;`
     ///
@@ -25306,7 +25623,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 464:
+    /// Semantic action for production 470:
     ///
     /// `Expression02Opt /* Option<T>::None */: ;`
     ///
@@ -25318,7 +25635,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 465:
+    /// Semantic action for production 471:
     ///
     /// `Expression01Op: Operator01;`
     ///
@@ -25338,7 +25655,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 466:
+    /// Semantic action for production 472:
     ///
     /// `Expression01Op: Operator02;`
     ///
@@ -25358,7 +25675,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 467:
+    /// Semantic action for production 473:
     ///
     /// `Expression01Op: Operator03;`
     ///
@@ -25378,7 +25695,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 468:
+    /// Semantic action for production 474:
     ///
     /// `Expression01Op: Operator04;`
     ///
@@ -25398,7 +25715,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 469:
+    /// Semantic action for production 475:
     ///
     /// `Expression01Op: Operator05;`
     ///
@@ -25418,7 +25735,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 470:
+    /// Semantic action for production 476:
     ///
     /// `Expression01Op: Operator06;`
     ///
@@ -25438,7 +25755,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 471:
+    /// Semantic action for production 477:
     ///
     /// `Expression01Op: Operator07;`
     ///
@@ -25458,7 +25775,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 472:
+    /// Semantic action for production 478:
     ///
     /// `Expression01Op: Star;`
     ///
@@ -25478,7 +25795,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 473:
+    /// Semantic action for production 479:
     ///
     /// `Expression01Op: Operator08;`
     ///
@@ -25498,7 +25815,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 474:
+    /// Semantic action for production 480:
     ///
     /// `Expression02Op: UnaryOperator;`
     ///
@@ -25518,7 +25835,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 475:
+    /// Semantic action for production 481:
     ///
     /// `Expression02Op: Operator06;`
     ///
@@ -25538,7 +25855,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 476:
+    /// Semantic action for production 482:
     ///
     /// `Expression02Op: Operator05;`
     ///
@@ -25558,7 +25875,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 477:
+    /// Semantic action for production 483:
     ///
     /// `Expression02Op: Operator03;`
     ///
@@ -25578,7 +25895,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 478:
+    /// Semantic action for production 484:
     ///
     /// `Expression02Op: Operator04;`
     ///
@@ -25598,7 +25915,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 479:
+    /// Semantic action for production 485:
     ///
     /// `Factor: Number;`
     ///
@@ -25617,7 +25934,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 480:
+    /// Semantic action for production 486:
     ///
     /// `Factor: BooleanLiteral;`
     ///
@@ -25636,7 +25953,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 481:
+    /// Semantic action for production 487:
     ///
     /// `Factor: IdentifierFactor;`
     ///
@@ -25655,7 +25972,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 482:
+    /// Semantic action for production 488:
     ///
     /// `Factor: LParen Expression RParen;`
     ///
@@ -25683,7 +26000,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 483:
+    /// Semantic action for production 489:
     ///
     /// `Factor: LBrace ConcatenationList RBrace;`
     ///
@@ -25711,7 +26028,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 484:
+    /// Semantic action for production 490:
     ///
     /// `Factor: QuoteLBrace ArrayLiteralList RBrace;`
     ///
@@ -25739,7 +26056,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 485:
+    /// Semantic action for production 491:
     ///
     /// `Factor: CaseExpression;`
     ///
@@ -25758,7 +26075,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 486:
+    /// Semantic action for production 492:
     ///
     /// `Factor: SwitchExpression;`
     ///
@@ -25777,7 +26094,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 487:
+    /// Semantic action for production 493:
     ///
     /// `Factor: StringLiteral;`
     ///
@@ -25796,7 +26113,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 488:
+    /// Semantic action for production 494:
     ///
     /// `Factor: FactorGroup;`
     ///
@@ -25815,7 +26132,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 489:
+    /// Semantic action for production 495:
     ///
     /// `FactorGroup: Msb;`
     ///
@@ -25830,7 +26147,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 490:
+    /// Semantic action for production 496:
     ///
     /// `FactorGroup: Lsb;`
     ///
@@ -25845,7 +26162,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 491:
+    /// Semantic action for production 497:
     ///
     /// `Factor: InsideExpression;`
     ///
@@ -25864,7 +26181,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 492:
+    /// Semantic action for production 498:
     ///
     /// `Factor: OutsideExpression;`
     ///
@@ -25883,7 +26200,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 493:
+    /// Semantic action for production 499:
     ///
     /// `Factor: TypeExpression;`
     ///
@@ -25902,7 +26219,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 494:
+    /// Semantic action for production 500:
     ///
     /// `Factor: FactorTypeFactor;`
     ///
@@ -25921,7 +26238,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 495:
+    /// Semantic action for production 501:
     ///
     /// `BooleanLiteral: True;`
     ///
@@ -25941,7 +26258,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 496:
+    /// Semantic action for production 502:
     ///
     /// `BooleanLiteral: False;`
     ///
@@ -25961,7 +26278,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 497:
+    /// Semantic action for production 503:
     ///
     /// `IdentifierFactor: ExpressionIdentifier IdentifierFactorOpt /* Option */;`
     ///
@@ -25988,7 +26305,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 498:
+    /// Semantic action for production 504:
     ///
     /// `IdentifierFactorOpt /* Option<T>::Some */: IdentifierFactorOptGroup;`
     ///
@@ -26015,7 +26332,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 499:
+    /// Semantic action for production 505:
     ///
     /// `IdentifierFactorOptGroup: FunctionCall;`
     ///
@@ -26036,7 +26353,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 500:
+    /// Semantic action for production 506:
     ///
     /// `IdentifierFactorOptGroup: StructConstructor;`
     ///
@@ -26060,7 +26377,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 501:
+    /// Semantic action for production 507:
     ///
     /// `IdentifierFactorOpt /* Option<T>::None */: ;`
     ///
@@ -26072,7 +26389,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 502:
+    /// Semantic action for production 508:
     ///
     /// `FactorTypeFactor: FactorTypeFactorList /* Vec */ FactorType;`
     ///
@@ -26098,7 +26415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 503:
+    /// Semantic action for production 509:
     ///
     /// `FactorTypeFactorList /* Vec<T>::Push */: TypeModifier FactorTypeFactorList;`
     ///
@@ -26125,7 +26442,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 504:
+    /// Semantic action for production 510:
     ///
     /// `FactorTypeFactorList /* Vec<T>::New */: ;`
     ///
@@ -26141,7 +26458,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 505:
+    /// Semantic action for production 511:
     ///
     /// `FunctionCall: LParen FunctionCallOpt /* Option */ RParen;`
     ///
@@ -26168,7 +26485,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 506:
+    /// Semantic action for production 512:
     ///
     /// `FunctionCallOpt /* Option<T>::Some */: ArgumentList;`
     ///
@@ -26187,7 +26504,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 507:
+    /// Semantic action for production 513:
     ///
     /// `FunctionCallOpt /* Option<T>::None */: ;`
     ///
@@ -26199,7 +26516,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 508:
+    /// Semantic action for production 514:
     ///
     /// `ArgumentList: ArgumentItem ArgumentListList /* Vec */ ArgumentListOpt /* Option */;`
     ///
@@ -26227,7 +26544,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 509:
+    /// Semantic action for production 515:
     ///
     /// `ArgumentListList /* Vec<T>::Push */: Comma ArgumentItem ArgumentListList;`
     ///
@@ -26253,7 +26570,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 510:
+    /// Semantic action for production 516:
     ///
     /// `ArgumentListList /* Vec<T>::New */: ;`
     ///
@@ -26269,7 +26586,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 511:
+    /// Semantic action for production 517:
     ///
     /// `ArgumentListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26288,7 +26605,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 512:
+    /// Semantic action for production 518:
     ///
     /// `ArgumentListOpt /* Option<T>::None */: ;`
     ///
@@ -26300,7 +26617,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 513:
+    /// Semantic action for production 519:
     ///
     /// `ArgumentItem: ArgumentExpression ArgumentItemOpt /* Option */;`
     ///
@@ -26324,7 +26641,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 514:
+    /// Semantic action for production 520:
     ///
     /// `ArgumentItemOpt /* Option<T>::Some */: Colon Expression;`
     ///
@@ -26349,7 +26666,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 515:
+    /// Semantic action for production 521:
     ///
     /// `ArgumentItemOpt /* Option<T>::None */: ;`
     ///
@@ -26361,17 +26678,28 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 516:
+    /// Semantic action for production 522:
     ///
-    /// `ArgumentExpression: Expression;`
+    /// `ArgumentExpression: Expression ArgumentExpressionOpt /* Option */;`
     ///
     #[parol_runtime::function_name::named]
-    fn argument_expression(&mut self, _expression: &ParseTreeType<'t>) -> Result<()> {
+    fn argument_expression(
+        &mut self,
+        _expression: &ParseTreeType<'t>,
+        _argument_expression_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
+        let argument_expression_opt = pop_item!(
+            self,
+            argument_expression_opt,
+            ArgumentExpressionOpt,
+            context
+        );
         let expression = pop_item!(self, expression, Expression, context);
         let argument_expression_built = ArgumentExpression {
             expression: Box::new(expression),
+            argument_expression_opt,
         };
         // Calling user action here
         self.user_grammar
@@ -26383,7 +26711,183 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 517:
+    /// Semantic action for production 523:
+    ///
+    /// `ArgumentExpressionOpt /* Option<T>::Some */: PropertyImplication;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn argument_expression_opt_0(
+        &mut self,
+        _property_implication: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let property_implication =
+            pop_item!(self, property_implication, PropertyImplication, context);
+        let argument_expression_opt_0_built = ArgumentExpressionOpt {
+            property_implication: Box::new(property_implication),
+        };
+        self.push(
+            ASTType::ArgumentExpressionOpt(Some(argument_expression_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 524:
+    ///
+    /// `ArgumentExpressionOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn argument_expression_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::ArgumentExpressionOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 525:
+    ///
+    /// `PropertyImplication: Implication PropertyImplicationOpt /* Option */ Expression;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property_implication(
+        &mut self,
+        _implication: &ParseTreeType<'t>,
+        _property_implication_opt: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expression = pop_item!(self, expression, Expression, context);
+        let property_implication_opt = pop_item!(
+            self,
+            property_implication_opt,
+            PropertyImplicationOpt,
+            context
+        );
+        let implication = pop_item!(self, implication, Implication, context);
+        let property_implication_built = PropertyImplication {
+            implication: Box::new(implication),
+            property_implication_opt,
+            expression: Box::new(expression),
+        };
+        // Calling user action here
+        self.user_grammar
+            .property_implication(&property_implication_built)?;
+        self.push(
+            ASTType::PropertyImplication(property_implication_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 526:
+    ///
+    /// `PropertyImplicationOpt /* Option<T>::Some */: CycleDelay;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property_implication_opt_0(&mut self, _cycle_delay: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cycle_delay = pop_item!(self, cycle_delay, CycleDelay, context);
+        let property_implication_opt_0_built = PropertyImplicationOpt {
+            cycle_delay: Box::new(cycle_delay),
+        };
+        self.push(
+            ASTType::PropertyImplicationOpt(Some(property_implication_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 527:
+    ///
+    /// `PropertyImplicationOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property_implication_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::PropertyImplicationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 528:
+    ///
+    /// `CycleDelay: HashHash CycleDelayGroup;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cycle_delay(
+        &mut self,
+        _hash_hash: &ParseTreeType<'t>,
+        _cycle_delay_group: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cycle_delay_group = pop_item!(self, cycle_delay_group, CycleDelayGroup, context);
+        let hash_hash = pop_item!(self, hash_hash, HashHash, context);
+        let cycle_delay_built = CycleDelay {
+            hash_hash: Box::new(hash_hash),
+            cycle_delay_group: Box::new(cycle_delay_group),
+        };
+        // Calling user action here
+        self.user_grammar.cycle_delay(&cycle_delay_built)?;
+        self.push(ASTType::CycleDelay(cycle_delay_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 529:
+    ///
+    /// `CycleDelayGroup: Number;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cycle_delay_group_0(&mut self, _number: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number = pop_item!(self, number, Number, context);
+        let cycle_delay_group_0_built = CycleDelayGroupNumber {
+            number: Box::new(number),
+        };
+        let cycle_delay_group_0_built = CycleDelayGroup::Number(cycle_delay_group_0_built);
+        self.push(ASTType::CycleDelayGroup(cycle_delay_group_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 530:
+    ///
+    /// `CycleDelayGroup: LBracket Expression Colon Expression RBracket;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cycle_delay_group_1(
+        &mut self,
+        _l_bracket: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+        _colon: &ParseTreeType<'t>,
+        _expression0: &ParseTreeType<'t>,
+        _r_bracket: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_bracket = pop_item!(self, r_bracket, RBracket, context);
+        let expression0 = pop_item!(self, expression0, Expression, context);
+        let colon = pop_item!(self, colon, Colon, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let l_bracket = pop_item!(self, l_bracket, LBracket, context);
+        let cycle_delay_group_1_built = CycleDelayGroupLBracketExpressionColonExpressionRBracket {
+            l_bracket: Box::new(l_bracket),
+            expression: Box::new(expression),
+            colon: Box::new(colon),
+            expression0: Box::new(expression0),
+            r_bracket: Box::new(r_bracket),
+        };
+        let cycle_delay_group_1_built =
+            CycleDelayGroup::LBracketExpressionColonExpressionRBracket(cycle_delay_group_1_built);
+        self.push(ASTType::CycleDelayGroup(cycle_delay_group_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 531:
     ///
     /// `StructConstructor: QuoteLBrace StructConstructorList StructConstructorOpt /* Option */ RBrace;`
     ///
@@ -26423,7 +26927,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 518:
+    /// Semantic action for production 532:
     ///
     /// `StructConstructorOpt /* Option<T>::Some */: DotDot Defaul LParen Expression RParen;`
     ///
@@ -26457,7 +26961,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 519:
+    /// Semantic action for production 533:
     ///
     /// `StructConstructorOpt /* Option<T>::None */: ;`
     ///
@@ -26469,7 +26973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 520:
+    /// Semantic action for production 534:
     ///
     /// `StructConstructorList: StructConstructorItem StructConstructorListList /* Vec */ StructConstructorListOpt /* Option */;`
     ///
@@ -26515,7 +27019,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 521:
+    /// Semantic action for production 535:
     ///
     /// `StructConstructorListList /* Vec<T>::Push */: Comma StructConstructorItem StructConstructorListList;`
     ///
@@ -26554,7 +27058,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 522:
+    /// Semantic action for production 536:
     ///
     /// `StructConstructorListList /* Vec<T>::New */: ;`
     ///
@@ -26570,7 +27074,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 523:
+    /// Semantic action for production 537:
     ///
     /// `StructConstructorListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26589,7 +27093,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 524:
+    /// Semantic action for production 538:
     ///
     /// `StructConstructorListOpt /* Option<T>::None */: ;`
     ///
@@ -26601,7 +27105,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 525:
+    /// Semantic action for production 539:
     ///
     /// `StructConstructorItem: Identifier Colon Expression;`
     ///
@@ -26632,7 +27136,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 526:
+    /// Semantic action for production 540:
     ///
     /// `ConcatenationList: ConcatenationItem ConcatenationListList /* Vec */ ConcatenationListOpt /* Option */;`
     ///
@@ -26669,7 +27173,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 527:
+    /// Semantic action for production 541:
     ///
     /// `ConcatenationListList /* Vec<T>::Push */: Comma ConcatenationItem ConcatenationListList;`
     ///
@@ -26703,7 +27207,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 528:
+    /// Semantic action for production 542:
     ///
     /// `ConcatenationListList /* Vec<T>::New */: ;`
     ///
@@ -26719,7 +27223,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 529:
+    /// Semantic action for production 543:
     ///
     /// `ConcatenationListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26738,7 +27242,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 530:
+    /// Semantic action for production 544:
     ///
     /// `ConcatenationListOpt /* Option<T>::None */: ;`
     ///
@@ -26750,7 +27254,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 531:
+    /// Semantic action for production 545:
     ///
     /// `ConcatenationItem: Expression ConcatenationItemOpt /* Option */;`
     ///
@@ -26779,7 +27283,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 532:
+    /// Semantic action for production 546:
     ///
     /// `ConcatenationItemOpt /* Option<T>::Some */: Repeat Expression;`
     ///
@@ -26804,7 +27308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 533:
+    /// Semantic action for production 547:
     ///
     /// `ConcatenationItemOpt /* Option<T>::None */: ;`
     ///
@@ -26816,7 +27320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 534:
+    /// Semantic action for production 548:
     ///
     /// `ArrayLiteralList: ArrayLiteralItem ArrayLiteralListList /* Vec */ ArrayLiteralListOpt /* Option */;`
     ///
@@ -26846,7 +27350,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 535:
+    /// Semantic action for production 549:
     ///
     /// `ArrayLiteralListList /* Vec<T>::Push */: Comma ArrayLiteralItem ArrayLiteralListList;`
     ///
@@ -26876,7 +27380,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 536:
+    /// Semantic action for production 550:
     ///
     /// `ArrayLiteralListList /* Vec<T>::New */: ;`
     ///
@@ -26892,7 +27396,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 537:
+    /// Semantic action for production 551:
     ///
     /// `ArrayLiteralListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26911,7 +27415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 538:
+    /// Semantic action for production 552:
     ///
     /// `ArrayLiteralListOpt /* Option<T>::None */: ;`
     ///
@@ -26923,7 +27427,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 539:
+    /// Semantic action for production 553:
     ///
     /// `ArrayLiteralItem: ArrayLiteralItemGroup;`
     ///
@@ -26947,7 +27451,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 540:
+    /// Semantic action for production 554:
     ///
     /// `ArrayLiteralItemGroup: Expression ArrayLiteralItemOpt /* Option */;`
     ///
@@ -26975,7 +27479,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 541:
+    /// Semantic action for production 555:
     ///
     /// `ArrayLiteralItemGroup: Defaul Colon Expression;`
     ///
@@ -27005,7 +27509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 542:
+    /// Semantic action for production 556:
     ///
     /// `ArrayLiteralItemOpt /* Option<T>::Some */: Repeat Expression;`
     ///
@@ -27030,7 +27534,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 543:
+    /// Semantic action for production 557:
     ///
     /// `ArrayLiteralItemOpt /* Option<T>::None */: ;`
     ///
@@ -27042,7 +27546,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 544:
+    /// Semantic action for production 558:
     ///
     /// `CaseExpression: Case Expression LBrace CaseCondition Colon Expression Comma CaseExpressionList /* Vec */ Defaul Colon Expression CaseExpressionOpt /* Option */ RBrace;`
     ///
@@ -27100,7 +27604,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 545:
+    /// Semantic action for production 559:
     ///
     /// `CaseExpressionList /* Vec<T>::Push */: CaseCondition Colon Expression Comma CaseExpressionList;`
     ///
@@ -27133,7 +27637,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 546:
+    /// Semantic action for production 560:
     ///
     /// `CaseExpressionList /* Vec<T>::New */: ;`
     ///
@@ -27149,7 +27653,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 547:
+    /// Semantic action for production 561:
     ///
     /// `CaseExpressionOpt /* Option<T>::Some */: Comma;`
     ///
@@ -27168,7 +27672,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 548:
+    /// Semantic action for production 562:
     ///
     /// `CaseExpressionOpt /* Option<T>::None */: ;`
     ///
@@ -27180,7 +27684,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 549:
+    /// Semantic action for production 563:
     ///
     /// `SwitchExpression: Switch LBrace SwitchCondition Colon Expression Comma SwitchExpressionList /* Vec */ Defaul Colon Expression SwitchExpressionOpt /* Option */ RBrace;`
     ///
@@ -27237,7 +27741,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 550:
+    /// Semantic action for production 564:
     ///
     /// `SwitchExpressionList /* Vec<T>::Push */: SwitchCondition Colon Expression Comma SwitchExpressionList;`
     ///
@@ -27273,7 +27777,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 551:
+    /// Semantic action for production 565:
     ///
     /// `SwitchExpressionList /* Vec<T>::New */: ;`
     ///
@@ -27289,7 +27793,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 552:
+    /// Semantic action for production 566:
     ///
     /// `SwitchExpressionOpt /* Option<T>::Some */: Comma;`
     ///
@@ -27308,7 +27812,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 553:
+    /// Semantic action for production 567:
     ///
     /// `SwitchExpressionOpt /* Option<T>::None */: ;`
     ///
@@ -27320,7 +27824,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 554:
+    /// Semantic action for production 568:
     ///
     /// `TypeExpression: Type LParen Expression RParen;`
     ///
@@ -27350,7 +27854,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 555:
+    /// Semantic action for production 569:
     ///
     /// `InsideExpression: Inside Expression LBrace RangeList RBrace;`
     ///
@@ -27384,7 +27888,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 556:
+    /// Semantic action for production 570:
     ///
     /// `OutsideExpression: Outside Expression LBrace RangeList RBrace;`
     ///
@@ -27421,7 +27925,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 557:
+    /// Semantic action for production 571:
     ///
     /// `RangeList: RangeItem RangeListList /* Vec */ RangeListOpt /* Option */;`
     ///
@@ -27448,7 +27952,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 558:
+    /// Semantic action for production 572:
     ///
     /// `RangeListList /* Vec<T>::Push */: Comma RangeItem RangeListList;`
     ///
@@ -27474,7 +27978,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 559:
+    /// Semantic action for production 573:
     ///
     /// `RangeListList /* Vec<T>::New */: ;`
     ///
@@ -27487,7 +27991,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 560:
+    /// Semantic action for production 574:
     ///
     /// `RangeListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -27503,7 +28007,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 561:
+    /// Semantic action for production 575:
     ///
     /// `RangeListOpt /* Option<T>::None */: ;`
     ///
@@ -27515,7 +28019,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 562:
+    /// Semantic action for production 576:
     ///
     /// `RangeItem: Range;`
     ///
@@ -27533,7 +28037,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 563:
+    /// Semantic action for production 577:
     ///
     /// `Select: LBracket Expression SelectOpt /* Option */ RBracket;`
     ///
@@ -27563,7 +28067,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 564:
+    /// Semantic action for production 578:
     ///
     /// `SelectOpt /* Option<T>::Some */: SelectOperator Expression;`
     ///
@@ -27585,7 +28089,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 565:
+    /// Semantic action for production 579:
     ///
     /// `SelectOpt /* Option<T>::None */: ;`
     ///
@@ -27597,7 +28101,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 566:
+    /// Semantic action for production 580:
     ///
     /// `SelectOperator: Colon;`
     ///
@@ -27617,7 +28121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 567:
+    /// Semantic action for production 581:
     ///
     /// `SelectOperator: PlusColon;`
     ///
@@ -27637,7 +28141,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 568:
+    /// Semantic action for production 582:
     ///
     /// `SelectOperator: MinusColon;`
     ///
@@ -27657,7 +28161,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 569:
+    /// Semantic action for production 583:
     ///
     /// `SelectOperator: Step;`
     ///
@@ -27677,7 +28181,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 570:
+    /// Semantic action for production 584:
     ///
     /// `Width: LAngle Expression WidthList /* Vec */ RAngle;`
     ///
@@ -27707,7 +28211,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 571:
+    /// Semantic action for production 585:
     ///
     /// `WidthList /* Vec<T>::Push */: Comma Expression WidthList;`
     ///
@@ -27733,7 +28237,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 572:
+    /// Semantic action for production 586:
     ///
     /// `WidthList /* Vec<T>::New */: ;`
     ///
@@ -27746,7 +28250,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 573:
+    /// Semantic action for production 587:
     ///
     /// `Array: LBracket Expression ArrayList /* Vec */ RBracket;`
     ///
@@ -27776,7 +28280,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 574:
+    /// Semantic action for production 588:
     ///
     /// `ArrayList /* Vec<T>::Push */: Comma Expression ArrayList;`
     ///
@@ -27802,7 +28306,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 575:
+    /// Semantic action for production 589:
     ///
     /// `ArrayList /* Vec<T>::New */: ;`
     ///
@@ -27815,7 +28319,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 576:
+    /// Semantic action for production 590:
     ///
     /// `Range: Expression RangeOpt /* Option */;`
     ///
@@ -27839,7 +28343,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 577:
+    /// Semantic action for production 591:
     ///
     /// `RangeOpt /* Option<T>::Some */: RangeOperator Expression;`
     ///
@@ -27861,7 +28365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 578:
+    /// Semantic action for production 592:
     ///
     /// `RangeOpt /* Option<T>::None */: ;`
     ///
@@ -27873,7 +28377,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 579:
+    /// Semantic action for production 593:
     ///
     /// `RangeOperator: DotDot;`
     ///
@@ -27892,7 +28396,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 580:
+    /// Semantic action for production 594:
     ///
     /// `RangeOperator: DotDotEqu;`
     ///
@@ -27911,7 +28415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 581:
+    /// Semantic action for production 595:
     ///
     /// `FixedType: P8;`
     ///
@@ -27928,7 +28432,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 582:
+    /// Semantic action for production 596:
     ///
     /// `FixedType: P16;`
     ///
@@ -27945,7 +28449,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 583:
+    /// Semantic action for production 597:
     ///
     /// `FixedType: P32;`
     ///
@@ -27962,7 +28466,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 584:
+    /// Semantic action for production 598:
     ///
     /// `FixedType: P64;`
     ///
@@ -27979,7 +28483,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 585:
+    /// Semantic action for production 599:
     ///
     /// `FixedType: U8;`
     ///
@@ -27996,7 +28500,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 586:
+    /// Semantic action for production 600:
     ///
     /// `FixedType: U16;`
     ///
@@ -28013,7 +28517,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 587:
+    /// Semantic action for production 601:
     ///
     /// `FixedType: U32;`
     ///
@@ -28030,7 +28534,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 588:
+    /// Semantic action for production 602:
     ///
     /// `FixedType: U64;`
     ///
@@ -28047,7 +28551,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 589:
+    /// Semantic action for production 603:
     ///
     /// `FixedType: I8;`
     ///
@@ -28064,7 +28568,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 590:
+    /// Semantic action for production 604:
     ///
     /// `FixedType: I16;`
     ///
@@ -28081,7 +28585,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 591:
+    /// Semantic action for production 605:
     ///
     /// `FixedType: I32;`
     ///
@@ -28098,7 +28602,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 592:
+    /// Semantic action for production 606:
     ///
     /// `FixedType: I64;`
     ///
@@ -28115,7 +28619,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 593:
+    /// Semantic action for production 607:
     ///
     /// `FixedType: F32;`
     ///
@@ -28132,7 +28636,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 594:
+    /// Semantic action for production 608:
     ///
     /// `FixedType: F64;`
     ///
@@ -28149,7 +28653,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 595:
+    /// Semantic action for production 609:
     ///
     /// `FixedType: BBool;`
     ///
@@ -28168,7 +28672,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 596:
+    /// Semantic action for production 610:
     ///
     /// `FixedType: LBool;`
     ///
@@ -28187,7 +28691,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 597:
+    /// Semantic action for production 611:
     ///
     /// `FixedType: Strin;`
     ///
@@ -28206,7 +28710,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 598:
+    /// Semantic action for production 612:
     ///
     /// `VariableType: Clock;`
     ///
@@ -28225,7 +28729,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 599:
+    /// Semantic action for production 613:
     ///
     /// `VariableType: ClockPosedge;`
     ///
@@ -28244,7 +28748,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 600:
+    /// Semantic action for production 614:
     ///
     /// `VariableType: ClockNegedge;`
     ///
@@ -28263,7 +28767,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 601:
+    /// Semantic action for production 615:
     ///
     /// `VariableType: Reset;`
     ///
@@ -28282,7 +28786,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 602:
+    /// Semantic action for production 616:
     ///
     /// `VariableType: ResetAsyncHigh;`
     ///
@@ -28301,7 +28805,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 603:
+    /// Semantic action for production 617:
     ///
     /// `VariableType: ResetAsyncLow;`
     ///
@@ -28320,7 +28824,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 604:
+    /// Semantic action for production 618:
     ///
     /// `VariableType: ResetSyncHigh;`
     ///
@@ -28339,7 +28843,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 605:
+    /// Semantic action for production 619:
     ///
     /// `VariableType: ResetSyncLow;`
     ///
@@ -28358,7 +28862,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 606:
+    /// Semantic action for production 620:
     ///
     /// `VariableType: Logic;`
     ///
@@ -28377,7 +28881,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 607:
+    /// Semantic action for production 621:
     ///
     /// `VariableType: Bit;`
     ///
@@ -28394,7 +28898,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 608:
+    /// Semantic action for production 622:
     ///
     /// `UserDefinedType: ScopedIdentifier;`
     ///
@@ -28413,7 +28917,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 609:
+    /// Semantic action for production 623:
     ///
     /// `TypeModifier: Tri;`
     ///
@@ -28430,7 +28934,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 610:
+    /// Semantic action for production 624:
     ///
     /// `TypeModifier: Signed;`
     ///
@@ -28449,7 +28953,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 611:
+    /// Semantic action for production 625:
     ///
     /// `TypeModifier: Defaul;`
     ///
@@ -28468,7 +28972,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 612:
+    /// Semantic action for production 626:
     ///
     /// `FactorType: FactorTypeGroup;`
     ///
@@ -28486,7 +28990,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 613:
+    /// Semantic action for production 627:
     ///
     /// `FactorTypeGroup: VariableType FactorTypeOpt /* Option */;`
     ///
@@ -28510,7 +29014,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 614:
+    /// Semantic action for production 628:
     ///
     /// `FactorTypeGroup: FixedType;`
     ///
@@ -28527,7 +29031,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 615:
+    /// Semantic action for production 629:
     ///
     /// `FactorTypeOpt /* Option<T>::Some */: Width;`
     ///
@@ -28546,7 +29050,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 616:
+    /// Semantic action for production 630:
     ///
     /// `FactorTypeOpt /* Option<T>::None */: ;`
     ///
@@ -28558,7 +29062,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 617:
+    /// Semantic action for production 631:
     ///
     /// `ScalarType: ScalarTypeList /* Vec */ ScalarTypeGroup;`
     ///
@@ -28583,7 +29087,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 618:
+    /// Semantic action for production 632:
     ///
     /// `ScalarTypeGroup: UserDefinedType ScalarTypeOpt /* Option */;`
     ///
@@ -28607,7 +29111,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 619:
+    /// Semantic action for production 633:
     ///
     /// `ScalarTypeGroup: FactorType;`
     ///
@@ -28624,7 +29128,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 620:
+    /// Semantic action for production 634:
     ///
     /// `ScalarTypeList /* Vec<T>::Push */: TypeModifier ScalarTypeList;`
     ///
@@ -28647,7 +29151,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 621:
+    /// Semantic action for production 635:
     ///
     /// `ScalarTypeList /* Vec<T>::New */: ;`
     ///
@@ -28660,7 +29164,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 622:
+    /// Semantic action for production 636:
     ///
     /// `ScalarTypeOpt /* Option<T>::Some */: Width;`
     ///
@@ -28679,7 +29183,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 623:
+    /// Semantic action for production 637:
     ///
     /// `ScalarTypeOpt /* Option<T>::None */: ;`
     ///
@@ -28691,7 +29195,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 624:
+    /// Semantic action for production 638:
     ///
     /// `ArrayType: ScalarType ArrayTypeOpt /* Option */;`
     ///
@@ -28715,7 +29219,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 625:
+    /// Semantic action for production 639:
     ///
     /// `ArrayTypeOpt /* Option<T>::Some */: Array;`
     ///
@@ -28731,7 +29235,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 626:
+    /// Semantic action for production 640:
     ///
     /// `ArrayTypeOpt /* Option<T>::None */: ;`
     ///
@@ -28743,7 +29247,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 627:
+    /// Semantic action for production 641:
     ///
     /// `CastingType: U8;`
     ///
@@ -28760,7 +29264,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 628:
+    /// Semantic action for production 642:
     ///
     /// `CastingType: U16;`
     ///
@@ -28777,7 +29281,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 629:
+    /// Semantic action for production 643:
     ///
     /// `CastingType: U32;`
     ///
@@ -28794,7 +29298,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 630:
+    /// Semantic action for production 644:
     ///
     /// `CastingType: U64;`
     ///
@@ -28811,7 +29315,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 631:
+    /// Semantic action for production 645:
     ///
     /// `CastingType: P8;`
     ///
@@ -28828,7 +29332,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 632:
+    /// Semantic action for production 646:
     ///
     /// `CastingType: P16;`
     ///
@@ -28845,7 +29349,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 633:
+    /// Semantic action for production 647:
     ///
     /// `CastingType: P32;`
     ///
@@ -28862,7 +29366,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 634:
+    /// Semantic action for production 648:
     ///
     /// `CastingType: P64;`
     ///
@@ -28879,7 +29383,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 635:
+    /// Semantic action for production 649:
     ///
     /// `CastingType: I8;`
     ///
@@ -28896,7 +29400,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 636:
+    /// Semantic action for production 650:
     ///
     /// `CastingType: I16;`
     ///
@@ -28913,7 +29417,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 637:
+    /// Semantic action for production 651:
     ///
     /// `CastingType: I32;`
     ///
@@ -28930,7 +29434,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 638:
+    /// Semantic action for production 652:
     ///
     /// `CastingType: I64;`
     ///
@@ -28947,7 +29451,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 639:
+    /// Semantic action for production 653:
     ///
     /// `CastingType: F32;`
     ///
@@ -28964,7 +29468,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 640:
+    /// Semantic action for production 654:
     ///
     /// `CastingType: F64;`
     ///
@@ -28981,7 +29485,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 641:
+    /// Semantic action for production 655:
     ///
     /// `CastingType: BBool;`
     ///
@@ -29000,7 +29504,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 642:
+    /// Semantic action for production 656:
     ///
     /// `CastingType: LBool;`
     ///
@@ -29019,7 +29523,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 643:
+    /// Semantic action for production 657:
     ///
     /// `CastingType: Clock;`
     ///
@@ -29038,7 +29542,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 644:
+    /// Semantic action for production 658:
     ///
     /// `CastingType: ClockPosedge;`
     ///
@@ -29057,7 +29561,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 645:
+    /// Semantic action for production 659:
     ///
     /// `CastingType: ClockNegedge;`
     ///
@@ -29076,7 +29580,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 646:
+    /// Semantic action for production 660:
     ///
     /// `CastingType: Reset;`
     ///
@@ -29095,7 +29599,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 647:
+    /// Semantic action for production 661:
     ///
     /// `CastingType: ResetAsyncHigh;`
     ///
@@ -29114,7 +29618,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 648:
+    /// Semantic action for production 662:
     ///
     /// `CastingType: ResetAsyncLow;`
     ///
@@ -29133,7 +29637,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 649:
+    /// Semantic action for production 663:
     ///
     /// `CastingType: ResetSyncHigh;`
     ///
@@ -29152,7 +29656,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 650:
+    /// Semantic action for production 664:
     ///
     /// `CastingType: ResetSyncLow;`
     ///
@@ -29171,7 +29675,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 651:
+    /// Semantic action for production 665:
     ///
     /// `CastingType: UserDefinedType;`
     ///
@@ -29190,7 +29694,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 652:
+    /// Semantic action for production 666:
     ///
     /// `CastingType: Based;`
     ///
@@ -29209,7 +29713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 653:
+    /// Semantic action for production 667:
     ///
     /// `CastingType: BaseLess;`
     ///
@@ -29228,7 +29732,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 654:
+    /// Semantic action for production 668:
     ///
     /// `ClockDomain: Quote Identifier;`
     ///
@@ -29252,7 +29756,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 655:
+    /// Semantic action for production 669:
     ///
     /// `StatementBlock: LBrace StatementBlockList /* Vec */ RBrace;`
     ///
@@ -29280,7 +29784,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 656:
+    /// Semantic action for production 670:
     ///
     /// `StatementBlockList /* Vec<T>::Push */: StatementBlockGroup StatementBlockList;`
     ///
@@ -29305,7 +29809,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 657:
+    /// Semantic action for production 671:
     ///
     /// `StatementBlockList /* Vec<T>::New */: ;`
     ///
@@ -29321,7 +29825,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 658:
+    /// Semantic action for production 672:
     ///
     /// `StatementBlockGroup: StatementBlockGroupList /* Vec */ StatementBlockGroupGroup;`
     ///
@@ -29359,7 +29863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 659:
+    /// Semantic action for production 673:
     ///
     /// `StatementBlockGroupGroup: Block LBrace StatementBlockGroupGroupList /* Vec */ RBrace;`
     ///
@@ -29400,7 +29904,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 660:
+    /// Semantic action for production 674:
     ///
     /// `StatementBlockGroupGroupList /* Vec<T>::Push */: StatementBlockGroup StatementBlockGroupGroupList;`
     ///
@@ -29432,7 +29936,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 661:
+    /// Semantic action for production 675:
     ///
     /// `StatementBlockGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -29448,7 +29952,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 662:
+    /// Semantic action for production 676:
     ///
     /// `StatementBlockGroupGroup: StatementBlockItem;`
     ///
@@ -29473,7 +29977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 663:
+    /// Semantic action for production 677:
     ///
     /// `StatementBlockGroupList /* Vec<T>::Push */: Attribute StatementBlockGroupList;`
     ///
@@ -29504,7 +30008,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 664:
+    /// Semantic action for production 678:
     ///
     /// `StatementBlockGroupList /* Vec<T>::New */: ;`
     ///
@@ -29520,7 +30024,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 665:
+    /// Semantic action for production 679:
     ///
     /// `StatementBlockItem: VarDeclaration;`
     ///
@@ -29544,7 +30048,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 666:
+    /// Semantic action for production 680:
     ///
     /// `StatementBlockItem: LetStatement;`
     ///
@@ -29568,7 +30072,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 667:
+    /// Semantic action for production 681:
     ///
     /// `StatementBlockItem: ConstDeclaration;`
     ///
@@ -29592,7 +30096,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 668:
+    /// Semantic action for production 682:
     ///
     /// `StatementBlockItem: GenDeclaration;`
     ///
@@ -29616,7 +30120,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 669:
+    /// Semantic action for production 683:
     ///
     /// `StatementBlockItem: Statement;`
     ///
@@ -29640,7 +30144,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 670:
+    /// Semantic action for production 684:
     ///
     /// `StatementBlockItem: ConcatenationAssignment;`
     ///
@@ -29672,7 +30176,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 671:
+    /// Semantic action for production 685:
     ///
     /// `Statement: IdentifierStatement;`
     ///
@@ -29692,7 +30196,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 672:
+    /// Semantic action for production 686:
     ///
     /// `Statement: IfStatement;`
     ///
@@ -29711,7 +30215,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 673:
+    /// Semantic action for production 687:
     ///
     /// `Statement: IfResetStatement;`
     ///
@@ -29730,7 +30234,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 674:
+    /// Semantic action for production 688:
     ///
     /// `Statement: ReturnStatement;`
     ///
@@ -29749,7 +30253,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 675:
+    /// Semantic action for production 689:
     ///
     /// `Statement: BreakStatement;`
     ///
@@ -29768,7 +30272,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 676:
+    /// Semantic action for production 690:
     ///
     /// `Statement: ForStatement;`
     ///
@@ -29787,7 +30291,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 677:
+    /// Semantic action for production 691:
     ///
     /// `Statement: CaseStatement;`
     ///
@@ -29806,7 +30310,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 678:
+    /// Semantic action for production 692:
     ///
     /// `Statement: SwitchStatement;`
     ///
@@ -29825,7 +30329,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 679:
+    /// Semantic action for production 693:
     ///
     /// `LetStatement: Let Identifier LetStatementOpt /* Option */ Equ Expression Semicolon;`
     ///
@@ -29861,7 +30365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 680:
+    /// Semantic action for production 694:
     ///
     /// `LetStatementOpt /* Option<T>::Some */: Colon LetStatementOpt0 /* Option */ ArrayType;`
     ///
@@ -29889,7 +30393,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 681:
+    /// Semantic action for production 695:
     ///
     /// `LetStatementOpt0 /* Option<T>::Some */: ClockDomain;`
     ///
@@ -29908,7 +30412,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 682:
+    /// Semantic action for production 696:
     ///
     /// `LetStatementOpt0 /* Option<T>::None */: ;`
     ///
@@ -29920,7 +30424,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 683:
+    /// Semantic action for production 697:
     ///
     /// `LetStatementOpt /* Option<T>::None */: ;`
     ///
@@ -29932,7 +30436,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 684:
+    /// Semantic action for production 698:
     ///
     /// `IdentifierStatement: ExpressionIdentifier IdentifierStatementGroup Semicolon;`
     ///
@@ -29969,7 +30473,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 685:
+    /// Semantic action for production 699:
     ///
     /// `IdentifierStatementGroup: FunctionCall;`
     ///
@@ -29990,7 +30494,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 686:
+    /// Semantic action for production 700:
     ///
     /// `IdentifierStatementGroup: Assignment;`
     ///
@@ -30011,7 +30515,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 687:
+    /// Semantic action for production 701:
     ///
     /// `ConcatenationAssignment: LBrace AssignConcatenationList RBrace Equ Expression Semicolon;`
     ///
@@ -30056,7 +30560,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 688:
+    /// Semantic action for production 702:
     ///
     /// `Assignment: AssignmentGroup Expression;`
     ///
@@ -30080,7 +30584,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 689:
+    /// Semantic action for production 703:
     ///
     /// `AssignmentGroup: Equ;`
     ///
@@ -30095,7 +30599,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 690:
+    /// Semantic action for production 704:
     ///
     /// `AssignmentGroup: AssignmentOperator;`
     ///
@@ -30113,7 +30617,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 691:
+    /// Semantic action for production 705:
     ///
     /// `AssignmentGroup: DiamondOperator;`
     ///
@@ -30130,7 +30634,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 692:
+    /// Semantic action for production 706:
     ///
     /// `IfStatement: If Expression StatementBlock IfStatementList /* Vec */ IfStatementOpt /* Option */;`
     ///
@@ -30164,7 +30668,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 693:
+    /// Semantic action for production 707:
     ///
     /// `IfStatementList /* Vec<T>::Push */: Else If Expression StatementBlock IfStatementList;`
     ///
@@ -30196,7 +30700,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 694:
+    /// Semantic action for production 708:
     ///
     /// `IfStatementList /* Vec<T>::New */: ;`
     ///
@@ -30209,7 +30713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 695:
+    /// Semantic action for production 709:
     ///
     /// `IfStatementOpt /* Option<T>::Some */: Else StatementBlock;`
     ///
@@ -30234,7 +30738,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 696:
+    /// Semantic action for production 710:
     ///
     /// `IfStatementOpt /* Option<T>::None */: ;`
     ///
@@ -30246,7 +30750,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 697:
+    /// Semantic action for production 711:
     ///
     /// `IfResetStatement: IfReset StatementBlock IfResetStatementList /* Vec */ IfResetStatementOpt /* Option */;`
     ///
@@ -30279,7 +30783,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 698:
+    /// Semantic action for production 712:
     ///
     /// `IfResetStatementList /* Vec<T>::Push */: Else If Expression StatementBlock IfResetStatementList;`
     ///
@@ -30315,7 +30819,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 699:
+    /// Semantic action for production 713:
     ///
     /// `IfResetStatementList /* Vec<T>::New */: ;`
     ///
@@ -30331,7 +30835,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 700:
+    /// Semantic action for production 714:
     ///
     /// `IfResetStatementOpt /* Option<T>::Some */: Else StatementBlock;`
     ///
@@ -30356,7 +30860,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 701:
+    /// Semantic action for production 715:
     ///
     /// `IfResetStatementOpt /* Option<T>::None */: ;`
     ///
@@ -30368,7 +30872,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 702:
+    /// Semantic action for production 716:
     ///
     /// `ReturnStatement: Return Expression Semicolon;`
     ///
@@ -30396,7 +30900,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 703:
+    /// Semantic action for production 717:
     ///
     /// `BreakStatement: Break Semicolon;`
     ///
@@ -30420,7 +30924,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 704:
+    /// Semantic action for production 718:
     ///
     /// `ForStatement: For Identifier In ForStatementOpt /* Option */ Range ForStatementOpt0 /* Option */ StatementBlock;`
     ///
@@ -30459,7 +30963,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 705:
+    /// Semantic action for production 719:
     ///
     /// `ForStatementOpt0 /* Option<T>::Some */: Step AssignmentOperator Expression;`
     ///
@@ -30487,7 +30991,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 706:
+    /// Semantic action for production 720:
     ///
     /// `ForStatementOpt0 /* Option<T>::None */: ;`
     ///
@@ -30499,7 +31003,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 707:
+    /// Semantic action for production 721:
     ///
     /// `ForStatementOpt /* Option<T>::Some */: Rev;`
     ///
@@ -30516,7 +31020,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 708:
+    /// Semantic action for production 722:
     ///
     /// `ForStatementOpt /* Option<T>::None */: ;`
     ///
@@ -30528,7 +31032,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 709:
+    /// Semantic action for production 723:
     ///
     /// `CaseStatement: Case Expression LBrace CaseStatementList /* Vec */ RBrace;`
     ///
@@ -30562,7 +31066,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 710:
+    /// Semantic action for production 724:
     ///
     /// `CaseStatementList /* Vec<T>::Push */: CaseItem CaseStatementList;`
     ///
@@ -30586,7 +31090,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 711:
+    /// Semantic action for production 725:
     ///
     /// `CaseStatementList /* Vec<T>::New */: ;`
     ///
@@ -30602,7 +31106,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 712:
+    /// Semantic action for production 726:
     ///
     /// `CaseItem: CaseItemGroup Colon CaseItemGroup0;`
     ///
@@ -30629,7 +31133,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 713:
+    /// Semantic action for production 727:
     ///
     /// `CaseItemGroup0: Statement;`
     ///
@@ -30646,7 +31150,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 714:
+    /// Semantic action for production 728:
     ///
     /// `CaseItemGroup0: StatementBlock;`
     ///
@@ -30663,7 +31167,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 715:
+    /// Semantic action for production 729:
     ///
     /// `CaseItemGroup: CaseCondition;`
     ///
@@ -30680,7 +31184,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 716:
+    /// Semantic action for production 730:
     ///
     /// `CaseItemGroup: Defaul;`
     ///
@@ -30697,7 +31201,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 717:
+    /// Semantic action for production 731:
     ///
     /// `CaseCondition: RangeItem CaseConditionList /* Vec */;`
     ///
@@ -30722,7 +31226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 718:
+    /// Semantic action for production 732:
     ///
     /// `CaseConditionList /* Vec<T>::Push */: Comma RangeItem CaseConditionList;`
     ///
@@ -30749,7 +31253,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 719:
+    /// Semantic action for production 733:
     ///
     /// `CaseConditionList /* Vec<T>::New */: ;`
     ///
@@ -30765,7 +31269,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 720:
+    /// Semantic action for production 734:
     ///
     /// `SwitchStatement: Switch LBrace SwitchStatementList /* Vec */ RBrace;`
     ///
@@ -30797,7 +31301,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 721:
+    /// Semantic action for production 735:
     ///
     /// `SwitchStatementList /* Vec<T>::Push */: SwitchItem SwitchStatementList;`
     ///
@@ -30821,7 +31325,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 722:
+    /// Semantic action for production 736:
     ///
     /// `SwitchStatementList /* Vec<T>::New */: ;`
     ///
@@ -30837,7 +31341,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 723:
+    /// Semantic action for production 737:
     ///
     /// `SwitchItem: SwitchItemGroup Colon SwitchItemGroup0;`
     ///
@@ -30864,7 +31368,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 724:
+    /// Semantic action for production 738:
     ///
     /// `SwitchItemGroup0: Statement;`
     ///
@@ -30884,7 +31388,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 725:
+    /// Semantic action for production 739:
     ///
     /// `SwitchItemGroup0: StatementBlock;`
     ///
@@ -30905,7 +31409,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 726:
+    /// Semantic action for production 740:
     ///
     /// `SwitchItemGroup: SwitchCondition;`
     ///
@@ -30922,7 +31426,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 727:
+    /// Semantic action for production 741:
     ///
     /// `SwitchItemGroup: Defaul;`
     ///
@@ -30939,7 +31443,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 728:
+    /// Semantic action for production 742:
     ///
     /// `SwitchCondition: Expression SwitchConditionList /* Vec */;`
     ///
@@ -30965,7 +31469,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 729:
+    /// Semantic action for production 743:
     ///
     /// `SwitchConditionList /* Vec<T>::Push */: Comma Expression SwitchConditionList;`
     ///
@@ -30992,7 +31496,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 730:
+    /// Semantic action for production 744:
     ///
     /// `SwitchConditionList /* Vec<T>::New */: ;`
     ///
@@ -31008,7 +31512,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 731:
+    /// Semantic action for production 745:
     ///
     /// `Attribute: HashLBracket Identifier AttributeOpt /* Option */ RBracket;`
     ///
@@ -31038,7 +31542,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 732:
+    /// Semantic action for production 746:
     ///
     /// `AttributeOpt /* Option<T>::Some */: LParen AttributeList RParen;`
     ///
@@ -31063,7 +31567,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 733:
+    /// Semantic action for production 747:
     ///
     /// `AttributeOpt /* Option<T>::None */: ;`
     ///
@@ -31075,7 +31579,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 734:
+    /// Semantic action for production 748:
     ///
     /// `AttributeList: AttributeItem AttributeListList /* Vec */ AttributeListOpt /* Option */;`
     ///
@@ -31103,7 +31607,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 735:
+    /// Semantic action for production 749:
     ///
     /// `AttributeListList /* Vec<T>::Push */: Comma AttributeItem AttributeListList;`
     ///
@@ -31130,7 +31634,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 736:
+    /// Semantic action for production 750:
     ///
     /// `AttributeListList /* Vec<T>::New */: ;`
     ///
@@ -31146,7 +31650,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 737:
+    /// Semantic action for production 751:
     ///
     /// `AttributeListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -31165,7 +31669,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 738:
+    /// Semantic action for production 752:
     ///
     /// `AttributeListOpt /* Option<T>::None */: ;`
     ///
@@ -31177,7 +31681,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 739:
+    /// Semantic action for production 753:
     ///
     /// `AttributeItem: Identifier;`
     ///
@@ -31196,7 +31700,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 740:
+    /// Semantic action for production 754:
     ///
     /// `AttributeItem: StringLiteral;`
     ///
@@ -31215,7 +31719,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 741:
+    /// Semantic action for production 755:
     ///
     /// `LetDeclaration: Let Identifier LetDeclarationOpt /* Option */ Equ Expression Semicolon;`
     ///
@@ -31251,7 +31755,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 742:
+    /// Semantic action for production 756:
     ///
     /// `LetDeclarationOpt /* Option<T>::Some */: Colon LetDeclarationOpt0 /* Option */ ArrayType;`
     ///
@@ -31280,7 +31784,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 743:
+    /// Semantic action for production 757:
     ///
     /// `LetDeclarationOpt0 /* Option<T>::Some */: ClockDomain;`
     ///
@@ -31299,7 +31803,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 744:
+    /// Semantic action for production 758:
     ///
     /// `LetDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -31311,7 +31815,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 745:
+    /// Semantic action for production 759:
     ///
     /// `LetDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31323,7 +31827,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 746:
+    /// Semantic action for production 760:
     ///
     /// `VarDeclaration: Var Identifier VarDeclarationOpt /* Option */ Semicolon;`
     ///
@@ -31353,7 +31857,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 747:
+    /// Semantic action for production 761:
     ///
     /// `VarDeclarationOpt /* Option<T>::Some */: Colon VarDeclarationOpt0 /* Option */ ArrayType;`
     ///
@@ -31382,7 +31886,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 748:
+    /// Semantic action for production 762:
     ///
     /// `VarDeclarationOpt0 /* Option<T>::Some */: ClockDomain;`
     ///
@@ -31401,7 +31905,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 749:
+    /// Semantic action for production 763:
     ///
     /// `VarDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -31413,7 +31917,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 750:
+    /// Semantic action for production 764:
     ///
     /// `VarDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31425,7 +31929,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 751:
+    /// Semantic action for production 765:
     ///
     /// `ConstDeclaration: Const Identifier ConstDeclarationOpt /* Option */ Equ Expression Semicolon;`
     ///
@@ -31463,7 +31967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 752:
+    /// Semantic action for production 766:
     ///
     /// `ConstDeclarationOpt /* Option<T>::Some */: Colon ConstDeclarationOptGroup;`
     ///
@@ -31493,7 +31997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 753:
+    /// Semantic action for production 767:
     ///
     /// `ConstDeclarationOptGroup: ArrayType;`
     ///
@@ -31514,7 +32018,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 754:
+    /// Semantic action for production 768:
     ///
     /// `ConstDeclarationOptGroup: Type;`
     ///
@@ -31535,7 +32039,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 755:
+    /// Semantic action for production 769:
     ///
     /// `ConstDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31547,7 +32051,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 756:
+    /// Semantic action for production 770:
     ///
     /// `GenDeclaration: Gen Identifier Colon GenDeclarationGroup Equ Expression Semicolon;`
     ///
@@ -31587,7 +32091,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 757:
+    /// Semantic action for production 771:
     ///
     /// `GenDeclarationGroup: GenericProtoBound;`
     ///
@@ -31608,7 +32112,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 758:
+    /// Semantic action for production 772:
     ///
     /// `GenDeclarationGroup: Type;`
     ///
@@ -31629,7 +32133,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 759:
+    /// Semantic action for production 773:
     ///
     /// `TypeDefDeclaration: Type Identifier Equ ArrayType Semicolon;`
     ///
@@ -31666,7 +32170,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 760:
+    /// Semantic action for production 774:
     ///
     /// `AlwaysFfDeclaration: AlwaysFf AlwaysFfDeclarationOpt /* Option */ StatementBlock;`
     ///
@@ -31702,7 +32206,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 761:
+    /// Semantic action for production 775:
     ///
     /// `AlwaysFfDeclarationOpt /* Option<T>::Some */: AlwaysFfEventList;`
     ///
@@ -31725,7 +32229,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 762:
+    /// Semantic action for production 776:
     ///
     /// `AlwaysFfDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31737,7 +32241,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 763:
+    /// Semantic action for production 777:
     ///
     /// `AlwaysFfEventList: LParen AlwaysFfClock AlwaysFfEventListOpt /* Option */ RParen;`
     ///
@@ -31776,7 +32280,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 764:
+    /// Semantic action for production 778:
     ///
     /// `AlwaysFfEventListOpt /* Option<T>::Some */: Comma AlwaysFfReset;`
     ///
@@ -31801,7 +32305,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 765:
+    /// Semantic action for production 779:
     ///
     /// `AlwaysFfEventListOpt /* Option<T>::None */: ;`
     ///
@@ -31813,7 +32317,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 766:
+    /// Semantic action for production 780:
     ///
     /// `AlwaysFfClock: HierarchicalIdentifier;`
     ///
@@ -31836,7 +32340,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 767:
+    /// Semantic action for production 781:
     ///
     /// `AlwaysFfReset: HierarchicalIdentifier;`
     ///
@@ -31859,7 +32363,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 768:
+    /// Semantic action for production 782:
     ///
     /// `AlwaysCombDeclaration: AlwaysComb StatementBlock;`
     ///
@@ -31887,7 +32391,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 769:
+    /// Semantic action for production 783:
     ///
     /// `AssignDeclaration: Assign AssignDestination Equ Expression Semicolon;`
     ///
@@ -31924,7 +32428,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 770:
+    /// Semantic action for production 784:
     ///
     /// `AssignDestination: HierarchicalIdentifier;`
     ///
@@ -31953,7 +32457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 771:
+    /// Semantic action for production 785:
     ///
     /// `AssignDestination: LBrace AssignConcatenationList RBrace;`
     ///
@@ -31991,7 +32495,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 772:
+    /// Semantic action for production 786:
     ///
     /// `AssignConcatenationList: AssignConcatenationItem AssignConcatenationListList /* Vec */ AssignConcatenationListOpt /* Option */;`
     ///
@@ -32037,7 +32541,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 773:
+    /// Semantic action for production 787:
     ///
     /// `AssignConcatenationListList /* Vec<T>::Push */: Comma AssignConcatenationItem AssignConcatenationListList;`
     ///
@@ -32076,7 +32580,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 774:
+    /// Semantic action for production 788:
     ///
     /// `AssignConcatenationListList /* Vec<T>::New */: ;`
     ///
@@ -32092,7 +32596,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 775:
+    /// Semantic action for production 789:
     ///
     /// `AssignConcatenationListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -32111,7 +32615,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 776:
+    /// Semantic action for production 790:
     ///
     /// `AssignConcatenationListOpt /* Option<T>::None */: ;`
     ///
@@ -32123,7 +32627,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 777:
+    /// Semantic action for production 791:
     ///
     /// `AssignConcatenationItem: HierarchicalIdentifier;`
     ///
@@ -32153,7 +32657,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 778:
+    /// Semantic action for production 792:
     ///
     /// `ConnectDeclaration: Connect HierarchicalIdentifier DiamondOperator Expression Semicolon;`
     ///
@@ -32195,7 +32699,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 779:
+    /// Semantic action for production 793:
     ///
     /// `ModportDeclaration: Modport Identifier LBrace ModportDeclarationOpt /* Option */ ModportDeclarationOpt0 /* Option */ RBrace;`
     ///
@@ -32245,7 +32749,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 780:
+    /// Semantic action for production 794:
     ///
     /// `ModportDeclarationOpt0 /* Option<T>::Some */: DotDot ModportDefault;`
     ///
@@ -32270,7 +32774,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 781:
+    /// Semantic action for production 795:
     ///
     /// `ModportDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -32282,7 +32786,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 782:
+    /// Semantic action for production 796:
     ///
     /// `ModportDeclarationOpt /* Option<T>::Some */: ModportList;`
     ///
@@ -32301,7 +32805,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 783:
+    /// Semantic action for production 797:
     ///
     /// `ModportDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -32313,7 +32817,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 784:
+    /// Semantic action for production 798:
     ///
     /// `ModportList: ModportGroup ModportListList /* Vec */ ModportListOpt /* Option */;`
     ///
@@ -32341,7 +32845,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 785:
+    /// Semantic action for production 799:
     ///
     /// `ModportListList /* Vec<T>::Push */: Comma ModportGroup ModportListList;`
     ///
@@ -32367,7 +32871,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 786:
+    /// Semantic action for production 800:
     ///
     /// `ModportListList /* Vec<T>::New */: ;`
     ///
@@ -32380,7 +32884,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 787:
+    /// Semantic action for production 801:
     ///
     /// `ModportListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -32399,7 +32903,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 788:
+    /// Semantic action for production 802:
     ///
     /// `ModportListOpt /* Option<T>::None */: ;`
     ///
@@ -32411,7 +32915,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 789:
+    /// Semantic action for production 803:
     ///
     /// `ModportGroup: ModportGroupList /* Vec */ ModportGroupGroup;`
     ///
@@ -32436,7 +32940,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 790:
+    /// Semantic action for production 804:
     ///
     /// `ModportGroupGroup: LBrace ModportList RBrace;`
     ///
@@ -32466,7 +32970,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 791:
+    /// Semantic action for production 805:
     ///
     /// `ModportGroupGroup: ModportItem;`
     ///
@@ -32487,7 +32991,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 792:
+    /// Semantic action for production 806:
     ///
     /// `ModportGroupList /* Vec<T>::Push */: Attribute ModportGroupList;`
     ///
@@ -32510,7 +33014,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 793:
+    /// Semantic action for production 807:
     ///
     /// `ModportGroupList /* Vec<T>::New */: ;`
     ///
@@ -32526,7 +33030,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 794:
+    /// Semantic action for production 808:
     ///
     /// `ModportItem: Identifier Colon Direction;`
     ///
@@ -32553,7 +33057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 795:
+    /// Semantic action for production 809:
     ///
     /// `ModportDefault: Input;`
     ///
@@ -32573,7 +33077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 796:
+    /// Semantic action for production 810:
     ///
     /// `ModportDefault: Output;`
     ///
@@ -32593,7 +33097,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 797:
+    /// Semantic action for production 811:
     ///
     /// `ModportDefault: Same LParen ModportDefaultList RParen;`
     ///
@@ -32627,7 +33131,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 798:
+    /// Semantic action for production 812:
     ///
     /// `ModportDefault: Converse LParen ModportDefaultList RParen;`
     ///
@@ -32661,7 +33165,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 799:
+    /// Semantic action for production 813:
     ///
     /// `ModportDefaultList: Identifier ModportDefaultListList /* Vec */ ModportDefaultListOpt /* Option */;`
     ///
@@ -32702,7 +33206,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 800:
+    /// Semantic action for production 814:
     ///
     /// `ModportDefaultListList /* Vec<T>::Push */: Comma Identifier ModportDefaultListList;`
     ///
@@ -32736,7 +33240,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 801:
+    /// Semantic action for production 815:
     ///
     /// `ModportDefaultListList /* Vec<T>::New */: ;`
     ///
@@ -32752,7 +33256,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 802:
+    /// Semantic action for production 816:
     ///
     /// `ModportDefaultListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -32771,7 +33275,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 803:
+    /// Semantic action for production 817:
     ///
     /// `ModportDefaultListOpt /* Option<T>::None */: ;`
     ///
@@ -32783,7 +33287,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 804:
+    /// Semantic action for production 818:
     ///
     /// `EnumDeclaration: Enum Identifier EnumDeclarationOpt /* Option */ LBrace EnumList RBrace;`
     ///
@@ -32821,7 +33325,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 805:
+    /// Semantic action for production 819:
     ///
     /// `EnumDeclarationOpt /* Option<T>::Some */: Colon ScalarType;`
     ///
@@ -32846,7 +33350,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 806:
+    /// Semantic action for production 820:
     ///
     /// `EnumDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -32858,7 +33362,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 807:
+    /// Semantic action for production 821:
     ///
     /// `EnumList: EnumGroup EnumListList /* Vec */ EnumListOpt /* Option */;`
     ///
@@ -32885,7 +33389,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 808:
+    /// Semantic action for production 822:
     ///
     /// `EnumListList /* Vec<T>::Push */: Comma EnumGroup EnumListList;`
     ///
@@ -32911,7 +33415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 809:
+    /// Semantic action for production 823:
     ///
     /// `EnumListList /* Vec<T>::New */: ;`
     ///
@@ -32924,7 +33428,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 810:
+    /// Semantic action for production 824:
     ///
     /// `EnumListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -32940,7 +33444,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 811:
+    /// Semantic action for production 825:
     ///
     /// `EnumListOpt /* Option<T>::None */: ;`
     ///
@@ -32952,7 +33456,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 812:
+    /// Semantic action for production 826:
     ///
     /// `EnumGroup: EnumGroupList /* Vec */ EnumGroupGroup;`
     ///
@@ -32976,7 +33480,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 813:
+    /// Semantic action for production 827:
     ///
     /// `EnumGroupGroup: LBrace EnumList RBrace;`
     ///
@@ -33003,7 +33507,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 814:
+    /// Semantic action for production 828:
     ///
     /// `EnumGroupGroup: EnumItem;`
     ///
@@ -33020,7 +33524,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 815:
+    /// Semantic action for production 829:
     ///
     /// `EnumGroupList /* Vec<T>::Push */: Attribute EnumGroupList;`
     ///
@@ -33043,7 +33547,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 816:
+    /// Semantic action for production 830:
     ///
     /// `EnumGroupList /* Vec<T>::New */: ;`
     ///
@@ -33056,7 +33560,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 817:
+    /// Semantic action for production 831:
     ///
     /// `EnumItem: Identifier EnumItemOpt /* Option */;`
     ///
@@ -33080,7 +33584,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 818:
+    /// Semantic action for production 832:
     ///
     /// `EnumItemOpt /* Option<T>::Some */: Equ Expression;`
     ///
@@ -33102,7 +33606,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 819:
+    /// Semantic action for production 833:
     ///
     /// `EnumItemOpt /* Option<T>::None */: ;`
     ///
@@ -33114,7 +33618,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 820:
+    /// Semantic action for production 834:
     ///
     /// `StructUnion: Struct;`
     ///
@@ -33133,7 +33637,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 821:
+    /// Semantic action for production 835:
     ///
     /// `StructUnion: Union;`
     ///
@@ -33152,7 +33656,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 822:
+    /// Semantic action for production 836:
     ///
     /// `StructUnionDeclaration: StructUnion Identifier StructUnionDeclarationOpt /* Option */ LBrace StructUnionList RBrace;`
     ///
@@ -33197,7 +33701,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 823:
+    /// Semantic action for production 837:
     ///
     /// `StructUnionDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -33220,7 +33724,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 824:
+    /// Semantic action for production 838:
     ///
     /// `StructUnionDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -33232,7 +33736,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 825:
+    /// Semantic action for production 839:
     ///
     /// `StructUnionList: StructUnionGroup StructUnionListList /* Vec */ StructUnionListOpt /* Option */;`
     ///
@@ -33262,7 +33766,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 826:
+    /// Semantic action for production 840:
     ///
     /// `StructUnionListList /* Vec<T>::Push */: Comma StructUnionGroup StructUnionListList;`
     ///
@@ -33292,7 +33796,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 827:
+    /// Semantic action for production 841:
     ///
     /// `StructUnionListList /* Vec<T>::New */: ;`
     ///
@@ -33308,7 +33812,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 828:
+    /// Semantic action for production 842:
     ///
     /// `StructUnionListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -33327,7 +33831,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 829:
+    /// Semantic action for production 843:
     ///
     /// `StructUnionListOpt /* Option<T>::None */: ;`
     ///
@@ -33339,7 +33843,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 830:
+    /// Semantic action for production 844:
     ///
     /// `StructUnionGroup: StructUnionGroupList /* Vec */ StructUnionGroupGroup;`
     ///
@@ -33370,7 +33874,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 831:
+    /// Semantic action for production 845:
     ///
     /// `StructUnionGroupGroup: LBrace StructUnionList RBrace;`
     ///
@@ -33400,7 +33904,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 832:
+    /// Semantic action for production 846:
     ///
     /// `StructUnionGroupGroup: StructUnionItem;`
     ///
@@ -33421,7 +33925,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 833:
+    /// Semantic action for production 847:
     ///
     /// `StructUnionGroupList /* Vec<T>::Push */: Attribute StructUnionGroupList;`
     ///
@@ -33448,7 +33952,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 834:
+    /// Semantic action for production 848:
     ///
     /// `StructUnionGroupList /* Vec<T>::New */: ;`
     ///
@@ -33464,7 +33968,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 835:
+    /// Semantic action for production 849:
     ///
     /// `StructUnionItem: Identifier Colon ScalarType;`
     ///
@@ -33492,7 +33996,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 836:
+    /// Semantic action for production 850:
     ///
     /// `InitialDeclaration: Initial StatementBlock;`
     ///
@@ -33520,7 +34024,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 837:
+    /// Semantic action for production 851:
     ///
     /// `FinalDeclaration: Final StatementBlock;`
     ///
@@ -33545,7 +34049,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 838:
+    /// Semantic action for production 852:
     ///
     /// `InstDeclaration: Inst ComponentInstantiation Semicolon;`
     ///
@@ -33578,7 +34082,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 839:
+    /// Semantic action for production 853:
     ///
     /// `BindDeclaration: Bind ScopedIdentifier LTMinus ComponentInstantiation Semicolon;`
     ///
@@ -33617,7 +34121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 840:
+    /// Semantic action for production 854:
     ///
     /// `ComponentInstantiation: Identifier Colon ComponentInstantiationOpt /* Option */ ScopedIdentifier ComponentInstantiationOpt0 /* Option */ ComponentInstantiationOpt1 /* Option */ ComponentInstantiationOpt2 /* Option */;`
     ///
@@ -33680,7 +34184,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 841:
+    /// Semantic action for production 855:
     ///
     /// `ComponentInstantiationOpt2 /* Option<T>::Some */: InstPort;`
     ///
@@ -33699,7 +34203,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 842:
+    /// Semantic action for production 856:
     ///
     /// `ComponentInstantiationOpt2 /* Option<T>::None */: ;`
     ///
@@ -33711,7 +34215,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 843:
+    /// Semantic action for production 857:
     ///
     /// `ComponentInstantiationOpt1 /* Option<T>::Some */: InstParameter;`
     ///
@@ -33733,7 +34237,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 844:
+    /// Semantic action for production 858:
     ///
     /// `ComponentInstantiationOpt1 /* Option<T>::None */: ;`
     ///
@@ -33745,7 +34249,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 845:
+    /// Semantic action for production 859:
     ///
     /// `ComponentInstantiationOpt0 /* Option<T>::Some */: Array;`
     ///
@@ -33764,7 +34268,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 846:
+    /// Semantic action for production 860:
     ///
     /// `ComponentInstantiationOpt0 /* Option<T>::None */: ;`
     ///
@@ -33776,7 +34280,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 847:
+    /// Semantic action for production 861:
     ///
     /// `ComponentInstantiationOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -33795,7 +34299,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 848:
+    /// Semantic action for production 862:
     ///
     /// `ComponentInstantiationOpt /* Option<T>::None */: ;`
     ///
@@ -33807,7 +34311,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 849:
+    /// Semantic action for production 863:
     ///
     /// `InstParameter: Hash LParen InstParameterOpt /* Option */ RParen;`
     ///
@@ -33837,7 +34341,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 850:
+    /// Semantic action for production 864:
     ///
     /// `InstParameterOpt /* Option<T>::Some */: InstParameterList;`
     ///
@@ -33856,7 +34360,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 851:
+    /// Semantic action for production 865:
     ///
     /// `InstParameterOpt /* Option<T>::None */: ;`
     ///
@@ -33868,7 +34372,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 852:
+    /// Semantic action for production 866:
     ///
     /// `InstParameterList: InstParameterGroup InstParameterListList /* Vec */ InstParameterListOpt /* Option */;`
     ///
@@ -33906,7 +34410,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 853:
+    /// Semantic action for production 867:
     ///
     /// `InstParameterListList /* Vec<T>::Push */: Comma InstParameterGroup InstParameterListList;`
     ///
@@ -33941,7 +34445,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 854:
+    /// Semantic action for production 868:
     ///
     /// `InstParameterListList /* Vec<T>::New */: ;`
     ///
@@ -33957,7 +34461,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 855:
+    /// Semantic action for production 869:
     ///
     /// `InstParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -33976,7 +34480,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 856:
+    /// Semantic action for production 870:
     ///
     /// `InstParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -33988,7 +34492,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 857:
+    /// Semantic action for production 871:
     ///
     /// `InstParameterGroup: InstParameterGroupList /* Vec */ InstParameterGroupGroup;`
     ///
@@ -34026,7 +34530,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 858:
+    /// Semantic action for production 872:
     ///
     /// `InstParameterGroupGroup: LBrace InstParameterList RBrace;`
     ///
@@ -34059,7 +34563,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 859:
+    /// Semantic action for production 873:
     ///
     /// `InstParameterGroupGroup: InstParameterItem;`
     ///
@@ -34083,7 +34587,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 860:
+    /// Semantic action for production 874:
     ///
     /// `InstParameterGroupList /* Vec<T>::Push */: Attribute InstParameterGroupList;`
     ///
@@ -34114,7 +34618,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 861:
+    /// Semantic action for production 875:
     ///
     /// `InstParameterGroupList /* Vec<T>::New */: ;`
     ///
@@ -34130,7 +34634,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 862:
+    /// Semantic action for production 876:
     ///
     /// `InstParameterItem: Identifier InstParameterItemOpt /* Option */;`
     ///
@@ -34159,7 +34663,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 863:
+    /// Semantic action for production 877:
     ///
     /// `InstParameterItemOpt /* Option<T>::Some */: Colon Expression;`
     ///
@@ -34184,7 +34688,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 864:
+    /// Semantic action for production 878:
     ///
     /// `InstParameterItemOpt /* Option<T>::None */: ;`
     ///
@@ -34196,7 +34700,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 865:
+    /// Semantic action for production 879:
     ///
     /// `InstPort: LParen InstPortOpt /* Option */ RParen;`
     ///
@@ -34223,7 +34727,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 866:
+    /// Semantic action for production 880:
     ///
     /// `InstPortOpt /* Option<T>::Some */: InstPortList;`
     ///
@@ -34239,7 +34743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 867:
+    /// Semantic action for production 881:
     ///
     /// `InstPortOpt /* Option<T>::None */: ;`
     ///
@@ -34251,7 +34755,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 868:
+    /// Semantic action for production 882:
     ///
     /// `InstPortList: InstPortGroup InstPortListList /* Vec */ InstPortListOpt /* Option */;`
     ///
@@ -34279,7 +34783,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 869:
+    /// Semantic action for production 883:
     ///
     /// `InstPortListList /* Vec<T>::Push */: Comma InstPortGroup InstPortListList;`
     ///
@@ -34306,7 +34810,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 870:
+    /// Semantic action for production 884:
     ///
     /// `InstPortListList /* Vec<T>::New */: ;`
     ///
@@ -34322,7 +34826,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 871:
+    /// Semantic action for production 885:
     ///
     /// `InstPortListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -34341,7 +34845,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 872:
+    /// Semantic action for production 886:
     ///
     /// `InstPortListOpt /* Option<T>::None */: ;`
     ///
@@ -34353,7 +34857,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 873:
+    /// Semantic action for production 887:
     ///
     /// `InstPortGroup: InstPortGroupList /* Vec */ InstPortGroupGroup;`
     ///
@@ -34379,7 +34883,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 874:
+    /// Semantic action for production 888:
     ///
     /// `InstPortGroupGroup: LBrace InstPortList RBrace;`
     ///
@@ -34409,7 +34913,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 875:
+    /// Semantic action for production 889:
     ///
     /// `InstPortGroupGroup: InstPortItem;`
     ///
@@ -34430,7 +34934,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 876:
+    /// Semantic action for production 890:
     ///
     /// `InstPortGroupList /* Vec<T>::Push */: Attribute InstPortGroupList;`
     ///
@@ -34454,7 +34958,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 877:
+    /// Semantic action for production 891:
     ///
     /// `InstPortGroupList /* Vec<T>::New */: ;`
     ///
@@ -34470,7 +34974,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 878:
+    /// Semantic action for production 892:
     ///
     /// `InstPortItem: Identifier InstPortItemOpt /* Option */;`
     ///
@@ -34494,7 +34998,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 879:
+    /// Semantic action for production 893:
     ///
     /// `InstPortItemOpt /* Option<T>::Some */: Colon Expression;`
     ///
@@ -34519,7 +35023,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 880:
+    /// Semantic action for production 894:
     ///
     /// `InstPortItemOpt /* Option<T>::None */: ;`
     ///
@@ -34531,7 +35035,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 881:
+    /// Semantic action for production 895:
     ///
     /// `WithParameter: Hash LParen WithParameterOpt /* Option */ RParen;`
     ///
@@ -34561,7 +35065,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 882:
+    /// Semantic action for production 896:
     ///
     /// `WithParameterOpt /* Option<T>::Some */: WithParameterList;`
     ///
@@ -34580,7 +35084,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 883:
+    /// Semantic action for production 897:
     ///
     /// `WithParameterOpt /* Option<T>::None */: ;`
     ///
@@ -34592,7 +35096,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 884:
+    /// Semantic action for production 898:
     ///
     /// `WithParameterList: WithParameterGroup WithParameterListList /* Vec */ WithParameterListOpt /* Option */;`
     ///
@@ -34630,7 +35134,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 885:
+    /// Semantic action for production 899:
     ///
     /// `WithParameterListList /* Vec<T>::Push */: Comma WithParameterGroup WithParameterListList;`
     ///
@@ -34665,7 +35169,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 886:
+    /// Semantic action for production 900:
     ///
     /// `WithParameterListList /* Vec<T>::New */: ;`
     ///
@@ -34681,7 +35185,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 887:
+    /// Semantic action for production 901:
     ///
     /// `WithParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -34700,7 +35204,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 888:
+    /// Semantic action for production 902:
     ///
     /// `WithParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -34712,7 +35216,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 889:
+    /// Semantic action for production 903:
     ///
     /// `WithParameterGroup: WithParameterGroupList /* Vec */ WithParameterGroupGroup;`
     ///
@@ -34750,7 +35254,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 890:
+    /// Semantic action for production 904:
     ///
     /// `WithParameterGroupGroup: LBrace WithParameterList RBrace;`
     ///
@@ -34783,7 +35287,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 891:
+    /// Semantic action for production 905:
     ///
     /// `WithParameterGroupGroup: WithParameterItem;`
     ///
@@ -34807,7 +35311,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 892:
+    /// Semantic action for production 906:
     ///
     /// `WithParameterGroupList /* Vec<T>::Push */: Attribute WithParameterGroupList;`
     ///
@@ -34838,7 +35342,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 893:
+    /// Semantic action for production 907:
     ///
     /// `WithParameterGroupList /* Vec<T>::New */: ;`
     ///
@@ -34854,7 +35358,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 894:
+    /// Semantic action for production 908:
     ///
     /// `WithParameterItem: WithParameterItemGroup Identifier Colon WithParameterItemGroup0 WithParameterItemOpt /* Option */;`
     ///
@@ -34902,7 +35406,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 895:
+    /// Semantic action for production 909:
     ///
     /// `WithParameterItemGroup0: ArrayType;`
     ///
@@ -34923,7 +35427,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 896:
+    /// Semantic action for production 910:
     ///
     /// `WithParameterItemGroup0: Type;`
     ///
@@ -34944,7 +35448,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 897:
+    /// Semantic action for production 911:
     ///
     /// `WithParameterItemGroup: Param;`
     ///
@@ -34965,7 +35469,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 898:
+    /// Semantic action for production 912:
     ///
     /// `WithParameterItemGroup: Const;`
     ///
@@ -34986,7 +35490,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 899:
+    /// Semantic action for production 913:
     ///
     /// `WithParameterItemOpt /* Option<T>::Some */: Equ Expression;`
     ///
@@ -35011,7 +35515,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 900:
+    /// Semantic action for production 914:
     ///
     /// `WithParameterItemOpt /* Option<T>::None */: ;`
     ///
@@ -35023,7 +35527,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 901:
+    /// Semantic action for production 915:
     ///
     /// `GenericBound: Type;`
     ///
@@ -35042,7 +35546,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 902:
+    /// Semantic action for production 916:
     ///
     /// `GenericBound: Inst ScopedIdentifier;`
     ///
@@ -35067,7 +35571,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 903:
+    /// Semantic action for production 917:
     ///
     /// `GenericBound: GenericProtoBound;`
     ///
@@ -35086,7 +35590,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 904:
+    /// Semantic action for production 918:
     ///
     /// `WithGenericParameter: ColonColonLAngle WithGenericParameterList RAngle;`
     ///
@@ -35122,7 +35626,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 905:
+    /// Semantic action for production 919:
     ///
     /// `WithGenericParameterList: WithGenericParameterItem WithGenericParameterListList /* Vec */ WithGenericParameterListOpt /* Option */;`
     ///
@@ -35168,7 +35672,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 906:
+    /// Semantic action for production 920:
     ///
     /// `WithGenericParameterListList /* Vec<T>::Push */: Comma WithGenericParameterItem WithGenericParameterListList;`
     ///
@@ -35207,7 +35711,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 907:
+    /// Semantic action for production 921:
     ///
     /// `WithGenericParameterListList /* Vec<T>::New */: ;`
     ///
@@ -35223,7 +35727,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 908:
+    /// Semantic action for production 922:
     ///
     /// `WithGenericParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -35242,7 +35746,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 909:
+    /// Semantic action for production 923:
     ///
     /// `WithGenericParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -35254,7 +35758,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 910:
+    /// Semantic action for production 924:
     ///
     /// `WithGenericParameterItem: Identifier Colon GenericBound WithGenericParameterItemOpt /* Option */;`
     ///
@@ -35293,7 +35797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 911:
+    /// Semantic action for production 925:
     ///
     /// `WithGenericParameterItemOpt /* Option<T>::Some */: Equ WithGenericArgumentItem;`
     ///
@@ -35323,7 +35827,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 912:
+    /// Semantic action for production 926:
     ///
     /// `WithGenericParameterItemOpt /* Option<T>::None */: ;`
     ///
@@ -35335,7 +35839,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 913:
+    /// Semantic action for production 927:
     ///
     /// `GenericProtoBound: ScopedIdentifier;`
     ///
@@ -35359,7 +35863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 914:
+    /// Semantic action for production 928:
     ///
     /// `GenericProtoBound: FixedType;`
     ///
@@ -35382,7 +35886,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 915:
+    /// Semantic action for production 929:
     ///
     /// `WithGenericArgument: ColonColonLAngle WithGenericArgumentOpt /* Option */ RAngle;`
     ///
@@ -35418,7 +35922,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 916:
+    /// Semantic action for production 930:
     ///
     /// `WithGenericArgumentOpt /* Option<T>::Some */: WithGenericArgumentList;`
     ///
@@ -35445,7 +35949,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 917:
+    /// Semantic action for production 931:
     ///
     /// `WithGenericArgumentOpt /* Option<T>::None */: ;`
     ///
@@ -35457,7 +35961,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 918:
+    /// Semantic action for production 932:
     ///
     /// `WithGenericArgumentList: WithGenericArgumentItem WithGenericArgumentListList /* Vec */ WithGenericArgumentListOpt /* Option */;`
     ///
@@ -35503,7 +36007,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 919:
+    /// Semantic action for production 933:
     ///
     /// `WithGenericArgumentListList /* Vec<T>::Push */: Comma WithGenericArgumentItem WithGenericArgumentListList;`
     ///
@@ -35542,7 +36046,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 920:
+    /// Semantic action for production 934:
     ///
     /// `WithGenericArgumentListList /* Vec<T>::New */: ;`
     ///
@@ -35558,7 +36062,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 921:
+    /// Semantic action for production 935:
     ///
     /// `WithGenericArgumentListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -35577,7 +36081,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 922:
+    /// Semantic action for production 936:
     ///
     /// `WithGenericArgumentListOpt /* Option<T>::None */: ;`
     ///
@@ -35589,7 +36093,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 923:
+    /// Semantic action for production 937:
     ///
     /// `WithGenericArgumentItem: GenericArgIdentifier;`
     ///
@@ -35617,7 +36121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 924:
+    /// Semantic action for production 938:
     ///
     /// `WithGenericArgumentItem: FixedType;`
     ///
@@ -35641,7 +36145,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 925:
+    /// Semantic action for production 939:
     ///
     /// `WithGenericArgumentItem: Number;`
     ///
@@ -35665,7 +36169,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 926:
+    /// Semantic action for production 940:
     ///
     /// `WithGenericArgumentItem: BooleanLiteral;`
     ///
@@ -35689,7 +36193,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 927:
+    /// Semantic action for production 941:
     ///
     /// `PortDeclaration: LParen PortDeclarationOpt /* Option */ RParen;`
     ///
@@ -35718,7 +36222,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 928:
+    /// Semantic action for production 942:
     ///
     /// `PortDeclarationOpt /* Option<T>::Some */: PortDeclarationList;`
     ///
@@ -35738,7 +36242,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 929:
+    /// Semantic action for production 943:
     ///
     /// `PortDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -35750,7 +36254,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 930:
+    /// Semantic action for production 944:
     ///
     /// `PortDeclarationList: PortDeclarationGroup PortDeclarationListList /* Vec */ PortDeclarationListOpt /* Option */;`
     ///
@@ -35792,7 +36296,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 931:
+    /// Semantic action for production 945:
     ///
     /// `PortDeclarationListList /* Vec<T>::Push */: Comma PortDeclarationGroup PortDeclarationListList;`
     ///
@@ -35827,7 +36331,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 932:
+    /// Semantic action for production 946:
     ///
     /// `PortDeclarationListList /* Vec<T>::New */: ;`
     ///
@@ -35843,7 +36347,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 933:
+    /// Semantic action for production 947:
     ///
     /// `PortDeclarationListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -35862,7 +36366,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 934:
+    /// Semantic action for production 948:
     ///
     /// `PortDeclarationListOpt /* Option<T>::None */: ;`
     ///
@@ -35874,7 +36378,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 935:
+    /// Semantic action for production 949:
     ///
     /// `PortDeclarationGroup: PortDeclarationGroupList /* Vec */ PortDeclarationGroupGroup;`
     ///
@@ -35912,7 +36416,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 936:
+    /// Semantic action for production 950:
     ///
     /// `PortDeclarationGroupGroup: LBrace PortDeclarationList RBrace;`
     ///
@@ -35946,7 +36450,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 937:
+    /// Semantic action for production 951:
     ///
     /// `PortDeclarationGroupGroup: PortDeclarationItem;`
     ///
@@ -35971,7 +36475,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 938:
+    /// Semantic action for production 952:
     ///
     /// `PortDeclarationGroupList /* Vec<T>::Push */: Attribute PortDeclarationGroupList;`
     ///
@@ -36002,7 +36506,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 939:
+    /// Semantic action for production 953:
     ///
     /// `PortDeclarationGroupList /* Vec<T>::New */: ;`
     ///
@@ -36018,7 +36522,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 940:
+    /// Semantic action for production 954:
     ///
     /// `PortDeclarationItem: Identifier Colon PortDeclarationItemGroup;`
     ///
@@ -36054,7 +36558,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 941:
+    /// Semantic action for production 955:
     ///
     /// `PortDeclarationItemGroup: PortTypeConcrete;`
     ///
@@ -36078,7 +36582,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 942:
+    /// Semantic action for production 956:
     ///
     /// `PortDeclarationItemGroup: PortTypeAbstract;`
     ///
@@ -36102,7 +36606,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 943:
+    /// Semantic action for production 957:
     ///
     /// `PortTypeConcrete: Direction PortTypeConcreteOpt /* Option */ ArrayType PortTypeConcreteOpt0 /* Option */;`
     ///
@@ -36135,7 +36639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 944:
+    /// Semantic action for production 958:
     ///
     /// `PortTypeConcreteOpt0 /* Option<T>::Some */: Equ PortDefaultValue;`
     ///
@@ -36160,7 +36664,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 945:
+    /// Semantic action for production 959:
     ///
     /// `PortTypeConcreteOpt0 /* Option<T>::None */: ;`
     ///
@@ -36172,7 +36676,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 946:
+    /// Semantic action for production 960:
     ///
     /// `PortTypeConcreteOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -36191,7 +36695,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 947:
+    /// Semantic action for production 961:
     ///
     /// `PortTypeConcreteOpt /* Option<T>::None */: ;`
     ///
@@ -36203,7 +36707,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 948:
+    /// Semantic action for production 962:
     ///
     /// `PortDefaultValue: Expression;`
     ///
@@ -36222,7 +36726,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 949:
+    /// Semantic action for production 963:
     ///
     /// `PortTypeAbstract: PortTypeAbstractOpt /* Option */ Interface PortTypeAbstractOpt0 /* Option */ PortTypeAbstractOpt1 /* Option */;`
     ///
@@ -36256,7 +36760,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 950:
+    /// Semantic action for production 964:
     ///
     /// `PortTypeAbstractOpt1 /* Option<T>::Some */: Array;`
     ///
@@ -36275,7 +36779,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 951:
+    /// Semantic action for production 965:
     ///
     /// `PortTypeAbstractOpt1 /* Option<T>::None */: ;`
     ///
@@ -36287,7 +36791,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 952:
+    /// Semantic action for production 966:
     ///
     /// `PortTypeAbstractOpt0 /* Option<T>::Some */: ColonColon Identifier;`
     ///
@@ -36312,7 +36816,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 953:
+    /// Semantic action for production 967:
     ///
     /// `PortTypeAbstractOpt0 /* Option<T>::None */: ;`
     ///
@@ -36324,7 +36828,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 954:
+    /// Semantic action for production 968:
     ///
     /// `PortTypeAbstractOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -36343,7 +36847,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 955:
+    /// Semantic action for production 969:
     ///
     /// `PortTypeAbstractOpt /* Option<T>::None */: ;`
     ///
@@ -36355,7 +36859,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 956:
+    /// Semantic action for production 970:
     ///
     /// `Direction: Input;`
     ///
@@ -36374,7 +36878,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 957:
+    /// Semantic action for production 971:
     ///
     /// `Direction: Output;`
     ///
@@ -36393,7 +36897,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 958:
+    /// Semantic action for production 972:
     ///
     /// `Direction: Inout;`
     ///
@@ -36412,7 +36916,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 959:
+    /// Semantic action for production 973:
     ///
     /// `Direction: Modport;`
     ///
@@ -36431,7 +36935,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 960:
+    /// Semantic action for production 974:
     ///
     /// `Direction: Import;`
     ///
@@ -36450,7 +36954,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 961:
+    /// Semantic action for production 975:
     ///
     /// `FunctionDeclaration: Function Identifier FunctionDeclarationOpt /* Option */ FunctionDeclarationOpt0 /* Option */ FunctionDeclarationOpt1 /* Option */ StatementBlock;`
     ///
@@ -36505,7 +37009,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 962:
+    /// Semantic action for production 976:
     ///
     /// `FunctionDeclarationOpt1 /* Option<T>::Some */: MinusGT ScalarType;`
     ///
@@ -36530,7 +37034,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 963:
+    /// Semantic action for production 977:
     ///
     /// `FunctionDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -36542,7 +37046,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 964:
+    /// Semantic action for production 978:
     ///
     /// `FunctionDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -36561,7 +37065,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 965:
+    /// Semantic action for production 979:
     ///
     /// `FunctionDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -36573,7 +37077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 966:
+    /// Semantic action for production 980:
     ///
     /// `FunctionDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -36596,7 +37100,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 967:
+    /// Semantic action for production 981:
     ///
     /// `FunctionDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -36608,7 +37112,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 968:
+    /// Semantic action for production 982:
     ///
     /// `ImportDeclaration: Import ScopedIdentifier ImportDeclarationOpt /* Option */ Semicolon;`
     ///
@@ -36643,7 +37147,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 969:
+    /// Semantic action for production 983:
     ///
     /// `ImportDeclarationOpt /* Option<T>::Some */: ColonColon ImportDeclarationOptGroup;`
     ///
@@ -36673,7 +37177,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 970:
+    /// Semantic action for production 984:
     ///
     /// `ImportDeclarationOptGroup: Star;`
     ///
@@ -36694,7 +37198,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 971:
+    /// Semantic action for production 985:
     ///
     /// `ImportDeclarationOptGroup: MultipleImportList;`
     ///
@@ -36719,7 +37223,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 972:
+    /// Semantic action for production 986:
     ///
     /// `ImportDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -36731,7 +37235,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 973:
+    /// Semantic action for production 987:
     ///
     /// `MultipleImportList: LBrace MultipleImportItem MultipleImportListList /* Vec */ MultipleImportListOpt /* Option */ RBrace;`
     ///
@@ -36779,7 +37283,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 974:
+    /// Semantic action for production 988:
     ///
     /// `MultipleImportListList /* Vec<T>::Push */: Comma MultipleImportItem MultipleImportListList;`
     ///
@@ -36814,7 +37318,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 975:
+    /// Semantic action for production 989:
     ///
     /// `MultipleImportListList /* Vec<T>::New */: ;`
     ///
@@ -36830,7 +37334,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 976:
+    /// Semantic action for production 990:
     ///
     /// `MultipleImportListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -36849,7 +37353,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 977:
+    /// Semantic action for production 991:
     ///
     /// `MultipleImportListOpt /* Option<T>::None */: ;`
     ///
@@ -36861,7 +37365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 978:
+    /// Semantic action for production 992:
     ///
     /// `MultipleImportItem: Identifier;`
     ///
@@ -36883,7 +37387,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 979:
+    /// Semantic action for production 993:
     ///
     /// `MixinDeclaration: Mixin ScopedIdentifier Semicolon;`
     ///
@@ -36911,7 +37415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 980:
+    /// Semantic action for production 994:
     ///
     /// `UnsafeBlock: Unsafe LParen Identifier RParen LBrace UnsafeBlockList /* Vec */ RBrace;`
     ///
@@ -36951,7 +37455,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 981:
+    /// Semantic action for production 995:
     ///
     /// `UnsafeBlockList /* Vec<T>::Push */: GenerateGroup UnsafeBlockList;`
     ///
@@ -36974,7 +37478,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 982:
+    /// Semantic action for production 996:
     ///
     /// `UnsafeBlockList /* Vec<T>::New */: ;`
     ///
@@ -36987,7 +37491,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 983:
+    /// Semantic action for production 997:
     ///
     /// `ModuleDeclaration: Module Identifier ModuleDeclarationOpt /* Option */ ModuleDeclarationOpt0 /* Option */ ModuleDeclarationOpt1 /* Option */ ModuleDeclarationOpt2 /* Option */ LBrace ModuleDeclarationList /* Vec */ RBrace;`
     ///
@@ -37057,7 +37561,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 984:
+    /// Semantic action for production 998:
     ///
     /// `ModuleDeclarationList /* Vec<T>::Push */: ModuleGroup ModuleDeclarationList;`
     ///
@@ -37088,7 +37592,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 985:
+    /// Semantic action for production 999:
     ///
     /// `ModuleDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -37104,7 +37608,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 986:
+    /// Semantic action for production 1000:
     ///
     /// `ModuleDeclarationOpt2 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -37123,7 +37627,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 987:
+    /// Semantic action for production 1001:
     ///
     /// `ModuleDeclarationOpt2 /* Option<T>::None */: ;`
     ///
@@ -37135,7 +37639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 988:
+    /// Semantic action for production 1002:
     ///
     /// `ModuleDeclarationOpt1 /* Option<T>::Some */: WithParameter;`
     ///
@@ -37154,7 +37658,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 989:
+    /// Semantic action for production 1003:
     ///
     /// `ModuleDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -37166,7 +37670,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 990:
+    /// Semantic action for production 1004:
     ///
     /// `ModuleDeclarationOpt0 /* Option<T>::Some */: For ScopedIdentifier;`
     ///
@@ -37191,7 +37695,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 991:
+    /// Semantic action for production 1005:
     ///
     /// `ModuleDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -37203,7 +37707,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 992:
+    /// Semantic action for production 1006:
     ///
     /// `ModuleDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -37226,7 +37730,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 993:
+    /// Semantic action for production 1007:
     ///
     /// `ModuleDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -37238,7 +37742,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 994:
+    /// Semantic action for production 1008:
     ///
     /// `ModuleGroup: ModuleGroupList /* Vec */ ModuleGroupGroup;`
     ///
@@ -37263,7 +37767,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 995:
+    /// Semantic action for production 1009:
     ///
     /// `ModuleGroupGroup: LBrace ModuleGroupGroupList /* Vec */ RBrace;`
     ///
@@ -37294,7 +37798,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 996:
+    /// Semantic action for production 1010:
     ///
     /// `ModuleGroupGroupList /* Vec<T>::Push */: ModuleGroup ModuleGroupGroupList;`
     ///
@@ -37321,7 +37825,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 997:
+    /// Semantic action for production 1011:
     ///
     /// `ModuleGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -37337,7 +37841,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 998:
+    /// Semantic action for production 1012:
     ///
     /// `ModuleGroupGroup: ModuleItem;`
     ///
@@ -37357,7 +37861,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 999:
+    /// Semantic action for production 1013:
     ///
     /// `ModuleGroupList /* Vec<T>::Push */: Attribute ModuleGroupList;`
     ///
@@ -37380,7 +37884,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1000:
+    /// Semantic action for production 1014:
     ///
     /// `ModuleGroupList /* Vec<T>::New */: ;`
     ///
@@ -37393,7 +37897,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1001:
+    /// Semantic action for production 1015:
     ///
     /// `ModuleItem: GenerateItem;`
     ///
@@ -37411,7 +37915,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1002:
+    /// Semantic action for production 1016:
     ///
     /// `InterfaceDeclaration: Interface Identifier InterfaceDeclarationOpt /* Option */ InterfaceDeclarationOpt0 /* Option */ InterfaceDeclarationOpt1 /* Option */ LBrace InterfaceDeclarationList /* Vec */ RBrace;`
     ///
@@ -37477,7 +37981,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1003:
+    /// Semantic action for production 1017:
     ///
     /// `InterfaceDeclarationList /* Vec<T>::Push */: InterfaceGroup InterfaceDeclarationList;`
     ///
@@ -37508,7 +38012,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1004:
+    /// Semantic action for production 1018:
     ///
     /// `InterfaceDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -37524,7 +38028,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1005:
+    /// Semantic action for production 1019:
     ///
     /// `InterfaceDeclarationOpt1 /* Option<T>::Some */: WithParameter;`
     ///
@@ -37543,7 +38047,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1006:
+    /// Semantic action for production 1020:
     ///
     /// `InterfaceDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -37555,7 +38059,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1007:
+    /// Semantic action for production 1021:
     ///
     /// `InterfaceDeclarationOpt0 /* Option<T>::Some */: For ScopedIdentifier;`
     ///
@@ -37580,7 +38084,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1008:
+    /// Semantic action for production 1022:
     ///
     /// `InterfaceDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -37592,7 +38096,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1009:
+    /// Semantic action for production 1023:
     ///
     /// `InterfaceDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -37615,7 +38119,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1010:
+    /// Semantic action for production 1024:
     ///
     /// `InterfaceDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -37627,7 +38131,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1011:
+    /// Semantic action for production 1025:
     ///
     /// `InterfaceGroup: InterfaceGroupList /* Vec */ InterfaceGroupGroup;`
     ///
@@ -37653,7 +38157,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1012:
+    /// Semantic action for production 1026:
     ///
     /// `InterfaceGroupGroup: LBrace InterfaceGroupGroupList /* Vec */ RBrace;`
     ///
@@ -37689,7 +38193,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1013:
+    /// Semantic action for production 1027:
     ///
     /// `InterfaceGroupGroupList /* Vec<T>::Push */: InterfaceGroup InterfaceGroupGroupList;`
     ///
@@ -37720,7 +38224,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1014:
+    /// Semantic action for production 1028:
     ///
     /// `InterfaceGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -37736,7 +38240,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1015:
+    /// Semantic action for production 1029:
     ///
     /// `InterfaceGroupGroup: InterfaceItem;`
     ///
@@ -37757,7 +38261,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1016:
+    /// Semantic action for production 1030:
     ///
     /// `InterfaceGroupList /* Vec<T>::Push */: Attribute InterfaceGroupList;`
     ///
@@ -37781,7 +38285,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1017:
+    /// Semantic action for production 1031:
     ///
     /// `InterfaceGroupList /* Vec<T>::New */: ;`
     ///
@@ -37797,7 +38301,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1018:
+    /// Semantic action for production 1032:
     ///
     /// `InterfaceItem: GenerateItem;`
     ///
@@ -37816,7 +38320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1019:
+    /// Semantic action for production 1033:
     ///
     /// `InterfaceItem: MixinDeclaration;`
     ///
@@ -37835,7 +38339,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1020:
+    /// Semantic action for production 1034:
     ///
     /// `InterfaceItem: ModportDeclaration;`
     ///
@@ -37854,7 +38358,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1021:
+    /// Semantic action for production 1035:
     ///
     /// `GenerateIfDeclaration: If Expression GenerateNamedBlock GenerateIfDeclarationList /* Vec */ GenerateIfDeclarationOpt /* Option */;`
     ///
@@ -37902,7 +38406,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1022:
+    /// Semantic action for production 1036:
     ///
     /// `GenerateIfDeclarationList /* Vec<T>::Push */: Else If Expression GenerateOptionalNamedBlock GenerateIfDeclarationList;`
     ///
@@ -37947,7 +38451,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1023:
+    /// Semantic action for production 1037:
     ///
     /// `GenerateIfDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -37963,7 +38467,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1024:
+    /// Semantic action for production 1038:
     ///
     /// `GenerateIfDeclarationOpt /* Option<T>::Some */: Else GenerateOptionalNamedBlock;`
     ///
@@ -37993,7 +38497,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1025:
+    /// Semantic action for production 1039:
     ///
     /// `GenerateIfDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -38005,7 +38509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1026:
+    /// Semantic action for production 1040:
     ///
     /// `GenerateForDeclaration: For Identifier In GenerateForDeclarationOpt /* Option */ Range GenerateForDeclarationOpt0 /* Option */ GenerateNamedBlock;`
     ///
@@ -38059,7 +38563,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1027:
+    /// Semantic action for production 1041:
     ///
     /// `GenerateForDeclarationOpt0 /* Option<T>::Some */: Step AssignmentOperator Expression;`
     ///
@@ -38087,7 +38591,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1028:
+    /// Semantic action for production 1042:
     ///
     /// `GenerateForDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -38099,7 +38603,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1029:
+    /// Semantic action for production 1043:
     ///
     /// `GenerateForDeclarationOpt /* Option<T>::Some */: Rev;`
     ///
@@ -38116,7 +38620,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1030:
+    /// Semantic action for production 1044:
     ///
     /// `GenerateForDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -38128,7 +38632,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1031:
+    /// Semantic action for production 1045:
     ///
     /// `GenerateBlockDeclaration: GenerateNamedBlock;`
     ///
@@ -38154,7 +38658,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1032:
+    /// Semantic action for production 1046:
     ///
     /// `GenerateNamedBlock: Colon Identifier LBrace GenerateNamedBlockList /* Vec */ RBrace;`
     ///
@@ -38196,7 +38700,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1033:
+    /// Semantic action for production 1047:
     ///
     /// `GenerateNamedBlockList /* Vec<T>::Push */: GenerateGroup GenerateNamedBlockList;`
     ///
@@ -38227,7 +38731,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1034:
+    /// Semantic action for production 1048:
     ///
     /// `GenerateNamedBlockList /* Vec<T>::New */: ;`
     ///
@@ -38243,7 +38747,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1035:
+    /// Semantic action for production 1049:
     ///
     /// `GenerateOptionalNamedBlock: GenerateOptionalNamedBlockOpt /* Option */ LBrace GenerateOptionalNamedBlockList /* Vec */ RBrace;`
     ///
@@ -38287,7 +38791,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1036:
+    /// Semantic action for production 1050:
     ///
     /// `GenerateOptionalNamedBlockList /* Vec<T>::Push */: GenerateGroup GenerateOptionalNamedBlockList;`
     ///
@@ -38318,7 +38822,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1037:
+    /// Semantic action for production 1051:
     ///
     /// `GenerateOptionalNamedBlockList /* Vec<T>::New */: ;`
     ///
@@ -38334,7 +38838,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1038:
+    /// Semantic action for production 1052:
     ///
     /// `GenerateOptionalNamedBlockOpt /* Option<T>::Some */: Colon Identifier;`
     ///
@@ -38359,7 +38863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1039:
+    /// Semantic action for production 1053:
     ///
     /// `GenerateOptionalNamedBlockOpt /* Option<T>::None */: ;`
     ///
@@ -38371,7 +38875,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1040:
+    /// Semantic action for production 1054:
     ///
     /// `GenerateGroup: GenerateGroupList /* Vec */ GenerateGroupGroup;`
     ///
@@ -38397,7 +38901,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1041:
+    /// Semantic action for production 1055:
     ///
     /// `GenerateGroupGroup: LBrace GenerateGroupGroupList /* Vec */ RBrace;`
     ///
@@ -38432,7 +38936,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1042:
+    /// Semantic action for production 1056:
     ///
     /// `GenerateGroupGroupList /* Vec<T>::Push */: GenerateGroup GenerateGroupGroupList;`
     ///
@@ -38463,7 +38967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1043:
+    /// Semantic action for production 1057:
     ///
     /// `GenerateGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -38479,7 +38983,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1044:
+    /// Semantic action for production 1058:
     ///
     /// `GenerateGroupGroup: GenerateItem;`
     ///
@@ -38500,7 +39004,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1045:
+    /// Semantic action for production 1059:
     ///
     /// `GenerateGroupList /* Vec<T>::Push */: Attribute GenerateGroupList;`
     ///
@@ -38524,7 +39028,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1046:
+    /// Semantic action for production 1060:
     ///
     /// `GenerateGroupList /* Vec<T>::New */: ;`
     ///
@@ -38540,7 +39044,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1047:
+    /// Semantic action for production 1061:
     ///
     /// `GenerateItem: LetDeclaration;`
     ///
@@ -38559,7 +39063,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1048:
+    /// Semantic action for production 1062:
     ///
     /// `GenerateItem: VarDeclaration;`
     ///
@@ -38578,7 +39082,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1049:
+    /// Semantic action for production 1063:
     ///
     /// `GenerateItem: InstDeclaration;`
     ///
@@ -38597,7 +39101,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1050:
+    /// Semantic action for production 1064:
     ///
     /// `GenerateItem: BindDeclaration;`
     ///
@@ -38616,7 +39120,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1051:
+    /// Semantic action for production 1065:
     ///
     /// `GenerateItem: ConstDeclaration;`
     ///
@@ -38635,7 +39139,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1052:
+    /// Semantic action for production 1066:
     ///
     /// `GenerateItem: GenDeclaration;`
     ///
@@ -38654,7 +39158,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1053:
+    /// Semantic action for production 1067:
     ///
     /// `GenerateItem: AlwaysFfDeclaration;`
     ///
@@ -38674,7 +39178,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1054:
+    /// Semantic action for production 1068:
     ///
     /// `GenerateItem: AlwaysCombDeclaration;`
     ///
@@ -38698,7 +39202,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1055:
+    /// Semantic action for production 1069:
     ///
     /// `GenerateItem: AssignDeclaration;`
     ///
@@ -38717,7 +39221,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1056:
+    /// Semantic action for production 1070:
     ///
     /// `GenerateItem: ConnectDeclaration;`
     ///
@@ -38736,7 +39240,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1057:
+    /// Semantic action for production 1071:
     ///
     /// `GenerateItem: FunctionDeclaration;`
     ///
@@ -38756,7 +39260,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1058:
+    /// Semantic action for production 1072:
     ///
     /// `GenerateItem: GenerateIfDeclaration;`
     ///
@@ -38780,7 +39284,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1059:
+    /// Semantic action for production 1073:
     ///
     /// `GenerateItem: GenerateForDeclaration;`
     ///
@@ -38804,7 +39308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1060:
+    /// Semantic action for production 1074:
     ///
     /// `GenerateItem: GenerateBlockDeclaration;`
     ///
@@ -38828,7 +39332,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1061:
+    /// Semantic action for production 1075:
     ///
     /// `GenerateItem: TypeDefDeclaration;`
     ///
@@ -38848,7 +39352,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1062:
+    /// Semantic action for production 1076:
     ///
     /// `GenerateItem: EnumDeclaration;`
     ///
@@ -38867,7 +39371,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1063:
+    /// Semantic action for production 1077:
     ///
     /// `GenerateItem: StructUnionDeclaration;`
     ///
@@ -38891,7 +39395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1064:
+    /// Semantic action for production 1078:
     ///
     /// `GenerateItem: ImportDeclaration;`
     ///
@@ -38910,7 +39414,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1065:
+    /// Semantic action for production 1079:
     ///
     /// `GenerateItem: AliasDeclaration;`
     ///
@@ -38929,7 +39433,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1066:
+    /// Semantic action for production 1080:
     ///
     /// `GenerateItem: InitialDeclaration;`
     ///
@@ -38948,7 +39452,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1067:
+    /// Semantic action for production 1081:
     ///
     /// `GenerateItem: FinalDeclaration;`
     ///
@@ -38967,7 +39471,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1068:
+    /// Semantic action for production 1082:
     ///
     /// `GenerateItem: UnsafeBlock;`
     ///
@@ -38986,7 +39490,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1069:
+    /// Semantic action for production 1083:
     ///
     /// `GenerateItem: EmbedDeclaration;`
     ///
@@ -39005,7 +39509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1070:
+    /// Semantic action for production 1084:
     ///
     /// `PackageDeclaration: Package Identifier PackageDeclarationOpt /* Option */ PackageDeclarationOpt0 /* Option */ LBrace PackageDeclarationList /* Vec */ RBrace;`
     ///
@@ -39063,7 +39567,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1071:
+    /// Semantic action for production 1085:
     ///
     /// `PackageDeclarationList /* Vec<T>::Push */: PackageGroup PackageDeclarationList;`
     ///
@@ -39094,7 +39598,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1072:
+    /// Semantic action for production 1086:
     ///
     /// `PackageDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -39110,7 +39614,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1073:
+    /// Semantic action for production 1087:
     ///
     /// `PackageDeclarationOpt0 /* Option<T>::Some */: For ScopedIdentifier;`
     ///
@@ -39135,7 +39639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1074:
+    /// Semantic action for production 1088:
     ///
     /// `PackageDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -39147,7 +39651,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1075:
+    /// Semantic action for production 1089:
     ///
     /// `PackageDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -39170,7 +39674,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1076:
+    /// Semantic action for production 1090:
     ///
     /// `PackageDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -39182,7 +39686,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1077:
+    /// Semantic action for production 1091:
     ///
     /// `PackageGroup: PackageGroupList /* Vec */ PackageGroupGroup;`
     ///
@@ -39207,7 +39711,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1078:
+    /// Semantic action for production 1092:
     ///
     /// `PackageGroupGroup: LBrace PackageGroupGroupList /* Vec */ RBrace;`
     ///
@@ -39242,7 +39746,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1079:
+    /// Semantic action for production 1093:
     ///
     /// `PackageGroupGroupList /* Vec<T>::Push */: PackageGroup PackageGroupGroupList;`
     ///
@@ -39273,7 +39777,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1080:
+    /// Semantic action for production 1094:
     ///
     /// `PackageGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -39289,7 +39793,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1081:
+    /// Semantic action for production 1095:
     ///
     /// `PackageGroupGroup: PackageItem;`
     ///
@@ -39310,7 +39814,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1082:
+    /// Semantic action for production 1096:
     ///
     /// `PackageGroupList /* Vec<T>::Push */: Attribute PackageGroupList;`
     ///
@@ -39333,7 +39837,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1083:
+    /// Semantic action for production 1097:
     ///
     /// `PackageGroupList /* Vec<T>::New */: ;`
     ///
@@ -39349,7 +39853,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1084:
+    /// Semantic action for production 1098:
     ///
     /// `PackageItem: ConstDeclaration;`
     ///
@@ -39368,7 +39872,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1085:
+    /// Semantic action for production 1099:
     ///
     /// `PackageItem: GenDeclaration;`
     ///
@@ -39387,7 +39891,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1086:
+    /// Semantic action for production 1100:
     ///
     /// `PackageItem: TypeDefDeclaration;`
     ///
@@ -39407,7 +39911,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1087:
+    /// Semantic action for production 1101:
     ///
     /// `PackageItem: EnumDeclaration;`
     ///
@@ -39426,7 +39930,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1088:
+    /// Semantic action for production 1102:
     ///
     /// `PackageItem: StructUnionDeclaration;`
     ///
@@ -39450,7 +39954,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1089:
+    /// Semantic action for production 1103:
     ///
     /// `PackageItem: FunctionDeclaration;`
     ///
@@ -39470,7 +39974,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1090:
+    /// Semantic action for production 1104:
     ///
     /// `PackageItem: ImportDeclaration;`
     ///
@@ -39489,7 +39993,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1091:
+    /// Semantic action for production 1105:
     ///
     /// `PackageItem: AliasDeclaration;`
     ///
@@ -39508,7 +40012,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1092:
+    /// Semantic action for production 1106:
     ///
     /// `PackageItem: EmbedDeclaration;`
     ///
@@ -39527,7 +40031,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1093:
+    /// Semantic action for production 1107:
     ///
     /// `AliasDeclaration: Alias AliasDeclarationGroup Identifier Equ ScopedIdentifier Semicolon;`
     ///
@@ -39569,7 +40073,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1094:
+    /// Semantic action for production 1108:
     ///
     /// `AliasDeclarationGroup: Module;`
     ///
@@ -39590,7 +40094,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1095:
+    /// Semantic action for production 1109:
     ///
     /// `AliasDeclarationGroup: Interface;`
     ///
@@ -39611,7 +40115,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1096:
+    /// Semantic action for production 1110:
     ///
     /// `AliasDeclarationGroup: Package;`
     ///
@@ -39632,7 +40136,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1097:
+    /// Semantic action for production 1111:
     ///
     /// `ProtoDeclaration: Proto ProtoDeclarationGroup;`
     ///
@@ -39662,7 +40166,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1098:
+    /// Semantic action for production 1112:
     ///
     /// `ProtoDeclarationGroup: ProtoModuleDeclaration;`
     ///
@@ -39691,7 +40195,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1099:
+    /// Semantic action for production 1113:
     ///
     /// `ProtoDeclarationGroup: ProtoInterfaceDeclaration;`
     ///
@@ -39720,7 +40224,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1100:
+    /// Semantic action for production 1114:
     ///
     /// `ProtoDeclarationGroup: ProtoPackageDeclaration;`
     ///
@@ -39749,7 +40253,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1101:
+    /// Semantic action for production 1115:
     ///
     /// `ProtoModuleDeclaration: Module Identifier ProtoModuleDeclarationOpt /* Option */ ProtoModuleDeclarationOpt0 /* Option */ Semicolon;`
     ///
@@ -39796,7 +40300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1102:
+    /// Semantic action for production 1116:
     ///
     /// `ProtoModuleDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -39818,7 +40322,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1103:
+    /// Semantic action for production 1117:
     ///
     /// `ProtoModuleDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -39830,7 +40334,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1104:
+    /// Semantic action for production 1118:
     ///
     /// `ProtoModuleDeclarationOpt /* Option<T>::Some */: WithParameter;`
     ///
@@ -39852,7 +40356,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1105:
+    /// Semantic action for production 1119:
     ///
     /// `ProtoModuleDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -39864,7 +40368,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1106:
+    /// Semantic action for production 1120:
     ///
     /// `ProtoInterfaceDeclaration: Interface Identifier ProtoInterfaceDeclarationOpt /* Option */ LBrace ProtoInterfaceDeclarationList /* Vec */ RBrace;`
     ///
@@ -39914,7 +40418,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1107:
+    /// Semantic action for production 1121:
     ///
     /// `ProtoInterfaceDeclarationList /* Vec<T>::Push */: ProtoInterfaceItem ProtoInterfaceDeclarationList;`
     ///
@@ -39946,7 +40450,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1108:
+    /// Semantic action for production 1122:
     ///
     /// `ProtoInterfaceDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -39962,7 +40466,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1109:
+    /// Semantic action for production 1123:
     ///
     /// `ProtoInterfaceDeclarationOpt /* Option<T>::Some */: WithParameter;`
     ///
@@ -39984,7 +40488,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1110:
+    /// Semantic action for production 1124:
     ///
     /// `ProtoInterfaceDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -39996,7 +40500,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1111:
+    /// Semantic action for production 1125:
     ///
     /// `ProtoInterfaceItem: VarDeclaration;`
     ///
@@ -40020,7 +40524,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1112:
+    /// Semantic action for production 1126:
     ///
     /// `ProtoInterfaceItem: ProtoConstDeclaration;`
     ///
@@ -40052,7 +40556,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1113:
+    /// Semantic action for production 1127:
     ///
     /// `ProtoInterfaceItem: ProtoFunctionDeclaration;`
     ///
@@ -40084,7 +40588,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1114:
+    /// Semantic action for production 1128:
     ///
     /// `ProtoInterfaceItem: ProtoTypeDefDeclaration;`
     ///
@@ -40116,7 +40620,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1115:
+    /// Semantic action for production 1129:
     ///
     /// `ProtoInterfaceItem: ProtoAliasDeclaration;`
     ///
@@ -40148,7 +40652,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1116:
+    /// Semantic action for production 1130:
     ///
     /// `ProtoInterfaceItem: ModportDeclaration;`
     ///
@@ -40172,7 +40676,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1117:
+    /// Semantic action for production 1131:
     ///
     /// `ProtoInterfaceItem: ImportDeclaration;`
     ///
@@ -40196,7 +40700,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1118:
+    /// Semantic action for production 1132:
     ///
     /// `ProtoPackageDeclaration: Package Identifier LBrace ProtoPackageDeclarationList /* Vec */ RBrace;`
     ///
@@ -40238,7 +40742,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1119:
+    /// Semantic action for production 1133:
     ///
     /// `ProtoPackageDeclarationList /* Vec<T>::Push */: ProtoPacakgeItem ProtoPackageDeclarationList;`
     ///
@@ -40269,7 +40773,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1120:
+    /// Semantic action for production 1134:
     ///
     /// `ProtoPackageDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -40285,7 +40789,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1121:
+    /// Semantic action for production 1135:
     ///
     /// `ProtoPacakgeItem: ProtoConstDeclaration;`
     ///
@@ -40314,7 +40818,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1122:
+    /// Semantic action for production 1136:
     ///
     /// `ProtoPacakgeItem: ProtoTypeDefDeclaration;`
     ///
@@ -40346,7 +40850,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1123:
+    /// Semantic action for production 1137:
     ///
     /// `ProtoPacakgeItem: EnumDeclaration;`
     ///
@@ -40370,7 +40874,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1124:
+    /// Semantic action for production 1138:
     ///
     /// `ProtoPacakgeItem: StructUnionDeclaration;`
     ///
@@ -40402,7 +40906,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1125:
+    /// Semantic action for production 1139:
     ///
     /// `ProtoPacakgeItem: ProtoFunctionDeclaration;`
     ///
@@ -40434,7 +40938,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1126:
+    /// Semantic action for production 1140:
     ///
     /// `ProtoPacakgeItem: ProtoAliasDeclaration;`
     ///
@@ -40463,7 +40967,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1127:
+    /// Semantic action for production 1141:
     ///
     /// `ProtoPacakgeItem: ImportDeclaration;`
     ///
@@ -40487,7 +40991,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1128:
+    /// Semantic action for production 1142:
     ///
     /// `ProtoConstDeclaration: Const Identifier Colon ProtoConstDeclarationGroup Semicolon;`
     ///
@@ -40529,7 +41033,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1129:
+    /// Semantic action for production 1143:
     ///
     /// `ProtoConstDeclarationGroup: ArrayType;`
     ///
@@ -40550,7 +41054,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1130:
+    /// Semantic action for production 1144:
     ///
     /// `ProtoConstDeclarationGroup: Type;`
     ///
@@ -40571,7 +41075,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1131:
+    /// Semantic action for production 1145:
     ///
     /// `ProtoTypeDefDeclaration: Type Identifier ProtoTypeDefDeclarationOpt /* Option */ Semicolon;`
     ///
@@ -40610,7 +41114,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1132:
+    /// Semantic action for production 1146:
     ///
     /// `ProtoTypeDefDeclarationOpt /* Option<T>::Some */: Equ ArrayType;`
     ///
@@ -40635,7 +41139,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1133:
+    /// Semantic action for production 1147:
     ///
     /// `ProtoTypeDefDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -40647,7 +41151,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1134:
+    /// Semantic action for production 1148:
     ///
     /// `ProtoFunctionDeclaration: Function Identifier ProtoFunctionDeclarationOpt /* Option */ ProtoFunctionDeclarationOpt0 /* Option */ ProtoFunctionDeclarationOpt1 /* Option */ Semicolon;`
     ///
@@ -40702,7 +41206,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1135:
+    /// Semantic action for production 1149:
     ///
     /// `ProtoFunctionDeclarationOpt1 /* Option<T>::Some */: MinusGT ScalarType;`
     ///
@@ -40727,7 +41231,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1136:
+    /// Semantic action for production 1150:
     ///
     /// `ProtoFunctionDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -40739,7 +41243,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1137:
+    /// Semantic action for production 1151:
     ///
     /// `ProtoFunctionDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -40761,7 +41265,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1138:
+    /// Semantic action for production 1152:
     ///
     /// `ProtoFunctionDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -40773,7 +41277,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1139:
+    /// Semantic action for production 1153:
     ///
     /// `ProtoFunctionDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -40796,7 +41300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1140:
+    /// Semantic action for production 1154:
     ///
     /// `ProtoFunctionDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -40808,7 +41312,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1141:
+    /// Semantic action for production 1155:
     ///
     /// `ProtoAliasDeclaration: Alias ProtoAliasDeclarationGroup Identifier Colon ScopedIdentifier Semicolon;`
     ///
@@ -40853,7 +41357,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1142:
+    /// Semantic action for production 1156:
     ///
     /// `ProtoAliasDeclarationGroup: Module;`
     ///
@@ -40874,7 +41378,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1143:
+    /// Semantic action for production 1157:
     ///
     /// `ProtoAliasDeclarationGroup: Interface;`
     ///
@@ -40895,7 +41399,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1144:
+    /// Semantic action for production 1158:
     ///
     /// `ProtoAliasDeclarationGroup: Package;`
     ///
@@ -40916,7 +41420,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1145:
+    /// Semantic action for production 1159:
     ///
     /// `EmbedDeclaration: Embed LParen Identifier RParen Identifier EmbedContent;`
     ///
@@ -40953,7 +41457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1146:
+    /// Semantic action for production 1160:
     ///
     /// `EmbedContent: TripleLBrace EmbedContentList /* Vec */ TripleRBrace;`
     ///
@@ -40981,7 +41485,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1147:
+    /// Semantic action for production 1161:
     ///
     /// `EmbedContentList /* Vec<T>::Push */: EmbedItem EmbedContentList;`
     ///
@@ -41004,7 +41508,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1148:
+    /// Semantic action for production 1162:
     ///
     /// `EmbedContentList /* Vec<T>::New */: ;`
     ///
@@ -41020,7 +41524,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1149:
+    /// Semantic action for production 1163:
     ///
     /// `EmbedScopedIdentifier: EscapedLBrace ScopedIdentifier EscapedRBrace;`
     ///
@@ -41051,7 +41555,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1150:
+    /// Semantic action for production 1164:
     ///
     /// `EmbedItem: EmbedLBrace EmbedItemList /* Vec */ EmbedRBrace;`
     ///
@@ -41079,7 +41583,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1151:
+    /// Semantic action for production 1165:
     ///
     /// `EmbedItemList /* Vec<T>::Push */: EmbedItem EmbedItemList;`
     ///
@@ -41102,7 +41606,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1152:
+    /// Semantic action for production 1166:
     ///
     /// `EmbedItemList /* Vec<T>::New */: ;`
     ///
@@ -41115,7 +41619,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1153:
+    /// Semantic action for production 1167:
     ///
     /// `EmbedItem: EmbedScopedIdentifier;`
     ///
@@ -41139,7 +41643,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1154:
+    /// Semantic action for production 1168:
     ///
     /// `EmbedItem: Any;`
     ///
@@ -41156,7 +41660,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1155:
+    /// Semantic action for production 1169:
     ///
     /// `IncludeDeclaration: Include LParen Identifier Comma StringLiteral RParen Semicolon;`
     ///
@@ -41199,7 +41703,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1156:
+    /// Semantic action for production 1170:
     ///
     /// `DescriptionGroup: DescriptionGroupList /* Vec */ DescriptionGroupGroup;`
     ///
@@ -41230,7 +41734,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1157:
+    /// Semantic action for production 1171:
     ///
     /// `DescriptionGroupGroup: LBrace DescriptionGroupGroupList /* Vec */ RBrace;`
     ///
@@ -41268,7 +41772,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1158:
+    /// Semantic action for production 1172:
     ///
     /// `DescriptionGroupGroupList /* Vec<T>::Push */: DescriptionGroup DescriptionGroupGroupList;`
     ///
@@ -41299,7 +41803,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1159:
+    /// Semantic action for production 1173:
     ///
     /// `DescriptionGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -41315,7 +41819,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1160:
+    /// Semantic action for production 1174:
     ///
     /// `DescriptionGroupGroup: DescriptionItem;`
     ///
@@ -41336,7 +41840,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1161:
+    /// Semantic action for production 1175:
     ///
     /// `DescriptionGroupList /* Vec<T>::Push */: Attribute DescriptionGroupList;`
     ///
@@ -41363,7 +41867,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1162:
+    /// Semantic action for production 1176:
     ///
     /// `DescriptionGroupList /* Vec<T>::New */: ;`
     ///
@@ -41379,7 +41883,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1163:
+    /// Semantic action for production 1177:
     ///
     /// `DescriptionItem: DescriptionItemOpt /* Option */ PublicDescriptionItem;`
     ///
@@ -41412,7 +41916,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1164:
+    /// Semantic action for production 1178:
     ///
     /// `DescriptionItem: ImportDeclaration;`
     ///
@@ -41432,7 +41936,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1165:
+    /// Semantic action for production 1179:
     ///
     /// `DescriptionItem: BindDeclaration;`
     ///
@@ -41452,7 +41956,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1166:
+    /// Semantic action for production 1180:
     ///
     /// `DescriptionItem: EmbedDeclaration;`
     ///
@@ -41472,7 +41976,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1167:
+    /// Semantic action for production 1181:
     ///
     /// `DescriptionItem: IncludeDeclaration;`
     ///
@@ -41493,7 +41997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1168:
+    /// Semantic action for production 1182:
     ///
     /// `DescriptionItemOpt /* Option<T>::Some */: Pub;`
     ///
@@ -41512,7 +42016,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1169:
+    /// Semantic action for production 1183:
     ///
     /// `DescriptionItemOpt /* Option<T>::None */: ;`
     ///
@@ -41524,7 +42028,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1170:
+    /// Semantic action for production 1184:
     ///
     /// `PublicDescriptionItem: ModuleDeclaration;`
     ///
@@ -41548,7 +42052,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1171:
+    /// Semantic action for production 1185:
     ///
     /// `PublicDescriptionItem: InterfaceDeclaration;`
     ///
@@ -41576,7 +42080,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1172:
+    /// Semantic action for production 1186:
     ///
     /// `PublicDescriptionItem: PackageDeclaration;`
     ///
@@ -41603,7 +42107,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1173:
+    /// Semantic action for production 1187:
     ///
     /// `PublicDescriptionItem: AliasDeclaration;`
     ///
@@ -41627,7 +42131,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1174:
+    /// Semantic action for production 1188:
     ///
     /// `PublicDescriptionItem: ProtoDeclaration;`
     ///
@@ -41651,7 +42155,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1175:
+    /// Semantic action for production 1189:
     ///
     /// `PublicDescriptionItem: FunctionDeclaration;`
     ///
@@ -41679,7 +42183,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1176:
+    /// Semantic action for production 1190:
     ///
     /// `Veryl: Start VerylList /* Vec */;`
     ///
@@ -41699,7 +42203,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1177:
+    /// Semantic action for production 1191:
     ///
     /// `VerylList /* Vec<T>::Push */: DescriptionGroup VerylList;`
     ///
@@ -41722,7 +42226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1178:
+    /// Semantic action for production 1192:
     ///
     /// `VerylList /* Vec<T>::New */: ;`
     ///
@@ -41757,468 +42261,474 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
             8 => self.minus_g_t_term(&children[0]),
             9 => self.l_t_minus_term(&children[0]),
             10 => self.plus_colon_term(&children[0]),
-            11 => self.assignment_operator_term(&children[0]),
-            12 => self.diamond_operator_term(&children[0]),
-            13 => self.operator08_term(&children[0]),
-            14 => self.operator07_term(&children[0]),
-            15 => self.operator06_term(&children[0]),
-            16 => self.operator02_term(&children[0]),
-            17 => self.operator01_term(&children[0]),
-            18 => self.operator05_term(&children[0]),
-            19 => self.operator04_term(&children[0]),
-            20 => self.operator03_term(&children[0]),
-            21 => self.unary_operator_term(&children[0]),
-            22 => self.colon_colon_l_angle_term(&children[0]),
-            23 => self.colon_colon_term(&children[0]),
-            24 => self.colon_term(&children[0]),
-            25 => self.comma_term(&children[0]),
-            26 => self.dot_dot_equ_term(&children[0]),
-            27 => self.dot_dot_term(&children[0]),
-            28 => self.dot_term(&children[0]),
-            29 => self.equ_term(&children[0]),
-            30 => self.hash_l_bracket_term(&children[0]),
-            31 => self.hash_term(&children[0]),
-            32 => self.l_angle_term(&children[0]),
-            33 => self.question_term(&children[0]),
-            34 => self.quote_l_brace_term(&children[0]),
-            35 => self.quote_term(&children[0]),
-            36 => self.escaped_l_brace_term(&children[0]),
-            37 => self.triple_l_brace_term(&children[0]),
-            38 => self.l_brace_term(&children[0]),
-            39 => self.l_bracket_term(&children[0]),
-            40 => self.l_paren_term(&children[0]),
-            41 => self.r_angle_term(&children[0]),
-            42 => self.escaped_r_brace_term(&children[0]),
-            43 => self.triple_r_brace_term(&children[0]),
-            44 => self.r_brace_term(&children[0]),
-            45 => self.r_bracket_term(&children[0]),
-            46 => self.r_paren_term(&children[0]),
-            47 => self.semicolon_term(&children[0]),
-            48 => self.star_term(&children[0]),
-            49 => self.alias_term(&children[0]),
-            50 => self.always_comb_term(&children[0]),
-            51 => self.always_ff_term(&children[0]),
-            52 => self.assign_term(&children[0]),
-            53 => self.as_term(&children[0]),
-            54 => self.bind_term(&children[0]),
-            55 => self.bit_term(&children[0]),
-            56 => self.block_term(&children[0]),
-            57 => self.b_bool_term(&children[0]),
-            58 => self.l_bool_term(&children[0]),
-            59 => self.case_term(&children[0]),
-            60 => self.clock_term(&children[0]),
-            61 => self.clock_posedge_term(&children[0]),
-            62 => self.clock_negedge_term(&children[0]),
-            63 => self.connect_term(&children[0]),
-            64 => self.const_term(&children[0]),
-            65 => self.converse_term(&children[0]),
-            66 => self.default_term(&children[0]),
-            67 => self.else_term(&children[0]),
-            68 => self.embed_term(&children[0]),
-            69 => self.enum_term(&children[0]),
-            70 => self.f32_term(&children[0]),
-            71 => self.f64_term(&children[0]),
-            72 => self.false_term(&children[0]),
-            73 => self.final_term(&children[0]),
-            74 => self.for_term(&children[0]),
-            75 => self.function_term(&children[0]),
-            76 => self.gen_term(&children[0]),
-            77 => self.i8_term(&children[0]),
-            78 => self.i16_term(&children[0]),
-            79 => self.i32_term(&children[0]),
-            80 => self.i64_term(&children[0]),
-            81 => self.if_reset_term(&children[0]),
-            82 => self.if_term(&children[0]),
-            83 => self.import_term(&children[0]),
-            84 => self.include_term(&children[0]),
-            85 => self.initial_term(&children[0]),
-            86 => self.inout_term(&children[0]),
-            87 => self.input_term(&children[0]),
-            88 => self.inside_term(&children[0]),
-            89 => self.inst_term(&children[0]),
-            90 => self.interface_term(&children[0]),
-            91 => self.in_term(&children[0]),
-            92 => self.let_term(&children[0]),
-            93 => self.logic_term(&children[0]),
-            94 => self.lsb_term(&children[0]),
-            95 => self.mixin_term(&children[0]),
-            96 => self.modport_term(&children[0]),
-            97 => self.module_term(&children[0]),
-            98 => self.msb_term(&children[0]),
-            99 => self.output_term(&children[0]),
-            100 => self.outside_term(&children[0]),
-            101 => self.package_term(&children[0]),
-            102 => self.param_term(&children[0]),
-            103 => self.proto_term(&children[0]),
-            104 => self.pub_term(&children[0]),
-            105 => self.repeat_term(&children[0]),
-            106 => self.reset_term(&children[0]),
-            107 => self.reset_async_high_term(&children[0]),
-            108 => self.reset_async_low_term(&children[0]),
-            109 => self.reset_sync_high_term(&children[0]),
-            110 => self.reset_sync_low_term(&children[0]),
-            111 => self.return_term(&children[0]),
-            112 => self.rev_term(&children[0]),
-            113 => self.break_term(&children[0]),
-            114 => self.same_term(&children[0]),
-            115 => self.signed_term(&children[0]),
-            116 => self.step_term(&children[0]),
-            117 => self.string_term(&children[0]),
-            118 => self.struct_term(&children[0]),
-            119 => self.switch_term(&children[0]),
-            120 => self.tri_term(&children[0]),
-            121 => self.true_term(&children[0]),
-            122 => self.type_term(&children[0]),
-            123 => self.p8_term(&children[0]),
-            124 => self.p16_term(&children[0]),
-            125 => self.p32_term(&children[0]),
-            126 => self.p64_term(&children[0]),
-            127 => self.u8_term(&children[0]),
-            128 => self.u16_term(&children[0]),
-            129 => self.u32_term(&children[0]),
-            130 => self.u64_term(&children[0]),
-            131 => self.union_term(&children[0]),
-            132 => self.unsafe_term(&children[0]),
-            133 => self.var_term(&children[0]),
-            134 => self.dollar_identifier_term(&children[0]),
-            135 => self.identifier_term(&children[0]),
-            136 => self.any_term(&children[0]),
-            137 => self.comments(&children[0]),
-            138 => self.comments_opt_0(&children[0]),
-            139 => self.comments_opt_1(),
-            140 => self.start_token(&children[0]),
-            141 => self.string_literal_token(&children[0], &children[1]),
-            142 => self.exponent_token(&children[0], &children[1]),
-            143 => self.fixed_point_token(&children[0], &children[1]),
-            144 => self.based_token(&children[0], &children[1]),
-            145 => self.base_less_token(&children[0], &children[1]),
-            146 => self.all_bit_token(&children[0], &children[1]),
-            147 => self.assignment_operator_token(&children[0], &children[1]),
-            148 => self.diamond_operator_token(&children[0], &children[1]),
-            149 => self.operator01_token(&children[0], &children[1]),
-            150 => self.operator02_token(&children[0], &children[1]),
-            151 => self.operator03_token(&children[0], &children[1]),
-            152 => self.operator04_token(&children[0], &children[1]),
-            153 => self.operator05_token(&children[0], &children[1]),
-            154 => self.operator06_token(&children[0], &children[1]),
-            155 => self.operator07_token(&children[0], &children[1]),
-            156 => self.operator08_token(&children[0], &children[1]),
-            157 => self.unary_operator_token(&children[0], &children[1]),
-            158 => self.colon_token(&children[0], &children[1]),
-            159 => self.colon_colon_l_angle_token(&children[0], &children[1]),
-            160 => self.colon_colon_token(&children[0], &children[1]),
-            161 => self.comma_token(&children[0], &children[1]),
-            162 => self.dot_dot_token(&children[0], &children[1]),
-            163 => self.dot_dot_equ_token(&children[0], &children[1]),
-            164 => self.dot_token(&children[0], &children[1]),
-            165 => self.equ_token(&children[0], &children[1]),
-            166 => self.hash_l_bracket_token(&children[0], &children[1]),
-            167 => self.hash_token(&children[0], &children[1]),
-            168 => self.question_token(&children[0], &children[1]),
-            169 => self.quote_l_brace_token(&children[0], &children[1]),
-            170 => self.quote_token(&children[0], &children[1]),
-            171 => self.l_angle_token(&children[0], &children[1]),
-            172 => self.embed_l_brace_token(&children[0]),
-            173 => self.escaped_l_brace_token(&children[0]),
-            174 => self.triple_l_brace_token(&children[0]),
-            175 => self.l_brace_token(&children[0], &children[1]),
-            176 => self.l_bracket_token(&children[0], &children[1]),
-            177 => self.l_paren_token(&children[0], &children[1]),
-            178 => self.l_t_minus_token(&children[0], &children[1]),
-            179 => self.minus_colon_token(&children[0], &children[1]),
-            180 => self.minus_g_t_token(&children[0], &children[1]),
-            181 => self.plus_colon_token(&children[0], &children[1]),
-            182 => self.r_angle_token(&children[0], &children[1]),
-            183 => self.embed_r_brace_token(&children[0]),
-            184 => self.escaped_r_brace_token(&children[0]),
-            185 => self.triple_r_brace_token(&children[0], &children[1]),
-            186 => self.r_brace_token(&children[0], &children[1]),
-            187 => self.r_bracket_token(&children[0], &children[1]),
-            188 => self.r_paren_token(&children[0], &children[1]),
-            189 => self.semicolon_token(&children[0], &children[1]),
-            190 => self.star_token(&children[0], &children[1]),
-            191 => self.alias_token(&children[0], &children[1]),
-            192 => self.always_comb_token(&children[0], &children[1]),
-            193 => self.always_ff_token(&children[0], &children[1]),
-            194 => self.as_token(&children[0], &children[1]),
-            195 => self.assign_token(&children[0], &children[1]),
-            196 => self.bind_token(&children[0], &children[1]),
-            197 => self.bit_token(&children[0], &children[1]),
-            198 => self.block_token(&children[0], &children[1]),
-            199 => self.b_bool_token(&children[0], &children[1]),
-            200 => self.l_bool_token(&children[0], &children[1]),
-            201 => self.case_token(&children[0], &children[1]),
-            202 => self.clock_token(&children[0], &children[1]),
-            203 => self.clock_posedge_token(&children[0], &children[1]),
-            204 => self.clock_negedge_token(&children[0], &children[1]),
-            205 => self.connect_token(&children[0], &children[1]),
-            206 => self.const_token(&children[0], &children[1]),
-            207 => self.converse_token(&children[0], &children[1]),
-            208 => self.default_token(&children[0], &children[1]),
-            209 => self.else_token(&children[0], &children[1]),
-            210 => self.embed_token(&children[0], &children[1]),
-            211 => self.enum_token(&children[0], &children[1]),
-            212 => self.f32_token(&children[0], &children[1]),
-            213 => self.f64_token(&children[0], &children[1]),
-            214 => self.false_token(&children[0], &children[1]),
-            215 => self.final_token(&children[0], &children[1]),
-            216 => self.for_token(&children[0], &children[1]),
-            217 => self.function_token(&children[0], &children[1]),
-            218 => self.gen_token(&children[0], &children[1]),
-            219 => self.i8_token(&children[0], &children[1]),
-            220 => self.i16_token(&children[0], &children[1]),
-            221 => self.i32_token(&children[0], &children[1]),
-            222 => self.i64_token(&children[0], &children[1]),
-            223 => self.if_reset_token(&children[0], &children[1]),
-            224 => self.if_token(&children[0], &children[1]),
-            225 => self.import_token(&children[0], &children[1]),
-            226 => self.include_token(&children[0], &children[1]),
-            227 => self.initial_token(&children[0], &children[1]),
-            228 => self.inout_token(&children[0], &children[1]),
-            229 => self.input_token(&children[0], &children[1]),
-            230 => self.inside_token(&children[0], &children[1]),
-            231 => self.inst_token(&children[0], &children[1]),
-            232 => self.interface_token(&children[0], &children[1]),
-            233 => self.in_token(&children[0], &children[1]),
-            234 => self.let_token(&children[0], &children[1]),
-            235 => self.logic_token(&children[0], &children[1]),
-            236 => self.lsb_token(&children[0], &children[1]),
-            237 => self.mixin_token(&children[0], &children[1]),
-            238 => self.modport_token(&children[0], &children[1]),
-            239 => self.module_token(&children[0], &children[1]),
-            240 => self.msb_token(&children[0], &children[1]),
-            241 => self.output_token(&children[0], &children[1]),
-            242 => self.outside_token(&children[0], &children[1]),
-            243 => self.package_token(&children[0], &children[1]),
-            244 => self.param_token(&children[0], &children[1]),
-            245 => self.proto_token(&children[0], &children[1]),
-            246 => self.pub_token(&children[0], &children[1]),
-            247 => self.repeat_token(&children[0], &children[1]),
-            248 => self.reset_token(&children[0], &children[1]),
-            249 => self.reset_async_high_token(&children[0], &children[1]),
-            250 => self.reset_async_low_token(&children[0], &children[1]),
-            251 => self.reset_sync_high_token(&children[0], &children[1]),
-            252 => self.reset_sync_low_token(&children[0], &children[1]),
-            253 => self.return_token(&children[0], &children[1]),
-            254 => self.rev_token(&children[0], &children[1]),
-            255 => self.break_token(&children[0], &children[1]),
-            256 => self.same_token(&children[0], &children[1]),
-            257 => self.signed_token(&children[0], &children[1]),
-            258 => self.step_token(&children[0], &children[1]),
-            259 => self.string_token(&children[0], &children[1]),
-            260 => self.struct_token(&children[0], &children[1]),
-            261 => self.switch_token(&children[0], &children[1]),
-            262 => self.tri_token(&children[0], &children[1]),
-            263 => self.true_token(&children[0], &children[1]),
-            264 => self.type_token(&children[0], &children[1]),
-            265 => self.p8_token(&children[0], &children[1]),
-            266 => self.p16_token(&children[0], &children[1]),
-            267 => self.p32_token(&children[0], &children[1]),
-            268 => self.p64_token(&children[0], &children[1]),
-            269 => self.u8_token(&children[0], &children[1]),
-            270 => self.u16_token(&children[0], &children[1]),
-            271 => self.u32_token(&children[0], &children[1]),
-            272 => self.u64_token(&children[0], &children[1]),
-            273 => self.union_token(&children[0], &children[1]),
-            274 => self.unsafe_token(&children[0], &children[1]),
-            275 => self.var_token(&children[0], &children[1]),
-            276 => self.dollar_identifier_token(&children[0], &children[1]),
-            277 => self.identifier_token(&children[0], &children[1]),
-            278 => self.any_token(&children[0]),
-            279 => self.start(&children[0]),
-            280 => self.string_literal(&children[0]),
-            281 => self.exponent(&children[0]),
-            282 => self.fixed_point(&children[0]),
-            283 => self.based(&children[0]),
-            284 => self.base_less(&children[0]),
-            285 => self.all_bit(&children[0]),
-            286 => self.assignment_operator(&children[0]),
-            287 => self.diamond_operator(&children[0]),
-            288 => self.operator01(&children[0]),
-            289 => self.operator02(&children[0]),
-            290 => self.operator03(&children[0]),
-            291 => self.operator04(&children[0]),
-            292 => self.operator05(&children[0]),
-            293 => self.operator06(&children[0]),
-            294 => self.operator07(&children[0]),
-            295 => self.operator08(&children[0]),
-            296 => self.unary_operator(&children[0]),
-            297 => self.colon(&children[0]),
-            298 => self.colon_colon_l_angle(&children[0]),
-            299 => self.colon_colon(&children[0]),
-            300 => self.comma(&children[0]),
-            301 => self.dot_dot(&children[0]),
-            302 => self.dot_dot_equ(&children[0]),
-            303 => self.dot(&children[0]),
-            304 => self.equ(&children[0]),
-            305 => self.hash_l_bracket(&children[0]),
-            306 => self.hash(&children[0]),
-            307 => self.question(&children[0]),
-            308 => self.quote_l_brace(&children[0]),
-            309 => self.quote(&children[0]),
-            310 => self.l_angle(&children[0]),
-            311 => self.embed_l_brace(&children[0]),
-            312 => self.escaped_l_brace(&children[0]),
-            313 => self.triple_l_brace(&children[0]),
-            314 => self.l_brace(&children[0]),
-            315 => self.l_bracket(&children[0]),
-            316 => self.l_paren(&children[0]),
-            317 => self.l_t_minus(&children[0]),
-            318 => self.minus_colon(&children[0]),
-            319 => self.minus_g_t(&children[0]),
-            320 => self.plus_colon(&children[0]),
-            321 => self.r_angle(&children[0]),
-            322 => self.embed_r_brace(&children[0]),
-            323 => self.escaped_r_brace(&children[0]),
-            324 => self.triple_r_brace(&children[0]),
-            325 => self.r_brace(&children[0]),
-            326 => self.r_bracket(&children[0]),
-            327 => self.r_paren(&children[0]),
-            328 => self.semicolon(&children[0]),
-            329 => self.star(&children[0]),
-            330 => self.alias(&children[0]),
-            331 => self.always_comb(&children[0]),
-            332 => self.always_ff(&children[0]),
-            333 => self.r#as(&children[0]),
-            334 => self.assign(&children[0]),
-            335 => self.bind(&children[0]),
-            336 => self.bit(&children[0]),
-            337 => self.block(&children[0]),
-            338 => self.b_bool(&children[0]),
-            339 => self.l_bool(&children[0]),
-            340 => self.r#break(&children[0]),
-            341 => self.case(&children[0]),
-            342 => self.clock(&children[0]),
-            343 => self.clock_posedge(&children[0]),
-            344 => self.clock_negedge(&children[0]),
-            345 => self.connect(&children[0]),
-            346 => self.r#const(&children[0]),
-            347 => self.converse(&children[0]),
-            348 => self.defaul(&children[0]),
-            349 => self.r#else(&children[0]),
-            350 => self.embed(&children[0]),
-            351 => self.r#enum(&children[0]),
-            352 => self.f32(&children[0]),
-            353 => self.f64(&children[0]),
-            354 => self.r#false(&children[0]),
-            355 => self.r#final(&children[0]),
-            356 => self.r#for(&children[0]),
-            357 => self.function(&children[0]),
-            358 => self.r#gen(&children[0]),
-            359 => self.i8(&children[0]),
-            360 => self.i16(&children[0]),
-            361 => self.i32(&children[0]),
-            362 => self.i64(&children[0]),
-            363 => self.r#if(&children[0]),
-            364 => self.if_reset(&children[0]),
-            365 => self.import(&children[0]),
-            366 => self.r#in(&children[0]),
-            367 => self.include(&children[0]),
-            368 => self.initial(&children[0]),
-            369 => self.inout(&children[0]),
-            370 => self.input(&children[0]),
-            371 => self.inside(&children[0]),
-            372 => self.inst(&children[0]),
-            373 => self.interface(&children[0]),
-            374 => self.r#let(&children[0]),
-            375 => self.logic(&children[0]),
-            376 => self.lsb(&children[0]),
-            377 => self.mixin(&children[0]),
-            378 => self.modport(&children[0]),
-            379 => self.module(&children[0]),
-            380 => self.msb(&children[0]),
-            381 => self.output(&children[0]),
-            382 => self.outside(&children[0]),
-            383 => self.package(&children[0]),
-            384 => self.param(&children[0]),
-            385 => self.proto(&children[0]),
-            386 => self.r#pub(&children[0]),
-            387 => self.repeat(&children[0]),
-            388 => self.reset(&children[0]),
-            389 => self.reset_async_high(&children[0]),
-            390 => self.reset_async_low(&children[0]),
-            391 => self.reset_sync_high(&children[0]),
-            392 => self.reset_sync_low(&children[0]),
-            393 => self.r#return(&children[0]),
-            394 => self.rev(&children[0]),
-            395 => self.same(&children[0]),
-            396 => self.signed(&children[0]),
-            397 => self.step(&children[0]),
-            398 => self.strin(&children[0]),
-            399 => self.r#struct(&children[0]),
-            400 => self.switch(&children[0]),
-            401 => self.tri(&children[0]),
-            402 => self.r#true(&children[0]),
-            403 => self.r#type(&children[0]),
-            404 => self.p8(&children[0]),
-            405 => self.p16(&children[0]),
-            406 => self.p32(&children[0]),
-            407 => self.p64(&children[0]),
-            408 => self.u8(&children[0]),
-            409 => self.u16(&children[0]),
-            410 => self.u32(&children[0]),
-            411 => self.u64(&children[0]),
-            412 => self.r#union(&children[0]),
-            413 => self.r#unsafe(&children[0]),
-            414 => self.var(&children[0]),
-            415 => self.dollar_identifier(&children[0]),
-            416 => self.identifier(&children[0]),
-            417 => self.any(&children[0]),
-            418 => self.number_0(&children[0]),
-            419 => self.number_1(&children[0]),
-            420 => self.integral_number_0(&children[0]),
-            421 => self.integral_number_1(&children[0]),
-            422 => self.integral_number_2(&children[0]),
-            423 => self.real_number_0(&children[0]),
-            424 => self.real_number_1(&children[0]),
-            425 => self.hierarchical_identifier(&children[0], &children[1], &children[2]),
-            426 => self.hierarchical_identifier_list0_0(
+            11 => self.implication_term(&children[0]),
+            12 => self.assignment_operator_term(&children[0]),
+            13 => self.diamond_operator_term(&children[0]),
+            14 => self.operator08_term(&children[0]),
+            15 => self.operator07_term(&children[0]),
+            16 => self.operator06_term(&children[0]),
+            17 => self.operator02_term(&children[0]),
+            18 => self.operator01_term(&children[0]),
+            19 => self.operator05_term(&children[0]),
+            20 => self.operator04_term(&children[0]),
+            21 => self.operator03_term(&children[0]),
+            22 => self.unary_operator_term(&children[0]),
+            23 => self.colon_colon_l_angle_term(&children[0]),
+            24 => self.colon_colon_term(&children[0]),
+            25 => self.colon_term(&children[0]),
+            26 => self.comma_term(&children[0]),
+            27 => self.dot_dot_equ_term(&children[0]),
+            28 => self.dot_dot_term(&children[0]),
+            29 => self.dot_term(&children[0]),
+            30 => self.equ_term(&children[0]),
+            31 => self.hash_hash_term(&children[0]),
+            32 => self.hash_l_bracket_term(&children[0]),
+            33 => self.hash_term(&children[0]),
+            34 => self.l_angle_term(&children[0]),
+            35 => self.question_term(&children[0]),
+            36 => self.quote_l_brace_term(&children[0]),
+            37 => self.quote_term(&children[0]),
+            38 => self.escaped_l_brace_term(&children[0]),
+            39 => self.triple_l_brace_term(&children[0]),
+            40 => self.l_brace_term(&children[0]),
+            41 => self.l_bracket_term(&children[0]),
+            42 => self.l_paren_term(&children[0]),
+            43 => self.r_angle_term(&children[0]),
+            44 => self.escaped_r_brace_term(&children[0]),
+            45 => self.triple_r_brace_term(&children[0]),
+            46 => self.r_brace_term(&children[0]),
+            47 => self.r_bracket_term(&children[0]),
+            48 => self.r_paren_term(&children[0]),
+            49 => self.semicolon_term(&children[0]),
+            50 => self.star_term(&children[0]),
+            51 => self.alias_term(&children[0]),
+            52 => self.always_comb_term(&children[0]),
+            53 => self.always_ff_term(&children[0]),
+            54 => self.assign_term(&children[0]),
+            55 => self.as_term(&children[0]),
+            56 => self.bind_term(&children[0]),
+            57 => self.bit_term(&children[0]),
+            58 => self.block_term(&children[0]),
+            59 => self.b_bool_term(&children[0]),
+            60 => self.l_bool_term(&children[0]),
+            61 => self.case_term(&children[0]),
+            62 => self.clock_term(&children[0]),
+            63 => self.clock_posedge_term(&children[0]),
+            64 => self.clock_negedge_term(&children[0]),
+            65 => self.connect_term(&children[0]),
+            66 => self.const_term(&children[0]),
+            67 => self.converse_term(&children[0]),
+            68 => self.default_term(&children[0]),
+            69 => self.else_term(&children[0]),
+            70 => self.embed_term(&children[0]),
+            71 => self.enum_term(&children[0]),
+            72 => self.f32_term(&children[0]),
+            73 => self.f64_term(&children[0]),
+            74 => self.false_term(&children[0]),
+            75 => self.final_term(&children[0]),
+            76 => self.for_term(&children[0]),
+            77 => self.function_term(&children[0]),
+            78 => self.gen_term(&children[0]),
+            79 => self.i8_term(&children[0]),
+            80 => self.i16_term(&children[0]),
+            81 => self.i32_term(&children[0]),
+            82 => self.i64_term(&children[0]),
+            83 => self.if_reset_term(&children[0]),
+            84 => self.if_term(&children[0]),
+            85 => self.import_term(&children[0]),
+            86 => self.include_term(&children[0]),
+            87 => self.initial_term(&children[0]),
+            88 => self.inout_term(&children[0]),
+            89 => self.input_term(&children[0]),
+            90 => self.inside_term(&children[0]),
+            91 => self.inst_term(&children[0]),
+            92 => self.interface_term(&children[0]),
+            93 => self.in_term(&children[0]),
+            94 => self.let_term(&children[0]),
+            95 => self.logic_term(&children[0]),
+            96 => self.lsb_term(&children[0]),
+            97 => self.mixin_term(&children[0]),
+            98 => self.modport_term(&children[0]),
+            99 => self.module_term(&children[0]),
+            100 => self.msb_term(&children[0]),
+            101 => self.output_term(&children[0]),
+            102 => self.outside_term(&children[0]),
+            103 => self.package_term(&children[0]),
+            104 => self.param_term(&children[0]),
+            105 => self.proto_term(&children[0]),
+            106 => self.pub_term(&children[0]),
+            107 => self.repeat_term(&children[0]),
+            108 => self.reset_term(&children[0]),
+            109 => self.reset_async_high_term(&children[0]),
+            110 => self.reset_async_low_term(&children[0]),
+            111 => self.reset_sync_high_term(&children[0]),
+            112 => self.reset_sync_low_term(&children[0]),
+            113 => self.return_term(&children[0]),
+            114 => self.rev_term(&children[0]),
+            115 => self.break_term(&children[0]),
+            116 => self.same_term(&children[0]),
+            117 => self.signed_term(&children[0]),
+            118 => self.step_term(&children[0]),
+            119 => self.string_term(&children[0]),
+            120 => self.struct_term(&children[0]),
+            121 => self.switch_term(&children[0]),
+            122 => self.tri_term(&children[0]),
+            123 => self.true_term(&children[0]),
+            124 => self.type_term(&children[0]),
+            125 => self.p8_term(&children[0]),
+            126 => self.p16_term(&children[0]),
+            127 => self.p32_term(&children[0]),
+            128 => self.p64_term(&children[0]),
+            129 => self.u8_term(&children[0]),
+            130 => self.u16_term(&children[0]),
+            131 => self.u32_term(&children[0]),
+            132 => self.u64_term(&children[0]),
+            133 => self.union_term(&children[0]),
+            134 => self.unsafe_term(&children[0]),
+            135 => self.var_term(&children[0]),
+            136 => self.dollar_identifier_term(&children[0]),
+            137 => self.identifier_term(&children[0]),
+            138 => self.any_term(&children[0]),
+            139 => self.comments(&children[0]),
+            140 => self.comments_opt_0(&children[0]),
+            141 => self.comments_opt_1(),
+            142 => self.start_token(&children[0]),
+            143 => self.string_literal_token(&children[0], &children[1]),
+            144 => self.exponent_token(&children[0], &children[1]),
+            145 => self.fixed_point_token(&children[0], &children[1]),
+            146 => self.based_token(&children[0], &children[1]),
+            147 => self.base_less_token(&children[0], &children[1]),
+            148 => self.all_bit_token(&children[0], &children[1]),
+            149 => self.assignment_operator_token(&children[0], &children[1]),
+            150 => self.diamond_operator_token(&children[0], &children[1]),
+            151 => self.implication_token(&children[0], &children[1]),
+            152 => self.operator01_token(&children[0], &children[1]),
+            153 => self.operator02_token(&children[0], &children[1]),
+            154 => self.operator03_token(&children[0], &children[1]),
+            155 => self.operator04_token(&children[0], &children[1]),
+            156 => self.operator05_token(&children[0], &children[1]),
+            157 => self.operator06_token(&children[0], &children[1]),
+            158 => self.operator07_token(&children[0], &children[1]),
+            159 => self.operator08_token(&children[0], &children[1]),
+            160 => self.unary_operator_token(&children[0], &children[1]),
+            161 => self.colon_token(&children[0], &children[1]),
+            162 => self.colon_colon_l_angle_token(&children[0], &children[1]),
+            163 => self.colon_colon_token(&children[0], &children[1]),
+            164 => self.comma_token(&children[0], &children[1]),
+            165 => self.dot_dot_token(&children[0], &children[1]),
+            166 => self.dot_dot_equ_token(&children[0], &children[1]),
+            167 => self.dot_token(&children[0], &children[1]),
+            168 => self.equ_token(&children[0], &children[1]),
+            169 => self.hash_hash_token(&children[0], &children[1]),
+            170 => self.hash_l_bracket_token(&children[0], &children[1]),
+            171 => self.hash_token(&children[0], &children[1]),
+            172 => self.question_token(&children[0], &children[1]),
+            173 => self.quote_l_brace_token(&children[0], &children[1]),
+            174 => self.quote_token(&children[0], &children[1]),
+            175 => self.l_angle_token(&children[0], &children[1]),
+            176 => self.embed_l_brace_token(&children[0]),
+            177 => self.escaped_l_brace_token(&children[0]),
+            178 => self.triple_l_brace_token(&children[0]),
+            179 => self.l_brace_token(&children[0], &children[1]),
+            180 => self.l_bracket_token(&children[0], &children[1]),
+            181 => self.l_paren_token(&children[0], &children[1]),
+            182 => self.l_t_minus_token(&children[0], &children[1]),
+            183 => self.minus_colon_token(&children[0], &children[1]),
+            184 => self.minus_g_t_token(&children[0], &children[1]),
+            185 => self.plus_colon_token(&children[0], &children[1]),
+            186 => self.r_angle_token(&children[0], &children[1]),
+            187 => self.embed_r_brace_token(&children[0]),
+            188 => self.escaped_r_brace_token(&children[0]),
+            189 => self.triple_r_brace_token(&children[0], &children[1]),
+            190 => self.r_brace_token(&children[0], &children[1]),
+            191 => self.r_bracket_token(&children[0], &children[1]),
+            192 => self.r_paren_token(&children[0], &children[1]),
+            193 => self.semicolon_token(&children[0], &children[1]),
+            194 => self.star_token(&children[0], &children[1]),
+            195 => self.alias_token(&children[0], &children[1]),
+            196 => self.always_comb_token(&children[0], &children[1]),
+            197 => self.always_ff_token(&children[0], &children[1]),
+            198 => self.as_token(&children[0], &children[1]),
+            199 => self.assign_token(&children[0], &children[1]),
+            200 => self.bind_token(&children[0], &children[1]),
+            201 => self.bit_token(&children[0], &children[1]),
+            202 => self.block_token(&children[0], &children[1]),
+            203 => self.b_bool_token(&children[0], &children[1]),
+            204 => self.l_bool_token(&children[0], &children[1]),
+            205 => self.case_token(&children[0], &children[1]),
+            206 => self.clock_token(&children[0], &children[1]),
+            207 => self.clock_posedge_token(&children[0], &children[1]),
+            208 => self.clock_negedge_token(&children[0], &children[1]),
+            209 => self.connect_token(&children[0], &children[1]),
+            210 => self.const_token(&children[0], &children[1]),
+            211 => self.converse_token(&children[0], &children[1]),
+            212 => self.default_token(&children[0], &children[1]),
+            213 => self.else_token(&children[0], &children[1]),
+            214 => self.embed_token(&children[0], &children[1]),
+            215 => self.enum_token(&children[0], &children[1]),
+            216 => self.f32_token(&children[0], &children[1]),
+            217 => self.f64_token(&children[0], &children[1]),
+            218 => self.false_token(&children[0], &children[1]),
+            219 => self.final_token(&children[0], &children[1]),
+            220 => self.for_token(&children[0], &children[1]),
+            221 => self.function_token(&children[0], &children[1]),
+            222 => self.gen_token(&children[0], &children[1]),
+            223 => self.i8_token(&children[0], &children[1]),
+            224 => self.i16_token(&children[0], &children[1]),
+            225 => self.i32_token(&children[0], &children[1]),
+            226 => self.i64_token(&children[0], &children[1]),
+            227 => self.if_reset_token(&children[0], &children[1]),
+            228 => self.if_token(&children[0], &children[1]),
+            229 => self.import_token(&children[0], &children[1]),
+            230 => self.include_token(&children[0], &children[1]),
+            231 => self.initial_token(&children[0], &children[1]),
+            232 => self.inout_token(&children[0], &children[1]),
+            233 => self.input_token(&children[0], &children[1]),
+            234 => self.inside_token(&children[0], &children[1]),
+            235 => self.inst_token(&children[0], &children[1]),
+            236 => self.interface_token(&children[0], &children[1]),
+            237 => self.in_token(&children[0], &children[1]),
+            238 => self.let_token(&children[0], &children[1]),
+            239 => self.logic_token(&children[0], &children[1]),
+            240 => self.lsb_token(&children[0], &children[1]),
+            241 => self.mixin_token(&children[0], &children[1]),
+            242 => self.modport_token(&children[0], &children[1]),
+            243 => self.module_token(&children[0], &children[1]),
+            244 => self.msb_token(&children[0], &children[1]),
+            245 => self.output_token(&children[0], &children[1]),
+            246 => self.outside_token(&children[0], &children[1]),
+            247 => self.package_token(&children[0], &children[1]),
+            248 => self.param_token(&children[0], &children[1]),
+            249 => self.proto_token(&children[0], &children[1]),
+            250 => self.pub_token(&children[0], &children[1]),
+            251 => self.repeat_token(&children[0], &children[1]),
+            252 => self.reset_token(&children[0], &children[1]),
+            253 => self.reset_async_high_token(&children[0], &children[1]),
+            254 => self.reset_async_low_token(&children[0], &children[1]),
+            255 => self.reset_sync_high_token(&children[0], &children[1]),
+            256 => self.reset_sync_low_token(&children[0], &children[1]),
+            257 => self.return_token(&children[0], &children[1]),
+            258 => self.rev_token(&children[0], &children[1]),
+            259 => self.break_token(&children[0], &children[1]),
+            260 => self.same_token(&children[0], &children[1]),
+            261 => self.signed_token(&children[0], &children[1]),
+            262 => self.step_token(&children[0], &children[1]),
+            263 => self.string_token(&children[0], &children[1]),
+            264 => self.struct_token(&children[0], &children[1]),
+            265 => self.switch_token(&children[0], &children[1]),
+            266 => self.tri_token(&children[0], &children[1]),
+            267 => self.true_token(&children[0], &children[1]),
+            268 => self.type_token(&children[0], &children[1]),
+            269 => self.p8_token(&children[0], &children[1]),
+            270 => self.p16_token(&children[0], &children[1]),
+            271 => self.p32_token(&children[0], &children[1]),
+            272 => self.p64_token(&children[0], &children[1]),
+            273 => self.u8_token(&children[0], &children[1]),
+            274 => self.u16_token(&children[0], &children[1]),
+            275 => self.u32_token(&children[0], &children[1]),
+            276 => self.u64_token(&children[0], &children[1]),
+            277 => self.union_token(&children[0], &children[1]),
+            278 => self.unsafe_token(&children[0], &children[1]),
+            279 => self.var_token(&children[0], &children[1]),
+            280 => self.dollar_identifier_token(&children[0], &children[1]),
+            281 => self.identifier_token(&children[0], &children[1]),
+            282 => self.any_token(&children[0]),
+            283 => self.start(&children[0]),
+            284 => self.string_literal(&children[0]),
+            285 => self.exponent(&children[0]),
+            286 => self.fixed_point(&children[0]),
+            287 => self.based(&children[0]),
+            288 => self.base_less(&children[0]),
+            289 => self.all_bit(&children[0]),
+            290 => self.assignment_operator(&children[0]),
+            291 => self.diamond_operator(&children[0]),
+            292 => self.implication(&children[0]),
+            293 => self.operator01(&children[0]),
+            294 => self.operator02(&children[0]),
+            295 => self.operator03(&children[0]),
+            296 => self.operator04(&children[0]),
+            297 => self.operator05(&children[0]),
+            298 => self.operator06(&children[0]),
+            299 => self.operator07(&children[0]),
+            300 => self.operator08(&children[0]),
+            301 => self.unary_operator(&children[0]),
+            302 => self.colon(&children[0]),
+            303 => self.colon_colon_l_angle(&children[0]),
+            304 => self.colon_colon(&children[0]),
+            305 => self.comma(&children[0]),
+            306 => self.dot_dot(&children[0]),
+            307 => self.dot_dot_equ(&children[0]),
+            308 => self.dot(&children[0]),
+            309 => self.equ(&children[0]),
+            310 => self.hash_hash(&children[0]),
+            311 => self.hash_l_bracket(&children[0]),
+            312 => self.hash(&children[0]),
+            313 => self.question(&children[0]),
+            314 => self.quote_l_brace(&children[0]),
+            315 => self.quote(&children[0]),
+            316 => self.l_angle(&children[0]),
+            317 => self.embed_l_brace(&children[0]),
+            318 => self.escaped_l_brace(&children[0]),
+            319 => self.triple_l_brace(&children[0]),
+            320 => self.l_brace(&children[0]),
+            321 => self.l_bracket(&children[0]),
+            322 => self.l_paren(&children[0]),
+            323 => self.l_t_minus(&children[0]),
+            324 => self.minus_colon(&children[0]),
+            325 => self.minus_g_t(&children[0]),
+            326 => self.plus_colon(&children[0]),
+            327 => self.r_angle(&children[0]),
+            328 => self.embed_r_brace(&children[0]),
+            329 => self.escaped_r_brace(&children[0]),
+            330 => self.triple_r_brace(&children[0]),
+            331 => self.r_brace(&children[0]),
+            332 => self.r_bracket(&children[0]),
+            333 => self.r_paren(&children[0]),
+            334 => self.semicolon(&children[0]),
+            335 => self.star(&children[0]),
+            336 => self.alias(&children[0]),
+            337 => self.always_comb(&children[0]),
+            338 => self.always_ff(&children[0]),
+            339 => self.r#as(&children[0]),
+            340 => self.assign(&children[0]),
+            341 => self.bind(&children[0]),
+            342 => self.bit(&children[0]),
+            343 => self.block(&children[0]),
+            344 => self.b_bool(&children[0]),
+            345 => self.l_bool(&children[0]),
+            346 => self.r#break(&children[0]),
+            347 => self.case(&children[0]),
+            348 => self.clock(&children[0]),
+            349 => self.clock_posedge(&children[0]),
+            350 => self.clock_negedge(&children[0]),
+            351 => self.connect(&children[0]),
+            352 => self.r#const(&children[0]),
+            353 => self.converse(&children[0]),
+            354 => self.defaul(&children[0]),
+            355 => self.r#else(&children[0]),
+            356 => self.embed(&children[0]),
+            357 => self.r#enum(&children[0]),
+            358 => self.f32(&children[0]),
+            359 => self.f64(&children[0]),
+            360 => self.r#false(&children[0]),
+            361 => self.r#final(&children[0]),
+            362 => self.r#for(&children[0]),
+            363 => self.function(&children[0]),
+            364 => self.r#gen(&children[0]),
+            365 => self.i8(&children[0]),
+            366 => self.i16(&children[0]),
+            367 => self.i32(&children[0]),
+            368 => self.i64(&children[0]),
+            369 => self.r#if(&children[0]),
+            370 => self.if_reset(&children[0]),
+            371 => self.import(&children[0]),
+            372 => self.r#in(&children[0]),
+            373 => self.include(&children[0]),
+            374 => self.initial(&children[0]),
+            375 => self.inout(&children[0]),
+            376 => self.input(&children[0]),
+            377 => self.inside(&children[0]),
+            378 => self.inst(&children[0]),
+            379 => self.interface(&children[0]),
+            380 => self.r#let(&children[0]),
+            381 => self.logic(&children[0]),
+            382 => self.lsb(&children[0]),
+            383 => self.mixin(&children[0]),
+            384 => self.modport(&children[0]),
+            385 => self.module(&children[0]),
+            386 => self.msb(&children[0]),
+            387 => self.output(&children[0]),
+            388 => self.outside(&children[0]),
+            389 => self.package(&children[0]),
+            390 => self.param(&children[0]),
+            391 => self.proto(&children[0]),
+            392 => self.r#pub(&children[0]),
+            393 => self.repeat(&children[0]),
+            394 => self.reset(&children[0]),
+            395 => self.reset_async_high(&children[0]),
+            396 => self.reset_async_low(&children[0]),
+            397 => self.reset_sync_high(&children[0]),
+            398 => self.reset_sync_low(&children[0]),
+            399 => self.r#return(&children[0]),
+            400 => self.rev(&children[0]),
+            401 => self.same(&children[0]),
+            402 => self.signed(&children[0]),
+            403 => self.step(&children[0]),
+            404 => self.strin(&children[0]),
+            405 => self.r#struct(&children[0]),
+            406 => self.switch(&children[0]),
+            407 => self.tri(&children[0]),
+            408 => self.r#true(&children[0]),
+            409 => self.r#type(&children[0]),
+            410 => self.p8(&children[0]),
+            411 => self.p16(&children[0]),
+            412 => self.p32(&children[0]),
+            413 => self.p64(&children[0]),
+            414 => self.u8(&children[0]),
+            415 => self.u16(&children[0]),
+            416 => self.u32(&children[0]),
+            417 => self.u64(&children[0]),
+            418 => self.r#union(&children[0]),
+            419 => self.r#unsafe(&children[0]),
+            420 => self.var(&children[0]),
+            421 => self.dollar_identifier(&children[0]),
+            422 => self.identifier(&children[0]),
+            423 => self.any(&children[0]),
+            424 => self.number_0(&children[0]),
+            425 => self.number_1(&children[0]),
+            426 => self.integral_number_0(&children[0]),
+            427 => self.integral_number_1(&children[0]),
+            428 => self.integral_number_2(&children[0]),
+            429 => self.real_number_0(&children[0]),
+            430 => self.real_number_1(&children[0]),
+            431 => self.hierarchical_identifier(&children[0], &children[1], &children[2]),
+            432 => self.hierarchical_identifier_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            427 => self.hierarchical_identifier_list0_list_0(&children[0], &children[1]),
-            428 => self.hierarchical_identifier_list0_list_1(),
-            429 => self.hierarchical_identifier_list0_1(),
-            430 => self.hierarchical_identifier_list_0(&children[0], &children[1]),
-            431 => self.hierarchical_identifier_list_1(),
-            432 => self.scoped_identifier(&children[0], &children[1]),
-            433 => self.scoped_identifier_group_0(&children[0]),
-            434 => self.scoped_identifier_group_1(&children[0], &children[1]),
-            435 => self.scoped_identifier_list_0(
+            433 => self.hierarchical_identifier_list0_list_0(&children[0], &children[1]),
+            434 => self.hierarchical_identifier_list0_list_1(),
+            435 => self.hierarchical_identifier_list0_1(),
+            436 => self.hierarchical_identifier_list_0(&children[0], &children[1]),
+            437 => self.hierarchical_identifier_list_1(),
+            438 => self.scoped_identifier(&children[0], &children[1]),
+            439 => self.scoped_identifier_group_0(&children[0]),
+            440 => self.scoped_identifier_group_1(&children[0], &children[1]),
+            441 => self.scoped_identifier_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            436 => self.scoped_identifier_list_1(),
-            437 => self.scoped_identifier_opt0_0(&children[0]),
-            438 => self.scoped_identifier_opt0_1(),
-            439 => self.scoped_identifier_opt_0(&children[0]),
-            440 => self.scoped_identifier_opt_1(),
-            441 => {
+            442 => self.scoped_identifier_list_1(),
+            443 => self.scoped_identifier_opt0_0(&children[0]),
+            444 => self.scoped_identifier_opt0_1(),
+            445 => self.scoped_identifier_opt_0(&children[0]),
+            446 => self.scoped_identifier_opt_1(),
+            447 => {
                 self.expression_identifier(&children[0], &children[1], &children[2], &children[3])
             }
-            442 => self.expression_identifier_list0_0(
+            448 => self.expression_identifier_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            443 => self.expression_identifier_list0_list_0(&children[0], &children[1]),
-            444 => self.expression_identifier_list0_list_1(),
-            445 => self.expression_identifier_list0_1(),
-            446 => self.expression_identifier_list_0(&children[0], &children[1]),
-            447 => self.expression_identifier_list_1(),
-            448 => self.expression_identifier_opt_0(&children[0]),
-            449 => self.expression_identifier_opt_1(),
-            450 => self.generic_arg_identifier(&children[0], &children[1]),
-            451 => self.generic_arg_identifier_list_0(&children[0], &children[1], &children[2]),
-            452 => self.generic_arg_identifier_list_1(),
-            453 => self.expression(&children[0]),
-            454 => self.if_expression(&children[0], &children[1]),
-            455 => self.if_expression_list_0(
+            449 => self.expression_identifier_list0_list_0(&children[0], &children[1]),
+            450 => self.expression_identifier_list0_list_1(),
+            451 => self.expression_identifier_list0_1(),
+            452 => self.expression_identifier_list_0(&children[0], &children[1]),
+            453 => self.expression_identifier_list_1(),
+            454 => self.expression_identifier_opt_0(&children[0]),
+            455 => self.expression_identifier_opt_1(),
+            456 => self.generic_arg_identifier(&children[0], &children[1]),
+            457 => self.generic_arg_identifier_list_0(&children[0], &children[1], &children[2]),
+            458 => self.generic_arg_identifier_list_1(),
+            459 => self.expression(&children[0]),
+            460 => self.if_expression(&children[0], &children[1]),
+            461 => self.if_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42226,101 +42736,115 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            456 => self.if_expression_list_1(),
-            457 => self.expression01(&children[0], &children[1]),
-            458 => self.expression01_list_0(&children[0], &children[1], &children[2]),
-            459 => self.expression01_list_1(),
-            460 => self.expression02(&children[0], &children[1], &children[2]),
-            461 => self.expression02_list_0(&children[0], &children[1]),
-            462 => self.expression02_list_1(),
-            463 => self.expression02_opt_0(&children[0], &children[1]),
-            464 => self.expression02_opt_1(),
-            465 => self.expression01_op_0(&children[0]),
-            466 => self.expression01_op_1(&children[0]),
-            467 => self.expression01_op_2(&children[0]),
-            468 => self.expression01_op_3(&children[0]),
-            469 => self.expression01_op_4(&children[0]),
-            470 => self.expression01_op_5(&children[0]),
-            471 => self.expression01_op_6(&children[0]),
-            472 => self.expression01_op_7(&children[0]),
-            473 => self.expression01_op_8(&children[0]),
-            474 => self.expression02_op_0(&children[0]),
-            475 => self.expression02_op_1(&children[0]),
-            476 => self.expression02_op_2(&children[0]),
-            477 => self.expression02_op_3(&children[0]),
-            478 => self.expression02_op_4(&children[0]),
-            479 => self.factor_0(&children[0]),
-            480 => self.factor_1(&children[0]),
-            481 => self.factor_2(&children[0]),
-            482 => self.factor_3(&children[0], &children[1], &children[2]),
-            483 => self.factor_4(&children[0], &children[1], &children[2]),
-            484 => self.factor_5(&children[0], &children[1], &children[2]),
-            485 => self.factor_6(&children[0]),
-            486 => self.factor_7(&children[0]),
-            487 => self.factor_8(&children[0]),
-            488 => self.factor_9(&children[0]),
-            489 => self.factor_group_0(&children[0]),
-            490 => self.factor_group_1(&children[0]),
-            491 => self.factor_10(&children[0]),
-            492 => self.factor_11(&children[0]),
-            493 => self.factor_12(&children[0]),
-            494 => self.factor_13(&children[0]),
-            495 => self.boolean_literal_0(&children[0]),
-            496 => self.boolean_literal_1(&children[0]),
-            497 => self.identifier_factor(&children[0], &children[1]),
-            498 => self.identifier_factor_opt_0(&children[0]),
-            499 => self.identifier_factor_opt_group_0(&children[0]),
-            500 => self.identifier_factor_opt_group_1(&children[0]),
-            501 => self.identifier_factor_opt_1(),
-            502 => self.factor_type_factor(&children[0], &children[1]),
-            503 => self.factor_type_factor_list_0(&children[0], &children[1]),
-            504 => self.factor_type_factor_list_1(),
-            505 => self.function_call(&children[0], &children[1], &children[2]),
-            506 => self.function_call_opt_0(&children[0]),
-            507 => self.function_call_opt_1(),
-            508 => self.argument_list(&children[0], &children[1], &children[2]),
-            509 => self.argument_list_list_0(&children[0], &children[1], &children[2]),
-            510 => self.argument_list_list_1(),
-            511 => self.argument_list_opt_0(&children[0]),
-            512 => self.argument_list_opt_1(),
-            513 => self.argument_item(&children[0], &children[1]),
-            514 => self.argument_item_opt_0(&children[0], &children[1]),
-            515 => self.argument_item_opt_1(),
-            516 => self.argument_expression(&children[0]),
-            517 => self.struct_constructor(&children[0], &children[1], &children[2], &children[3]),
-            518 => self.struct_constructor_opt_0(
+            462 => self.if_expression_list_1(),
+            463 => self.expression01(&children[0], &children[1]),
+            464 => self.expression01_list_0(&children[0], &children[1], &children[2]),
+            465 => self.expression01_list_1(),
+            466 => self.expression02(&children[0], &children[1], &children[2]),
+            467 => self.expression02_list_0(&children[0], &children[1]),
+            468 => self.expression02_list_1(),
+            469 => self.expression02_opt_0(&children[0], &children[1]),
+            470 => self.expression02_opt_1(),
+            471 => self.expression01_op_0(&children[0]),
+            472 => self.expression01_op_1(&children[0]),
+            473 => self.expression01_op_2(&children[0]),
+            474 => self.expression01_op_3(&children[0]),
+            475 => self.expression01_op_4(&children[0]),
+            476 => self.expression01_op_5(&children[0]),
+            477 => self.expression01_op_6(&children[0]),
+            478 => self.expression01_op_7(&children[0]),
+            479 => self.expression01_op_8(&children[0]),
+            480 => self.expression02_op_0(&children[0]),
+            481 => self.expression02_op_1(&children[0]),
+            482 => self.expression02_op_2(&children[0]),
+            483 => self.expression02_op_3(&children[0]),
+            484 => self.expression02_op_4(&children[0]),
+            485 => self.factor_0(&children[0]),
+            486 => self.factor_1(&children[0]),
+            487 => self.factor_2(&children[0]),
+            488 => self.factor_3(&children[0], &children[1], &children[2]),
+            489 => self.factor_4(&children[0], &children[1], &children[2]),
+            490 => self.factor_5(&children[0], &children[1], &children[2]),
+            491 => self.factor_6(&children[0]),
+            492 => self.factor_7(&children[0]),
+            493 => self.factor_8(&children[0]),
+            494 => self.factor_9(&children[0]),
+            495 => self.factor_group_0(&children[0]),
+            496 => self.factor_group_1(&children[0]),
+            497 => self.factor_10(&children[0]),
+            498 => self.factor_11(&children[0]),
+            499 => self.factor_12(&children[0]),
+            500 => self.factor_13(&children[0]),
+            501 => self.boolean_literal_0(&children[0]),
+            502 => self.boolean_literal_1(&children[0]),
+            503 => self.identifier_factor(&children[0], &children[1]),
+            504 => self.identifier_factor_opt_0(&children[0]),
+            505 => self.identifier_factor_opt_group_0(&children[0]),
+            506 => self.identifier_factor_opt_group_1(&children[0]),
+            507 => self.identifier_factor_opt_1(),
+            508 => self.factor_type_factor(&children[0], &children[1]),
+            509 => self.factor_type_factor_list_0(&children[0], &children[1]),
+            510 => self.factor_type_factor_list_1(),
+            511 => self.function_call(&children[0], &children[1], &children[2]),
+            512 => self.function_call_opt_0(&children[0]),
+            513 => self.function_call_opt_1(),
+            514 => self.argument_list(&children[0], &children[1], &children[2]),
+            515 => self.argument_list_list_0(&children[0], &children[1], &children[2]),
+            516 => self.argument_list_list_1(),
+            517 => self.argument_list_opt_0(&children[0]),
+            518 => self.argument_list_opt_1(),
+            519 => self.argument_item(&children[0], &children[1]),
+            520 => self.argument_item_opt_0(&children[0], &children[1]),
+            521 => self.argument_item_opt_1(),
+            522 => self.argument_expression(&children[0], &children[1]),
+            523 => self.argument_expression_opt_0(&children[0]),
+            524 => self.argument_expression_opt_1(),
+            525 => self.property_implication(&children[0], &children[1], &children[2]),
+            526 => self.property_implication_opt_0(&children[0]),
+            527 => self.property_implication_opt_1(),
+            528 => self.cycle_delay(&children[0], &children[1]),
+            529 => self.cycle_delay_group_0(&children[0]),
+            530 => self.cycle_delay_group_1(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+            ),
+            531 => self.struct_constructor(&children[0], &children[1], &children[2], &children[3]),
+            532 => self.struct_constructor_opt_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            519 => self.struct_constructor_opt_1(),
-            520 => self.struct_constructor_list(&children[0], &children[1], &children[2]),
-            521 => self.struct_constructor_list_list_0(&children[0], &children[1], &children[2]),
-            522 => self.struct_constructor_list_list_1(),
-            523 => self.struct_constructor_list_opt_0(&children[0]),
-            524 => self.struct_constructor_list_opt_1(),
-            525 => self.struct_constructor_item(&children[0], &children[1], &children[2]),
-            526 => self.concatenation_list(&children[0], &children[1], &children[2]),
-            527 => self.concatenation_list_list_0(&children[0], &children[1], &children[2]),
-            528 => self.concatenation_list_list_1(),
-            529 => self.concatenation_list_opt_0(&children[0]),
-            530 => self.concatenation_list_opt_1(),
-            531 => self.concatenation_item(&children[0], &children[1]),
-            532 => self.concatenation_item_opt_0(&children[0], &children[1]),
-            533 => self.concatenation_item_opt_1(),
-            534 => self.array_literal_list(&children[0], &children[1], &children[2]),
-            535 => self.array_literal_list_list_0(&children[0], &children[1], &children[2]),
-            536 => self.array_literal_list_list_1(),
-            537 => self.array_literal_list_opt_0(&children[0]),
-            538 => self.array_literal_list_opt_1(),
-            539 => self.array_literal_item(&children[0]),
-            540 => self.array_literal_item_group_0(&children[0], &children[1]),
-            541 => self.array_literal_item_group_1(&children[0], &children[1], &children[2]),
-            542 => self.array_literal_item_opt_0(&children[0], &children[1]),
-            543 => self.array_literal_item_opt_1(),
-            544 => self.case_expression(
+            533 => self.struct_constructor_opt_1(),
+            534 => self.struct_constructor_list(&children[0], &children[1], &children[2]),
+            535 => self.struct_constructor_list_list_0(&children[0], &children[1], &children[2]),
+            536 => self.struct_constructor_list_list_1(),
+            537 => self.struct_constructor_list_opt_0(&children[0]),
+            538 => self.struct_constructor_list_opt_1(),
+            539 => self.struct_constructor_item(&children[0], &children[1], &children[2]),
+            540 => self.concatenation_list(&children[0], &children[1], &children[2]),
+            541 => self.concatenation_list_list_0(&children[0], &children[1], &children[2]),
+            542 => self.concatenation_list_list_1(),
+            543 => self.concatenation_list_opt_0(&children[0]),
+            544 => self.concatenation_list_opt_1(),
+            545 => self.concatenation_item(&children[0], &children[1]),
+            546 => self.concatenation_item_opt_0(&children[0], &children[1]),
+            547 => self.concatenation_item_opt_1(),
+            548 => self.array_literal_list(&children[0], &children[1], &children[2]),
+            549 => self.array_literal_list_list_0(&children[0], &children[1], &children[2]),
+            550 => self.array_literal_list_list_1(),
+            551 => self.array_literal_list_opt_0(&children[0]),
+            552 => self.array_literal_list_opt_1(),
+            553 => self.array_literal_item(&children[0]),
+            554 => self.array_literal_item_group_0(&children[0], &children[1]),
+            555 => self.array_literal_item_group_1(&children[0], &children[1], &children[2]),
+            556 => self.array_literal_item_opt_0(&children[0], &children[1]),
+            557 => self.array_literal_item_opt_1(),
+            558 => self.case_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42335,17 +42859,17 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[11],
                 &children[12],
             ),
-            545 => self.case_expression_list_0(
+            559 => self.case_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            546 => self.case_expression_list_1(),
-            547 => self.case_expression_opt_0(&children[0]),
-            548 => self.case_expression_opt_1(),
-            549 => self.switch_expression(
+            560 => self.case_expression_list_1(),
+            561 => self.case_expression_opt_0(&children[0]),
+            562 => self.case_expression_opt_1(),
+            563 => self.switch_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42359,159 +42883,159 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[10],
                 &children[11],
             ),
-            550 => self.switch_expression_list_0(
+            564 => self.switch_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            551 => self.switch_expression_list_1(),
-            552 => self.switch_expression_opt_0(&children[0]),
-            553 => self.switch_expression_opt_1(),
-            554 => self.type_expression(&children[0], &children[1], &children[2], &children[3]),
-            555 => self.inside_expression(
+            565 => self.switch_expression_list_1(),
+            566 => self.switch_expression_opt_0(&children[0]),
+            567 => self.switch_expression_opt_1(),
+            568 => self.type_expression(&children[0], &children[1], &children[2], &children[3]),
+            569 => self.inside_expression(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            556 => self.outside_expression(
+            570 => self.outside_expression(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            557 => self.range_list(&children[0], &children[1], &children[2]),
-            558 => self.range_list_list_0(&children[0], &children[1], &children[2]),
-            559 => self.range_list_list_1(),
-            560 => self.range_list_opt_0(&children[0]),
-            561 => self.range_list_opt_1(),
-            562 => self.range_item(&children[0]),
-            563 => self.select(&children[0], &children[1], &children[2], &children[3]),
-            564 => self.select_opt_0(&children[0], &children[1]),
-            565 => self.select_opt_1(),
-            566 => self.select_operator_0(&children[0]),
-            567 => self.select_operator_1(&children[0]),
-            568 => self.select_operator_2(&children[0]),
-            569 => self.select_operator_3(&children[0]),
-            570 => self.width(&children[0], &children[1], &children[2], &children[3]),
-            571 => self.width_list_0(&children[0], &children[1], &children[2]),
-            572 => self.width_list_1(),
-            573 => self.array(&children[0], &children[1], &children[2], &children[3]),
-            574 => self.array_list_0(&children[0], &children[1], &children[2]),
-            575 => self.array_list_1(),
-            576 => self.range(&children[0], &children[1]),
-            577 => self.range_opt_0(&children[0], &children[1]),
-            578 => self.range_opt_1(),
-            579 => self.range_operator_0(&children[0]),
-            580 => self.range_operator_1(&children[0]),
-            581 => self.fixed_type_0(&children[0]),
-            582 => self.fixed_type_1(&children[0]),
-            583 => self.fixed_type_2(&children[0]),
-            584 => self.fixed_type_3(&children[0]),
-            585 => self.fixed_type_4(&children[0]),
-            586 => self.fixed_type_5(&children[0]),
-            587 => self.fixed_type_6(&children[0]),
-            588 => self.fixed_type_7(&children[0]),
-            589 => self.fixed_type_8(&children[0]),
-            590 => self.fixed_type_9(&children[0]),
-            591 => self.fixed_type_10(&children[0]),
-            592 => self.fixed_type_11(&children[0]),
-            593 => self.fixed_type_12(&children[0]),
-            594 => self.fixed_type_13(&children[0]),
-            595 => self.fixed_type_14(&children[0]),
-            596 => self.fixed_type_15(&children[0]),
-            597 => self.fixed_type_16(&children[0]),
-            598 => self.variable_type_0(&children[0]),
-            599 => self.variable_type_1(&children[0]),
-            600 => self.variable_type_2(&children[0]),
-            601 => self.variable_type_3(&children[0]),
-            602 => self.variable_type_4(&children[0]),
-            603 => self.variable_type_5(&children[0]),
-            604 => self.variable_type_6(&children[0]),
-            605 => self.variable_type_7(&children[0]),
-            606 => self.variable_type_8(&children[0]),
-            607 => self.variable_type_9(&children[0]),
-            608 => self.user_defined_type(&children[0]),
-            609 => self.type_modifier_0(&children[0]),
-            610 => self.type_modifier_1(&children[0]),
-            611 => self.type_modifier_2(&children[0]),
-            612 => self.factor_type(&children[0]),
-            613 => self.factor_type_group_0(&children[0], &children[1]),
-            614 => self.factor_type_group_1(&children[0]),
-            615 => self.factor_type_opt_0(&children[0]),
-            616 => self.factor_type_opt_1(),
-            617 => self.scalar_type(&children[0], &children[1]),
-            618 => self.scalar_type_group_0(&children[0], &children[1]),
-            619 => self.scalar_type_group_1(&children[0]),
-            620 => self.scalar_type_list_0(&children[0], &children[1]),
-            621 => self.scalar_type_list_1(),
-            622 => self.scalar_type_opt_0(&children[0]),
-            623 => self.scalar_type_opt_1(),
-            624 => self.array_type(&children[0], &children[1]),
-            625 => self.array_type_opt_0(&children[0]),
-            626 => self.array_type_opt_1(),
-            627 => self.casting_type_0(&children[0]),
-            628 => self.casting_type_1(&children[0]),
-            629 => self.casting_type_2(&children[0]),
-            630 => self.casting_type_3(&children[0]),
-            631 => self.casting_type_4(&children[0]),
-            632 => self.casting_type_5(&children[0]),
-            633 => self.casting_type_6(&children[0]),
-            634 => self.casting_type_7(&children[0]),
-            635 => self.casting_type_8(&children[0]),
-            636 => self.casting_type_9(&children[0]),
-            637 => self.casting_type_10(&children[0]),
-            638 => self.casting_type_11(&children[0]),
-            639 => self.casting_type_12(&children[0]),
-            640 => self.casting_type_13(&children[0]),
-            641 => self.casting_type_14(&children[0]),
-            642 => self.casting_type_15(&children[0]),
-            643 => self.casting_type_16(&children[0]),
-            644 => self.casting_type_17(&children[0]),
-            645 => self.casting_type_18(&children[0]),
-            646 => self.casting_type_19(&children[0]),
-            647 => self.casting_type_20(&children[0]),
-            648 => self.casting_type_21(&children[0]),
-            649 => self.casting_type_22(&children[0]),
-            650 => self.casting_type_23(&children[0]),
-            651 => self.casting_type_24(&children[0]),
-            652 => self.casting_type_25(&children[0]),
-            653 => self.casting_type_26(&children[0]),
-            654 => self.clock_domain(&children[0], &children[1]),
-            655 => self.statement_block(&children[0], &children[1], &children[2]),
-            656 => self.statement_block_list_0(&children[0], &children[1]),
-            657 => self.statement_block_list_1(),
-            658 => self.statement_block_group(&children[0], &children[1]),
-            659 => self.statement_block_group_group_0(
+            571 => self.range_list(&children[0], &children[1], &children[2]),
+            572 => self.range_list_list_0(&children[0], &children[1], &children[2]),
+            573 => self.range_list_list_1(),
+            574 => self.range_list_opt_0(&children[0]),
+            575 => self.range_list_opt_1(),
+            576 => self.range_item(&children[0]),
+            577 => self.select(&children[0], &children[1], &children[2], &children[3]),
+            578 => self.select_opt_0(&children[0], &children[1]),
+            579 => self.select_opt_1(),
+            580 => self.select_operator_0(&children[0]),
+            581 => self.select_operator_1(&children[0]),
+            582 => self.select_operator_2(&children[0]),
+            583 => self.select_operator_3(&children[0]),
+            584 => self.width(&children[0], &children[1], &children[2], &children[3]),
+            585 => self.width_list_0(&children[0], &children[1], &children[2]),
+            586 => self.width_list_1(),
+            587 => self.array(&children[0], &children[1], &children[2], &children[3]),
+            588 => self.array_list_0(&children[0], &children[1], &children[2]),
+            589 => self.array_list_1(),
+            590 => self.range(&children[0], &children[1]),
+            591 => self.range_opt_0(&children[0], &children[1]),
+            592 => self.range_opt_1(),
+            593 => self.range_operator_0(&children[0]),
+            594 => self.range_operator_1(&children[0]),
+            595 => self.fixed_type_0(&children[0]),
+            596 => self.fixed_type_1(&children[0]),
+            597 => self.fixed_type_2(&children[0]),
+            598 => self.fixed_type_3(&children[0]),
+            599 => self.fixed_type_4(&children[0]),
+            600 => self.fixed_type_5(&children[0]),
+            601 => self.fixed_type_6(&children[0]),
+            602 => self.fixed_type_7(&children[0]),
+            603 => self.fixed_type_8(&children[0]),
+            604 => self.fixed_type_9(&children[0]),
+            605 => self.fixed_type_10(&children[0]),
+            606 => self.fixed_type_11(&children[0]),
+            607 => self.fixed_type_12(&children[0]),
+            608 => self.fixed_type_13(&children[0]),
+            609 => self.fixed_type_14(&children[0]),
+            610 => self.fixed_type_15(&children[0]),
+            611 => self.fixed_type_16(&children[0]),
+            612 => self.variable_type_0(&children[0]),
+            613 => self.variable_type_1(&children[0]),
+            614 => self.variable_type_2(&children[0]),
+            615 => self.variable_type_3(&children[0]),
+            616 => self.variable_type_4(&children[0]),
+            617 => self.variable_type_5(&children[0]),
+            618 => self.variable_type_6(&children[0]),
+            619 => self.variable_type_7(&children[0]),
+            620 => self.variable_type_8(&children[0]),
+            621 => self.variable_type_9(&children[0]),
+            622 => self.user_defined_type(&children[0]),
+            623 => self.type_modifier_0(&children[0]),
+            624 => self.type_modifier_1(&children[0]),
+            625 => self.type_modifier_2(&children[0]),
+            626 => self.factor_type(&children[0]),
+            627 => self.factor_type_group_0(&children[0], &children[1]),
+            628 => self.factor_type_group_1(&children[0]),
+            629 => self.factor_type_opt_0(&children[0]),
+            630 => self.factor_type_opt_1(),
+            631 => self.scalar_type(&children[0], &children[1]),
+            632 => self.scalar_type_group_0(&children[0], &children[1]),
+            633 => self.scalar_type_group_1(&children[0]),
+            634 => self.scalar_type_list_0(&children[0], &children[1]),
+            635 => self.scalar_type_list_1(),
+            636 => self.scalar_type_opt_0(&children[0]),
+            637 => self.scalar_type_opt_1(),
+            638 => self.array_type(&children[0], &children[1]),
+            639 => self.array_type_opt_0(&children[0]),
+            640 => self.array_type_opt_1(),
+            641 => self.casting_type_0(&children[0]),
+            642 => self.casting_type_1(&children[0]),
+            643 => self.casting_type_2(&children[0]),
+            644 => self.casting_type_3(&children[0]),
+            645 => self.casting_type_4(&children[0]),
+            646 => self.casting_type_5(&children[0]),
+            647 => self.casting_type_6(&children[0]),
+            648 => self.casting_type_7(&children[0]),
+            649 => self.casting_type_8(&children[0]),
+            650 => self.casting_type_9(&children[0]),
+            651 => self.casting_type_10(&children[0]),
+            652 => self.casting_type_11(&children[0]),
+            653 => self.casting_type_12(&children[0]),
+            654 => self.casting_type_13(&children[0]),
+            655 => self.casting_type_14(&children[0]),
+            656 => self.casting_type_15(&children[0]),
+            657 => self.casting_type_16(&children[0]),
+            658 => self.casting_type_17(&children[0]),
+            659 => self.casting_type_18(&children[0]),
+            660 => self.casting_type_19(&children[0]),
+            661 => self.casting_type_20(&children[0]),
+            662 => self.casting_type_21(&children[0]),
+            663 => self.casting_type_22(&children[0]),
+            664 => self.casting_type_23(&children[0]),
+            665 => self.casting_type_24(&children[0]),
+            666 => self.casting_type_25(&children[0]),
+            667 => self.casting_type_26(&children[0]),
+            668 => self.clock_domain(&children[0], &children[1]),
+            669 => self.statement_block(&children[0], &children[1], &children[2]),
+            670 => self.statement_block_list_0(&children[0], &children[1]),
+            671 => self.statement_block_list_1(),
+            672 => self.statement_block_group(&children[0], &children[1]),
+            673 => self.statement_block_group_group_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            660 => self.statement_block_group_group_list_0(&children[0], &children[1]),
-            661 => self.statement_block_group_group_list_1(),
-            662 => self.statement_block_group_group_1(&children[0]),
-            663 => self.statement_block_group_list_0(&children[0], &children[1]),
-            664 => self.statement_block_group_list_1(),
-            665 => self.statement_block_item_0(&children[0]),
-            666 => self.statement_block_item_1(&children[0]),
-            667 => self.statement_block_item_2(&children[0]),
-            668 => self.statement_block_item_3(&children[0]),
-            669 => self.statement_block_item_4(&children[0]),
-            670 => self.statement_block_item_5(&children[0]),
-            671 => self.statement_0(&children[0]),
-            672 => self.statement_1(&children[0]),
-            673 => self.statement_2(&children[0]),
-            674 => self.statement_3(&children[0]),
-            675 => self.statement_4(&children[0]),
-            676 => self.statement_5(&children[0]),
-            677 => self.statement_6(&children[0]),
-            678 => self.statement_7(&children[0]),
-            679 => self.let_statement(
+            674 => self.statement_block_group_group_list_0(&children[0], &children[1]),
+            675 => self.statement_block_group_group_list_1(),
+            676 => self.statement_block_group_group_1(&children[0]),
+            677 => self.statement_block_group_list_0(&children[0], &children[1]),
+            678 => self.statement_block_group_list_1(),
+            679 => self.statement_block_item_0(&children[0]),
+            680 => self.statement_block_item_1(&children[0]),
+            681 => self.statement_block_item_2(&children[0]),
+            682 => self.statement_block_item_3(&children[0]),
+            683 => self.statement_block_item_4(&children[0]),
+            684 => self.statement_block_item_5(&children[0]),
+            685 => self.statement_0(&children[0]),
+            686 => self.statement_1(&children[0]),
+            687 => self.statement_2(&children[0]),
+            688 => self.statement_3(&children[0]),
+            689 => self.statement_4(&children[0]),
+            690 => self.statement_5(&children[0]),
+            691 => self.statement_6(&children[0]),
+            692 => self.statement_7(&children[0]),
+            693 => self.let_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42519,14 +43043,14 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            680 => self.let_statement_opt_0(&children[0], &children[1], &children[2]),
-            681 => self.let_statement_opt0_0(&children[0]),
-            682 => self.let_statement_opt0_1(),
-            683 => self.let_statement_opt_1(),
-            684 => self.identifier_statement(&children[0], &children[1], &children[2]),
-            685 => self.identifier_statement_group_0(&children[0]),
-            686 => self.identifier_statement_group_1(&children[0]),
-            687 => self.concatenation_assignment(
+            694 => self.let_statement_opt_0(&children[0], &children[1], &children[2]),
+            695 => self.let_statement_opt0_0(&children[0]),
+            696 => self.let_statement_opt0_1(),
+            697 => self.let_statement_opt_1(),
+            698 => self.identifier_statement(&children[0], &children[1], &children[2]),
+            699 => self.identifier_statement_group_0(&children[0]),
+            700 => self.identifier_statement_group_1(&children[0]),
+            701 => self.concatenation_assignment(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42534,41 +43058,41 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            688 => self.assignment(&children[0], &children[1]),
-            689 => self.assignment_group_0(&children[0]),
-            690 => self.assignment_group_1(&children[0]),
-            691 => self.assignment_group_2(&children[0]),
-            692 => self.if_statement(
+            702 => self.assignment(&children[0], &children[1]),
+            703 => self.assignment_group_0(&children[0]),
+            704 => self.assignment_group_1(&children[0]),
+            705 => self.assignment_group_2(&children[0]),
+            706 => self.if_statement(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            693 => self.if_statement_list_0(
+            707 => self.if_statement_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            694 => self.if_statement_list_1(),
-            695 => self.if_statement_opt_0(&children[0], &children[1]),
-            696 => self.if_statement_opt_1(),
-            697 => self.if_reset_statement(&children[0], &children[1], &children[2], &children[3]),
-            698 => self.if_reset_statement_list_0(
+            708 => self.if_statement_list_1(),
+            709 => self.if_statement_opt_0(&children[0], &children[1]),
+            710 => self.if_statement_opt_1(),
+            711 => self.if_reset_statement(&children[0], &children[1], &children[2], &children[3]),
+            712 => self.if_reset_statement_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            699 => self.if_reset_statement_list_1(),
-            700 => self.if_reset_statement_opt_0(&children[0], &children[1]),
-            701 => self.if_reset_statement_opt_1(),
-            702 => self.return_statement(&children[0], &children[1], &children[2]),
-            703 => self.break_statement(&children[0], &children[1]),
-            704 => self.for_statement(
+            713 => self.if_reset_statement_list_1(),
+            714 => self.if_reset_statement_opt_0(&children[0], &children[1]),
+            715 => self.if_reset_statement_opt_1(),
+            716 => self.return_statement(&children[0], &children[1], &children[2]),
+            717 => self.break_statement(&children[0], &children[1]),
+            718 => self.for_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42577,49 +43101,49 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            705 => self.for_statement_opt0_0(&children[0], &children[1], &children[2]),
-            706 => self.for_statement_opt0_1(),
-            707 => self.for_statement_opt_0(&children[0]),
-            708 => self.for_statement_opt_1(),
-            709 => self.case_statement(
+            719 => self.for_statement_opt0_0(&children[0], &children[1], &children[2]),
+            720 => self.for_statement_opt0_1(),
+            721 => self.for_statement_opt_0(&children[0]),
+            722 => self.for_statement_opt_1(),
+            723 => self.case_statement(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            710 => self.case_statement_list_0(&children[0], &children[1]),
-            711 => self.case_statement_list_1(),
-            712 => self.case_item(&children[0], &children[1], &children[2]),
-            713 => self.case_item_group0_0(&children[0]),
-            714 => self.case_item_group0_1(&children[0]),
-            715 => self.case_item_group_0(&children[0]),
-            716 => self.case_item_group_1(&children[0]),
-            717 => self.case_condition(&children[0], &children[1]),
-            718 => self.case_condition_list_0(&children[0], &children[1], &children[2]),
-            719 => self.case_condition_list_1(),
-            720 => self.switch_statement(&children[0], &children[1], &children[2], &children[3]),
-            721 => self.switch_statement_list_0(&children[0], &children[1]),
-            722 => self.switch_statement_list_1(),
-            723 => self.switch_item(&children[0], &children[1], &children[2]),
-            724 => self.switch_item_group0_0(&children[0]),
-            725 => self.switch_item_group0_1(&children[0]),
-            726 => self.switch_item_group_0(&children[0]),
-            727 => self.switch_item_group_1(&children[0]),
-            728 => self.switch_condition(&children[0], &children[1]),
-            729 => self.switch_condition_list_0(&children[0], &children[1], &children[2]),
-            730 => self.switch_condition_list_1(),
-            731 => self.attribute(&children[0], &children[1], &children[2], &children[3]),
-            732 => self.attribute_opt_0(&children[0], &children[1], &children[2]),
-            733 => self.attribute_opt_1(),
-            734 => self.attribute_list(&children[0], &children[1], &children[2]),
-            735 => self.attribute_list_list_0(&children[0], &children[1], &children[2]),
-            736 => self.attribute_list_list_1(),
-            737 => self.attribute_list_opt_0(&children[0]),
-            738 => self.attribute_list_opt_1(),
-            739 => self.attribute_item_0(&children[0]),
-            740 => self.attribute_item_1(&children[0]),
-            741 => self.let_declaration(
+            724 => self.case_statement_list_0(&children[0], &children[1]),
+            725 => self.case_statement_list_1(),
+            726 => self.case_item(&children[0], &children[1], &children[2]),
+            727 => self.case_item_group0_0(&children[0]),
+            728 => self.case_item_group0_1(&children[0]),
+            729 => self.case_item_group_0(&children[0]),
+            730 => self.case_item_group_1(&children[0]),
+            731 => self.case_condition(&children[0], &children[1]),
+            732 => self.case_condition_list_0(&children[0], &children[1], &children[2]),
+            733 => self.case_condition_list_1(),
+            734 => self.switch_statement(&children[0], &children[1], &children[2], &children[3]),
+            735 => self.switch_statement_list_0(&children[0], &children[1]),
+            736 => self.switch_statement_list_1(),
+            737 => self.switch_item(&children[0], &children[1], &children[2]),
+            738 => self.switch_item_group0_0(&children[0]),
+            739 => self.switch_item_group0_1(&children[0]),
+            740 => self.switch_item_group_0(&children[0]),
+            741 => self.switch_item_group_1(&children[0]),
+            742 => self.switch_condition(&children[0], &children[1]),
+            743 => self.switch_condition_list_0(&children[0], &children[1], &children[2]),
+            744 => self.switch_condition_list_1(),
+            745 => self.attribute(&children[0], &children[1], &children[2], &children[3]),
+            746 => self.attribute_opt_0(&children[0], &children[1], &children[2]),
+            747 => self.attribute_opt_1(),
+            748 => self.attribute_list(&children[0], &children[1], &children[2]),
+            749 => self.attribute_list_list_0(&children[0], &children[1], &children[2]),
+            750 => self.attribute_list_list_1(),
+            751 => self.attribute_list_opt_0(&children[0]),
+            752 => self.attribute_list_opt_1(),
+            753 => self.attribute_item_0(&children[0]),
+            754 => self.attribute_item_1(&children[0]),
+            755 => self.let_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42627,16 +43151,16 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            742 => self.let_declaration_opt_0(&children[0], &children[1], &children[2]),
-            743 => self.let_declaration_opt0_0(&children[0]),
-            744 => self.let_declaration_opt0_1(),
-            745 => self.let_declaration_opt_1(),
-            746 => self.var_declaration(&children[0], &children[1], &children[2], &children[3]),
-            747 => self.var_declaration_opt_0(&children[0], &children[1], &children[2]),
-            748 => self.var_declaration_opt0_0(&children[0]),
-            749 => self.var_declaration_opt0_1(),
-            750 => self.var_declaration_opt_1(),
-            751 => self.const_declaration(
+            756 => self.let_declaration_opt_0(&children[0], &children[1], &children[2]),
+            757 => self.let_declaration_opt0_0(&children[0]),
+            758 => self.let_declaration_opt0_1(),
+            759 => self.let_declaration_opt_1(),
+            760 => self.var_declaration(&children[0], &children[1], &children[2], &children[3]),
+            761 => self.var_declaration_opt_0(&children[0], &children[1], &children[2]),
+            762 => self.var_declaration_opt0_0(&children[0]),
+            763 => self.var_declaration_opt0_1(),
+            764 => self.var_declaration_opt_1(),
+            765 => self.const_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42644,11 +43168,11 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            752 => self.const_declaration_opt_0(&children[0], &children[1]),
-            753 => self.const_declaration_opt_group_0(&children[0]),
-            754 => self.const_declaration_opt_group_1(&children[0]),
-            755 => self.const_declaration_opt_1(),
-            756 => self.gen_declaration(
+            766 => self.const_declaration_opt_0(&children[0], &children[1]),
+            767 => self.const_declaration_opt_group_0(&children[0]),
+            768 => self.const_declaration_opt_group_1(&children[0]),
+            769 => self.const_declaration_opt_1(),
+            770 => self.gen_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42657,49 +43181,49 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            757 => self.gen_declaration_group_0(&children[0]),
-            758 => self.gen_declaration_group_1(&children[0]),
-            759 => self.type_def_declaration(
+            771 => self.gen_declaration_group_0(&children[0]),
+            772 => self.gen_declaration_group_1(&children[0]),
+            773 => self.type_def_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            760 => self.always_ff_declaration(&children[0], &children[1], &children[2]),
-            761 => self.always_ff_declaration_opt_0(&children[0]),
-            762 => self.always_ff_declaration_opt_1(),
-            763 => {
+            774 => self.always_ff_declaration(&children[0], &children[1], &children[2]),
+            775 => self.always_ff_declaration_opt_0(&children[0]),
+            776 => self.always_ff_declaration_opt_1(),
+            777 => {
                 self.always_ff_event_list(&children[0], &children[1], &children[2], &children[3])
             }
-            764 => self.always_ff_event_list_opt_0(&children[0], &children[1]),
-            765 => self.always_ff_event_list_opt_1(),
-            766 => self.always_ff_clock(&children[0]),
-            767 => self.always_ff_reset(&children[0]),
-            768 => self.always_comb_declaration(&children[0], &children[1]),
-            769 => self.assign_declaration(
+            778 => self.always_ff_event_list_opt_0(&children[0], &children[1]),
+            779 => self.always_ff_event_list_opt_1(),
+            780 => self.always_ff_clock(&children[0]),
+            781 => self.always_ff_reset(&children[0]),
+            782 => self.always_comb_declaration(&children[0], &children[1]),
+            783 => self.assign_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            770 => self.assign_destination_0(&children[0]),
-            771 => self.assign_destination_1(&children[0], &children[1], &children[2]),
-            772 => self.assign_concatenation_list(&children[0], &children[1], &children[2]),
-            773 => self.assign_concatenation_list_list_0(&children[0], &children[1], &children[2]),
-            774 => self.assign_concatenation_list_list_1(),
-            775 => self.assign_concatenation_list_opt_0(&children[0]),
-            776 => self.assign_concatenation_list_opt_1(),
-            777 => self.assign_concatenation_item(&children[0]),
-            778 => self.connect_declaration(
+            784 => self.assign_destination_0(&children[0]),
+            785 => self.assign_destination_1(&children[0], &children[1], &children[2]),
+            786 => self.assign_concatenation_list(&children[0], &children[1], &children[2]),
+            787 => self.assign_concatenation_list_list_0(&children[0], &children[1], &children[2]),
+            788 => self.assign_concatenation_list_list_1(),
+            789 => self.assign_concatenation_list_opt_0(&children[0]),
+            790 => self.assign_concatenation_list_opt_1(),
+            791 => self.assign_concatenation_item(&children[0]),
+            792 => self.connect_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            779 => self.modport_declaration(
+            793 => self.modport_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42707,31 +43231,31 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            780 => self.modport_declaration_opt0_0(&children[0], &children[1]),
-            781 => self.modport_declaration_opt0_1(),
-            782 => self.modport_declaration_opt_0(&children[0]),
-            783 => self.modport_declaration_opt_1(),
-            784 => self.modport_list(&children[0], &children[1], &children[2]),
-            785 => self.modport_list_list_0(&children[0], &children[1], &children[2]),
-            786 => self.modport_list_list_1(),
-            787 => self.modport_list_opt_0(&children[0]),
-            788 => self.modport_list_opt_1(),
-            789 => self.modport_group(&children[0], &children[1]),
-            790 => self.modport_group_group_0(&children[0], &children[1], &children[2]),
-            791 => self.modport_group_group_1(&children[0]),
-            792 => self.modport_group_list_0(&children[0], &children[1]),
-            793 => self.modport_group_list_1(),
-            794 => self.modport_item(&children[0], &children[1], &children[2]),
-            795 => self.modport_default_0(&children[0]),
-            796 => self.modport_default_1(&children[0]),
-            797 => self.modport_default_2(&children[0], &children[1], &children[2], &children[3]),
-            798 => self.modport_default_3(&children[0], &children[1], &children[2], &children[3]),
-            799 => self.modport_default_list(&children[0], &children[1], &children[2]),
-            800 => self.modport_default_list_list_0(&children[0], &children[1], &children[2]),
-            801 => self.modport_default_list_list_1(),
-            802 => self.modport_default_list_opt_0(&children[0]),
-            803 => self.modport_default_list_opt_1(),
-            804 => self.enum_declaration(
+            794 => self.modport_declaration_opt0_0(&children[0], &children[1]),
+            795 => self.modport_declaration_opt0_1(),
+            796 => self.modport_declaration_opt_0(&children[0]),
+            797 => self.modport_declaration_opt_1(),
+            798 => self.modport_list(&children[0], &children[1], &children[2]),
+            799 => self.modport_list_list_0(&children[0], &children[1], &children[2]),
+            800 => self.modport_list_list_1(),
+            801 => self.modport_list_opt_0(&children[0]),
+            802 => self.modport_list_opt_1(),
+            803 => self.modport_group(&children[0], &children[1]),
+            804 => self.modport_group_group_0(&children[0], &children[1], &children[2]),
+            805 => self.modport_group_group_1(&children[0]),
+            806 => self.modport_group_list_0(&children[0], &children[1]),
+            807 => self.modport_group_list_1(),
+            808 => self.modport_item(&children[0], &children[1], &children[2]),
+            809 => self.modport_default_0(&children[0]),
+            810 => self.modport_default_1(&children[0]),
+            811 => self.modport_default_2(&children[0], &children[1], &children[2], &children[3]),
+            812 => self.modport_default_3(&children[0], &children[1], &children[2], &children[3]),
+            813 => self.modport_default_list(&children[0], &children[1], &children[2]),
+            814 => self.modport_default_list_list_0(&children[0], &children[1], &children[2]),
+            815 => self.modport_default_list_list_1(),
+            816 => self.modport_default_list_opt_0(&children[0]),
+            817 => self.modport_default_list_opt_1(),
+            818 => self.enum_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42739,24 +43263,24 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            805 => self.enum_declaration_opt_0(&children[0], &children[1]),
-            806 => self.enum_declaration_opt_1(),
-            807 => self.enum_list(&children[0], &children[1], &children[2]),
-            808 => self.enum_list_list_0(&children[0], &children[1], &children[2]),
-            809 => self.enum_list_list_1(),
-            810 => self.enum_list_opt_0(&children[0]),
-            811 => self.enum_list_opt_1(),
-            812 => self.enum_group(&children[0], &children[1]),
-            813 => self.enum_group_group_0(&children[0], &children[1], &children[2]),
-            814 => self.enum_group_group_1(&children[0]),
-            815 => self.enum_group_list_0(&children[0], &children[1]),
-            816 => self.enum_group_list_1(),
-            817 => self.enum_item(&children[0], &children[1]),
-            818 => self.enum_item_opt_0(&children[0], &children[1]),
-            819 => self.enum_item_opt_1(),
-            820 => self.struct_union_0(&children[0]),
-            821 => self.struct_union_1(&children[0]),
-            822 => self.struct_union_declaration(
+            819 => self.enum_declaration_opt_0(&children[0], &children[1]),
+            820 => self.enum_declaration_opt_1(),
+            821 => self.enum_list(&children[0], &children[1], &children[2]),
+            822 => self.enum_list_list_0(&children[0], &children[1], &children[2]),
+            823 => self.enum_list_list_1(),
+            824 => self.enum_list_opt_0(&children[0]),
+            825 => self.enum_list_opt_1(),
+            826 => self.enum_group(&children[0], &children[1]),
+            827 => self.enum_group_group_0(&children[0], &children[1], &children[2]),
+            828 => self.enum_group_group_1(&children[0]),
+            829 => self.enum_group_list_0(&children[0], &children[1]),
+            830 => self.enum_group_list_1(),
+            831 => self.enum_item(&children[0], &children[1]),
+            832 => self.enum_item_opt_0(&children[0], &children[1]),
+            833 => self.enum_item_opt_1(),
+            834 => self.struct_union_0(&children[0]),
+            835 => self.struct_union_1(&children[0]),
+            836 => self.struct_union_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42764,30 +43288,30 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            823 => self.struct_union_declaration_opt_0(&children[0]),
-            824 => self.struct_union_declaration_opt_1(),
-            825 => self.struct_union_list(&children[0], &children[1], &children[2]),
-            826 => self.struct_union_list_list_0(&children[0], &children[1], &children[2]),
-            827 => self.struct_union_list_list_1(),
-            828 => self.struct_union_list_opt_0(&children[0]),
-            829 => self.struct_union_list_opt_1(),
-            830 => self.struct_union_group(&children[0], &children[1]),
-            831 => self.struct_union_group_group_0(&children[0], &children[1], &children[2]),
-            832 => self.struct_union_group_group_1(&children[0]),
-            833 => self.struct_union_group_list_0(&children[0], &children[1]),
-            834 => self.struct_union_group_list_1(),
-            835 => self.struct_union_item(&children[0], &children[1], &children[2]),
-            836 => self.initial_declaration(&children[0], &children[1]),
-            837 => self.final_declaration(&children[0], &children[1]),
-            838 => self.inst_declaration(&children[0], &children[1], &children[2]),
-            839 => self.bind_declaration(
+            837 => self.struct_union_declaration_opt_0(&children[0]),
+            838 => self.struct_union_declaration_opt_1(),
+            839 => self.struct_union_list(&children[0], &children[1], &children[2]),
+            840 => self.struct_union_list_list_0(&children[0], &children[1], &children[2]),
+            841 => self.struct_union_list_list_1(),
+            842 => self.struct_union_list_opt_0(&children[0]),
+            843 => self.struct_union_list_opt_1(),
+            844 => self.struct_union_group(&children[0], &children[1]),
+            845 => self.struct_union_group_group_0(&children[0], &children[1], &children[2]),
+            846 => self.struct_union_group_group_1(&children[0]),
+            847 => self.struct_union_group_list_0(&children[0], &children[1]),
+            848 => self.struct_union_group_list_1(),
+            849 => self.struct_union_item(&children[0], &children[1], &children[2]),
+            850 => self.initial_declaration(&children[0], &children[1]),
+            851 => self.final_declaration(&children[0], &children[1]),
+            852 => self.inst_declaration(&children[0], &children[1], &children[2]),
+            853 => self.bind_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            840 => self.component_instantiation(
+            854 => self.component_instantiation(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42796,140 +43320,140 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            841 => self.component_instantiation_opt2_0(&children[0]),
-            842 => self.component_instantiation_opt2_1(),
-            843 => self.component_instantiation_opt1_0(&children[0]),
-            844 => self.component_instantiation_opt1_1(),
-            845 => self.component_instantiation_opt0_0(&children[0]),
-            846 => self.component_instantiation_opt0_1(),
-            847 => self.component_instantiation_opt_0(&children[0]),
-            848 => self.component_instantiation_opt_1(),
-            849 => self.inst_parameter(&children[0], &children[1], &children[2], &children[3]),
-            850 => self.inst_parameter_opt_0(&children[0]),
-            851 => self.inst_parameter_opt_1(),
-            852 => self.inst_parameter_list(&children[0], &children[1], &children[2]),
-            853 => self.inst_parameter_list_list_0(&children[0], &children[1], &children[2]),
-            854 => self.inst_parameter_list_list_1(),
-            855 => self.inst_parameter_list_opt_0(&children[0]),
-            856 => self.inst_parameter_list_opt_1(),
-            857 => self.inst_parameter_group(&children[0], &children[1]),
-            858 => self.inst_parameter_group_group_0(&children[0], &children[1], &children[2]),
-            859 => self.inst_parameter_group_group_1(&children[0]),
-            860 => self.inst_parameter_group_list_0(&children[0], &children[1]),
-            861 => self.inst_parameter_group_list_1(),
-            862 => self.inst_parameter_item(&children[0], &children[1]),
-            863 => self.inst_parameter_item_opt_0(&children[0], &children[1]),
-            864 => self.inst_parameter_item_opt_1(),
-            865 => self.inst_port(&children[0], &children[1], &children[2]),
-            866 => self.inst_port_opt_0(&children[0]),
-            867 => self.inst_port_opt_1(),
-            868 => self.inst_port_list(&children[0], &children[1], &children[2]),
-            869 => self.inst_port_list_list_0(&children[0], &children[1], &children[2]),
-            870 => self.inst_port_list_list_1(),
-            871 => self.inst_port_list_opt_0(&children[0]),
-            872 => self.inst_port_list_opt_1(),
-            873 => self.inst_port_group(&children[0], &children[1]),
-            874 => self.inst_port_group_group_0(&children[0], &children[1], &children[2]),
-            875 => self.inst_port_group_group_1(&children[0]),
-            876 => self.inst_port_group_list_0(&children[0], &children[1]),
-            877 => self.inst_port_group_list_1(),
-            878 => self.inst_port_item(&children[0], &children[1]),
-            879 => self.inst_port_item_opt_0(&children[0], &children[1]),
-            880 => self.inst_port_item_opt_1(),
-            881 => self.with_parameter(&children[0], &children[1], &children[2], &children[3]),
-            882 => self.with_parameter_opt_0(&children[0]),
-            883 => self.with_parameter_opt_1(),
-            884 => self.with_parameter_list(&children[0], &children[1], &children[2]),
-            885 => self.with_parameter_list_list_0(&children[0], &children[1], &children[2]),
-            886 => self.with_parameter_list_list_1(),
-            887 => self.with_parameter_list_opt_0(&children[0]),
-            888 => self.with_parameter_list_opt_1(),
-            889 => self.with_parameter_group(&children[0], &children[1]),
-            890 => self.with_parameter_group_group_0(&children[0], &children[1], &children[2]),
-            891 => self.with_parameter_group_group_1(&children[0]),
-            892 => self.with_parameter_group_list_0(&children[0], &children[1]),
-            893 => self.with_parameter_group_list_1(),
-            894 => self.with_parameter_item(
+            855 => self.component_instantiation_opt2_0(&children[0]),
+            856 => self.component_instantiation_opt2_1(),
+            857 => self.component_instantiation_opt1_0(&children[0]),
+            858 => self.component_instantiation_opt1_1(),
+            859 => self.component_instantiation_opt0_0(&children[0]),
+            860 => self.component_instantiation_opt0_1(),
+            861 => self.component_instantiation_opt_0(&children[0]),
+            862 => self.component_instantiation_opt_1(),
+            863 => self.inst_parameter(&children[0], &children[1], &children[2], &children[3]),
+            864 => self.inst_parameter_opt_0(&children[0]),
+            865 => self.inst_parameter_opt_1(),
+            866 => self.inst_parameter_list(&children[0], &children[1], &children[2]),
+            867 => self.inst_parameter_list_list_0(&children[0], &children[1], &children[2]),
+            868 => self.inst_parameter_list_list_1(),
+            869 => self.inst_parameter_list_opt_0(&children[0]),
+            870 => self.inst_parameter_list_opt_1(),
+            871 => self.inst_parameter_group(&children[0], &children[1]),
+            872 => self.inst_parameter_group_group_0(&children[0], &children[1], &children[2]),
+            873 => self.inst_parameter_group_group_1(&children[0]),
+            874 => self.inst_parameter_group_list_0(&children[0], &children[1]),
+            875 => self.inst_parameter_group_list_1(),
+            876 => self.inst_parameter_item(&children[0], &children[1]),
+            877 => self.inst_parameter_item_opt_0(&children[0], &children[1]),
+            878 => self.inst_parameter_item_opt_1(),
+            879 => self.inst_port(&children[0], &children[1], &children[2]),
+            880 => self.inst_port_opt_0(&children[0]),
+            881 => self.inst_port_opt_1(),
+            882 => self.inst_port_list(&children[0], &children[1], &children[2]),
+            883 => self.inst_port_list_list_0(&children[0], &children[1], &children[2]),
+            884 => self.inst_port_list_list_1(),
+            885 => self.inst_port_list_opt_0(&children[0]),
+            886 => self.inst_port_list_opt_1(),
+            887 => self.inst_port_group(&children[0], &children[1]),
+            888 => self.inst_port_group_group_0(&children[0], &children[1], &children[2]),
+            889 => self.inst_port_group_group_1(&children[0]),
+            890 => self.inst_port_group_list_0(&children[0], &children[1]),
+            891 => self.inst_port_group_list_1(),
+            892 => self.inst_port_item(&children[0], &children[1]),
+            893 => self.inst_port_item_opt_0(&children[0], &children[1]),
+            894 => self.inst_port_item_opt_1(),
+            895 => self.with_parameter(&children[0], &children[1], &children[2], &children[3]),
+            896 => self.with_parameter_opt_0(&children[0]),
+            897 => self.with_parameter_opt_1(),
+            898 => self.with_parameter_list(&children[0], &children[1], &children[2]),
+            899 => self.with_parameter_list_list_0(&children[0], &children[1], &children[2]),
+            900 => self.with_parameter_list_list_1(),
+            901 => self.with_parameter_list_opt_0(&children[0]),
+            902 => self.with_parameter_list_opt_1(),
+            903 => self.with_parameter_group(&children[0], &children[1]),
+            904 => self.with_parameter_group_group_0(&children[0], &children[1], &children[2]),
+            905 => self.with_parameter_group_group_1(&children[0]),
+            906 => self.with_parameter_group_list_0(&children[0], &children[1]),
+            907 => self.with_parameter_group_list_1(),
+            908 => self.with_parameter_item(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            895 => self.with_parameter_item_group0_0(&children[0]),
-            896 => self.with_parameter_item_group0_1(&children[0]),
-            897 => self.with_parameter_item_group_0(&children[0]),
-            898 => self.with_parameter_item_group_1(&children[0]),
-            899 => self.with_parameter_item_opt_0(&children[0], &children[1]),
-            900 => self.with_parameter_item_opt_1(),
-            901 => self.generic_bound_0(&children[0]),
-            902 => self.generic_bound_1(&children[0], &children[1]),
-            903 => self.generic_bound_2(&children[0]),
-            904 => self.with_generic_parameter(&children[0], &children[1], &children[2]),
-            905 => self.with_generic_parameter_list(&children[0], &children[1], &children[2]),
-            906 => {
+            909 => self.with_parameter_item_group0_0(&children[0]),
+            910 => self.with_parameter_item_group0_1(&children[0]),
+            911 => self.with_parameter_item_group_0(&children[0]),
+            912 => self.with_parameter_item_group_1(&children[0]),
+            913 => self.with_parameter_item_opt_0(&children[0], &children[1]),
+            914 => self.with_parameter_item_opt_1(),
+            915 => self.generic_bound_0(&children[0]),
+            916 => self.generic_bound_1(&children[0], &children[1]),
+            917 => self.generic_bound_2(&children[0]),
+            918 => self.with_generic_parameter(&children[0], &children[1], &children[2]),
+            919 => self.with_generic_parameter_list(&children[0], &children[1], &children[2]),
+            920 => {
                 self.with_generic_parameter_list_list_0(&children[0], &children[1], &children[2])
             }
-            907 => self.with_generic_parameter_list_list_1(),
-            908 => self.with_generic_parameter_list_opt_0(&children[0]),
-            909 => self.with_generic_parameter_list_opt_1(),
-            910 => self.with_generic_parameter_item(
+            921 => self.with_generic_parameter_list_list_1(),
+            922 => self.with_generic_parameter_list_opt_0(&children[0]),
+            923 => self.with_generic_parameter_list_opt_1(),
+            924 => self.with_generic_parameter_item(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            911 => self.with_generic_parameter_item_opt_0(&children[0], &children[1]),
-            912 => self.with_generic_parameter_item_opt_1(),
-            913 => self.generic_proto_bound_0(&children[0]),
-            914 => self.generic_proto_bound_1(&children[0]),
-            915 => self.with_generic_argument(&children[0], &children[1], &children[2]),
-            916 => self.with_generic_argument_opt_0(&children[0]),
-            917 => self.with_generic_argument_opt_1(),
-            918 => self.with_generic_argument_list(&children[0], &children[1], &children[2]),
-            919 => self.with_generic_argument_list_list_0(&children[0], &children[1], &children[2]),
-            920 => self.with_generic_argument_list_list_1(),
-            921 => self.with_generic_argument_list_opt_0(&children[0]),
-            922 => self.with_generic_argument_list_opt_1(),
-            923 => self.with_generic_argument_item_0(&children[0]),
-            924 => self.with_generic_argument_item_1(&children[0]),
-            925 => self.with_generic_argument_item_2(&children[0]),
-            926 => self.with_generic_argument_item_3(&children[0]),
-            927 => self.port_declaration(&children[0], &children[1], &children[2]),
-            928 => self.port_declaration_opt_0(&children[0]),
-            929 => self.port_declaration_opt_1(),
-            930 => self.port_declaration_list(&children[0], &children[1], &children[2]),
-            931 => self.port_declaration_list_list_0(&children[0], &children[1], &children[2]),
-            932 => self.port_declaration_list_list_1(),
-            933 => self.port_declaration_list_opt_0(&children[0]),
-            934 => self.port_declaration_list_opt_1(),
-            935 => self.port_declaration_group(&children[0], &children[1]),
-            936 => self.port_declaration_group_group_0(&children[0], &children[1], &children[2]),
-            937 => self.port_declaration_group_group_1(&children[0]),
-            938 => self.port_declaration_group_list_0(&children[0], &children[1]),
-            939 => self.port_declaration_group_list_1(),
-            940 => self.port_declaration_item(&children[0], &children[1], &children[2]),
-            941 => self.port_declaration_item_group_0(&children[0]),
-            942 => self.port_declaration_item_group_1(&children[0]),
-            943 => self.port_type_concrete(&children[0], &children[1], &children[2], &children[3]),
-            944 => self.port_type_concrete_opt0_0(&children[0], &children[1]),
-            945 => self.port_type_concrete_opt0_1(),
-            946 => self.port_type_concrete_opt_0(&children[0]),
-            947 => self.port_type_concrete_opt_1(),
-            948 => self.port_default_value(&children[0]),
-            949 => self.port_type_abstract(&children[0], &children[1], &children[2], &children[3]),
-            950 => self.port_type_abstract_opt1_0(&children[0]),
-            951 => self.port_type_abstract_opt1_1(),
-            952 => self.port_type_abstract_opt0_0(&children[0], &children[1]),
-            953 => self.port_type_abstract_opt0_1(),
-            954 => self.port_type_abstract_opt_0(&children[0]),
-            955 => self.port_type_abstract_opt_1(),
-            956 => self.direction_0(&children[0]),
-            957 => self.direction_1(&children[0]),
-            958 => self.direction_2(&children[0]),
-            959 => self.direction_3(&children[0]),
-            960 => self.direction_4(&children[0]),
-            961 => self.function_declaration(
+            925 => self.with_generic_parameter_item_opt_0(&children[0], &children[1]),
+            926 => self.with_generic_parameter_item_opt_1(),
+            927 => self.generic_proto_bound_0(&children[0]),
+            928 => self.generic_proto_bound_1(&children[0]),
+            929 => self.with_generic_argument(&children[0], &children[1], &children[2]),
+            930 => self.with_generic_argument_opt_0(&children[0]),
+            931 => self.with_generic_argument_opt_1(),
+            932 => self.with_generic_argument_list(&children[0], &children[1], &children[2]),
+            933 => self.with_generic_argument_list_list_0(&children[0], &children[1], &children[2]),
+            934 => self.with_generic_argument_list_list_1(),
+            935 => self.with_generic_argument_list_opt_0(&children[0]),
+            936 => self.with_generic_argument_list_opt_1(),
+            937 => self.with_generic_argument_item_0(&children[0]),
+            938 => self.with_generic_argument_item_1(&children[0]),
+            939 => self.with_generic_argument_item_2(&children[0]),
+            940 => self.with_generic_argument_item_3(&children[0]),
+            941 => self.port_declaration(&children[0], &children[1], &children[2]),
+            942 => self.port_declaration_opt_0(&children[0]),
+            943 => self.port_declaration_opt_1(),
+            944 => self.port_declaration_list(&children[0], &children[1], &children[2]),
+            945 => self.port_declaration_list_list_0(&children[0], &children[1], &children[2]),
+            946 => self.port_declaration_list_list_1(),
+            947 => self.port_declaration_list_opt_0(&children[0]),
+            948 => self.port_declaration_list_opt_1(),
+            949 => self.port_declaration_group(&children[0], &children[1]),
+            950 => self.port_declaration_group_group_0(&children[0], &children[1], &children[2]),
+            951 => self.port_declaration_group_group_1(&children[0]),
+            952 => self.port_declaration_group_list_0(&children[0], &children[1]),
+            953 => self.port_declaration_group_list_1(),
+            954 => self.port_declaration_item(&children[0], &children[1], &children[2]),
+            955 => self.port_declaration_item_group_0(&children[0]),
+            956 => self.port_declaration_item_group_1(&children[0]),
+            957 => self.port_type_concrete(&children[0], &children[1], &children[2], &children[3]),
+            958 => self.port_type_concrete_opt0_0(&children[0], &children[1]),
+            959 => self.port_type_concrete_opt0_1(),
+            960 => self.port_type_concrete_opt_0(&children[0]),
+            961 => self.port_type_concrete_opt_1(),
+            962 => self.port_default_value(&children[0]),
+            963 => self.port_type_abstract(&children[0], &children[1], &children[2], &children[3]),
+            964 => self.port_type_abstract_opt1_0(&children[0]),
+            965 => self.port_type_abstract_opt1_1(),
+            966 => self.port_type_abstract_opt0_0(&children[0], &children[1]),
+            967 => self.port_type_abstract_opt0_1(),
+            968 => self.port_type_abstract_opt_0(&children[0]),
+            969 => self.port_type_abstract_opt_1(),
+            970 => self.direction_0(&children[0]),
+            971 => self.direction_1(&children[0]),
+            972 => self.direction_2(&children[0]),
+            973 => self.direction_3(&children[0]),
+            974 => self.direction_4(&children[0]),
+            975 => self.function_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42937,31 +43461,31 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            962 => self.function_declaration_opt1_0(&children[0], &children[1]),
-            963 => self.function_declaration_opt1_1(),
-            964 => self.function_declaration_opt0_0(&children[0]),
-            965 => self.function_declaration_opt0_1(),
-            966 => self.function_declaration_opt_0(&children[0]),
-            967 => self.function_declaration_opt_1(),
-            968 => self.import_declaration(&children[0], &children[1], &children[2], &children[3]),
-            969 => self.import_declaration_opt_0(&children[0], &children[1]),
-            970 => self.import_declaration_opt_group_0(&children[0]),
-            971 => self.import_declaration_opt_group_1(&children[0]),
-            972 => self.import_declaration_opt_1(),
-            973 => self.multiple_import_list(
+            976 => self.function_declaration_opt1_0(&children[0], &children[1]),
+            977 => self.function_declaration_opt1_1(),
+            978 => self.function_declaration_opt0_0(&children[0]),
+            979 => self.function_declaration_opt0_1(),
+            980 => self.function_declaration_opt_0(&children[0]),
+            981 => self.function_declaration_opt_1(),
+            982 => self.import_declaration(&children[0], &children[1], &children[2], &children[3]),
+            983 => self.import_declaration_opt_0(&children[0], &children[1]),
+            984 => self.import_declaration_opt_group_0(&children[0]),
+            985 => self.import_declaration_opt_group_1(&children[0]),
+            986 => self.import_declaration_opt_1(),
+            987 => self.multiple_import_list(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            974 => self.multiple_import_list_list_0(&children[0], &children[1], &children[2]),
-            975 => self.multiple_import_list_list_1(),
-            976 => self.multiple_import_list_opt_0(&children[0]),
-            977 => self.multiple_import_list_opt_1(),
-            978 => self.multiple_import_item(&children[0]),
-            979 => self.mixin_declaration(&children[0], &children[1], &children[2]),
-            980 => self.unsafe_block(
+            988 => self.multiple_import_list_list_0(&children[0], &children[1], &children[2]),
+            989 => self.multiple_import_list_list_1(),
+            990 => self.multiple_import_list_opt_0(&children[0]),
+            991 => self.multiple_import_list_opt_1(),
+            992 => self.multiple_import_item(&children[0]),
+            993 => self.mixin_declaration(&children[0], &children[1], &children[2]),
+            994 => self.unsafe_block(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42970,9 +43494,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            981 => self.unsafe_block_list_0(&children[0], &children[1]),
-            982 => self.unsafe_block_list_1(),
-            983 => self.module_declaration(
+            995 => self.unsafe_block_list_0(&children[0], &children[1]),
+            996 => self.unsafe_block_list_1(),
+            997 => self.module_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -42983,25 +43507,25 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[7],
                 &children[8],
             ),
-            984 => self.module_declaration_list_0(&children[0], &children[1]),
-            985 => self.module_declaration_list_1(),
-            986 => self.module_declaration_opt2_0(&children[0]),
-            987 => self.module_declaration_opt2_1(),
-            988 => self.module_declaration_opt1_0(&children[0]),
-            989 => self.module_declaration_opt1_1(),
-            990 => self.module_declaration_opt0_0(&children[0], &children[1]),
-            991 => self.module_declaration_opt0_1(),
-            992 => self.module_declaration_opt_0(&children[0]),
-            993 => self.module_declaration_opt_1(),
-            994 => self.module_group(&children[0], &children[1]),
-            995 => self.module_group_group_0(&children[0], &children[1], &children[2]),
-            996 => self.module_group_group_list_0(&children[0], &children[1]),
-            997 => self.module_group_group_list_1(),
-            998 => self.module_group_group_1(&children[0]),
-            999 => self.module_group_list_0(&children[0], &children[1]),
-            1000 => self.module_group_list_1(),
-            1001 => self.module_item(&children[0]),
-            1002 => self.interface_declaration(
+            998 => self.module_declaration_list_0(&children[0], &children[1]),
+            999 => self.module_declaration_list_1(),
+            1000 => self.module_declaration_opt2_0(&children[0]),
+            1001 => self.module_declaration_opt2_1(),
+            1002 => self.module_declaration_opt1_0(&children[0]),
+            1003 => self.module_declaration_opt1_1(),
+            1004 => self.module_declaration_opt0_0(&children[0], &children[1]),
+            1005 => self.module_declaration_opt0_1(),
+            1006 => self.module_declaration_opt_0(&children[0]),
+            1007 => self.module_declaration_opt_1(),
+            1008 => self.module_group(&children[0], &children[1]),
+            1009 => self.module_group_group_0(&children[0], &children[1], &children[2]),
+            1010 => self.module_group_group_list_0(&children[0], &children[1]),
+            1011 => self.module_group_group_list_1(),
+            1012 => self.module_group_group_1(&children[0]),
+            1013 => self.module_group_list_0(&children[0], &children[1]),
+            1014 => self.module_group_list_1(),
+            1015 => self.module_item(&children[0]),
+            1016 => self.interface_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43011,42 +43535,42 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            1003 => self.interface_declaration_list_0(&children[0], &children[1]),
-            1004 => self.interface_declaration_list_1(),
-            1005 => self.interface_declaration_opt1_0(&children[0]),
-            1006 => self.interface_declaration_opt1_1(),
-            1007 => self.interface_declaration_opt0_0(&children[0], &children[1]),
-            1008 => self.interface_declaration_opt0_1(),
-            1009 => self.interface_declaration_opt_0(&children[0]),
-            1010 => self.interface_declaration_opt_1(),
-            1011 => self.interface_group(&children[0], &children[1]),
-            1012 => self.interface_group_group_0(&children[0], &children[1], &children[2]),
-            1013 => self.interface_group_group_list_0(&children[0], &children[1]),
-            1014 => self.interface_group_group_list_1(),
-            1015 => self.interface_group_group_1(&children[0]),
-            1016 => self.interface_group_list_0(&children[0], &children[1]),
-            1017 => self.interface_group_list_1(),
-            1018 => self.interface_item_0(&children[0]),
-            1019 => self.interface_item_1(&children[0]),
-            1020 => self.interface_item_2(&children[0]),
-            1021 => self.generate_if_declaration(
+            1017 => self.interface_declaration_list_0(&children[0], &children[1]),
+            1018 => self.interface_declaration_list_1(),
+            1019 => self.interface_declaration_opt1_0(&children[0]),
+            1020 => self.interface_declaration_opt1_1(),
+            1021 => self.interface_declaration_opt0_0(&children[0], &children[1]),
+            1022 => self.interface_declaration_opt0_1(),
+            1023 => self.interface_declaration_opt_0(&children[0]),
+            1024 => self.interface_declaration_opt_1(),
+            1025 => self.interface_group(&children[0], &children[1]),
+            1026 => self.interface_group_group_0(&children[0], &children[1], &children[2]),
+            1027 => self.interface_group_group_list_0(&children[0], &children[1]),
+            1028 => self.interface_group_group_list_1(),
+            1029 => self.interface_group_group_1(&children[0]),
+            1030 => self.interface_group_list_0(&children[0], &children[1]),
+            1031 => self.interface_group_list_1(),
+            1032 => self.interface_item_0(&children[0]),
+            1033 => self.interface_item_1(&children[0]),
+            1034 => self.interface_item_2(&children[0]),
+            1035 => self.generate_if_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1022 => self.generate_if_declaration_list_0(
+            1036 => self.generate_if_declaration_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1023 => self.generate_if_declaration_list_1(),
-            1024 => self.generate_if_declaration_opt_0(&children[0], &children[1]),
-            1025 => self.generate_if_declaration_opt_1(),
-            1026 => self.generate_for_declaration(
+            1037 => self.generate_if_declaration_list_1(),
+            1038 => self.generate_if_declaration_opt_0(&children[0], &children[1]),
+            1039 => self.generate_if_declaration_opt_1(),
+            1040 => self.generate_for_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43055,61 +43579,61 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            1027 => self.generate_for_declaration_opt0_0(&children[0], &children[1], &children[2]),
-            1028 => self.generate_for_declaration_opt0_1(),
-            1029 => self.generate_for_declaration_opt_0(&children[0]),
-            1030 => self.generate_for_declaration_opt_1(),
-            1031 => self.generate_block_declaration(&children[0]),
-            1032 => self.generate_named_block(
+            1041 => self.generate_for_declaration_opt0_0(&children[0], &children[1], &children[2]),
+            1042 => self.generate_for_declaration_opt0_1(),
+            1043 => self.generate_for_declaration_opt_0(&children[0]),
+            1044 => self.generate_for_declaration_opt_1(),
+            1045 => self.generate_block_declaration(&children[0]),
+            1046 => self.generate_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1033 => self.generate_named_block_list_0(&children[0], &children[1]),
-            1034 => self.generate_named_block_list_1(),
-            1035 => self.generate_optional_named_block(
+            1047 => self.generate_named_block_list_0(&children[0], &children[1]),
+            1048 => self.generate_named_block_list_1(),
+            1049 => self.generate_optional_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            1036 => self.generate_optional_named_block_list_0(&children[0], &children[1]),
-            1037 => self.generate_optional_named_block_list_1(),
-            1038 => self.generate_optional_named_block_opt_0(&children[0], &children[1]),
-            1039 => self.generate_optional_named_block_opt_1(),
-            1040 => self.generate_group(&children[0], &children[1]),
-            1041 => self.generate_group_group_0(&children[0], &children[1], &children[2]),
-            1042 => self.generate_group_group_list_0(&children[0], &children[1]),
-            1043 => self.generate_group_group_list_1(),
-            1044 => self.generate_group_group_1(&children[0]),
-            1045 => self.generate_group_list_0(&children[0], &children[1]),
-            1046 => self.generate_group_list_1(),
-            1047 => self.generate_item_0(&children[0]),
-            1048 => self.generate_item_1(&children[0]),
-            1049 => self.generate_item_2(&children[0]),
-            1050 => self.generate_item_3(&children[0]),
-            1051 => self.generate_item_4(&children[0]),
-            1052 => self.generate_item_5(&children[0]),
-            1053 => self.generate_item_6(&children[0]),
-            1054 => self.generate_item_7(&children[0]),
-            1055 => self.generate_item_8(&children[0]),
-            1056 => self.generate_item_9(&children[0]),
-            1057 => self.generate_item_10(&children[0]),
-            1058 => self.generate_item_11(&children[0]),
-            1059 => self.generate_item_12(&children[0]),
-            1060 => self.generate_item_13(&children[0]),
-            1061 => self.generate_item_14(&children[0]),
-            1062 => self.generate_item_15(&children[0]),
-            1063 => self.generate_item_16(&children[0]),
-            1064 => self.generate_item_17(&children[0]),
-            1065 => self.generate_item_18(&children[0]),
-            1066 => self.generate_item_19(&children[0]),
-            1067 => self.generate_item_20(&children[0]),
-            1068 => self.generate_item_21(&children[0]),
-            1069 => self.generate_item_22(&children[0]),
-            1070 => self.package_declaration(
+            1050 => self.generate_optional_named_block_list_0(&children[0], &children[1]),
+            1051 => self.generate_optional_named_block_list_1(),
+            1052 => self.generate_optional_named_block_opt_0(&children[0], &children[1]),
+            1053 => self.generate_optional_named_block_opt_1(),
+            1054 => self.generate_group(&children[0], &children[1]),
+            1055 => self.generate_group_group_0(&children[0], &children[1], &children[2]),
+            1056 => self.generate_group_group_list_0(&children[0], &children[1]),
+            1057 => self.generate_group_group_list_1(),
+            1058 => self.generate_group_group_1(&children[0]),
+            1059 => self.generate_group_list_0(&children[0], &children[1]),
+            1060 => self.generate_group_list_1(),
+            1061 => self.generate_item_0(&children[0]),
+            1062 => self.generate_item_1(&children[0]),
+            1063 => self.generate_item_2(&children[0]),
+            1064 => self.generate_item_3(&children[0]),
+            1065 => self.generate_item_4(&children[0]),
+            1066 => self.generate_item_5(&children[0]),
+            1067 => self.generate_item_6(&children[0]),
+            1068 => self.generate_item_7(&children[0]),
+            1069 => self.generate_item_8(&children[0]),
+            1070 => self.generate_item_9(&children[0]),
+            1071 => self.generate_item_10(&children[0]),
+            1072 => self.generate_item_11(&children[0]),
+            1073 => self.generate_item_12(&children[0]),
+            1074 => self.generate_item_13(&children[0]),
+            1075 => self.generate_item_14(&children[0]),
+            1076 => self.generate_item_15(&children[0]),
+            1077 => self.generate_item_16(&children[0]),
+            1078 => self.generate_item_17(&children[0]),
+            1079 => self.generate_item_18(&children[0]),
+            1080 => self.generate_item_19(&children[0]),
+            1081 => self.generate_item_20(&children[0]),
+            1082 => self.generate_item_21(&children[0]),
+            1083 => self.generate_item_22(&children[0]),
+            1084 => self.package_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43118,29 +43642,29 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            1071 => self.package_declaration_list_0(&children[0], &children[1]),
-            1072 => self.package_declaration_list_1(),
-            1073 => self.package_declaration_opt0_0(&children[0], &children[1]),
-            1074 => self.package_declaration_opt0_1(),
-            1075 => self.package_declaration_opt_0(&children[0]),
-            1076 => self.package_declaration_opt_1(),
-            1077 => self.package_group(&children[0], &children[1]),
-            1078 => self.package_group_group_0(&children[0], &children[1], &children[2]),
-            1079 => self.package_group_group_list_0(&children[0], &children[1]),
-            1080 => self.package_group_group_list_1(),
-            1081 => self.package_group_group_1(&children[0]),
-            1082 => self.package_group_list_0(&children[0], &children[1]),
-            1083 => self.package_group_list_1(),
-            1084 => self.package_item_0(&children[0]),
-            1085 => self.package_item_1(&children[0]),
-            1086 => self.package_item_2(&children[0]),
-            1087 => self.package_item_3(&children[0]),
-            1088 => self.package_item_4(&children[0]),
-            1089 => self.package_item_5(&children[0]),
-            1090 => self.package_item_6(&children[0]),
-            1091 => self.package_item_7(&children[0]),
-            1092 => self.package_item_8(&children[0]),
-            1093 => self.alias_declaration(
+            1085 => self.package_declaration_list_0(&children[0], &children[1]),
+            1086 => self.package_declaration_list_1(),
+            1087 => self.package_declaration_opt0_0(&children[0], &children[1]),
+            1088 => self.package_declaration_opt0_1(),
+            1089 => self.package_declaration_opt_0(&children[0]),
+            1090 => self.package_declaration_opt_1(),
+            1091 => self.package_group(&children[0], &children[1]),
+            1092 => self.package_group_group_0(&children[0], &children[1], &children[2]),
+            1093 => self.package_group_group_list_0(&children[0], &children[1]),
+            1094 => self.package_group_group_list_1(),
+            1095 => self.package_group_group_1(&children[0]),
+            1096 => self.package_group_list_0(&children[0], &children[1]),
+            1097 => self.package_group_list_1(),
+            1098 => self.package_item_0(&children[0]),
+            1099 => self.package_item_1(&children[0]),
+            1100 => self.package_item_2(&children[0]),
+            1101 => self.package_item_3(&children[0]),
+            1102 => self.package_item_4(&children[0]),
+            1103 => self.package_item_5(&children[0]),
+            1104 => self.package_item_6(&children[0]),
+            1105 => self.package_item_7(&children[0]),
+            1106 => self.package_item_8(&children[0]),
+            1107 => self.alias_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43148,25 +43672,25 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1094 => self.alias_declaration_group_0(&children[0]),
-            1095 => self.alias_declaration_group_1(&children[0]),
-            1096 => self.alias_declaration_group_2(&children[0]),
-            1097 => self.proto_declaration(&children[0], &children[1]),
-            1098 => self.proto_declaration_group_0(&children[0]),
-            1099 => self.proto_declaration_group_1(&children[0]),
-            1100 => self.proto_declaration_group_2(&children[0]),
-            1101 => self.proto_module_declaration(
+            1108 => self.alias_declaration_group_0(&children[0]),
+            1109 => self.alias_declaration_group_1(&children[0]),
+            1110 => self.alias_declaration_group_2(&children[0]),
+            1111 => self.proto_declaration(&children[0], &children[1]),
+            1112 => self.proto_declaration_group_0(&children[0]),
+            1113 => self.proto_declaration_group_1(&children[0]),
+            1114 => self.proto_declaration_group_2(&children[0]),
+            1115 => self.proto_module_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1102 => self.proto_module_declaration_opt0_0(&children[0]),
-            1103 => self.proto_module_declaration_opt0_1(),
-            1104 => self.proto_module_declaration_opt_0(&children[0]),
-            1105 => self.proto_module_declaration_opt_1(),
-            1106 => self.proto_interface_declaration(
+            1116 => self.proto_module_declaration_opt0_0(&children[0]),
+            1117 => self.proto_module_declaration_opt0_1(),
+            1118 => self.proto_module_declaration_opt_0(&children[0]),
+            1119 => self.proto_module_declaration_opt_1(),
+            1120 => self.proto_interface_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43174,51 +43698,51 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1107 => self.proto_interface_declaration_list_0(&children[0], &children[1]),
-            1108 => self.proto_interface_declaration_list_1(),
-            1109 => self.proto_interface_declaration_opt_0(&children[0]),
-            1110 => self.proto_interface_declaration_opt_1(),
-            1111 => self.proto_interface_item_0(&children[0]),
-            1112 => self.proto_interface_item_1(&children[0]),
-            1113 => self.proto_interface_item_2(&children[0]),
-            1114 => self.proto_interface_item_3(&children[0]),
-            1115 => self.proto_interface_item_4(&children[0]),
-            1116 => self.proto_interface_item_5(&children[0]),
-            1117 => self.proto_interface_item_6(&children[0]),
-            1118 => self.proto_package_declaration(
+            1121 => self.proto_interface_declaration_list_0(&children[0], &children[1]),
+            1122 => self.proto_interface_declaration_list_1(),
+            1123 => self.proto_interface_declaration_opt_0(&children[0]),
+            1124 => self.proto_interface_declaration_opt_1(),
+            1125 => self.proto_interface_item_0(&children[0]),
+            1126 => self.proto_interface_item_1(&children[0]),
+            1127 => self.proto_interface_item_2(&children[0]),
+            1128 => self.proto_interface_item_3(&children[0]),
+            1129 => self.proto_interface_item_4(&children[0]),
+            1130 => self.proto_interface_item_5(&children[0]),
+            1131 => self.proto_interface_item_6(&children[0]),
+            1132 => self.proto_package_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1119 => self.proto_package_declaration_list_0(&children[0], &children[1]),
-            1120 => self.proto_package_declaration_list_1(),
-            1121 => self.proto_pacakge_item_0(&children[0]),
-            1122 => self.proto_pacakge_item_1(&children[0]),
-            1123 => self.proto_pacakge_item_2(&children[0]),
-            1124 => self.proto_pacakge_item_3(&children[0]),
-            1125 => self.proto_pacakge_item_4(&children[0]),
-            1126 => self.proto_pacakge_item_5(&children[0]),
-            1127 => self.proto_pacakge_item_6(&children[0]),
-            1128 => self.proto_const_declaration(
+            1133 => self.proto_package_declaration_list_0(&children[0], &children[1]),
+            1134 => self.proto_package_declaration_list_1(),
+            1135 => self.proto_pacakge_item_0(&children[0]),
+            1136 => self.proto_pacakge_item_1(&children[0]),
+            1137 => self.proto_pacakge_item_2(&children[0]),
+            1138 => self.proto_pacakge_item_3(&children[0]),
+            1139 => self.proto_pacakge_item_4(&children[0]),
+            1140 => self.proto_pacakge_item_5(&children[0]),
+            1141 => self.proto_pacakge_item_6(&children[0]),
+            1142 => self.proto_const_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1129 => self.proto_const_declaration_group_0(&children[0]),
-            1130 => self.proto_const_declaration_group_1(&children[0]),
-            1131 => self.proto_type_def_declaration(
+            1143 => self.proto_const_declaration_group_0(&children[0]),
+            1144 => self.proto_const_declaration_group_1(&children[0]),
+            1145 => self.proto_type_def_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            1132 => self.proto_type_def_declaration_opt_0(&children[0], &children[1]),
-            1133 => self.proto_type_def_declaration_opt_1(),
-            1134 => self.proto_function_declaration(
+            1146 => self.proto_type_def_declaration_opt_0(&children[0], &children[1]),
+            1147 => self.proto_type_def_declaration_opt_1(),
+            1148 => self.proto_function_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43226,13 +43750,13 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1135 => self.proto_function_declaration_opt1_0(&children[0], &children[1]),
-            1136 => self.proto_function_declaration_opt1_1(),
-            1137 => self.proto_function_declaration_opt0_0(&children[0]),
-            1138 => self.proto_function_declaration_opt0_1(),
-            1139 => self.proto_function_declaration_opt_0(&children[0]),
-            1140 => self.proto_function_declaration_opt_1(),
-            1141 => self.proto_alias_declaration(
+            1149 => self.proto_function_declaration_opt1_0(&children[0], &children[1]),
+            1150 => self.proto_function_declaration_opt1_1(),
+            1151 => self.proto_function_declaration_opt0_0(&children[0]),
+            1152 => self.proto_function_declaration_opt0_1(),
+            1153 => self.proto_function_declaration_opt_0(&children[0]),
+            1154 => self.proto_function_declaration_opt_1(),
+            1155 => self.proto_alias_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43240,10 +43764,10 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1142 => self.proto_alias_declaration_group_0(&children[0]),
-            1143 => self.proto_alias_declaration_group_1(&children[0]),
-            1144 => self.proto_alias_declaration_group_2(&children[0]),
-            1145 => self.embed_declaration(
+            1156 => self.proto_alias_declaration_group_0(&children[0]),
+            1157 => self.proto_alias_declaration_group_1(&children[0]),
+            1158 => self.proto_alias_declaration_group_2(&children[0]),
+            1159 => self.embed_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43251,16 +43775,16 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1146 => self.embed_content(&children[0], &children[1], &children[2]),
-            1147 => self.embed_content_list_0(&children[0], &children[1]),
-            1148 => self.embed_content_list_1(),
-            1149 => self.embed_scoped_identifier(&children[0], &children[1], &children[2]),
-            1150 => self.embed_item_0(&children[0], &children[1], &children[2]),
-            1151 => self.embed_item_list_0(&children[0], &children[1]),
-            1152 => self.embed_item_list_1(),
-            1153 => self.embed_item_1(&children[0]),
-            1154 => self.embed_item_2(&children[0]),
-            1155 => self.include_declaration(
+            1160 => self.embed_content(&children[0], &children[1], &children[2]),
+            1161 => self.embed_content_list_0(&children[0], &children[1]),
+            1162 => self.embed_content_list_1(),
+            1163 => self.embed_scoped_identifier(&children[0], &children[1], &children[2]),
+            1164 => self.embed_item_0(&children[0], &children[1], &children[2]),
+            1165 => self.embed_item_list_0(&children[0], &children[1]),
+            1166 => self.embed_item_list_1(),
+            1167 => self.embed_item_1(&children[0]),
+            1168 => self.embed_item_2(&children[0]),
+            1169 => self.include_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -43269,29 +43793,29 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            1156 => self.description_group(&children[0], &children[1]),
-            1157 => self.description_group_group_0(&children[0], &children[1], &children[2]),
-            1158 => self.description_group_group_list_0(&children[0], &children[1]),
-            1159 => self.description_group_group_list_1(),
-            1160 => self.description_group_group_1(&children[0]),
-            1161 => self.description_group_list_0(&children[0], &children[1]),
-            1162 => self.description_group_list_1(),
-            1163 => self.description_item_0(&children[0], &children[1]),
-            1164 => self.description_item_1(&children[0]),
-            1165 => self.description_item_2(&children[0]),
-            1166 => self.description_item_3(&children[0]),
-            1167 => self.description_item_4(&children[0]),
-            1168 => self.description_item_opt_0(&children[0]),
-            1169 => self.description_item_opt_1(),
-            1170 => self.public_description_item_0(&children[0]),
-            1171 => self.public_description_item_1(&children[0]),
-            1172 => self.public_description_item_2(&children[0]),
-            1173 => self.public_description_item_3(&children[0]),
-            1174 => self.public_description_item_4(&children[0]),
-            1175 => self.public_description_item_5(&children[0]),
-            1176 => self.veryl(&children[0], &children[1]),
-            1177 => self.veryl_list_0(&children[0], &children[1]),
-            1178 => self.veryl_list_1(),
+            1170 => self.description_group(&children[0], &children[1]),
+            1171 => self.description_group_group_0(&children[0], &children[1], &children[2]),
+            1172 => self.description_group_group_list_0(&children[0], &children[1]),
+            1173 => self.description_group_group_list_1(),
+            1174 => self.description_group_group_1(&children[0]),
+            1175 => self.description_group_list_0(&children[0], &children[1]),
+            1176 => self.description_group_list_1(),
+            1177 => self.description_item_0(&children[0], &children[1]),
+            1178 => self.description_item_1(&children[0]),
+            1179 => self.description_item_2(&children[0]),
+            1180 => self.description_item_3(&children[0]),
+            1181 => self.description_item_4(&children[0]),
+            1182 => self.description_item_opt_0(&children[0]),
+            1183 => self.description_item_opt_1(),
+            1184 => self.public_description_item_0(&children[0]),
+            1185 => self.public_description_item_1(&children[0]),
+            1186 => self.public_description_item_2(&children[0]),
+            1187 => self.public_description_item_3(&children[0]),
+            1188 => self.public_description_item_4(&children[0]),
+            1189 => self.public_description_item_5(&children[0]),
+            1190 => self.veryl(&children[0], &children[1]),
+            1191 => self.veryl_list_0(&children[0], &children[1]),
+            1192 => self.veryl_list_1(),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {prod_num}"
             ))
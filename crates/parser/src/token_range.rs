@@ -292,6 +292,7 @@ impl_token_range_singular!(AllBit);
 // Operator
 impl_token_range_singular!(AssignmentOperator);
 impl_token_range_singular!(DiamondOperator);
+impl_token_range_singular!(Implication);
 impl_token_range_singular!(Operator01);
 impl_token_range_singular!(Operator02);
 impl_token_range_singular!(Operator03);
@@ -311,6 +312,7 @@ impl_token_range_singular!(DotDot);
 impl_token_range_singular!(DotDotEqu);
 impl_token_range_singular!(Dot);
 impl_token_range_singular!(Equ);
+impl_token_range_singular!(HashHash);
 impl_token_range_singular!(HashLBracket);
 impl_token_range_singular!(Hash);
 impl_token_range_singular!(Question);
@@ -655,7 +657,33 @@ impl From<&ArgumentItem> for TokenRange {
 }
 impl_token_ext!(ArgumentItem);
 
-impl_token_range!(ArgumentExpression, expression);
+impl From<&ArgumentExpression> for TokenRange {
+    fn from(value: &ArgumentExpression) -> Self {
+        let mut ret: TokenRange = value.expression.as_ref().into();
+        if let Some(x) = &value.argument_expression_opt {
+            ret.set_end(x.property_implication.as_ref().into());
+        }
+        ret
+    }
+}
+impl_token_ext!(ArgumentExpression);
+
+impl_token_range!(PropertyImplication, implication, expression);
+
+impl From<&CycleDelay> for TokenRange {
+    fn from(value: &CycleDelay) -> Self {
+        let mut ret: TokenRange = value.hash_hash.as_ref().into();
+        match value.cycle_delay_group.as_ref() {
+            CycleDelayGroup::Number(x) => ret.set_end(x.number.as_ref().into()),
+            CycleDelayGroup::LBracketExpressionColonExpressionRBracket(x) => {
+                ret.set_end(x.r_bracket.as_ref().into())
+            }
+        }
+        ret
+    }
+}
+impl_token_ext!(CycleDelay);
+
 impl_token_range!(StructConstructor, quote_l_brace, r_brace);
 impl_token_range_list!(StructConstructorList, StructConstructorItem);
 impl_token_range!(StructConstructorItem, identifier, expression);
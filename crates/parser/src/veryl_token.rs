@@ -471,6 +471,7 @@ token_with_comments!(DotDot);
 token_with_comments!(DotDotEqu);
 token_with_comments!(Dot);
 token_with_comments!(Equ);
+token_with_comments!(HashHash);
 token_with_comments!(HashLBracket);
 token_with_comments!(Hash);
 token_with_comments!(Question);
@@ -499,6 +500,7 @@ token_with_comments!(Star);
 
 token_with_comments!(AssignmentOperator);
 token_with_comments!(DiamondOperator);
+token_with_comments!(Implication);
 token_with_comments!(Operator01);
 token_with_comments!(Operator02);
 token_with_comments!(Operator03);
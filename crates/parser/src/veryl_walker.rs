@@ -90,6 +90,13 @@ pub trait VerylWalker {
         after!(self, diamond_operator, arg);
     }
 
+    /// Semantic action for non-terminal 'Implication'
+    fn implication(&mut self, arg: &Implication) {
+        before!(self, implication, arg);
+        self.veryl_token(&arg.implication_token);
+        after!(self, implication, arg);
+    }
+
     /// Semantic action for non-terminal 'Operator01'
     fn operator01(&mut self, arg: &Operator01) {
         before!(self, operator01, arg);
@@ -209,6 +216,13 @@ pub trait VerylWalker {
         after!(self, equ, arg);
     }
 
+    /// Semantic action for non-terminal 'HashHash'
+    fn hash_hash(&mut self, arg: &HashHash) {
+        before!(self, hash_hash, arg);
+        self.veryl_token(&arg.hash_hash_token);
+        after!(self, hash_hash, arg);
+    }
+
     /// Semantic action for non-terminal 'HashLBracket'
     fn hash_l_bracket(&mut self, arg: &HashLBracket) {
         before!(self, hash_l_bracket, arg);
@@ -1307,9 +1321,40 @@ pub trait VerylWalker {
     fn argument_expression(&mut self, arg: &ArgumentExpression) {
         before!(self, argument_expression, arg);
         self.expression(&arg.expression);
+        if let Some(ref x) = arg.argument_expression_opt {
+            self.property_implication(&x.property_implication);
+        }
         after!(self, argument_expression, arg);
     }
 
+    /// Semantic action for non-terminal 'PropertyImplication'
+    fn property_implication(&mut self, arg: &PropertyImplication) {
+        before!(self, property_implication, arg);
+        self.implication(&arg.implication);
+        if let Some(ref x) = arg.property_implication_opt {
+            self.cycle_delay(&x.cycle_delay);
+        }
+        self.expression(&arg.expression);
+        after!(self, property_implication, arg);
+    }
+
+    /// Semantic action for non-terminal 'CycleDelay'
+    fn cycle_delay(&mut self, arg: &CycleDelay) {
+        before!(self, cycle_delay, arg);
+        self.hash_hash(&arg.hash_hash);
+        match &*arg.cycle_delay_group {
+            CycleDelayGroup::Number(x) => self.number(&x.number),
+            CycleDelayGroup::LBracketExpressionColonExpressionRBracket(x) => {
+                self.l_bracket(&x.l_bracket);
+                self.expression(&x.expression);
+                self.colon(&x.colon);
+                self.expression(&x.expression0);
+                self.r_bracket(&x.r_bracket);
+            }
+        }
+        after!(self, cycle_delay, arg);
+    }
+
     /// Semantic action for non-terminal 'StructConstructor'
     fn struct_constructor(&mut self, arg: &StructConstructor) {
         before!(self, struct_constructor, arg);
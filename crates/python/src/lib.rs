@@ -0,0 +1,276 @@
+//! Python bindings for the native simulator, built as the `veryl_sim`
+//! extension module (`maturin develop -m crates/python/Cargo.toml`).
+//!
+//! ```python
+//! import veryl_sim
+//!
+//! sim = veryl_sim.Simulator("path/to/project", "Top")
+//! sim.step_reset("i_rst")
+//! sim.set("i_data", 0x1234)
+//! sim.step("i_clk")
+//! assert sim.get("o_data") == 0x1234
+//! ```
+//!
+//! Values are Python ints of any width. Writes keep the low bits that fit the
+//! signal, so a negative int writes its two's complement; reads are
+//! unsigned.
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyKeyError, PyOSError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyInt};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use veryl_cosim::{Cosim, CosimError};
+use veryl_simulator::ir::Event;
+use veryl_simulator::simulator::SignalRef;
+
+create_exception!(
+    veryl_sim,
+    VerylError,
+    PyException,
+    "The design failed to load."
+);
+
+fn to_py_err(e: CosimError) -> PyErr {
+    let message = e.to_string();
+    match e {
+        CosimError::Argument(_) => PyValueError::new_err(message),
+        CosimError::Load(_) => VerylError::new_err(message),
+        CosimError::NotFound { .. } => PyKeyError::new_err(message),
+        CosimError::Io(_) => PyOSError::new_err(message),
+    }
+}
+
+/// `value` truncated to `signal`, as LSB-first 64-bit words.
+fn to_words(value: &Bound<'_, PyAny>, signal: &SignalRef) -> PyResult<Vec<u64>> {
+    let py = value.py();
+    let nwords = signal.nwords();
+    let mask = 1u8.into_pyobject(py)?.lshift(signal.width())?.sub(1)?;
+    let bytes: Vec<u8> = value
+        .bitand(mask)?
+        .call_method1("to_bytes", (nwords * 8, "little"))?
+        .extract()?;
+    Ok(bytes
+        .chunks_exact(8)
+        .map(|x| u64::from_le_bytes(x.try_into().unwrap()))
+        .collect())
+}
+
+fn from_words<'py>(py: Python<'py>, words: &[u64]) -> PyResult<Bound<'py, PyAny>> {
+    let bytes: Vec<u8> = words.iter().flat_map(|x| x.to_le_bytes()).collect();
+    py.get_type::<PyInt>()
+        .call_method1("from_bytes", (PyBytes::new(py, &bytes), "little"))
+}
+
+/// A design simulated by the native simulator. It must stay on the thread
+/// that created it.
+#[pyclass(unsendable, module = "veryl_sim")]
+pub struct Simulator {
+    cosim: Cosim,
+    ports: HashMap<String, SignalRef>,
+    vars: HashMap<String, SignalRef>,
+    clocks: HashMap<String, Event>,
+    resets: HashMap<String, Event>,
+}
+
+impl Simulator {
+    fn port(&mut self, name: &str) -> PyResult<SignalRef> {
+        if let Some(x) = self.ports.get(name) {
+            return Ok(*x);
+        }
+        let signal = self.cosim.port(name).map_err(to_py_err)?;
+        self.ports.insert(name.to_string(), signal);
+        Ok(signal)
+    }
+
+    fn var(&mut self, path: &str) -> PyResult<SignalRef> {
+        if let Some(x) = self.vars.get(path) {
+            return Ok(*x);
+        }
+        let signal = self.cosim.var(path).map_err(to_py_err)?;
+        self.vars.insert(path.to_string(), signal);
+        Ok(signal)
+    }
+
+    fn write(
+        &mut self,
+        signal: &SignalRef,
+        value: &Bound<'_, PyAny>,
+        mask_xz: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<()> {
+        let words = to_words(value, signal)?;
+        let mask_xz = mask_xz.map(|x| to_words(x, signal)).transpose()?;
+        self.cosim.set(signal, &words, mask_xz.as_deref());
+        Ok(())
+    }
+
+    fn read<'py>(
+        &mut self,
+        py: Python<'py>,
+        signal: &SignalRef,
+    ) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+        let mut words = vec![0; signal.nwords()];
+        let mut mask_xz = vec![0; signal.nwords()];
+        self.cosim.get(signal, &mut words, Some(&mut mask_xz));
+        Ok((from_words(py, &words)?, from_words(py, &mask_xz)?))
+    }
+}
+
+#[pymethods]
+impl Simulator {
+    /// Loads module `top` from the Veryl project `project` belongs to (its
+    /// `Veryl.toml`, or any file or directory inside it). `four_state`
+    /// simulates X and Z.
+    #[new]
+    #[pyo3(signature = (project, top, four_state = false))]
+    fn new(project: PathBuf, top: &str, four_state: bool) -> PyResult<Self> {
+        let cosim = Cosim::open_project(&project, top, four_state).map_err(to_py_err)?;
+        Ok(Self {
+            cosim,
+            ports: HashMap::new(),
+            vars: HashMap::new(),
+            clocks: HashMap::new(),
+            resets: HashMap::new(),
+        })
+    }
+
+    /// Writes port `name`. Bits set in `mask_xz` are X (or Z where `value`
+    /// is also set).
+    #[pyo3(signature = (name, value, mask_xz = None))]
+    fn set(
+        &mut self,
+        name: &str,
+        value: &Bound<'_, PyAny>,
+        mask_xz: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<()> {
+        let signal = self.port(name)?;
+        self.write(&signal, value, mask_xz)
+    }
+
+    /// Reads port `name`; X/Z bits read as in `get_xz`'s value.
+    fn get<'py>(&mut self, py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        let signal = self.port(name)?;
+        Ok(self.read(py, &signal)?.0)
+    }
+
+    /// Reads port `name` as `(value, mask_xz)`.
+    fn get_xz<'py>(
+        &mut self,
+        py: Python<'py>,
+        name: &str,
+    ) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+        let signal = self.port(name)?;
+        self.read(py, &signal)
+    }
+
+    /// Deposits `value` on the variable at hierarchical `path` (e.g.
+    /// "u_core.cnt"); the design overwrites it on its next assignment.
+    #[pyo3(signature = (path, value, mask_xz = None))]
+    fn set_var(
+        &mut self,
+        path: &str,
+        value: &Bound<'_, PyAny>,
+        mask_xz: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<()> {
+        let signal = self.var(path)?;
+        self.write(&signal, value, mask_xz)
+    }
+
+    /// Reads the variable at hierarchical `path` (e.g. "u_core.cnt").
+    fn get_var<'py>(&mut self, py: Python<'py>, path: &str) -> PyResult<Bound<'py, PyAny>> {
+        let signal = self.var(path)?;
+        Ok(self.read(py, &signal)?.0)
+    }
+
+    /// Reads the variable at hierarchical `path` as `(value, mask_xz)`.
+    fn get_var_xz<'py>(
+        &mut self,
+        py: Python<'py>,
+        path: &str,
+    ) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+        let signal = self.var(path)?;
+        self.read(py, &signal)
+    }
+
+    /// Width in bits of port `name`.
+    fn width(&mut self, name: &str) -> PyResult<usize> {
+        Ok(self.port(name)?.width())
+    }
+
+    /// Advances `cycles` active edges of clock `clock`.
+    #[pyo3(signature = (clock, cycles = 1))]
+    fn step(&mut self, clock: &str, cycles: u64) -> PyResult<()> {
+        let event = match self.clocks.get(clock) {
+            Some(x) => x.clone(),
+            None => {
+                let event = self.cosim.clock(clock).map_err(to_py_err)?;
+                self.clocks.insert(clock.to_string(), event.clone());
+                event
+            }
+        };
+        for _ in 0..cycles {
+            self.cosim.step(&event);
+        }
+        Ok(())
+    }
+
+    /// Takes one edge of every clock with reset `reset` asserted.
+    fn step_reset(&mut self, reset: &str) -> PyResult<()> {
+        let event = match self.resets.get(reset) {
+            Some(x) => x.clone(),
+            None => {
+                let event = self.cosim.reset(reset).map_err(to_py_err)?;
+                self.resets.insert(reset.to_string(), event.clone());
+                event
+            }
+        };
+        self.cosim.step_reset(&event);
+        Ok(())
+    }
+
+    /// Simulation time stamped on dumped values.
+    #[getter]
+    fn time(&mut self) -> u64 {
+        self.cosim.simulator().time
+    }
+
+    #[setter]
+    fn set_time(&mut self, time: u64) {
+        self.cosim.simulator().time = time;
+    }
+
+    /// Starts a waveform of every variable at `path`: FST for a `.fst`
+    /// extension, VCD otherwise.
+    fn open_dump(&mut self, path: PathBuf) -> PyResult<()> {
+        self.cosim.open_dump(&path).map_err(to_py_err)
+    }
+
+    /// Records every variable into the waveform at `time`, by default the
+    /// current time.
+    #[pyo3(signature = (time = None))]
+    fn dump(&mut self, time: Option<u64>) {
+        let time = time.unwrap_or(self.cosim.simulator().time);
+        self.cosim.dump(time);
+    }
+
+    /// Finishes the waveform; the simulator stays usable.
+    fn close_dump(&mut self) {
+        self.cosim.simulator().dump = None;
+    }
+
+    /// Failures reported by user-defined components since the last call.
+    fn component_failures(&mut self) -> Vec<String> {
+        self.cosim.simulator().take_component_failures()
+    }
+}
+
+#[pymodule]
+fn veryl_sim(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Simulator>()?;
+    m.add("VerylError", m.py().get_type::<VerylError>())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,123 @@
+use super::*;
+use pyo3::types::PyDict;
+use std::ffi::CString;
+use veryl_cosim::test_utils::project;
+
+const TOP: &str = r#"
+module Sub (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    o_cnt: output logic<8>,
+) {
+    var cnt: logic<8>;
+    always_ff {
+        if_reset {
+            cnt = 0;
+        } else {
+            cnt += 1;
+        }
+    }
+    assign o_cnt = cnt;
+}
+
+module Top (
+    i_clk : input  clock,
+    i_rst : input  reset,
+    i_wide: input  logic<100>,
+    o_wide: output logic<100>,
+    o_cnt : output logic<8>,
+) {
+    inst u_sub: Sub (
+        i_clk,
+        i_rst,
+        o_cnt,
+    );
+    assign o_wide = i_wide;
+}
+"#;
+
+/// Runs `code` with the module imported as `veryl_sim` and the project
+/// directory bound to `project`.
+fn run(code: &str) {
+    let dir = project(TOP);
+    Python::initialize();
+    Python::attach(|py| {
+        let module = PyModule::new(py, "veryl_sim").unwrap();
+        veryl_sim(&module).unwrap();
+        let globals = PyDict::new(py);
+        globals.set_item("veryl_sim", module).unwrap();
+        globals.set_item("project", dir.path()).unwrap();
+        let code = CString::new(code).unwrap();
+        if let Err(e) = py.run(&code, Some(&globals), None) {
+            panic!("{e}");
+        }
+    });
+}
+
+#[test]
+fn drive_ports_and_read_hierarchy() {
+    run(r##"
+sim = veryl_sim.Simulator(project, "Top")
+sim.step_reset("i_rst")
+sim.step("i_clk", 5)
+assert sim.get("o_cnt") == 5
+assert sim.get_var("u_sub.cnt") == 5
+
+sim.set("i_wide", (1 << 99) | 0x1234)
+assert sim.get("o_wide") == (1 << 99) | 0x1234
+sim.set("i_wide", -1)
+assert sim.get("o_wide") == (1 << 100) - 1
+assert sim.width("i_wide") == 100
+
+sim.set_var("u_sub.cnt", 200)
+sim.step("i_clk")
+assert sim.get("o_cnt") == 201
+assert sim.component_failures() == []
+"##);
+}
+
+#[test]
+fn four_state_values() {
+    run(r##"
+sim = veryl_sim.Simulator(project, "Top", four_state=True)
+sim.set("i_wide", 0b10, mask_xz=0b11)
+assert sim.get_xz("o_wide") == (0b10, 0b11)
+"##);
+}
+
+#[test]
+fn errors_raise_exceptions() {
+    run(r##"
+try:
+    veryl_sim.Simulator(project, "Missing")
+    raise AssertionError("no error")
+except KeyError as e:
+    assert "Missing" in str(e)
+
+sim = veryl_sim.Simulator(project, "Top")
+for call in (lambda: sim.get("nope"), lambda: sim.step("no_clk")):
+    try:
+        call()
+        raise AssertionError("no error")
+    except KeyError:
+        pass
+"##);
+}
+
+#[test]
+fn dump_waveform() {
+    run(r##"
+import os, tempfile
+path = os.path.join(tempfile.mkdtemp(), "wave.vcd")
+sim = veryl_sim.Simulator(project, "Top")
+sim.open_dump(path)
+sim.step_reset("i_rst")
+for t in range(1, 4):
+    sim.step("i_clk")
+    sim.time = t * 10
+    sim.dump()
+sim.close_dump()
+text = open(path).read()
+assert "$var" in text and "#30" in text
+"##);
+}
@@ -53,6 +53,13 @@ typedef struct {\n\
 __attribute__((visibility(\"default\"))) veryl_wideops_t veryl_wideops;\n\
 __attribute__((visibility(\"default\"))) void veryl_set_wideops(const void* t) { veryl_wideops = *(const veryl_wideops_t*)t; }\n";
 
+/// The coverage counter array (`crate::coverage`), published at dlopen via
+/// `veryl_set_cov` like the wide-op table: a baked address would tie the
+/// cached `.so` to one process.
+const COVERAGE_C_DECLS: &str = "\
+__attribute__((visibility(\"default\"))) uint64_t *veryl_cov;\n\
+__attribute__((visibility(\"default\"))) void veryl_set_cov(uint64_t *p) { veryl_cov = p; }\n";
+
 /// Inline C implementations of the wide-op helpers, emitted into every AOT-C
 /// `.so` so the hot wide arithmetic compiles in-place (no `call_indirect`
 /// through the Rust binary).  Call sites emit `vw_<op>(...)` instead of the
@@ -578,6 +585,13 @@ impl LocalAnalysis {
                     self.poison(s);
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        self.poison(s);
+                    }
+                }
+            }
             ProtoStatement::CompiledBlock(x) => {
                 for s in &x.original_stmts {
                     self.poison(s);
@@ -739,9 +753,27 @@ impl LocalAnalysis {
                         self.walk_reads(a, i);
                     }
                 }
-                ProtoSystemFunctionCall::Readmemh { .. } | ProtoSystemFunctionCall::Finish => {}
+                ProtoSystemFunctionCall::Cover { value, .. } => {
+                    self.walk_reads(value, i);
+                }
+                ProtoSystemFunctionCall::Wait { duration, .. } => {
+                    self.walk_reads(duration, i);
+                }
+                ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                    self.walk_reads(condition, i);
+                }
+                // Writes storage its operand does not reveal to the walk.
+                ProtoSystemFunctionCall::Force { .. } | ProtoSystemFunctionCall::Release { .. } => {
+                    self.poison(s);
+                }
+                ProtoSystemFunctionCall::Readmemh { .. }
+                | ProtoSystemFunctionCall::Finish
+                | ProtoSystemFunctionCall::DumpOn
+                | ProtoSystemFunctionCall::DumpOff
+                | ProtoSystemFunctionCall::Coverage { .. }
+                | ProtoSystemFunctionCall::Line { .. } => {}
             },
-            ProtoStatement::CompiledBlock(_) => {
+            ProtoStatement::CompiledBlock(_) | ProtoStatement::Fork(_) => {
                 self.poison(s);
             }
             ProtoStatement::TbMethodCall { .. } | ProtoStatement::Break => {}
@@ -2103,6 +2135,13 @@ fn collect_uncovered(stmt: &ProtoStatement, out: &mut Vec<String>) {
                 collect_uncovered(s, out);
             }
         }
+        ProtoStatement::Fork(threads) => {
+            for thread in threads {
+                for s in thread {
+                    collect_uncovered(s, out);
+                }
+            }
+        }
         ProtoStatement::For(f) => {
             for s in &f.body {
                 collect_uncovered(s, out);
@@ -2530,11 +2569,24 @@ fn comb_touches(
             ProtoSystemFunctionCall::Assert {
                 condition, args, ..
             } => expr(condition, reads) && args.iter().all(|a| expr(a, reads)),
-            ProtoSystemFunctionCall::Readmemh { .. } | ProtoSystemFunctionCall::Finish => true,
+            ProtoSystemFunctionCall::Cover { value, .. } => expr(value, reads),
+            ProtoSystemFunctionCall::Wait { duration, .. } => expr(duration, reads),
+            ProtoSystemFunctionCall::WaitUntil { condition, .. } => expr(condition, reads),
+            ProtoSystemFunctionCall::Force { .. } | ProtoSystemFunctionCall::Release { .. } => {
+                false
+            }
+            ProtoSystemFunctionCall::Readmemh { .. }
+            | ProtoSystemFunctionCall::Finish
+            | ProtoSystemFunctionCall::DumpOn
+            | ProtoSystemFunctionCall::DumpOff
+            | ProtoSystemFunctionCall::Coverage { .. }
+            | ProtoSystemFunctionCall::Line { .. } => true,
         },
         // A pre-compiled child reads and writes comb_values directly, and a
         // testbench call can reach anything.
-        ProtoStatement::CompiledBlock(_) | ProtoStatement::TbMethodCall { .. } => false,
+        ProtoStatement::CompiledBlock(_)
+        | ProtoStatement::TbMethodCall { .. }
+        | ProtoStatement::Fork(_) => false,
         ProtoStatement::Break => true,
     }
 }
@@ -3152,8 +3204,8 @@ fn const_cone_partition(
                 // Readmemh writes storage this walker does not model.
                 !matches!(c, crate::ir::ProtoSystemFunctionCall::Readmemh { .. })
             }
-            // TB-method writes are not modeled either.
-            ProtoStatement::TbMethodCall { .. } => false,
+            // TB-method and fork writes are not modeled either.
+            ProtoStatement::TbMethodCall { .. } | ProtoStatement::Fork(_) => false,
         }
     }
     let mut wranges: WRanges = Vec::new();
@@ -4105,6 +4157,7 @@ fn emit_event_function(stmts: &[ProtoStatement]) -> Option<String> {
          __attribute__((visibility(\"default\"))) void veryl_set_sysfn_cb(void *p) { veryl_sysfn_cb = (veryl_sysfn_t)p; }\n",
     );
     src.push_str(WIDEOPS_C_DECLS);
+    src.push_str(COVERAGE_C_DECLS);
     src.push_str(WIDEOPS_C_INLINE);
     src.push_str(
         "\n\
@@ -4390,10 +4443,12 @@ fn split_translation_units(src: &str, n: usize) -> Option<Vec<String>> {
     // it one definition and the rest a declaration.  Anything else exported
     // from the header would lose its definition in the non-entry units, so
     // leave such a source whole.
-    if header
-        .iter()
-        .any(|l| l.starts_with(ENTRY_ATTR) && !l.contains(WIDEOPS_DEF) && !l.contains(WIDEOPS_SET))
-    {
+    if header.iter().any(|l| {
+        l.starts_with(ENTRY_ATTR)
+            && ![WIDEOPS_DEF, WIDEOPS_SET, COVERAGE_DEF, COVERAGE_SET]
+                .iter()
+                .any(|x| l.contains(x))
+    }) {
         return None;
     }
     // Every unit defines the chunks it holds and declares the rest, so the
@@ -4430,6 +4485,8 @@ fn split_translation_units(src: &str, n: usize) -> Option<Vec<String>> {
                 out.push((*l).to_string());
             } else if l.contains(WIDEOPS_DEF) {
                 out.push(format!("extern {WIDEOPS_DEF}"));
+            } else if l.contains(COVERAGE_DEF) {
+                out.push(format!("extern {COVERAGE_DEF}"));
             }
         }
         out.extend(decls.iter().cloned());
@@ -4449,6 +4506,8 @@ fn split_translation_units(src: &str, n: usize) -> Option<Vec<String>> {
 const ENTRY_ATTR: &str = "__attribute__((visibility(\"default\")))";
 const WIDEOPS_DEF: &str = "veryl_wideops_t veryl_wideops;";
 const WIDEOPS_SET: &str = "void veryl_set_wideops(";
+const COVERAGE_DEF: &str = "uint64_t *veryl_cov;";
+const COVERAGE_SET: &str = "void veryl_set_cov(";
 const CHUNK_DECL_ATTR: &str = "__attribute__((noinline,visibility(\"hidden\")))";
 const CHUNK_DEF_PREFIX: &str =
     "__attribute__((noinline,visibility(\"hidden\"))) void veryl_aot_chunk_";
@@ -4670,6 +4729,13 @@ fn compile_source_in(cache_dir: &Path, src: &str) -> Result<EmittedModule, Strin
         let table = wideops_table();
         unsafe { setter(&table as *const WideOpsTable as *const c_void) };
     }
+    // Only a coverage run has probes reading the table, and only it has one.
+    if let Some(base) = crate::coverage::counters_base()
+        && let Ok(setter) =
+            unsafe { lib.get::<unsafe extern "C" fn(*mut c_void)>(b"veryl_set_cov\0") }
+    {
+        unsafe { setter(base as *mut c_void) };
+    }
     // Event modules that emitted $display/$write expose `veryl_set_sysfn_cb`;
     // wire it to the Rust formatter so their output goes through `output_buffer`
     // (byte-identical, correctly buffered).  Absent on comb / sysfn-free
@@ -4983,6 +5049,7 @@ pub fn emit_function(stmts: &[ProtoStatement]) -> Option<String> {
          typedef uint16_t veryl_u16_ua __attribute__((__aligned__(1)));\n",
     );
     body.push_str(WIDEOPS_C_DECLS);
+    body.push_str(COVERAGE_C_DECLS);
     body.push_str(WIDEOPS_C_INLINE);
     body.push('\n');
 
@@ -6451,6 +6518,9 @@ fn emit_stmt_inner(stmt: &ProtoStatement) -> Option<String> {
         }
         ProtoStatement::For(for_stmt) => emit_for(for_stmt),
         ProtoStatement::Break => Some("break;".to_string()),
+        ProtoStatement::SystemFunctionCall(ProtoSystemFunctionCall::Coverage { block }) => Some(
+            format!("__atomic_fetch_add(&veryl_cov[{block}], 1, __ATOMIC_RELAXED);"),
+        ),
         ProtoStatement::SystemFunctionCall(call) => {
             // Event path: emit $display/$write as a call into the Rust formatter
             // (veryl_sysfn_cb) so a single rare trace statement no longer forces
@@ -6471,7 +6541,7 @@ fn emit_stmt_inner(stmt: &ProtoStatement) -> Option<String> {
                 None
             }
         }
-        ProtoStatement::TbMethodCall { .. } => {
+        ProtoStatement::TbMethodCall { .. } | ProtoStatement::Fork(_) => {
             // ClockNext / ResetAssert advance simulation timeline; the
             // testbench Module that contains them stays on the
             // Cranelift dispatch path.
@@ -9,8 +9,9 @@ use crate::ir::variable::native_bytes as calc_native_bytes;
 use crate::ir::{
     ProtoAssignDynamicStatement, ProtoAssignStatement, ProtoCaseStatement, ProtoExpression,
     ProtoForBound, ProtoForRange, ProtoForStatement, ProtoIfStatement, ProtoStatement,
+    ProtoSystemFunctionCall,
 };
-use cranelift::codegen::ir::BlockArg;
+use cranelift::codegen::ir::{AtomicRmwOp, BlockArg};
 use cranelift::prelude::Value as CraneliftValue;
 use cranelift::prelude::types::{I32, I64, I128};
 use cranelift::prelude::{FunctionBuilder, InstBuilder, IntCC, MemFlagsData};
@@ -854,10 +855,11 @@ impl ProtoStatement {
             ProtoStatement::If(x) => x.can_build_binary(),
             ProtoStatement::Case(x) => x.can_build_binary(),
             ProtoStatement::For(x) => x.can_build_binary(),
+            ProtoStatement::SystemFunctionCall(ProtoSystemFunctionCall::Coverage { .. }) => true,
             ProtoStatement::SystemFunctionCall(_) => false,
             ProtoStatement::CompiledBlock(_) => false,
             ProtoStatement::SequentialBlock(body) => body.iter().all(|s| s.can_build_binary()),
-            ProtoStatement::TbMethodCall { .. } => false,
+            ProtoStatement::TbMethodCall { .. } | ProtoStatement::Fork(_) => false,
             ProtoStatement::Break => false,
         }
     }
@@ -878,6 +880,18 @@ impl ProtoStatement {
             ProtoStatement::If(x) => x.build_binary(context, builder, is_last),
             ProtoStatement::Case(x) => x.build_binary(context, builder, is_last),
             ProtoStatement::For(x) => x.build_binary(context, builder, is_last),
+            ProtoStatement::SystemFunctionCall(ProtoSystemFunctionCall::Coverage { block }) => {
+                // The counter array never moves, so its address is baked in.
+                let base = crate::coverage::counters_base()?;
+                let addr = builder
+                    .ins()
+                    .iconst(I64, base.wrapping_add(*block as usize) as i64);
+                let one = builder.ins().iconst(I64, 1);
+                builder
+                    .ins()
+                    .atomic_rmw(I64, MemFlagsData::trusted(), AtomicRmwOp::Add, addr, one);
+                Some(())
+            }
             ProtoStatement::SystemFunctionCall(_) => None,
             ProtoStatement::CompiledBlock(_) => None,
             ProtoStatement::SequentialBlock(body) => {
@@ -886,7 +900,7 @@ impl ProtoStatement {
                 }
                 Some(())
             }
-            ProtoStatement::TbMethodCall { .. } => None,
+            ProtoStatement::TbMethodCall { .. } | ProtoStatement::Fork(_) => None,
             ProtoStatement::Break => None,
         }
     }
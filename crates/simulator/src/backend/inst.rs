@@ -305,6 +305,7 @@ fn reloc_var_meta(m: &VariableMeta, ff_delta: isize, comb_delta: isize) -> Varia
             } else {
                 e.next_offset
             },
+            force: e.force.map(|x| x + ff_delta),
         })
         .collect();
     nm
@@ -317,6 +318,7 @@ fn reloc_module_meta(
 ) -> ModuleVariableMeta {
     ModuleVariableMeta {
         name: mm.name,
+        module: mm.module,
         hierarchy: mm.hierarchy.clone(),
         variable_meta: mm
             .variable_meta
@@ -325,6 +325,7 @@ fn classify_proto_stmt(s: &ProtoStatement) -> String {
         ProtoStatement::CompiledBlock(_) => "CompiledBlock".to_string(),
         ProtoStatement::SequentialBlock(b) => format!("SequentialBlock(len={})", b.len()),
         ProtoStatement::TbMethodCall { .. } => "TbMethodCall".to_string(),
+        ProtoStatement::Fork(threads) => format!("Fork(threads={})", threads.len()),
     }
 }
 
@@ -0,0 +1,174 @@
+//! On-disk simulator checkpoint, written by `Simulator::save_checkpoint`.
+//!
+//! Layout: an 8-byte magic, a little-endian `u32` format version, a
+//! length-prefixed JSON header, then the raw sections the header sizes: FF
+//! storage, comb storage and one record per component instance, in instance
+//! order. Storage is copied byte for byte, so a checkpoint only restores into
+//! the design (and simulator build) that wrote it; the header carries enough
+//! to reject an obvious mismatch.
+
+use crate::SimulatorError;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 8] = b"VRLCKPT\0";
+const VERSION: u32 = 1;
+
+pub struct Checkpoint {
+    /// Top module name.
+    pub top: String,
+    pub use_4state: bool,
+    pub time: u64,
+    /// Testbench clock cycles completed (`Simulator::cycle_count`).
+    pub cycle: u64,
+    /// Previous-step derived-clock values, for edge detection.
+    pub derived_clocks: Vec<u8>,
+    /// `random_table::save` record.
+    pub random: serde_json::Value,
+    pub ff_values: Vec<u8>,
+    pub comb_values: Vec<u8>,
+    pub components: Vec<ComponentState>,
+}
+
+pub struct ComponentState {
+    pub name: String,
+    /// Fired-clock count, reported to the component as `ctx.cycle()`.
+    pub cycle: u64,
+    /// The component's `save_state` record.
+    pub data: Vec<u8>,
+}
+
+pub(crate) fn error(path: &Path, message: impl Into<String>) -> SimulatorError {
+    SimulatorError::Checkpoint {
+        path: path.display().to_string(),
+        message: message.into(),
+    }
+}
+
+impl Checkpoint {
+    pub fn write(&self, path: &Path) -> Result<(), SimulatorError> {
+        let components: Vec<_> = self
+            .components
+            .iter()
+            .map(|c| serde_json::json!({ "name": c.name, "cycle": c.cycle, "len": c.data.len() }))
+            .collect();
+        let header = serde_json::json!({
+            "top": self.top,
+            "use_4state": self.use_4state,
+            "time": self.time,
+            "cycle": self.cycle,
+            "derived_clocks": self.derived_clocks,
+            "random": self.random,
+            "ff_len": self.ff_values.len(),
+            "comb_len": self.comb_values.len(),
+            "components": components,
+        });
+        let header = header.to_string();
+
+        let io = |e: std::io::Error| error(path, e.to_string());
+        let file = std::fs::File::create(path).map_err(io)?;
+        let mut w = BufWriter::new(file);
+        w.write_all(MAGIC).map_err(io)?;
+        w.write_all(&VERSION.to_le_bytes()).map_err(io)?;
+        w.write_all(&(header.len() as u64).to_le_bytes())
+            .map_err(io)?;
+        w.write_all(header.as_bytes()).map_err(io)?;
+        w.write_all(&self.ff_values).map_err(io)?;
+        w.write_all(&self.comb_values).map_err(io)?;
+        for c in &self.components {
+            w.write_all(&c.data).map_err(io)?;
+        }
+        w.flush().map_err(io)
+    }
+
+    pub fn read(path: &Path) -> Result<Self, SimulatorError> {
+        let io = |e: std::io::Error| error(path, e.to_string());
+        let file = std::fs::File::open(path).map_err(io)?;
+        let mut r = BufReader::new(file);
+
+        let mut magic = [0u8; 8];
+        r.read_exact(&mut magic)
+            .map_err(|_| error(path, "not a checkpoint file"))?;
+        if &magic != MAGIC {
+            return Err(error(path, "not a checkpoint file"));
+        }
+        let mut word = [0u8; 4];
+        r.read_exact(&mut word).map_err(io)?;
+        let version = u32::from_le_bytes(word);
+        if version != VERSION {
+            return Err(error(
+                path,
+                format!("unsupported format version {version} (expected {VERSION})"),
+            ));
+        }
+        let mut len = [0u8; 8];
+        r.read_exact(&mut len).map_err(io)?;
+        let header = read_section(&mut r, u64::from_le_bytes(len))
+            .map_err(|_| error(path, "truncated checkpoint"))?;
+        let header: serde_json::Value = serde_json::from_slice(&header)
+            .map_err(|e| error(path, format!("malformed header: {e}")))?;
+
+        let malformed = |field: &str| error(path, format!("malformed header: missing `{field}`"));
+        let u64_field = |field: &str| header[field].as_u64().ok_or_else(|| malformed(field));
+        let mut section = |len: u64| -> Result<Vec<u8>, SimulatorError> {
+            read_section(&mut r, len).map_err(|_| error(path, "truncated checkpoint"))
+        };
+
+        let top = header["top"]
+            .as_str()
+            .ok_or_else(|| malformed("top"))?
+            .to_string();
+        let use_4state = header["use_4state"]
+            .as_bool()
+            .ok_or_else(|| malformed("use_4state"))?;
+        let time = u64_field("time")?;
+        let cycle = u64_field("cycle")?;
+        let derived_clocks = header["derived_clocks"]
+            .as_array()
+            .ok_or_else(|| malformed("derived_clocks"))?
+            .iter()
+            .map(|v| v.as_u64().map(|v| v as u8))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| malformed("derived_clocks"))?;
+        let ff_values = section(u64_field("ff_len")?)?;
+        let comb_values = section(u64_field("comb_len")?)?;
+        let mut components = Vec::new();
+        for c in header["components"]
+            .as_array()
+            .ok_or_else(|| malformed("components"))?
+        {
+            let name = c["name"].as_str().ok_or_else(|| malformed("name"))?;
+            let cycle = c["cycle"].as_u64().ok_or_else(|| malformed("cycle"))?;
+            let len = c["len"].as_u64().ok_or_else(|| malformed("len"))?;
+            components.push(ComponentState {
+                name: name.to_string(),
+                cycle,
+                data: section(len)?,
+            });
+        }
+
+        Ok(Self {
+            top,
+            use_4state,
+            time,
+            cycle,
+            derived_clocks,
+            random: header["random"].clone(),
+            ff_values,
+            comb_values,
+            components,
+        })
+    }
+}
+
+/// Reads a `len`-byte section. The buffer grows with the data actually read,
+/// so a corrupt length fails as truncated rather than allocating it upfront.
+fn read_section(r: &mut impl Read, len: u64) -> std::io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    r.take(len).read_to_end(&mut buf)?;
+    if buf.len() as u64 == len {
+        Ok(buf)
+    } else {
+        Err(std::io::ErrorKind::UnexpectedEof.into())
+    }
+}
@@ -148,6 +148,9 @@ pub struct HostContext {
     /// rejected (`in_create` gates it).
     pub trace_vars: Vec<HostTraceVar>,
     in_create: bool,
+    /// Checkpoint record collected from `state_write`; `Some` only while a
+    /// `save_state` hook runs.
+    state: Option<Vec<u8>>,
 }
 
 impl HostContext {
@@ -526,6 +529,12 @@ impl HostContext {
         };
         var.words.copy_from_slice(words);
     }
+
+    pub(crate) fn svc_state_write(&mut self, data: &[u8]) {
+        if let Some(state) = &mut self.state {
+            state.extend_from_slice(data);
+        }
+    }
 }
 
 /// # Safety
@@ -694,6 +703,16 @@ extern "C" fn host_trace_write(ctx: *mut sys::VrlCtx, handle: i32, words: *const
     host.svc_trace_write(handle, words);
 }
 
+extern "C" fn host_state_write(ctx: *mut sys::VrlCtx, data: *const u8, len: usize) {
+    let host = unsafe { host(ctx) };
+    let data = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }
+    };
+    host.svc_state_write(data);
+}
+
 static HOST_API: sys::VrlHostApi = sys::VrlHostApi {
     size: size_of::<sys::VrlHostApi>(),
     port_index: host_port_index,
@@ -717,6 +736,7 @@ static HOST_API: sys::VrlHostApi = sys::VrlHostApi {
     trace_write: host_trace_write,
     is_4state: host_is_4state,
     port_direct: host_port_direct,
+    state_write: host_state_write,
 };
 
 /// Capacity of the method return buffer; larger than the 64-bit values the
@@ -837,6 +857,32 @@ impl ExternalInstance {
         }
     }
 
+    /// Collects the instance's checkpoint record. `None` means the
+    /// component reported an error, already recorded in the context's
+    /// failures.
+    pub fn save_state(&mut self, host: &mut HostContext) -> Option<Vec<u8>> {
+        host.state = Some(Vec::new());
+        let rc = match &mut self.inner {
+            InstanceInner::Native { state, vtable } => unsafe {
+                (vtable.save_state)(*state, host.as_ctx())
+            },
+            #[cfg(not(target_family = "wasm"))]
+            InstanceInner::Wasm(instance) => instance.save_state(host),
+        };
+        let state = host.state.take();
+        if rc != 0 { None } else { state }
+    }
+
+    pub fn restore_state(&mut self, host: &mut HostContext, data: &[u8]) -> i32 {
+        match &mut self.inner {
+            InstanceInner::Native { state, vtable } => unsafe {
+                (vtable.restore_state)(*state, host.as_ctx(), data.as_ptr(), data.len())
+            },
+            #[cfg(not(target_family = "wasm"))]
+            InstanceInner::Wasm(instance) => instance.restore_state(host, data),
+        }
+    }
+
     /// Zero-time method call. `None` means the component reported an error,
     /// already recorded in the context's failures.
     pub fn call_method(
@@ -174,7 +174,7 @@ fn value_to_words(value: &Value, nwords: usize) -> Vec<u64> {
 }
 
 /// Payload words of a value into a reused buffer, LSB-first.
-fn value_to_words_into(value: &Value, nwords: usize, out: &mut Vec<u64>) {
+pub(crate) fn value_to_words_into(value: &Value, nwords: usize, out: &mut Vec<u64>) {
     out.clear();
     match value {
         Value::U64(x) => out.push(x.payload),
@@ -184,7 +184,7 @@ fn value_to_words_into(value: &Value, nwords: usize, out: &mut Vec<u64>) {
 }
 
 /// Four-state mask words of a value (X/Z bits) into a reused buffer, LSB-first.
-fn value_to_mask_xz_into(value: &Value, nwords: usize, out: &mut Vec<u64>) {
+pub(crate) fn value_to_mask_xz_into(value: &Value, nwords: usize, out: &mut Vec<u64>) {
     out.clear();
     match value {
         Value::U64(x) => out.push(x.mask_xz),
@@ -869,6 +869,33 @@ impl RuntimeComponent {
         }
     }
 
+    /// The instance's checkpoint record and its fired-clock count. `None`
+    /// means the component failed the hook (recorded as a failure).
+    pub fn save_state(&mut self) -> Option<(u64, Vec<u8>)> {
+        let failures_before = self.host.failures().len();
+        match self.instance.save_state(&mut self.host) {
+            Some(data) => Some((self.fire_count, data)),
+            None => {
+                if self.host.failures().len() == failures_before {
+                    self.host.svc_fail("component hook `save_state` failed");
+                }
+                None
+            }
+        }
+    }
+
+    /// Counterpart of [`Self::save_state`]; returns false on a failed hook.
+    pub fn restore_state(&mut self, fire_count: u64, data: &[u8]) -> bool {
+        self.fire_count = fire_count;
+        self.host.cycle = fire_count;
+        let failures_before = self.host.failures().len();
+        let rc = self.instance.restore_state(&mut self.host, data);
+        if rc != 0 && self.host.failures().len() == failures_before {
+            self.host.svc_fail("component hook `restore_state` failed");
+        }
+        rc == 0
+    }
+
     pub fn listens_to(&self, event: &Event) -> bool {
         match event {
             Event::Clock(_) => self.clock_events.iter().any(|(e, _)| e == event),
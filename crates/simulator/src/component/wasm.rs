@@ -133,6 +133,10 @@ struct GuestFuncs {
     on_reset: TypedFunc<u32, i32>,
     on_clock: TypedFunc<u32, i32>,
     on_finish: TypedFunc<u32, i32>,
+    /// Optional at instantiation so a guest built against an older ABI
+    /// still reaches the version check and reports a mismatch.
+    save_state: Option<TypedFunc<u32, i32>>,
+    restore_state: Option<TypedFunc<(u32, u32, u32), i32>>,
     call_method: TypedFunc<(u32, u32, u32, u32, u32, u32), i32>,
     alloc: TypedFunc<u32, u32>,
     free: TypedFunc<(u32, u32), ()>,
@@ -200,6 +204,20 @@ impl WasmLibrary {
                 "veryl_component_on_finish",
                 &self.path,
             )?,
+            save_state: typed(
+                &mut store,
+                &instance,
+                "veryl_component_save_state",
+                &self.path,
+            )
+            .ok(),
+            restore_state: typed(
+                &mut store,
+                &instance,
+                "veryl_component_restore_state",
+                &self.path,
+            )
+            .ok(),
             call_method: typed(
                 &mut store,
                 &instance,
@@ -395,6 +413,43 @@ impl WasmInstance {
         self.call_hook(self.funcs.on_finish.clone(), host)
     }
 
+    pub(crate) fn save_state(&mut self, host: &mut HostContext) -> i32 {
+        let Some(f) = self.funcs.save_state.clone() else {
+            host.svc_fail("component exports no `veryl_component_save_state`");
+            return 1;
+        };
+        self.call_hook(f, host)
+    }
+
+    pub(crate) fn restore_state(&mut self, host: &mut HostContext, data: &[u8]) -> i32 {
+        let Some(f) = self.funcs.restore_state.clone() else {
+            host.svc_fail("component exports no `veryl_component_restore_state`");
+            return 1;
+        };
+        let mut allocs = vec![];
+        let ptr = match self.alloc_tracked(data, &mut allocs) {
+            Ok(ptr) => ptr,
+            Err(e) => {
+                self.free_tracked(&allocs);
+                host.svc_fail(&format!("state restore setup failed: {}", trap_text(&e)));
+                return 1;
+            }
+        };
+
+        self.store.data_mut().host = host;
+        arm_call_deadline(&mut self.store);
+        let result = f.call(&mut self.store, (self.handle, ptr, data.len() as u32));
+        self.store.data_mut().host = std::ptr::null_mut();
+        self.free_tracked(&allocs);
+        match result {
+            Ok(rc) => rc,
+            Err(e) => {
+                host.svc_fail(&format!("component trapped: {}", trap_text(&e)));
+                1
+            }
+        }
+    }
+
     fn alloc_tracked(
         &mut self,
         bytes: &[u8],
@@ -883,5 +938,17 @@ fn add_host_imports(linker: &mut Linker<StoreCtx>) -> WResult<()> {
             Ok(())
         },
     )?;
+    linker.func_wrap(
+        m,
+        "state_write",
+        |mut caller: Caller<'_, StoreCtx>, data: u32, len: u32| -> WResult<()> {
+            let memory = memory_of(&mut caller)?;
+            let bytes = guest_bytes(memory, &caller, data, len)?;
+            if let Some(host) = host_of(&caller) {
+                host.svc_state_write(&bytes);
+            }
+            Ok(())
+        },
+    )?;
     Ok(())
 }
@@ -0,0 +1,171 @@
+//! Thread-local table of the functional cover points a testbench samples.
+//!
+//! `$cover(cond, "name")` counts how often it ran and how often it saw `cond`
+//! true.  `$cover_bins(value, "name", n)` splits the range of `value` into `n`
+//! equal bins (64 when omitted, never more than the values there are) and
+//! counts the samples falling in each; an X/Z sample counts in no bin.
+//! Placed in an `always_ff` of the testbench, either one samples once per
+//! edge of that clock.
+//!
+//! Like `random_table`, the table is per thread and so per test:
+//! `run_testbench` resets it and the driver collects the points with
+//! [`take`] when the test is done.  Points are keyed by name, so two calls
+//! with one name are one point.
+
+use crate::ir::Value;
+use std::cell::RefCell;
+
+/// Bins a `$cover_bins` without an explicit count gets, like the default
+/// `auto_bin_max` of a SystemVerilog coverpoint.
+pub const DEFAULT_BINS: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverBins {
+    /// Width of the sampled value; at most 64.
+    pub width: usize,
+    /// Requested bin count, before clamping to the values there are.
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoverBin {
+    /// Inclusive value range of the bin.
+    pub low: u64,
+    pub high: u64,
+    pub hits: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CoverKind {
+    /// `$cover`: samples that saw the condition true.
+    Point { hits: u64 },
+    /// `$cover_bins`.
+    Bins(Vec<CoverBin>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoverPoint {
+    pub name: String,
+    /// Times the point was sampled.
+    pub samples: u64,
+    pub kind: CoverKind,
+}
+
+impl CoverPoint {
+    /// Bins in total and bins hit; a `$cover` point is a single bin.
+    pub fn bins_hit(&self) -> (usize, usize) {
+        match &self.kind {
+            CoverKind::Point { hits } => (1, (*hits > 0) as usize),
+            CoverKind::Bins(bins) => (bins.len(), bins.iter().filter(|b| b.hits > 0).count()),
+        }
+    }
+}
+
+thread_local! {
+    static TABLE: RefCell<Vec<CoverPoint>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Drop every point. Call before a test.
+pub fn reset() {
+    TABLE.with(|t| t.borrow_mut().clear());
+}
+
+/// The points sampled since the last [`reset`], in order of first sample.
+pub fn take() -> Vec<CoverPoint> {
+    TABLE.with(|t| std::mem::take(&mut *t.borrow_mut()))
+}
+
+fn with_point(name: &str, create: impl FnOnce() -> CoverKind, f: impl FnOnce(&mut CoverPoint)) {
+    TABLE.with(|t| {
+        let mut t = t.borrow_mut();
+        let index = match t.iter().position(|p| p.name == name) {
+            Some(index) => index,
+            None => {
+                t.push(CoverPoint {
+                    name: name.to_string(),
+                    samples: 0,
+                    kind: create(),
+                });
+                t.len() - 1
+            }
+        };
+        f(&mut t[index]);
+    });
+}
+
+fn create_bins(bins: CoverBins) -> CoverKind {
+    let values = 1u128 << bins.width;
+    let count = (bins.count.max(1) as u128).min(values);
+    let bins = (0..count)
+        .map(|i| CoverBin {
+            low: (i * values / count) as u64,
+            high: ((i + 1) * values / count - 1) as u64,
+            hits: 0,
+        })
+        .collect();
+    CoverKind::Bins(bins)
+}
+
+/// One execution of `$cover(cond, name)`.
+pub fn sample(name: &str, cond: &Value) {
+    let hit = !cond.is_xz() && cond.payload_u64() != 0;
+    with_point(
+        name,
+        || CoverKind::Point { hits: 0 },
+        |p| {
+            p.samples += 1;
+            if let CoverKind::Point { hits } = &mut p.kind {
+                *hits += hit as u64;
+            }
+        },
+    );
+}
+
+/// One execution of `$cover_bins(value, name, bins.count)`.
+pub fn sample_bins(name: &str, bins: CoverBins, value: &Value) {
+    with_point(
+        name,
+        || create_bins(bins),
+        |p| {
+            p.samples += 1;
+            if value.is_xz() {
+                return;
+            }
+            let value = value.payload_u64();
+            if let CoverKind::Bins(bins) = &mut p.kind
+                && let Some(bin) = bins.iter_mut().find(|b| b.low <= value && value <= b.high)
+            {
+                bin.hits += 1;
+            }
+        },
+    );
+}
+
+/// Fold `points` into `into`, adding up the counts of points with one name.
+pub fn merge(into: &mut Vec<CoverPoint>, points: Vec<CoverPoint>) {
+    for point in points {
+        let Some(dst) = into.iter_mut().find(|p| p.name == point.name) else {
+            into.push(point);
+            continue;
+        };
+        dst.samples += point.samples;
+        match (&mut dst.kind, point.kind) {
+            (CoverKind::Point { hits }, CoverKind::Point { hits: src }) => *hits += src,
+            (CoverKind::Bins(bins), CoverKind::Bins(src)) => {
+                for src in src {
+                    match bins
+                        .iter_mut()
+                        .find(|b| b.low == src.low && b.high == src.high)
+                    {
+                        Some(bin) => bin.hits += src.hits,
+                        None => bins.push(src),
+                    }
+                }
+                bins.sort_unstable_by_key(|b| (b.low, b.high));
+            }
+            // One name used as a point in one test and as bins in another:
+            // keep the first.
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,406 @@
+//! Line, branch and toggle coverage for `veryl test --coverage`.
+//!
+//! With `Config::coverage` set, conv opens every statement list — a process
+//! body, an `if`/`else` side, a `case` arm, a `for` body — with a
+//! `Coverage` probe that bumps the counter of that block.  Blocks are keyed
+//! by source position, so every instance of a module and every test in the
+//! process share one counter per block.  The counters sit in one fixed,
+//! zero-initialised array whose address never changes, so the Cranelift and
+//! AOT-C backends bump them in place.
+//!
+//! Toggle coverage is sampled by the simulator at each dump point instead
+//! (see [`ToggleTracker`]) and merged here when the simulator is dropped.
+//!
+//! [`report`] folds both into per-file line and branch hit counts and
+//! per-signal toggle counts.  Only modules some test elaborated appear.
+
+use crate::HashMap;
+use crate::ir::ModuleVariables;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use veryl_parser::resource_table;
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_token::TokenSource;
+
+/// Number of blocks the counter array holds.  Reserved, not committed:
+/// untouched pages of the zeroed allocation cost nothing.
+pub const CAPACITY: usize = 1 << 20;
+
+/// Which statement list of its owner a block is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Slot {
+    /// A process or function body, keyed by its first statement.
+    Body,
+    /// The body of the `for` that owns it.
+    Loop,
+    /// Arm `n` of the `if` / `case` that owns it: `if` has the true side
+    /// as arm 0 and the false side as arm 1, `case` its default last.
+    Arm(u32),
+}
+
+struct Block {
+    path: PathBuf,
+    /// Lines of the statements directly in the block.
+    lines: Vec<u32>,
+    /// Line and column of the owning `if` / `case`, for an arm.
+    branch: Option<(u32, u32, u32)>,
+}
+
+type ToggleBits = (usize, Vec<u8>, Vec<u8>);
+
+/// Per-file line hits and ((line, column, arm), hits) branches.
+type FileCounts = (BTreeMap<u32, u64>, Vec<((u32, u32, u32), u64)>);
+
+#[derive(Default)]
+struct Registry {
+    /// Keyed by the path itself: `PathId`s come from a per-thread table,
+    /// and tests build their IR on whichever thread runs them.
+    ids: HashMap<(PathBuf, u32, u32, Slot), u32>,
+    blocks: Vec<Block>,
+    /// (module, signal) → width and bits seen rising and falling.
+    toggles: BTreeMap<(String, String), ToggleBits>,
+}
+
+static REGISTRY: LazyLock<Mutex<Registry>> = LazyLock::new(Default::default);
+static COUNTERS: OnceLock<&'static [AtomicU64]> = OnceLock::new();
+
+fn counters() -> &'static [AtomicU64] {
+    COUNTERS.get_or_init(|| {
+        let layout = std::alloc::Layout::array::<AtomicU64>(CAPACITY).unwrap();
+        // SAFETY: an all-zero AtomicU64 is a valid 0; the allocation is
+        // leaked, so the slice is 'static.
+        unsafe {
+            let ptr = std::alloc::alloc_zeroed(layout) as *const AtomicU64;
+            if ptr.is_null() {
+                std::alloc::handle_alloc_error(layout);
+            }
+            std::slice::from_raw_parts(ptr, CAPACITY)
+        }
+    })
+}
+
+/// Base of the counter array, once any block has been registered.  Backends
+/// that bake the address in (or publish it to a `.so`) read it from here.
+pub(crate) fn counters_base() -> Option<*const AtomicU64> {
+    COUNTERS.get().map(|c| c.as_ptr())
+}
+
+/// Registers the block `slot` of `owner` holding statements on `lines`, and
+/// returns its counter index.  `None` for a block with no source file behind
+/// it, or once [`CAPACITY`] blocks exist.
+pub(crate) fn register_block(
+    owner: &TokenRange,
+    slot: Slot,
+    lines: impl IntoIterator<Item = u32>,
+) -> Option<u32> {
+    let TokenSource::File { path, .. } = owner.beg.source else {
+        return None;
+    };
+    let path = resource_table::get_path_value(path)?;
+    let key = (path.clone(), owner.beg.line, owner.beg.column, slot);
+    let mut registry = REGISTRY.lock().unwrap();
+    if let Some(&id) = registry.ids.get(&key) {
+        return Some(id);
+    }
+    if registry.blocks.len() >= CAPACITY {
+        return None;
+    }
+    let mut lines: Vec<u32> = lines.into_iter().collect();
+    if let Slot::Body = slot {
+        lines.push(owner.beg.line);
+    }
+    lines.sort_unstable();
+    lines.dedup();
+    let branch = match slot {
+        Slot::Arm(arm) => Some((owner.beg.line, owner.beg.column, arm)),
+        _ => None,
+    };
+    let id = registry.blocks.len() as u32;
+    registry.blocks.push(Block {
+        path,
+        lines,
+        branch,
+    });
+    registry.ids.insert(key, id);
+    counters();
+    Some(id)
+}
+
+/// Counts one execution of block `id` (interpreter path).
+pub(crate) fn hit(id: u32) {
+    counters()[id as usize].fetch_add(1, Ordering::Relaxed);
+}
+
+/// One sampled signal: `bytes` of little-endian payload at `ptr`.
+struct ToggleSignal {
+    module: String,
+    name: String,
+    width: usize,
+    ptr: *const u8,
+    prev: Vec<u8>,
+    rose: Vec<u8>,
+    fell: Vec<u8>,
+}
+
+/// Per-simulator toggle sampler.  Tracks every scalar variable (clocks and
+/// array elements are left out) and folds its bits into the process-wide
+/// totals on drop.
+pub struct ToggleTracker {
+    signals: Vec<ToggleSignal>,
+    sampled: bool,
+}
+
+// SAFETY: the pointers address the owning simulator's value buffers, which
+// move with it; see `DumpVar`.
+unsafe impl Send for ToggleTracker {}
+
+impl ToggleTracker {
+    pub fn new(module_vars: &ModuleVariables) -> Self {
+        let mut signals = Vec::new();
+        Self::collect(module_vars, &mut signals);
+        Self {
+            signals,
+            sampled: false,
+        }
+    }
+
+    /// Signals are named relative to their module, so every instance of a
+    /// module folds into the same entry.
+    fn collect(module_vars: &ModuleVariables, signals: &mut Vec<ToggleSignal>) {
+        let module = module_vars.module.to_string();
+        for x in module_vars.variables.values() {
+            if x.current_values.len() != 1 || x.r#type.is_clock() || x.width == 0 {
+                continue;
+            }
+            let bytes = x.width.div_ceil(8);
+            signals.push(ToggleSignal {
+                module: module.clone(),
+                name: x.path.to_string(),
+                width: x.width,
+                ptr: x.current_values[0],
+                prev: vec![0; bytes],
+                rose: vec![0; bytes],
+                fell: vec![0; bytes],
+            });
+        }
+        for child in &module_vars.children {
+            Self::collect(child, signals);
+        }
+    }
+
+    /// Records the bits that changed since the previous sample.  The first
+    /// sample only sets the baseline.
+    pub fn sample(&mut self) {
+        for s in &mut self.signals {
+            for i in 0..s.prev.len() {
+                // SAFETY: `ptr` addresses at least `width` bits of payload.
+                let now = unsafe { s.ptr.add(i).read() };
+                let was = s.prev[i];
+                if self.sampled {
+                    s.rose[i] |= now & !was;
+                    s.fell[i] |= !now & was;
+                }
+                s.prev[i] = now;
+            }
+        }
+        self.sampled = true;
+    }
+}
+
+impl Drop for ToggleTracker {
+    fn drop(&mut self) {
+        let mut registry = REGISTRY.lock().unwrap();
+        for s in self.signals.drain(..) {
+            let entry = registry
+                .toggles
+                .entry((s.module, s.name))
+                .or_insert_with(|| (s.width, vec![0; s.rose.len()], vec![0; s.fell.len()]));
+            for (acc, bits) in entry.1.iter_mut().zip(&s.rose) {
+                *acc |= bits;
+            }
+            for (acc, bits) in entry.2.iter_mut().zip(&s.fell) {
+                *acc |= bits;
+            }
+        }
+    }
+}
+
+pub struct BranchCoverage {
+    pub line: u32,
+    /// Index of the owning `if` / `case` among those of the file.
+    pub block: u32,
+    pub arm: u32,
+    pub hits: u64,
+}
+
+pub struct FileCoverage {
+    pub path: PathBuf,
+    /// Line → executions.
+    pub lines: BTreeMap<u32, u64>,
+    pub branches: Vec<BranchCoverage>,
+}
+
+pub struct ToggleCoverage {
+    pub module: String,
+    pub signal: String,
+    pub bits: usize,
+    /// Bits seen both rising and falling.
+    pub toggled: usize,
+}
+
+#[derive(Default)]
+pub struct Report {
+    pub files: Vec<FileCoverage>,
+    pub toggles: Vec<ToggleCoverage>,
+}
+
+/// Snapshot of everything counted so far in this process.
+pub fn report() -> Report {
+    let registry = REGISTRY.lock().unwrap();
+    let counters = COUNTERS.get();
+    let count = |id: usize| counters.map_or(0, |c| c[id].load(Ordering::Relaxed));
+
+    let mut files: BTreeMap<&PathBuf, FileCounts> = BTreeMap::new();
+    for (id, block) in registry.blocks.iter().enumerate() {
+        let hits = count(id);
+        let (lines, branches) = files.entry(&block.path).or_default();
+        for &line in &block.lines {
+            *lines.entry(line).or_default() += hits;
+        }
+        if let Some(branch) = block.branch {
+            branches.push((branch, hits));
+        }
+    }
+    let files = files
+        .into_iter()
+        .map(|(path, (lines, mut branches))| {
+            branches.sort_unstable_by_key(|(b, _)| *b);
+            let mut block = 0;
+            let mut owner = None;
+            let branches = branches
+                .into_iter()
+                .map(|((line, column, arm), hits)| {
+                    if owner.is_some_and(|o| o != (line, column)) {
+                        block += 1;
+                    }
+                    owner = Some((line, column));
+                    BranchCoverage {
+                        line,
+                        block,
+                        arm,
+                        hits,
+                    }
+                })
+                .collect();
+            FileCoverage {
+                path: path.clone(),
+                lines,
+                branches,
+            }
+        })
+        .collect();
+
+    let toggles = registry
+        .toggles
+        .iter()
+        .map(|((module, signal), (width, rose, fell))| {
+            let toggled = (0..*width)
+                .filter(|i| (rose[i / 8] & fell[i / 8]) >> (i % 8) & 1 != 0)
+                .count();
+            ToggleCoverage {
+                module: module.clone(),
+                signal: signal.clone(),
+                bits: *width,
+                toggled,
+            }
+        })
+        .collect();
+
+    Report { files, toggles }
+}
+
+impl FileCoverage {
+    pub fn lines_hit(&self) -> usize {
+        self.lines.values().filter(|&&n| n > 0).count()
+    }
+
+    pub fn branches_hit(&self) -> usize {
+        self.branches.iter().filter(|b| b.hits > 0).count()
+    }
+}
+
+impl Report {
+    /// Writes the line and branch counts as an LCOV tracefile.
+    pub fn write_lcov(&self, w: &mut impl Write) -> io::Result<()> {
+        writeln!(w, "TN:")?;
+        for file in &self.files {
+            writeln!(w, "SF:{}", file.path.display())?;
+            for b in &file.branches {
+                writeln!(w, "BRDA:{},{},{},{}", b.line, b.block, b.arm, b.hits)?;
+            }
+            writeln!(w, "BRF:{}", file.branches.len())?;
+            writeln!(w, "BRH:{}", file.branches_hit())?;
+            for (line, hits) in &file.lines {
+                writeln!(w, "DA:{line},{hits}")?;
+            }
+            writeln!(w, "LF:{}", file.lines.len())?;
+            writeln!(w, "LH:{}", file.lines_hit())?;
+            writeln!(w, "end_of_record")?;
+        }
+        Ok(())
+    }
+
+    /// Totals per kind, per file and per signal.
+    pub fn summary(&self) -> serde_json::Value {
+        fn ratio(found: usize, hit: usize) -> serde_json::Value {
+            let percent = if found == 0 {
+                100.0
+            } else {
+                hit as f64 * 100.0 / found as f64
+            };
+            serde_json::json!({ "found": found, "hit": hit, "percent": percent })
+        }
+
+        let lines_found: usize = self.files.iter().map(|f| f.lines.len()).sum();
+        let lines_hit: usize = self.files.iter().map(|f| f.lines_hit()).sum();
+        let branches_found: usize = self.files.iter().map(|f| f.branches.len()).sum();
+        let branches_hit: usize = self.files.iter().map(|f| f.branches_hit()).sum();
+        let bits: usize = self.toggles.iter().map(|t| t.bits).sum();
+        let toggled: usize = self.toggles.iter().map(|t| t.toggled).sum();
+
+        let files: Vec<_> = self
+            .files
+            .iter()
+            .map(|f| {
+                serde_json::json!({
+                    "path": f.path.to_string_lossy(),
+                    "lines": ratio(f.lines.len(), f.lines_hit()),
+                    "branches": ratio(f.branches.len(), f.branches_hit()),
+                })
+            })
+            .collect();
+        let signals: Vec<_> = self
+            .toggles
+            .iter()
+            .map(|t| {
+                serde_json::json!({
+                    "module": t.module,
+                    "signal": t.signal,
+                    "bits": t.bits,
+                    "toggled": t.toggled,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "lines": ratio(lines_found, lines_hit),
+            "branches": ratio(branches_found, branches_hit),
+            "toggles": ratio(bits, toggled),
+            "files": files,
+            "signals": signals,
+        })
+    }
+}
@@ -0,0 +1,637 @@
+//! Interactive debugger for native testbenches (`veryl test --debug`).
+//!
+//! Under `Config::debug`, conv puts a `Line` marker ahead of every testbench
+//! statement.  The testbench driver reports those markers, the end of every
+//! clock cycle and the end of the test to the [`Debugger`] the simulator
+//! carries.  The debugger checks its breakpoints there and, when the run has
+//! to stop, hands the simulator to its [`Frontend`] until the user resumes.
+//! [`Repl`] is the line-oriented frontend of `veryl test --debug`;
+//! `veryl-dap` drives the same API over the Debug Adapter Protocol.
+//!
+//! Line breakpoints and `next` work at testbench-statement granularity; the
+//! design itself advances a whole clock edge at a time, so signal and
+//! condition breakpoints are checked after every edge and every statement.
+
+use crate::HashMap;
+use crate::ir::{ModuleVariables, Value, VarPath};
+use crate::simulator::{SignalRef, Simulator};
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+use veryl_parser::resource_table;
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_token::TokenSource;
+
+/// A point where the testbench driver reports to the debugger.
+pub(crate) enum Site<'a> {
+    /// Before the first testbench statement.
+    Start,
+    /// Before the testbench statement at the token.
+    Line(&'a TokenRange),
+    /// After a testbench clock cycle.
+    Cycle,
+    /// After the test, with its failure message if it failed.
+    End(Option<&'a str>),
+}
+
+/// Why the run stopped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// Before the first testbench statement.
+    Start,
+    /// A `step` or `next` completed.
+    Step,
+    /// Breakpoint `id` was hit; `detail` says how.
+    Breakpoint { id: u32, detail: String },
+    /// The frontend asked to stop from [`Frontend::poll`].
+    Pause,
+    /// The test ended, with its failure message if it failed.
+    End { failure: Option<String> },
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stop::Start => "stopped at start".fmt(f),
+            Stop::Step => "stopped".fmt(f),
+            Stop::Pause => "paused".fmt(f),
+            Stop::Breakpoint { id, detail } => write!(f, "breakpoint {id}: {detail}"),
+            Stop::End { failure: None } => "test passed".fmt(f),
+            Stop::End { failure: Some(msg) } => write!(f, "test failed: {msg}"),
+        }
+    }
+}
+
+/// How the run continues after a stop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resume {
+    /// Run until a breakpoint or the end of the test.
+    Continue,
+    /// Stop again after this many clock cycles.
+    Cycles(u64),
+    /// Stop again before the next testbench statement.
+    Statement,
+    /// Stop the test.
+    Quit,
+}
+
+/// What the user sees of a stopped run.
+pub trait Frontend {
+    /// Called at every stop; returns once the user resumes the run.
+    fn stopped(&mut self, state: &mut DebugState, sim: &mut Simulator, stop: &Stop) -> Resume;
+
+    /// Called at every site while the run goes on, for a frontend that takes
+    /// requests as they come; `true` stops the run with [`Stop::Pause`].
+    fn poll(&mut self, _state: &mut DebugState, _sim: &mut Simulator) -> bool {
+        false
+    }
+}
+
+/// Comparison of a condition breakpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    fn holds(self, lhs: u64, rhs: u64) -> bool {
+        match self {
+            CompareOp::Eq => lhs == rhs,
+            CompareOp::Ne => lhs != rhs,
+            CompareOp::Lt => lhs < rhs,
+            CompareOp::Le => lhs <= rhs,
+            CompareOp::Gt => lhs > rhs,
+            CompareOp::Ge => lhs >= rhs,
+        }
+    }
+}
+
+impl FromStr for CompareOp {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "==" => Ok(CompareOp::Eq),
+            "!=" => Ok(CompareOp::Ne),
+            "<" => Ok(CompareOp::Lt),
+            "<=" => Ok(CompareOp::Le),
+            ">" => Ok(CompareOp::Gt),
+            ">=" => Ok(CompareOp::Ge),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for CompareOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            CompareOp::Eq => "==",
+            CompareOp::Ne => "!=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        };
+        text.fmt(f)
+    }
+}
+
+pub struct Breakpoint {
+    pub id: u32,
+    pub kind: BreakpointKind,
+}
+
+pub enum BreakpointKind {
+    /// Before a testbench statement on `line`, in a file whose path ends
+    /// with `file` if given.
+    Line { file: Option<String>, line: u32 },
+    /// When the variable at `path` changes value.
+    Change {
+        path: String,
+        signal: SignalRef,
+        last: Value,
+    },
+    /// When `path op value` becomes true.  Values compare as unsigned
+    /// integers of their low 64 bits; a value with X/Z bits never matches.
+    Condition {
+        path: String,
+        signal: SignalRef,
+        op: CompareOp,
+        value: Value,
+        /// Whether the condition held at the last check, so that it stops
+        /// once per rising edge rather than at every check it stays true.
+        held: bool,
+    },
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            BreakpointKind::Line {
+                file: Some(file),
+                line,
+            } => write!(f, "{}: line {file}:{line}", self.id),
+            BreakpointKind::Line { file: None, line } => write!(f, "{}: line {line}", self.id),
+            BreakpointKind::Change { path, .. } => write!(f, "{}: watch {path}", self.id),
+            BreakpointKind::Condition {
+                path, op, value, ..
+            } => write!(f, "{}: when {path} {op} {value:x}", self.id),
+        }
+    }
+}
+
+/// Breakpoints and the current position, shared with the frontend.
+#[derive(Default)]
+pub struct DebugState {
+    breakpoints: Vec<Breakpoint>,
+    next_id: u32,
+    location: Option<TokenRange>,
+}
+
+impl DebugState {
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    /// The testbench statement the run last reached.
+    pub fn location(&self) -> Option<&TokenRange> {
+        self.location.as_ref()
+    }
+
+    pub fn add_line(&mut self, file: Option<String>, line: u32) -> u32 {
+        self.add(BreakpointKind::Line { file, line })
+    }
+
+    pub fn add_watch(&mut self, sim: &mut Simulator, path: &str) -> Result<u32, String> {
+        let signal = resolve(sim, path)?;
+        let last = sim.read_signal(&signal);
+        Ok(self.add(BreakpointKind::Change {
+            path: path.to_string(),
+            signal,
+            last,
+        }))
+    }
+
+    pub fn add_condition(
+        &mut self,
+        sim: &mut Simulator,
+        path: &str,
+        op: CompareOp,
+        value: Value,
+    ) -> Result<u32, String> {
+        let signal = resolve(sim, path)?;
+        let held = compare(&sim.read_signal(&signal), op, &value);
+        Ok(self.add(BreakpointKind::Condition {
+            path: path.to_string(),
+            signal,
+            op,
+            value,
+            held,
+        }))
+    }
+
+    /// Removes breakpoint `id`; `false` if there is none.
+    pub fn remove(&mut self, id: u32) -> bool {
+        let len = self.breakpoints.len();
+        self.breakpoints.retain(|x| x.id != id);
+        self.breakpoints.len() != len
+    }
+
+    fn add(&mut self, kind: BreakpointKind) -> u32 {
+        self.next_id += 1;
+        self.breakpoints.push(Breakpoint {
+            id: self.next_id,
+            kind,
+        });
+        self.next_id
+    }
+
+    /// The first signal or condition breakpoint that fires, after updating
+    /// every one of them.
+    fn check_signals(&mut self, sim: &mut Simulator) -> Option<Stop> {
+        let mut stop = None;
+        for bp in &mut self.breakpoints {
+            let detail = match &mut bp.kind {
+                BreakpointKind::Line { .. } => None,
+                BreakpointKind::Change { path, signal, last } => {
+                    let value = sim.read_signal(signal);
+                    if value != *last {
+                        let detail = format!("{path} changed {last:x} -> {value:x}");
+                        *last = value;
+                        Some(detail)
+                    } else {
+                        None
+                    }
+                }
+                BreakpointKind::Condition {
+                    path,
+                    signal,
+                    op,
+                    value,
+                    held,
+                } => {
+                    let current = sim.read_signal(signal);
+                    let holds = compare(&current, *op, value);
+                    let rising = holds && !*held;
+                    *held = holds;
+                    rising.then(|| format!("{path} {op} {value:x} ({path} = {current:x})"))
+                }
+            };
+            if stop.is_none()
+                && let Some(detail) = detail
+            {
+                stop = Some(Stop::Breakpoint { id: bp.id, detail });
+            }
+        }
+        stop
+    }
+
+    fn check_lines(&self, token: &TokenRange) -> Option<Stop> {
+        let line = token.beg.line;
+        let path = source_path(token);
+        self.breakpoints.iter().find_map(|bp| match &bp.kind {
+            BreakpointKind::Line { file, line: x } if *x == line => {
+                let matches = match (file, &path) {
+                    (None, _) => true,
+                    (Some(file), Some(path)) => path.ends_with(file),
+                    (Some(_), None) => false,
+                };
+                matches.then(|| Stop::Breakpoint {
+                    id: bp.id,
+                    detail: format!("line {line}"),
+                })
+            }
+            _ => None,
+        })
+    }
+}
+
+enum Mode {
+    Run,
+    Cycles(u64),
+    Statement,
+    Quit,
+}
+
+/// The debugger a simulator carries through a testbench run.
+pub struct Debugger {
+    state: DebugState,
+    mode: Mode,
+    frontend: Box<dyn Frontend>,
+}
+
+impl Debugger {
+    pub fn new(frontend: Box<dyn Frontend>) -> Self {
+        Self {
+            state: DebugState::default(),
+            mode: Mode::Run,
+            frontend,
+        }
+    }
+
+    /// Checks breakpoints at `site` and stops there if one fires.  Returns
+    /// `false` once the user quits.
+    pub(crate) fn reached(&mut self, sim: &mut Simulator, site: Site) -> bool {
+        if let Mode::Quit = self.mode {
+            return false;
+        }
+        let paused = match site {
+            Site::Line(_) | Site::Cycle => self.frontend.poll(&mut self.state, sim),
+            Site::Start | Site::End(_) => false,
+        };
+        let stop = match site {
+            Site::Start => Some(Stop::Start),
+            Site::Line(token) => {
+                self.state.location = Some(*token);
+                self.state
+                    .check_signals(sim)
+                    .or_else(|| self.state.check_lines(token))
+                    .or_else(|| matches!(self.mode, Mode::Statement).then_some(Stop::Step))
+                    .or_else(|| paused.then_some(Stop::Pause))
+            }
+            Site::Cycle => {
+                let stepped = match &mut self.mode {
+                    Mode::Cycles(n) => {
+                        *n = n.saturating_sub(1);
+                        *n == 0
+                    }
+                    _ => false,
+                };
+                self.state
+                    .check_signals(sim)
+                    .or_else(|| stepped.then_some(Stop::Step))
+                    .or_else(|| paused.then_some(Stop::Pause))
+            }
+            Site::End(failure) => Some(Stop::End {
+                failure: failure.map(str::to_string),
+            }),
+        };
+        let Some(stop) = stop else {
+            return true;
+        };
+        self.mode = match self.frontend.stopped(&mut self.state, sim, &stop) {
+            Resume::Continue | Resume::Cycles(0) => Mode::Run,
+            Resume::Cycles(n) => Mode::Cycles(n),
+            Resume::Statement => Mode::Statement,
+            Resume::Quit => Mode::Quit,
+        };
+        !matches!(self.mode, Mode::Quit)
+    }
+}
+
+/// The variable at `path`, as `print` and `set` find it.
+pub fn resolve(sim: &Simulator, path: &str) -> Result<SignalRef, String> {
+    sim.resolve_var(path)
+        .ok_or_else(|| format!("no variable `{path}`"))
+}
+
+fn compare(current: &Value, op: CompareOp, value: &Value) -> bool {
+    !current.is_xz() && op.holds(current.payload_u64(), value.payload_u64())
+}
+
+/// The file `token` was read from.
+pub fn source_path(token: &TokenRange) -> Option<PathBuf> {
+    match token.beg.source {
+        TokenSource::File { path, .. } => resource_table::get_path_value(path),
+        _ => None,
+    }
+}
+
+const HELP: &str = "\
+commands:
+  continue, c               run until a breakpoint or the end of the test
+  step, s [N]               run N clock cycles (default 1)
+  next, n                   run to the next testbench statement
+  break, b [FILE:]LINE      stop before the testbench statement on LINE
+  break, b VAR OP VALUE     stop when VAR OP VALUE becomes true (OP: == != < <= > >=)
+  watch, w VAR              stop when VAR changes
+  delete, d ID              remove a breakpoint
+  breakpoints               list breakpoints
+  print, p VAR              show a variable
+  set VAR VALUE             write a variable until the design next assigns it
+  scope [INST]              list the instances and variables of INST (default top)
+  where                     show the current statement, time and cycle
+  quit, q                   stop the test";
+
+/// The line-oriented frontend of `veryl test --debug`.
+pub struct Repl<R, W> {
+    input: R,
+    output: W,
+    /// Lines of the source files shown so far.
+    sources: HashMap<PathBuf, Vec<String>>,
+}
+
+impl<R: BufRead, W: Write> Repl<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            input,
+            output,
+            sources: HashMap::default(),
+        }
+    }
+
+    fn where_(&mut self, state: &DebugState, sim: &Simulator) {
+        let _ = writeln!(self.output, "time {}, cycle {}", sim.time, sim.cycle_count);
+        let Some(token) = state.location() else {
+            return;
+        };
+        let line = token.beg.line;
+        let Some(path) = source_path(token).filter(|x| !x.as_os_str().is_empty()) else {
+            let _ = writeln!(self.output, "at line {line}");
+            return;
+        };
+        let lines = self.sources.entry(path.clone()).or_insert_with(|| {
+            std::fs::read_to_string(&path)
+                .map(|x| x.lines().map(str::to_string).collect())
+                .unwrap_or_default()
+        });
+        match lines.get(line as usize - 1) {
+            Some(text) => {
+                let _ = writeln!(self.output, "{}:{line}: {}", path.display(), text.trim());
+            }
+            None => {
+                let _ = writeln!(self.output, "{}:{line}", path.display());
+            }
+        }
+    }
+
+    fn scope(&mut self, sim: &mut Simulator, inst: Option<&str>) -> Result<(), String> {
+        let module = match inst {
+            Some(inst) => find_scope(&sim.ir.module_variables, inst)?,
+            None => &sim.ir.module_variables,
+        };
+        let mut children: Vec<_> = module.children.iter().map(|x| x.name.to_string()).collect();
+        children.sort();
+        let variables = variables(module);
+        for child in children {
+            let _ = writeln!(self.output, "  inst {child}");
+        }
+        for (path, signal) in variables {
+            let value = sim.read_signal(&signal);
+            let _ = writeln!(self.output, "  {path} = {value:x}");
+        }
+        Ok(())
+    }
+
+    /// Runs one command; `Some` resumes the run.
+    fn command(
+        &mut self,
+        state: &mut DebugState,
+        sim: &mut Simulator,
+        line: &str,
+    ) -> Result<Option<Resume>, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&cmd, args)) = words.split_first() else {
+            return Ok(None);
+        };
+        match (cmd, args) {
+            ("continue" | "c", []) => return Ok(Some(Resume::Continue)),
+            ("step" | "s", []) => return Ok(Some(Resume::Cycles(1))),
+            ("step" | "s", [n]) => {
+                let n = n.parse().map_err(|_| format!("invalid count `{n}`"))?;
+                return Ok(Some(Resume::Cycles(n)));
+            }
+            ("next" | "n", []) => return Ok(Some(Resume::Statement)),
+            ("quit" | "q", []) => return Ok(Some(Resume::Quit)),
+            ("break" | "b", [location]) => {
+                let (file, line) = match location.rsplit_once(':') {
+                    Some((file, line)) => (Some(file.to_string()), line),
+                    None => (None, *location),
+                };
+                let line = line
+                    .parse()
+                    .map_err(|_| format!("invalid line `{location}`"))?;
+                let id = state.add_line(file, line);
+                let _ = writeln!(self.output, "breakpoint {id}");
+            }
+            ("break" | "b", [path, op, value]) => {
+                let op = op.parse().map_err(|_| format!("invalid operator `{op}`"))?;
+                let value = parse_value(value)?;
+                let id = state.add_condition(sim, path, op, value)?;
+                let _ = writeln!(self.output, "breakpoint {id}");
+            }
+            ("watch" | "w", [path]) => {
+                let id = state.add_watch(sim, path)?;
+                let _ = writeln!(self.output, "breakpoint {id}");
+            }
+            ("delete" | "d", [id]) => {
+                let id = id
+                    .parse()
+                    .map_err(|_| format!("invalid breakpoint `{id}`"))?;
+                if !state.remove(id) {
+                    return Err(format!("no breakpoint {id}"));
+                }
+            }
+            ("breakpoints", []) => {
+                for bp in state.breakpoints() {
+                    let _ = writeln!(self.output, "  {bp}");
+                }
+            }
+            ("print" | "p", [path]) => {
+                let signal = resolve(sim, path)?;
+                let value = sim.read_signal(&signal);
+                let _ = writeln!(self.output, "{path} = {value:x}");
+            }
+            ("set", [path, value]) => {
+                let signal = resolve(sim, path)?;
+                let value = parse_value(value)?;
+                sim.write_signal(&signal, value);
+            }
+            ("scope", []) => self.scope(sim, None)?,
+            ("scope", [inst]) => self.scope(sim, Some(inst))?,
+            ("where", []) => self.where_(state, sim),
+            ("help" | "h", []) => {
+                let _ = writeln!(self.output, "{HELP}");
+            }
+            _ => return Err(format!("unknown command `{line}` (try `help`)")),
+        }
+        Ok(None)
+    }
+}
+
+impl<R: BufRead, W: Write> Frontend for Repl<R, W> {
+    fn stopped(&mut self, state: &mut DebugState, sim: &mut Simulator, stop: &Stop) -> Resume {
+        let _ = writeln!(self.output, "{stop}");
+        if !matches!(stop, Stop::Start) {
+            self.where_(state, sim);
+        }
+        loop {
+            let _ = write!(self.output, "(veryl) ");
+            let _ = self.output.flush();
+            let mut line = String::new();
+            match self.input.read_line(&mut line) {
+                Ok(0) | Err(_) => return Resume::Quit,
+                Ok(_) => {}
+            }
+            match self.command(state, sim, line.trim()) {
+                Ok(Some(resume)) => return resume,
+                Ok(None) => {}
+                Err(msg) => {
+                    let _ = writeln!(self.output, "error: {msg}");
+                }
+            }
+        }
+    }
+}
+
+/// Parses a Veryl literal (`42`, `'hff`, `8'b1010`) or `0x`/`0b`-prefixed
+/// integer.
+pub fn parse_value(text: &str) -> Result<Value, String> {
+    let invalid = || format!("invalid value `{text}`");
+    let digits = text.replace('_', "");
+    if let Some(hex) = digits.strip_prefix("0x") {
+        let x = u64::from_str_radix(hex, 16).map_err(|_| invalid())?;
+        return Ok(Value::new(x, 64, false));
+    }
+    if let Some(bin) = digits.strip_prefix("0b") {
+        let x = u64::from_str_radix(bin, 2).map_err(|_| invalid())?;
+        return Ok(Value::new(x, 64, false));
+    }
+    let valid = digits
+        .chars()
+        .next()
+        .is_some_and(|x| x.is_ascii_digit() || x == '\'');
+    if !valid {
+        return Err(invalid());
+    }
+    let value = Value::from_str(&digits).map_err(|_| invalid())?;
+    // A base-less literal is a signed 32-bit one in Veryl; show it unsigned.
+    if digits.contains('\'') {
+        Ok(value)
+    } else {
+        Ok(Value::new(value.payload_u64(), value.width(), false))
+    }
+}
+
+/// The instance at the dotted path `inst` below `module`.
+pub fn find_scope<'a>(
+    module: &'a ModuleVariables,
+    inst: &str,
+) -> Result<&'a ModuleVariables, String> {
+    let mut scope = module;
+    for name in inst.split('.') {
+        scope = scope
+            .children
+            .iter()
+            .find(|x| x.name.to_string() == name)
+            .ok_or_else(|| format!("no instance `{inst}`"))?;
+    }
+    Ok(scope)
+}
+
+/// The variables directly in `module`, sorted by path.
+pub fn variables(module: &ModuleVariables) -> Vec<(VarPath, SignalRef)> {
+    let mut ret: Vec<_> = module
+        .variables
+        .values()
+        .map(|x| (x.path.clone(), SignalRef::new(x)))
+        .collect();
+    ret.sort_by_key(|(path, _)| path.to_string());
+    ret
+}
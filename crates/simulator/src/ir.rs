@@ -8,6 +8,7 @@ pub mod derived_clock;
 mod event;
 mod expression;
 pub(crate) mod external;
+pub(crate) mod force;
 pub(crate) mod hier_ref;
 pub(crate) mod inst_layout;
 pub(crate) mod module;
@@ -30,7 +31,7 @@ pub use external::{
 pub use module::{Module, ProtoModule};
 pub use opt::comb_fusion::force_disable as force_disable_comb_fusion;
 pub use statement::{
-    CompiledBatchStmt, CompiledBlockStatement, CompiledStmt, ComponentArg,
+    CompiledBatchStmt, CompiledBlockStatement, CompiledStmt, ComponentArg, ForceTarget,
     ProtoAssignDynamicStatement, ProtoAssignStatement, ProtoCaseStatement, ProtoComponentArg,
     ProtoForBound, ProtoForRange, ProtoForStatement, ProtoIfStatement, ProtoStatement,
     ProtoStatementBlock, ProtoStatements, ProtoSystemFunctionCall, RetWidthCheck, RuntimeForBound,
@@ -118,6 +119,8 @@ pub struct Ir {
     /// Snapshotted from `Config::aot_c_validate_stride`: dual-run only every
     /// Nth settle (0/1 = every cycle).
     pub aot_c_validate_stride: u64,
+    /// Snapshotted from `Config::coverage`: the simulator samples toggles.
+    pub coverage: bool,
     /// Per-event whole-event dispatch handles.  When the current
     /// event's `try_dispatch` succeeds, `step()` invokes it instead of
     /// the per-stmt Cranelift dispatch.  Built in `ProtoModule::conv`
@@ -196,6 +199,7 @@ impl Ir {
             whole_comb: module.whole_comb,
             aot_c_validate: config.aot_c_validate,
             aot_c_validate_stride: config.aot_c_validate_stride,
+            coverage: config.coverage,
             whole_events: module.whole_events,
             external_components: module.external_components,
             seed: config.seed,
@@ -401,6 +405,7 @@ impl Ir {
                     format!("Seq{{ {} }}", kids.join("; "))
                 }
                 Statement::TbMethodCall { .. } => "TbMethodCall".to_string(),
+                Statement::Fork(threads) => format!("Fork(threads={})", threads.len()),
                 Statement::Break => "Break".to_string(),
                 Statement::Compiled(_) | Statement::CompiledBatch(_) => "Compiled".to_string(),
             }
@@ -850,6 +855,7 @@ pub fn build_ir(ir: &air::Ir, top: StrId, config: &Config) -> Result<Ir, Simulat
             && top == x.name
         {
             let token = x.token;
+            let config = &*force::config_for(x, config);
             let mut context = context::Context {
                 config: config.clone(),
                 backends: BackendRegistry::for_config(config),
@@ -897,6 +903,7 @@ pub fn build_ir_cached(
             && top == x.name
         {
             let token = x.token;
+            let config = &*force::config_for(x, config);
             let mut context = context::Context {
                 config: config.clone(),
                 backends: BackendRegistry::for_config(config),
@@ -970,6 +977,18 @@ pub struct Config {
     pub component_libraries: std::collections::HashMap<String, ComponentLibrary>,
     /// See `Ir::component_file_base`.
     pub component_file_base: Option<PathBuf>,
+    /// Instrument statement lists with `crate::coverage` probes and sample
+    /// toggles.  Default false; `veryl test --coverage` enables it.
+    pub coverage: bool,
+    /// Mark every testbench statement with a `Line` stop site for
+    /// `crate::debugger`.  Default false; `veryl test --debug` enables it.
+    pub debug: bool,
+    /// Give every comb variable the storage and pin statements
+    /// `Simulator::force_signal` needs to hold it against its own drivers
+    /// (see `crate::ir::force`).  FF variables can be forced either
+    /// way.  Default false; `build_ir` turns it on for a testbench
+    /// that calls `$force`, and cosim callers opt in.
+    pub force: bool,
 }
 
 impl Config {
@@ -6,6 +6,7 @@ use crate::ir::ProtoStatement;
 use crate::ir::VarId;
 use crate::ir::VariableMeta;
 use crate::ir::event::Event;
+use crate::ir::force::ForceIndex;
 use crate::ir::statement::StmtDep;
 use crate::ir::variable::VarOffset;
 use crate::simulator_error::SimulatorError;
@@ -27,6 +28,9 @@ pub struct ScopeContext {
     /// inlined-function storage per call-site (see the `FunctionCall`
     /// factor in `expression.rs`).  `None` until first queried.
     pub func_offset_index: Option<HashMap<isize, VarId>>,
+    /// Lazily-built force pairs of this scope's comb elements (see
+    /// `crate::ir::force`).  `None` until first queried.
+    pub force_index: Option<ForceIndex>,
 }
 
 impl ScopeContext {
@@ -52,6 +56,18 @@ impl ScopeContext {
         }
         self.func_offset_index.as_ref().unwrap().get(&off).copied()
     }
+
+    /// Force pairs of this scope's comb elements, for the pins
+    /// `Config::force` adds after their assignments.  Builds the index on
+    /// first call.
+    pub fn force_index(&mut self, use_4state: bool) -> &mut ForceIndex {
+        if self.force_index.is_none() {
+            let mut idx = ForceIndex::default();
+            crate::ir::force::collect(self.variable_meta.values(), use_4state, &mut idx);
+            self.force_index = Some(idx);
+        }
+        self.force_index.as_mut().unwrap()
+    }
 }
 
 /// Cached compiled chunk + variable offsets it reads/writes (for
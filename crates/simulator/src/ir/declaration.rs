@@ -1,6 +1,7 @@
 use crate::backend::inst::{
     ReuseOutcome, port_alias_enabled, try_compile_inst_chunks, try_reuse_or_claim,
 };
+use crate::coverage::Slot;
 use crate::ir::context::{Context, Conv, ScopeContext};
 use crate::ir::expression::{ExpressionContext, build_dynamic_bit_select};
 use crate::ir::external::{ProtoExternalComponent, ProtoExternalConnect};
@@ -10,7 +11,8 @@ use crate::ir::opt::multi_write_analysis::collect_dyn_indexed_vars;
 use crate::ir::opt::version_split;
 use crate::ir::partial_index::partial_index_base;
 use crate::ir::statement::{
-    ProtoAssignStatement, const_array_element_exprs, msb_first_window, size_literal_rhs,
+    ProtoAssignStatement, const_array_element_exprs, coverage_probe, msb_first_window,
+    size_literal_rhs,
 };
 use crate::ir::variable::{
     ModuleVariableMeta, VarOffset, align_up_64, create_variable_meta, ff_cacheline_pad_enabled,
@@ -738,11 +740,19 @@ impl Conv<&air::Declaration> for ProtoDeclaration {
     fn conv(context: &mut Context, src: &air::Declaration) -> Result<Self, SimulatorError> {
         match src {
             air::Declaration::Comb(x) => {
-                let mut comb_statements = vec![];
+                let mut comb_statements: Vec<ProtoStatement> =
+                    coverage_probe(context, None, Slot::Body, &x.statements)
+                        .into_iter()
+                        .collect();
                 for stmt in &x.statements {
                     let stmts: Vec<ProtoStatement> = Conv::conv(context, stmt)?;
                     comb_statements.extend(stmts);
                 }
+                if context.config.force {
+                    let use_4state = context.config.use_4state;
+                    let index = context.scope().force_index(use_4state);
+                    comb_statements = crate::ir::force::pin_statements(comb_statements, index);
+                }
                 #[allow(unused_mut)]
                 let mut comb_statements = if comb_statements.len() > 1 {
                     vec![ProtoStatement::SequentialBlock(comb_statements)]
@@ -755,8 +765,11 @@ impl Conv<&air::Declaration> for ProtoDeclaration {
                 // blocks end up nested inside CompiledBlocks).
                 // Skipped when a statement in the block reads a whole memory:
                 // the pass's select chains over such reads buy nothing.
+                // Skipped under `Config::force`: its renames would move the
+                // writes the pins follow into temporaries.
                 #[cfg(not(target_family = "wasm"))]
                 if version_split::pass_enabled(context.config.use_4state)
+                    && !context.config.force
                     && !crate::ir::deps::stmts_infeasible(&comb_statements)
                 {
                     // Fresh comb offsets for rename temps come from the same
@@ -801,6 +814,10 @@ impl Conv<&air::Declaration> for ProtoDeclaration {
                     statements.extend(stmts);
                 }
 
+                // Goes in front of the clock branch, after the `if_reset`
+                // split below has taken the body apart.
+                let probe = coverage_probe(context, None, Slot::Body, &x.statements);
+
                 let clock_event = Event::Clock(x.clock.id);
                 let mut event_statements: HashMap<Event, Vec<ProtoStatement>> = HashMap::default();
 
@@ -839,8 +856,10 @@ impl Conv<&air::Declaration> for ProtoDeclaration {
                         true_side,
                         false_side,
                     }));
+                    clock_stmts.splice(0..0, probe);
                     event_statements.insert(clock_event, clock_stmts);
                 } else {
+                    statements.splice(0..0, probe);
                     event_statements.insert(clock_event, statements);
                 }
 
@@ -856,7 +875,10 @@ impl Conv<&air::Declaration> for ProtoDeclaration {
             air::Declaration::Inst(x) => Conv::conv(context, x.as_ref()),
             air::Declaration::Initial(x) => {
                 context.in_initial = true;
-                let mut initial_statements = vec![];
+                let mut initial_statements: Vec<ProtoStatement> =
+                    coverage_probe(context, None, Slot::Body, &x.statements)
+                        .into_iter()
+                        .collect();
                 let mut conv_err = None;
                 for stmt in &x.statements {
                     match Conv::conv(context, stmt) {
@@ -886,7 +908,10 @@ impl Conv<&air::Declaration> for ProtoDeclaration {
                 })
             }
             air::Declaration::Final(x) => {
-                let mut final_statements = vec![];
+                let mut final_statements: Vec<ProtoStatement> =
+                    coverage_probe(context, None, Slot::Body, &x.statements)
+                        .into_iter()
+                        .collect();
                 for stmt in &x.statements {
                     let stmts: Vec<ProtoStatement> = Conv::conv(context, stmt)?;
                     final_statements.extend(stmts);
@@ -1040,6 +1065,7 @@ impl Conv<&air::InstDeclaration> for ProtoDeclaration {
             &multi_rmw_set,
             &dyn_indexed,
             context.config.use_4state,
+            context.config.force,
             ff_start,
             comb_start,
         )?;
@@ -1101,6 +1127,7 @@ impl Conv<&air::InstDeclaration> for ProtoDeclaration {
                 for (i, parent_elem) in parent_meta.elements.iter().enumerate() {
                     entry.elements[i].current = parent_elem.current;
                     entry.elements[i].next_offset = parent_elem.next_offset;
+                    entry.elements[i].force = parent_elem.force;
                 }
                 // Drop initial_values so fill_buffers_recursive doesn't
                 // overwrite parent storage with the child port's default.
@@ -1164,6 +1191,7 @@ impl Conv<&air::InstDeclaration> for ProtoDeclaration {
                 for (i, parent_elem) in parent_meta.elements.iter().enumerate() {
                     entry.elements[i].current = parent_elem.current;
                     entry.elements[i].next_offset = parent_elem.next_offset;
+                    entry.elements[i].force = parent_elem.force;
                 }
                 entry.initial_values.clear();
                 aliased_output_ids.insert(output.id);
@@ -1249,6 +1277,7 @@ impl Conv<&air::InstDeclaration> for ProtoDeclaration {
                     &child_module.declarations,
                 ),
                 func_offset_index: None,
+                force_index: None,
             };
             context.scope_contexts.push(child_scope);
 
@@ -1321,6 +1350,8 @@ impl Conv<&air::InstDeclaration> for ProtoDeclaration {
             }
         }
 
+        let port_statements_start = all_comb_statements.len();
+
         // Input ports: parent expr → child port var
         for input in &src.inputs {
             if aliased_input_ids.contains(&input.id) {
@@ -1642,6 +1673,21 @@ impl Conv<&air::InstDeclaration> for ProtoDeclaration {
             }
         }
 
+        // Port connections drive the child's inputs and the parent's output
+        // destinations: pin both sides.  The child's offsets are absolute, so
+        // its pairs join the parent's index without clashing.
+        if context.config.force {
+            let use_4state = context.config.use_4state;
+            let index = context.scope().force_index(use_4state);
+            crate::ir::force::collect(child_variable_meta.values(), use_4state, index);
+            let ports = all_comb_statements.split_off(port_statements_start);
+            all_comb_statements.extend(
+                ports
+                    .into_iter()
+                    .map(|x| crate::ir::force::pin_statement(x, index)),
+            );
+        }
+
         // Remap child event keys (clock/reset) to parent VarIds via input port connections
         let mut child_to_parent_var: HashMap<air::VarId, air::VarId> = HashMap::default();
         for input in &src.inputs {
@@ -1722,6 +1768,7 @@ impl Conv<&air::InstDeclaration> for ProtoDeclaration {
 
         let child_module_meta = ModuleVariableMeta {
             name: src.name,
+            module: child_module.name,
             hierarchy: src.hierarchy.clone(),
             variable_meta: child_variable_meta,
             children: all_child_modules,
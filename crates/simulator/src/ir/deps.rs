@@ -152,6 +152,11 @@ pub fn collect_stmt_deps(stmt: &ProtoStatement, deps: &mut StmtDeps) {
                 collect_stmt_deps(s, deps);
             }
         }
+        ProtoStatement::Fork(threads) => {
+            for s in threads.iter().flatten() {
+                collect_stmt_deps(s, deps);
+            }
+        }
         ProtoStatement::TbMethodCall { .. } => {}
     }
 }
@@ -0,0 +1,192 @@
+//! Force pins for comb variables (`Config::force`).
+//!
+//! A comb variable is recomputed by every settle, so a value the simulator
+//! writes into its storage lasts only until its driver runs again.  Under
+//! `Config::force` each comb element owns a mask/value pair in the FF buffer
+//! (`VariableElement::force`), and every assignment to it is followed by
+//!
+//! ```text
+//! x = (x & ~mask) | (value & mask)
+//! ```
+//!
+//! so readers downstream see the forced bits whichever backend runs the
+//! statements.  Nothing else writes the pair, and the comb relayout never
+//! moves it; an unforced element has an all-zero mask and pins to itself.
+
+use crate::HashMap;
+use crate::ir::Config;
+use crate::ir::ProtoStatement;
+use crate::ir::expression::{ExpressionContext, ProtoExpression};
+use crate::ir::statement::ProtoAssignStatement;
+use crate::ir::variable::{VarOffset, VariableMeta, value_size};
+use std::borrow::Cow;
+use veryl_analyzer::ir as air;
+use veryl_analyzer::ir::{Op, SystemFunctionKind};
+use veryl_parser::token_range::TokenRange;
+
+/// Comb byte offset -> `(force mask offset, forced value offset, width)`.
+pub type ForceIndex = HashMap<isize, (isize, isize, usize)>;
+
+/// `config` for simulating `top`, with `Config::force` on when its
+/// testbench calls `$force`.  A forcing DUT is converted with pins, so it
+/// also stays out of the cross-test reuse cache.
+pub fn config_for<'a>(top: &air::Module, config: &'a Config) -> Cow<'a, Config> {
+    if config.force || !calls_force(top) {
+        return Cow::Borrowed(config);
+    }
+    let mut config = config.clone();
+    config.force = true;
+    config.dut_reuse = false;
+    Cow::Owned(config)
+}
+
+fn calls_force(module: &air::Module) -> bool {
+    module.declarations.iter().any(|x| match x {
+        air::Declaration::Initial(x) => stmts_call_force(&x.statements),
+        air::Declaration::Final(x) => stmts_call_force(&x.statements),
+        _ => false,
+    })
+}
+
+fn stmts_call_force(stmts: &[air::Statement]) -> bool {
+    stmts.iter().any(|x| match x {
+        air::Statement::SystemFunctionCall(x) => {
+            matches!(x.kind, SystemFunctionKind::Force { .. })
+        }
+        air::Statement::If(x) => stmts_call_force(&x.true_side) || stmts_call_force(&x.false_side),
+        air::Statement::Case(x) => {
+            x.arms.iter().any(|x| stmts_call_force(&x.body)) || stmts_call_force(&x.default)
+        }
+        air::Statement::For(x) => stmts_call_force(&x.body),
+        air::Statement::Fork(x) => x.threads.iter().any(|x| stmts_call_force(x)),
+        _ => false,
+    })
+}
+
+/// Adds the comb elements of `metas` that own a force pair to `index`.
+pub fn collect<'a>(
+    metas: impl IntoIterator<Item = &'a VariableMeta>,
+    use_4state: bool,
+    index: &mut ForceIndex,
+) {
+    for meta in metas {
+        for e in &meta.elements {
+            if let (VarOffset::Comb(off), Some(mask)) = (e.current, e.force) {
+                let value = mask + value_size(e.native_bytes, use_4state) as isize;
+                index.insert(off, (mask, value, meta.width));
+            }
+        }
+    }
+}
+
+/// `stmt` with a pin after each assignment to a forceable element; an
+/// assignment at the top becomes a `SequentialBlock` with its pin.
+pub fn pin_statement(stmt: ProtoStatement, index: &ForceIndex) -> ProtoStatement {
+    let mut out = pin_statements(vec![stmt], index);
+    if out.len() == 1 {
+        out.pop().unwrap()
+    } else {
+        ProtoStatement::SequentialBlock(out)
+    }
+}
+
+/// `stmts` with a pin after each assignment to a forceable element.
+pub fn pin_statements(stmts: Vec<ProtoStatement>, index: &ForceIndex) -> Vec<ProtoStatement> {
+    if index.is_empty() {
+        return stmts;
+    }
+    let mut out = Vec::with_capacity(stmts.len());
+    for stmt in stmts {
+        pin_into(stmt, index, &mut out);
+    }
+    out
+}
+
+fn pin_into(stmt: ProtoStatement, index: &ForceIndex, out: &mut Vec<ProtoStatement>) {
+    match stmt {
+        ProtoStatement::Assign(x) => {
+            let pin = match x.dst {
+                VarOffset::Comb(off) => pin(off, index),
+                VarOffset::Ff(_) => None,
+            };
+            out.push(ProtoStatement::Assign(x));
+            out.extend(pin);
+        }
+        ProtoStatement::AssignDynamic(x) => {
+            // The index is only known at run time: pin every element.
+            let pins: Vec<_> = match x.dst_base {
+                VarOffset::Comb(base) => (0..x.dst_num_elements)
+                    .filter_map(|i| pin(base + i as isize * x.dst_stride, index))
+                    .collect(),
+                VarOffset::Ff(_) => vec![],
+            };
+            out.push(ProtoStatement::AssignDynamic(x));
+            out.extend(pins);
+        }
+        ProtoStatement::If(mut x) => {
+            x.true_side = pin_statements(x.true_side, index);
+            x.false_side = pin_statements(x.false_side, index);
+            out.push(ProtoStatement::If(x));
+        }
+        ProtoStatement::Case(mut x) => {
+            for arm in &mut x.arms {
+                arm.body = pin_statements(std::mem::take(&mut arm.body), index);
+            }
+            x.default = pin_statements(x.default, index);
+            out.push(ProtoStatement::Case(x));
+        }
+        ProtoStatement::For(mut x) => {
+            x.body = pin_statements(x.body, index);
+            out.push(ProtoStatement::For(x));
+        }
+        ProtoStatement::SequentialBlock(x) => {
+            out.push(ProtoStatement::SequentialBlock(pin_statements(x, index)));
+        }
+        x => out.push(x),
+    }
+}
+
+fn pin(off: isize, index: &ForceIndex) -> Option<ProtoStatement> {
+    let (mask, value, width) = *index.get(&off)?;
+    let expr_context = ExpressionContext {
+        width,
+        signed: false,
+    };
+    let var = |var_offset| ProtoExpression::Variable {
+        var_offset,
+        select: None,
+        dynamic_select: None,
+        width,
+        var_full_width: width,
+        expr_context,
+    };
+    let binary = |x, op, y| ProtoExpression::Binary {
+        x: Box::new(x),
+        op,
+        y: Box::new(y),
+        width,
+        expr_context,
+    };
+    let not_mask = ProtoExpression::Unary {
+        op: Op::BitNot,
+        x: Box::new(var(VarOffset::Ff(mask))),
+        width,
+        expr_context,
+    };
+    let kept = binary(var(VarOffset::Comb(off)), Op::BitAnd, not_mask);
+    let forced = binary(
+        var(VarOffset::Ff(value)),
+        Op::BitAnd,
+        var(VarOffset::Ff(mask)),
+    );
+    Some(ProtoStatement::Assign(ProtoAssignStatement {
+        dst: VarOffset::Comb(off),
+        dst_width: width,
+        select: None,
+        dynamic_select: None,
+        rhs_select: None,
+        expr: binary(kept, Op::BitOr, forced),
+        dst_ff_current_offset: 0, // not FF
+        token: TokenRange::default(),
+    }))
+}
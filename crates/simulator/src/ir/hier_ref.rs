@@ -9,7 +9,9 @@ use crate::ir::ProtoStatement;
 use crate::ir::context::Context;
 use crate::ir::event::Event;
 use crate::ir::expression::ProtoExpression;
-use crate::ir::statement::{ProtoForBound, ProtoForRange, ProtoSystemFunctionCall};
+use crate::ir::statement::{
+    ProtoForBound, ProtoForRange, ProtoSystemFunctionCall, is_force_target,
+};
 use crate::ir::variable::{ModuleVariableMeta, VarOffset, VariableMeta};
 use crate::simulator_error::SimulatorError;
 use veryl_analyzer::ir as air;
@@ -33,6 +35,25 @@ pub fn resolve_hier_refs(
     Ok(())
 }
 
+/// A `$force` / `$release` target must stay one variable's storage once
+/// resolved; a dynamic index into a child's array does not.
+fn resolve_force_target(
+    target: &mut ProtoExpression,
+    context: &mut Context,
+    children: &[ModuleVariableMeta],
+) -> Result<(), SimulatorError> {
+    let token = match target {
+        ProtoExpression::HierVariable(x) => x.token,
+        _ => return Ok(()),
+    };
+    resolve_expr(target, context, children)?;
+    if is_force_target(target) {
+        Ok(())
+    } else {
+        Err(SimulatorError::unsupported_description(&token))
+    }
+}
+
 fn find_target<'a>(
     children: &'a [ModuleVariableMeta],
     inst_path: &[StrId],
@@ -131,7 +152,28 @@ fn resolve_stmt(
                     resolve_expr(arg, context, children)?;
                 }
             }
-            ProtoSystemFunctionCall::Readmemh { .. } | ProtoSystemFunctionCall::Finish => {}
+            ProtoSystemFunctionCall::Cover { value, .. } => {
+                resolve_expr(value, context, children)?;
+            }
+            ProtoSystemFunctionCall::Wait { duration, .. } => {
+                resolve_expr(duration, context, children)?;
+            }
+            ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                resolve_expr(condition, context, children)?;
+            }
+            ProtoSystemFunctionCall::Force { target, value } => {
+                resolve_force_target(target, context, children)?;
+                resolve_expr(value, context, children)?;
+            }
+            ProtoSystemFunctionCall::Release { target } => {
+                resolve_force_target(target, context, children)?;
+            }
+            ProtoSystemFunctionCall::Readmemh { .. }
+            | ProtoSystemFunctionCall::Finish
+            | ProtoSystemFunctionCall::DumpOn
+            | ProtoSystemFunctionCall::DumpOff
+            | ProtoSystemFunctionCall::Coverage { .. }
+            | ProtoSystemFunctionCall::Line { .. } => {}
         },
         ProtoStatement::TbMethodCall { method, .. } => match method {
             crate::ir::statement::ProtoTbMethodKind::ClockNext { count, period } => {
@@ -174,6 +216,13 @@ fn resolve_stmt(
                 resolve_stmt(s, context, children)?;
             }
         }
+        ProtoStatement::Fork(threads) => {
+            for thread in threads {
+                for s in thread {
+                    resolve_stmt(s, context, children)?;
+                }
+            }
+        }
         // Compiled blocks come from child-module internals, which cannot
         // contain hierarchical references.
         ProtoStatement::CompiledBlock(_) | ProtoStatement::Break => {}
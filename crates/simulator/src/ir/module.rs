@@ -234,6 +234,8 @@ fn create_variables_recursive(
     for (id, meta) in &module_meta.variable_meta {
         let mut current_values: Vec<*mut u8> = vec![];
         let mut next_values: Vec<*mut u8> = vec![];
+        let mut force_values: Vec<*mut u8> = vec![];
+        let forceable = meta.elements.iter().any(|x| x.force.is_some());
 
         for element in &meta.elements {
             let current = unsafe {
@@ -246,6 +248,12 @@ fn create_variables_recursive(
                 let next = unsafe { ff_base.add(element.next_offset as usize) };
                 next_values.push(next);
             }
+            if forceable {
+                force_values.push(match element.force {
+                    Some(x) => unsafe { ff_base.add(x as usize) },
+                    None => std::ptr::null_mut(),
+                });
+            }
         }
 
         variables.insert(
@@ -257,6 +265,7 @@ fn create_variables_recursive(
                 native_bytes: meta.native_bytes,
                 current_values,
                 next_values,
+                force_values,
             },
         );
     }
@@ -269,6 +278,7 @@ fn create_variables_recursive(
 
     ModuleVariables {
         name: module_meta.name,
+        module: module_meta.module,
         variables,
         children,
     }
@@ -350,6 +360,7 @@ impl ProtoModule {
                     ProtoStatement::SequentialBlock(_) => "SeqBlock".to_string(),
                     ProtoStatement::SystemFunctionCall(_) => "SysFn".to_string(),
                     ProtoStatement::TbMethodCall { .. } => "TbMethod".to_string(),
+                    ProtoStatement::Fork(_) => "Fork".to_string(),
                     ProtoStatement::Break => "Break".to_string(),
                     ProtoStatement::CompiledBlock(_) => "NestedCB".to_string(),
                 };
@@ -908,6 +919,7 @@ pub(crate) fn dump_stmt_order(tag: &str, module_name: StrId, stmts: &[ProtoState
             ProtoStatement::CompiledBlock(_) => "CB",
             ProtoStatement::SequentialBlock(_) => "SeqBlock",
             ProtoStatement::TbMethodCall { .. } => "TbMethod",
+            ProtoStatement::Fork(_) => "Fork",
         };
         eprintln!("[stmtord] {module_name} {tag} {path} {kind} tok={tok} out={outs:?} in={ins:?}");
         match s {
@@ -1016,6 +1028,12 @@ fn collect_event_written_comb(
                 }
                 f.body.iter().all(|s| walk(s, out))
             }
+            ProtoStatement::Fork(threads) => threads.iter().flatten().all(|s| walk(s, out)),
+            // A force writes whatever its target resolved to.
+            ProtoStatement::SystemFunctionCall(
+                crate::ir::ProtoSystemFunctionCall::Force { .. }
+                | crate::ir::ProtoSystemFunctionCall::Release { .. },
+            ) => false,
             ProtoStatement::SystemFunctionCall(c) => {
                 // Readmemh writes are boundable: one offset per element, or
                 // just the ends once the image owns a folded span (see
@@ -1657,6 +1675,13 @@ pub(crate) fn analyze_dependency(
                         flatten(sub, out);
                     }
                 }
+                ProtoStatement::Fork(threads) => {
+                    for thread in threads {
+                        for sub in thread {
+                            flatten(sub, out);
+                        }
+                    }
+                }
                 other => out.push(other),
             }
         }
@@ -1966,6 +1991,13 @@ pub(crate) fn gather_bit_aware_outputs(
                 gather_bit_aware_outputs(s, out);
             }
         }
+        ProtoStatement::Fork(threads) => {
+            for thread in threads {
+                for s in thread {
+                    gather_bit_aware_outputs(s, out);
+                }
+            }
+        }
         ProtoStatement::CompiledBlock(x) => {
             if !x.original_stmts.is_empty() {
                 // Match gather_variable_offsets: FF offsets written inside a
@@ -2212,6 +2244,7 @@ fn compute_scc_stats(sorted: &[ProtoStatement]) -> (usize, usize, usize) {
                     ProtoStatement::CompiledBlock(_) => "CompiledBlock",
                     ProtoStatement::SequentialBlock(_) => "SequentialBlock",
                     ProtoStatement::TbMethodCall { .. } => "TbMethodCall",
+                    ProtoStatement::Fork(_) => "Fork",
                 };
                 *kind_hist.entry(kind).or_insert(0) += 1;
                 for &off in &stmt_outputs[idx] {
@@ -3326,6 +3359,7 @@ impl Conv<&air::Module> for ProtoModule {
             &multi_rmw_set,
             &dyn_indexed,
             context.config.use_4state,
+            context.config.force,
             ff_start,
             comb_start,
         )?;
@@ -3339,6 +3373,7 @@ impl Conv<&air::Module> for ProtoModule {
             ff_table: ff_table.clone(),
             inst_reset_kind: collect_inst_reset_kinds(declarations),
             func_offset_index: None,
+            force_index: None,
         };
         context.scope_contexts.push(scope);
 
@@ -4128,6 +4163,7 @@ impl Conv<&air::Module> for ProtoModule {
 
         let module_variable_meta = ModuleVariableMeta {
             name: src.name,
+            module: src.name,
             hierarchy: vec![],
             variable_meta,
             children: all_child_modules,
@@ -600,7 +600,8 @@ fn has_side_effects(s: &ProtoStatement) -> bool {
         ProtoStatement::SequentialBlock(b) => b.iter().any(has_side_effects),
         ProtoStatement::SystemFunctionCall(_)
         | ProtoStatement::CompiledBlock(_)
-        | ProtoStatement::TbMethodCall { .. } => true,
+        | ProtoStatement::TbMethodCall { .. }
+        | ProtoStatement::Fork(_) => true,
     }
 }
 
@@ -1351,6 +1352,7 @@ mod tests {
             native_bytes: nb,
             current: off,
             next_offset: 0,
+            force: None,
         };
         // FF elements gate through `FfOwner`, and a negative offset is not
         // storage at all; neither joins a comb compare set.
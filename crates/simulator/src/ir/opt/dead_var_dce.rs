@@ -244,6 +244,13 @@ fn walk_stmt_liveness(stmt: &ProtoStatement, c: &mut Census) {
                 walk_stmt_liveness(s, c);
             }
         }
+        ProtoStatement::Fork(threads) => {
+            for thread in threads {
+                for s in thread {
+                    walk_stmt_liveness(s, c);
+                }
+            }
+        }
         ProtoStatement::SystemFunctionCall(x) => match x {
             ProtoSystemFunctionCall::Display { args, .. }
             | ProtoSystemFunctionCall::Write { args, .. } => {
@@ -260,7 +267,29 @@ fn walk_stmt_liveness(stmt: &ProtoStatement, c: &mut Census) {
                     walk_expr_reads(a, c);
                 }
             }
-            ProtoSystemFunctionCall::Finish => {}
+            ProtoSystemFunctionCall::Cover { value, .. } => {
+                walk_expr_reads(value, c);
+            }
+            ProtoSystemFunctionCall::Wait { duration, .. } => {
+                walk_expr_reads(duration, c);
+            }
+            ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                walk_expr_reads(condition, c);
+            }
+            // The target stays live: its drivers still run under a partial
+            // force, and after the release.
+            ProtoSystemFunctionCall::Force { target, value } => {
+                walk_expr_reads(target, c);
+                walk_expr_reads(value, c);
+            }
+            ProtoSystemFunctionCall::Release { target } => {
+                walk_expr_reads(target, c);
+            }
+            ProtoSystemFunctionCall::Finish
+            | ProtoSystemFunctionCall::DumpOn
+            | ProtoSystemFunctionCall::DumpOff
+            | ProtoSystemFunctionCall::Coverage { .. }
+            | ProtoSystemFunctionCall::Line { .. } => {}
         },
         ProtoStatement::CompiledBlock(x) => {
             for s in &x.original_stmts {
@@ -436,7 +465,7 @@ pub fn collect_localize_info(
 }
 
 /// Filter out every `Assign` whose dst is in `dead`.  Recurses into
-/// `If` / `For` / `SequentialBlock`.  CompiledBlock is left intact —
+/// `If` / `For` / `SequentialBlock` / `Fork`.  CompiledBlock is left intact —
 /// its `func` was pre-compiled with the original offsets and can't
 /// be patched, so any `Assign` it owns stays put even when its dst
 /// looks dead at this layer.  Returns `(new_stmts, dropped_count)`
@@ -490,6 +519,15 @@ pub fn apply_counting(
                 for_stmt.body = body;
                 out.push(ProtoStatement::For(for_stmt));
             }
+            ProtoStatement::Fork(threads) => {
+                let mut kept = Vec::with_capacity(threads.len());
+                for thread in threads {
+                    let (thread, d) = apply_counting(thread, dead);
+                    dropped += d;
+                    kept.push(thread);
+                }
+                out.push(ProtoStatement::Fork(kept));
+            }
             other => out.push(other),
         }
     }
@@ -108,6 +108,13 @@ fn walk_stmt(s: &ProtoStatement, idx: usize, reads: &mut FutureReads) {
                 walk_stmt(sub, idx, reads);
             }
         }
+        ProtoStatement::Fork(threads) => {
+            for thread in threads {
+                for sub in thread {
+                    walk_stmt(sub, idx, reads);
+                }
+            }
+        }
         ProtoStatement::SystemFunctionCall(_)
         | ProtoStatement::CompiledBlock(_)
         | ProtoStatement::TbMethodCall { .. }
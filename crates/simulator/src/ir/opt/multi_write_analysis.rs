@@ -127,6 +127,13 @@ fn count_writes_one(
         Statement::For(f) => {
             return count_writes_seq(&f.body, ctx);
         }
+        Statement::Fork(f) => {
+            for thread in &f.threads {
+                for (k, n) in count_writes_seq(thread, ctx) {
+                    *result.entry(k).or_insert(0) += n;
+                }
+            }
+        }
         Statement::FunctionCall(call) => {
             for outputs in call.outputs.values() {
                 for dst in outputs {
@@ -97,7 +97,7 @@ impl SiteTable {
 
 impl SiteTable {
     /// Walk pre-JIT `ProtoStatement`s and append a `SiteInfo` for every FF
-    /// write site.  Recurses through If / For / SequentialBlock bodies to
+    /// write site.  Recurses through If / For / SequentialBlock / Fork bodies to
     /// surface conditional / loop-internal sites.  Does not descend into
     /// `CompiledBlock` because the parent's pre-JIT list already contains
     /// the originals.
@@ -154,6 +154,11 @@ fn visit(stmt: &ProtoStatement, table: &mut SiteTable) {
                 visit(s, table);
             }
         }
+        ProtoStatement::Fork(threads) => {
+            for s in threads.iter().flatten() {
+                visit(s, table);
+            }
+        }
         // CompiledBlock / SystemFunctionCall / TbMethodCall / Break / non-FF
         // Assign(Dynamic) have no FF writes for this metadata table.
         _ => {}
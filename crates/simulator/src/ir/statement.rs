@@ -2,6 +2,8 @@ use crate::HashMap;
 use crate::HashSet;
 use crate::assert_buffer;
 use crate::backend::ChunkArtifact;
+use crate::cover_table::{self, CoverBins};
+use crate::coverage::{self, Slot};
 use crate::ir::big_array::BigArrayFold;
 use crate::ir::context::{Context, Conv};
 use crate::ir::expression::{
@@ -171,7 +173,57 @@ pub enum SystemFunctionCall {
         format_str: String,
         args: Vec<Expression>,
     },
+    /// `$cover` (`bins: None`, `value` is the condition) or `$cover_bins`;
+    /// see `crate::cover_table`.
+    Cover {
+        name: String,
+        value: Expression,
+        bins: Option<CoverBins>,
+    },
     Finish,
+    /// `$dumpon()`; handled by the testbench driver.
+    DumpOn,
+    /// `$dumpoff()`; handled by the testbench driver.
+    DumpOff,
+    /// `$wait(duration)`; `clocks` are the `$tb::clock_gen` instances that
+    /// keep running meanwhile, with their `period`s.
+    Wait {
+        duration: Expression,
+        clocks: Vec<(StrId, Option<u64>)>,
+    },
+    /// `$wait_until(condition)`, with `clocks` as for `Wait`.
+    WaitUntil {
+        condition: Expression,
+        clocks: Vec<(StrId, Option<u64>)>,
+    },
+    /// `$force(target, value)`; see `Simulator::force_signal`.
+    Force {
+        target: ForceTarget,
+        value: Expression,
+    },
+    /// `$release(target)`.
+    Release {
+        target: ForceTarget,
+    },
+    /// Counts one execution of the enclosing block; see `crate::coverage`.
+    Coverage {
+        block: u32,
+    },
+    /// Marks where the next testbench statement starts, for the debugger;
+    /// see `Config::debug`.
+    Line {
+        token: TokenRange,
+    },
+}
+
+/// The storage a `$force` / `$release` acts on.
+#[derive(Clone, Copy)]
+pub struct ForceTarget {
+    pub ptr: *mut u8,
+    pub native_bytes: usize,
+    /// Full width of the variable, whatever `select` picks of it.
+    pub width: usize,
+    pub select: Option<(usize, usize)>,
 }
 
 #[derive(Clone)]
@@ -383,6 +435,8 @@ pub enum Statement {
         inst: StrId,
         method: TbMethodKind,
     },
+    /// Testbench `#[fork]` block: one statement list per thread.
+    Fork(Vec<Vec<Statement>>),
 }
 
 #[derive(Clone)]
@@ -451,6 +505,13 @@ pub fn patch_stmt_log_buf(s: &mut Statement, log_buf: *mut u8) {
                 patch_stmt_log_buf(s, log_buf);
             }
         }
+        Statement::Fork(threads) => {
+            for thread in threads {
+                for s in thread {
+                    patch_stmt_log_buf(s, log_buf);
+                }
+            }
+        }
         Statement::Assign(_)
         | Statement::AssignDynamic(_)
         | Statement::Break
@@ -557,6 +618,17 @@ impl Statement {
                 }
                 ControlFlow::Continue
             }
+            // Outside the testbench driver the threads run one after another.
+            Statement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        if s.eval_step(mask_cache) == ControlFlow::Break {
+                            break;
+                        }
+                    }
+                }
+                ControlFlow::Continue
+            }
             Statement::SystemFunctionCall(x) => {
                 x.eval_step(mask_cache);
                 ControlFlow::Continue
@@ -582,6 +654,13 @@ impl Statement {
                     s.gather_variable(inputs, outputs);
                 }
             }
+            Statement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.gather_variable(inputs, outputs);
+                    }
+                }
+            }
             Statement::SystemFunctionCall(x) => x.gather_variable(inputs),
             Statement::TbMethodCall { .. } => (),
         }
@@ -826,7 +905,24 @@ impl SystemFunctionCall {
                     }
                 }
             }
-            SystemFunctionCall::Finish => {
+            SystemFunctionCall::Cover { name, value, bins } => {
+                let value = value.eval(mask_cache);
+                match bins {
+                    None => cover_table::sample(name, &value),
+                    Some(bins) => cover_table::sample_bins(name, *bins, &value),
+                }
+            }
+            SystemFunctionCall::Finish
+            | SystemFunctionCall::DumpOn
+            | SystemFunctionCall::DumpOff
+            | SystemFunctionCall::Wait { .. }
+            | SystemFunctionCall::WaitUntil { .. }
+            | SystemFunctionCall::Force { .. }
+            | SystemFunctionCall::Release { .. } => {
+                // Handled by testbench driver
+            }
+            SystemFunctionCall::Coverage { block } => crate::coverage::hit(*block),
+            SystemFunctionCall::Line { .. } => {
                 // Handled by testbench driver
             }
         }
@@ -845,7 +941,28 @@ impl SystemFunctionCall {
                 let mut dummy_outputs = vec![];
                 condition.gather_variable(inputs, &mut dummy_outputs);
             }
-            SystemFunctionCall::Finish => {}
+            SystemFunctionCall::Cover { value, .. } => {
+                let mut dummy_outputs = vec![];
+                value.gather_variable(inputs, &mut dummy_outputs);
+            }
+            SystemFunctionCall::Wait { duration, .. } => {
+                let mut dummy_outputs = vec![];
+                duration.gather_variable(inputs, &mut dummy_outputs);
+            }
+            SystemFunctionCall::WaitUntil { condition, .. } => {
+                let mut dummy_outputs = vec![];
+                condition.gather_variable(inputs, &mut dummy_outputs);
+            }
+            SystemFunctionCall::Force { value, .. } => {
+                let mut dummy_outputs = vec![];
+                value.gather_variable(inputs, &mut dummy_outputs);
+            }
+            SystemFunctionCall::Release { .. }
+            | SystemFunctionCall::Finish
+            | SystemFunctionCall::DumpOn
+            | SystemFunctionCall::DumpOff
+            | SystemFunctionCall::Coverage { .. }
+            | SystemFunctionCall::Line { .. } => {}
         }
     }
 }
@@ -871,7 +988,41 @@ pub enum ProtoSystemFunctionCall {
         format_str: String,
         args: Vec<ProtoExpression>,
     },
+    /// `bins: None` for `$cover`, whose `value` is the condition.
+    Cover {
+        name: String,
+        value: ProtoExpression,
+        bins: Option<CoverBins>,
+    },
     Finish,
+    DumpOn,
+    DumpOff,
+    Wait {
+        duration: ProtoExpression,
+        clocks: Vec<(StrId, Option<u64>)>,
+    },
+    WaitUntil {
+        condition: ProtoExpression,
+        clocks: Vec<(StrId, Option<u64>)>,
+    },
+    /// `target` is a plain `Variable` (a `HierVariable` until
+    /// `resolve_hier_refs`) without a dynamic select.
+    Force {
+        target: ProtoExpression,
+        value: ProtoExpression,
+    },
+    Release {
+        target: ProtoExpression,
+    },
+    /// Not a user call: the probe `Config::coverage` opens each block with.
+    Coverage {
+        block: u32,
+    },
+    /// Not a user call: the marker `Config::debug` puts ahead of each
+    /// testbench statement.
+    Line {
+        token: TokenRange,
+    },
 }
 
 #[derive(Clone, Debug, Hash)]
@@ -1043,6 +1194,8 @@ pub enum ProtoStatement {
         inst: StrId,
         method: ProtoTbMethodKind,
     },
+    /// Testbench `#[fork]` block: one statement list per thread.
+    Fork(Vec<Vec<ProtoStatement>>),
 }
 
 impl ProtoStatement {
@@ -1120,7 +1273,27 @@ impl ProtoStatement {
                         arg.adjust_offsets(ff_delta, comb_delta);
                     }
                 }
-                ProtoSystemFunctionCall::Finish => {}
+                ProtoSystemFunctionCall::Cover { value, .. } => {
+                    value.adjust_offsets(ff_delta, comb_delta);
+                }
+                ProtoSystemFunctionCall::Wait { duration, .. } => {
+                    duration.adjust_offsets(ff_delta, comb_delta);
+                }
+                ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                    condition.adjust_offsets(ff_delta, comb_delta);
+                }
+                ProtoSystemFunctionCall::Force { target, value } => {
+                    target.adjust_offsets(ff_delta, comb_delta);
+                    value.adjust_offsets(ff_delta, comb_delta);
+                }
+                ProtoSystemFunctionCall::Release { target } => {
+                    target.adjust_offsets(ff_delta, comb_delta);
+                }
+                ProtoSystemFunctionCall::Finish
+                | ProtoSystemFunctionCall::DumpOn
+                | ProtoSystemFunctionCall::DumpOff
+                | ProtoSystemFunctionCall::Coverage { .. }
+                | ProtoSystemFunctionCall::Line { .. } => {}
             },
             ProtoStatement::CompiledBlock(_) => {
                 // CompiledBlocks use ff_delta_bytes/comb_delta_bytes at runtime.
@@ -1150,6 +1323,13 @@ impl ProtoStatement {
                     s.adjust_offsets(ff_delta, comb_delta);
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.adjust_offsets(ff_delta, comb_delta);
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { method, .. } => match method {
                 ProtoTbMethodKind::ClockNext { count, period } => {
                     if let Some(c) = count {
@@ -1238,6 +1418,13 @@ impl ProtoStatement {
                     s.collect_written_offsets(out);
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.collect_written_offsets(out);
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { .. } => {}
             ProtoStatement::Break => {}
         }
@@ -1312,7 +1499,27 @@ impl ProtoStatement {
                         arg.remap_offsets_with(f);
                     }
                 }
-                ProtoSystemFunctionCall::Finish => {}
+                ProtoSystemFunctionCall::Cover { value, .. } => {
+                    value.remap_offsets_with(f);
+                }
+                ProtoSystemFunctionCall::Wait { duration, .. } => {
+                    duration.remap_offsets_with(f);
+                }
+                ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                    condition.remap_offsets_with(f);
+                }
+                ProtoSystemFunctionCall::Force { target, value } => {
+                    target.remap_offsets_with(f);
+                    value.remap_offsets_with(f);
+                }
+                ProtoSystemFunctionCall::Release { target } => {
+                    target.remap_offsets_with(f);
+                }
+                ProtoSystemFunctionCall::Finish
+                | ProtoSystemFunctionCall::DumpOn
+                | ProtoSystemFunctionCall::DumpOff
+                | ProtoSystemFunctionCall::Coverage { .. }
+                | ProtoSystemFunctionCall::Line { .. } => {}
             },
             ProtoStatement::CompiledBlock(_) => {}
             ProtoStatement::For(x) => {
@@ -1339,6 +1546,13 @@ impl ProtoStatement {
                     s.remap_offsets_with(f);
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.remap_offsets_with(f);
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { method, .. } => match method {
                 ProtoTbMethodKind::ClockNext { count, period } => {
                     if let Some(c) = count {
@@ -1470,7 +1684,24 @@ impl ProtoStatement {
                         arg.gather_variable_offsets(inputs);
                     }
                 }
-                ProtoSystemFunctionCall::Finish => {}
+                ProtoSystemFunctionCall::Cover { value, .. } => {
+                    value.gather_variable_offsets(inputs);
+                }
+                ProtoSystemFunctionCall::Wait { duration, .. } => {
+                    duration.gather_variable_offsets(inputs);
+                }
+                ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                    condition.gather_variable_offsets(inputs);
+                }
+                ProtoSystemFunctionCall::Force { value, .. } => {
+                    value.gather_variable_offsets(inputs);
+                }
+                ProtoSystemFunctionCall::Release { .. }
+                | ProtoSystemFunctionCall::Finish
+                | ProtoSystemFunctionCall::DumpOn
+                | ProtoSystemFunctionCall::DumpOff
+                | ProtoSystemFunctionCall::Coverage { .. }
+                | ProtoSystemFunctionCall::Line { .. } => {}
             },
             ProtoStatement::CompiledBlock(x) => {
                 // Only include comb (non-FF) offsets for dependency analysis.
@@ -1532,6 +1763,13 @@ impl ProtoStatement {
                 }
                 outputs.extend(all_outs);
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.gather_variable_offsets(inputs, outputs);
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { .. } => {}
             ProtoStatement::Break => {}
         }
@@ -1595,7 +1833,24 @@ impl ProtoStatement {
                         arg.gather_reads_with_ranges(out);
                     }
                 }
-                ProtoSystemFunctionCall::Finish => {}
+                ProtoSystemFunctionCall::Cover { value, .. } => {
+                    value.gather_reads_with_ranges(out);
+                }
+                ProtoSystemFunctionCall::Wait { duration, .. } => {
+                    duration.gather_reads_with_ranges(out);
+                }
+                ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                    condition.gather_reads_with_ranges(out);
+                }
+                ProtoSystemFunctionCall::Force { value, .. } => {
+                    value.gather_reads_with_ranges(out);
+                }
+                ProtoSystemFunctionCall::Release { .. }
+                | ProtoSystemFunctionCall::Finish
+                | ProtoSystemFunctionCall::DumpOn
+                | ProtoSystemFunctionCall::DumpOff
+                | ProtoSystemFunctionCall::Coverage { .. }
+                | ProtoSystemFunctionCall::Line { .. } => {}
             },
             ProtoStatement::CompiledBlock(x) => {
                 if !x.stmt_deps.is_empty() {
@@ -1637,6 +1892,13 @@ impl ProtoStatement {
                     }
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.gather_reads_with_ranges(out);
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { .. } => {}
             ProtoStatement::Break => {}
         }
@@ -1724,7 +1986,24 @@ impl ProtoStatement {
                         arg.gather_variable_offsets_expanded(fold, inputs);
                     }
                 }
-                ProtoSystemFunctionCall::Finish => {}
+                ProtoSystemFunctionCall::Cover { value, .. } => {
+                    value.gather_variable_offsets_expanded(fold, inputs);
+                }
+                ProtoSystemFunctionCall::Wait { duration, .. } => {
+                    duration.gather_variable_offsets_expanded(fold, inputs);
+                }
+                ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                    condition.gather_variable_offsets_expanded(fold, inputs);
+                }
+                ProtoSystemFunctionCall::Force { value, .. } => {
+                    value.gather_variable_offsets_expanded(fold, inputs);
+                }
+                ProtoSystemFunctionCall::Release { .. }
+                | ProtoSystemFunctionCall::Finish
+                | ProtoSystemFunctionCall::DumpOn
+                | ProtoSystemFunctionCall::DumpOff
+                | ProtoSystemFunctionCall::Coverage { .. }
+                | ProtoSystemFunctionCall::Line { .. } => {}
             },
             ProtoStatement::CompiledBlock(x) => {
                 // Prefer walking the original statements so AssignDynamic /
@@ -1772,6 +2051,13 @@ impl ProtoStatement {
                     s.gather_variable_offsets_expanded(fold, inputs, outputs);
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.gather_variable_offsets_expanded(fold, inputs, outputs);
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { .. } => {}
             ProtoStatement::Break => {}
         }
@@ -1829,7 +2115,25 @@ impl ProtoStatement {
                         arg.collect_big_arrays(fold);
                     }
                 }
-                ProtoSystemFunctionCall::Readmemh { .. } | ProtoSystemFunctionCall::Finish => {}
+                ProtoSystemFunctionCall::Cover { value, .. } => {
+                    value.collect_big_arrays(fold);
+                }
+                ProtoSystemFunctionCall::Wait { duration, .. } => {
+                    duration.collect_big_arrays(fold);
+                }
+                ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                    condition.collect_big_arrays(fold);
+                }
+                ProtoSystemFunctionCall::Force { value, .. } => {
+                    value.collect_big_arrays(fold);
+                }
+                ProtoSystemFunctionCall::Readmemh { .. }
+                | ProtoSystemFunctionCall::Release { .. }
+                | ProtoSystemFunctionCall::Finish
+                | ProtoSystemFunctionCall::DumpOn
+                | ProtoSystemFunctionCall::DumpOff
+                | ProtoSystemFunctionCall::Coverage { .. }
+                | ProtoSystemFunctionCall::Line { .. } => {}
             },
             ProtoStatement::CompiledBlock(x) => {
                 // The cached offset lists carry no array shape, so only the
@@ -1853,6 +2157,13 @@ impl ProtoStatement {
                     s.collect_big_arrays(fold);
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.collect_big_arrays(fold);
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { .. } | ProtoStatement::Break => {}
         }
     }
@@ -1914,7 +2225,24 @@ impl ProtoStatement {
                         arg.gather_dynamic_read_ranges(ranges);
                     }
                 }
-                ProtoSystemFunctionCall::Finish => {}
+                ProtoSystemFunctionCall::Cover { value, .. } => {
+                    value.gather_dynamic_read_ranges(ranges);
+                }
+                ProtoSystemFunctionCall::Wait { duration, .. } => {
+                    duration.gather_dynamic_read_ranges(ranges);
+                }
+                ProtoSystemFunctionCall::WaitUntil { condition, .. } => {
+                    condition.gather_dynamic_read_ranges(ranges);
+                }
+                ProtoSystemFunctionCall::Force { value, .. } => {
+                    value.gather_dynamic_read_ranges(ranges);
+                }
+                ProtoSystemFunctionCall::Release { .. }
+                | ProtoSystemFunctionCall::Finish
+                | ProtoSystemFunctionCall::DumpOn
+                | ProtoSystemFunctionCall::DumpOff
+                | ProtoSystemFunctionCall::Coverage { .. }
+                | ProtoSystemFunctionCall::Line { .. } => {}
             },
             ProtoStatement::CompiledBlock(x) => {
                 // Prefer the originals so their DynamicVariable reads register
@@ -1940,6 +2268,13 @@ impl ProtoStatement {
                     s.gather_dynamic_read_ranges(ranges);
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        s.gather_dynamic_read_ranges(ranges);
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { .. } => {}
             ProtoStatement::Break => {}
         }
@@ -2001,6 +2336,13 @@ impl ProtoStatement {
                     result.extend(s.gather_ff_canonical_offsets());
                 }
             }
+            ProtoStatement::Fork(threads) => {
+                for thread in threads {
+                    for s in thread {
+                        result.extend(s.gather_ff_canonical_offsets());
+                    }
+                }
+            }
             ProtoStatement::TbMethodCall { .. } => {}
             ProtoStatement::Break => {}
         }
@@ -2187,6 +2529,61 @@ impl ProtoStatement {
                             args,
                         })
                     }
+                    ProtoSystemFunctionCall::Wait { duration, clocks } => {
+                        let duration = duration.apply_values_ptr(
+                            ff_values_ptr,
+                            ff_len,
+                            comb_values_ptr,
+                            comb_len,
+                            use_4state,
+                        );
+                        Statement::SystemFunctionCall(SystemFunctionCall::Wait {
+                            duration,
+                            clocks: clocks.clone(),
+                        })
+                    }
+                    ProtoSystemFunctionCall::WaitUntil { condition, clocks } => {
+                        let condition = condition.apply_values_ptr(
+                            ff_values_ptr,
+                            ff_len,
+                            comb_values_ptr,
+                            comb_len,
+                            use_4state,
+                        );
+                        Statement::SystemFunctionCall(SystemFunctionCall::WaitUntil {
+                            condition,
+                            clocks: clocks.clone(),
+                        })
+                    }
+                    ProtoSystemFunctionCall::Force { target, value } => {
+                        let target = force_target(target, ff_values_ptr, comb_values_ptr);
+                        let value = value.apply_values_ptr(
+                            ff_values_ptr,
+                            ff_len,
+                            comb_values_ptr,
+                            comb_len,
+                            use_4state,
+                        );
+                        Statement::SystemFunctionCall(SystemFunctionCall::Force { target, value })
+                    }
+                    ProtoSystemFunctionCall::Release { target } => {
+                        let target = force_target(target, ff_values_ptr, comb_values_ptr);
+                        Statement::SystemFunctionCall(SystemFunctionCall::Release { target })
+                    }
+                    ProtoSystemFunctionCall::Cover { name, value, bins } => {
+                        let value = value.apply_values_ptr(
+                            ff_values_ptr,
+                            ff_len,
+                            comb_values_ptr,
+                            comb_len,
+                            use_4state,
+                        );
+                        Statement::SystemFunctionCall(SystemFunctionCall::Cover {
+                            name: name.clone(),
+                            value,
+                            bins: *bins,
+                        })
+                    }
                     ProtoSystemFunctionCall::Write { format_str, args } => {
                         let args = args
                             .iter()
@@ -2208,6 +2605,20 @@ impl ProtoStatement {
                     ProtoSystemFunctionCall::Finish => {
                         Statement::SystemFunctionCall(SystemFunctionCall::Finish)
                     }
+                    ProtoSystemFunctionCall::DumpOn => {
+                        Statement::SystemFunctionCall(SystemFunctionCall::DumpOn)
+                    }
+                    ProtoSystemFunctionCall::DumpOff => {
+                        Statement::SystemFunctionCall(SystemFunctionCall::DumpOff)
+                    }
+                    ProtoSystemFunctionCall::Line { token } => {
+                        Statement::SystemFunctionCall(SystemFunctionCall::Line { token: *token })
+                    }
+                    ProtoSystemFunctionCall::Coverage { block } => {
+                        Statement::SystemFunctionCall(SystemFunctionCall::Coverage {
+                            block: *block,
+                        })
+                    }
                 },
                 ProtoStatement::CompiledBlock(x) => {
                     // Use wrapping_offset because the adjusted pointer may temporarily
@@ -2332,6 +2743,26 @@ impl ProtoStatement {
                         .collect();
                     Statement::SequentialBlock(stmts)
                 }
+                ProtoStatement::Fork(threads) => {
+                    let threads = threads
+                        .iter()
+                        .map(|thread| {
+                            thread
+                                .iter()
+                                .map(|s| {
+                                    s.apply_values_ptr(
+                                        ff_values_ptr,
+                                        ff_len,
+                                        comb_values_ptr,
+                                        comb_len,
+                                        use_4state,
+                                    )
+                                })
+                                .collect()
+                        })
+                        .collect();
+                    Statement::Fork(threads)
+                }
                 ProtoStatement::Break => Statement::Break,
                 ProtoStatement::TbMethodCall { inst, method } => {
                     let method = match method {
@@ -3099,6 +3530,130 @@ impl ProtoCaseStatement {
     }
 }
 
+/// Where a statement starts in the source, when it has a position.
+fn stmt_token(src: &air::Statement) -> Option<TokenRange> {
+    match src {
+        air::Statement::Assign(x) => Some(x.token),
+        air::Statement::If(x) => Some(x.token),
+        air::Statement::IfReset(x) => Some(x.token),
+        air::Statement::Case(x) => Some(x.token),
+        air::Statement::For(x) => Some(x.token),
+        air::Statement::Fork(x) => Some(x.token),
+        air::Statement::SystemFunctionCall(x) => Some(x.comptime.token),
+        air::Statement::FunctionCall(x) => Some(x.comptime.token),
+        air::Statement::Unsupported(x) => Some(*x),
+        air::Statement::TbMethodCall(_) | air::Statement::Break | air::Statement::Null => None,
+    }
+}
+
+/// The probe opening the `slot` statement list `src` of `owner` under
+/// `Config::coverage`.  A body has no owner of its own and is keyed by its
+/// first statement.
+pub(crate) fn coverage_probe(
+    context: &Context,
+    owner: Option<TokenRange>,
+    slot: Slot,
+    src: &[air::Statement],
+) -> Option<ProtoStatement> {
+    if !context.config.coverage {
+        return None;
+    }
+    let owner = owner.or_else(|| src.iter().find_map(stmt_token))?;
+    let lines = src.iter().filter_map(stmt_token).map(|t| t.beg.line);
+    let block = coverage::register_block(&owner, slot, lines)?;
+    Some(ProtoStatement::SystemFunctionCall(
+        ProtoSystemFunctionCall::Coverage { block },
+    ))
+}
+
+/// Opens `stmts` with its [`coverage_probe`], if any.
+fn instrument(
+    context: &Context,
+    stmts: &mut Vec<ProtoStatement>,
+    owner: Option<TokenRange>,
+    slot: Slot,
+    src: &[air::Statement],
+) {
+    if let Some(probe) = coverage_probe(context, owner, slot, src) {
+        stmts.insert(0, probe);
+    }
+}
+
+/// Evaluates the `period`s of the `$tb::clock_gen` instances a `$wait` or
+/// `$wait_until` keeps running.
+fn tb_clock_periods(
+    context: &mut Context,
+    clocks: &[(StrId, Option<air::Expression>)],
+) -> Result<Vec<(StrId, Option<u64>)>, SimulatorError> {
+    let mut periods = Vec::new();
+    for (name, period) in clocks {
+        let period = match period {
+            Some(x) => Some(
+                x.eval_value(&mut context.scope().analyzer_context)
+                    .and_then(|v| v.to_u64())
+                    .ok_or_else(|| SimulatorError::unsupported_description(&x.token_range()))?,
+            ),
+            None => None,
+        };
+        periods.push((*name, period));
+    }
+    Ok(periods)
+}
+
+/// Converts the target of a `$force` / `$release`, which must name the
+/// storage of one variable: an array index or bit select known only at run
+/// time has none.
+fn conv_force_target(
+    context: &mut Context,
+    expr: &air::Expression,
+) -> Result<ProtoExpression, SimulatorError> {
+    let target: ProtoExpression = Conv::conv(context, expr)?;
+    match &target {
+        ProtoExpression::HierVariable(_) => Ok(target),
+        x if is_force_target(x) => Ok(target),
+        _ => Err(SimulatorError::unsupported_description(&expr.token_range())),
+    }
+}
+
+pub(crate) fn is_force_target(expr: &ProtoExpression) -> bool {
+    matches!(
+        expr,
+        ProtoExpression::Variable {
+            dynamic_select: None,
+            ..
+        }
+    )
+}
+
+/// # Safety
+/// `ff_values_ptr` and `comb_values_ptr` must point to valid buffers.
+unsafe fn force_target(
+    expr: &ProtoExpression,
+    ff_values_ptr: *mut u8,
+    comb_values_ptr: *mut u8,
+) -> ForceTarget {
+    let ProtoExpression::Variable {
+        var_offset,
+        select,
+        var_full_width,
+        ..
+    } = expr
+    else {
+        unreachable!("force target checked by conv_force_target");
+    };
+    let base = if var_offset.is_ff() {
+        ff_values_ptr
+    } else {
+        comb_values_ptr
+    };
+    ForceTarget {
+        ptr: unsafe { base.offset(var_offset.raw()) },
+        native_bytes: calc_native_bytes(*var_full_width),
+        width: *var_full_width,
+        select: *select,
+    }
+}
+
 fn extract_display_args(
     context: &mut Context,
     inputs: &[SystemFunctionInput],
@@ -3190,6 +3745,14 @@ impl Conv<&air::Statement> for Vec<ProtoStatement> {
                         let v: Vec<ProtoStatement> = Conv::conv(context, s)?;
                         body.extend(v);
                     }
+                    let arm_index = converted.len() as u32;
+                    instrument(
+                        context,
+                        &mut body,
+                        Some(c.token),
+                        Slot::Arm(arm_index),
+                        &arm.body,
+                    );
                     converted.push((cond, cond_pending, body));
                 }
                 let mut default: Vec<ProtoStatement> = Vec::new();
@@ -3197,6 +3760,14 @@ impl Conv<&air::Statement> for Vec<ProtoStatement> {
                     let v: Vec<ProtoStatement> = Conv::conv(context, s)?;
                     default.extend(v);
                 }
+                let default_index = c.arms.len() as u32;
+                instrument(
+                    context,
+                    &mut default,
+                    Some(c.token),
+                    Slot::Arm(default_index),
+                    &c.default,
+                );
 
                 if any_cond_pending {
                     // An inlined function in a condition must run lazily per
@@ -3289,11 +3860,89 @@ impl Conv<&air::Statement> for Vec<ProtoStatement> {
                         },
                     )]
                 }
+                SystemFunctionKind::Cover { cond, name } => {
+                    let value: ProtoExpression = Conv::conv(context, &cond.0)?;
+                    let name = extract_string_value(&name.0).ok_or_else(|| {
+                        SimulatorError::unsupported_description(&x.comptime.token)
+                    })?;
+                    vec![ProtoStatement::SystemFunctionCall(
+                        ProtoSystemFunctionCall::Cover {
+                            name,
+                            value,
+                            bins: None,
+                        },
+                    )]
+                }
+                SystemFunctionKind::CoverBins { value, name, bins } => {
+                    let value: ProtoExpression = Conv::conv(context, &value.0)?;
+                    let name = extract_string_value(&name.0).ok_or_else(|| {
+                        SimulatorError::unsupported_description(&x.comptime.token)
+                    })?;
+                    // Bins are u64 ranges.
+                    let width = value.width();
+                    if width > 64 {
+                        return Err(SimulatorError::unsupported_description(&x.comptime.token));
+                    }
+                    let count = match bins {
+                        Some(bins) => bins
+                            .0
+                            .eval_value(&mut context.scope().analyzer_context)
+                            .and_then(|v| v.to_usize())
+                            .ok_or_else(|| {
+                                SimulatorError::unsupported_description(&bins.0.token_range())
+                            })?,
+                        None => cover_table::DEFAULT_BINS,
+                    };
+                    vec![ProtoStatement::SystemFunctionCall(
+                        ProtoSystemFunctionCall::Cover {
+                            name,
+                            value,
+                            bins: Some(CoverBins { width, count }),
+                        },
+                    )]
+                }
                 SystemFunctionKind::Finish => {
                     vec![ProtoStatement::SystemFunctionCall(
                         ProtoSystemFunctionCall::Finish,
                     )]
                 }
+                SystemFunctionKind::DumpOn => {
+                    vec![ProtoStatement::SystemFunctionCall(
+                        ProtoSystemFunctionCall::DumpOn,
+                    )]
+                }
+                SystemFunctionKind::DumpOff => {
+                    vec![ProtoStatement::SystemFunctionCall(
+                        ProtoSystemFunctionCall::DumpOff,
+                    )]
+                }
+                SystemFunctionKind::Wait { duration, clocks } => {
+                    let duration: ProtoExpression = Conv::conv(context, &duration.0)?;
+                    let clocks = tb_clock_periods(context, clocks)?;
+                    vec![ProtoStatement::SystemFunctionCall(
+                        ProtoSystemFunctionCall::Wait { duration, clocks },
+                    )]
+                }
+                SystemFunctionKind::WaitUntil { condition, clocks } => {
+                    let condition: ProtoExpression = Conv::conv(context, &condition.0)?;
+                    let clocks = tb_clock_periods(context, clocks)?;
+                    vec![ProtoStatement::SystemFunctionCall(
+                        ProtoSystemFunctionCall::WaitUntil { condition, clocks },
+                    )]
+                }
+                SystemFunctionKind::Force { target, value } => {
+                    let target = conv_force_target(context, &target.0)?;
+                    let value: ProtoExpression = Conv::conv(context, &value.0)?;
+                    vec![ProtoStatement::SystemFunctionCall(
+                        ProtoSystemFunctionCall::Force { target, value },
+                    )]
+                }
+                SystemFunctionKind::Release { target } => {
+                    let target = conv_force_target(context, &target.0)?;
+                    vec![ProtoStatement::SystemFunctionCall(
+                        ProtoSystemFunctionCall::Release { target },
+                    )]
+                }
                 _ => {
                     return Err(SimulatorError::unsupported_description(&x.comptime.token));
                 }
@@ -3445,6 +4094,7 @@ impl Conv<&air::Statement> for Vec<ProtoStatement> {
                     let stmts: Vec<ProtoStatement> = Conv::conv(context, stmt)?;
                     body.extend(stmts);
                 }
+                instrument(context, &mut body, Some(x.token), Slot::Loop, &x.body);
 
                 let token = x.token;
                 let resolve_bound = |b: &air::ForBound,
@@ -3510,6 +4160,18 @@ impl Conv<&air::Statement> for Vec<ProtoStatement> {
                     body,
                 })]
             }
+            air::Statement::Fork(x) => {
+                let mut threads = vec![];
+                for thread in &x.threads {
+                    let mut body = vec![];
+                    for stmt in thread {
+                        let stmts: Vec<ProtoStatement> = Conv::conv(context, stmt)?;
+                        body.extend(stmts);
+                    }
+                    threads.push(body);
+                }
+                vec![ProtoStatement::Fork(threads)]
+            }
             air::Statement::Unsupported(token) => {
                 return Err(SimulatorError::unsupported_description(token));
             }
@@ -3524,6 +4186,20 @@ impl Conv<&air::Statement> for Vec<ProtoStatement> {
             result = pending;
         }
 
+        if context.in_initial
+            && context.config.debug
+            && let Some(token) = match src {
+                // Coverage leaves `$tb` method lines out; the debugger stops there.
+                air::Statement::TbMethodCall(x) => Some(x.token),
+                _ => stmt_token(src),
+            }
+        {
+            result.insert(
+                0,
+                ProtoStatement::SystemFunctionCall(ProtoSystemFunctionCall::Line { token }),
+            );
+        }
+
         Ok(result)
     }
 }
@@ -4274,6 +4950,21 @@ impl Conv<&air::IfStatement> for ProtoIfStatement {
             false_side.extend(stmts);
         }
 
+        instrument(
+            context,
+            &mut true_side,
+            Some(src.token),
+            Slot::Arm(0),
+            &src.true_side,
+        );
+        instrument(
+            context,
+            &mut false_side,
+            Some(src.token),
+            Slot::Arm(1),
+            &src.false_side,
+        );
+
         // Branch conversions drain their own per-statement pending, so anything
         // left now belongs after the condition's: keep condition-pending first.
         let mut pending = cond_pending;
@@ -4302,6 +4993,21 @@ impl Conv<&air::IfResetStatement> for ProtoIfStatement {
             false_side.extend(stmts);
         }
 
+        instrument(
+            context,
+            &mut true_side,
+            Some(src.token),
+            Slot::Arm(0),
+            &src.true_side,
+        );
+        instrument(
+            context,
+            &mut false_side,
+            Some(src.token),
+            Slot::Arm(1),
+            &src.false_side,
+        );
+
         Ok(ProtoIfStatement {
             cond: None,
             true_side,
@@ -4503,6 +5209,7 @@ impl Conv<&FunctionCall> for Vec<ProtoStatement> {
         pending.append(&mut result);
         result = pending;
 
+        result.extend(coverage_probe(context, None, Slot::Body, &body.statements));
         for stmt in &body.statements {
             let stmts: Vec<ProtoStatement> = Conv::conv(context, stmt)?;
             result.extend(stmts);
@@ -225,6 +225,10 @@ pub struct Variable {
     pub native_bytes: usize,
     pub current_values: Vec<*mut u8>,
     pub next_values: Vec<*mut u8>,
+    /// Per element, the mask/value pair that holds a comb element forced
+    /// (null for an FF element); see `VariableElement::force`. Empty when
+    /// no element has one.
+    pub force_values: Vec<*mut u8>,
 }
 
 impl fmt::Display for Variable {
@@ -250,6 +254,11 @@ pub struct VariableElement {
     pub current: VarOffset,
     /// byte offset of next value from ff_buf[0]; meaningful only when is_ff == true
     pub next_offset: isize,
+    /// FF-buffer offset of the force mask of a comb element, followed by the
+    /// forced value; the pin statements `Config::force` adds after each
+    /// assignment read both. `None` for FF elements, which the simulator
+    /// pins after each commit instead.
+    pub force: Option<isize>,
 }
 
 impl VariableElement {
@@ -326,12 +335,14 @@ fn report_subject(v: &air::Variable) -> String {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_variable_meta(
     src: &HashMap<VarId, air::Variable>,
     ff_table: &air::FfTable,
     multi_rmw_set: &HashSet<(VarId, usize)>,
     dyn_indexed: &HashSet<VarId>,
     use_4state: bool,
+    force: bool,
     ff_start_bytes: isize,
     comb_start_bytes: isize,
 ) -> Result<(HashMap<VarId, VariableMeta>, usize, usize), SimulatorError> {
@@ -427,6 +438,7 @@ pub fn create_variable_meta(
                         native_bytes: nb,
                         current: VarOffset::Ff(current_offset),
                         next_offset: current_offset,
+                        force: None,
                     });
                     ff_pos += vs as isize;
                 } else {
@@ -435,6 +447,7 @@ pub fn create_variable_meta(
                         native_bytes: nb,
                         current: VarOffset::Ff(current_offset),
                         next_offset,
+                        force: None,
                     });
                     ff_pos += (vs * 2) as isize; // current + next
                 }
@@ -444,6 +457,7 @@ pub fn create_variable_meta(
                     native_bytes: nb,
                     current: VarOffset::Comb(current_offset),
                     next_offset: 0,
+                    force: None,
                 });
                 comb_pos += vs as isize;
             }
@@ -477,6 +491,26 @@ pub fn create_variable_meta(
         variables.insert(*k, meta);
     }
 
+    // Force pairs go after every variable, so the FF layout is the same
+    // with and without them.
+    if force {
+        let mut ids: Vec<_> = variables.keys().copied().collect();
+        ids.sort();
+        for id in ids {
+            if matches!(src[&id].kind, air::VarKind::Param | air::VarKind::Const) {
+                continue;
+            }
+            let meta = variables.get_mut(&id).unwrap();
+            let vs = value_size(meta.native_bytes, use_4state) as isize;
+            for elem in &mut meta.elements {
+                if !elem.is_ff() {
+                    elem.force = Some(ff_pos);
+                    ff_pos += vs * 2;
+                }
+            }
+        }
+    }
+
     #[cfg(debug_assertions)]
     {
         let ff_end = ff_pos;
@@ -516,6 +550,8 @@ pub fn create_variable_meta(
 #[derive(Clone, Debug)]
 pub struct ModuleVariableMeta {
     pub name: StrId,
+    /// Name of the module this node instantiates (`name` is the instance).
+    pub module: StrId,
     /// Generate-block prefix addressing this node (e.g. `["g_leaf[0]"]`), from
     /// `air::InstDeclaration::hierarchy`; empty for a plain instance or the top.
     pub hierarchy: Vec<StrId>,
@@ -527,6 +563,8 @@ pub struct ModuleVariableMeta {
 #[derive(Clone, Debug)]
 pub struct ModuleVariables {
     pub name: StrId,
+    /// See `ModuleVariableMeta::module`.
+    pub module: StrId,
     pub variables: HashMap<VarId, Variable>,
     pub children: Vec<ModuleVariables>,
 }
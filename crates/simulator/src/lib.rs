@@ -1,6 +1,10 @@
 pub mod assert_buffer;
 pub mod backend;
+pub mod checkpoint;
 pub mod component;
+pub mod cover_table;
+pub mod coverage;
+pub mod debugger;
 pub mod file_table;
 pub mod ir;
 pub mod output_buffer;
@@ -91,6 +91,51 @@ pub fn get_seed_handle(key: StrId) -> u64 {
     })
 }
 
+/// Generator states for a simulator checkpoint, keyed by handle name so
+/// the record does not depend on this process's `StrId` numbering.
+pub fn save() -> serde_json::Value {
+    TABLE.with(|t| {
+        let t = t.borrow();
+        let rngs: serde_json::Map<String, serde_json::Value> = t
+            .rngs
+            .iter()
+            .map(|(key, (rng, seed))| {
+                let name = resource_table::get_str_value(*key).unwrap_or_default();
+                // PCG state is `u128`, which `serde_json::Value` cannot hold;
+                // keep the serialized text instead.
+                let rng = serde_json::to_string(rng).unwrap_or_default();
+                (name, serde_json::json!({ "seed": seed, "rng": rng }))
+            })
+            .collect();
+        serde_json::json!({ "base_seed": t.base_seed, "rngs": rngs })
+    })
+}
+
+/// Replace the table with a [`save`] record. Returns an error message for
+/// a malformed record.
+pub fn restore(record: &serde_json::Value) -> Result<(), String> {
+    let base_seed = record["base_seed"]
+        .as_u64()
+        .ok_or("missing random base seed")?;
+    let mut rngs = HashMap::default();
+    if let Some(entries) = record["rngs"].as_object() {
+        for (name, entry) in entries {
+            let seed = entry["seed"]
+                .as_u64()
+                .ok_or_else(|| format!("missing seed of random handle `{name}`"))?;
+            let rng: Pcg64 = serde_json::from_str(entry["rng"].as_str().unwrap_or_default())
+                .map_err(|e| format!("random handle `{name}`: {e}"))?;
+            rngs.insert(resource_table::insert_str(name), (rng, seed));
+        }
+    }
+    TABLE.with(|t| {
+        let mut t = t.borrow_mut();
+        t.base_seed = base_seed;
+        t.rngs = rngs;
+    });
+    Ok(())
+}
+
 fn mask(width: u32) -> u64 {
     if width >= 64 {
         u64::MAX
@@ -1,18 +1,25 @@
+use crate::SimulatorError;
 use crate::backend::{CompiledWhole, DispatchOutcome};
+use crate::checkpoint;
 use crate::component::loader::ComponentError;
 use crate::component::runtime::{RuntimeComponent, build_components};
+use crate::coverage::ToggleTracker;
+use crate::ir::variable::value_size;
 use crate::ir::write_log::{
     WriteLogBuffer, clear_event_write_log, ff_commit_from_log, set_event_write_log,
 };
 use crate::ir::{
-    Event, Ir, ModuleVariables, Statement, Value, VarId, VarPath, dispatch_stmt_fast,
+    Event, ForceTarget, Ir, ModuleVariables, Statement, Value, VarId, VarPath, dispatch_stmt_fast,
     read_native_value, write_native_value,
 };
 use crate::residency;
 use crate::wave_dumper::{DumpVar, WaveDumper};
+use num_bigint::BigUint;
+use num_traits::One;
 use smallvec::SmallVec;
 use std::collections::{BTreeSet, HashMap};
 use std::env;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
@@ -43,12 +50,20 @@ pub struct Simulator {
     pub time: u64,
     pub dump: Option<WaveDumper>,
     dump_vars: Vec<DumpVar>,
+    /// Toggle sampler, present under `Config::coverage`; sampled with the
+    /// dump.
+    toggles: Option<ToggleTracker>,
     pub mask_cache: MaskCache,
     comb_dirty: bool,
     pub profile: SimProfile,
     /// Which testbench statements are known not to invalidate the comb.
     /// Empty (the default) settles after every testbench statement.
     pub(crate) tb_dirty: crate::tb_dirty::TbDirtyFilter,
+    /// Clock scheduler of a testbench that uses `$wait`; `None` keeps the
+    /// cycle-based `clk.next()`.
+    pub(crate) time_wheel: Option<crate::testbench::TimeWheel>,
+    /// Debugger the testbench driver reports to; see `crate::debugger`.
+    pub debugger: Option<crate::debugger::Debugger>,
     last_event: Option<Event>,
     last_event_stmts: *const Vec<Statement>,
     /// Whole-event AOT-C handle for `last_event`, cached alongside
@@ -71,7 +86,11 @@ pub struct Simulator {
     pub event_diag: Option<HashMap<(Event, usize), (u64, u64)>>,
     /// Stop the testbench after this many clock cycles; `None` runs to completion.
     pub cycle_limit: Option<u64>,
+    /// Testbench clock cycles completed, continuing from a restored
+    /// checkpoint's count.
     pub cycle_count: u64,
+    /// Save a checkpoint to the path once `cycle_count` reaches the cycle.
+    pub checkpoint_at: Option<(u64, PathBuf)>,
     /// Env-gated `VERYL_STEP_WATCH=path1,path2` debug watch: resolved
     /// variable pointers printed at each phase of `step_with_derived_clocks`.
     watch_vars: Vec<WatchVar>,
@@ -89,6 +108,132 @@ pub struct Simulator {
     /// The async-reset assertion edge to evaluate alongside this step's clock
     /// event, taken once.  See `step_in_reset`.
     pending_assertion_edge: Option<Event>,
+    /// Forces in effect; see `force_signal`.
+    forced: Vec<Force>,
+    /// `current storage -> force pair` of every comb element that has one
+    /// (`Config::force`), built on the first force.
+    force_pairs: Option<HashMap<*mut u8, *mut u8>>,
+}
+
+/// A force in effect: the set bits of `mask` hold the storage at `ptr` at
+/// those of `value`.  Both span the payload and, in 4-state, the X/Z half.
+struct Force {
+    ptr: *mut u8,
+    mask: Vec<u8>,
+    value: Vec<u8>,
+}
+
+impl Force {
+    /// # Safety
+    /// `ptr` must still point to the storage of a live simulator.
+    unsafe fn apply(&self) {
+        for (i, (m, v)) in self.mask.iter().zip(&self.value).enumerate() {
+            unsafe {
+                let b = self.ptr.add(i);
+                *b = (*b & !m) | (v & m);
+            }
+        }
+    }
+}
+
+/// A variable resolved once by `Simulator::resolve_port` or
+/// `Simulator::resolve_var`, so repeated reads and writes skip the path
+/// lookup. Only meaningful for the simulator that resolved it.
+#[derive(Clone, Copy, Debug)]
+pub struct SignalRef {
+    ptr: *mut u8,
+    native_bytes: usize,
+    width: usize,
+}
+
+impl SignalRef {
+    /// Refers to `var`, or to its first element if it is an array.
+    pub fn new(var: &crate::ir::Variable) -> Self {
+        Self {
+            ptr: var.current_values[0],
+            native_bytes: var.native_bytes,
+            width: var.width,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// 64-bit words needed to hold the value.
+    pub fn nwords(&self) -> usize {
+        self.width.div_ceil(64).max(1)
+    }
+
+    /// The value of LSB-first 64-bit words; missing high words read as zero
+    /// and a missing `mask_xz` makes a 2-state value.
+    fn words_value(&self, words: &[u64], mask_xz: Option<&[u64]>) -> Value {
+        let nwords = self.nwords();
+        // `trunc` keeps bits above a value's own width, so clear them here.
+        let top_bits = self.width % 64;
+        let fit = |ws: &[u64]| {
+            let mut ws = ws[..ws.len().min(nwords)].to_vec();
+            ws.resize(nwords, 0);
+            if top_bits != 0 {
+                ws[nwords - 1] &= (1u64 << top_bits) - 1;
+            }
+            ws
+        };
+        let mask_xz = mask_xz.map(fit).unwrap_or_default();
+        crate::component::runtime::words_to_value_masked(&fit(words), &mask_xz, self.width as u32)
+    }
+}
+
+impl From<&SignalRef> for ForceTarget {
+    fn from(value: &SignalRef) -> Self {
+        Self {
+            ptr: value.ptr,
+            native_bytes: value.native_bytes,
+            width: value.width,
+            select: None,
+        }
+    }
+}
+
+/// Writes the pair the pins of a forced comb element read: the mask, with no
+/// X/Z bits, then the value.
+///
+/// # Safety
+/// `pair` must be a force pair of an element of `native_bytes`, and `value`
+/// span that element's storage.
+unsafe fn write_force_pair(pair: *mut u8, native_bytes: usize, mask: &[u8], value: &[u8]) {
+    let size = value.len();
+    unsafe {
+        std::ptr::copy_nonoverlapping(mask.as_ptr(), pair, native_bytes);
+        std::ptr::write_bytes(pair.add(native_bytes), 0, size - native_bytes);
+        std::ptr::copy_nonoverlapping(value.as_ptr(), pair.add(size), size);
+    }
+}
+
+fn collect_force_pairs(module: &ModuleVariables, pairs: &mut HashMap<*mut u8, *mut u8>) {
+    for var in module.variables.values() {
+        for (current, pair) in var.current_values.iter().zip(&var.force_values) {
+            if !pair.is_null() {
+                pairs.insert(*current, *pair);
+            }
+        }
+    }
+    for child in &module.children {
+        collect_force_pairs(child, pairs);
+    }
+}
+
+/// Hierarchical path of the variable whose storage starts at `ptr`.
+fn find_path_by_ptr(module: &ModuleVariables, ptr: *mut u8, prefix: &str) -> Option<String> {
+    for var in module.variables.values() {
+        if var.current_values.contains(&ptr) {
+            return Some(format!("{prefix}{}", var.path));
+        }
+    }
+    module
+        .children
+        .iter()
+        .find_map(|x| find_path_by_ptr(x, ptr, &format!("{prefix}{}.", x.name)))
 }
 
 struct WatchVar {
@@ -148,10 +293,13 @@ impl Simulator {
             time: 0,
             dump: None,
             dump_vars: Vec::new(),
+            toggles: None,
             mask_cache: MaskCache::default(),
             comb_dirty: true,
             profile: Default::default(),
             tb_dirty: Default::default(),
+            time_wheel: None,
+            debugger: None,
             last_event: None,
             last_event_stmts: std::ptr::null(),
             last_whole_event: None,
@@ -164,12 +312,15 @@ impl Simulator {
             event_diag: (env::var("VERYL_EVENT_DIAG").as_deref() == Ok("1")).then(HashMap::default),
             cycle_limit: None,
             cycle_count: 0,
+            checkpoint_at: None,
             watch_vars: Vec::new(),
             components: Vec::new(),
             components_pending,
             trace_dump_vars: Vec::new(),
             component_event_override: None,
             pending_assertion_edge: None,
+            forced: Vec::new(),
+            force_pairs: None,
         };
 
         // Reset nets start DEASSERTED: zeroed storage reads as ASSERTED on an
@@ -188,6 +339,10 @@ impl Simulator {
             ret.set_reset_level(&id, false);
         }
 
+        if ret.ir.coverage {
+            ret.toggles = Some(ToggleTracker::new(&ret.ir.module_variables));
+        }
+
         if env::var("VERYL_DERIVED_CLOCK_DUMP").as_deref() == Ok("1") {
             fn find_var_by_ptr(
                 module: &ModuleVariables,
@@ -349,9 +504,20 @@ impl Simulator {
     /// Settle the comb list with whichever engine is configured.
     #[inline]
     fn do_settle_comb(&mut self) {
+        // Undo what deposits did to forced storage; the pins take care of
+        // the comb drivers.
+        self.apply_forces();
         self.ir.settle_comb(&mut self.mask_cache, &mut self.profile);
     }
 
+    #[inline]
+    fn apply_forces(&self) {
+        for x in &self.forced {
+            // SAFETY: forces point into `self.ir`'s buffers.
+            unsafe { x.apply() };
+        }
+    }
+
     /// Dump the `VERYL_EVENT_DIAG=1` per-statement event-eval time
     /// attribution: top entries by cumulative time plus per-kind totals.
     /// No-op when the diagnostic is disabled.
@@ -374,6 +540,7 @@ impl Simulator {
                 Some(Statement::SequentialBlock(_)) => "seq".into(),
                 Some(Statement::SystemFunctionCall(_)) => "sysfn".into(),
                 Some(Statement::TbMethodCall { .. }) => "tbcall".into(),
+                Some(Statement::Fork(_)) => "fork".into(),
                 Some(Statement::Break) => "break".into(),
                 None => "?".into(),
             }
@@ -421,68 +588,51 @@ impl Simulator {
     }
 
     pub fn set(&mut self, port: &str, value: Value) {
-        let port = VarPath::from_str(port).unwrap();
-
-        if let Some(id) = self.ir.ports.get(&port)
-            && let Some(x) = self.ir.module_variables.variables.get_mut(id)
-        {
-            let mut value = value;
-            value.trunc(x.width);
-            unsafe {
-                write_native_value(
-                    x.current_values[0],
-                    x.native_bytes,
-                    self.ir.use_4state,
-                    &value,
-                );
-            }
-            self.comb_dirty = true;
+        if let Some(signal) = self.resolve_port(port) {
+            self.write_signal(&signal, value);
         }
     }
 
     pub fn get(&mut self, port: &str) -> Option<Value> {
-        self.ensure_comb_updated();
-
-        let port = VarPath::from_str(port).unwrap();
-
-        if let Some(id) = self.ir.ports.get(&port)
-            && let Some(x) = self.ir.module_variables.variables.get(id)
-        {
-            let value = unsafe {
-                read_native_value(
-                    x.current_values[0],
-                    x.native_bytes,
-                    self.ir.use_4state,
-                    x.width as u32,
-                    false,
-                )
-            };
-            Some(value)
-        } else {
-            None
-        }
+        let signal = self.resolve_port(port)?;
+        Some(self.read_signal(&signal))
     }
 
     /// Get a variable value by hierarchical path (e.g., "dut.cnt").
     /// Searches all module variables including children.
     pub fn get_var(&mut self, path: &str) -> Option<Value> {
-        self.ensure_comb_updated();
+        let signal = self.resolve_var(path)?;
+        Some(self.read_signal(&signal))
+    }
 
+    /// Resolves a top-level port by name.
+    pub fn resolve_port(&self, port: &str) -> Option<SignalRef> {
+        let port = VarPath::from_str(port).unwrap();
+        let id = self.ir.ports.get(&port)?;
+        self.ir
+            .module_variables
+            .variables
+            .get(id)
+            .map(SignalRef::new)
+    }
+
+    /// Resolves a variable by hierarchical path (e.g., "dut.cnt"), like
+    /// `get_var`.
+    pub fn resolve_var(&self, path: &str) -> Option<SignalRef> {
         let target = VarPath::from_str(path).unwrap();
-        Self::find_var_in_module(&self.ir.module_variables, &target, self.ir.use_4state)
+        Self::find_var_in_module(&self.ir.module_variables, &target).map(SignalRef::new)
     }
 
-    fn find_var_in_module(
-        module: &ModuleVariables,
+    fn find_var_in_module<'a>(
+        module: &'a ModuleVariables,
         target: &VarPath,
-        use_4state: bool,
-    ) -> Option<Value> {
+    ) -> Option<&'a crate::ir::Variable> {
         // If target has multiple segments, try matching child module by name first
         if target.0.len() > 1 {
             for child in &module.children {
                 if child.name == target.0[0] {
                     let sub = VarPath::from_slice(&target.0[1..]);
-                    if let Some(v) = Self::find_var_in_module(child, &sub, use_4state) {
+                    if let Some(v) = Self::find_var_in_module(child, &sub) {
                         return Some(v);
                     }
                 }
@@ -490,21 +640,199 @@ impl Simulator {
         }
 
         // Look for a variable whose path matches exactly
-        for var in module.variables.values() {
-            if var.path == *target {
-                let value = unsafe {
-                    read_native_value(
-                        var.current_values[0],
-                        var.native_bytes,
-                        use_4state,
-                        var.width as u32,
-                        false,
-                    )
-                };
-                return Some(value);
+        module.variables.values().find(|var| var.path == *target)
+    }
+
+    pub fn read_signal(&mut self, signal: &SignalRef) -> Value {
+        self.ensure_comb_updated();
+        unsafe {
+            read_native_value(
+                signal.ptr,
+                signal.native_bytes,
+                self.ir.use_4state,
+                signal.width as u32,
+                false,
+            )
+        }
+    }
+
+    /// Writes `value`, truncated to the signal width. A non-port variable is
+    /// deposited: the design overwrites it on its next assignment.
+    pub fn write_signal(&mut self, signal: &SignalRef, value: Value) {
+        let mut value = value;
+        value.trunc(signal.width);
+        unsafe {
+            write_native_value(signal.ptr, signal.native_bytes, self.ir.use_4state, &value);
+        }
+        self.comb_dirty = true;
+    }
+
+    /// Forces `signal` to `value` until `release_signal`, as SystemVerilog's
+    /// `force` does: neither its drivers nor `write_signal` change it
+    /// meanwhile.  A comb variable can be forced only under `Config::force`.
+    pub fn force_signal(&mut self, signal: &SignalRef, value: Value) -> Result<(), SimulatorError> {
+        self.force_target(&ForceTarget::from(signal), value)
+    }
+
+    /// Ends a `force_signal`.  A comb variable follows its drivers again; an
+    /// FF keeps the forced value until its next update.
+    pub fn release_signal(&mut self, signal: &SignalRef) -> Result<(), SimulatorError> {
+        self.release_target(&ForceTarget::from(signal))
+    }
+
+    /// Forces the bits `target` selects; see `force_signal`.
+    pub(crate) fn force_target(
+        &mut self,
+        target: &ForceTarget,
+        value: Value,
+    ) -> Result<(), SimulatorError> {
+        let pair = self.force_pair(target)?;
+        let (mask, value) = self.force_bytes(target, Some(value));
+        let index = match self.forced.iter().position(|x| x.ptr == target.ptr) {
+            Some(i) => i,
+            None => {
+                self.forced.push(Force {
+                    ptr: target.ptr,
+                    mask: vec![0; mask.len()],
+                    value: vec![0; mask.len()],
+                });
+                self.forced.len() - 1
+            }
+        };
+        let force = &mut self.forced[index];
+        for (i, m) in mask.iter().enumerate() {
+            force.value[i] = (force.value[i] & !m) | (value[i] & m);
+            force.mask[i] |= m;
+        }
+        // SAFETY: `target` points into `self.ir`'s buffers.
+        unsafe { force.apply() };
+        if let Some(pair) = pair {
+            let nb = target.native_bytes;
+            unsafe { write_force_pair(pair, nb, &force.mask[..nb], &force.value) };
+        }
+        self.comb_dirty = true;
+        Ok(())
+    }
+
+    /// Releases the bits `target` selects; see `release_signal`.
+    pub(crate) fn release_target(&mut self, target: &ForceTarget) -> Result<(), SimulatorError> {
+        let pair = self.force_pair(target)?;
+        let Some(index) = self.forced.iter().position(|x| x.ptr == target.ptr) else {
+            return Ok(());
+        };
+        let (mask, _) = self.force_bytes(target, None);
+        let force = &mut self.forced[index];
+        for (i, m) in mask.iter().enumerate() {
+            force.mask[i] &= !m;
+            force.value[i] &= force.mask[i];
+        }
+        if let Some(pair) = pair {
+            let nb = target.native_bytes;
+            unsafe { write_force_pair(pair, nb, &force.mask[..nb], &force.value) };
+        }
+        if force.mask.iter().all(|x| *x == 0) {
+            self.forced.swap_remove(index);
+        }
+        self.comb_dirty = true;
+        Ok(())
+    }
+
+    /// The force pair of a comb `target`, `None` for an FF: the simulator
+    /// itself pins those after each commit.
+    fn force_pair(&mut self, target: &ForceTarget) -> Result<Option<*mut u8>, SimulatorError> {
+        if self
+            .ir
+            .ff_values
+            .as_ptr_range()
+            .contains(&(target.ptr as *const u8))
+        {
+            return Ok(None);
+        }
+        let pairs = self.force_pairs.get_or_insert_with(|| {
+            let mut pairs = HashMap::default();
+            collect_force_pairs(&self.ir.module_variables, &mut pairs);
+            pairs
+        });
+        match pairs.get(&target.ptr) {
+            Some(x) => Ok(Some(*x)),
+            None => Err(SimulatorError::UnforceableSignal {
+                path: find_path_by_ptr(&self.ir.module_variables, target.ptr, "")
+                    .unwrap_or_default(),
+            }),
+        }
+    }
+
+    /// `(mask, value)` in storage layout for the bits `target` selects, the
+    /// mask repeated over the X/Z half so X and Z are forced as well.
+    fn force_bytes(&self, target: &ForceTarget, value: Option<Value>) -> (Vec<u8>, Vec<u8>) {
+        let use_4state = self.ir.use_4state;
+        let nb = target.native_bytes;
+        let width = target.width;
+        let (msb, lsb) = target.select.unwrap_or((width - 1, 0));
+        let ones = (BigUint::one() << (msb - lsb + 1)) - BigUint::one();
+        let mask = Value::new_biguint(ones << lsb, width, false);
+        let size = value_size(nb, use_4state);
+        let mut mask_bytes = vec![0; size];
+        let mut value_bytes = vec![0; size];
+        unsafe {
+            write_native_value(mask_bytes.as_mut_ptr(), nb, use_4state, &mask);
+        }
+        if use_4state {
+            mask_bytes.copy_within(..nb, nb);
+        }
+        if let Some(value) = value {
+            let mut full = Value::new(0, width, false);
+            full.assign(value, msb, lsb);
+            unsafe {
+                write_native_value(value_bytes.as_mut_ptr(), nb, use_4state, &full);
             }
         }
-        None
+        (mask_bytes, value_bytes)
+    }
+
+    /// Reads `signal` as LSB-first 64-bit words. `mask_xz` receives the X/Z
+    /// bits (all zero in a 2-state simulation). Words beyond the signal width
+    /// are zeroed; a short slice receives the low words only.
+    pub fn read_signal_words(
+        &mut self,
+        signal: &SignalRef,
+        words: &mut [u64],
+        mask_xz: Option<&mut [u64]>,
+    ) {
+        use crate::component::runtime::{value_to_mask_xz_into, value_to_words_into};
+
+        let value = self.read_signal(signal);
+        let mut buf = Vec::new();
+        value_to_words_into(&value, words.len(), &mut buf);
+        words.copy_from_slice(&buf[..words.len()]);
+        if let Some(mask_xz) = mask_xz {
+            value_to_mask_xz_into(&value, mask_xz.len(), &mut buf);
+            mask_xz.copy_from_slice(&buf[..mask_xz.len()]);
+        }
+    }
+
+    /// Writes LSB-first 64-bit words to `signal`; see `write_signal`. Missing
+    /// high words read as zero and a missing `mask_xz` writes a 2-state value.
+    pub fn write_signal_words(
+        &mut self,
+        signal: &SignalRef,
+        words: &[u64],
+        mask_xz: Option<&[u64]>,
+    ) {
+        let value = signal.words_value(words, mask_xz);
+        self.write_signal(signal, value);
+    }
+
+    /// Forces `signal` to LSB-first 64-bit words; see `force_signal` and
+    /// `write_signal_words`.
+    pub fn force_signal_words(
+        &mut self,
+        signal: &SignalRef,
+        words: &[u64],
+        mask_xz: Option<&[u64]>,
+    ) -> Result<(), SimulatorError> {
+        let value = signal.words_value(words, mask_xz);
+        self.force_signal(signal, value)
     }
 
     /// Watch-path resolver that collects EVERY match (generate
@@ -624,14 +952,26 @@ impl Simulator {
     /// reset asserting into a gated-off domain).  Components have a reset
     /// hook of their own, so they are staged and fired with the reset event.
     pub fn step_in_reset(&mut self, clock: &Event, reset: &Event, assertion_edge: bool) {
+        self.step_events_in_reset(std::slice::from_ref(clock), clock, reset, assertion_edge);
+    }
+
+    /// [`Self::step_events`] with `clock`, one of `events`, taken while
+    /// `reset` is asserted; see [`Self::step_in_reset`].
+    pub fn step_events_in_reset(
+        &mut self,
+        events: &[Event],
+        clock: &Event,
+        reset: &Event,
+        assertion_edge: bool,
+    ) {
         if assertion_edge {
             self.pending_assertion_edge = Some(reset.clone());
         }
         if self.components.is_empty() {
-            self.step(clock);
+            self.step_events(events);
         } else {
             self.component_event_override = Some((clock.clone(), reset.clone()));
-            self.step(clock);
+            self.step_events(events);
             self.component_event_override = None;
         }
         self.pending_assertion_edge = None;
@@ -646,6 +986,12 @@ impl Simulator {
     }
 
     pub fn step(&mut self, event: &Event) {
+        self.step_events(std::slice::from_ref(event));
+    }
+
+    /// Edges of several clocks at one time, taken as one step: every domain
+    /// samples the values from before the edges, then all commit together.
+    pub fn step_events(&mut self, events: &[Event]) {
         // A missing init_components call would let the run pass vacuously
         // (no hook ever fires); catch that bug in debug builds without
         // paying an assert on every step.
@@ -661,13 +1007,13 @@ impl Simulator {
 
         // Common case (no derived clocks) skips the edge-detect loop.
         if self.ir.derived_clock_schedule.is_empty() {
-            self.step_legacy(event);
+            self.step_legacy(events);
         } else {
-            self.step_with_derived_clocks(event);
+            self.step_with_derived_clocks(events);
         }
     }
 
-    fn step_legacy(&mut self, event: &Event) {
+    fn step_legacy(&mut self, events: &[Event]) {
         // Install before settle_comb so comb-scope FF writes
         // (`--disable-ff-opt` path) hit a live log.
         // SAFETY: buffer outlives every dispatch_stmt_fast call below
@@ -689,13 +1035,13 @@ impl Simulator {
             }
         }
 
-        self.step_event_inner(event);
+        self.step_event_inner(events);
 
         clear_event_write_log();
         self.comb_dirty = true;
 
         if !self.watch_vars.is_empty() {
-            let tag = match event {
+            let tag = match &events[0] {
                 Event::Clock(_) => "clk",
                 Event::Reset(_) => "rst",
                 _ => "evt",
@@ -706,22 +1052,28 @@ impl Simulator {
         self.dump_variables();
     }
 
-    /// Fire `event_statements[event]` then `ff_commit_from_log`.  The
-    /// caller is responsible for `set_event_write_log`, `settle_comb`,
-    /// and `dump_variables`.
-    fn step_event_inner(&mut self, event: &Event) {
+    /// Fire `event_statements` of each of `events` then one
+    /// `ff_commit_from_log`.  The caller is responsible for
+    /// `set_event_write_log`, `settle_comb`, and `dump_variables`.
+    fn step_event_inner(&mut self, events: &[Event]) {
         let has_components = !self.components.is_empty();
         if has_components {
-            self.stage_components(event);
+            for event in events {
+                self.stage_components(event);
+            }
+        }
+        for event in events {
+            self.eval_event_stmts(event);
         }
-        self.eval_event_stmts(event);
         // The async-reset assertion edge, if this step carries one.
         if let Some(reset) = self.pending_assertion_edge.take() {
             self.eval_event_stmts(&reset);
         }
         self.commit_event_log();
         if has_components {
-            self.fire_components(event);
+            for event in events {
+                self.fire_components(event);
+            }
         }
     }
 
@@ -856,6 +1208,108 @@ impl Simulator {
         self.components = components;
     }
 
+    /// Writes the complete run state to `path`: variable storage, time and
+    /// cycle counters, derived-clock edge state, `$tb::random` generators
+    /// and each component's `save_state` record. Taken between clock
+    /// cycles, when no event is in flight.
+    pub fn save_checkpoint(&mut self, path: &Path) -> Result<(), SimulatorError> {
+        if self.components_pending {
+            return Err(checkpoint::error(path, "components are not initialized"));
+        }
+        let mut components = Vec::with_capacity(self.components.len());
+        for c in &mut self.components {
+            let Some((cycle, data)) = c.save_state() else {
+                let messages = c.host.take_failures().join("; ");
+                return Err(checkpoint::error(
+                    path,
+                    format!(
+                        "component `{}` failed to save its state: {messages}",
+                        c.name
+                    ),
+                ));
+            };
+            components.push(checkpoint::ComponentState {
+                name: c.name.clone(),
+                cycle,
+                data,
+            });
+        }
+        let ckpt = checkpoint::Checkpoint {
+            top: veryl_parser::resource_table::get_str_value(self.ir.name).unwrap_or_default(),
+            use_4state: self.ir.use_4state,
+            time: self.time,
+            cycle: self.cycle_count,
+            derived_clocks: self.prev_derived_clock_values.clone(),
+            random: crate::random_table::save(),
+            ff_values: self.ir.ff_values.to_vec(),
+            comb_values: self.ir.comb_values.to_vec(),
+            components,
+        };
+        ckpt.write(path)
+    }
+
+    /// Replaces the run state with a checkpoint written by
+    /// [`Self::save_checkpoint`] for the same design. Components must be
+    /// initialized first; their `restore_state` hook runs after `on_init`.
+    pub fn restore_checkpoint(&mut self, path: &Path) -> Result<(), SimulatorError> {
+        if self.components_pending {
+            return Err(checkpoint::error(path, "components are not initialized"));
+        }
+        let ckpt = checkpoint::Checkpoint::read(path)?;
+        let top = veryl_parser::resource_table::get_str_value(self.ir.name).unwrap_or_default();
+        if ckpt.top != top {
+            return Err(checkpoint::error(
+                path,
+                format!("saved from module `{}`, not `{top}`", ckpt.top),
+            ));
+        }
+        if ckpt.use_4state != self.ir.use_4state
+            || ckpt.ff_values.len() != self.ir.ff_values.len()
+            || ckpt.comb_values.len() != self.ir.comb_values.len()
+            || ckpt.derived_clocks.len() != self.prev_derived_clock_values.len()
+        {
+            return Err(checkpoint::error(
+                path,
+                "the design or simulator configuration changed since it was saved",
+            ));
+        }
+        let names: Vec<_> = self.components.iter().map(|c| c.name.as_str()).collect();
+        let saved: Vec<_> = ckpt.components.iter().map(|c| c.name.as_str()).collect();
+        if names != saved {
+            return Err(checkpoint::error(
+                path,
+                "component instances differ from the saved run",
+            ));
+        }
+        crate::random_table::restore(&ckpt.random).map_err(|e| checkpoint::error(path, e))?;
+
+        // JIT code holds pointers into the storage, so copy in place.
+        self.ir.ff_values.copy_from_slice(&ckpt.ff_values);
+        self.ir.comb_values.copy_from_slice(&ckpt.comb_values);
+        self.prev_derived_clock_values = ckpt.derived_clocks;
+        self.time = ckpt.time;
+        self.cycle_count = ckpt.cycle;
+        // Cone-gate shadows describe the overwritten comb inputs.
+        *self.ir.cone_gate_state.borrow_mut() = None;
+        self.comb_dirty = true;
+
+        for (c, state) in self.components.iter_mut().zip(&ckpt.components) {
+            c.host.time = self.time;
+            if !c.restore_state(state.cycle, &state.data) {
+                let messages = c.host.take_failures().join("; ");
+                return Err(checkpoint::error(
+                    path,
+                    format!(
+                        "component `{}` failed to restore its state: {messages}",
+                        c.name
+                    ),
+                ));
+            }
+            c.drain_logs();
+        }
+        Ok(())
+    }
+
     /// Evaluate `event_statements[event]` into the write log without
     /// committing, so simultaneous events (master + gated clocks) share
     /// one pre-commit state and one commit.
@@ -952,6 +1406,7 @@ impl Simulator {
         let ff_start = Instant::now();
 
         ff_commit_from_log(&mut self.ir.ff_values, &self.ir.write_log_buffer);
+        self.apply_forces();
 
         if self.write_log_diag.enabled {
             let n = self.ir.write_log_buffer.count();
@@ -973,10 +1428,10 @@ impl Simulator {
         }
     }
 
-    /// Toggles master 0→1, fires the event + chained derived-clock
+    /// Toggles the masters 0→1, fires the events + chained derived-clock
     /// events, then restores master=0 so `prev_derived_clock_values`
     /// samples on a consistent baseline.
-    fn step_with_derived_clocks(&mut self, event: &Event) {
+    fn step_with_derived_clocks(&mut self, events: &[Event]) {
         // SAFETY: same as `step_legacy`; one install covers settle_comb
         // plus every step_event_inner fire in this step.
         unsafe {
@@ -996,24 +1451,27 @@ impl Simulator {
             self.dump_watch("after_settle");
         }
 
-        let master_id_opt = match event {
-            Event::Clock(id) | Event::Reset(id) => {
-                let id = *id;
-                let is_master = self
-                    .ir
+        let master_ids: SmallVec<[VarId; 2]> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Clock(id) | Event::Reset(id) => Some(*id),
+                _ => None,
+            })
+            .filter(|id| {
+                self.ir
                     .derived_clock_schedule
                     .master_input_clocks
-                    .contains(&id);
-                if is_master { Some(id) } else { None }
-            }
-            _ => None,
-        };
+                    .contains(id)
+            })
+            .collect();
 
         let has_eval_chunk = !self.ir.derived_clock_eval_stmts.is_empty();
 
         // Master high → gated-clock exprs see the rising edge.
-        if let Some(id) = master_id_opt {
-            self.set_input_clock_bit(id, 1);
+        if !master_ids.is_empty() {
+            for &id in &master_ids {
+                self.set_input_clock_bit(id, 1);
+            }
             if has_eval_chunk {
                 self.ir.partial_settle(&mut self.mask_cache);
             }
@@ -1029,7 +1487,7 @@ impl Simulator {
         let n = self.ir.derived_clock_schedule.clocks.len();
         let mut fired_mask: Vec<bool> = vec![false; n];
         let mut pre_fire: SmallVec<[usize; 8]> = SmallVec::new();
-        if master_id_opt.is_some() {
+        if !master_ids.is_empty() {
             for i in 0..n {
                 let clk = &self.ir.derived_clock_schedule.clocks[i];
                 if clk.current_offset.is_ff() || !clk.master_gated {
@@ -1041,12 +1499,16 @@ impl Simulator {
             }
         }
 
-        self.stage_components(event);
+        for event in events {
+            self.stage_components(event);
+        }
         for &i in &pre_fire {
             let vid = self.ir.derived_clock_schedule.clocks[i].var_id;
             self.stage_components(&Event::Clock(vid));
         }
-        self.eval_event_stmts(event);
+        for event in events {
+            self.eval_event_stmts(event);
+        }
         for &i in &pre_fire {
             let vid = self.ir.derived_clock_schedule.clocks[i].var_id;
             if watch_enabled {
@@ -1061,7 +1523,9 @@ impl Simulator {
             self.eval_event_stmts(&reset);
         }
         self.commit_event_log();
-        self.fire_components(event);
+        for event in events {
+            self.fire_components(event);
+        }
         for &i in &pre_fire {
             let vid = self.ir.derived_clock_schedule.clocks[i].var_id;
             self.fire_components(&Event::Clock(vid));
@@ -1131,7 +1595,7 @@ impl Simulator {
                     if watch_enabled {
                         self.dump_watch(&format!("before_derived[{i}]"));
                     }
-                    self.step_event_inner(&Event::Clock(vid));
+                    self.step_event_inner(&[Event::Clock(vid)]);
                     if watch_enabled {
                         self.dump_watch(&format!("after_derived[{i}]"));
                     }
@@ -1143,8 +1607,10 @@ impl Simulator {
 
         // master=0 + resettle so the prev snapshot matches the next
         // step's starting baseline.
-        if let Some(id) = master_id_opt {
-            self.set_input_clock_bit(id, 0);
+        if !master_ids.is_empty() {
+            for &id in &master_ids {
+                self.set_input_clock_bit(id, 0);
+            }
             if has_eval_chunk {
                 self.ir.partial_settle(&mut self.mask_cache);
             }
@@ -1332,7 +1798,9 @@ impl Simulator {
     }
 
     pub fn dump_start(&mut self) {
-        if let Some(dump) = &mut self.dump {
+        if let Some(dump) = &mut self.dump
+            && dump.records(self.cycle_count)
+        {
             dump.begin_dumpvars();
             dump.dump_all_vars(&self.dump_vars, self.ir.use_4state);
             Self::dump_trace_vars(dump, &self.trace_dump_vars, &self.components);
@@ -1341,16 +1809,31 @@ impl Simulator {
     }
 
     pub fn dump_variables(&mut self) {
-        if self.dump.is_some() {
-            if self.comb_dirty {
-                self.do_settle_comb();
-                self.comb_dirty = false;
-            }
-            let dump = self.dump.as_mut().unwrap();
+        if self.dump.is_none() && self.toggles.is_none() {
+            return;
+        }
+        if self.comb_dirty {
+            self.do_settle_comb();
+            self.comb_dirty = false;
+        }
+        if let Some(dump) = self.dump.as_mut()
+            && dump.records(self.cycle_count)
+        {
             dump.timestamp(self.time);
             dump.dump_all_vars(&self.dump_vars, self.ir.use_4state);
             Self::dump_trace_vars(dump, &self.trace_dump_vars, &self.components);
         }
+        if let Some(toggles) = self.toggles.as_mut() {
+            toggles.sample();
+        }
+    }
+
+    /// `$dumpon()` / `$dumpoff()`: resumes or stops the waveform.  Every
+    /// dump records all variables, so resuming needs no dump of its own.
+    pub fn set_dump_on(&mut self, on: bool) {
+        if let Some(dump) = self.dump.as_mut() {
+            dump.set_on(on);
+        }
     }
 
     fn dump_trace_vars(
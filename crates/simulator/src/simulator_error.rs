@@ -29,6 +29,18 @@ pub enum SimulatorError {
     #[error("{message}")]
     IoError { message: String },
 
+    #[diagnostic(severity(Error), code(checkpoint))]
+    #[error("checkpoint {path}: {message}")]
+    Checkpoint { path: String, message: String },
+
+    #[diagnostic(
+        severity(Error),
+        code(unforceable_signal),
+        help("a comb variable can be forced only in a simulator built with `Config::force`")
+    )]
+    #[error("\"{path}\" can't be forced")]
+    UnforceableSignal { path: String },
+
     #[diagnostic(severity(Error), code(unresolved_expression))]
     #[error("unresolved expression")]
     UnresolvedExpression {
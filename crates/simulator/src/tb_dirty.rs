@@ -297,6 +297,11 @@ fn collect_clean(
                 collect_clean(else_block, spans, clean);
             }
             TestbenchStatement::For { body, .. } => collect_clean(body, spans, clean),
+            TestbenchStatement::Fork { threads } => {
+                for thread in threads {
+                    collect_clean(thread, spans, clean);
+                }
+            }
             // Clock/reset drive design nets; the rest either write through
             // paths this filter does not model or advance time.  All keep the
             // unconditional dirty mark at their own call sites.
@@ -1,16 +1,19 @@
 use crate::HashMap;
 use crate::assert_buffer;
+use crate::debugger::{Debugger, Site};
 use crate::ir::{
-    ComponentArg, Event, Expression, Ir, ModuleVariables, RuntimeForRange, Statement,
+    ComponentArg, Event, Expression, ForceTarget, Ir, ModuleVariables, RuntimeForRange, Statement,
     SystemFunctionCall, TbMethodKind, Value, VarId, VarPath, format_assert_message, format_output,
     write_native_value,
 };
 use crate::simulator::Simulator;
 use crate::simulator_error::SimulatorError;
 use crate::wave_dumper::WaveDumper;
+use std::path::{Path, PathBuf};
 use veryl_analyzer::ir::{AssertKind, ControlFlow};
 use veryl_analyzer::value::MaskCache;
 use veryl_parser::resource_table::StrId;
+use veryl_parser::token_range::TokenRange;
 
 pub enum TestbenchStatement {
     /// Normal simulator statement (assign, $display, etc.)
@@ -98,6 +101,27 @@ pub enum TestbenchStatement {
         body: Vec<TestbenchStatement>,
         loop_var: Option<LoopVariable>,
     },
+    /// `$wait(duration)`
+    Wait { duration: Expression },
+    /// `$wait_until(condition)`
+    WaitUntil { condition: Expression },
+    /// `$force(target, value)`
+    Force {
+        target: ForceTarget,
+        value: Expression,
+    },
+    /// `$release(target)`
+    Release { target: ForceTarget },
+    /// Debugger stop site ahead of the statement at the token.
+    Line { token: TokenRange },
+    /// `#[fork]` block: one statement list per thread, joined at its end.
+    Fork {
+        threads: Vec<Vec<TestbenchStatement>>,
+    },
+    /// `$dumpon()`
+    DumpOn,
+    /// `$dumpoff()`
+    DumpOff,
     /// $finish
     Finish,
 }
@@ -111,6 +135,80 @@ pub struct LoopVariable {
     pub range: RuntimeForRange,
 }
 
+impl LoopVariable {
+    fn write(&self, i: u64) {
+        let val = Value::new(i, self.width, self.signed);
+        unsafe {
+            write_native_value(self.ptr, self.native_bytes, self.use_4state, &val);
+        }
+    }
+}
+
+/// Iteration state of a testbench `for` loop.
+enum LoopCursor {
+    Forward {
+        i: u64,
+        end: u64,
+        step: u64,
+        op: Option<veryl_analyzer::ir::Op>,
+    },
+    /// Mirrors the emitted SV `for (int i = hi - 1; i >= lo; i -= step)`;
+    /// i64 makes underflow past lo terminate.
+    Reverse { i: i64, lo: i64, step: i64 },
+}
+
+impl LoopCursor {
+    fn new(sim: &mut Simulator, range: &RuntimeForRange) -> Self {
+        let start = range.start.eval(&mut sim.mask_cache);
+        let mut end = range.end.eval(&mut sim.mask_cache);
+        if range.inclusive {
+            end = end.saturating_add(1);
+        }
+        if range.reverse {
+            LoopCursor::Reverse {
+                i: end as i64 - 1,
+                lo: start as i64,
+                step: range.step as i64,
+            }
+        } else {
+            LoopCursor::Forward {
+                i: start,
+                end,
+                step: range.step,
+                op: range.op,
+            }
+        }
+    }
+
+    fn current(&self) -> Option<u64> {
+        match self {
+            LoopCursor::Forward { i, end, .. } => (i < end).then_some(*i),
+            LoopCursor::Reverse { i, lo, .. } => (i >= lo).then_some(*i as u64),
+        }
+    }
+
+    fn advance(&mut self) {
+        match self {
+            LoopCursor::Forward {
+                i,
+                end,
+                step,
+                op: Some(op),
+            } => {
+                // Progress guard: a stalled or faulting step would spin
+                // forever (const-bound cases are rejected at analysis;
+                // runtime bounds reach here).
+                match op.eval(*i as usize, *step as usize) {
+                    Some(n) if n as u64 > *i => *i = n as u64,
+                    _ => *i = *end,
+                }
+            }
+            LoopCursor::Forward { i, step, .. } => *i += *step,
+            LoopCursor::Reverse { i, step, .. } => *i -= *step,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum TestResult {
     Pass,
@@ -171,10 +269,14 @@ fn collect_tb_insts(
                         clock_insts.push(*inst);
                     }
                 }
-                TbMethodKind::ResetAssert { .. } => {
+                // Its clock is edged by the reset even if nothing calls `next`.
+                TbMethodKind::ResetAssert { clock, .. } => {
                     if !reset_insts.contains(inst) {
                         reset_insts.push(*inst);
                     }
+                    if !clock_insts.contains(clock) {
+                        clock_insts.push(*clock);
+                    }
                 }
                 // File handles, component methods and random generators
                 // drive no clock/reset event.
@@ -201,6 +303,11 @@ fn collect_tb_insts(
                 }
                 collect_tb_insts(&case_stmt.default, clock_insts, reset_insts);
             }
+            Statement::Fork(threads) => {
+                for thread in threads {
+                    collect_tb_insts(thread, clock_insts, reset_insts);
+                }
+            }
             _ => {}
         }
     }
@@ -262,11 +369,226 @@ fn collect_clock_periods(stmts: &[Statement], periods: &mut HashMap<StrId, u64>)
                 }
                 collect_clock_periods(&case_stmt.default, periods);
             }
+            Statement::Fork(threads) => {
+                for thread in threads {
+                    collect_clock_periods(thread, periods);
+                }
+            }
             _ => {}
         }
     }
 }
 
+/// The `$tb::clock_gen` instances a `$wait` or `$wait_until` keeps running,
+/// if the statements contain one.
+fn collect_wait_clocks(stmts: &[Statement]) -> Option<Vec<(StrId, Option<u64>)>> {
+    for stmt in stmts {
+        let found = match stmt {
+            Statement::SystemFunctionCall(
+                SystemFunctionCall::Wait { clocks, .. }
+                | SystemFunctionCall::WaitUntil { clocks, .. },
+            ) => Some(clocks.clone()),
+            Statement::For(for_stmt) => collect_wait_clocks(&for_stmt.body),
+            Statement::If(if_stmt) => collect_wait_clocks(&if_stmt.true_side)
+                .or_else(|| collect_wait_clocks(&if_stmt.false_side)),
+            Statement::Case(case_stmt) => case_stmt
+                .arms
+                .iter()
+                .find_map(|arm| collect_wait_clocks(&arm.body))
+                .or_else(|| collect_wait_clocks(&case_stmt.default)),
+            Statement::Fork(threads) => threads.iter().find_map(|x| collect_wait_clocks(x)),
+            _ => None,
+        };
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+/// Clock scheduler for a testbench that uses `$wait`, `$wait_until` or
+/// `#[fork]`.
+///
+/// Every `$tb::clock_gen` runs freely at its own period from the time the
+/// testbench first advances, and edges of all clocks are taken in timestamp
+/// order; at equal times, falling edges go first, and the rising edges are
+/// taken as one step, so no domain sees another's update from the same
+/// instant. `$wait(t)` advances by `t`
+/// time units, and `clk.next(n)` until just before the `n + 1`-th rising edge
+/// of `clk` from now, as it does without the wheel.
+pub struct TimeWheel {
+    clocks: Vec<WheelClock>,
+    started: bool,
+}
+
+struct WheelClock {
+    event: Event,
+    high_time: u64,
+    low_time: u64,
+    /// Time of the next edge.
+    next: u64,
+    /// Whether the next edge is the rising one.
+    rising: bool,
+}
+
+impl TimeWheel {
+    /// `clocks` pairs each clock with its period.
+    pub fn new(clocks: Vec<(Event, u64)>) -> Self {
+        let clocks = clocks
+            .into_iter()
+            .map(|(event, period)| {
+                let (high_time, low_time) = compute_half_periods(period);
+                WheelClock {
+                    event,
+                    high_time,
+                    low_time,
+                    next: 0,
+                    rising: true,
+                }
+            })
+            .collect();
+        Self {
+            clocks,
+            started: false,
+        }
+    }
+
+    fn start(&mut self, now: u64) {
+        if !self.started {
+            for clock in &mut self.clocks {
+                clock.next = now;
+                clock.rising = true;
+            }
+            self.started = true;
+        }
+    }
+
+    /// The time just before the `count + 1`-th rising edge of `clock`, or
+    /// `None` if the wheel does not drive it.
+    fn after_edges(&mut self, now: u64, clock: &Event, count: u64) -> Option<u64> {
+        self.start(now);
+        let clock = self.clocks.iter().find(|x| &x.event == clock)?;
+        let rise = if clock.rising {
+            clock.next
+        } else {
+            clock.next + clock.low_time
+        };
+        Some(rise + count * (clock.high_time + clock.low_time))
+    }
+
+    /// Takes every edge before `until`, then sets the time to `until`.
+    ///
+    /// Rising edges of `reset.1` are taken with `reset.0` asserted, and do
+    /// not count as testbench cycles.
+    fn run(
+        &mut self,
+        sim: &mut Simulator,
+        until: u64,
+        reset: Option<(&Event, &Event)>,
+    ) -> ExecResult {
+        self.start(sim.time);
+        let mut resets: Vec<_> = reset
+            .map(|(reset, clock)| HeldReset::new(reset.clone(), clock.clone()))
+            .into_iter()
+            .collect();
+        loop {
+            match self.take_edge(sim, until, &mut resets) {
+                Edge::Idle => break,
+                Edge::Taken => {}
+                Edge::Stop(result) => return result,
+            }
+        }
+        sim.time = until;
+        ExecResult::Continue
+    }
+
+    /// Takes the earliest edges before `until`, if there are any: the
+    /// falling ones, then every rising one at that time as one step.
+    ///
+    /// A rising edge of a clock in `resets` is taken with that reset
+    /// asserted, and does not count as a testbench cycle.
+    fn take_edge(&mut self, sim: &mut Simulator, until: u64, resets: &mut [HeldReset]) -> Edge {
+        let Some(now) = self
+            .clocks
+            .iter()
+            .map(|x| x.next)
+            .filter(|&x| x < until)
+            .min()
+        else {
+            return Edge::Idle;
+        };
+        let has_dump = sim.dump.is_some();
+        sim.time = now;
+        let mut falling = false;
+        let mut rising = Vec::new();
+        for clock in self.clocks.iter_mut().filter(|x| x.next == now) {
+            clock.rising = !clock.rising;
+            if clock.rising {
+                clock.next += clock.low_time;
+                falling = true;
+                if has_dump && let Some(id) = clock.event.var_id() {
+                    sim.set_var_by_id(&id, Value::new(0, 1, false));
+                }
+            } else {
+                clock.next += clock.high_time;
+                rising.push(clock.event.clone());
+            }
+        }
+        if has_dump && falling {
+            sim.dump_variables();
+        }
+        if rising.is_empty() {
+            return Edge::Taken;
+        }
+
+        if has_dump {
+            for id in rising.iter().filter_map(|x| x.var_id()) {
+                sim.set_var_by_id(&id, Value::new(1, 1, false));
+            }
+        }
+        let mut cycles = rising.len();
+        match resets.iter_mut().find(|x| rising.contains(&x.clock)) {
+            Some(held) => {
+                sim.step_events_in_reset(&rising, &held.clock, &held.reset, held.assertion_edge);
+                held.assertion_edge = false;
+                cycles -= 1;
+            }
+            None => sim.step_events(&rising),
+        }
+        for _ in 0..cycles {
+            if let Some(result) = end_cycle(sim) {
+                return Edge::Stop(result);
+            }
+        }
+        Edge::Taken
+    }
+}
+
+enum Edge {
+    /// No edge is left before the limit.
+    Idle,
+    Taken,
+    Stop(ExecResult),
+}
+
+/// A reset held asserted while the wheel takes edges of its clock.
+struct HeldReset {
+    reset: Event,
+    clock: Event,
+    /// Whether the next rising edge is the first one of the assertion.
+    assertion_edge: bool,
+}
+
+impl HeldReset {
+    fn new(reset: Event, clock: Event) -> Self {
+        Self {
+            reset,
+            clock,
+            assertion_edge: true,
+        }
+    }
+}
+
 pub fn build_clock_periods(
     event_statements: &HashMap<Event, Vec<Statement>>,
 ) -> HashMap<StrId, u64> {
@@ -402,6 +724,36 @@ fn convert_stmt(
             args: args.clone(),
         },
         Statement::SystemFunctionCall(SystemFunctionCall::Finish) => TestbenchStatement::Finish,
+        Statement::SystemFunctionCall(SystemFunctionCall::DumpOn) => TestbenchStatement::DumpOn,
+        Statement::SystemFunctionCall(SystemFunctionCall::DumpOff) => TestbenchStatement::DumpOff,
+        Statement::SystemFunctionCall(SystemFunctionCall::Wait { duration, .. }) => {
+            TestbenchStatement::Wait {
+                duration: duration.clone(),
+            }
+        }
+        Statement::SystemFunctionCall(SystemFunctionCall::Line { token }) => {
+            TestbenchStatement::Line { token: *token }
+        }
+        Statement::SystemFunctionCall(SystemFunctionCall::WaitUntil { condition, .. }) => {
+            TestbenchStatement::WaitUntil {
+                condition: condition.clone(),
+            }
+        }
+        Statement::SystemFunctionCall(SystemFunctionCall::Force { target, value }) => {
+            TestbenchStatement::Force {
+                target: *target,
+                value: value.clone(),
+            }
+        }
+        Statement::SystemFunctionCall(SystemFunctionCall::Release { target }) => {
+            TestbenchStatement::Release { target: *target }
+        }
+        Statement::Fork(threads) => TestbenchStatement::Fork {
+            threads: threads
+                .iter()
+                .map(|x| convert_stmts(x, event_map, clock_periods, default_reset_duration))
+                .collect(),
+        },
         Statement::If(if_stmt) => {
             let then_block = convert_stmts(
                 &if_stmt.true_side,
@@ -483,10 +835,33 @@ fn convert_stmts(
 }
 
 pub fn run_testbench(sim: &mut Simulator, stmts: &[TestbenchStatement]) -> TestResult {
+    run_testbench_restored(sim, stmts, None)
+}
+
+/// Like [`run_testbench`], but first resumes from the checkpoint at
+/// `restore`: the testbench runs from its start against the saved design
+/// state.
+pub fn run_testbench_restored(
+    sim: &mut Simulator,
+    stmts: &[TestbenchStatement],
+    restore: Option<&Path>,
+) -> TestResult {
     assert_buffer::reset();
+    crate::cover_table::reset();
     crate::file_table::reset();
     crate::random_table::reset(sim.ir.seed);
-    let result: TestResult = exec(sim, stmts).into();
+    // After the table resets, which would otherwise discard the restored
+    // generators.
+    if let Some(path) = restore
+        && let Err(err) = sim.restore_checkpoint(path)
+    {
+        return TestResult::Fail(err.to_string());
+    }
+    let result = match debug_stop(sim, Site::Start) {
+        Some(quit) => quit,
+        None => exec(sim, stmts),
+    };
+    let result: TestResult = result.into();
     // End-of-test component hooks may still record failures.
     sim.finish_components();
     let component_failures = sim.take_component_failures();
@@ -509,11 +884,18 @@ pub fn run_testbench(sim: &mut Simulator, stmts: &[TestbenchStatement]) -> TestR
         Some(msg) => TestResult::Fail(format!("{msg}\n{}", component_failures.join("\n"))),
     };
     // Component behavior may be seed-dependent; make failures reproducible.
-    match result {
+    let result = match result {
         TestResult::Fail(msg) if sim.components.is_empty() => TestResult::Fail(msg),
         TestResult::Fail(msg) => TestResult::Fail(format!("{msg}\n(seed: {})", sim.ir.seed)),
         pass => pass,
-    }
+    };
+    // The final state stays inspectable, a failed one in particular.
+    let failure = match &result {
+        TestResult::Fail(msg) => Some(msg.as_str()),
+        TestResult::Pass => None,
+    };
+    debug_stop(sim, Site::End(failure));
+    result
 }
 
 /// What a run needs before its first cycle that the ELABORATED DESIGN alone
@@ -534,12 +916,58 @@ fn derive_testbench(
         .ok_or_else(|| SimulatorError::no_initial_block(module_name, &token))?;
 
     let tb_stmts = convert_initial_to_testbench(initial_stmts, &event_map, &clock_periods, 3);
+    // Fork threads that only count clock edges still need the wheel to
+    // interleave them; it then runs every clock of the testbench.
+    let wait_clocks = collect_wait_clocks(initial_stmts).or_else(|| {
+        has_fork(&tb_stmts).then(|| {
+            let mut clocks: Vec<_> = event_map
+                .iter()
+                .filter(|(_, event)| matches!(event, Event::Clock(_)))
+                .map(|(name, _)| (*name, clock_periods.get(name).copied()))
+                .collect();
+            clocks.sort_by_key(|(name, _)| *name);
+            clocks
+        })
+    });
+    sim.time_wheel = wait_clocks.map(|clocks| {
+        let clocks = clocks
+            .into_iter()
+            .filter_map(|(name, period)| {
+                let id = find_var_id_by_name(&sim.ir.module_variables, name)?;
+                Some((Event::Clock(id), period.unwrap_or(2)))
+            })
+            .collect();
+        TimeWheel::new(clocks)
+    });
     // Statements that write only testbench-private state do not have to
     // re-settle the design; see `tb_dirty`.
     sim.tb_dirty = crate::tb_dirty::TbDirtyFilter::build(&sim.ir, &tb_stmts);
     Ok(tb_stmts)
 }
 
+fn has_fork(stmts: &[TestbenchStatement]) -> bool {
+    stmts.iter().any(|stmt| match stmt {
+        TestbenchStatement::Fork { .. } => true,
+        TestbenchStatement::If {
+            then_block,
+            else_block,
+            ..
+        } => has_fork(then_block) || has_fork(else_block),
+        TestbenchStatement::For { body, .. } => has_fork(body),
+        _ => false,
+    })
+}
+
+/// Checkpoint handling for a native test run.
+#[derive(Clone, Debug, Default)]
+pub struct CheckpointOptions {
+    /// Start from this checkpoint instead of the initial design state.
+    pub restore: Option<PathBuf>,
+    /// Save a checkpoint to the path once the testbench completes the given
+    /// clock cycle (counted across a restore).
+    pub save_at: Option<(u64, PathBuf)>,
+}
+
 /// Run a native testbench from a simulator IR.
 ///
 /// `module_name` must be pre-resolved from `ir.name` on the main thread
@@ -560,7 +988,14 @@ pub fn run_native_testbench_capped(
     module_name: String,
     max_cycles: Option<u64>,
 ) -> Result<TestResult, SimulatorError> {
-    run_native_testbench_timed(ir, dump, module_name, max_cycles).map(|(r, _)| r)
+    run_native_testbench_timed(
+        ir,
+        dump,
+        module_name,
+        max_cycles,
+        &CheckpointOptions::default(),
+    )
+    .map(|(r, _)| r)
 }
 
 /// Like [`run_native_testbench_capped`], and additionally reports how long
@@ -571,11 +1006,37 @@ pub fn run_native_testbench_timed(
     dump: Option<WaveDumper>,
     module_name: String,
     max_cycles: Option<u64>,
+    checkpoint: &CheckpointOptions,
+) -> Result<(TestResult, std::time::Duration), SimulatorError> {
+    run_native(ir, dump, module_name, max_cycles, checkpoint, None)
+}
+
+/// Like [`run_native_testbench`], but under `debugger`.  The IR should be
+/// built with `Config::debug`, or the testbench has no statement to stop at.
+pub fn run_native_testbench_debug(
+    ir: Ir,
+    dump: Option<WaveDumper>,
+    module_name: String,
+    checkpoint: &CheckpointOptions,
+    debugger: Debugger,
+) -> Result<TestResult, SimulatorError> {
+    run_native(ir, dump, module_name, None, checkpoint, Some(debugger)).map(|(r, _)| r)
+}
+
+fn run_native(
+    ir: Ir,
+    dump: Option<WaveDumper>,
+    module_name: String,
+    max_cycles: Option<u64>,
+    checkpoint: &CheckpointOptions,
+    debugger: Option<Debugger>,
 ) -> Result<(TestResult, std::time::Duration), SimulatorError> {
     // The dump attaches after `init_components` so component trace
     // variables (registered during `create`) land in the waveform header.
     let mut sim = Simulator::new(ir, None);
     sim.cycle_limit = max_cycles;
+    sim.debugger = debugger;
+    sim.checkpoint_at = checkpoint.save_at.clone();
     // Component load/create errors are per-test failures, not simulator
     // errors.
     let seed = sim.ir.seed;
@@ -594,7 +1055,7 @@ pub fn run_native_testbench_timed(
     let derive_el = t_derive.elapsed();
     #[cfg(target_family = "wasm")]
     let derive_el = std::time::Duration::ZERO;
-    let result = run_testbench(&mut sim, &tb_stmts);
+    let result = run_testbench_restored(&mut sim, &tb_stmts, checkpoint.restore.as_deref());
 
     #[cfg(feature = "profile")]
     {
@@ -694,8 +1155,15 @@ fn exec_one(sim: &mut Simulator, stmt: &TestbenchStatement) -> ExecResult {
             } else {
                 1
             };
+            if let Some(mut wheel) = sim.time_wheel.take() {
+                let result = match wheel.after_edges(sim.time, clock, n) {
+                    Some(until) if n > 0 => wheel.run(sim, until, None),
+                    _ => ExecResult::Continue,
+                };
+                sim.time_wheel = Some(wheel);
+                return result;
+            }
             let has_dump = sim.dump.is_some();
-            let has_components = !sim.components.is_empty();
             for _ in 0..n {
                 if has_dump && let Some(id) = clock.var_id() {
                     sim.set_var_by_id(&id, Value::new(1, 1, false));
@@ -709,22 +1177,8 @@ fn exec_one(sim: &mut Simulator, stmt: &TestbenchStatement) -> ExecResult {
                     sim.dump_variables();
                 }
                 sim.time += low_time;
-                // Component-requested termination, checked at cycle end
-                // (after commit and dump).
-                if has_components {
-                    if sim.components_failed() {
-                        return ExecResult::Fail(sim.take_component_failures().join("\n"));
-                    }
-                    if sim.component_finish_requested() {
-                        return ExecResult::Finished;
-                    }
-                }
-                // Stop once the optional clock-cycle cap is reached.
-                if let Some(limit) = sim.cycle_limit {
-                    sim.cycle_count += 1;
-                    if sim.cycle_count >= limit {
-                        return ExecResult::Finished;
-                    }
+                if let Some(result) = end_cycle(sim) {
+                    return result;
                 }
             }
             ExecResult::Continue
@@ -740,11 +1194,22 @@ fn exec_one(sim: &mut Simulator, stmt: &TestbenchStatement) -> ExecResult {
             // the rest of the design keeps clocking through the window, as it
             // does in SystemVerilog, and a reset derived from that net reaches
             // its own `if_reset` too.
-            let has_dump = sim.dump.is_some();
             let reset_id = reset.var_id();
             if let Some(id) = &reset_id {
                 sim.set_reset_level(id, true);
             }
+            if let Some(mut wheel) = sim.time_wheel.take() {
+                let result = match wheel.after_edges(sim.time, clock, *duration) {
+                    Some(until) => wheel.run(sim, until, Some((reset, clock))),
+                    None => ExecResult::Continue,
+                };
+                sim.time_wheel = Some(wheel);
+                if let Some(id) = &reset_id {
+                    sim.set_reset_level(id, false);
+                }
+                return result;
+            }
+            let has_dump = sim.dump.is_some();
             for i in 0..*duration {
                 if has_dump && let Some(id) = clock.var_id() {
                     sim.set_var_by_id(&id, Value::new(1, 1, false));
@@ -781,6 +1246,26 @@ fn exec_one(sim: &mut Simulator, stmt: &TestbenchStatement) -> ExecResult {
             }
             ExecResult::Continue
         }
+        TestbenchStatement::Force { target, value } => {
+            sim.ensure_comb_updated();
+            let value = value.eval(&mut sim.mask_cache);
+            match sim.force_target(target, value) {
+                Ok(()) => ExecResult::Continue,
+                Err(e) => ExecResult::Fail(e.to_string()),
+            }
+        }
+        TestbenchStatement::Release { target } => match sim.release_target(target) {
+            Ok(()) => ExecResult::Continue,
+            Err(e) => ExecResult::Fail(e.to_string()),
+        },
+        TestbenchStatement::DumpOn => {
+            sim.set_dump_on(true);
+            ExecResult::Continue
+        }
+        TestbenchStatement::DumpOff => {
+            sim.set_dump_on(false);
+            ExecResult::Continue
+        }
         TestbenchStatement::If {
             condition,
             then_block,
@@ -800,63 +1285,16 @@ fn exec_one(sim: &mut Simulator, stmt: &TestbenchStatement) -> ExecResult {
             loop_var,
         } => {
             if let Some(lv) = loop_var {
-                let r = &lv.range;
-                let start = r.start.eval(&mut sim.mask_cache);
-                let mut end = r.end.eval(&mut sim.mask_cache);
-                if r.inclusive {
-                    end = end.saturating_add(1);
-                }
-                let step = r.step;
-                let op = r.op;
-                let reverse = r.reverse;
-                let mut step_body = |i: u64| -> ExecResult {
-                    let val = Value::new(i, lv.width, lv.signed);
-                    unsafe {
-                        write_native_value(lv.ptr, lv.native_bytes, lv.use_4state, &val);
-                    }
-                    exec(sim, body)
-                };
+                let mut cursor = LoopCursor::new(sim, &lv.range);
                 let mut loop_result = ExecResult::Continue;
-                if reverse {
-                    // Mirror the emitted SV `for (int i = hi - 1; i >= lo;
-                    // i -= step)`; i64 makes underflow past lo terminate.
-                    let mut i = end as i64 - 1;
-                    let lo = start as i64;
-                    let step = step as i64;
-                    while i >= lo {
-                        let result = step_body(i as u64);
-                        if result.should_stop() {
-                            loop_result = result;
-                            break;
-                        }
-                        i -= step;
-                    }
-                } else if let Some(op) = op {
-                    let mut i = start;
-                    while i < end {
-                        let result = step_body(i);
-                        if result.should_stop() {
-                            loop_result = result;
-                            break;
-                        }
-                        // Progress guard: a stalled or faulting step would
-                        // spin forever (const-bound cases are rejected at
-                        // analysis; runtime bounds reach here).
-                        match op.eval(i as usize, step as usize) {
-                            Some(n) if n as u64 > i => i = n as u64,
-                            _ => break,
-                        }
-                    }
-                } else {
-                    let mut i = start;
-                    while i < end {
-                        let result = step_body(i);
-                        if result.should_stop() {
-                            loop_result = result;
-                            break;
-                        }
-                        i += step;
+                while let Some(i) = cursor.current() {
+                    lv.write(i);
+                    let result = exec(sim, body);
+                    if result.should_stop() {
+                        loop_result = result;
+                        break;
                     }
+                    cursor.advance();
                 }
                 if matches!(loop_result, ExecResult::Break) {
                     return ExecResult::Continue;
@@ -997,6 +1435,392 @@ fn exec_one(sim: &mut Simulator, stmt: &TestbenchStatement) -> ExecResult {
             }
             ExecResult::Continue
         }
+        TestbenchStatement::Wait { duration } => {
+            sim.ensure_comb_updated();
+            let duration = duration.eval(&mut sim.mask_cache).payload_u64();
+            let until = sim.time + duration;
+            match sim.time_wheel.take() {
+                Some(mut wheel) => {
+                    let result = wheel.run(sim, until, None);
+                    sim.time_wheel = Some(wheel);
+                    result
+                }
+                None => {
+                    sim.time = until;
+                    ExecResult::Continue
+                }
+            }
+        }
+        TestbenchStatement::Line { token } => {
+            debug_stop(sim, Site::Line(token)).unwrap_or(ExecResult::Continue)
+        }
+        TestbenchStatement::WaitUntil { .. } => schedule(sim, vec![std::slice::from_ref(stmt)]),
+        TestbenchStatement::Fork { threads } => {
+            schedule(sim, threads.iter().map(Vec::as_slice).collect())
+        }
         TestbenchStatement::Finish => ExecResult::Finished,
     }
 }
+
+/// A cooperative testbench thread: a `#[fork]` branch, or a top-level
+/// `$wait_until`.
+struct Thread<'a> {
+    frames: Vec<Frame<'a>>,
+    wake: Wake<'a>,
+    /// The thread whose `#[fork]` spawned this one.
+    parent: Option<usize>,
+    /// Reset this thread's `rst.assert` holds until it wakes.
+    reset: Option<Event>,
+}
+
+enum Frame<'a> {
+    Block {
+        stmts: &'a [TestbenchStatement],
+        pc: usize,
+    },
+    Loop {
+        body: &'a [TestbenchStatement],
+        var: &'a LoopVariable,
+        cursor: LoopCursor,
+        started: bool,
+    },
+    Repeat {
+        body: &'a [TestbenchStatement],
+        remaining: u64,
+    },
+}
+
+enum Wake<'a> {
+    Ready,
+    Time(u64),
+    Condition(&'a Expression),
+    /// Waits for this many spawned threads to finish.
+    Join(usize),
+    Done,
+}
+
+impl<'a> Thread<'a> {
+    fn new(stmts: &'a [TestbenchStatement], parent: Option<usize>) -> Self {
+        Self {
+            frames: vec![Frame::Block { stmts, pc: 0 }],
+            wake: Wake::Ready,
+            parent,
+            reset: None,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        matches!(self.wake, Wake::Done)
+    }
+
+    /// The next statement to run, stepping loops as their bodies end.
+    fn next_stmt(&mut self) -> Option<&'a TestbenchStatement> {
+        loop {
+            match self.frames.last_mut()? {
+                Frame::Block { stmts, pc } => {
+                    let stmts: &'a [TestbenchStatement] = stmts;
+                    if let Some(stmt) = stmts.get(*pc) {
+                        *pc += 1;
+                        return Some(stmt);
+                    }
+                    self.frames.pop();
+                }
+                Frame::Loop {
+                    body,
+                    var,
+                    cursor,
+                    started,
+                } => {
+                    let body: &'a [TestbenchStatement] = body;
+                    if *started {
+                        cursor.advance();
+                    }
+                    *started = true;
+                    match cursor.current() {
+                        Some(i) => {
+                            var.write(i);
+                            self.frames.push(Frame::Block { stmts: body, pc: 0 });
+                        }
+                        None => {
+                            self.frames.pop();
+                        }
+                    }
+                }
+                Frame::Repeat { body, remaining } => {
+                    let body: &'a [TestbenchStatement] = body;
+                    if *remaining == 0 {
+                        self.frames.pop();
+                    } else {
+                        *remaining -= 1;
+                        self.frames.push(Frame::Block { stmts: body, pc: 0 });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Leaves the innermost loop, as `break` does.
+    fn break_loop(&mut self) {
+        while let Some(frame) = self.frames.pop() {
+            if matches!(frame, Frame::Loop { .. } | Frame::Repeat { .. }) {
+                return;
+            }
+        }
+    }
+}
+
+/// Runs `roots` as cooperative threads until all of them finish.
+///
+/// A thread runs until it waits on time, a clock, a condition or a nested
+/// `#[fork]`; the wheel then takes one edge at a time, and after each edge
+/// every thread whose wait is over runs again, in spawn order. `$wait_until`
+/// conditions are re-checked after every edge.
+fn schedule(sim: &mut Simulator, roots: Vec<&[TestbenchStatement]>) -> ExecResult {
+    let mut wheel = sim
+        .time_wheel
+        .take()
+        .unwrap_or_else(|| TimeWheel::new(Vec::new()));
+    let mut resets = Vec::new();
+    let result = schedule_threads(sim, &mut wheel, &mut resets, roots);
+    // Release resets still held by threads that never woke.
+    for held in resets {
+        if let Some(id) = held.reset.var_id() {
+            sim.set_reset_level(&id, false);
+        }
+    }
+    sim.time_wheel = Some(wheel);
+    result
+}
+
+fn schedule_threads(
+    sim: &mut Simulator,
+    wheel: &mut TimeWheel,
+    resets: &mut Vec<HeldReset>,
+    roots: Vec<&[TestbenchStatement]>,
+) -> ExecResult {
+    wheel.start(sim.time);
+    let n_roots = roots.len();
+    let mut threads: Vec<Thread> = roots.into_iter().map(|x| Thread::new(x, None)).collect();
+    loop {
+        let mut progress = true;
+        while progress {
+            progress = false;
+            let mut idx = 0;
+            while idx < threads.len() {
+                if is_awake(sim, &threads[idx]) {
+                    if let Some(reset) = threads[idx].reset.take() {
+                        if let Some(id) = reset.var_id() {
+                            sim.set_reset_level(&id, false);
+                        }
+                        resets.retain(|x| x.reset != reset);
+                    }
+                    threads[idx].wake = Wake::Ready;
+                    if let Some(result) = run_thread(sim, wheel, resets, &mut threads, idx) {
+                        return result;
+                    }
+                    if threads[idx].is_done()
+                        && let Some(parent) = threads[idx].parent
+                        && let Wake::Join(n) = &mut threads[parent].wake
+                    {
+                        *n -= 1;
+                    }
+                    progress = true;
+                }
+                idx += 1;
+            }
+        }
+        if threads[..n_roots].iter().all(Thread::is_done) {
+            return ExecResult::Continue;
+        }
+        let next_time = threads
+            .iter()
+            .filter_map(|x| match x.wake {
+                Wake::Time(t) => Some(t),
+                _ => None,
+            })
+            .min();
+        match wheel.take_edge(sim, next_time.unwrap_or(u64::MAX), resets) {
+            Edge::Idle => match next_time {
+                Some(t) => sim.time = t,
+                None => {
+                    return ExecResult::Fail(
+                        "testbench threads wait on conditions that no clock can change".to_string(),
+                    );
+                }
+            },
+            Edge::Taken => {}
+            Edge::Stop(result) => return result,
+        }
+    }
+}
+
+fn is_awake(sim: &mut Simulator, thread: &Thread) -> bool {
+    match thread.wake {
+        Wake::Ready | Wake::Join(0) => true,
+        Wake::Time(t) => sim.time >= t,
+        Wake::Condition(condition) => {
+            sim.ensure_comb_updated();
+            condition.eval(&mut sim.mask_cache).payload_u64() != 0
+        }
+        Wake::Join(_) | Wake::Done => false,
+    }
+}
+
+/// Runs thread `idx` until it waits or ends. Returns a result that stops the
+/// whole testbench.
+fn run_thread<'a>(
+    sim: &mut Simulator,
+    wheel: &mut TimeWheel,
+    resets: &mut Vec<HeldReset>,
+    threads: &mut Vec<Thread<'a>>,
+    idx: usize,
+) -> Option<ExecResult> {
+    loop {
+        let Some(stmt) = threads[idx].next_stmt() else {
+            threads[idx].wake = Wake::Done;
+            return None;
+        };
+        let thread = &mut threads[idx];
+        match stmt {
+            TestbenchStatement::ClockNext { clock, count, .. } => {
+                let n = match count {
+                    Some(expr) => {
+                        sim.ensure_comb_updated();
+                        expr.eval(&mut sim.mask_cache).payload_u64()
+                    }
+                    None => 1,
+                };
+                if n == 0 {
+                    continue;
+                }
+                if let Some(until) = wheel.after_edges(sim.time, clock, n) {
+                    thread.wake = Wake::Time(until);
+                    return None;
+                }
+            }
+            TestbenchStatement::ResetAssert {
+                reset,
+                clock,
+                duration,
+                ..
+            } => {
+                if let Some(until) = wheel.after_edges(sim.time, clock, *duration) {
+                    if let Some(id) = reset.var_id() {
+                        sim.set_reset_level(&id, true);
+                    }
+                    resets.push(HeldReset::new(reset.clone(), clock.clone()));
+                    thread.reset = Some(reset.clone());
+                    thread.wake = Wake::Time(until);
+                    return None;
+                }
+            }
+            TestbenchStatement::Wait { duration } => {
+                sim.ensure_comb_updated();
+                let duration = duration.eval(&mut sim.mask_cache).payload_u64();
+                thread.wake = Wake::Time(sim.time + duration);
+                return None;
+            }
+            TestbenchStatement::WaitUntil { condition } => {
+                sim.ensure_comb_updated();
+                if condition.eval(&mut sim.mask_cache).payload_u64() == 0 {
+                    thread.wake = Wake::Condition(condition);
+                    return None;
+                }
+                continue;
+            }
+            TestbenchStatement::Fork { threads: children } => {
+                thread.wake = Wake::Join(children.len());
+                threads.extend(children.iter().map(|x| Thread::new(x, Some(idx))));
+                return None;
+            }
+            TestbenchStatement::If {
+                condition,
+                then_block,
+                else_block,
+            } => {
+                sim.ensure_comb_updated();
+                let stmts = if condition.eval(&mut sim.mask_cache).payload_u64() != 0 {
+                    then_block
+                } else {
+                    else_block
+                };
+                thread.frames.push(Frame::Block { stmts, pc: 0 });
+                continue;
+            }
+            TestbenchStatement::For {
+                count,
+                body,
+                loop_var,
+            } => {
+                let frame = match loop_var {
+                    Some(var) => Frame::Loop {
+                        body,
+                        var,
+                        cursor: LoopCursor::new(sim, &var.range),
+                        started: false,
+                    },
+                    None => Frame::Repeat {
+                        body,
+                        remaining: *count,
+                    },
+                };
+                thread.frames.push(frame);
+                continue;
+            }
+            _ => {}
+        }
+        // Statements that do not wait, and clocks the wheel does not drive,
+        // run as they do outside a fork.
+        match exec_one(sim, stmt) {
+            ExecResult::Continue => {}
+            ExecResult::Break => {
+                threads[idx].break_loop();
+            }
+            result => return Some(result),
+        }
+        if assert_buffer::has_fatal() {
+            return Some(ExecResult::Fail(
+                assert_buffer::take_failure().unwrap_or_default(),
+            ));
+        }
+    }
+}
+
+/// Reports `site` to the simulator's debugger, if any; `Some` stops the
+/// testbench once the user quits.
+fn debug_stop(sim: &mut Simulator, site: Site) -> Option<ExecResult> {
+    let mut debugger = sim.debugger.take()?;
+    let resume = debugger.reached(sim, site);
+    sim.debugger = Some(debugger);
+    (!resume).then(|| ExecResult::Fail("quit from the debugger".to_string()))
+}
+
+/// Bookkeeping at the end of a testbench clock cycle (after commit and dump):
+/// component-requested termination, the checkpoint, the cycle cap and the
+/// debugger.
+fn end_cycle(sim: &mut Simulator) -> Option<ExecResult> {
+    if !sim.components.is_empty() {
+        if sim.components_failed() {
+            return Some(ExecResult::Fail(sim.take_component_failures().join("\n")));
+        }
+        if sim.component_finish_requested() {
+            return Some(ExecResult::Finished);
+        }
+    }
+    sim.cycle_count += 1;
+    if let Some((at, path)) = &sim.checkpoint_at
+        && sim.cycle_count == *at
+    {
+        let path = path.clone();
+        if let Err(err) = sim.save_checkpoint(&path) {
+            return Some(ExecResult::Fail(err.to_string()));
+        }
+    }
+    // Stop once the optional clock-cycle cap is reached.
+    if let Some(limit) = sim.cycle_limit
+        && sim.cycle_count >= limit
+    {
+        return Some(ExecResult::Finished);
+    }
+    debug_stop(sim, Site::Cycle)
+}
@@ -21,10 +21,16 @@ fn analyze(code: &str, config: &Config) -> Ir {
 
 #[track_caller]
 fn analyze_top(code: &str, config: &Config, top: &str) -> Result<Ir, SimulatorError> {
+    analyze_at(code, config, top, "")
+}
+
+/// Like `analyze_top`, but parses `code` as if it were read from `path`.
+#[track_caller]
+fn analyze_at(code: &str, config: &Config, top: &str, path: &str) -> Result<Ir, SimulatorError> {
     symbol_table::clear();
 
     let metadata = Metadata::create_default("prj").unwrap();
-    let parser = Parser::parse(code, &"").unwrap();
+    let parser = Parser::parse(code, &path).unwrap();
     let analyzer = Analyzer::new(&metadata);
     let mut context = Context::default();
 
@@ -450,10 +456,14 @@ where
     }
 }
 
+mod checkpoint;
 mod component;
 mod component_sim;
+mod coverage;
+mod debugger;
 mod derived_clock;
 mod error;
 mod hier_ref;
+mod property;
 mod simulation;
 mod testbench;
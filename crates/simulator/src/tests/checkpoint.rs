@@ -0,0 +1,228 @@
+use super::*;
+
+fn temp_checkpoint(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("veryl_test_{name}_{}.ckpt", std::process::id()))
+}
+
+#[test]
+fn restore_continues_like_an_uninterrupted_run() {
+    let code = r#"
+    module Top (
+        clk : input  clock,
+        rst : input  reset,
+        en  : input  logic,
+        cnt : output logic<16>,
+        lfsr: output logic<8>,
+    ) {
+        var c: logic<16>;
+        var l: logic<8>;
+        always_ff {
+            if_reset {
+                c = 0;
+                l = 1;
+            } else {
+                if en {
+                    c = c + 1;
+                }
+                l = {l[6:0], l[7] ^ l[5]};
+            }
+        }
+        assign cnt  = c;
+        assign lfsr = l;
+    }
+    "#;
+
+    for config in Config::all() {
+        dbg!(&config);
+        let path = temp_checkpoint("restore_continues");
+
+        let ir = analyze(code, &config);
+        let mut sim = Simulator::new(ir, None);
+        let clk = sim.get_clock("clk").unwrap();
+        let rst = sim.get_reset("rst").unwrap();
+        sim.step_reset(&clk, &rst);
+        sim.set("en", Value::new(1, 1, false));
+        for _ in 0..10 {
+            sim.step(&clk);
+            sim.time += 10;
+        }
+        sim.cycle_count = 10;
+        sim.save_checkpoint(&path).unwrap();
+
+        let mut expected = vec![];
+        for i in 0..5 {
+            sim.set("en", Value::new(i & 1, 1, false));
+            sim.step(&clk);
+            expected.push((sim.get("cnt").unwrap(), sim.get("lfsr").unwrap()));
+        }
+
+        let ir = analyze(code, &config);
+        let mut sim = Simulator::new(ir, None);
+        sim.restore_checkpoint(&path).unwrap();
+        assert_eq!(sim.time, 100);
+        assert_eq!(sim.cycle_count, 10);
+        assert_eq!(sim.get("cnt").unwrap(), Value::new(10, 16, false));
+
+        let mut actual = vec![];
+        for i in 0..5 {
+            sim.set("en", Value::new(i & 1, 1, false));
+            sim.step(&clk);
+            actual.push((sim.get("cnt").unwrap(), sim.get("lfsr").unwrap()));
+        }
+        assert_eq!(actual, expected);
+        let _ = std::fs::remove_file(&path);
+    }
+}
+
+#[test]
+fn restore_rejects_another_design() {
+    let saved = r#"
+    module Top (
+        clk: input  clock,
+        q  : output logic<8>,
+    ) {
+        always_ff {
+            q = q + 1;
+        }
+    }
+    "#;
+    let other = r#"
+    module Top (
+        clk: input  clock,
+        q  : output logic<32>,
+        r  : output logic<32>,
+    ) {
+        always_ff {
+            q = q + 1;
+            r = q;
+        }
+    }
+    "#;
+
+    let config = Config::default();
+    let path = temp_checkpoint("restore_rejects");
+    let mut sim = Simulator::new(analyze(saved, &config), None);
+    sim.save_checkpoint(&path).unwrap();
+
+    let mut sim = Simulator::new(analyze(other, &config), None);
+    let err = sim.restore_checkpoint(&path).unwrap_err();
+    assert!(
+        matches!(err, SimulatorError::Checkpoint { .. }),
+        "unexpected error: {err}"
+    );
+
+    std::fs::write(&path, b"not a checkpoint").unwrap();
+    let err = sim.restore_checkpoint(&path).unwrap_err();
+    assert!(err.to_string().contains("not a checkpoint file"));
+
+    // A corrupt length fails without allocating what it claims.
+    let mut corrupt = b"VRLCKPT\0".to_vec();
+    corrupt.extend(1u32.to_le_bytes());
+    corrupt.extend(u64::MAX.to_le_bytes());
+    corrupt.extend(b"{}");
+    std::fs::write(&path, corrupt).unwrap();
+    let err = sim.restore_checkpoint(&path).unwrap_err();
+    assert!(err.to_string().contains("truncated checkpoint"), "{err}");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn random_streams_resume_from_saved_state() {
+    let key = veryl_parser::resource_table::insert_str("ckpt_rng");
+    crate::random_table::reset(42);
+    crate::random_table::get(key, 32, false);
+    let saved = crate::random_table::save();
+    let expected: Vec<_> = (0..4)
+        .map(|_| crate::random_table::get(key, 32, false))
+        .collect();
+
+    crate::random_table::reset(7);
+    crate::random_table::restore(&saved).unwrap();
+    let actual: Vec<_> = (0..4)
+        .map(|_| crate::random_table::get(key, 32, false))
+        .collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn testbench_saves_at_requested_cycle() {
+    let code = r#"
+    #[test(test_ckpt)]
+    module test_ckpt {
+        inst clk: $tb::clock_gen;
+        inst rst: $tb::reset_gen(clk);
+        var cnt: logic<8>;
+        always_ff (clk, rst) {
+            if_reset {
+                cnt = 0;
+            } else {
+                cnt = cnt + 1;
+            }
+        }
+        initial {
+            rst.assert();
+            clk.next(8);
+            $finish();
+        }
+    }
+    "#;
+    let resume = r#"
+    #[test(test_ckpt)]
+    module test_ckpt {
+        inst clk: $tb::clock_gen;
+        inst rst: $tb::reset_gen(clk);
+        var cnt: logic<8>;
+        always_ff (clk, rst) {
+            if_reset {
+                cnt = 0;
+            } else {
+                cnt = cnt + 1;
+            }
+        }
+        initial {
+            $assert(cnt == 5);
+            clk.next(2);
+            $assert(cnt == 7);
+            $finish();
+        }
+    }
+    "#;
+
+    for config in Config::all() {
+        dbg!(&config);
+        let path = temp_checkpoint("testbench_saves");
+        let _ = std::fs::remove_file(&path);
+
+        let ir = analyze_top(code, &config, "test_ckpt").unwrap();
+        let options = crate::testbench::CheckpointOptions {
+            restore: None,
+            save_at: Some((5, path.clone())),
+        };
+        let (result, _) = crate::testbench::run_native_testbench_timed(
+            ir,
+            None,
+            "test_ckpt".to_string(),
+            None,
+            &options,
+        )
+        .unwrap();
+        assert_eq!(result, TestResult::Pass);
+        assert!(path.exists());
+
+        let ir = analyze_top(resume, &config, "test_ckpt").unwrap();
+        let options = crate::testbench::CheckpointOptions {
+            restore: Some(path.clone()),
+            save_at: None,
+        };
+        let (result, _) = crate::testbench::run_native_testbench_timed(
+            ir,
+            None,
+            "test_ckpt".to_string(),
+            None,
+            &options,
+        )
+        .unwrap();
+        assert_eq!(result, TestResult::Pass);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -38,6 +38,18 @@ impl Component for Counter {
         ctx.write(self.out, self.count);
         Ok(())
     }
+
+    fn save_state(&mut self, _ctx: &mut SimCtx) -> Result<Vec<u8>> {
+        Ok(self.count.to_le_bytes().to_vec())
+    }
+
+    fn restore_state(&mut self, state: &[u8], _ctx: &mut SimCtx) -> Result<()> {
+        let Ok(bytes) = state.try_into() else {
+            bail!("bad counter state");
+        };
+        self.count = u64::from_le_bytes(bytes);
+        Ok(())
+    }
 }
 
 struct EchoWide {
@@ -221,6 +233,45 @@ fn counter_on_clock_roundtrip() {
     assert_eq!(instance.on_finish(&mut host), 0);
 }
 
+#[test]
+fn counter_state_survives_save_restore() {
+    let vtable = vtable("counter");
+    let new_host = || {
+        let mut host = HostContext::new();
+        host.add_port("out", PortDir::Output, 16);
+        host.add_param("INC", HostValue::bits_u64(3, 32));
+        host
+    };
+
+    let mut host = new_host();
+    let mut instance = ExternalInstance::create(vtable, &mut host).unwrap();
+    assert_eq!(instance.on_clock(&mut host), 0);
+    assert_eq!(instance.on_clock(&mut host), 0);
+    let state = instance.save_state(&mut host).unwrap();
+    assert_eq!(state, 6u64.to_le_bytes());
+
+    let mut host = new_host();
+    let mut restored = ExternalInstance::create(vtable, &mut host).unwrap();
+    assert_eq!(restored.on_init(&mut host), 0);
+    assert_eq!(restored.restore_state(&mut host, &state), 0);
+    assert_eq!(restored.on_clock(&mut host), 0);
+    assert_eq!(host.output_u64("out"), 9);
+
+    // A malformed record is a reported failure.
+    assert_ne!(restored.restore_state(&mut host, &[1, 2]), 0);
+    assert!(host.failed());
+}
+
+#[test]
+fn default_state_hooks_save_nothing() {
+    let vtable = vtable("method_only");
+    let mut host = HostContext::new();
+    let mut instance = ExternalInstance::create(vtable, &mut host).unwrap();
+    assert_eq!(instance.save_state(&mut host), Some(vec![]));
+    assert_eq!(instance.restore_state(&mut host, &[]), 0);
+    assert!(!host.failed());
+}
+
 #[test]
 fn output_width_is_masked() {
     let vtable = vtable("counter");
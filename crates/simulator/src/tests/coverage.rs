@@ -0,0 +1,231 @@
+use super::*;
+use crate::coverage::{self, BranchCoverage, FileCoverage, Report, ToggleCoverage};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// 1-based line of the first line of `code` containing `pat`.
+fn line_of(code: &str, pat: &str) -> u32 {
+    code.lines().position(|x| x.contains(pat)).unwrap() as u32 + 1
+}
+
+/// Coverage counters are process-global and keyed by source position, so
+/// every run parses from its own path and reads back only that file.
+fn file_report(path: &str) -> FileCoverage {
+    coverage::report()
+        .files
+        .into_iter()
+        .find(|x| x.path == Path::new(path))
+        .unwrap()
+}
+
+#[test]
+fn line_and_branch() {
+    let code = r#"
+    module Top (
+        clk: input  clock,
+        rst: input  reset,
+        sel: input  logic<2>,
+        cnt: output logic<8>,
+    ) {
+        always_ff {
+            if_reset {
+                cnt = 0;
+            } else if sel == 0 {
+                cnt += 1;
+            } else {
+                case sel {
+                    1      : cnt += 2;
+                    2      : cnt += 3;
+                    default: cnt = cnt;
+                }
+            }
+        }
+    }
+    "#;
+
+    for (i, config) in Config::all().into_iter().enumerate() {
+        dbg!(&config);
+
+        let path = format!("coverage_line_and_branch_{i}.veryl");
+        let config = Config {
+            coverage: true,
+            ..config
+        };
+        let ir = analyze_at(code, &config, "Top", &path).unwrap();
+        let mut sim = Simulator::new(ir, None);
+
+        let clk = sim.get_clock("clk").unwrap();
+        let rst = sim.get_reset("rst").unwrap();
+
+        sim.step_reset(&clk, &rst);
+        sim.set("sel", Value::new(0, 2, false));
+        for _ in 0..3 {
+            sim.step(&clk);
+        }
+        sim.set("sel", Value::new(1, 2, false));
+        for _ in 0..2 {
+            sim.step(&clk);
+        }
+
+        assert_eq!(sim.get("cnt").unwrap(), Value::new(7, 8, false));
+        drop(sim);
+
+        let file = file_report(&path);
+        // The async reset runs the block on its own event and on the edge.
+        assert!(file.lines[&line_of(code, "cnt = 0")] > 0);
+        assert_eq!(file.lines[&line_of(code, "cnt += 1")], 3);
+        assert_eq!(file.lines[&line_of(code, "cnt += 2")], 2);
+        assert_eq!(file.lines[&line_of(code, "cnt += 3")], 0);
+        assert_eq!(file.lines[&line_of(code, "cnt = cnt")], 0);
+
+        let case = line_of(code, "case sel");
+        let arms: Vec<_> = file
+            .branches
+            .iter()
+            .filter(|x| x.line == case)
+            .map(|x| (x.arm, x.hits))
+            .collect();
+        assert_eq!(arms, [(0, 2), (1, 0), (2, 0)]);
+
+        let if_sel = line_of(code, "else if sel");
+        let arms: Vec<_> = file
+            .branches
+            .iter()
+            .filter(|x| x.line == if_sel)
+            .map(|x| (x.arm, x.hits))
+            .collect();
+        assert_eq!(arms, [(0, 3), (1, 2)]);
+    }
+}
+
+#[test]
+fn toggle() {
+    // The module name is unique to this test: toggles are keyed by module.
+    let code = r#"
+    module CoverageToggle (
+        clk: input  clock,
+        rst: input  reset,
+        cnt: output logic<8>,
+    ) {
+        always_ff {
+            if_reset {
+                cnt = 0;
+            } else {
+                cnt += 1;
+            }
+        }
+    }
+    "#;
+
+    for (i, config) in Config::all().into_iter().enumerate() {
+        dbg!(&config);
+
+        let path = format!("coverage_toggle_{i}.veryl");
+        let config = Config {
+            coverage: true,
+            ..config
+        };
+        let ir = analyze_at(code, &config, "CoverageToggle", &path).unwrap();
+        let mut sim = Simulator::new(ir, None);
+
+        let clk = sim.get_clock("clk").unwrap();
+        let rst = sim.get_reset("rst").unwrap();
+
+        // 0 -> 4: bits 0 and 1 rise and fall, bit 2 only rises.
+        sim.step_reset(&clk, &rst);
+        for _ in 0..4 {
+            sim.step(&clk);
+        }
+        drop(sim);
+
+        let report = coverage::report();
+        let cnt = report
+            .toggles
+            .iter()
+            .find(|x| x.module == "CoverageToggle" && x.signal == "cnt")
+            .unwrap();
+        assert_eq!((cnt.bits, cnt.toggled), (8, 2));
+        assert!(
+            report
+                .toggles
+                .iter()
+                .all(|x| x.module != "CoverageToggle" || x.signal != "clk")
+        );
+    }
+}
+
+#[test]
+fn disabled_by_default() {
+    let code = r#"
+    module Top (
+        a: input  logic,
+        b: output logic,
+    ) {
+        always_comb {
+            b = ~a;
+        }
+    }
+    "#;
+
+    let path = "coverage_disabled_by_default.veryl";
+    let ir = analyze_at(code, &Config::default(), "Top", path).unwrap();
+    assert!(!ir.coverage);
+    let mut sim = Simulator::new(ir, None);
+    sim.set("a", Value::new(0, 1, false));
+    sim.step(&Event::Clock(VarId::SYNTHETIC));
+
+    let report = coverage::report();
+    assert!(report.files.iter().all(|x| x.path != Path::new(path)));
+}
+
+#[test]
+fn lcov() {
+    let report = Report {
+        files: vec![FileCoverage {
+            path: PathBuf::from("src/top.veryl"),
+            lines: BTreeMap::from([(3, 2), (4, 0)]),
+            branches: vec![
+                BranchCoverage {
+                    line: 3,
+                    block: 0,
+                    arm: 0,
+                    hits: 2,
+                },
+                BranchCoverage {
+                    line: 3,
+                    block: 0,
+                    arm: 1,
+                    hits: 0,
+                },
+            ],
+        }],
+        toggles: vec![ToggleCoverage {
+            module: "Top".to_string(),
+            signal: "cnt".to_string(),
+            bits: 8,
+            toggled: 2,
+        }],
+    };
+
+    let mut lcov = vec![];
+    report.write_lcov(&mut lcov).unwrap();
+    let exp = r#"TN:
+SF:src/top.veryl
+BRDA:3,0,0,2
+BRDA:3,0,1,0
+BRF:2
+BRH:1
+DA:3,2
+DA:4,0
+LF:2
+LH:1
+end_of_record
+"#;
+    assert_eq!(String::from_utf8(lcov).unwrap(), exp);
+
+    let summary = report.summary();
+    assert_eq!(summary["lines"]["hit"], 1);
+    assert_eq!(summary["branches"]["found"], 2);
+    assert_eq!(summary["toggles"]["found"], 8);
+    assert_eq!(summary["toggles"]["hit"], 2);
+}
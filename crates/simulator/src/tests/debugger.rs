@@ -0,0 +1,145 @@
+use super::*;
+use crate::debugger::{Debugger, Repl};
+use crate::testbench::{CheckpointOptions, TestResult, run_native_testbench_debug};
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
+
+/// REPL output the test reads back after the run.
+#[derive(Clone, Default)]
+struct Output(Rc<RefCell<Vec<u8>>>);
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// 1-based line of the first line of `code` containing `pat`.
+fn line_of(code: &str, pat: &str) -> u32 {
+    code.lines().position(|x| x.contains(pat)).unwrap() as u32 + 1
+}
+
+fn run_repl(code: &str, top: &str, config: &Config, script: &str) -> (TestResult, String) {
+    let config = Config {
+        debug: true,
+        ..config.clone()
+    };
+    let ir = analyze_top(code, &config, top).unwrap();
+    let module_name = ir.name.to_string();
+    let output = Output::default();
+    let repl = Repl::new(std::io::Cursor::new(script.to_string()), output.clone());
+    let result = run_native_testbench_debug(
+        ir,
+        None,
+        module_name,
+        &CheckpointOptions::default(),
+        Debugger::new(Box::new(repl)),
+    )
+    .unwrap();
+    let text = String::from_utf8(output.0.borrow().clone()).unwrap();
+    (result, text)
+}
+
+const COUNTER: &str = r#"
+module Counter (
+    clk: input  clock     ,
+    rst: input  reset     ,
+    cnt: output logic<32> ,
+) {
+    always_ff {
+        if_reset { cnt = 0; }
+        else     { cnt += 1; }
+    }
+}
+
+#[test(test_debug)]
+module test_debug {
+    inst clk: $tb::clock_gen;
+    inst rst: $tb::reset_gen (clk);
+
+    var cnt: logic<32>;
+    var x  : logic<8> ;
+
+    inst u: Counter (clk, rst, cnt);
+
+    initial {
+        x = 0;
+        rst.assert();
+        clk.next(10);
+        x = 7;
+        $assert(cnt == 32'd10);
+        $finish();
+    }
+}
+"#;
+
+#[test]
+fn repl_breakpoints_step_and_inspect() {
+    let line = line_of(COUNTER, "x = 7;");
+    let reset = line_of(COUNTER, "rst.assert();");
+    let script = format!(
+        "next\n\
+         next\n\
+         break {line}\n\
+         break cnt == 3\n\
+         c\n\
+         p cnt\n\
+         step 2\n\
+         p cnt\n\
+         set x 5\n\
+         delete 2\n\
+         breakpoints\n\
+         c\n\
+         p x\n\
+         next\n\
+         p x\n\
+         scope u\n\
+         c\n\
+         c\n"
+    );
+
+    for config in Config::all() {
+        let (result, text) = run_repl(COUNTER, "test_debug", &config, &script);
+        assert_eq!(result, TestResult::Pass, "config={config:?}");
+        let expected = [
+            format!("at line {reset}"),
+            "breakpoint 2: cnt == 32'h00000003 (cnt = 32'h00000003)".to_string(),
+            "time 12, cycle 3".to_string(),
+            "cnt = 32'h00000005".to_string(),
+            format!("  1: line {line}"),
+            format!("breakpoint 1: line {line}"),
+            // `set` sticks until the testbench assigns `x` itself.
+            "x = 8'h05".to_string(),
+            format!("at line {}", line + 1),
+            "x = 8'h07".to_string(),
+            "  cnt = 32'h0000000a".to_string(),
+            "test passed".to_string(),
+        ];
+        let mut rest = text.as_str();
+        for x in &expected {
+            let at = rest
+                .find(x.as_str())
+                .unwrap_or_else(|| panic!("`{x}` missing in:\n{text}\nconfig={config:?}"));
+            rest = &rest[at + x.len()..];
+        }
+    }
+}
+
+#[test]
+fn repl_quit_fails_the_test() {
+    for config in Config::all() {
+        let (result, text) = run_repl(COUNTER, "test_debug", &config, "p nothing\nq\n");
+        assert!(text.contains("error: no variable `nothing`"), "{text}");
+        assert_eq!(
+            result,
+            TestResult::Fail("quit from the debugger".to_string()),
+            "config={config:?}"
+        );
+    }
+}
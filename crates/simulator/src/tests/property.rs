@@ -0,0 +1,139 @@
+use super::*;
+
+fn set(sim: &mut Simulator, name: &str, value: u64, width: usize) {
+    sim.set(name, Value::new(value, width, false));
+}
+
+#[test]
+fn implication_window() {
+    let code = r#"
+    module Top (
+        clk  : input clock,
+        valid: input logic,
+        ready: input logic,
+    ) {
+        always_ff {
+            $assert_continue(valid |-> ##[1:2] ready, "late ready");
+        }
+    }
+    "#;
+
+    for config in Config::all() {
+        dbg!(&config);
+
+        let ir = analyze(code, &config);
+        let mut sim = Simulator::new(ir, None);
+        let clk = sim.get_clock("clk").unwrap();
+        crate::assert_buffer::reset();
+
+        // Ready two cycles after valid is inside the window.
+        for (valid, ready) in [(1, 0), (0, 0), (0, 1)] {
+            set(&mut sim, "valid", valid, 1);
+            set(&mut sim, "ready", ready, 1);
+            sim.step(&clk);
+        }
+        assert_eq!(crate::assert_buffer::take_failure(), None);
+
+        // Ready in the cycle of valid is too early, and none follows.
+        for (valid, ready) in [(1, 1), (0, 0), (0, 0)] {
+            set(&mut sim, "valid", valid, 1);
+            set(&mut sim, "ready", ready, 1);
+            sim.step(&clk);
+        }
+        assert_eq!(
+            crate::assert_buffer::take_failure().as_deref(),
+            Some("late ready")
+        );
+    }
+}
+
+#[test]
+fn implication_next_cycle() {
+    let code = r#"
+    module Top (
+        clk  : input clock,
+        valid: input logic,
+        ready: input logic,
+        data : input logic<8>,
+    ) {
+        always_ff {
+            $assert_continue(valid && !ready |=> valid && $stable(data), "dropped");
+        }
+    }
+    "#;
+
+    for config in Config::all() {
+        dbg!(&config);
+
+        let ir = analyze(code, &config);
+        let mut sim = Simulator::new(ir, None);
+        let clk = sim.get_clock("clk").unwrap();
+        crate::assert_buffer::reset();
+
+        for (valid, ready, data) in [(1, 0, 3), (1, 0, 3), (1, 1, 3), (0, 0, 7)] {
+            set(&mut sim, "valid", valid, 1);
+            set(&mut sim, "ready", ready, 1);
+            set(&mut sim, "data", data, 8);
+            sim.step(&clk);
+        }
+        assert_eq!(crate::assert_buffer::take_failure(), None);
+
+        for (valid, ready, data) in [(1, 0, 3), (1, 0, 4)] {
+            set(&mut sim, "valid", valid, 1);
+            set(&mut sim, "ready", ready, 1);
+            set(&mut sim, "data", data, 8);
+            sim.step(&clk);
+        }
+        assert_eq!(
+            crate::assert_buffer::take_failure().as_deref(),
+            Some("dropped")
+        );
+    }
+}
+
+#[test]
+fn sampled_value_functions() {
+    let code = r#"
+    module Top (
+        clk   : input  clock,
+        d     : input  logic<4>,
+        rose  : output logic,
+        fell  : output logic,
+        stable: output logic,
+        past2 : output logic<4>,
+    ) {
+        always_ff {
+            rose   = $rose(d);
+            fell   = $fell(d);
+            stable = $stable(d);
+            past2  = $past(d, 2);
+        }
+    }
+    "#;
+
+    for config in Config::all() {
+        dbg!(&config);
+
+        let ir = analyze(code, &config);
+        let mut sim = Simulator::new(ir, None);
+        let clk = sim.get_clock("clk").unwrap();
+
+        // d, then rose, fell, stable and past2 sampled on that edge.
+        let expected = [
+            (1, 1, 0, 0, 0),
+            (1, 0, 0, 1, 0),
+            (0, 0, 1, 0, 1),
+            (4, 0, 0, 0, 1),
+            (4, 0, 0, 1, 0),
+            (4, 0, 0, 1, 4),
+        ];
+        for (d, rose, fell, stable, past2) in expected {
+            set(&mut sim, "d", d, 4);
+            sim.step(&clk);
+            assert_eq!(sim.get("rose").unwrap(), Value::new(rose, 1, false));
+            assert_eq!(sim.get("fell").unwrap(), Value::new(fell, 1, false));
+            assert_eq!(sim.get("stable").unwrap(), Value::new(stable, 1, false));
+            assert_eq!(sim.get("past2").unwrap(), Value::new(past2, 4, false));
+        }
+    }
+}
@@ -532,6 +532,66 @@ fn ff_to_ff() {
     }
 }
 
+#[test]
+fn force_signal_api() {
+    let code = r#"
+    module Top (
+        clk: input  clock,
+        rst: input  reset,
+        a  : input  logic<8>,
+        y  : output logic<8>,
+        cnt: output logic<8>,
+    ) {
+        var b: logic<8>;
+        assign b = a + 1;
+        assign y = b;
+        always_ff {
+            if_reset {
+                cnt = 0;
+            } else {
+                cnt += 1;
+            }
+        }
+    }
+    "#;
+
+    for mut config in Config::all() {
+        dbg!(&config);
+
+        let ir = analyze(code, &config);
+        let mut sim = Simulator::new(ir, None);
+        let b = sim.resolve_var("b").unwrap();
+        assert!(matches!(
+            sim.force_signal(&b, Value::new(7, 8, false)),
+            Err(SimulatorError::UnforceableSignal { ref path }) if path == "b"
+        ));
+
+        config.force = true;
+        let ir = analyze(code, &config);
+        let mut sim = Simulator::new(ir, None);
+        let clk = sim.get_clock("clk").unwrap();
+        let rst = sim.get_reset("rst").unwrap();
+        let b = sim.resolve_var("b").unwrap();
+        let cnt = sim.resolve_var("cnt").unwrap();
+        sim.step_reset(&clk, &rst);
+
+        sim.set("a", Value::new(1, 8, false));
+        sim.force_signal(&b, Value::new(7, 8, false)).unwrap();
+        sim.force_signal(&cnt, Value::new(9, 8, false)).unwrap();
+        assert_eq!(sim.get("y").unwrap(), Value::new(7, 8, false));
+        sim.set("a", Value::new(2, 8, false));
+        sim.step(&clk);
+        assert_eq!(sim.get("y").unwrap(), Value::new(7, 8, false));
+        assert_eq!(sim.get("cnt").unwrap(), Value::new(9, 8, false));
+
+        sim.release_signal(&b).unwrap();
+        sim.release_signal(&cnt).unwrap();
+        assert_eq!(sim.get("y").unwrap(), Value::new(3, 8, false));
+        sim.step(&clk);
+        assert_eq!(sim.get("cnt").unwrap(), Value::new(10, 8, false));
+    }
+}
+
 #[test]
 fn ff_statement_after_if_reset() {
     // Regression: statements placed after the `if_reset` block in an always_ff
@@ -1333,6 +1333,136 @@ fn testbench_fst_dumps_all_array_elements() {
     }
 }
 
+#[test]
+fn testbench_wave_struct_enum_array() {
+    let code = r#"
+    module Top (
+        clk: input  clock    ,
+        rst: input  reset    ,
+        o  : output logic<10>,
+    ) {
+        enum State: logic<2> {
+            Idle,
+            Busy,
+            Done,
+        }
+        struct Packet {
+            state: State   ,
+            data : logic<4>,
+        }
+
+        var pkt : Packet;
+        var prev: State;
+        var mem : logic<4> [2, 3];
+        always_ff {
+            if_reset {
+                pkt.state = State::Idle;
+                prev      = State::Idle;
+                pkt.data  = 4'h5;
+                mem[0][0] = 4'h5;
+                mem[0][1] = 4'h6;
+                mem[0][2] = 4'h7;
+                mem[1][0] = 4'h8;
+                mem[1][1] = 4'h9;
+                mem[1][2] = 4'ha;
+            } else {
+                pkt.state = State::Busy;
+                prev      = pkt.state;
+            }
+        }
+        assign o = {pkt ^ {prev, 4'h0}, mem[0][0] ^ mem[0][1] ^ mem[0][2] ^ mem[1][0] ^ mem[1][1] ^ mem[1][2]};
+    }
+    "#;
+
+    for config in Config::all() {
+        for fst in [false, true] {
+            let ir = analyze(code, &config);
+
+            let path = format!(
+                "{}/wave_struct_{}_{}.{}",
+                std::env::temp_dir().display(),
+                config.use_jit,
+                config.use_4state,
+                if fst { "fst" } else { "vcd" },
+            );
+
+            use crate::wave_dumper::WaveDumper;
+            let dumper = if fst {
+                WaveDumper::new_fst(&path)
+            } else {
+                WaveDumper::new_vcd(Box::new(std::fs::File::create(&path).unwrap()))
+            };
+            let mut sim = Simulator::new(ir, Some(dumper));
+
+            let clk = sim.get_clock("clk").unwrap();
+            let rst = sim.get_reset("rst").unwrap();
+
+            let stmts = vec![
+                TestbenchStatement::ResetAssert {
+                    reset: rst.clone(),
+                    clock: clk.clone(),
+                    duration: 2,
+                    high_time: 1,
+                    low_time: 1,
+                },
+                TestbenchStatement::ClockNext {
+                    clock: clk.clone(),
+                    count: None,
+                    high_time: 1,
+                    low_time: 1,
+                },
+            ];
+            let result = run_testbench(&mut sim, &stmts);
+            assert_eq!(result, TestResult::Pass);
+            drop(sim);
+
+            let mut wave = wellen::simple::read(&path).expect("failed to read waveform");
+            // wellen reads the indices of `mem[i][j]` as scopes.
+            let find = |name: &str| {
+                let hier = wave.hierarchy();
+                hier.all_vars()
+                    .find(|v| hier[*v].full_name(hier).replace(".[", "[") == name)
+                    .unwrap_or_else(|| panic!("{name} not found (fst={fst})"))
+            };
+
+            // Struct members sit in a scope named after the variable.
+            let state = find("Top.pkt.state");
+            let data = find("Top.pkt.data");
+            let elements: Vec<_> = (0..2)
+                .flat_map(|i| (0..3).map(move |j| (i, j)))
+                .map(|(i, j)| find(&format!("Top.mem[{i}][{j}]")))
+                .collect();
+
+            // Both formats name enum values with a table, declared once and
+            // shared by the variables of the type.
+            let prev = find("Top.prev");
+            let hier = wave.hierarchy();
+            for var in [state, prev] {
+                let (name, mapping) = hier[var].enum_type(hier).unwrap_or_else(|| {
+                    panic!("{} has no enum table (fst={fst})", hier[var].name(hier))
+                });
+                assert_eq!(name, "State");
+                assert_eq!(mapping, [("00", "Idle"), ("01", "Busy"), ("10", "Done")]);
+            }
+
+            let refs = [state, data, elements[5]].map(|x| hier[x].signal_ref());
+            wave.load_signals(&refs);
+            let n = wave.time_table().len();
+            let last: Vec<_> = refs
+                .iter()
+                .map(|x| {
+                    let signal = wave.get_signal(*x).unwrap();
+                    let offset = signal.get_offset((n - 1) as u32).unwrap();
+                    signal.get_value_at(&offset, 0).to_bit_string().unwrap()
+                })
+                .collect();
+            assert_eq!(last, ["01", "0101", "1010"], "fst={fst}");
+
+            std::fs::remove_file(&path).ok();
+        }
+    }
+}
+
 #[test]
 fn testbench_array_input_port() {
     let code = r#"
@@ -3269,3 +3399,457 @@ fn tb_chunk_does_not_outrun_the_settle() {
         );
     }
 }
+
+#[test]
+fn tb_cover_points() {
+    use crate::cover_table::{self, CoverBin, CoverKind};
+
+    let code = r#"
+    #[test(test_cover)]
+    module test_cover {
+        inst clk: $tb::clock_gen;
+        inst rst: $tb::reset_gen(clk);
+
+        var x: logic<4>;
+
+        always_ff (clk) {
+            $cover_bins(x, "x_bins", 4);
+            $cover(x == 4'd15, "x_max");
+        }
+
+        initial {
+            x = 0;
+            rst.assert();
+            for i in 0..6 {
+                x = i;
+                clk.next();
+            }
+            $cover(x == 4'd5, "x_last");
+            $finish();
+        }
+    }
+    "#;
+
+    let bin = |low, high, hits| CoverBin { low, high, hits };
+
+    for config in Config::all() {
+        let ir = analyze_top(code, &config, "test_cover").unwrap();
+        let module_name = ir.name.to_string();
+        let result = run_native_testbench(ir, None, module_name);
+        assert_eq!(result.unwrap(), TestResult::Pass, "config={config:?}");
+
+        // Three reset cycles sample 0, then 0..=5 once each.
+        let points = cover_table::take();
+        let kinds: Vec<_> = points
+            .iter()
+            .map(|p| (p.name.as_str(), p.samples, &p.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (
+                    "x_bins",
+                    9,
+                    &CoverKind::Bins(vec![
+                        bin(0, 3, 7),
+                        bin(4, 7, 2),
+                        bin(8, 11, 0),
+                        bin(12, 15, 0),
+                    ])
+                ),
+                ("x_max", 9, &CoverKind::Point { hits: 0 }),
+                ("x_last", 1, &CoverKind::Point { hits: 1 }),
+            ],
+            "config={config:?}"
+        );
+        assert_eq!(points[0].bins_hit(), (4, 2));
+        assert_eq!(points[1].bins_hit(), (1, 0));
+
+        // A second run of the same test adds up by name.
+        let mut merged = points.clone();
+        cover_table::merge(&mut merged, points);
+        assert_eq!(merged[0].samples, 18);
+        assert_eq!(merged[2].kind, CoverKind::Point { hits: 2 });
+    }
+}
+
+/// `$wait` runs every clock at its own period: edges of both domains are
+/// taken in time order, and `clk.next(n)` keeps the other clock running.
+#[test]
+fn tb_wait_time_wheel() {
+    let code = r#"
+    module Counter (
+        clk: input  clock     ,
+        rst: input  reset     ,
+        cnt: output logic<32> ,
+    ) {
+        always_ff {
+            if_reset { cnt = 0; }
+            else     { cnt += 1; }
+        }
+    }
+
+    #[test(test_wait)]
+    module test_wait {
+        inst clk_a: $tb::clock_gen #(period: 10);
+        inst clk_b: $tb::clock_gen #(period: 4);
+        inst rst  : $tb::reset_gen (clk: clk_a);
+
+        var cnt_a: logic<32>;
+        var cnt_b: logic<32>;
+
+        inst a: Counter (clk: clk_a, rst, cnt: cnt_a);
+        inst b: Counter (clk: clk_b, rst, cnt: cnt_b);
+
+        initial {
+            // clk_a rises at 0, 10, 20; clk_b keeps clocking in reset.
+            rst.assert();
+            $assert(cnt_b == 32'd0);
+            // [30, 130): 10 edges of clk_a, 25 of clk_b.
+            $wait(100);
+            $assert(cnt_a == 32'd10);
+            $assert(cnt_b == 32'd25);
+            // clk_a rises at 130 and 140; clk_b 5 times before 150.
+            clk_a.next(2);
+            $assert(cnt_a == 32'd12);
+            $assert(cnt_b == 32'd30);
+            $wait(3);
+            $assert(cnt_b == 32'd31);
+            $finish();
+        }
+    }
+    "#;
+
+    for config in Config::all() {
+        let ir = analyze_top(code, &config, "test_wait").unwrap();
+        let module_name = ir.name.to_string();
+        let result = run_native_testbench(ir, None, module_name);
+        assert_eq!(result.unwrap(), TestResult::Pass, "config={config:?}");
+    }
+}
+
+/// Rising edges of two clocks at one time are one step: the domain of
+/// `clk_b` samples the count of `clk_a` from before the shared edge, whichever
+/// clock is declared first.
+#[test]
+fn tb_wait_coinciding_edges() {
+    let code = r#"
+    module Counter (
+        clk: input  clock     ,
+        rst: input  reset     ,
+        cnt: output logic<32> ,
+    ) {
+        always_ff {
+            if_reset { cnt = 0; }
+            else     { cnt += 1; }
+        }
+    }
+
+    module Sampler (
+        clk: input  clock     ,
+        d  : input  logic<32> ,
+        q  : output logic<32> ,
+    ) {
+        always_ff {
+            q = d;
+        }
+    }
+
+    #[test(test_edges)]
+    module test_edges {
+        inst CLK_FIRST : $tb::clock_gen #(period: 10);
+        inst CLK_SECOND: $tb::clock_gen #(period: 10);
+        inst rst       : $tb::reset_gen (clk: clk_a);
+
+        var cnt_a: logic<32>;
+        var smp_b: logic<32>;
+
+        inst a: Counter (clk: clk_a, rst, cnt: cnt_a);
+        inst b: Sampler (clk: clk_b, d: cnt_a, q: smp_b);
+
+        initial {
+            rst.assert();
+            $wait(100);
+            $assert(cnt_a == 32'd10);
+            $assert(smp_b == cnt_a - 32'd1);
+            $finish();
+        }
+    }
+    "#;
+
+    for (first, second) in [("clk_a", "clk_b"), ("clk_b", "clk_a")] {
+        let code = code
+            .replace("CLK_FIRST", first)
+            .replace("CLK_SECOND", second);
+        for config in Config::all() {
+            let ir = analyze_top(&code, &config, "test_edges").unwrap();
+            let module_name = ir.name.to_string();
+            let result = run_native_testbench(ir, None, module_name);
+            assert_eq!(
+                result.unwrap(),
+                TestResult::Pass,
+                "first={first} config={config:?}"
+            );
+        }
+    }
+}
+
+/// `#[fork]` threads interleave on the shared clock: each runs until it waits,
+/// and `$wait_until` conditions are re-checked after every edge.
+#[test]
+fn tb_fork_wait_until() {
+    let code = r#"
+    module Counter (
+        clk: input  clock     ,
+        rst: input  reset     ,
+        cnt: output logic<32> ,
+    ) {
+        always_ff {
+            if_reset { cnt = 0; }
+            else     { cnt += 1; }
+        }
+    }
+
+    #[test(test_fork)]
+    module test_fork {
+        inst clk: $tb::clock_gen;
+        inst rst: $tb::reset_gen (clk);
+
+        var cnt   : logic<32>;
+        var seen  : logic<32>;
+        var ready : logic    ;
+        var polls : logic<32>;
+
+        inst u: Counter (clk, rst, cnt);
+
+        initial {
+            ready = 0;
+            seen  = 0;
+            polls = 0;
+            rst.assert();
+            #[fork]
+            block {
+                block {
+                    clk.next(5);
+                    ready = 1;
+                }
+                block {
+                    $wait_until(ready);
+                    seen = cnt;
+                }
+                block {
+                    for _i in 0..10 {
+                        if cnt >= 32'd3 {
+                            break;
+                        }
+                        polls += 1;
+                        clk.next();
+                    }
+                }
+                $wait_until(cnt >= 32'd8);
+            }
+            $assert(seen == 32'd5);
+            $assert(polls == 32'd3);
+            $assert(cnt == 32'd8);
+            $wait_until(cnt == 32'd10);
+            $assert(cnt == 32'd10);
+            $finish();
+        }
+    }
+    "#;
+
+    for config in Config::all() {
+        let ir = analyze_top(code, &config, "test_fork").unwrap();
+        let module_name = ir.name.to_string();
+        let result = run_native_testbench(ir, None, module_name);
+        assert_eq!(result.unwrap(), TestResult::Pass, "config={config:?}");
+    }
+}
+
+/// `$force` holds a comb variable against its driver and an FF against its
+/// update, down to single bits of a child instance; `$release` hands both
+/// back.
+#[test]
+fn tb_force_release() {
+    let code = r#"
+    module Parity (
+        clk : input  clock    ,
+        rst : input  reset    ,
+        data: input  logic<8> ,
+        err : output logic    ,
+        cnt : output logic<8> ,
+    ) {
+        var word: logic<8>;
+        assign word = data;
+        assign err  = ^word;
+        always_ff {
+            if_reset { cnt = 0; }
+            else     { cnt += 1; }
+        }
+    }
+
+    #[test(test_force)]
+    module test_force {
+        inst clk: $tb::clock_gen;
+        inst rst: $tb::reset_gen (clk);
+
+        var data: logic<8>;
+        var err : logic   ;
+        var cnt : logic<8>;
+
+        inst u: Parity (clk, rst, data, err, cnt);
+
+        initial {
+            data = 8'h03;
+            rst.assert();
+            $assert(err == 1'b0);
+
+            // Flip one bit of the child's comb word: the checker sees it.
+            $force(u.word[4], 1'b1);
+            $assert(u.word == 8'h13);
+            $assert(err == 1'b1);
+            data = 8'h00;
+            $assert(u.word == 8'h10);
+            $release(u.word[4]);
+            $assert(u.word == 8'h00);
+            $assert(err == 1'b0);
+
+            // A forced FF stops counting and keeps the value once released.
+            clk.next(2);
+            $assert(cnt == 8'd2);
+            $force(u.cnt, 8'd40);
+            clk.next(3);
+            $assert(cnt == 8'd40);
+            $release(u.cnt);
+            $assert(cnt == 8'd40);
+            clk.next();
+            $assert(cnt == 8'd41);
+            $finish();
+        }
+    }
+    "#;
+
+    for config in Config::all() {
+        let ir = analyze_top(code, &config, "test_force").unwrap();
+        let module_name = ir.name.to_string();
+        let result = run_native_testbench(ir, None, module_name);
+        assert_eq!(result.unwrap(), TestResult::Pass, "config={config:?}");
+    }
+}
+
+#[test]
+fn tb_selective_wave_dump() {
+    let code = r#"
+    module Leaf (
+        clk: input  clock   ,
+        rst: input  reset   ,
+        cnt: output logic<8>,
+    ) {
+        always_ff {
+            if_reset { cnt = 0; }
+            else     { cnt += 1; }
+        }
+    }
+
+    module Mid (
+        clk: input  clock   ,
+        rst: input  reset   ,
+        cnt: output logic<8>,
+    ) {
+        var inner: logic<8>;
+        inst u_c: Leaf (clk, rst, cnt: inner);
+        assign cnt = inner;
+    }
+
+    #[test(test_wave)]
+    module test_wave {
+        inst clk: $tb::clock_gen;
+        inst rst: $tb::reset_gen (clk);
+
+        var a: logic<8>;
+        var b: logic<8>;
+
+        inst u_a: Leaf (clk, rst, cnt: a);
+        inst u_b: Mid (clk, rst, cnt: b);
+
+        initial {
+            rst.assert();
+            clk.next(6);
+            $dumpoff();
+            clk.next(3);
+            $dumpon();
+            clk.next(2);
+            $finish();
+        }
+    }
+    "#;
+
+    use crate::wave_dumper::{SharedVec, WaveDumper, WaveFilter};
+    use std::collections::BTreeSet;
+
+    // Runs the test and returns the waveform's scopes and the values it
+    // recorded for `u_b.u_c.cnt`.
+    let run = |config: &Config, filter: WaveFilter| {
+        let ir = analyze_top(code, config, "test_wave").unwrap();
+        let module_name = ir.name.to_string();
+        let dump_buf = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let dumper = WaveDumper::new_vcd(Box::new(SharedVec(dump_buf.clone()))).with_filter(filter);
+        let result = run_native_testbench(ir, Some(dumper), module_name);
+        assert_eq!(result.unwrap(), TestResult::Pass);
+
+        let dump = std::mem::take(&mut *dump_buf.lock().unwrap());
+        let mut parser = vcd::Parser::new(dump.as_slice());
+        let header = parser.parse_header().unwrap();
+        let has = |path: &[&str]| header.find_var(path).is_some();
+        let vars = [
+            has(&["test_wave", "a"]),
+            has(&["test_wave", "u_a", "cnt"]),
+            has(&["test_wave", "u_b", "inner"]),
+            has(&["test_wave", "u_b", "u_c", "cnt"]),
+        ];
+        let Some(cnt) = header.find_var(&["test_wave", "u_b", "u_c", "cnt"]) else {
+            return (vars, BTreeSet::new());
+        };
+        let code = cnt.code;
+        let mut values = BTreeSet::new();
+        for cmd in parser {
+            if let vcd::Command::ChangeVector(x, vec) = cmd.unwrap()
+                && x == code
+            {
+                let value = vec
+                    .iter()
+                    .fold(0u64, |acc, x| (acc << 1) | (x == vcd::Value::V1) as u64);
+                values.insert(value);
+            }
+        }
+        (vars, values)
+    };
+
+    for config in Config::all() {
+        // Everything, but nothing from the `$dumpoff()` after cycle 6 to the
+        // `$dumpon()` after 9.
+        let (vars, values) = run(&config, WaveFilter::default());
+        assert_eq!(vars, [true; 4]);
+        assert_eq!(values, BTreeSet::from([0, 1, 2, 3, 4, 5, 6, 10, 11]));
+
+        // `u_b` and its children, from cycle 2 up to 5.
+        let filter = WaveFilter {
+            scopes: vec!["u_b".to_string()],
+            depth: Some(2),
+            start: Some(2),
+            stop: Some(5),
+        };
+        let (vars, values) = run(&config, filter);
+        assert_eq!(vars, [false, false, true, true]);
+        assert_eq!(values, BTreeSet::from([3, 4, 5]));
+
+        // `u_b` alone.
+        let filter = WaveFilter {
+            scopes: vec!["u_b".to_string()],
+            depth: Some(1),
+            ..Default::default()
+        };
+        let (vars, _) = run(&config, filter);
+        assert_eq!(vars, [false, false, true, false]);
+    }
+}
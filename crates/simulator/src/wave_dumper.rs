@@ -1,8 +1,11 @@
+use crate::HashMap;
 use crate::ir::{ModuleVariables, Value, read_native_value};
-use std::io::Write;
-use std::path::PathBuf;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
-use vcd::{self, SimulationCommand, TimescaleUnit};
+use vcd::{self, ScopeType, SimulationCommand, TimescaleUnit};
+use veryl_analyzer::ir::{Type, TypeKind, TypeKindEnum};
+use veryl_analyzer::symbol::SymbolId;
 
 /// Write adapter backed by a shared `Vec<u8>`, used in tests to capture VCD output.
 pub struct SharedVec(pub Arc<Mutex<Vec<u8>>>);
@@ -26,6 +29,81 @@ pub enum VarHandle {
 pub struct WaveDumper {
     kind: WaveDumperKind,
     path: Option<PathBuf>,
+    /// Power of ten of one time unit, in seconds.
+    timescale_exponent: i8,
+    filter: WaveFilter,
+    /// Cleared by `$dumpoff()`, set again by `$dumpon()`.
+    on: bool,
+}
+
+/// What a `WaveDumper` records, and when.
+#[derive(Clone, Debug, Default)]
+pub struct WaveFilter {
+    /// Instance paths from the top (`u_soc.u_core`) recorded with the
+    /// instances below them; empty records the whole design.
+    pub scopes: Vec<String>,
+    /// Instance levels recorded from each scope, `1` being the scope alone;
+    /// `None` records every level.
+    pub depth: Option<usize>,
+    /// Testbench clock cycle recording starts at.
+    pub start: Option<u64>,
+    /// Testbench clock cycle recording stops at.
+    pub stop: Option<u64>,
+}
+
+impl WaveFilter {
+    /// Whether the variables of the instance at `path` (`""` for the top)
+    /// are recorded.
+    fn records_scope(&self, path: &str) -> bool {
+        let within = |x: usize| self.depth.is_none_or(|depth| x < depth);
+        if self.scopes.is_empty() {
+            return within(levels(path));
+        }
+        self.scopes
+            .iter()
+            .filter_map(|scope| levels_below(path, scope))
+            .any(within)
+    }
+
+    /// Whether the instance at `path` is recorded or leads to one that is.
+    fn reaches_scope(&self, path: &str) -> bool {
+        path.is_empty()
+            || self.records_scope(path)
+            || self
+                .scopes
+                .iter()
+                .any(|x| x.strip_prefix(path).is_some_and(|x| x.starts_with('.')))
+    }
+
+    /// Whether the testbench cycle `cycle` falls in the recorded window.
+    fn records_cycle(&self, cycle: u64) -> bool {
+        self.start.is_none_or(|x| cycle >= x) && self.stop.is_none_or(|x| cycle < x)
+    }
+}
+
+/// Instance levels below the top in `path`.
+fn levels(path: &str) -> usize {
+    if path.is_empty() {
+        0
+    } else {
+        path.matches('.').count() + 1
+    }
+}
+
+/// The instance at `path` below `module`.
+fn find_scope<'a>(module: &'a ModuleVariables, path: &str) -> Option<&'a ModuleVariables> {
+    path.split('.').try_fold(module, |module, name| {
+        module.children.iter().find(|x| x.name.to_string() == name)
+    })
+}
+
+/// Instance levels `path` lies below `scope`; `None` outside it.
+fn levels_below(path: &str, scope: &str) -> Option<usize> {
+    let rest = path.strip_prefix(scope)?;
+    match rest.strip_prefix('.') {
+        Some(rest) => Some(levels(rest)),
+        None => rest.is_empty().then_some(0),
+    }
 }
 
 enum WaveDumperKind {
@@ -35,10 +113,20 @@ enum WaveDumperKind {
 
 struct VcdDumper {
     writer: vcd::Writer<Box<dyn Write + Send>>,
+    /// Handles of the enum tables declared so far.
+    enum_tables: HashMap<SymbolId, usize>,
 }
 
 struct FstDumper {
     state: FstState,
+    path: PathBuf,
+    /// Variables declared so far.
+    vars: usize,
+    /// Handles of the enum tables declared so far.
+    enum_tables: HashMap<SymbolId, usize>,
+    /// Hierarchy attributes fst-writer can't write, with the index of the
+    /// variable each goes before; see [`insert_fst_attributes`].
+    attributes: Vec<(usize, Vec<u8>)>,
 }
 
 enum FstState {
@@ -49,18 +137,33 @@ enum FstState {
 
 impl WaveDumper {
     pub fn new_vcd(io: Box<dyn Write + Send>) -> Self {
+        Self::new_vcd_with_timescale(io, -6)
+    }
+
+    /// A VCD dumper whose time unit is `10^exponent` s; a multiple of three
+    /// from -15 (fs) to 0 (s).
+    pub fn new_vcd_with_timescale(io: Box<dyn Write + Send>, exponent: i8) -> Self {
         WaveDumper {
             kind: WaveDumperKind::Vcd(VcdDumper {
                 writer: vcd::Writer::new(io),
+                enum_tables: HashMap::default(),
             }),
             path: None,
+            timescale_exponent: exponent,
+            filter: WaveFilter::default(),
+            on: true,
         }
     }
 
     pub fn new_fst(path: &str) -> Self {
+        Self::new_fst_with_timescale(path, -6)
+    }
+
+    /// An FST dumper whose time unit is `10^exponent` s.
+    pub fn new_fst_with_timescale(path: &str, exponent: i8) -> Self {
         let info = fst_writer::FstInfo {
             start_time: 0,
-            timescale_exponent: -6, // 1us
+            timescale_exponent: exponent,
             version: "Veryl Simulator".to_string(),
             date: String::new(),
             file_type: fst_writer::FstFileType::Verilog,
@@ -69,8 +172,15 @@ impl WaveDumper {
         WaveDumper {
             kind: WaveDumperKind::Fst(Box::new(FstDumper {
                 state: FstState::Header(header),
+                path: PathBuf::from(path),
+                vars: 0,
+                enum_tables: HashMap::default(),
+                attributes: Vec::new(),
             })),
             path: None,
+            timescale_exponent: exponent,
+            filter: WaveFilter::default(),
+            on: true,
         }
     }
 
@@ -79,6 +189,22 @@ impl WaveDumper {
         self
     }
 
+    /// Records only what `filter` selects.
+    pub fn with_filter(mut self, filter: WaveFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Resumes (`$dumpon()`) or stops (`$dumpoff()`) recording.
+    pub fn set_on(&mut self, on: bool) {
+        self.on = on;
+    }
+
+    /// Whether values at testbench cycle `cycle` are recorded.
+    pub fn records(&self, cycle: u64) -> bool {
+        self.on && self.filter.records_cycle(cycle)
+    }
+
     pub fn path(&self) -> Option<&PathBuf> {
         self.path.as_ref()
     }
@@ -90,7 +216,15 @@ impl WaveDumper {
     pub fn timescale(&mut self) {
         match &mut self.kind {
             WaveDumperKind::Vcd(v) => {
-                v.writer.timescale(1, TimescaleUnit::US).unwrap();
+                let unit = match self.timescale_exponent {
+                    0 => TimescaleUnit::S,
+                    -3 => TimescaleUnit::MS,
+                    -9 => TimescaleUnit::NS,
+                    -12 => TimescaleUnit::PS,
+                    -15 => TimescaleUnit::FS,
+                    _ => TimescaleUnit::US,
+                };
+                v.writer.timescale(1, unit).unwrap();
             }
             WaveDumperKind::Fst(_) => {
                 // Already set in FstInfo during construction
@@ -129,6 +263,7 @@ impl WaveDumper {
                             None,
                         )
                         .unwrap();
+                    f.vars += 1;
                     VarHandle::Fst(id)
                 }
                 _ => panic!("FST: add_wire called after header finished"),
@@ -136,6 +271,76 @@ impl WaveDumper {
         }
     }
 
+    /// Opens the scope of a struct (`union == false`) or union variable.
+    fn add_struct_scope(&mut self, name: &str, union: bool) {
+        match &mut self.kind {
+            WaveDumperKind::Vcd(v) => {
+                // VCD has no struct scope type.
+                v.writer.scope_def(ScopeType::Begin, name).unwrap();
+            }
+            WaveDumperKind::Fst(f) => match &mut f.state {
+                FstState::Header(h) => {
+                    let tpe = if union {
+                        fst_writer::FstScopeType::Union
+                    } else {
+                        fst_writer::FstScopeType::Struct
+                    };
+                    h.scope(name, "", tpe).unwrap();
+                }
+                _ => panic!("FST: add_struct_scope called after header finished"),
+            },
+        }
+    }
+
+    /// Adds a wire whose values `r#enum` names.
+    fn add_enum_wire(&mut self, width: u32, name: &str, r#enum: &TypeKindEnum) -> VarHandle {
+        let variants: Vec<_> = r#enum
+            .variants
+            .iter()
+            .filter_map(|x| Some((x.name, x.value.as_ref()?)))
+            .collect();
+        if variants.is_empty() {
+            return self.add_wire(width, name);
+        }
+
+        // GTKWave's enum table attributes: the table once, then a reference
+        // to it before each variable it names.
+        let table = || {
+            let mut table = format!("{} {}", r#enum.name, variants.len());
+            for (name, _) in &variants {
+                table.push_str(&format!(" {name}"));
+            }
+            for (_, value) in &variants {
+                table.push_str(&format!(" {value:0>width$b}", width = width as usize));
+            }
+            table
+        };
+        let enum_tables = match &mut self.kind {
+            WaveDumperKind::Vcd(v) => &mut v.enum_tables,
+            WaveDumperKind::Fst(f) => &mut f.enum_tables,
+        };
+        let next = enum_tables.len() + 1;
+        let handle = *enum_tables.entry(r#enum.id).or_insert(next);
+        match &mut self.kind {
+            WaveDumperKind::Vcd(v) => {
+                let out = v.writer.writer();
+                if handle == next {
+                    writeln!(out, "$attrbegin misc 07 {} {handle} $end", table()).unwrap();
+                }
+                writeln!(out, "$attrbegin misc 07 \"\" {handle} $end").unwrap();
+            }
+            WaveDumperKind::Fst(f) => {
+                let mut attributes = Vec::new();
+                if handle == next {
+                    push_fst_enum_table(&mut attributes, &table(), handle);
+                }
+                push_fst_enum_table(&mut attributes, "", handle);
+                f.attributes.push((f.vars, attributes));
+            }
+        }
+        self.add_wire(width, name)
+    }
+
     pub fn upscope(&mut self) {
         match &mut self.kind {
             WaveDumperKind::Vcd(v) => {
@@ -228,37 +433,113 @@ impl WaveDumper {
     }
 
     pub fn setup_module(&mut self, module_vars: &ModuleVariables, dump_vars: &mut Vec<DumpVar>) {
+        for scope in &self.filter.scopes {
+            if find_scope(module_vars, scope).is_none() {
+                log::warn!(
+                    "wave scope \"{scope}\" is not an instance path; nothing recorded for it"
+                );
+            }
+        }
+        self.setup_scope(module_vars, "", dump_vars);
+    }
+
+    /// Declares the instance at `path` with the variables the filter
+    /// records, then its children.
+    fn setup_scope(
+        &mut self,
+        module_vars: &ModuleVariables,
+        path: &str,
+        dump_vars: &mut Vec<DumpVar>,
+    ) {
         self.add_module(&sanitize_wave_name(&module_vars.name.to_string()));
 
-        for x in module_vars.variables.values() {
+        let variables = if self.filter.records_scope(path) {
+            module_vars.variables.values().collect()
+        } else {
+            vec![]
+        };
+        for x in variables {
             let name = sanitize_wave_name(&x.path.to_string());
-            let width = x.width as u32;
             // One pointer per array element (scalar = one). Dump a `name[i]`
-            // wire per element, not just element [0].
-            let num_elements = x.current_values.len();
-            for (i, &ptr) in x.current_values.iter().enumerate() {
-                let elem_name = if num_elements > 1 {
-                    format!("{name}[{i}]")
-                } else {
-                    name.clone()
-                };
-                let handle = self.add_wire(width, &elem_name);
-                dump_vars.push(DumpVar {
-                    handle,
+            // wire (or struct scope) per element, not just element [0].
+            let names = element_names(&name, &x.r#type, x.current_values.len());
+            for (elem_name, &ptr) in names.iter().zip(&x.current_values) {
+                let element = Element {
                     ptr,
                     native_bytes: x.native_bytes,
                     width: x.width,
-                });
+                };
+                if x.r#type.total_width() == Some(x.width) {
+                    self.add_field(&x.r#type, elem_name, 0, &element, dump_vars);
+                } else {
+                    let handle = self.add_wire(x.width as u32, elem_name);
+                    dump_vars.push(element.dump_var(handle, None));
+                }
             }
         }
 
         for child in &module_vars.children {
-            self.setup_module(child, dump_vars);
+            let path = if path.is_empty() {
+                child.name.to_string()
+            } else {
+                format!("{path}.{}", child.name)
+            };
+            if self.filter.reaches_scope(&path) {
+                self.setup_scope(child, &path, dump_vars);
+            }
         }
 
         self.upscope();
     }
 
+    /// Declares bits `lsb..` of `element`, of type `r#type`: a scope of
+    /// members for a struct or union, a wire otherwise.
+    fn add_field(
+        &mut self,
+        r#type: &Type,
+        name: &str,
+        lsb: usize,
+        element: &Element,
+        dump_vars: &mut Vec<DumpVar>,
+    ) {
+        // The element of a packed array of structs or enums is dumped whole.
+        let scalar = r#type.width().total() == Some(1);
+        let width = r#type.total_width().unwrap_or(element.width);
+        let members = match &r#type.kind {
+            TypeKind::Struct(x) if scalar => Some((&x.members, false)),
+            TypeKind::Union(x) if scalar => Some((&x.members, true)),
+            _ => None,
+        };
+        if let Some((members, union)) = members
+            && members.iter().all(|x| x.r#type.total_width().is_some())
+        {
+            self.add_struct_scope(name, union);
+            // Struct members are laid out from the MSB down; union members
+            // all start at the LSB.
+            let mut msb = lsb + width;
+            for member in members {
+                let member_width = member.r#type.total_width().unwrap();
+                let member_lsb = if union {
+                    lsb
+                } else {
+                    msb -= member_width;
+                    msb
+                };
+                let member_name = member.name.to_string();
+                self.add_field(&member.r#type, &member_name, member_lsb, element, dump_vars);
+            }
+            self.upscope();
+            return;
+        }
+
+        let handle = match &r#type.kind {
+            TypeKind::Enum(x) if scalar => self.add_enum_wire(width as u32, name, x),
+            _ => self.add_wire(width as u32, name),
+        };
+        let field = (width != element.width).then_some((lsb, width));
+        dump_vars.push(element.dump_var(handle, field));
+    }
+
     pub fn dump_all_vars(&mut self, dump_vars: &[DumpVar], use_4state: bool) {
         for entry in dump_vars {
             let mut value = unsafe {
@@ -271,6 +552,9 @@ impl WaveDumper {
                 )
             };
             value.trunc(entry.width);
+            if let Some((lsb, width)) = entry.field {
+                value = value.select(lsb + width - 1, lsb);
+            }
             self.change_vector(entry.handle, &value);
         }
     }
@@ -279,10 +563,155 @@ impl WaveDumper {
 impl Drop for FstDumper {
     fn drop(&mut self) {
         let old = std::mem::replace(&mut self.state, FstState::Transitioning);
-        if let FstState::Body(body) = old {
-            let _ = body.finish();
+        if let FstState::Body(body) = old
+            && body.finish().is_ok()
+            && !self.attributes.is_empty()
+            && let Err(x) = insert_fst_attributes(&self.path, &self.attributes)
+        {
+            log::warn!(
+                "failed to write enum tables to {}: {x}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+const FST_BLOCK_HIERARCHY_LZ4: u8 = 6;
+const FST_BLOCK_SKIP: u8 = 255;
+const FST_HIERARCHY_SCOPE: u8 = 254;
+const FST_HIERARCHY_UP_SCOPE: u8 = 255;
+const FST_HIERARCHY_ATTRIBUTE_BEGIN: u8 = 252;
+const FST_HIERARCHY_ATTRIBUTE_END: u8 = 253;
+
+/// Appends the attribute that defines enum table `handle` as `table`, or
+/// refers to it when `table` is empty.
+fn push_fst_enum_table(out: &mut Vec<u8>, table: &str, handle: usize) {
+    // Misc attribute, enum table subtype.
+    out.extend([FST_HIERARCHY_ATTRIBUTE_BEGIN, 0, 7]);
+    out.extend(table.as_bytes());
+    out.push(0);
+    let mut value = handle as u64;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Adds `attributes` to the hierarchy of the finished FST file at `path`.
+///
+/// fst-writer has no API for attributes, so the hierarchy block it wrote is
+/// turned into a skip block and a copy with the attributes is appended;
+/// readers take the hierarchy from wherever it is in the file.
+fn insert_fst_attributes(path: &Path, attributes: &[(usize, Vec<u8>)]) -> std::io::Result<()> {
+    let invalid = |x: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, x.to_string());
+
+    let mut file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)?;
+    let file_len = file.metadata()?.len();
+    // Every block is its type, then a big-endian length covering the rest.
+    let mut pos = 0;
+    let section_len = loop {
+        let mut head = [0; 9];
+        file.seek(SeekFrom::Start(pos))?;
+        file.read_exact(&mut head)?;
+        let section_len = u64::from_be_bytes(head[1..].try_into().unwrap());
+        if head[0] == FST_BLOCK_HIERARCHY_LZ4 {
+            break section_len;
         }
+        pos += 1 + section_len;
+        if section_len == 0 || pos >= file_len {
+            return Err(invalid("no hierarchy block"));
+        }
+    };
+    let mut raw_len = [0; 8];
+    file.read_exact(&mut raw_len)?;
+    let raw_len = u64::from_be_bytes(raw_len) as usize;
+    let mut compressed = Vec::new();
+    (&mut file)
+        .take(section_len.saturating_sub(16))
+        .read_to_end(&mut compressed)?;
+    let hierarchy =
+        lz4_flex::decompress(&compressed, raw_len).map_err(|x| invalid(&x.to_string()))?;
+
+    let hierarchy = insert_hierarchy_attributes(&hierarchy, attributes)
+        .ok_or_else(|| invalid("malformed hierarchy block"))?;
+    let compressed = lz4_flex::compress(&hierarchy);
+
+    file.seek(SeekFrom::Start(pos))?;
+    file.write_all(&[FST_BLOCK_SKIP])?;
+    file.seek(SeekFrom::End(0))?;
+    file.write_all(&[FST_BLOCK_HIERARCHY_LZ4])?;
+    file.write_all(&(16 + compressed.len() as u64).to_be_bytes())?;
+    file.write_all(&(hierarchy.len() as u64).to_be_bytes())?;
+    file.write_all(&compressed)?;
+    Ok(())
+}
+
+/// `hierarchy` with each of `attributes` inserted before the variable of its
+/// index; `None` if the entries can't be parsed.
+fn insert_hierarchy_attributes(
+    hierarchy: &[u8],
+    attributes: &[(usize, Vec<u8>)],
+) -> Option<Vec<u8>> {
+    fn c_str(x: &[u8], pos: usize) -> Option<usize> {
+        Some(pos + x.get(pos..)?.iter().position(|x| *x == 0)? + 1)
+    }
+    fn varint(x: &[u8], pos: usize) -> Option<usize> {
+        Some(pos + x.get(pos..)?.iter().position(|x| x & 0x80 == 0)? + 1)
+    }
+
+    let mut ret = Vec::with_capacity(hierarchy.len() + attributes.len() * 16);
+    let mut attributes = attributes.iter().peekable();
+    let mut var = 0;
+    let mut pos = 0;
+    while pos < hierarchy.len() {
+        let end = match hierarchy[pos] {
+            FST_HIERARCHY_SCOPE => c_str(hierarchy, c_str(hierarchy, pos + 2)?)?,
+            FST_HIERARCHY_UP_SCOPE | FST_HIERARCHY_ATTRIBUTE_END => pos + 1,
+            FST_HIERARCHY_ATTRIBUTE_BEGIN => varint(hierarchy, c_str(hierarchy, pos + 3)?)?,
+            _ => {
+                while let Some((_, x)) = attributes.next_if(|(index, _)| *index == var) {
+                    ret.extend(x);
+                }
+                var += 1;
+                // Type, direction, name, length and alias.
+                varint(hierarchy, varint(hierarchy, c_str(hierarchy, pos + 2)?)?)?
+            }
+        };
+        ret.extend(hierarchy.get(pos..end)?);
+        pos = end;
     }
+    Some(ret)
+}
+
+/// The wave names of the `len` elements of a variable of type `r#type`:
+/// `name[i][j]` by the array dimensions, `name` alone for a scalar.
+fn element_names(name: &str, r#type: &Type, len: usize) -> Vec<String> {
+    if len == 1 {
+        return vec![name.to_string()];
+    }
+    let dims: Vec<usize> = match r#type.array.iter().copied().collect::<Option<Vec<_>>>() {
+        Some(dims) if dims.iter().product::<usize>() == len => dims,
+        _ => vec![len],
+    };
+    (0..len)
+        .map(|i| {
+            let mut index = String::new();
+            let mut rest = i;
+            for dim in dims.iter().rev() {
+                index.insert_str(0, &format!("[{}]", rest % dim));
+                rest /= dim;
+            }
+            format!("{name}{index}")
+        })
+        .collect()
 }
 
 fn sanitize_wave_name(name: &str) -> String {
@@ -294,7 +723,29 @@ pub struct DumpVar {
     pub ptr: *const u8,
     pub native_bytes: usize,
     pub width: usize,
+    /// Lowest bit and width of the struct member dumped from the element;
+    /// `None` dumps it whole.
+    pub field: Option<(usize, usize)>,
 }
 
 // SAFETY: Same as Statement — see statement.rs.
 unsafe impl Send for DumpVar {}
+
+/// An element of a variable being declared.
+struct Element {
+    ptr: *const u8,
+    native_bytes: usize,
+    width: usize,
+}
+
+impl Element {
+    fn dump_var(&self, handle: VarHandle, field: Option<(usize, usize)>) -> DumpVar {
+        DumpVar {
+            handle,
+            ptr: self.ptr,
+            native_bytes: self.native_bytes,
+            width: self.width,
+            field,
+        }
+    }
+}
@@ -19,6 +19,8 @@ const GUEST_EXPORTS: &[&str] = &[
     "veryl_component_on_reset",
     "veryl_component_on_clock",
     "veryl_component_on_finish",
+    "veryl_component_save_state",
+    "veryl_component_restore_state",
     "veryl_component_call_method",
     "veryl_component_alloc",
     "veryl_component_free",
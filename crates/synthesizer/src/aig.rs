@@ -1,4 +1,5 @@
-//! AIG-based structural rewrite pipeline (opt-in via the `aig` Cargo feature).
+//! AIG-based structural rewrite pipeline (opt-in via the `aig` Cargo feature)
+//! and AIG export for external tools.
 //!
 //! - [`graph`] — hash-consed And-Inverter Graph data structure.
 //! - [`npn4`] — 4-variable truth-table NPN canonicalisation + library
@@ -6,12 +7,18 @@
 //! - [`convert`] — lower / raise between `GateModule` and AIG.
 //! - [`rewrite`] — cut enumeration + NPN4 pattern replacement.
 //! - [`techmap`] — map the rewritten AIG back to compound library cells.
+//! - [`sequential`] — a whole-module AIG with FFs as latches.
+//! - [`export`] — BLIF / AIGER writers for [`sequential::SequentialAig`].
 //!
 //! Flow when the feature is active (see `conv::convert_module`):
 //! `aigify` → `rewrite` → `aig_to_cells_techmap` → worklist re-converge.
+//! The module itself is always built so `veryl synth --emit-aig/--emit-blif`
+//! can export the final netlist regardless of the feature.
 
 pub mod convert;
+pub mod export;
 pub mod graph;
 pub mod npn4;
 pub mod rewrite;
+pub mod sequential;
 pub mod techmap;
@@ -28,110 +28,6 @@ pub fn aigify(gate: &GateModule) -> AigModule {
     aig.net_edge.insert(NET_CONST0, AigEdge::CONST0);
     aig.net_edge.insert(NET_CONST1, AigEdge::CONST1);
 
-    // Topological walk of cells driven by `net`, lowering each into
-    // hash-consed ANDs. We memoise on net so shared nets lower once and
-    // the hash-cons does the heavy CSE.
-    fn lower_net(aig: &mut AigModule, gate: &GateModule, net: NetId) -> AigEdge {
-        if let Some(&e) = aig.net_edge.get(&net) {
-            return e;
-        }
-        let edge = match gate.nets[net as usize].driver {
-            NetDriver::Const(false) => AigEdge::CONST0,
-            NetDriver::Const(true) => AigEdge::CONST1,
-            NetDriver::PortInput | NetDriver::FfQ(_) | NetDriver::Undriven => {
-                // Treat any non-combinational driver as a primary input
-                // for the AIG. The caller wires the same NetId back when
-                // re-emitting cells.
-                aig.add_input(net)
-            }
-            NetDriver::Cell(idx) => {
-                let cell = &gate.cells[idx];
-                lower_cell(aig, gate, cell)
-            }
-        };
-        aig.net_edge.insert(net, edge);
-        edge
-    }
-
-    fn lower_cell(aig: &mut AigModule, gate: &GateModule, cell: &Cell) -> AigEdge {
-        use CellKind::*;
-        // Resolve every input up-front so each mk_* call has exclusive
-        // access to the AIG's `&mut` borrow.
-        let inputs: Vec<AigEdge> = cell
-            .inputs
-            .iter()
-            .map(|&n| lower_net(aig, gate, n))
-            .collect();
-        match cell.kind {
-            Buf => inputs[0],
-            Not => inputs[0].negate(),
-            And2 => aig.mk_and(inputs[0], inputs[1]),
-            Or2 => aig.mk_or(inputs[0], inputs[1]),
-            Nand2 => aig.mk_and(inputs[0], inputs[1]).negate(),
-            Nor2 => aig.mk_or(inputs[0], inputs[1]).negate(),
-            Xor2 => aig.mk_xor(inputs[0], inputs[1]),
-            Xnor2 => aig.mk_xor(inputs[0], inputs[1]).negate(),
-            And3 => {
-                let ab = aig.mk_and(inputs[0], inputs[1]);
-                aig.mk_and(ab, inputs[2])
-            }
-            Or3 => {
-                let ab = aig.mk_or(inputs[0], inputs[1]);
-                aig.mk_or(ab, inputs[2])
-            }
-            Nand3 => {
-                let ab = aig.mk_and(inputs[0], inputs[1]);
-                aig.mk_and(ab, inputs[2]).negate()
-            }
-            Nor3 => {
-                let ab = aig.mk_or(inputs[0], inputs[1]);
-                aig.mk_or(ab, inputs[2]).negate()
-            }
-            Ao21 => {
-                let ab = aig.mk_and(inputs[0], inputs[1]);
-                aig.mk_or(ab, inputs[2])
-            }
-            Aoi21 => {
-                let ab = aig.mk_and(inputs[0], inputs[1]);
-                aig.mk_or(ab, inputs[2]).negate()
-            }
-            Oa21 => {
-                let ab = aig.mk_or(inputs[0], inputs[1]);
-                aig.mk_and(ab, inputs[2])
-            }
-            Oai21 => {
-                let ab = aig.mk_or(inputs[0], inputs[1]);
-                aig.mk_and(ab, inputs[2]).negate()
-            }
-            Ao31 => {
-                let ab = aig.mk_and(inputs[0], inputs[1]);
-                let abc = aig.mk_and(ab, inputs[2]);
-                aig.mk_or(abc, inputs[3])
-            }
-            Aoi31 => {
-                let ab = aig.mk_and(inputs[0], inputs[1]);
-                let abc = aig.mk_and(ab, inputs[2]);
-                aig.mk_or(abc, inputs[3]).negate()
-            }
-            Ao22 => {
-                let ab = aig.mk_and(inputs[0], inputs[1]);
-                let cd = aig.mk_and(inputs[2], inputs[3]);
-                aig.mk_or(ab, cd)
-            }
-            Aoi22 => {
-                let ab = aig.mk_and(inputs[0], inputs[1]);
-                let cd = aig.mk_and(inputs[2], inputs[3]);
-                aig.mk_or(ab, cd).negate()
-            }
-            Oai22 => {
-                let ab = aig.mk_or(inputs[0], inputs[1]);
-                let cd = aig.mk_or(inputs[2], inputs[3]);
-                aig.mk_and(ab, cd).negate()
-            }
-            Mux2 => aig.mk_mux(inputs[0], inputs[1], inputs[2]),
-        }
-    }
-
     // Seed sinks: every output / FF D input. These root the traversal;
     // anything not reachable from them stays out of the AIG.
     for port in &gate.ports {
@@ -148,10 +44,122 @@ pub fn aigify(gate: &GateModule) -> AigModule {
         // the FF index by position.
         aig.add_sink(ff.d, edge);
     }
+    // RAM-consumed nets last, so the round-trip keeps them driven.
+    let mut ram_inputs = Vec::new();
+    gate.for_each_ram_input_net(|net| ram_inputs.push(net));
+    for net in ram_inputs {
+        let edge = lower_net(&mut aig, gate, net);
+        aig.add_sink(net, edge);
+    }
 
     aig
 }
 
+/// Topological walk of cells driven by `net`, lowering each into
+/// hash-consed ANDs. We memoise on net so shared nets lower once and
+/// the hash-cons does the heavy CSE. Also used by exporters that need
+/// edges for nets `aigify` did not root (e.g. FF resets).
+pub fn lower_net(aig: &mut AigModule, gate: &GateModule, net: NetId) -> AigEdge {
+    if let Some(&e) = aig.net_edge.get(&net) {
+        return e;
+    }
+    let edge = match gate.nets[net as usize].driver {
+        NetDriver::Const(false) => AigEdge::CONST0,
+        NetDriver::Const(true) => AigEdge::CONST1,
+        NetDriver::PortInput | NetDriver::FfQ(_) | NetDriver::RamRead(..) | NetDriver::Undriven => {
+            // Treat any non-combinational driver as a primary input
+            // for the AIG. The caller wires the same NetId back when
+            // re-emitting cells.
+            aig.add_input(net)
+        }
+        NetDriver::Cell(idx) => {
+            let cell = &gate.cells[idx];
+            lower_cell(aig, gate, cell)
+        }
+    };
+    aig.net_edge.insert(net, edge);
+    edge
+}
+
+fn lower_cell(aig: &mut AigModule, gate: &GateModule, cell: &Cell) -> AigEdge {
+    use CellKind::*;
+    // Resolve every input up-front so each mk_* call has exclusive
+    // access to the AIG's `&mut` borrow.
+    let inputs: Vec<AigEdge> = cell
+        .inputs
+        .iter()
+        .map(|&n| lower_net(aig, gate, n))
+        .collect();
+    match cell.kind {
+        Buf => inputs[0],
+        Not => inputs[0].negate(),
+        And2 => aig.mk_and(inputs[0], inputs[1]),
+        Or2 => aig.mk_or(inputs[0], inputs[1]),
+        Nand2 => aig.mk_and(inputs[0], inputs[1]).negate(),
+        Nor2 => aig.mk_or(inputs[0], inputs[1]).negate(),
+        Xor2 => aig.mk_xor(inputs[0], inputs[1]),
+        Xnor2 => aig.mk_xor(inputs[0], inputs[1]).negate(),
+        And3 => {
+            let ab = aig.mk_and(inputs[0], inputs[1]);
+            aig.mk_and(ab, inputs[2])
+        }
+        Or3 => {
+            let ab = aig.mk_or(inputs[0], inputs[1]);
+            aig.mk_or(ab, inputs[2])
+        }
+        Nand3 => {
+            let ab = aig.mk_and(inputs[0], inputs[1]);
+            aig.mk_and(ab, inputs[2]).negate()
+        }
+        Nor3 => {
+            let ab = aig.mk_or(inputs[0], inputs[1]);
+            aig.mk_or(ab, inputs[2]).negate()
+        }
+        Ao21 => {
+            let ab = aig.mk_and(inputs[0], inputs[1]);
+            aig.mk_or(ab, inputs[2])
+        }
+        Aoi21 => {
+            let ab = aig.mk_and(inputs[0], inputs[1]);
+            aig.mk_or(ab, inputs[2]).negate()
+        }
+        Oa21 => {
+            let ab = aig.mk_or(inputs[0], inputs[1]);
+            aig.mk_and(ab, inputs[2])
+        }
+        Oai21 => {
+            let ab = aig.mk_or(inputs[0], inputs[1]);
+            aig.mk_and(ab, inputs[2]).negate()
+        }
+        Ao31 => {
+            let ab = aig.mk_and(inputs[0], inputs[1]);
+            let abc = aig.mk_and(ab, inputs[2]);
+            aig.mk_or(abc, inputs[3])
+        }
+        Aoi31 => {
+            let ab = aig.mk_and(inputs[0], inputs[1]);
+            let abc = aig.mk_and(ab, inputs[2]);
+            aig.mk_or(abc, inputs[3]).negate()
+        }
+        Ao22 => {
+            let ab = aig.mk_and(inputs[0], inputs[1]);
+            let cd = aig.mk_and(inputs[2], inputs[3]);
+            aig.mk_or(ab, cd)
+        }
+        Aoi22 => {
+            let ab = aig.mk_and(inputs[0], inputs[1]);
+            let cd = aig.mk_and(inputs[2], inputs[3]);
+            aig.mk_or(ab, cd).negate()
+        }
+        Oai22 => {
+            let ab = aig.mk_or(inputs[0], inputs[1]);
+            let cd = aig.mk_or(inputs[2], inputs[3]);
+            aig.mk_and(ab, cd).negate()
+        }
+        Mux2 => aig.mk_mux(inputs[0], inputs[1], inputs[2]),
+    }
+}
+
 /// Reconstruct a [`GateModule`] from an optimised AIG while preserving
 /// the original ports and FFs. All combinational cells come from ANDs
 /// and inverters in the AIG — the postpass then fuses them into
@@ -163,6 +171,7 @@ pub fn aig_to_cells(aig: &AigModule, original: &GateModule) -> GateModule {
         nets: Vec::new(),
         cells: Vec::new(),
         ffs: original.ffs.clone(),
+        ram_blocks: original.ram_blocks.clone(),
     };
 
     // Preserve the original net table layout so port / FF references
@@ -175,6 +184,7 @@ pub fn aig_to_cells(aig: &AigModule, original: &GateModule) -> GateModule {
                 NetDriver::Const(b) => NetDriver::Const(b),
                 NetDriver::PortInput => NetDriver::PortInput,
                 NetDriver::FfQ(idx) => NetDriver::FfQ(idx),
+                NetDriver::RamRead(r, p, b) => NetDriver::RamRead(r, p, b),
                 _ => NetDriver::Undriven,
             },
             origin: n.origin,
@@ -270,17 +280,22 @@ pub fn aig_to_cells(aig: &AigModule, original: &GateModule) -> GateModule {
 
     // Wire sinks: port outputs and FF D pins read from the edge's net.
     // Sinks appear in the order they were added: ports first, FFs
-    // second (see `aigify`).
+    // second, RAM inputs last (see `aigify`).
     let port_out_count: usize = original
         .ports
         .iter()
         .filter(|p| matches!(p.dir, PortDir::Output | PortDir::Inout))
         .map(|p| p.nets.len())
         .sum();
+    let ff_end = port_out_count + original.ffs.len();
 
     for (i, sink) in aig.sinks.iter().enumerate() {
         let src_net = resolve_fanin(&mut out, &mut pos_net, &mut neg_net, sink.edge);
-        if i < port_out_count {
+        if (port_out_count..ff_end).contains(&i) {
+            // FF D: rewire directly to the resolved net.
+            let ff_idx = i - port_out_count;
+            out.ffs[ff_idx].d = src_net;
+        } else {
             let target = sink.target;
             if src_net != target {
                 // Buffer target so the worklist / postpass collapses the
@@ -293,10 +308,6 @@ pub fn aig_to_cells(aig: &AigModule, original: &GateModule) -> GateModule {
                 });
                 out.nets[target as usize].driver = NetDriver::Cell(cell_idx);
             }
-        } else {
-            // FF D: rewire directly to the resolved net.
-            let ff_idx = i - port_out_count;
-            out.ffs[ff_idx].d = src_net;
         }
     }
 
@@ -0,0 +1,244 @@
+//! BLIF and AIGER writers for a [`SequentialAig`], so ABC, model checkers
+//! and equivalence tools can consume exactly the logic Veryl optimized.
+//!
+//! Internal names use `$`, which no Veryl identifier can contain, so they
+//! never collide with port names: `$and<node>` for AND nodes, `$ff<i>` for
+//! latches, `$net<id>` / `$ram..` for free nets.
+
+use crate::aig::graph::{AigEdge, AigNode};
+use crate::aig::sequential::SequentialAig;
+use crate::ir::ClockEdge;
+use std::fmt::Write;
+
+/// Berkeley Logic Interchange Format. Latches carry their clock when it is
+/// a primary input (`re`/`fe` control), otherwise they use BLIF's implicit
+/// global clock.
+pub fn write_blif(seq: &SequentialAig) -> String {
+    let mut ret = String::new();
+    let _ = writeln!(ret, "# Generated by `veryl synth`.");
+    let _ = writeln!(ret, ".model {}", seq.name);
+
+    let inputs: Vec<&str> = seq.inputs.iter().map(|x| x.name.as_str()).collect();
+    let outputs: Vec<&str> = seq.outputs.iter().map(|x| x.name.as_str()).collect();
+    if !inputs.is_empty() {
+        let _ = writeln!(ret, ".inputs {}", inputs.join(" "));
+    }
+    if !outputs.is_empty() {
+        let _ = writeln!(ret, ".outputs {}", outputs.join(" "));
+    }
+
+    let names = SignalNames::new(seq);
+
+    for latch in &seq.latches {
+        if let Some((name, bit)) = latch.origin {
+            let _ = writeln!(ret, "# {}: {}[{}]", latch.name, name, bit);
+        }
+        let control = match latch.clock {
+            Some((idx, edge)) => {
+                let kind = match edge {
+                    ClockEdge::Posedge => "re",
+                    ClockEdge::Negedge => "fe",
+                };
+                format!(" {} {}", kind, seq.inputs[idx].name)
+            }
+            None => String::new(),
+        };
+        let init = match latch.init {
+            Some(false) => 0,
+            Some(true) => 1,
+            None => 3,
+        };
+        let _ = writeln!(
+            ret,
+            ".latch {}$d {}{} {}",
+            latch.name, latch.name, control, init
+        );
+    }
+
+    for node in seq.live_ands() {
+        if let AigNode::And { fanin0, fanin1 } = &seq.aig.nodes[node as usize] {
+            let _ = writeln!(
+                ret,
+                ".names {} {} $and{}",
+                names.node(fanin0.node()),
+                names.node(fanin1.node()),
+                node
+            );
+            let _ = writeln!(
+                ret,
+                "{}{} 1",
+                if fanin0.is_negated() { '0' } else { '1' },
+                if fanin1.is_negated() { '0' } else { '1' },
+            );
+        }
+    }
+
+    for latch in &seq.latches {
+        write_blif_buffer(&mut ret, &names, latch.next, &format!("{}$d", latch.name));
+    }
+    for output in &seq.outputs {
+        write_blif_buffer(&mut ret, &names, output.edge, &output.name);
+    }
+
+    let _ = writeln!(ret, ".end");
+    ret
+}
+
+fn write_blif_buffer(ret: &mut String, names: &SignalNames, edge: AigEdge, target: &str) {
+    if edge.node() == 0 {
+        // Constant: an empty cover is 0, a single `1` row is 1.
+        let _ = writeln!(ret, ".names {}", target);
+        if edge == AigEdge::CONST1 {
+            let _ = writeln!(ret, "1");
+        }
+        return;
+    }
+    let _ = writeln!(ret, ".names {} {}", names.node(edge.node()), target);
+    let _ = writeln!(ret, "{} 1", if edge.is_negated() { '0' } else { '1' });
+}
+
+struct SignalNames {
+    names: Vec<Option<String>>,
+}
+
+impl SignalNames {
+    fn new(seq: &SequentialAig) -> Self {
+        let mut names = vec![None; seq.aig.nodes.len()];
+        for input in &seq.inputs {
+            names[input.edge.node() as usize] = Some(input.name.clone());
+        }
+        for latch in &seq.latches {
+            names[latch.current.node() as usize] = Some(latch.name.clone());
+        }
+        Self { names }
+    }
+
+    fn node(&self, node: u32) -> String {
+        match &self.names[node as usize] {
+            Some(name) => name.clone(),
+            None => format!("$and{}", node),
+        }
+    }
+}
+
+/// AIGER 1.9 (`aag` when `binary` is false, else `aig`). Latch initial
+/// values are written explicitly; an FF without reset is marked
+/// uninitialised. Clocks are implicit, as AIGER has a single global clock.
+pub fn write_aiger(seq: &SequentialAig, binary: bool) -> Vec<u8> {
+    let ands = seq.live_ands();
+    let num_inputs = seq.inputs.len() as u32;
+    let num_latches = seq.latches.len() as u32;
+
+    // AIGER variable of each node: inputs, then latches, then ANDs.
+    let mut var = vec![0u32; seq.aig.nodes.len()];
+    for (i, input) in seq.inputs.iter().enumerate() {
+        var[input.edge.node() as usize] = 1 + i as u32;
+    }
+    for (i, latch) in seq.latches.iter().enumerate() {
+        var[latch.current.node() as usize] = 1 + num_inputs + i as u32;
+    }
+    for (i, &node) in ands.iter().enumerate() {
+        var[node as usize] = 1 + num_inputs + num_latches + i as u32;
+    }
+    let lit = |e: AigEdge| 2 * var[e.node() as usize] + e.is_negated() as u32;
+
+    let max_var = num_inputs + num_latches + ands.len() as u32;
+    let mut ret = String::new();
+    let _ = writeln!(
+        ret,
+        "{} {} {} {} {} {}",
+        if binary { "aig" } else { "aag" },
+        max_var,
+        num_inputs,
+        num_latches,
+        seq.outputs.len(),
+        ands.len()
+    );
+    if !binary {
+        for input in &seq.inputs {
+            let _ = writeln!(ret, "{}", lit(input.edge));
+        }
+    }
+    for latch in &seq.latches {
+        let init = match latch.init {
+            Some(false) => 0,
+            Some(true) => 1,
+            None => lit(latch.current),
+        };
+        // ASCII names the latch literal first; binary implies it.
+        if !binary {
+            let _ = write!(ret, "{} ", lit(latch.current));
+        }
+        let _ = writeln!(ret, "{} {}", lit(latch.next), init);
+    }
+    for output in &seq.outputs {
+        let _ = writeln!(ret, "{}", lit(output.edge));
+    }
+
+    let mut bytes = Vec::new();
+    if binary {
+        bytes.extend_from_slice(ret.as_bytes());
+        ret.clear();
+    }
+    for &node in &ands {
+        if let AigNode::And { fanin0, fanin1 } = &seq.aig.nodes[node as usize] {
+            let lhs = 2 * var[node as usize];
+            let (mut rhs0, mut rhs1) = (lit(*fanin0), lit(*fanin1));
+            if rhs0 < rhs1 {
+                std::mem::swap(&mut rhs0, &mut rhs1);
+            }
+            if binary {
+                encode_delta(&mut bytes, lhs - rhs0);
+                encode_delta(&mut bytes, rhs0 - rhs1);
+            } else {
+                let _ = writeln!(ret, "{} {} {}", lhs, rhs0, rhs1);
+            }
+        }
+    }
+
+    for (i, input) in seq.inputs.iter().enumerate() {
+        let _ = writeln!(ret, "i{} {}", i, input.name);
+    }
+    for (i, latch) in seq.latches.iter().enumerate() {
+        match latch.origin {
+            Some((name, bit)) => {
+                let _ = writeln!(ret, "l{} {}[{}]", i, name, bit);
+            }
+            None => {
+                let _ = writeln!(ret, "l{} {}", i, latch.name);
+            }
+        }
+    }
+    for (i, output) in seq.outputs.iter().enumerate() {
+        let _ = writeln!(ret, "o{} {}", i, output.name);
+    }
+    let _ = writeln!(ret, "c");
+    let _ = writeln!(ret, "Generated by `veryl synth` for module {}.", seq.name);
+
+    bytes.extend_from_slice(ret.as_bytes());
+    bytes
+}
+
+/// Binary AIGER's 7-bit little-endian varint.
+fn encode_delta(bytes: &mut Vec<u8>, mut x: u32) {
+    while x & !0x7f != 0 {
+        bytes.push((x & 0x7f) as u8 | 0x80);
+        x >>= 7;
+    }
+    bytes.push(x as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delta_encoding_matches_aiger_spec() {
+        let mut bytes = Vec::new();
+        encode_delta(&mut bytes, 0);
+        encode_delta(&mut bytes, 127);
+        encode_delta(&mut bytes, 128);
+        encode_delta(&mut bytes, 16384);
+        assert_eq!(bytes, vec![0x00, 0x7f, 0x80, 0x01, 0x80, 0x80, 0x01]);
+    }
+}
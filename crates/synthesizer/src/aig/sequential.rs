@@ -0,0 +1,194 @@
+//! Whole-module sequential AIG: the combinational logic of a
+//! [`GateModule`] (via [`convert::aigify`]) plus one latch per flip-flop,
+//! with named primary inputs / outputs. This is the shape BLIF, AIGER and
+//! model checkers expect, as opposed to the sink list `aigify` produces for
+//! the in-place rewrite.
+//!
+//! Reset handling: a latch starts at its FF's reset value, and the reset
+//! (sync or async alike) is folded into the next-state function, so the
+//! model is cycle-accurate for designs that assert reset around a clock
+//! edge. FFs without a reset start uninitialised. Inferred RAM macros are
+//! black boxes: their read data are free inputs and their inputs are not
+//! observed.
+
+use crate::aig::convert::{aigify, lower_net};
+use crate::aig::graph::{AigEdge, AigModule, AigNode};
+use crate::ir::{ClockEdge, GateModule, NetDriver, NetId, PortDir, ResetPolarity};
+use std::collections::HashSet;
+use veryl_parser::resource_table::StrId;
+
+/// A primary input: an input-port bit, or a net with no modelled driver
+/// (undriven, RAM read data).
+#[derive(Clone, Debug)]
+pub struct SeqInput {
+    pub name: String,
+    pub net: NetId,
+    /// Positive edge of the input's AIG node.
+    pub edge: AigEdge,
+}
+
+/// One flip-flop. `current` is the AIG input node standing for Q.
+#[derive(Clone, Debug)]
+pub struct SeqLatch {
+    pub name: String,
+    /// Index into `GateModule::ffs`.
+    pub ff: usize,
+    pub origin: Option<(StrId, usize)>,
+    pub current: AigEdge,
+    pub next: AigEdge,
+    /// `None` — no reset, initial value unknown.
+    pub init: Option<bool>,
+    /// Clocking input (index into [`SequentialAig::inputs`]) when the clock
+    /// is a primary input.
+    pub clock: Option<(usize, ClockEdge)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SeqOutput {
+    pub name: String,
+    pub edge: AigEdge,
+}
+
+pub struct SequentialAig {
+    pub name: String,
+    pub aig: AigModule,
+    pub inputs: Vec<SeqInput>,
+    pub latches: Vec<SeqLatch>,
+    pub outputs: Vec<SeqOutput>,
+}
+
+impl SequentialAig {
+    pub fn from_gate(gate: &GateModule) -> Self {
+        let mut aig = aigify(gate);
+
+        let port_out_count: usize = gate
+            .ports
+            .iter()
+            .filter(|p| matches!(p.dir, PortDir::Output | PortDir::Inout))
+            .map(|p| p.nets.len())
+            .sum();
+
+        // Outputs: the leading sinks, in port order.
+        let mut outputs = Vec::new();
+        let mut sinks = aig.sinks.iter();
+        for port in &gate.ports {
+            if matches!(port.dir, PortDir::Output | PortDir::Inout) {
+                for i in 0..port.nets.len() {
+                    let sink = sinks.next().expect("aigify emits one sink per output bit");
+                    outputs.push(SeqOutput {
+                        name: port.bit_name(i),
+                        edge: sink.edge,
+                    });
+                }
+            }
+        }
+
+        // Inputs: every input-port bit (even if unused, so the interface
+        // matches the RTL), then the remaining free nets in node order.
+        let mut inputs = Vec::new();
+        let mut seen: HashSet<NetId> = HashSet::new();
+        for port in &gate.ports {
+            if port.dir == PortDir::Input {
+                for (i, &net) in port.nets.iter().enumerate() {
+                    if net < crate::ir::RESERVED_NETS || !seen.insert(net) {
+                        continue;
+                    }
+                    let edge = aig.add_input(net);
+                    inputs.push(SeqInput {
+                        name: port.bit_name(i),
+                        net,
+                        edge,
+                    });
+                }
+            }
+        }
+
+        // Latches: next state = FF D with the reset folded in.
+        let mut latches = Vec::new();
+        for (i, ff) in gate.ffs.iter().enumerate() {
+            let current = lower_net(&mut aig, gate, ff.q);
+            let mut next = aig.sinks[port_out_count + i].edge;
+            let init = ff.reset.as_ref().map(|_| ff.reset_value);
+            if let Some(r) = &ff.reset {
+                let rst = lower_net(&mut aig, gate, r.net);
+                let active = rst.negate_if(r.polarity == ResetPolarity::ActiveLow);
+                let value = if ff.reset_value {
+                    AigEdge::CONST1
+                } else {
+                    AigEdge::CONST0
+                };
+                next = aig.mk_mux(active, next, value);
+            }
+            let clock = inputs
+                .iter()
+                .position(|x| x.net == ff.clock)
+                .map(|idx| (idx, ff.clock_edge));
+            latches.push(SeqLatch {
+                name: format!("$ff{}", i),
+                ff: i,
+                origin: ff.origin,
+                current,
+                next,
+                init,
+                clock,
+            });
+        }
+
+        let latch_q: HashSet<NetId> = gate.ffs.iter().map(|ff| ff.q).collect();
+        for (idx, node) in aig.nodes.iter().enumerate() {
+            if let AigNode::Input { origin } = node
+                && !seen.contains(origin)
+                && !latch_q.contains(origin)
+            {
+                let name = match gate.nets[*origin as usize].driver {
+                    NetDriver::RamRead(r, p, b) => format!("$ram{}.r{}[{}]", r, p, b),
+                    _ => format!("$net{}", origin),
+                };
+                inputs.push(SeqInput {
+                    name,
+                    net: *origin,
+                    edge: AigEdge::new(idx as u32, false),
+                });
+            }
+        }
+
+        let name = match gate.name {
+            Some(n) => n.to_string(),
+            None => "unnamed".into(),
+        };
+        SequentialAig {
+            name,
+            aig,
+            inputs,
+            latches,
+            outputs,
+        }
+    }
+
+    /// Nodes reachable from the outputs and latch next-state functions, in
+    /// ascending (topological) order.
+    pub fn live_ands(&self) -> Vec<u32> {
+        let mut live = vec![false; self.aig.nodes.len()];
+        let mut stack: Vec<u32> = self
+            .outputs
+            .iter()
+            .map(|o| o.edge.node())
+            .chain(self.latches.iter().map(|l| l.next.node()))
+            .collect();
+        while let Some(idx) = stack.pop() {
+            if live[idx as usize] {
+                continue;
+            }
+            live[idx as usize] = true;
+            if let AigNode::And { fanin0, fanin1 } = &self.aig.nodes[idx as usize] {
+                stack.push(fanin0.node());
+                stack.push(fanin1.node());
+            }
+        }
+        (0..self.aig.nodes.len() as u32)
+            .filter(|&i| {
+                live[i as usize] && matches!(self.aig.nodes[i as usize], AigNode::And { .. })
+            })
+            .collect()
+    }
+}
@@ -32,6 +32,7 @@ pub fn aig_to_cells_techmap(aig: &AigModule, original: &GateModule) -> GateModul
         nets: Vec::new(),
         cells: Vec::new(),
         ffs: original.ffs.clone(),
+        ram_blocks: original.ram_blocks.clone(),
     };
     out.nets = original
         .nets
@@ -41,6 +42,7 @@ pub fn aig_to_cells_techmap(aig: &AigModule, original: &GateModule) -> GateModul
                 NetDriver::Const(b) => NetDriver::Const(b),
                 NetDriver::PortInput => NetDriver::PortInput,
                 NetDriver::FfQ(idx) => NetDriver::FfQ(idx),
+                NetDriver::RamRead(r, p, b) => NetDriver::RamRead(r, p, b),
                 _ => NetDriver::Undriven,
             },
             origin: n.origin,
@@ -155,16 +157,20 @@ pub fn aig_to_cells_techmap(aig: &AigModule, original: &GateModule) -> GateModul
         }
     }
 
-    // Wire sinks: ports first, then FF Ds.
+    // Wire sinks: ports first, then FF Ds, then RAM inputs.
     let port_out_count: usize = original
         .ports
         .iter()
         .filter(|p| matches!(p.dir, PortDir::Output | PortDir::Inout))
         .map(|p| p.nets.len())
         .sum();
+    let ff_end = port_out_count + original.ffs.len();
     for (i, sink) in aig.sinks.iter().enumerate() {
         let src_net = resolve(&mut out, &mut pos_net, &mut neg_net, sink.edge);
-        if i < port_out_count {
+        if (port_out_count..ff_end).contains(&i) {
+            let ff_idx = i - port_out_count;
+            out.ffs[ff_idx].d = src_net;
+        } else {
             let target = sink.target;
             if src_net != target {
                 let cell_idx = out.cells.len();
@@ -175,9 +181,6 @@ pub fn aig_to_cells_techmap(aig: &AigModule, original: &GateModule) -> GateModul
                 });
                 out.nets[target as usize].driver = NetDriver::Cell(cell_idx);
             }
-        } else {
-            let ff_idx = i - port_out_count;
-            out.ffs[ff_idx].d = src_net;
         }
     }
     out
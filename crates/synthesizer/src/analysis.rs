@@ -2,8 +2,10 @@ use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
-use crate::ir::{CellKind, GateModule, NetDriver, NetId, PortDir};
+use crate::ir::{CellKind, ClockEdge, GateModule, NetDriver, NetId, PortDir};
 use crate::library::CellLibrary;
+use veryl_analyzer::symbol::ClockDomain;
+use veryl_metadata::TimingConstraints;
 
 fn max_float_width(vs: impl IntoIterator<Item = f64>, prec: usize) -> usize {
     vs.into_iter()
@@ -280,23 +282,18 @@ pub fn compute_power(
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct TimingReport {
     pub critical_path_delay: f64,
     pub critical_path_depth: usize,
     pub critical_path: Vec<PathStep>,
     pub endpoint: Option<Endpoint>,
-}
-
-impl Default for TimingReport {
-    fn default() -> Self {
-        Self {
-            critical_path_delay: 0.0,
-            critical_path_depth: 0,
-            critical_path: Vec::new(),
-            endpoint: None,
-        }
-    }
+    /// Per-clock slack against `[synth.constraints]`, filled by
+    /// [`compute_clock_slack`]; empty when no clock is constrained.
+    pub clocks: Vec<ClockSlack>,
+    /// Design clocks with no constraint; paths they launch or capture are
+    /// not checked.
+    pub unconstrained_clocks: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -436,7 +433,64 @@ pub fn port_label(step: Option<&PathStep>) -> String {
 impl fmt::Display for TimingReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "timing: {}", self.summary())?;
-        self.write_path(f)
+        self.write_path(f)?;
+        if !self.clocks.is_empty() {
+            writeln!(f, "  clocks:")?;
+            for clock in &self.clocks {
+                writeln!(f, "    {}", clock)?;
+            }
+        }
+        if !self.unconstrained_clocks.is_empty() {
+            writeln!(
+                f,
+                "  unconstrained clocks: {}",
+                self.unconstrained_clocks.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Slack of one constrained clock: the worst over the register and RAM
+/// endpoints it captures and the output ports it launches.
+#[derive(Clone, Debug)]
+pub struct ClockSlack {
+    pub clock: String,
+    pub period: f64,
+    /// Worst slack (ns); `None` when no path is checked against the clock.
+    pub slack: Option<f64>,
+    pub endpoints: usize,
+    pub failing: usize,
+    /// Worst path, start point first.
+    pub path: Vec<PathStep>,
+    /// Whether `clock` names an input port of the design. A misspelled
+    /// constraint checks nothing, so it isn't met.
+    pub found: bool,
+}
+
+impl ClockSlack {
+    pub fn met(&self) -> bool {
+        self.found && self.failing == 0
+    }
+}
+
+impl fmt::Display for ClockSlack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}  period {:.3} ns  ", self.clock, self.period)?;
+        match self.slack {
+            None if !self.found => write!(f, "(no such input port)  VIOLATED"),
+            None => write!(f, "(no paths)"),
+            Some(slack) => write!(
+                f,
+                "slack {:+.3} ns  {}  ({} of {} endpoints failing)  {} → {}",
+                slack,
+                if self.met() { "MET" } else { "VIOLATED" },
+                self.failing,
+                self.endpoints,
+                port_label(self.path.first()),
+                port_label(self.path.last()),
+            ),
+        }
     }
 }
 
@@ -447,6 +501,259 @@ pub fn compute_timing(module: &GateModule, library: &dyn CellLibrary) -> TimingR
         .unwrap_or_default()
 }
 
+/// Check every path against `constraints`, per capturing clock:
+///
+/// - Launch and capture on the same clock get one period, or half of it
+///   between opposite edges; between two different clocks the tighter
+///   period applies.
+/// - Paths between flip-flops in incompatible clock domains (as the
+///   analyzer tracks them) are false paths: such crossings are
+///   asynchronous and need a synchronizer, not a timing check.
+/// - Input delays are relative to the capturing clock, output delays to the
+///   launching one; a pure input-to-output path is checked against the
+///   tightest clock.
+/// - Register endpoints subtract the library setup time; clock-to-Q is 0,
+///   as in [`compute_timing_top_n`].
+///
+/// Returns the per-clock slack, in `constraints.clocks` order, and the
+/// design clocks that have no constraint.
+pub fn compute_clock_slack(
+    module: &GateModule,
+    library: &dyn CellLibrary,
+    constraints: &TimingConstraints,
+) -> (Vec<ClockSlack>, Vec<String>) {
+    if constraints.clocks.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+    let periods: Vec<f64> = constraints.clocks.iter().map(|c| c.period).collect();
+    let mut unconstrained: Vec<String> = Vec::new();
+    let mut clock_index = |net: NetId| {
+        let name = clock_name(module, net);
+        let index = constraints.clocks.iter().position(|c| c.port == name);
+        if index.is_none() && !unconstrained.contains(&name) {
+            unconstrained.push(name);
+        }
+        index
+    };
+    let ff_clocks: Vec<Option<usize>> = module.ffs.iter().map(|ff| clock_index(ff.clock)).collect();
+    let ram_clocks: Vec<Option<usize>> = module
+        .ram_blocks
+        .iter()
+        .map(|ram| clock_index(ram.clock))
+        .collect();
+
+    // Endpoints: (endpoint, net, capture clock, edge, domain) for registers
+    // and RAM writes; output ports carry their name for the output delay.
+    struct Check<'a> {
+        endpoint: Endpoint,
+        net: NetId,
+        capture: Option<(usize, ClockEdge, &'a ClockDomain)>,
+        port: Option<String>,
+    }
+    let mut checks = Vec::new();
+    for (i, ff) in module.ffs.iter().enumerate() {
+        checks.push(Check {
+            endpoint: Endpoint::Ff(i),
+            net: ff.d,
+            capture: ff_clocks[i].map(|c| (c, ff.clock_edge, &ff.clock_domain)),
+            port: None,
+        });
+    }
+    for (ri, ram) in module.ram_blocks.iter().enumerate() {
+        for wp in &ram.write_ports {
+            let inputs = wp
+                .addr
+                .iter()
+                .chain(wp.data.iter())
+                .chain(std::iter::once(&wp.enable))
+                .chain(wp.mask.iter().flatten());
+            for &net in inputs {
+                checks.push(Check {
+                    endpoint: Endpoint::RamWrite(ri),
+                    net,
+                    capture: ram_clocks[ri].map(|c| (c, ram.clock_edge, &ClockDomain::None)),
+                    port: None,
+                });
+            }
+        }
+    }
+    for port in &module.ports {
+        if matches!(port.dir, PortDir::Output | PortDir::Inout) {
+            for &net in &port.nets {
+                checks.push(Check {
+                    endpoint: Endpoint::Port,
+                    net,
+                    capture: None,
+                    port: Some(port.flat_name()),
+                });
+            }
+        }
+    }
+
+    // Launch groups: each constrained clock edge, then the input ports.
+    struct Launch<'a> {
+        clock: Option<(usize, ClockEdge, &'a ClockDomain)>,
+        starts: Vec<(NetId, f64)>,
+    }
+    let mut launches = Vec::new();
+    for c in 0..periods.len() {
+        for edge in [ClockEdge::Posedge, ClockEdge::Negedge] {
+            let mut domain = &ClockDomain::None;
+            let mut starts = Vec::new();
+            for (i, ff) in module.ffs.iter().enumerate() {
+                if ff_clocks[i] == Some(c) && ff.clock_edge == edge {
+                    domain = &ff.clock_domain;
+                    starts.push((ff.q, 0.0));
+                }
+            }
+            for (ri, ram) in module.ram_blocks.iter().enumerate() {
+                if ram_clocks[ri] == Some(c) && ram.clock_edge == edge {
+                    for rp in ram.read_ports.iter().filter(|rp| rp.sync) {
+                        starts.extend(rp.data.iter().map(|&d| (d, 0.0)));
+                    }
+                }
+            }
+            if !starts.is_empty() {
+                launches.push(Launch {
+                    clock: Some((c, edge, domain)),
+                    starts,
+                });
+            }
+        }
+    }
+    let mut inputs = Vec::new();
+    for port in &module.ports {
+        if port.dir == PortDir::Input {
+            let delay = constraints.input_delay(&port.flat_name());
+            inputs.extend(port.nets.iter().map(|&n| (n, delay)));
+        }
+    }
+    launches.push(Launch {
+        clock: None,
+        starts: inputs,
+    });
+
+    let tightest = (0..periods.len()).min_by(|&a, &b| {
+        periods[a]
+            .partial_cmp(&periods[b])
+            .unwrap_or(Ordering::Equal)
+    });
+    let setup = library.ff_setup();
+    let labels = endpoint_labels(module);
+    let mut worst: Vec<Option<(f64, Vec<PathStep>)>> = vec![None; periods.len()];
+    let mut checked: Vec<HashSet<usize>> = vec![HashSet::new(); periods.len()];
+    let mut failing: Vec<HashSet<usize>> = vec![HashSet::new(); periods.len()];
+
+    let n_nets = module.nets.len();
+    for launch in &launches {
+        let mut arrival = vec![f64::NEG_INFINITY; n_nets];
+        let mut depth = vec![0_usize; n_nets];
+        let mut predecessor = vec![None::<NetId>; n_nets];
+        for &(net, t) in &launch.starts {
+            arrival[net as usize] = arrival[net as usize].max(t);
+        }
+        propagate(module, library, &mut arrival, &mut depth, &mut predecessor);
+
+        for (k, check) in checks.iter().enumerate() {
+            let t = arrival[check.net as usize];
+            if t == f64::NEG_INFINITY {
+                continue;
+            }
+            let (clock, required) = match (check.capture, launch.clock, &check.port) {
+                (Some((cc, ce, cd)), Some((lc, le, ld)), _) => {
+                    if !ld.compatible(cd) {
+                        continue;
+                    }
+                    let period = if lc != cc {
+                        periods[lc].min(periods[cc])
+                    } else if le != ce {
+                        periods[cc] / 2.0
+                    } else {
+                        periods[cc]
+                    };
+                    (cc, period - setup)
+                }
+                (Some((cc, ..)), None, _) => (cc, periods[cc] - setup),
+                (None, Some((lc, ..)), Some(port)) => {
+                    (lc, periods[lc] - constraints.output_delay(port))
+                }
+                (None, None, Some(port)) => match tightest {
+                    Some(tc) => (tc, periods[tc] - constraints.output_delay(port)),
+                    None => continue,
+                },
+                _ => continue,
+            };
+            let slack = required - t;
+            checked[clock].insert(k);
+            if slack < 0.0 {
+                failing[clock].insert(k);
+            }
+            if worst[clock].as_ref().is_none_or(|(w, _)| slack < *w) {
+                let path = trace_path(
+                    module,
+                    &labels,
+                    &arrival,
+                    &predecessor,
+                    &check.endpoint,
+                    check.net,
+                );
+                worst[clock] = Some((slack, path));
+            }
+        }
+    }
+
+    let clocks = constraints
+        .clocks
+        .iter()
+        .enumerate()
+        .map(|(c, constraint)| {
+            let (slack, path) = match worst[c].take() {
+                Some((slack, path)) => (Some(slack), path),
+                None => (None, Vec::new()),
+            };
+            let found = module.ports.iter().any(|port| {
+                port.dir == PortDir::Input
+                    && (0..port.nets.len()).any(|bit| port.bit_name(bit) == constraint.port)
+            });
+            ClockSlack {
+                clock: constraint.port.clone(),
+                period: constraint.period,
+                slack,
+                endpoints: checked[c].len(),
+                failing: failing[c].len(),
+                path,
+                found,
+            }
+        })
+        .collect();
+    (clocks, unconstrained)
+}
+
+/// Name of the port driving a clock net (through buffers), else the net's
+/// own variable name.
+fn clock_name(module: &GateModule, mut net: NetId) -> String {
+    while let NetDriver::Cell(i) = module.nets[net as usize].driver {
+        let cell = &module.cells[i];
+        if cell.kind != CellKind::Buf {
+            break;
+        }
+        net = cell.inputs[0];
+    }
+    for port in &module.ports {
+        if let Some(bit) = port.nets.iter().position(|&n| n == net) {
+            return if port.nets.len() == 1 {
+                port.flat_name()
+            } else {
+                port.bit_name(bit)
+            };
+        }
+    }
+    match module.nets[net as usize].origin {
+        Some((name, bit)) => format!("{}[{}]", name, bit),
+        None => format!("n{}", net),
+    }
+}
+
 /// Returns the `n` endpoints with the longest arrival time, each with its
 /// critical path, worst first. A single top-1 can shadow a close second;
 /// top-N exposes near-critical paths a different tech-mapping might prefer.
@@ -466,73 +773,7 @@ pub fn compute_timing_top_n(
     let mut depth = vec![0_usize; n_nets];
     let mut predecessor = vec![None::<NetId>; n_nets];
 
-    // Cells are roughly topological but branch-merging MUXes can land out of
-    // order; iterate to a fixed point rather than pre-sort.
-    let sram = library.sram_model();
-    let mut changed = true;
-    while changed {
-        changed = false;
-        for cell in &module.cells {
-            let info = library.info(cell.kind);
-            let mut max_in_arrival = 0.0_f64;
-            let mut max_in_depth = 0_usize;
-            let mut arg_net = None;
-            for &inp in &cell.inputs {
-                let i = inp as usize;
-                if arrival[i] > max_in_arrival || arg_net.is_none() {
-                    max_in_arrival = arrival[i];
-                    arg_net = Some(inp);
-                }
-                if depth[i] > max_in_depth {
-                    max_in_depth = depth[i];
-                }
-            }
-            let out = cell.output as usize;
-            let new_arr = max_in_arrival + info.delay;
-            let new_depth = max_in_depth + usize::from(!matches!(cell.kind, CellKind::Buf));
-            if new_arr > arrival[out] + 1e-12 || new_depth > depth[out] {
-                arrival[out] = new_arr;
-                depth[out] = new_depth;
-                predecessor[out] = arg_net;
-                changed = true;
-            }
-        }
-        // Asynchronous RAM read: data valid `access_delay` after the address
-        // settles, so propagate addr → data like a multi-input cell. Sync read
-        // ports leave their data nets at arrival 0 (registered start points).
-        for ram in &module.ram_blocks {
-            let access = sram.access_delay(ram.depth);
-            for rp in &ram.read_ports {
-                if rp.sync {
-                    continue;
-                }
-                let mut max_addr_arr = 0.0_f64;
-                let mut max_addr_depth = 0_usize;
-                let mut arg_net = None;
-                for &a in &rp.addr {
-                    let i = a as usize;
-                    if arrival[i] > max_addr_arr || arg_net.is_none() {
-                        max_addr_arr = arrival[i];
-                        arg_net = Some(a);
-                    }
-                    if depth[i] > max_addr_depth {
-                        max_addr_depth = depth[i];
-                    }
-                }
-                let new_arr = max_addr_arr + access;
-                let new_depth = max_addr_depth + 1;
-                for &d in &rp.data {
-                    let o = d as usize;
-                    if new_arr > arrival[o] + 1e-12 || new_depth > depth[o] {
-                        arrival[o] = new_arr;
-                        depth[o] = new_depth;
-                        predecessor[o] = arg_net;
-                        changed = true;
-                    }
-                }
-            }
-        }
-    }
+    propagate(module, library, &mut arrival, &mut depth, &mut predecessor);
 
     // Report the longest combinational arrival at each endpoint. Matches
     // the methodology ABC / other netlist-level STA tools use (FFs treated
@@ -583,9 +824,31 @@ pub fn compute_timing_top_n(
     let mut seen_nets: HashSet<NetId> = HashSet::new();
     endpoints.retain(|(_, _, _, net)| seen_nets.insert(*net));
 
-    // endpoint_label: net → (port/register name, bit). Without this, the final
-    // step would inherit whatever intermediate let-variable shares the net via
-    // cell-output origin propagation.
+    let endpoint_label = endpoint_labels(module);
+    endpoints
+        .into_iter()
+        .take(n)
+        .map(|(delay, dep, endpoint, end_net)| TimingReport {
+            critical_path_delay: delay,
+            critical_path_depth: dep,
+            critical_path: trace_path(
+                module,
+                &endpoint_label,
+                &arrival,
+                &predecessor,
+                &endpoint,
+                end_net,
+            ),
+            endpoint: Some(endpoint),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// net → (port/register name, bit). Without this, the final step of a path
+/// would inherit whatever intermediate let-variable shares the net via
+/// cell-output origin propagation.
+fn endpoint_labels(module: &GateModule) -> HashMap<NetId, (String, usize)> {
     let mut endpoint_label: HashMap<NetId, (String, usize)> = HashMap::new();
     for port in &module.ports {
         if matches!(port.dir, PortDir::Output | PortDir::Inout) {
@@ -602,68 +865,147 @@ pub fn compute_timing_top_n(
             endpoint_label.insert(ff.q, (name.to_string(), bit));
         }
     }
+    endpoint_label
+}
 
-    endpoints
-        .into_iter()
-        .take(n)
-        .map(|(delay, dep, endpoint, end_net)| {
-            let mut visited: HashSet<NetId> = HashSet::new();
-            let mut cur = Some(end_net);
-            let tail_kind = match &endpoint {
-                Endpoint::Ff(i) => StepKind::FfInput(*i),
-                Endpoint::Port => StepKind::PortOutput,
-                Endpoint::RamWrite(i) => StepKind::RamWriteInput(*i),
-            };
-            let mut trace = Vec::new();
-            while let Some(net) = cur {
-                if !visited.insert(net) {
-                    break;
+/// Walk `predecessor` back from `end_net`, start point first.
+fn trace_path(
+    module: &GateModule,
+    endpoint_label: &HashMap<NetId, (String, usize)>,
+    arrival: &[f64],
+    predecessor: &[Option<NetId>],
+    endpoint: &Endpoint,
+    end_net: NetId,
+) -> Vec<PathStep> {
+    let mut visited: HashSet<NetId> = HashSet::new();
+    let mut cur = Some(end_net);
+    let tail_kind = match endpoint {
+        Endpoint::Ff(i) => StepKind::FfInput(*i),
+        Endpoint::Port => StepKind::PortOutput,
+        Endpoint::RamWrite(i) => StepKind::RamWriteInput(*i),
+    };
+    let mut trace = Vec::new();
+    while let Some(net) = cur {
+        if !visited.insert(net) {
+            break;
+        }
+        let idx = net as usize;
+        let kind = match &module.nets[idx].driver {
+            NetDriver::Const(_) => StepKind::StartPoint,
+            NetDriver::PortInput => StepKind::StartPoint,
+            NetDriver::FfQ(i) => StepKind::FfOutput(*i),
+            NetDriver::Cell(i) => StepKind::CellOutput(*i, module.cells[*i].kind),
+            NetDriver::RamRead(i, _, _) => StepKind::RamReadOutput(*i),
+            NetDriver::Undriven => StepKind::StartPoint,
+        };
+        // Prefer explicit boundary label; fall back to net origin.
+        let origin = endpoint_label
+            .get(&net)
+            .cloned()
+            .or_else(|| module.nets[idx].origin.map(|(s, bit)| (s.to_string(), bit)));
+        trace.push(PathStep {
+            net,
+            kind,
+            arrival: arrival[idx],
+            origin,
+        });
+        match &module.nets[idx].driver {
+            // Async RAM read continues back through its address; a sync
+            // read has no predecessor and stops here (start point).
+            NetDriver::Cell(_) | NetDriver::RamRead(..) => cur = predecessor[idx],
+            _ => break,
+        }
+    }
+    trace.reverse();
+    let end_origin = endpoint_label.get(&end_net).cloned().or_else(|| {
+        module.nets[end_net as usize]
+            .origin
+            .map(|(s, bit)| (s.to_string(), bit))
+    });
+    trace.push(PathStep {
+        net: end_net,
+        kind: tail_kind,
+        arrival: arrival[end_net as usize],
+        origin: end_origin,
+    });
+    trace
+}
+
+/// Propagate `arrival` / `depth` from the start-point nets through cells
+/// and asynchronous RAM reads. A net at `f64::NEG_INFINITY` is unreachable
+/// and stays so unless a start point feeds it.
+fn propagate(
+    module: &GateModule,
+    library: &dyn CellLibrary,
+    arrival: &mut [f64],
+    depth: &mut [usize],
+    predecessor: &mut [Option<NetId>],
+) {
+    // Cells are roughly topological but branch-merging MUXes can land out of
+    // order; iterate to a fixed point rather than pre-sort.
+    let sram = library.sram_model();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for cell in &module.cells {
+            let info = library.info(cell.kind);
+            let mut max_in_arrival = 0.0_f64;
+            let mut max_in_depth = 0_usize;
+            let mut arg_net = None;
+            for &inp in &cell.inputs {
+                let i = inp as usize;
+                if arrival[i] > max_in_arrival || arg_net.is_none() {
+                    max_in_arrival = arrival[i];
+                    arg_net = Some(inp);
                 }
-                let idx = net as usize;
-                let kind = match &module.nets[idx].driver {
-                    NetDriver::Const(_) => StepKind::StartPoint,
-                    NetDriver::PortInput => StepKind::StartPoint,
-                    NetDriver::FfQ(i) => StepKind::FfOutput(*i),
-                    NetDriver::Cell(i) => StepKind::CellOutput(*i, module.cells[*i].kind),
-                    NetDriver::RamRead(i, _, _) => StepKind::RamReadOutput(*i),
-                    NetDriver::Undriven => StepKind::StartPoint,
-                };
-                // Prefer explicit boundary label; fall back to net origin.
-                let origin = endpoint_label
-                    .get(&net)
-                    .cloned()
-                    .or_else(|| module.nets[idx].origin.map(|(s, bit)| (s.to_string(), bit)));
-                trace.push(PathStep {
-                    net,
-                    kind,
-                    arrival: arrival[idx],
-                    origin,
-                });
-                match &module.nets[idx].driver {
-                    // Async RAM read continues back through its address; a sync
-                    // read has no predecessor and stops here (start point).
-                    NetDriver::Cell(_) | NetDriver::RamRead(..) => cur = predecessor[idx],
-                    _ => break,
+                if depth[i] > max_in_depth {
+                    max_in_depth = depth[i];
                 }
             }
-            trace.reverse();
-            let end_origin = endpoint_label.get(&end_net).cloned().or_else(|| {
-                module.nets[end_net as usize]
-                    .origin
-                    .map(|(s, bit)| (s.to_string(), bit))
-            });
-            trace.push(PathStep {
-                net: end_net,
-                kind: tail_kind,
-                arrival: delay,
-                origin: end_origin,
-            });
-            TimingReport {
-                critical_path_delay: delay,
-                critical_path_depth: dep,
-                critical_path: trace,
-                endpoint: Some(endpoint),
+            let out = cell.output as usize;
+            let new_arr = max_in_arrival + info.delay;
+            let new_depth = max_in_depth + usize::from(!matches!(cell.kind, CellKind::Buf));
+            if new_arr > arrival[out] + 1e-12 || new_depth > depth[out] {
+                arrival[out] = new_arr;
+                depth[out] = new_depth;
+                predecessor[out] = arg_net;
+                changed = true;
             }
-        })
-        .collect()
+        }
+        // Asynchronous RAM read: data valid `access_delay` after the address
+        // settles, so propagate addr → data like a multi-input cell. Sync read
+        // ports leave their data nets at arrival 0 (registered start points).
+        for ram in &module.ram_blocks {
+            let access = sram.access_delay(ram.depth);
+            for rp in &ram.read_ports {
+                if rp.sync {
+                    continue;
+                }
+                let mut max_addr_arr = 0.0_f64;
+                let mut max_addr_depth = 0_usize;
+                let mut arg_net = None;
+                for &a in &rp.addr {
+                    let i = a as usize;
+                    if arrival[i] > max_addr_arr || arg_net.is_none() {
+                        max_addr_arr = arrival[i];
+                        arg_net = Some(a);
+                    }
+                    if depth[i] > max_addr_depth {
+                        max_addr_depth = depth[i];
+                    }
+                }
+                let new_arr = max_addr_arr + access;
+                let new_depth = max_addr_depth + 1;
+                for &d in &rp.data {
+                    let o = d as usize;
+                    if new_arr > arrival[o] + 1e-12 || new_depth > depth[o] {
+                        arrival[o] = new_arr;
+                        depth[o] = new_depth;
+                        predecessor[o] = arg_net;
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
 }
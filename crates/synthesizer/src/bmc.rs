@@ -0,0 +1,272 @@
+//! Bounded model checking of `$assert`s (`veryl prove`).
+//!
+//! The design is the module as first built from the RTL, with RAM inference
+//! off so every register is a flip-flop. It is unrolled one clock cycle per
+//! frame into a single AIG: frame `k` sees the flip-flop state after `k`
+//! cycles and a fresh free input per input-port bit, and an assertion is
+//! violated in a frame where its enable is high and its condition low. Each
+//! frame's violation is handed to the SAT solver in turn, so the first
+//! counterexample found is also the shortest.
+//!
+//! The run starts the way a testbench does: every flip-flop at 0, then one
+//! cycle with all reset ports asserted and the other inputs at 0. After that
+//! reset ports stay deasserted and clock ports read as 0 in logic. All clock
+//! domains step together, one edge per cycle.
+
+use crate::aig::convert::lower_net;
+use crate::aig::graph::{AigEdge, AigModule};
+use crate::ir::{GateAssertion, GateModule, NET_CONST0, NET_CONST1, NetId, PortDir, ResetPolarity};
+use crate::verify::sat::{Lit, SatResult};
+use crate::verify::{Assignment, const_edge, eval, support, tseitin};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Conflict budget per cycle before the run stops as undecided.
+const CONFLICT_LIMIT: u64 = 1_000_000;
+
+/// A module prepared for [`ProofModel::check`]; see
+/// `crate::build_proof_model`.
+pub struct ProofModel {
+    pub module: GateModule,
+    pub assertions: Vec<GateAssertion>,
+}
+
+pub struct BmcReport {
+    /// Cycles checked, starting at the first cycle after reset.
+    pub depth: usize,
+    pub outcome: BmcOutcome,
+}
+
+pub enum BmcOutcome {
+    /// No assertion can fail within `depth` cycles.
+    Safe,
+    Violated(Counterexample),
+    /// The solver ran out of budget at `cycle`; earlier cycles are safe.
+    Unknown {
+        cycle: usize,
+    },
+}
+
+pub struct Counterexample {
+    /// Index into [`ProofModel::assertions`].
+    pub assertion: usize,
+    /// Cycle the assertion fails in, 0 being the first after reset.
+    pub cycle: usize,
+    /// Whether the trace starts with the reset cycle; `false` when no input
+    /// port resets a flip-flop.
+    pub reset_cycle: bool,
+    /// Input port values for cycles `0..=cycle`: port path (`.`-joined) and
+    /// LSB-first bits. Clock and reset ports are left out.
+    pub inputs: Vec<Vec<(String, Vec<bool>)>>,
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (cycle, ports) in self.inputs.iter().enumerate() {
+            write!(f, "cycle {cycle}:")?;
+            for (name, bits) in ports {
+                let digits: String = bits
+                    .chunks(4)
+                    .rev()
+                    .map(|nibble| {
+                        let v = nibble
+                            .iter()
+                            .enumerate()
+                            .fold(0, |acc, (i, &b)| acc | ((b as u32) << i));
+                        char::from_digit(v, 16).unwrap()
+                    })
+                    .collect();
+                write!(f, " {name}='h{digits}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// How a free input-port bit is driven in every frame after reset.
+#[derive(Clone, Copy)]
+enum PortBit {
+    Free,
+    Fixed(AigEdge),
+}
+
+impl ProofModel {
+    /// Looks for an input sequence that makes an assertion fail within
+    /// `depth` cycles.
+    pub fn check(&self, depth: usize) -> BmcReport {
+        let gate = &self.module;
+        let clocks: HashSet<NetId> = gate.ffs.iter().map(|ff| ff.clock).collect();
+        let resets: HashMap<NetId, ResetPolarity> = gate
+            .ffs
+            .iter()
+            .filter_map(|ff| ff.reset.as_ref().map(|r| (r.net, r.polarity)))
+            .collect();
+        // `Fixed` holds the post-reset level; the reset frame inverts it.
+        let inputs: Vec<(String, Vec<(NetId, PortBit)>)> = gate
+            .ports
+            .iter()
+            .filter(|p| p.dir == PortDir::Input)
+            .map(|p| {
+                let name = p
+                    .path
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                let bits = p
+                    .nets
+                    .iter()
+                    .map(|&n| {
+                        let bit = if clocks.contains(&n) {
+                            PortBit::Fixed(AigEdge::CONST0)
+                        } else if let Some(&polarity) = resets.get(&n) {
+                            PortBit::Fixed(const_edge(polarity == ResetPolarity::ActiveLow))
+                        } else {
+                            PortBit::Free
+                        };
+                        (n, bit)
+                    })
+                    .collect();
+                (name, bits)
+            })
+            .collect();
+        let has_reset = inputs
+            .iter()
+            .flat_map(|(_, bits)| bits)
+            .any(|(n, _)| resets.contains_key(n));
+
+        let mut aig = AigModule::new();
+        let mut state: Vec<AigEdge> = vec![AigEdge::CONST0; gate.ffs.len()];
+        if has_reset {
+            begin_frame(&mut aig, gate, &state);
+            for (_, bits) in &inputs {
+                for &(n, bit) in bits {
+                    let edge = match bit {
+                        PortBit::Fixed(e) if resets.contains_key(&n) => e.negate(),
+                        _ => AigEdge::CONST0,
+                    };
+                    aig.net_edge.insert(n, edge);
+                }
+            }
+            state = next_state(&mut aig, gate);
+        }
+
+        // Per frame, per input port, the edge of each bit.
+        let mut frames: Vec<Vec<Vec<AigEdge>>> = Vec::new();
+        for cycle in 0..depth {
+            begin_frame(&mut aig, gate, &state);
+            let ports: Vec<Vec<AigEdge>> = inputs
+                .iter()
+                .map(|(_, bits)| {
+                    bits.iter()
+                        .map(|&(n, bit)| match bit {
+                            PortBit::Fixed(e) => {
+                                aig.net_edge.insert(n, e);
+                                e
+                            }
+                            PortBit::Free => aig.add_input(n),
+                        })
+                        .collect()
+                })
+                .collect();
+            frames.push(ports);
+
+            let violations: Vec<AigEdge> = self
+                .assertions
+                .iter()
+                .map(|a| {
+                    let enable = lower_net(&mut aig, gate, a.enable);
+                    let cond = lower_net(&mut aig, gate, a.cond);
+                    aig.mk_and(enable, cond.negate())
+                })
+                .collect();
+            let bad = violations
+                .iter()
+                .fold(AigEdge::CONST0, |acc, &v| aig.mk_or(acc, v));
+            if bad != AigEdge::CONST0 {
+                let (mut solver, var) = tseitin(&aig, &[bad]);
+                solver.add_clause(&[Lit::new(var[&bad.node()], bad.is_negated())]);
+                match solver.solve(CONFLICT_LIMIT) {
+                    SatResult::Unsat => {}
+                    SatResult::Unknown => {
+                        return BmcReport {
+                            depth,
+                            outcome: BmcOutcome::Unknown { cycle },
+                        };
+                    }
+                    SatResult::Sat => {
+                        let model: Assignment = support(&aig, &[bad])
+                            .into_iter()
+                            .map(|n| (n, solver.value(var[&n]).unwrap_or(false)))
+                            .collect();
+                        let assertion = violations
+                            .iter()
+                            .position(|&v| eval(&aig, v, &model))
+                            .expect("satisfied violation has a failing assertion");
+                        let inputs = frames
+                            .iter()
+                            .map(|ports| {
+                                inputs
+                                    .iter()
+                                    .zip(ports)
+                                    .filter(|((_, bits), _)| {
+                                        bits.iter().any(|(_, b)| matches!(b, PortBit::Free))
+                                    })
+                                    .map(|((name, _), edges)| {
+                                        let bits =
+                                            edges.iter().map(|&e| eval(&aig, e, &model)).collect();
+                                        (name.clone(), bits)
+                                    })
+                                    .collect()
+                            })
+                            .collect();
+                        return BmcReport {
+                            depth,
+                            outcome: BmcOutcome::Violated(Counterexample {
+                                assertion,
+                                cycle,
+                                reset_cycle: has_reset,
+                                inputs,
+                            }),
+                        };
+                    }
+                }
+            }
+            state = next_state(&mut aig, gate);
+        }
+        BmcReport {
+            depth,
+            outcome: BmcOutcome::Safe,
+        }
+    }
+}
+
+/// Forgets the previous frame's nets and seeds the constants and the
+/// flip-flop outputs for the next one.
+fn begin_frame(aig: &mut AigModule, gate: &GateModule, state: &[AigEdge]) {
+    aig.net_edge.clear();
+    aig.net_edge.insert(NET_CONST0, AigEdge::CONST0);
+    aig.net_edge.insert(NET_CONST1, AigEdge::CONST1);
+    for (ff, &q) in gate.ffs.iter().zip(state) {
+        aig.net_edge.insert(ff.q, q);
+    }
+}
+
+/// Every flip-flop's value after this frame's clock edge, reset folded in.
+fn next_state(aig: &mut AigModule, gate: &GateModule) -> Vec<AigEdge> {
+    gate.ffs
+        .iter()
+        .map(|ff| {
+            let d = lower_net(aig, gate, ff.d);
+            match &ff.reset {
+                Some(r) => {
+                    let active = lower_net(aig, gate, r.net)
+                        .negate_if(r.polarity == ResetPolarity::ActiveLow);
+                    aig.mk_mux(active, d, const_edge(ff.reset_value))
+                }
+                None => d,
+            }
+        })
+        .collect()
+}
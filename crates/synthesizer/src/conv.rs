@@ -29,9 +29,9 @@ use crate::conv::prefix::{absorb_complement, prefix_parallelize};
 use crate::conv::ram::RamCandidate;
 use crate::conv::worklist::{Simpl, dead_cell_elimination, simplify, worklist_simplify};
 use crate::ir::{
-    Cell, CellKind, ClockEdge, FfCell, GateModule, GatePort, NET_CONST0, NET_CONST1, NetDriver,
-    NetId, NetInfo, PortDir, RESERVED_NETS, RamBlock, RamReadPort, RamWritePort, ResetPolarity,
-    ResetSpec,
+    Cell, CellKind, ClockEdge, FfCell, GateAssertion, GateModule, GatePort, NET_CONST0, NET_CONST1,
+    NetDriver, NetId, NetInfo, PortDir, RESERVED_NETS, RamBlock, RamReadPort, RamWritePort,
+    ResetPolarity, ResetSpec,
 };
 use crate::library::{CellLibrary, library_for};
 use crate::synthesizer_error::{SynthesizerError, UnsupportedKind};
@@ -70,6 +70,56 @@ pub fn convert_module_with_library(
     ram: RamConfig,
     library: &'static dyn CellLibrary,
 ) -> Result<GateModule, SynthesizerError> {
+    convert(module, ram, library, ConvMode::Optimized).map(|c| c.gate)
+}
+
+/// [`convert_module_with_library`] that also returns the module built
+/// without any optimization, for `veryl synth --verify`. Child instances are
+/// flattened unoptimized into the reference too, so it covers their
+/// optimization as well as the top module's.
+pub fn convert_module_with_reference(
+    module: &air::Module,
+    ram: RamConfig,
+    library: &'static dyn CellLibrary,
+) -> Result<(GateModule, GateModule), SynthesizerError> {
+    let gate = convert(module, ram, library, ConvMode::Optimized)?.gate;
+    let reference = convert(module, ram, library, ConvMode::Reference)?.gate;
+    Ok((gate, reference))
+}
+
+/// The module as first built — no optimization — plus its `$assert`s as
+/// [`GateAssertion`]s, for `veryl prove`. Assertions are collected from this
+/// module's own blocks only; flattened child instances contribute logic but
+/// not their assertions.
+pub fn convert_module_for_proof(
+    module: &air::Module,
+    ram: RamConfig,
+    library: &'static dyn CellLibrary,
+) -> Result<(GateModule, Vec<GateAssertion>), SynthesizerError> {
+    let c = convert(module, ram, library, ConvMode::Proof)?;
+    Ok((c.gate, c.assertions))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ConvMode {
+    Optimized,
+    /// Unoptimized, child instances included.
+    Reference,
+    /// Unoptimized, with assertions collected.
+    Proof,
+}
+
+struct Converted {
+    gate: GateModule,
+    assertions: Vec<GateAssertion>,
+}
+
+fn convert(
+    module: &air::Module,
+    ram: RamConfig,
+    library: &'static dyn CellLibrary,
+    mode: ConvMode,
+) -> Result<Converted, SynthesizerError> {
     struct DepthGuard;
     impl Drop for DepthGuard {
         fn drop(&mut self) {
@@ -85,6 +135,16 @@ pub fn convert_module_with_library(
     CONV_DEPTH.with(|d| d.set(d.get() + 1));
     let _depth_guard = DepthGuard;
 
+    // Registers only checkers read are no part of the design; a proof, which
+    // checks the assertions, is the one mode that needs them.
+    let stripped;
+    let module = if mode != ConvMode::Proof && !module.verification_vars.is_empty() {
+        stripped = strip_verification(module);
+        &stripped
+    } else {
+        module
+    };
+
     let timed = module.declarations.len() > 600 && env::var_os("VERYL_SYNTH_TIME").is_some();
     macro_rules! phase {
         ($label:expr, $e:expr) => {{
@@ -107,6 +167,10 @@ pub fn convert_module_with_library(
         .map(|(k, v)| (*k, v.clone()))
         .collect();
     let mut ctx = ConvContext::new(functions, ram, library);
+    if mode == ConvMode::Proof {
+        ctx.assertions = Some(Vec::new());
+    }
+    ctx.keep_reference = mode == ConvMode::Reference;
     // Detect RAMs before FF banks are allocated, so qualifying arrays skip the
     // per-bit flip-flop + address decode/mux expansion. Opt-out: VERYL_SYNTH_NO_RAM.
     if env::var_os("VERYL_SYNTH_NO_RAM").is_none() {
@@ -151,7 +215,9 @@ pub fn convert_module_with_library(
         );
     }
 
-    ctx.finalize(module)
+    let assertions = ctx.assertions.take().unwrap_or_default();
+    let gate = ctx.finalize(module, mode)?;
+    Ok(Converted { gate, assertions })
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -239,6 +305,16 @@ pub(crate) struct ConvContext {
     pub ram_config: RamConfig,
     /// Target cell library, scoring the restructure A/B in `finalize`.
     pub library: &'static dyn CellLibrary,
+    /// `$assert`s lowered so far; `None` (the default) skips them, since only
+    /// `veryl prove` consumes them and their conditions would otherwise add
+    /// dead logic and unsupported-expression errors to plain synthesis.
+    pub assertions: Option<Vec<GateAssertion>>,
+    /// Set while an `always_ff` body is processed. Its `$assert`s read the
+    /// registers as they were before the block's (nonblocking) writes.
+    pub in_ff: bool,
+    /// Building a reference for `veryl synth --verify`: child instances are
+    /// flattened unoptimized too.
+    pub keep_reference: bool,
 }
 
 /// One enclosing branch condition. `Neg` (the `else` side) materialises its NOT
@@ -280,6 +356,9 @@ impl ConvContext {
             cond_stack: Vec::new(),
             ram_config,
             library,
+            assertions: None,
+            in_ff: false,
+            keep_reference: false,
         }
     }
 
@@ -530,6 +609,29 @@ impl ConvContext {
         result
     }
 
+    fn ff_reset_spec(
+        &self,
+        ff_decl: &air::FfDeclaration,
+    ) -> Result<Option<ResetSpec>, SynthesizerError> {
+        let Some(reset) = &ff_decl.reset else {
+            return Ok(None);
+        };
+        let rn = self.resolve_scalar_ref(reset.id, "reset")?;
+        let (polarity, sync) = match reset.comptime.r#type.kind {
+            air::TypeKind::ResetAsyncHigh => (ResetPolarity::ActiveHigh, false),
+            air::TypeKind::ResetAsyncLow => (ResetPolarity::ActiveLow, false),
+            air::TypeKind::ResetSyncHigh => (ResetPolarity::ActiveHigh, true),
+            air::TypeKind::ResetSyncLow => (ResetPolarity::ActiveLow, true),
+            // `reset` without explicit polarity defaults to active-low async.
+            _ => (ResetPolarity::ActiveLow, false),
+        };
+        Ok(Some(ResetSpec {
+            net: rn,
+            polarity,
+            sync,
+        }))
+    }
+
     fn preallocate_ff_cells(&mut self, module: &air::Module) -> Result<(), SynthesizerError> {
         for decl in &module.declarations {
             if let Declaration::Ff(ff_decl) = decl {
@@ -538,24 +640,7 @@ impl ConvContext {
                     air::TypeKind::ClockNegedge => ClockEdge::Negedge,
                     _ => ClockEdge::Posedge,
                 };
-                let reset_spec = if let Some(reset) = &ff_decl.reset {
-                    let rn = self.resolve_scalar_ref(reset.id, "reset")?;
-                    let (polarity, sync) = match reset.comptime.r#type.kind {
-                        air::TypeKind::ResetAsyncHigh => (ResetPolarity::ActiveHigh, false),
-                        air::TypeKind::ResetAsyncLow => (ResetPolarity::ActiveLow, false),
-                        air::TypeKind::ResetSyncHigh => (ResetPolarity::ActiveHigh, true),
-                        air::TypeKind::ResetSyncLow => (ResetPolarity::ActiveLow, true),
-                        // `reset` without explicit polarity defaults to active-low async.
-                        _ => (ResetPolarity::ActiveLow, false),
-                    };
-                    Some(ResetSpec {
-                        net: rn,
-                        polarity,
-                        sync,
-                    })
-                } else {
-                    None
-                };
+                let reset_spec = self.ff_reset_spec(ff_decl)?;
                 let clock_domain = ff_decl.clock.comptime.clock_domain;
 
                 let mut assigned: Vec<air::VarId> = Vec::new();
@@ -699,7 +784,29 @@ impl ConvContext {
                         }
                     }
                 }
-                process_statements(self, &main_stmts, &mut current)?;
+                // An assertion in the clocked path of an `if_reset` block only
+                // runs while reset is inactive; thread that onto `cond_stack`
+                // so it lands in the assertion's enable. Proof mode only: it
+                // would also gate RAM write enables, which synthesis keeps as-is.
+                let reset_guard = match self.assertions {
+                    Some(_) if matches!(x.statements.first(), Some(Statement::IfReset(_))) => {
+                        self.ff_reset_spec(x)?.map(|spec| match spec.polarity {
+                            ResetPolarity::ActiveHigh => CondTerm::Neg(spec.net),
+                            ResetPolarity::ActiveLow => CondTerm::Pos(spec.net),
+                        })
+                    }
+                    _ => None,
+                };
+                if let Some(term) = reset_guard {
+                    self.cond_stack.push(term);
+                }
+                self.in_ff = true;
+                let r = process_statements(self, &main_stmts, &mut current);
+                self.in_ff = false;
+                if reset_guard.is_some() {
+                    self.cond_stack.pop();
+                }
+                r?;
                 for (vid, nets) in current {
                     let pre = match self.ff_allocation.get(&vid) {
                         Some(p) => p.ff_indices.clone(),
@@ -785,19 +892,21 @@ impl ConvContext {
         let account = CONV_DEPTH.with(|d| d.get()) == 1;
         let t_child = Instant::now();
         let cache_key = Arc::as_ptr(&inst.component) as *const ();
-        let child_gate: Rc<GateModule> =
-            match CHILD_CACHE.with(|c| c.borrow().get(&cache_key).cloned()) {
-                Some(g) => g,
-                None => {
-                    let g = Rc::new(convert_module_with_library(
-                        child_module,
-                        self.ram_config,
-                        self.library,
-                    )?);
-                    CHILD_CACHE.with(|c| c.borrow_mut().insert(cache_key, g.clone()));
-                    g
-                }
-            };
+        let child_gate: Rc<GateModule> = match CHILD_CACHE
+            .with(|c| c.borrow().get(&cache_key).cloned())
+        {
+            Some(g) => g,
+            None => {
+                let mode = if self.keep_reference {
+                    ConvMode::Reference
+                } else {
+                    ConvMode::Optimized
+                };
+                let g = Rc::new(convert(child_module, self.ram_config, self.library, mode)?.gate);
+                CHILD_CACHE.with(|c| c.borrow_mut().insert(cache_key, g.clone()));
+                g
+            }
+        };
         if account {
             FLATTEN_NANOS.fetch_add(t_child.elapsed().as_nanos() as u64, Ordering::Relaxed);
         }
@@ -1016,7 +1125,11 @@ impl ConvContext {
         Ok(())
     }
 
-    fn finalize(mut self, module: &air::Module) -> Result<GateModule, SynthesizerError> {
+    fn finalize(
+        mut self,
+        module: &air::Module,
+        mode: ConvMode,
+    ) -> Result<GateModule, SynthesizerError> {
         let ports = self.build_ports(module);
         // Tie any undriven output/inout net to GND so downstream analysis
         // doesn't hit an Undriven in the middle of a path.
@@ -1071,6 +1184,9 @@ impl ConvContext {
             ffs: self.ffs,
             ram_blocks,
         };
+        if mode != ConvMode::Optimized {
+            return Ok(gate);
+        }
         // Worklist-based convergence. Each cell is revisited only when one
         // of its inputs has been rewritten since the last visit, instead of
         // scanning the whole cell list every outer iteration. Drops outer
@@ -1292,6 +1408,44 @@ fn eliminate_dq_ffs(module: &mut GateModule) {
 
 /// Walks nested statements, calling `f` once per `VarId` that appears as an
 /// assignment destination (including inside `if` / `for` bodies).
+/// `module` without its verification-only registers and their updates.
+fn strip_verification(module: &air::Module) -> air::Module {
+    let vars = &module.verification_vars;
+    let mut ret = module.clone();
+    ret.variables.retain(|id, _| !vars.contains(id));
+    for decl in &mut ret.declarations {
+        if let Declaration::Ff(x) = decl {
+            retain_design(&mut x.statements, vars);
+        }
+    }
+    ret.verification_vars.clear();
+    ret
+}
+
+fn retain_design(stmts: &mut Vec<Statement>, vars: &fxhash::FxHashSet<air::VarId>) {
+    stmts.retain_mut(|stmt| match stmt {
+        Statement::Assign(a) => !a.dst.iter().all(|d| vars.contains(&d.id)),
+        Statement::If(i) => {
+            retain_design(&mut i.true_side, vars);
+            retain_design(&mut i.false_side, vars);
+            true
+        }
+        Statement::IfReset(i) => {
+            retain_design(&mut i.true_side, vars);
+            retain_design(&mut i.false_side, vars);
+            true
+        }
+        Statement::Case(c) => {
+            for arm in &mut c.arms {
+                retain_design(&mut arm.body, vars);
+            }
+            retain_design(&mut c.default, vars);
+            true
+        }
+        _ => true,
+    });
+}
+
 pub(crate) fn collect_assigned(stmt: &Statement, f: &mut impl FnMut(air::VarId)) {
     match stmt {
         Statement::Assign(a) => {
@@ -9,7 +9,7 @@ use crate::conv::expression::{
 };
 use crate::conv::ram;
 use crate::conv::{CondTerm, ConvContext};
-use crate::ir::{CellKind, NET_CONST0, NET_CONST1, NetId, RamWritePort};
+use crate::ir::{CellKind, GateAssertion, NET_CONST0, NET_CONST1, NetId, RamWritePort};
 use crate::synthesizer_error::{SynthesizerError, UnsupportedKind};
 
 pub(crate) fn process_statements(
@@ -109,12 +109,37 @@ fn process_statement(
             UnsupportedKind::ForStatement,
             &fs.token,
         )),
-        // $display / $finish etc have no gate-level effect.
-        Statement::SystemFunctionCall(_) => Ok(()),
+        Statement::SystemFunctionCall(call) => {
+            if let air::SystemFunctionKind::Assert { kind, cond, .. } = &call.kind
+                && ctx.assertions.is_some()
+            {
+                // In `always_ff`, resolve reads against the Q nets, not the
+                // D values `current` has accumulated so far.
+                let cond = if ctx.in_ff {
+                    synthesize_expr(ctx, &cond.0, &mut HashMap::new(), 1)?[0]
+                } else {
+                    synthesize_expr(ctx, &cond.0, current, 1)?[0]
+                };
+                let enable = current_write_enable(ctx);
+                if let Some(assertions) = ctx.assertions.as_mut() {
+                    assertions.push(GateAssertion {
+                        kind: *kind,
+                        cond,
+                        enable,
+                        token: call.comptime.token,
+                    });
+                }
+            }
+            // $display / $finish etc have no gate-level effect.
+            Ok(())
+        }
         Statement::FunctionCall(call) => synth_function_call_stmt(ctx, call, current),
         Statement::TbMethodCall(_) => Err(SynthesizerError::internal(
             "testbench method call reached synthesizer",
         )),
+        Statement::Fork(_) => Err(SynthesizerError::internal(
+            "fork statement reached synthesizer",
+        )),
         Statement::Break => Err(SynthesizerError::internal(
             "break statement reached synthesizer",
         )),
@@ -796,7 +821,11 @@ fn synth_case_chain(
     }
 
     // This fold processes arm bodies without pushing onto `cond_stack`, so a RAM
-    // write inside would lose its arm condition. Fall back to nested-if lowering.
+    // write or an assertion inside would lose its arm condition. Fall back to
+    // nested-if lowering.
+    if ctx.assertions.is_some() {
+        return Ok(false);
+    }
     if !ctx.ram_vars.is_empty()
         && (ram::stmts_write_ram(&ctx.ram_vars, chain.default_body)
             || chain
@@ -1,7 +1,9 @@
 use indent::indent_all_by;
 use std::fmt;
+use veryl_analyzer::ir::AssertKind;
 use veryl_analyzer::symbol::ClockDomain;
 use veryl_parser::resource_table::StrId;
+use veryl_parser::token_range::TokenRange;
 
 pub type NetId = u32;
 
@@ -45,6 +47,28 @@ pub struct GatePort {
     pub nets: Vec<NetId>,
 }
 
+impl GatePort {
+    /// Flat identifier for emitted netlists: modport members
+    /// (`path.len() > 1`) are joined with `_`.
+    pub fn flat_name(&self) -> String {
+        if self.path.len() > 1 {
+            let segs: Vec<String> = self.path.iter().map(|s| s.to_string()).collect();
+            segs.join("_")
+        } else {
+            self.name.to_string()
+        }
+    }
+
+    /// Name of bit `i`: the bare port name for 1-bit ports, else `name[i]`.
+    pub fn bit_name(&self, i: usize) -> String {
+        if self.nets.len() == 1 {
+            self.flat_name()
+        } else {
+            format!("{}[{}]", self.flat_name(), i)
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PortDir {
     Input,
@@ -120,6 +144,31 @@ pub enum CellKind {
 }
 
 impl CellKind {
+    pub const ALL: [CellKind; 22] = [
+        CellKind::Buf,
+        CellKind::Not,
+        CellKind::And2,
+        CellKind::Or2,
+        CellKind::Nand2,
+        CellKind::Nor2,
+        CellKind::Xor2,
+        CellKind::Xnor2,
+        CellKind::And3,
+        CellKind::Or3,
+        CellKind::Nand3,
+        CellKind::Nor3,
+        CellKind::Ao21,
+        CellKind::Aoi21,
+        CellKind::Oa21,
+        CellKind::Oai21,
+        CellKind::Ao31,
+        CellKind::Aoi31,
+        CellKind::Ao22,
+        CellKind::Aoi22,
+        CellKind::Oai22,
+        CellKind::Mux2,
+    ];
+
     pub fn arity(self) -> usize {
         match self {
             CellKind::Buf | CellKind::Not => 1,
@@ -172,6 +221,40 @@ impl CellKind {
             CellKind::Mux2 => "mux2",
         }
     }
+
+    /// Output for input values `x`, in [`Cell::inputs`] order.
+    pub fn eval(self, x: &[bool]) -> bool {
+        match self {
+            CellKind::Buf => x[0],
+            CellKind::Not => !x[0],
+            CellKind::And2 => x[0] && x[1],
+            CellKind::Or2 => x[0] || x[1],
+            CellKind::Nand2 => !(x[0] && x[1]),
+            CellKind::Nor2 => !(x[0] || x[1]),
+            CellKind::Xor2 => x[0] ^ x[1],
+            CellKind::Xnor2 => !(x[0] ^ x[1]),
+            CellKind::And3 => x[0] && x[1] && x[2],
+            CellKind::Or3 => x[0] || x[1] || x[2],
+            CellKind::Nand3 => !(x[0] && x[1] && x[2]),
+            CellKind::Nor3 => !(x[0] || x[1] || x[2]),
+            CellKind::Ao21 => (x[0] && x[1]) || x[2],
+            CellKind::Aoi21 => !((x[0] && x[1]) || x[2]),
+            CellKind::Oa21 => (x[0] || x[1]) && x[2],
+            CellKind::Oai21 => !((x[0] || x[1]) && x[2]),
+            CellKind::Ao31 => (x[0] && x[1] && x[2]) || x[3],
+            CellKind::Aoi31 => !((x[0] && x[1] && x[2]) || x[3]),
+            CellKind::Ao22 => (x[0] && x[1]) || (x[2] && x[3]),
+            CellKind::Aoi22 => !((x[0] && x[1]) || (x[2] && x[3])),
+            CellKind::Oai22 => !((x[0] || x[1]) && (x[2] || x[3])),
+            CellKind::Mux2 => {
+                if x[0] {
+                    x[2]
+                } else {
+                    x[1]
+                }
+            }
+        }
+    }
 }
 
 impl fmt::Display for CellKind {
@@ -236,6 +319,19 @@ pub struct FfCell {
     pub origin: Option<(StrId, usize)>,
 }
 
+/// A `$assert` / `$assert_continue` lowered to gates: it fails in a cycle
+/// where `enable` (the enclosing branch conditions, plus "not in reset" for
+/// an `if_reset` block) is high and `cond` is low. Only collected for
+/// `veryl prove`, on the unoptimized module; see
+/// `conv::convert_module_for_proof`.
+#[derive(Clone)]
+pub struct GateAssertion {
+    pub kind: AssertKind,
+    pub cond: NetId,
+    pub enable: NetId,
+    pub token: TokenRange,
+}
+
 /// One synchronous write port of a [`RamBlock`]. `data`/`addr`/`enable` are
 /// driven by surrounding logic; the write commits on the RAM's clock edge when
 /// `enable` is high. `addr`/`data` are LSB-first.
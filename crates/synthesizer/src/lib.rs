@@ -1,22 +1,29 @@
-#[cfg(feature = "aig")]
 pub mod aig;
 pub mod analysis;
+pub mod bmc;
 pub mod conv;
 pub mod ir;
 pub mod library;
+pub mod netlist;
 pub mod synthesizer_error;
+pub mod verify;
 
+pub use aig::export::{write_aiger, write_blif};
+pub use aig::sequential::SequentialAig;
 pub use analysis::{
-    AreaReport, PathStep, PowerKindRow, PowerReport, StepKind, TimingReport, compute_power,
-    compute_timing_top_n, port_label,
+    AreaReport, ClockSlack, PathStep, PowerKindRow, PowerReport, StepKind, TimingReport,
+    compute_clock_slack, compute_power, compute_timing_top_n, port_label,
 };
+pub use bmc::{BmcOutcome, BmcReport, Counterexample, ProofModel};
 pub use ir::{
-    Cell, CellKind, ClockEdge, FfCell, GateIr, GateModule, GatePort, NetId, NetInfo, PortDir,
-    RamBlock, RamReadPort, RamWritePort, ResetPolarity, ResetSpec,
+    Cell, CellKind, ClockEdge, FfCell, GateAssertion, GateIr, GateModule, GatePort, NetId, NetInfo,
+    PortDir, RamBlock, RamReadPort, RamWritePort, ResetPolarity, ResetSpec,
 };
-pub use library::{CellInfo, CellLibrary, SramModel, library_for};
+pub use library::{CellInfo, CellLibrary, LibertyLibrary, SramModel, library_for};
+pub use netlist::write_verilog;
 pub use synthesizer_error::SynthesizerError;
-pub use veryl_metadata::{Library, Synth};
+pub use verify::{Mismatch, MismatchKind, VerifyReport, check_equivalence};
+pub use veryl_metadata::{ClockConstraint, Library, Synth, TimingConstraints};
 
 use std::env;
 use std::time::Instant;
@@ -73,12 +80,29 @@ pub fn build_gate_ir_with_library(
     ram: RamConfig,
     library: &'static dyn CellLibrary,
 ) -> Result<GateIr, SynthesizerError> {
+    let module = conv::convert_module_with_library(find_top(ir, top)?, ram, library)?;
+    Ok(GateIr { module })
+}
+
+/// The `top` module and its `$assert`s, for [`ProofModel::check`]: built
+/// without RAM inference or optimization, so every register is a flip-flop
+/// and every assertion keeps its nets.
+pub fn build_proof_model(ir: &AnalyzerIr, top: StrId) -> Result<ProofModel, SynthesizerError> {
+    let ram = RamConfig {
+        min_bits: usize::MAX,
+        ..RamConfig::default()
+    };
+    let (module, assertions) =
+        conv::convert_module_for_proof(find_top(ir, top)?, ram, library_for(Library::default()))?;
+    Ok(ProofModel { module, assertions })
+}
+
+fn find_top(ir: &AnalyzerIr, top: StrId) -> Result<&veryl_analyzer::ir::Module, SynthesizerError> {
     for c in &ir.components {
         if let veryl_analyzer::ir::Component::Module(m) = c
             && m.name == top
         {
-            let module = conv::convert_module_with_library(m, ram, library)?;
-            return Ok(GateIr { module });
+            return Ok(m);
         }
     }
     Err(SynthesizerError::TopModuleNotFound {
@@ -107,10 +131,45 @@ pub fn synthesize_with(
     library: Library,
     ram: RamConfig,
 ) -> Result<SynthResult, SynthesizerError> {
-    let timed = env::var_os("VERYL_SYNTH_TIME").is_some();
-    let lib = library_for(library);
+    synthesize_with_library(ir, top, library_for(library), ram)
+}
+
+/// [`synthesize_with`] against any [`CellLibrary`], e.g. a
+/// [`LibertyLibrary`].
+pub fn synthesize_with_library(
+    ir: &AnalyzerIr,
+    top: StrId,
+    lib: &'static dyn CellLibrary,
+    ram: RamConfig,
+) -> Result<SynthResult, SynthesizerError> {
     let t = Instant::now();
     let gate_ir = build_gate_ir_with_library(ir, top, ram, lib)?;
+    Ok(report(gate_ir, lib, t))
+}
+
+/// [`synthesize_with_library`] plus a formal equivalence check of the
+/// result against the unoptimized gate netlist (see [`verify`]).
+pub fn synthesize_and_verify(
+    ir: &AnalyzerIr,
+    top: StrId,
+    lib: &'static dyn CellLibrary,
+    ram: RamConfig,
+) -> Result<(SynthResult, VerifyReport), SynthesizerError> {
+    let t = Instant::now();
+    let (module, reference) = conv::convert_module_with_reference(find_top(ir, top)?, ram, lib)?;
+    let result = report(GateIr { module }, lib, t);
+    let t = Instant::now();
+    let verify = check_equivalence(&reference, &result.gate_ir.module);
+    if env::var_os("VERYL_SYNTH_TIME").is_some() {
+        eprintln!("[synth-time] verify: {:.3}s", t.elapsed().as_secs_f64());
+    }
+    Ok((result, verify))
+}
+
+/// Area and timing for a freshly built `gate_ir`; `t` marks the start of
+/// the build.
+fn report(gate_ir: GateIr, lib: &'static dyn CellLibrary, t: Instant) -> SynthResult {
+    let timed = env::var_os("VERYL_SYNTH_TIME").is_some();
     if timed {
         eprintln!(
             "[synth-time] build_gate_ir: {:.3}s ({} cells, {} ffs, {} rams)",
@@ -136,9 +195,9 @@ pub fn synthesize_with(
             t.elapsed().as_secs_f64()
         );
     }
-    Ok(SynthResult {
+    SynthResult {
         gate_ir,
         area,
         timing,
-    })
+    }
 }
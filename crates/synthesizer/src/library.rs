@@ -12,11 +12,14 @@
 mod asap7;
 mod gf180mcu;
 mod ihp_sg13g2;
+mod liberty;
 mod sky130;
 
 use crate::ir::CellKind;
 use veryl_metadata::Library;
 
+pub use liberty::LibertyLibrary;
+
 /// Per-cell area (um²), intrinsic delay (ns), leakage power (nW), and
 /// internal switching energy (pJ per output transition).
 #[derive(Clone, Copy, Debug)]
@@ -57,12 +60,61 @@ impl SramModel {
     }
 }
 
+/// Library master a [`CellKind`] is instantiated as in a structural netlist.
+/// `inputs` lists pin names in [`crate::ir::Cell::inputs`] order (so Mux2 is
+/// `[sel, d0, d1]`).
+#[derive(Clone, Copy, Debug)]
+pub struct NetlistCell {
+    pub name: &'static str,
+    pub inputs: &'static [&'static str],
+    pub output: &'static str,
+}
+
+/// Async clear/set pin of a [`NetlistFf`].
+#[derive(Clone, Copy, Debug)]
+pub struct NetlistResetPin {
+    pub name: &'static str,
+    pub active_low: bool,
+}
+
+/// A positive-edge D flip-flop master. `reset` is the async clear (or set)
+/// pin, `None` for a plain DFF; `ties` are further pins held at a constant
+/// (e.g. the unused SET of a combined set/reset FF). `q_inverted` marks a
+/// QN-only master.
+#[derive(Clone, Copy, Debug)]
+pub struct NetlistFf {
+    pub name: &'static str,
+    pub clock: &'static str,
+    pub d: &'static str,
+    pub q: &'static str,
+    pub q_inverted: bool,
+    pub reset: Option<NetlistResetPin>,
+    pub ties: &'static [(&'static str, bool)],
+}
+
+/// The flip-flop masters a netlist writer maps every [`crate::ir::FfCell`]
+/// onto. Negedge clocks, reset polarity and sync resets are built from
+/// these plus ordinary cells. A library without a dedicated plain or
+/// async-set DFF leaves that slot `None`; the writer then ties off or
+/// inverts around `clear`.
+#[derive(Clone, Copy, Debug)]
+pub struct NetlistFfs {
+    pub plain: Option<NetlistFf>,
+    pub clear: NetlistFf,
+    pub set: Option<NetlistFf>,
+}
+
 /// The per-technology data a synthesis estimation needs: cell-kind
 /// metrics, FF primitive parameters, and a banner line for reports.
-/// One implementation per PDK, dispatched via [`library_for`].
+/// One implementation per built-in PDK, dispatched via [`library_for`],
+/// plus [`LibertyLibrary`] for user-supplied `.lib` files.
 pub trait CellLibrary {
     fn banner(&self) -> &'static str;
     fn info(&self, kind: CellKind) -> CellInfo;
+    /// Netlist master for `kind`. `None` when the library has no matching
+    /// cell; the writer then decomposes it (Buf → `assign`, Mux2 → Not + Ao22).
+    fn netlist_cell(&self, kind: CellKind) -> Option<NetlistCell>;
+    fn netlist_ffs(&self) -> NetlistFfs;
     fn ff_setup(&self) -> f64;
     fn ff_area(&self) -> f64;
     fn ff_leakage(&self) -> f64;
@@ -6,7 +6,7 @@
 //! No dedicated Mux2 cell; approximated by AOI22x1.
 //!
 //! Source: https://github.com/The-OpenROAD-Project/asap7 (BSD 3-Clause).
-use super::{CellInfo, CellLibrary};
+use super::{CellInfo, CellLibrary, NetlistCell, NetlistFf, NetlistFfs, NetlistResetPin};
 use crate::ir::CellKind;
 
 pub struct Asap7;
@@ -175,6 +175,76 @@ impl CellLibrary for Asap7 {
         }
     }
 
+    fn netlist_cell(&self, kind: CellKind) -> Option<NetlistCell> {
+        let (name, inputs, output): (_, &[_], _) = match kind {
+            CellKind::Buf => return None,
+            CellKind::Not => ("INVx1_ASAP7_75t_R", &["A"], "Y"),
+            CellKind::And2 => ("AND2x2_ASAP7_75t_R", &["A", "B"], "Y"),
+            CellKind::Or2 => ("OR2x2_ASAP7_75t_R", &["A", "B"], "Y"),
+            CellKind::Nand2 => ("NAND2x1_ASAP7_75t_R", &["A", "B"], "Y"),
+            CellKind::Nor2 => ("NOR2x1_ASAP7_75t_R", &["A", "B"], "Y"),
+            CellKind::Xor2 => ("XOR2x1_ASAP7_75t_R", &["A", "B"], "Y"),
+            CellKind::Xnor2 => ("XNOR2x1_ASAP7_75t_R", &["A", "B"], "Y"),
+            CellKind::And3 => ("AND3x1_ASAP7_75t_R", &["A", "B", "C"], "Y"),
+            CellKind::Or3 => ("OR3x1_ASAP7_75t_R", &["A", "B", "C"], "Y"),
+            CellKind::Nand3 => ("NAND3x1_ASAP7_75t_R", &["A", "B", "C"], "Y"),
+            CellKind::Nor3 => ("NOR3x1_ASAP7_75t_R", &["A", "B", "C"], "Y"),
+            CellKind::Ao21 => ("AO21x1_ASAP7_75t_R", &["A1", "A2", "B"], "Y"),
+            CellKind::Aoi21 => ("AOI21x1_ASAP7_75t_R", &["A1", "A2", "B"], "Y"),
+            CellKind::Oa21 => ("OA21x2_ASAP7_75t_R", &["A1", "A2", "B"], "Y"),
+            CellKind::Oai21 => ("OAI21x1_ASAP7_75t_R", &["A1", "A2", "B"], "Y"),
+            CellKind::Ao31 => ("AO31x2_ASAP7_75t_R", &["A1", "A2", "A3", "B"], "Y"),
+            CellKind::Aoi31 => ("AOI31xp33_ASAP7_75t_R", &["A1", "A2", "A3", "B"], "Y"),
+            CellKind::Ao22 => ("AO22x1_ASAP7_75t_R", &["A1", "A2", "B1", "B2"], "Y"),
+            CellKind::Aoi22 => ("AOI22x1_ASAP7_75t_R", &["A1", "A2", "B1", "B2"], "Y"),
+            CellKind::Oai22 => ("OAI22x1_ASAP7_75t_R", &["A1", "A2", "B1", "B2"], "Y"),
+            CellKind::Mux2 => return None,
+        };
+        Some(NetlistCell {
+            name,
+            inputs,
+            output,
+        })
+    }
+
+    fn netlist_ffs(&self) -> NetlistFfs {
+        NetlistFfs {
+            plain: Some(NetlistFf {
+                name: "DFFHQNx1_ASAP7_75t_R",
+                clock: "CLK",
+                d: "D",
+                q: "QN",
+                q_inverted: true,
+                reset: None,
+                ties: &[],
+            }),
+            clear: NetlistFf {
+                name: "ASYNC_DFFHx2_ASAP7_75t_R",
+                clock: "CLK",
+                d: "D",
+                q: "QN",
+                q_inverted: true,
+                reset: Some(NetlistResetPin {
+                    name: "RESET",
+                    active_low: false,
+                }),
+                ties: &[("SET", false)],
+            },
+            set: Some(NetlistFf {
+                name: "ASYNC_DFFHx2_ASAP7_75t_R",
+                clock: "CLK",
+                d: "D",
+                q: "QN",
+                q_inverted: true,
+                reset: Some(NetlistResetPin {
+                    name: "SET",
+                    active_low: false,
+                }),
+                ties: &[("RESET", false)],
+            }),
+        }
+    }
+
     // DFFHQNx1 — positive-edge D-FF at tt_0p7V.
     fn ff_setup(&self) -> f64 {
         0.050
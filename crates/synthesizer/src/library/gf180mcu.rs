@@ -5,7 +5,7 @@
 //! 1.8 V operation — high leakage by modern standards.
 //!
 //! Source: https://github.com/google/gf180mcu-pdk (Apache 2.0).
-use super::{CellInfo, CellLibrary};
+use super::{CellInfo, CellLibrary, NetlistCell, NetlistFf, NetlistFfs, NetlistResetPin};
 use crate::ir::CellKind;
 
 pub struct Gf180mcu;
@@ -174,6 +174,100 @@ impl CellLibrary for Gf180mcu {
         }
     }
 
+    fn netlist_cell(&self, kind: CellKind) -> Option<NetlistCell> {
+        let (name, inputs, output): (_, &[_], _) = match kind {
+            CellKind::Buf => return None,
+            CellKind::Not => ("gf180mcu_fd_sc_mcu7t5v0__inv_1", &["I"], "ZN"),
+            CellKind::And2 => ("gf180mcu_fd_sc_mcu7t5v0__and2_1", &["A1", "A2"], "Z"),
+            CellKind::Or2 => ("gf180mcu_fd_sc_mcu7t5v0__or2_1", &["A1", "A2"], "Z"),
+            CellKind::Nand2 => ("gf180mcu_fd_sc_mcu7t5v0__nand2_1", &["A1", "A2"], "ZN"),
+            CellKind::Nor2 => ("gf180mcu_fd_sc_mcu7t5v0__nor2_1", &["A1", "A2"], "ZN"),
+            CellKind::Xor2 => ("gf180mcu_fd_sc_mcu7t5v0__xor2_1", &["A1", "A2"], "Z"),
+            CellKind::Xnor2 => ("gf180mcu_fd_sc_mcu7t5v0__xnor2_1", &["A1", "A2"], "ZN"),
+            CellKind::And3 => ("gf180mcu_fd_sc_mcu7t5v0__and3_1", &["A1", "A2", "A3"], "Z"),
+            CellKind::Or3 => ("gf180mcu_fd_sc_mcu7t5v0__or3_1", &["A1", "A2", "A3"], "Z"),
+            CellKind::Nand3 => (
+                "gf180mcu_fd_sc_mcu7t5v0__nand3_1",
+                &["A1", "A2", "A3"],
+                "ZN",
+            ),
+            CellKind::Nor3 => ("gf180mcu_fd_sc_mcu7t5v0__nor3_1", &["A1", "A2", "A3"], "ZN"),
+            CellKind::Ao21 => ("gf180mcu_fd_sc_mcu7t5v0__ao21_1", &["A1", "A2", "B"], "Z"),
+            CellKind::Aoi21 => ("gf180mcu_fd_sc_mcu7t5v0__aoi21_1", &["A1", "A2", "B"], "ZN"),
+            CellKind::Oa21 => ("gf180mcu_fd_sc_mcu7t5v0__oa21_1", &["A1", "A2", "B"], "Z"),
+            CellKind::Oai21 => ("gf180mcu_fd_sc_mcu7t5v0__oai21_1", &["A1", "A2", "B"], "ZN"),
+            CellKind::Ao31 => (
+                "gf180mcu_fd_sc_mcu7t5v0__ao31_1",
+                &["A1", "A2", "A3", "B"],
+                "Z",
+            ),
+            CellKind::Aoi31 => (
+                "gf180mcu_fd_sc_mcu7t5v0__aoi31_1",
+                &["A1", "A2", "A3", "B"],
+                "ZN",
+            ),
+            CellKind::Ao22 => (
+                "gf180mcu_fd_sc_mcu7t5v0__ao22_1",
+                &["A1", "A2", "B1", "B2"],
+                "Z",
+            ),
+            CellKind::Aoi22 => (
+                "gf180mcu_fd_sc_mcu7t5v0__aoi22_1",
+                &["A1", "A2", "B1", "B2"],
+                "ZN",
+            ),
+            CellKind::Oai22 => (
+                "gf180mcu_fd_sc_mcu7t5v0__oai22_1",
+                &["A1", "A2", "B1", "B2"],
+                "ZN",
+            ),
+            CellKind::Mux2 => ("gf180mcu_fd_sc_mcu7t5v0__mux2_1", &["S", "I0", "I1"], "Z"),
+        };
+        Some(NetlistCell {
+            name,
+            inputs,
+            output,
+        })
+    }
+
+    fn netlist_ffs(&self) -> NetlistFfs {
+        NetlistFfs {
+            plain: Some(NetlistFf {
+                name: "gf180mcu_fd_sc_mcu7t5v0__dffq_1",
+                clock: "CLK",
+                d: "D",
+                q: "Q",
+                q_inverted: false,
+                reset: None,
+                ties: &[],
+            }),
+            clear: NetlistFf {
+                name: "gf180mcu_fd_sc_mcu7t5v0__dffrnq_1",
+                clock: "CLK",
+                d: "D",
+                q: "Q",
+                q_inverted: false,
+                reset: Some(NetlistResetPin {
+                    name: "RN",
+                    active_low: true,
+                }),
+                ties: &[],
+            },
+            set: Some(NetlistFf {
+                name: "gf180mcu_fd_sc_mcu7t5v0__dffsnq_1",
+                clock: "CLK",
+                d: "D",
+                q: "Q",
+                q_inverted: false,
+                reset: Some(NetlistResetPin {
+                    name: "SETN",
+                    active_low: true,
+                }),
+                ties: &[],
+            }),
+        }
+    }
+
     // dffq_1 — D-FF with Q output at tt_025C_1v80.
     fn ff_setup(&self) -> f64 {
         0.30
@@ -5,7 +5,7 @@
 //! 1.2 V operation.
 //!
 //! Source: https://github.com/IHP-GmbH/IHP-Open-PDK (Apache 2.0).
-use super::{CellInfo, CellLibrary};
+use super::{CellInfo, CellLibrary, NetlistCell, NetlistFf, NetlistFfs, NetlistResetPin};
 use crate::ir::CellKind;
 
 pub struct IhpSg13g2;
@@ -174,6 +174,57 @@ impl CellLibrary for IhpSg13g2 {
         }
     }
 
+    fn netlist_cell(&self, kind: CellKind) -> Option<NetlistCell> {
+        let (name, inputs, output): (_, &[_], _) = match kind {
+            CellKind::Buf => return None,
+            CellKind::Not => ("sg13g2_inv_1", &["A"], "Y"),
+            CellKind::And2 => ("sg13g2_and2_1", &["A", "B"], "X"),
+            CellKind::Or2 => ("sg13g2_or2_1", &["A", "B"], "X"),
+            CellKind::Nand2 => ("sg13g2_nand2_1", &["A", "B"], "Y"),
+            CellKind::Nor2 => ("sg13g2_nor2_1", &["A", "B"], "Y"),
+            CellKind::Xor2 => ("sg13g2_xor2_1", &["A", "B"], "X"),
+            CellKind::Xnor2 => ("sg13g2_xnor2_1", &["A", "B"], "Y"),
+            CellKind::And3 => ("sg13g2_and3_1", &["A", "B", "C"], "X"),
+            CellKind::Or3 => ("sg13g2_or3_1", &["A", "B", "C"], "X"),
+            CellKind::Nand3 => ("sg13g2_nand3_1", &["A", "B", "C"], "Y"),
+            CellKind::Nor3 => ("sg13g2_nor3_1", &["A", "B", "C"], "Y"),
+            CellKind::Ao21 => ("sg13g2_a21o_1", &["A1", "A2", "B1"], "X"),
+            CellKind::Aoi21 => ("sg13g2_a21oi_1", &["A1", "A2", "B1"], "Y"),
+            CellKind::Oa21 => ("sg13g2_o21a_1", &["A1", "A2", "B1"], "X"),
+            CellKind::Oai21 => ("sg13g2_o21ai_1", &["A1", "A2", "B1"], "Y"),
+            CellKind::Ao31 => ("sg13g2_a31o_1", &["A1", "A2", "A3", "B1"], "X"),
+            CellKind::Aoi31 => ("sg13g2_a31oi_1", &["A1", "A2", "A3", "B1"], "Y"),
+            CellKind::Ao22 => ("sg13g2_a22o_1", &["A1", "A2", "B1", "B2"], "X"),
+            CellKind::Aoi22 => ("sg13g2_a22oi_1", &["A1", "A2", "B1", "B2"], "Y"),
+            CellKind::Oai22 => ("sg13g2_o22ai_1", &["A1", "A2", "B1", "B2"], "Y"),
+            CellKind::Mux2 => ("sg13g2_mux2_1", &["S", "A0", "A1"], "X"),
+        };
+        Some(NetlistCell {
+            name,
+            inputs,
+            output,
+        })
+    }
+
+    fn netlist_ffs(&self) -> NetlistFfs {
+        NetlistFfs {
+            plain: None,
+            clear: NetlistFf {
+                name: "sg13g2_dfrbpq_1",
+                clock: "CLK",
+                d: "D",
+                q: "Q",
+                q_inverted: false,
+                reset: Some(NetlistResetPin {
+                    name: "RESET_B",
+                    active_low: true,
+                }),
+                ties: &[],
+            },
+            set: None,
+        }
+    }
+
     // sg13g2_dfrbpq_1 — D-FF with async reset + Q output at 1.2V typical.
     fn ff_setup(&self) -> f64 {
         0.10
@@ -0,0 +1,1327 @@
+//! Cell library read from user-supplied Liberty (`.lib`) files, for PDKs
+//! that have no built-in table.
+//!
+//! Every [`CellKind`] is matched against the single-output combinational
+//! cells whose `function` equals it under some input-pin permutation; the
+//! smallest-area match wins, so drive strength 1 is chosen the way the
+//! built-in tables do. Its delay and internal energy are read from the NLDM
+//! tables at an FO4 load (four inverter input capacitances) and the
+//! fastest characterized input slew — the worst arc, rise/fall averaged. A
+//! kind without a matching cell is costed as a small composition of kinds
+//! that have one, and written to netlists as an `assign`.
+//!
+//! Flip-flop figures come from the smallest positive-edge D flip-flop with
+//! an async clear, like the built-in tables' `dfrtp`-style reference.
+//!
+//! Several files are merged (e.g. separate logic and sequential libraries);
+//! each file's own `time_unit` / `capacitive_load_unit` /
+//! `leakage_power_unit` / `voltage_unit` apply to its cells.
+
+use super::{CellInfo, CellLibrary, NetlistCell, NetlistFf, NetlistFfs, NetlistResetPin};
+use crate::SynthesizerError;
+use crate::ir::CellKind;
+use std::collections::HashMap;
+
+pub struct LibertyLibrary {
+    name: String,
+    banner: &'static str,
+    infos: HashMap<CellKind, CellInfo>,
+    cells: HashMap<CellKind, NetlistCell>,
+    ffs: NetlistFfs,
+    ff_setup: f64,
+    ff_area: f64,
+    ff_leakage: f64,
+    ff_internal_energy: f64,
+}
+
+impl LibertyLibrary {
+    /// Build a library from `(path, text)` pairs. `path` is only used in
+    /// error messages.
+    pub fn parse(sources: &[(&str, &str)]) -> Result<Self, SynthesizerError> {
+        let mut names = Vec::new();
+        let mut cells = Vec::new();
+        for (path, text) in sources {
+            let error = |e: ParseError| SynthesizerError::liberty(*path, e.to_string());
+            let groups = parse_groups(text).map_err(error)?;
+            let libraries: Vec<&Group> = groups.iter().filter(|g| g.kind == "library").collect();
+            if libraries.is_empty() {
+                return Err(SynthesizerError::liberty(*path, "no `library` group"));
+            }
+            for library in libraries {
+                names.push(library.name().to_string());
+                read_library(library, &mut cells);
+            }
+        }
+        let all_paths: Vec<&str> = sources.iter().map(|(path, _)| *path).collect();
+        let error = |message: &str| SynthesizerError::liberty(all_paths.join(", "), message);
+
+        // Best combinational match per kind.
+        let mut matched: HashMap<CellKind, (&LibCell, Vec<usize>)> = HashMap::new();
+        for cell in &cells {
+            let Some(tt) = cell.truth_table() else {
+                continue;
+            };
+            for kind in CellKind::ALL {
+                if kind == CellKind::Buf || kind.arity() != cell.inputs.len() {
+                    continue;
+                }
+                let Some(perm) = permutation_for(kind, tt, cell.inputs.len()) else {
+                    continue;
+                };
+                let better = match matched.get(&kind) {
+                    Some((best, _)) => (cell.area, &cell.name) < (best.area, &best.name),
+                    None => true,
+                };
+                if better {
+                    matched.insert(kind, (cell, perm));
+                }
+            }
+        }
+
+        // FO4: four inverters (or, failing that, NAND2 inputs) of load.
+        let fo4 = match matched
+            .get(&CellKind::Not)
+            .or_else(|| matched.get(&CellKind::Nand2))
+        {
+            Some((cell, _)) => 4.0 * cell.mean_input_capacitance(),
+            None => return Err(error("no inverter or 2-input NAND cell")),
+        };
+
+        let mut infos = HashMap::new();
+        let mut netlist_cells = HashMap::new();
+        infos.insert(
+            CellKind::Buf,
+            CellInfo {
+                area: 0.0,
+                delay: 0.0,
+                leakage: 0.0,
+                internal_energy: 0.0,
+            },
+        );
+        for (&kind, (cell, perm)) in &matched {
+            infos.insert(
+                kind,
+                CellInfo {
+                    area: cell.area,
+                    delay: cell.delay(fo4),
+                    leakage: cell.leakage,
+                    internal_energy: cell.output_energy(fo4),
+                },
+            );
+            let inputs: Vec<&'static str> =
+                perm.iter().map(|&i| leak(&cell.inputs[i].name)).collect();
+            netlist_cells.insert(
+                kind,
+                NetlistCell {
+                    name: leak(&cell.name),
+                    inputs: Box::leak(inputs.into_boxed_slice()),
+                    output: leak(&cell.outputs[0].name),
+                },
+            );
+        }
+        resolve_substitutes(&mut infos);
+        if let Some(kind) = CellKind::ALL.iter().find(|k| !infos.contains_key(k)) {
+            return Err(error(&format!(
+                "cannot cost `{}`: the library needs an inverter and a 2-input NAND, NOR, AND or OR",
+                kind
+            )));
+        }
+
+        let ffs = FfCandidates::new(&cells);
+        let Some((clear_cell, clear)) = ffs.clear() else {
+            return Err(error(
+                "no positive-edge D flip-flop with an asynchronous clear",
+            ));
+        };
+        let netlist_ffs = NetlistFfs {
+            plain: ffs.plain.map(|(_, ff)| ff),
+            clear,
+            set: ffs.set().map(|(_, ff)| ff),
+        };
+
+        let name = names.join("+");
+        let banner = leak(&format!(
+            "{} (Liberty, {} cells, FO4 at fastest characterized slew)",
+            name,
+            cells.len()
+        ));
+        Ok(LibertyLibrary {
+            name,
+            banner,
+            infos,
+            cells: netlist_cells,
+            ffs: netlist_ffs,
+            ff_setup: clear_cell.setup(),
+            ff_area: clear_cell.area,
+            ff_leakage: clear_cell.leakage,
+            ff_internal_energy: clear_cell.total_energy(fo4),
+        })
+    }
+
+    /// `library` group name(s), joined with `+` when several files are
+    /// merged.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl CellLibrary for LibertyLibrary {
+    fn banner(&self) -> &'static str {
+        self.banner
+    }
+
+    fn info(&self, kind: CellKind) -> CellInfo {
+        self.infos[&kind]
+    }
+
+    fn netlist_cell(&self, kind: CellKind) -> Option<NetlistCell> {
+        self.cells.get(&kind).copied()
+    }
+
+    fn netlist_ffs(&self) -> NetlistFfs {
+        self.ffs
+    }
+
+    fn ff_setup(&self) -> f64 {
+        self.ff_setup
+    }
+
+    fn ff_area(&self) -> f64 {
+        self.ff_area
+    }
+
+    fn ff_leakage(&self) -> f64 {
+        self.ff_leakage
+    }
+
+    fn ff_internal_energy(&self) -> f64 {
+        self.ff_internal_energy
+    }
+}
+
+/// [`CellLibrary`] hands out `&'static str`s; a loaded library lives for
+/// the rest of the process anyway.
+fn leak(s: &str) -> &'static str {
+    Box::leak(s.to_string().into_boxed_str())
+}
+
+/// Input order of a cell realizing `kind`: `perm[j]` is the cell input
+/// driven by the kind's `j`-th input.
+fn permutation_for(kind: CellKind, tt: u16, n: usize) -> Option<Vec<usize>> {
+    permutations(n).into_iter().find(|perm| {
+        (0..1usize << n).all(|m| {
+            let x: Vec<bool> = (0..n).map(|j| (m >> j) & 1 == 1).collect();
+            let cell_m: usize = (0..n).map(|j| (x[j] as usize) << perm[j]).sum();
+            kind.eval(&x) == ((tt >> cell_m) & 1 == 1)
+        })
+    })
+}
+
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    let mut ret = Vec::new();
+    for perm in permutations(n - 1) {
+        for pos in 0..n {
+            let mut p = perm.clone();
+            p.insert(pos, n - 1);
+            ret.push(p);
+        }
+    }
+    ret
+}
+
+/// Stand-ins for a kind the library has no cell for, tried in order. Each
+/// is a sequence of logic levels; the kinds within a level work in
+/// parallel, so delay sums the slowest of each level.
+fn substitutes(kind: CellKind) -> &'static [&'static [&'static [CellKind]]] {
+    use CellKind::*;
+    match kind {
+        Buf => &[],
+        Not => &[&[&[Nand2]], &[&[Nor2]]],
+        And2 => &[&[&[Nand2], &[Not]], &[&[Not, Not], &[Nor2]]],
+        Or2 => &[&[&[Nor2], &[Not]], &[&[Not, Not], &[Nand2]]],
+        Nand2 => &[&[&[And2], &[Not]], &[&[Not, Not], &[Or2]]],
+        Nor2 => &[&[&[Or2], &[Not]], &[&[Not, Not], &[And2]]],
+        Xor2 => &[&[&[Nand2], &[Nand2, Nand2], &[Nand2]]],
+        Xnor2 => &[&[&[Xor2], &[Not]]],
+        And3 => &[&[&[And2], &[And2]]],
+        Or3 => &[&[&[Or2], &[Or2]]],
+        Nand3 => &[&[&[And3], &[Not]]],
+        Nor3 => &[&[&[Or3], &[Not]]],
+        Ao21 => &[&[&[And2], &[Or2]]],
+        Aoi21 => &[&[&[Ao21], &[Not]]],
+        Oa21 => &[&[&[Or2], &[And2]]],
+        Oai21 => &[&[&[Oa21], &[Not]]],
+        Ao31 => &[&[&[And3], &[Or2]]],
+        Aoi31 => &[&[&[Ao31], &[Not]]],
+        Ao22 => &[&[&[And2, And2], &[Or2]]],
+        Aoi22 => &[&[&[Ao22], &[Not]]],
+        Oai22 => &[&[&[Or2, Or2], &[Nand2]]],
+        Mux2 => &[&[&[Not], &[Ao22]]],
+    }
+}
+
+/// Fill in every kind reachable through [`substitutes`] from the matched
+/// ones.
+fn resolve_substitutes(infos: &mut HashMap<CellKind, CellInfo>) {
+    loop {
+        let mut changed = false;
+        for kind in CellKind::ALL {
+            if infos.contains_key(&kind) {
+                continue;
+            }
+            for levels in substitutes(kind) {
+                let parts = levels.iter().flat_map(|level| level.iter());
+                if !parts.clone().all(|k| infos.contains_key(k)) {
+                    continue;
+                }
+                let mut info = CellInfo {
+                    area: 0.0,
+                    delay: 0.0,
+                    leakage: 0.0,
+                    internal_energy: 0.0,
+                };
+                for k in parts {
+                    info.area += infos[k].area;
+                    info.leakage += infos[k].leakage;
+                    info.internal_energy += infos[k].internal_energy;
+                }
+                for level in levels.iter() {
+                    info.delay += level.iter().map(|k| infos[k].delay).fold(0.0_f64, f64::max);
+                }
+                infos.insert(kind, info);
+                changed = true;
+                break;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+struct FfCandidates<'a> {
+    plain: Option<(&'a LibCell, NetlistFf)>,
+    clear: Option<(&'a LibCell, NetlistFf)>,
+    set: Option<(&'a LibCell, NetlistFf)>,
+    /// Both clear and preset, as `(cell, ff, clear pin, preset pin)`.
+    both: Option<(&'a LibCell, NetlistFf, NetlistResetPin, NetlistResetPin)>,
+}
+
+impl<'a> FfCandidates<'a> {
+    fn new(cells: &'a [LibCell]) -> Self {
+        let mut ret = FfCandidates {
+            plain: None,
+            clear: None,
+            set: None,
+            both: None,
+        };
+        fn keep<'a, T>(slot: &mut Option<(&'a LibCell, T)>, cell: &'a LibCell, value: T) {
+            if slot.as_ref().is_none_or(|(best, _)| cell.area < best.area) {
+                *slot = Some((cell, value));
+            }
+        }
+        for cell in cells {
+            let Some((ff, clear, preset)) = cell.d_flip_flop() else {
+                continue;
+            };
+            match (clear, preset) {
+                (None, None) => keep(&mut ret.plain, cell, ff),
+                (Some(c), None) => keep(
+                    &mut ret.clear,
+                    cell,
+                    NetlistFf {
+                        reset: Some(c),
+                        ..ff
+                    },
+                ),
+                (None, Some(p)) => keep(
+                    &mut ret.set,
+                    cell,
+                    NetlistFf {
+                        reset: Some(p),
+                        ..ff
+                    },
+                ),
+                (Some(c), Some(p)) => {
+                    if ret
+                        .both
+                        .as_ref()
+                        .is_none_or(|(best, ..)| cell.area < best.area)
+                    {
+                        ret.both = Some((cell, ff, c, p));
+                    }
+                }
+            }
+        }
+        ret
+    }
+
+    fn clear(&self) -> Option<(&'a LibCell, NetlistFf)> {
+        self.clear.or_else(|| {
+            self.both
+                .map(|(cell, ff, clear, preset)| (cell, tied(ff, clear, preset)))
+        })
+    }
+
+    fn set(&self) -> Option<(&'a LibCell, NetlistFf)> {
+        self.set.or_else(|| {
+            self.both
+                .map(|(cell, ff, clear, preset)| (cell, tied(ff, preset, clear)))
+        })
+    }
+}
+
+/// A set/reset flip-flop used through `reset`, with `unused` held inactive.
+fn tied(ff: NetlistFf, reset: NetlistResetPin, unused: NetlistResetPin) -> NetlistFf {
+    NetlistFf {
+        reset: Some(reset),
+        ties: Box::leak(vec![(unused.name, unused.active_low)].into_boxed_slice()),
+        ..ff
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Cells
+
+struct LibCell {
+    name: String,
+    area: f64,
+    /// nW.
+    leakage: f64,
+    inputs: Vec<Pin>,
+    outputs: Vec<Pin>,
+    ff: Option<FfGroup>,
+    /// Latch, state table or bus pins: never used.
+    unsupported: bool,
+}
+
+#[derive(Default)]
+struct Pin {
+    name: String,
+    /// pF.
+    capacitance: f64,
+    function: Option<String>,
+    /// Delay tables (ns) per timing arc.
+    arcs: Vec<Vec<Table>>,
+    /// `setup_rising` constraint tables (ns).
+    setup: Vec<Table>,
+    /// Internal energy tables (pJ).
+    power: Vec<Table>,
+}
+
+struct FfGroup {
+    iq: String,
+    iqn: Option<String>,
+    next_state: Option<String>,
+    clocked_on: Option<String>,
+    clear: Option<String>,
+    preset: Option<String>,
+}
+
+impl LibCell {
+    /// Output truth table over the inputs (bit `m` is the output when input
+    /// `i` is bit `i` of `m`), for a usable combinational cell.
+    fn truth_table(&self) -> Option<u16> {
+        if self.unsupported || self.ff.is_some() || self.outputs.len() != 1 {
+            return None;
+        }
+        let n = self.inputs.len();
+        if n == 0 || n > 4 {
+            return None;
+        }
+        let expr = Expr::parse(self.outputs[0].function.as_ref()?)?;
+        let names: Vec<&str> = self.inputs.iter().map(|p| p.name.as_str()).collect();
+        let mut tt = 0u16;
+        for m in 0..1usize << n {
+            let values: Vec<bool> = (0..n).map(|i| (m >> i) & 1 == 1).collect();
+            if expr.eval(&names, &values)? {
+                tt |= 1 << m;
+            }
+        }
+        Some(tt)
+    }
+
+    fn mean_input_capacitance(&self) -> f64 {
+        let sum: f64 = self.inputs.iter().map(|p| p.capacitance).sum();
+        sum / self.inputs.len().max(1) as f64
+    }
+
+    /// Worst arc delay into `load` (pF), rise/fall averaged.
+    fn delay(&self, load: f64) -> f64 {
+        self.outputs
+            .iter()
+            .flat_map(|p| &p.arcs)
+            .map(|arc| mean(arc.iter().map(|t| t.lookup(load))))
+            .fold(0.0, f64::max)
+    }
+
+    /// Energy per output transition: the output pins' internal power.
+    fn output_energy(&self, load: f64) -> f64 {
+        mean(
+            self.outputs
+                .iter()
+                .flat_map(|p| &p.power)
+                .map(|t| t.lookup(load)),
+        )
+    }
+
+    /// Energy per clock cycle of a flip-flop: every pin's internal power
+    /// (clock, data and output), each averaged over its conditions.
+    fn total_energy(&self, load: f64) -> f64 {
+        self.inputs
+            .iter()
+            .chain(&self.outputs)
+            .map(|p| mean(p.power.iter().map(|t| t.lookup(load))))
+            .sum()
+    }
+
+    fn setup(&self) -> f64 {
+        self.inputs
+            .iter()
+            .flat_map(|p| &p.setup)
+            .map(|t| t.lookup(0.0))
+            .fold(0.0, f64::max)
+    }
+
+    /// A positive-edge D flip-flop with nothing but clock, data and
+    /// optional async clear / preset pins, as `(ff, clear, preset)`.
+    fn d_flip_flop(&self) -> Option<(NetlistFf, Option<NetlistResetPin>, Option<NetlistResetPin>)> {
+        if self.unsupported {
+            return None;
+        }
+        let ff = self.ff.as_ref()?;
+        let d = Expr::parse(ff.next_state.as_ref()?)?.pin()?.to_string();
+        let clock = Expr::parse(ff.clocked_on.as_ref()?)?.pin()?.to_string();
+        let reset_pin = |expr: &Option<String>| -> Option<Option<NetlistResetPin>> {
+            match expr {
+                None => Some(None),
+                Some(e) => {
+                    let expr = Expr::parse(e)?;
+                    let (name, active_low) = expr.literal()?;
+                    Some(Some(NetlistResetPin {
+                        name: leak(name),
+                        active_low,
+                    }))
+                }
+            }
+        };
+        let clear = reset_pin(&ff.clear)?;
+        let preset = reset_pin(&ff.preset)?;
+
+        // Scan, enable and other extra inputs make it something else.
+        let known = |name: &str| {
+            name == d
+                || name == clock
+                || clear.is_some_and(|c| c.name == name)
+                || preset.is_some_and(|p| p.name == name)
+        };
+        if !self.inputs.iter().all(|p| known(&p.name)) {
+            return None;
+        }
+
+        let mut q = None;
+        for pin in &self.outputs {
+            let Some(expr) = pin.function.as_ref().and_then(|f| Expr::parse(f)) else {
+                continue;
+            };
+            let inverted = match expr.literal() {
+                Some((name, false)) if name == ff.iq => false,
+                Some((name, true)) if name == ff.iq => true,
+                Some((name, false)) if Some(name) == ff.iqn.as_deref() => true,
+                _ => continue,
+            };
+            if q.is_none_or(|(_, inv)| inv && !inverted) {
+                q = Some((pin.name.as_str(), inverted));
+            }
+        }
+        let (q, q_inverted) = q?;
+
+        let ff = NetlistFf {
+            name: leak(&self.name),
+            clock: leak(&clock),
+            d: leak(&d),
+            q: leak(q),
+            q_inverted,
+            reset: None,
+            ties: &[],
+        };
+        Some((ff, clear, preset))
+    }
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, n) = values.fold((0.0, 0usize), |(s, n), v| (s + v, n + 1));
+    if n == 0 { 0.0 } else { sum / n as f64 }
+}
+
+/// Per-file unit scale factors to ns / pF / nW / pJ.
+struct Units {
+    time: f64,
+    capacitance: f64,
+    leakage: f64,
+    energy: f64,
+}
+
+impl Units {
+    fn new(library: &Group) -> Self {
+        let time = library
+            .attr("time_unit")
+            .and_then(|s| scaled(s, &[("ns", 1.0), ("ps", 1e-3), ("us", 1e3)]))
+            .unwrap_or(1.0);
+        let capacitance = library
+            .complex("capacitive_load_unit")
+            .and_then(|v| {
+                let x: f64 = v.first()?.parse().ok()?;
+                let unit = match v.get(1)?.to_ascii_lowercase().as_str() {
+                    "pf" => 1.0,
+                    "ff" => 1e-3,
+                    "nf" => 1e3,
+                    _ => return None,
+                };
+                Some(x * unit)
+            })
+            .unwrap_or(1.0);
+        let leakage = library
+            .attr("leakage_power_unit")
+            .and_then(|s| {
+                scaled(
+                    s,
+                    &[
+                        ("pw", 1e-3),
+                        ("nw", 1.0),
+                        ("uw", 1e3),
+                        ("mw", 1e6),
+                        ("w", 1e9),
+                    ],
+                )
+            })
+            .unwrap_or(1.0);
+        let voltage = library
+            .attr("voltage_unit")
+            .and_then(|s| scaled(s, &[("mv", 1e-3), ("v", 1.0)]))
+            .unwrap_or(1.0);
+        // Internal power tables are in capacitive_load_unit × voltage_unit².
+        Units {
+            time,
+            capacitance,
+            leakage,
+            energy: capacitance * voltage * voltage,
+        }
+    }
+}
+
+/// `"10ps"` against `[("ps", 1e-3), ..]` → `0.01`.
+fn scaled(s: &str, units: &[(&str, f64)]) -> Option<f64> {
+    let s = s.trim().to_ascii_lowercase();
+    let split = s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number: f64 = if number.trim().is_empty() {
+        1.0
+    } else {
+        number.trim().parse().ok()?
+    };
+    let (_, factor) = units.iter().find(|(u, _)| *u == unit.trim())?;
+    Some(number * factor)
+}
+
+fn read_library(library: &Group, cells: &mut Vec<LibCell>) {
+    let units = Units::new(library);
+    let mut templates = HashMap::new();
+    for group in &library.groups {
+        if group.kind.ends_with("_template") {
+            templates.insert(group.name().to_string(), group);
+        }
+    }
+    for group in library.groups.iter().filter(|g| g.kind == "cell") {
+        if group.attr("dont_use") == Some("true") {
+            continue;
+        }
+        if let Some(cell) = read_cell(group, &units, &templates) {
+            cells.push(cell);
+        }
+    }
+}
+
+fn read_cell(group: &Group, units: &Units, templates: &HashMap<String, &Group>) -> Option<LibCell> {
+    let area = group.attr("area").and_then(|s| s.parse::<f64>().ok())?;
+    let leakage = match group.attr("cell_leakage_power") {
+        Some(s) => s.parse::<f64>().ok()?,
+        None => mean(
+            group
+                .groups
+                .iter()
+                .filter(|g| g.kind == "leakage_power")
+                .filter_map(|g| g.attr("value")?.parse::<f64>().ok()),
+        ),
+    } * units.leakage;
+
+    let mut cell = LibCell {
+        name: group.name().to_string(),
+        area,
+        leakage,
+        inputs: Vec::new(),
+        outputs: Vec::new(),
+        ff: None,
+        unsupported: false,
+    };
+    for sub in &group.groups {
+        match sub.kind.as_str() {
+            "pin" => {
+                for name in &sub.names {
+                    let pin = read_pin(name, sub, units, templates);
+                    match sub.attr("direction") {
+                        Some("input") => cell.inputs.push(pin),
+                        Some("output") => cell.outputs.push(pin),
+                        // Internal pins carry no connectivity.
+                        Some("internal") => (),
+                        _ => cell.unsupported = true,
+                    }
+                }
+            }
+            "ff" => {
+                cell.ff = Some(FfGroup {
+                    iq: sub.names.first().cloned().unwrap_or_default(),
+                    iqn: sub.names.get(1).cloned(),
+                    next_state: sub.attr("next_state").map(str::to_string),
+                    clocked_on: sub.attr("clocked_on").map(str::to_string),
+                    clear: sub.attr("clear").map(str::to_string),
+                    preset: sub.attr("preset").map(str::to_string),
+                });
+            }
+            "latch" | "ff_bank" | "latch_bank" | "statetable" | "bus" | "bundle" => {
+                cell.unsupported = true;
+            }
+            _ => (),
+        }
+    }
+    Some(cell)
+}
+
+fn read_pin(name: &str, group: &Group, units: &Units, templates: &HashMap<String, &Group>) -> Pin {
+    let number = |attr: &str| group.attr(attr).and_then(|s| s.parse::<f64>().ok());
+    let capacitance = number("capacitance")
+        .or_else(|| Some((number("rise_capacitance")? + number("fall_capacitance")?) / 2.0))
+        .unwrap_or(0.0)
+        * units.capacitance;
+
+    let mut pin = Pin {
+        name: name.to_string(),
+        capacitance,
+        function: group.attr("function").map(str::to_string),
+        ..Default::default()
+    };
+    for sub in &group.groups {
+        let tables = |kinds: &[&str], scale: f64| -> Vec<Table> {
+            sub.groups
+                .iter()
+                .filter(|g| kinds.contains(&g.kind.as_str()))
+                .filter_map(|g| Table::read(g, templates, units, scale))
+                .collect()
+        };
+        match sub.kind.as_str() {
+            "timing" => {
+                let timing_type = sub.attr("timing_type").unwrap_or("combinational");
+                if timing_type == "setup_rising" {
+                    pin.setup
+                        .extend(tables(&["rise_constraint", "fall_constraint"], units.time));
+                } else if !is_constraint(timing_type) {
+                    let arc = tables(&["cell_rise", "cell_fall"], units.time);
+                    if !arc.is_empty() {
+                        pin.arcs.push(arc);
+                    }
+                }
+            }
+            "internal_power" => {
+                pin.power
+                    .extend(tables(&["rise_power", "fall_power", "power"], units.energy));
+            }
+            _ => (),
+        }
+    }
+    pin
+}
+
+fn is_constraint(timing_type: &str) -> bool {
+    [
+        "setup",
+        "hold",
+        "recovery",
+        "removal",
+        "skew",
+        "non_seq",
+        "nochange",
+        "min_pulse",
+        "minimum_period",
+        "max_clock_tree",
+    ]
+    .iter()
+    .any(|prefix| timing_type.starts_with(prefix))
+}
+
+// ---------------------------------------------------------------------------
+// NLDM tables
+
+/// A lookup table, values scaled to ns / pJ and load axes to pF.
+#[derive(Debug)]
+struct Table {
+    axes: Vec<Axis>,
+    /// Row-major over `axes`.
+    values: Vec<f64>,
+}
+
+#[derive(Debug)]
+struct Axis {
+    is_load: bool,
+    index: Vec<f64>,
+}
+
+impl Table {
+    fn read(
+        group: &Group,
+        templates: &HashMap<String, &Group>,
+        units: &Units,
+        scale: f64,
+    ) -> Option<Table> {
+        let template = templates.get(group.name());
+        let mut axes = Vec::new();
+        for i in 1..=3 {
+            let key = format!("index_{}", i);
+            let Some(index) = group
+                .complex(&key)
+                .or_else(|| template.and_then(|t| t.complex(&key)))
+            else {
+                break;
+            };
+            let variable = template
+                .and_then(|t| t.attr(&format!("variable_{}", i)))
+                .unwrap_or("");
+            let is_load = variable.contains("capacitance");
+            let mut index = numbers(index)?;
+            if is_load {
+                index.iter_mut().for_each(|x| *x *= units.capacitance);
+            }
+            axes.push(Axis { is_load, index });
+        }
+        let mut values = numbers(group.complex("values")?)?;
+        values.iter_mut().for_each(|x| *x *= scale);
+        let expected: usize = axes.iter().map(|a| a.index.len()).product();
+        (values.len() == expected).then_some(Table { axes, values })
+    }
+
+    /// Multilinear interpolation at `load` (pF) and the smallest index of
+    /// every other axis (i.e. the fastest slew).
+    fn lookup(&self, load: f64) -> f64 {
+        let brackets: Vec<(usize, usize, f64)> = self
+            .axes
+            .iter()
+            .map(|a| {
+                if a.is_load {
+                    a.bracket(load)
+                } else {
+                    (0, 0, 0.0)
+                }
+            })
+            .collect();
+        let mut ret = 0.0;
+        for corner in 0..1usize << brackets.len() {
+            let mut weight = 1.0;
+            let mut offset = 0;
+            for (k, &(lo, hi, t)) in brackets.iter().enumerate() {
+                let (i, w) = if (corner >> k) & 1 == 1 {
+                    (hi, t)
+                } else {
+                    (lo, 1.0 - t)
+                };
+                weight *= w;
+                offset = offset * self.axes[k].index.len() + i;
+            }
+            if weight != 0.0 {
+                ret += weight * self.values[offset];
+            }
+        }
+        ret
+    }
+}
+
+impl Axis {
+    /// Segment around `x` and the position within it; extrapolates past
+    /// either end.
+    fn bracket(&self, x: f64) -> (usize, usize, f64) {
+        if self.index.len() < 2 {
+            return (0, 0, 0.0);
+        }
+        let last = self.index.len() - 2;
+        let lo = self.index[..=last]
+            .iter()
+            .rposition(|&v| v <= x)
+            .unwrap_or(0);
+        let span = self.index[lo + 1] - self.index[lo];
+        let t = if span == 0.0 {
+            0.0
+        } else {
+            (x - self.index[lo]) / span
+        };
+        (lo, lo + 1, t)
+    }
+}
+
+/// `("0.1, 0.2", "0.3, 0.4")` → `[0.1, 0.2, 0.3, 0.4]`.
+fn numbers(values: &[String]) -> Option<Vec<f64>> {
+    values
+        .iter()
+        .flat_map(|v| v.split([',', ' ', '\t']))
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().ok())
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Boolean functions
+
+/// A Liberty `function` / `next_state` / `clear` expression.
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Const(bool),
+    Pin(String),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Xor(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Precedence, tightest first: `!` / postfix `'`, `^`, `&` / `*` /
+    /// juxtaposition, `|` / `+`.
+    fn parse(s: &str) -> Option<Expr> {
+        let mut tokens = Vec::new();
+        let mut chars = s.chars().peekable();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+            } else if "!'&*|+^()".contains(c) {
+                tokens.push(c.to_string());
+                chars.next();
+            } else {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "!'&*|+^()".contains(c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(word);
+            }
+        }
+        let mut parser = ExprParser { tokens, pos: 0 };
+        let expr = parser.or()?;
+        (parser.pos == parser.tokens.len()).then_some(expr)
+    }
+
+    fn eval(&self, names: &[&str], values: &[bool]) -> Option<bool> {
+        Some(match self {
+            Expr::Const(v) => *v,
+            Expr::Pin(name) => values[names.iter().position(|n| *n == name.as_str())?],
+            Expr::Not(a) => !a.eval(names, values)?,
+            Expr::And(a, b) => a.eval(names, values)? & b.eval(names, values)?,
+            Expr::Or(a, b) => a.eval(names, values)? | b.eval(names, values)?,
+            Expr::Xor(a, b) => a.eval(names, values)? ^ b.eval(names, values)?,
+        })
+    }
+
+    fn pin(&self) -> Option<&str> {
+        match self {
+            Expr::Pin(name) => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
+    /// `A` → `(A, false)`, `!A` → `(A, true)`.
+    fn literal(&self) -> Option<(&str, bool)> {
+        match self {
+            Expr::Pin(name) => Some((name.as_str(), false)),
+            Expr::Not(a) => a.pin().map(|name| (name, true)),
+            _ => None,
+        }
+    }
+}
+
+struct ExprParser {
+    tokens: Vec<String>,
+    pos: usize,
+}
+
+impl ExprParser {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> Option<Expr> {
+        let mut lhs = self.and()?;
+        while self.eat("|") || self.eat("+") {
+            lhs = Expr::Or(Box::new(lhs), Box::new(self.and()?));
+        }
+        Some(lhs)
+    }
+
+    fn and(&mut self) -> Option<Expr> {
+        let mut lhs = self.xor()?;
+        loop {
+            let explicit = self.eat("&") || self.eat("*");
+            let implicit =
+                matches!(self.peek(), Some(t) if t == "!" || t == "(" || !is_operator(t));
+            if !explicit && !implicit {
+                return Some(lhs);
+            }
+            lhs = Expr::And(Box::new(lhs), Box::new(self.xor()?));
+        }
+    }
+
+    fn xor(&mut self) -> Option<Expr> {
+        let mut lhs = self.unary()?;
+        while self.eat("^") {
+            lhs = Expr::Xor(Box::new(lhs), Box::new(self.unary()?));
+        }
+        Some(lhs)
+    }
+
+    fn unary(&mut self) -> Option<Expr> {
+        if self.eat("!") {
+            return Some(Expr::Not(Box::new(self.unary()?)));
+        }
+        let mut expr = if self.eat("(") {
+            let inner = self.or()?;
+            self.eat(")").then_some(inner)?
+        } else {
+            let token = self.peek()?.to_string();
+            if is_operator(&token) {
+                return None;
+            }
+            self.pos += 1;
+            match token.as_str() {
+                "0" => Expr::Const(false),
+                "1" => Expr::Const(true),
+                _ => Expr::Pin(token),
+            }
+        };
+        while self.eat("'") {
+            expr = Expr::Not(Box::new(expr));
+        }
+        Some(expr)
+    }
+}
+
+fn is_operator(token: &str) -> bool {
+    token.len() == 1 && "!'&*|+^()".contains(token)
+}
+
+// ---------------------------------------------------------------------------
+// Syntax
+
+/// `kind (names) { attrs; groups }`. Simple attributes (`a : v;`) hold one
+/// value, complex ones (`a (v1, v2);`) several.
+#[derive(Debug, Default)]
+struct Group {
+    kind: String,
+    names: Vec<String>,
+    attrs: Vec<(String, Vec<String>)>,
+    groups: Vec<Group>,
+}
+
+impl Group {
+    fn name(&self) -> &str {
+        self.names.first().map(String::as_str).unwrap_or("")
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.complex(name)?.first().map(String::as_str)
+    }
+
+    fn complex(&self, name: &str) -> Option<&[String]> {
+        self.attrs
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_slice())
+    }
+}
+
+#[derive(Debug)]
+struct ParseError {
+    line: usize,
+    message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Punct(char),
+    Word(String),
+}
+
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let mut ret = Vec::new();
+    let mut line = 1;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => (),
+            // Line continuation.
+            '\\' => (),
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let start = line;
+                let mut prev = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if prev == '*' => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            prev = c;
+                        }
+                        None => {
+                            return Err(ParseError {
+                                line: start,
+                                message: "unterminated comment".into(),
+                            });
+                        }
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.peek().is_some_and(|&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            '"' => {
+                let start = line;
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') if chars.peek() == Some(&'\n') => {
+                            chars.next();
+                            line += 1;
+                        }
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            s.push(c);
+                        }
+                        None => {
+                            return Err(ParseError {
+                                line: start,
+                                message: "unterminated string".into(),
+                            });
+                        }
+                    }
+                }
+                ret.push((Token::Word(s), start));
+            }
+            '(' | ')' | '{' | '}' | ':' | ';' | ',' => ret.push((Token::Punct(c), line)),
+            c => {
+                let mut s = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "(){}:;,\"".contains(c) {
+                        break;
+                    }
+                    s.push(c);
+                    chars.next();
+                }
+                ret.push((Token::Word(s), line));
+            }
+        }
+    }
+    Ok(ret)
+}
+
+fn parse_groups(text: &str) -> Result<Vec<Group>, ParseError> {
+    let tokens = tokenize(text)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let mut top = Group::default();
+    parser.body(&mut top, false)?;
+    Ok(top.groups)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map(|(_, line)| *line)
+            .unwrap_or(1)
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line(),
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Statements up to the closing `}` (or end of input at top level).
+    fn body(&mut self, group: &mut Group, nested: bool) -> Result<(), ParseError> {
+        loop {
+            let name = match self.peek() {
+                None if nested => return Err(self.error("missing `}`")),
+                None => return Ok(()),
+                Some(Token::Punct('}')) if nested => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                Some(Token::Punct(';')) => {
+                    self.pos += 1;
+                    continue;
+                }
+                Some(Token::Word(name)) => name.clone(),
+                Some(Token::Punct(c)) => return Err(self.error(format!("unexpected `{}`", c))),
+            };
+            self.pos += 1;
+            if self.eat(':') {
+                let line = self.line();
+                let mut words = Vec::new();
+                while let Some((Token::Word(w), l)) = self.tokens.get(self.pos) {
+                    if *l != line && !words.is_empty() {
+                        break;
+                    }
+                    words.push(w.clone());
+                    self.pos += 1;
+                }
+                self.eat(';');
+                group.attrs.push((name, vec![words.join(" ")]));
+            } else if self.eat('(') {
+                let args = self.args()?;
+                if self.eat('{') {
+                    let mut sub = Group {
+                        kind: name,
+                        names: args,
+                        ..Default::default()
+                    };
+                    self.body(&mut sub, true)?;
+                    group.groups.push(sub);
+                } else {
+                    self.eat(';');
+                    group.attrs.push((name, args));
+                }
+            } else {
+                return Err(self.error(format!("expected `:` or `(` after `{}`", name)));
+            }
+        }
+    }
+
+    /// Comma-separated values up to and including `)`.
+    fn args(&mut self) -> Result<Vec<String>, ParseError> {
+        let mut ret = Vec::new();
+        let mut current: Option<String> = None;
+        loop {
+            match self.peek().cloned() {
+                Some(Token::Punct(')')) => {
+                    self.pos += 1;
+                    ret.extend(current);
+                    return Ok(ret);
+                }
+                Some(Token::Punct(',')) => {
+                    self.pos += 1;
+                    ret.push(current.take().unwrap_or_default());
+                }
+                Some(Token::Word(w)) => {
+                    self.pos += 1;
+                    current = Some(match current {
+                        Some(c) => format!("{} {}", c, w),
+                        None => w,
+                    });
+                }
+                Some(Token::Punct(c)) => return Err(self.error(format!("unexpected `{}`", c))),
+                None => return Err(self.error("missing `)`")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_precedence() {
+        let names = ["A", "B", "C"];
+        let f = Expr::parse("!(A B) + C").unwrap();
+        let g = Expr::parse("A ^ B & C").unwrap();
+        for m in 0..8 {
+            let v = [m & 1 == 1, m & 2 == 2, m & 4 == 4];
+            assert_eq!(f.eval(&names, &v), Some(!(v[0] && v[1]) || v[2]));
+            assert_eq!(g.eval(&names, &v), Some((v[0] ^ v[1]) && v[2]));
+        }
+        assert_eq!(
+            Expr::parse("!RESET_B").unwrap().literal(),
+            Some(("RESET_B", true))
+        );
+        assert!(Expr::parse("A &").is_none());
+    }
+
+    #[test]
+    fn table_interpolates_on_load_at_fastest_slew() {
+        let text = r#"
+            library (t) {
+              lu_table_template (delay) {
+                variable_1 : input_net_transition;
+                variable_2 : total_output_net_capacitance;
+                index_1 ("0.01, 0.1");
+                index_2 ("0.001, 0.011");
+              }
+              cell (x) { pin (Y) { timing () {
+                cell_rise (delay) {
+                  values ("0.1, 0.2", \
+                          "0.5, 0.6");
+                }
+              } } }
+            }
+        "#;
+        let groups = parse_groups(text).unwrap();
+        let library = &groups[0];
+        let units = Units::new(library);
+        let templates: HashMap<String, &Group> = [("delay".to_string(), &library.groups[0])].into();
+        let timing = &library.groups[1].groups[0].groups[0];
+        let table = Table::read(&timing.groups[0], &templates, &units, 1.0).unwrap();
+        assert!((table.lookup(0.006) - 0.15).abs() < 1e-9);
+        assert!((table.lookup(0.021) - 0.3).abs() < 1e-9);
+    }
+}
@@ -4,7 +4,7 @@
 //! Hand-curated typical values rather than a single NLDM extraction point.
 //!
 //! Source: https://github.com/google/skywater-pdk (Apache 2.0).
-use super::{CellInfo, CellLibrary};
+use super::{CellInfo, CellLibrary, NetlistCell, NetlistFf, NetlistFfs, NetlistResetPin};
 use crate::ir::CellKind;
 
 pub struct Sky130;
@@ -174,6 +174,76 @@ impl CellLibrary for Sky130 {
         }
     }
 
+    fn netlist_cell(&self, kind: CellKind) -> Option<NetlistCell> {
+        let (name, inputs, output): (_, &[_], _) = match kind {
+            CellKind::Buf => return None,
+            CellKind::Not => ("sky130_fd_sc_hd__inv_1", &["A"], "Y"),
+            CellKind::And2 => ("sky130_fd_sc_hd__and2_1", &["A", "B"], "X"),
+            CellKind::Or2 => ("sky130_fd_sc_hd__or2_1", &["A", "B"], "X"),
+            CellKind::Nand2 => ("sky130_fd_sc_hd__nand2_1", &["A", "B"], "Y"),
+            CellKind::Nor2 => ("sky130_fd_sc_hd__nor2_1", &["A", "B"], "Y"),
+            CellKind::Xor2 => ("sky130_fd_sc_hd__xor2_1", &["A", "B"], "X"),
+            CellKind::Xnor2 => ("sky130_fd_sc_hd__xnor2_1", &["A", "B"], "Y"),
+            CellKind::And3 => ("sky130_fd_sc_hd__and3_1", &["A", "B", "C"], "X"),
+            CellKind::Or3 => ("sky130_fd_sc_hd__or3_1", &["A", "B", "C"], "X"),
+            CellKind::Nand3 => ("sky130_fd_sc_hd__nand3_1", &["A", "B", "C"], "Y"),
+            CellKind::Nor3 => ("sky130_fd_sc_hd__nor3_1", &["A", "B", "C"], "Y"),
+            CellKind::Ao21 => ("sky130_fd_sc_hd__a21o_1", &["A1", "A2", "B1"], "X"),
+            CellKind::Aoi21 => ("sky130_fd_sc_hd__a21oi_1", &["A1", "A2", "B1"], "Y"),
+            CellKind::Oa21 => ("sky130_fd_sc_hd__o21a_1", &["A1", "A2", "B1"], "X"),
+            CellKind::Oai21 => ("sky130_fd_sc_hd__o21ai_1", &["A1", "A2", "B1"], "Y"),
+            CellKind::Ao31 => ("sky130_fd_sc_hd__a31o_1", &["A1", "A2", "A3", "B1"], "X"),
+            CellKind::Aoi31 => ("sky130_fd_sc_hd__a31oi_1", &["A1", "A2", "A3", "B1"], "Y"),
+            CellKind::Ao22 => ("sky130_fd_sc_hd__a22o_1", &["A1", "A2", "B1", "B2"], "X"),
+            CellKind::Aoi22 => ("sky130_fd_sc_hd__a22oi_1", &["A1", "A2", "B1", "B2"], "Y"),
+            CellKind::Oai22 => ("sky130_fd_sc_hd__o22ai_1", &["A1", "A2", "B1", "B2"], "Y"),
+            CellKind::Mux2 => ("sky130_fd_sc_hd__mux2_1", &["S", "A0", "A1"], "X"),
+        };
+        Some(NetlistCell {
+            name,
+            inputs,
+            output,
+        })
+    }
+
+    fn netlist_ffs(&self) -> NetlistFfs {
+        NetlistFfs {
+            plain: Some(NetlistFf {
+                name: "sky130_fd_sc_hd__dfxtp_1",
+                clock: "CLK",
+                d: "D",
+                q: "Q",
+                q_inverted: false,
+                reset: None,
+                ties: &[],
+            }),
+            clear: NetlistFf {
+                name: "sky130_fd_sc_hd__dfrtp_1",
+                clock: "CLK",
+                d: "D",
+                q: "Q",
+                q_inverted: false,
+                reset: Some(NetlistResetPin {
+                    name: "RESET_B",
+                    active_low: true,
+                }),
+                ties: &[],
+            },
+            set: Some(NetlistFf {
+                name: "sky130_fd_sc_hd__dfstp_1",
+                clock: "CLK",
+                d: "D",
+                q: "Q",
+                q_inverted: false,
+                reset: Some(NetlistResetPin {
+                    name: "SET_B",
+                    active_low: true,
+                }),
+                ties: &[],
+            }),
+        }
+    }
+
     // sky130_fd_sc_hd__dfrtp_1 — async-reset positive-edge D-FF at tt_025C_1v80.
     fn ff_setup(&self) -> f64 {
         0.15
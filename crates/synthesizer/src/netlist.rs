@@ -0,0 +1,414 @@
+//! Structural Verilog writer for a techmapped [`GateModule`].
+//!
+//! Every cell becomes an instance of the library master reported by
+//! [`CellLibrary::netlist_cell`], every flip-flop an instance from
+//! [`CellLibrary::netlist_ffs`], and every inferred RAM a black-box macro
+//! declared at the end of the file. Nets are named `n<id>` after their
+//! [`NetId`]; ports are connected through plain `assign`s so the body never
+//! has to special-case feed-throughs or constant outputs.
+//!
+//! The result is meant for STA (OpenSTA / OpenROAD) and gate-level
+//! simulation against the RTL, not as a signoff netlist: cell choice and
+//! drive strength follow the estimation tables in [`crate::library`].
+
+use crate::ir::{
+    CellKind, ClockEdge, FfCell, GateModule, NET_CONST0, NET_CONST1, NetId, PortDir, RamBlock,
+    ResetPolarity,
+};
+use crate::library::{CellLibrary, NetlistFf};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+
+/// Render `module` as a gate-level structural Verilog netlist against
+/// `library`.
+pub fn write_verilog(module: &GateModule, library: &dyn CellLibrary) -> String {
+    let mut w = Writer::new(module, library);
+    w.emit();
+    w.finish()
+}
+
+struct Writer<'a> {
+    module: &'a GateModule,
+    library: &'a dyn CellLibrary,
+    body: String,
+    /// Nets referenced anywhere in `body`, declared as `wire`s.
+    wires: BTreeSet<NetId>,
+    /// Scratch nets for inverters / decompositions, numbered past the IR.
+    next_net: NetId,
+    /// Net name → its shared inverted copy.
+    inverted: HashMap<String, String>,
+    next_inst: usize,
+    /// RAM black-box signature → its module declaration.
+    rams: BTreeMap<String, String>,
+}
+
+impl<'a> Writer<'a> {
+    fn new(module: &'a GateModule, library: &'a dyn CellLibrary) -> Self {
+        Self {
+            module,
+            library,
+            body: String::new(),
+            wires: BTreeSet::new(),
+            next_net: module.nets.len() as NetId,
+            inverted: HashMap::new(),
+            next_inst: 0,
+            rams: BTreeMap::new(),
+        }
+    }
+
+    fn net(&mut self, id: NetId) -> String {
+        match id {
+            NET_CONST0 => "1'b0".into(),
+            NET_CONST1 => "1'b1".into(),
+            _ => {
+                self.wires.insert(id);
+                format!("n{}", id)
+            }
+        }
+    }
+
+    fn scratch(&mut self) -> String {
+        let id = self.next_net;
+        self.next_net += 1;
+        self.net(id)
+    }
+
+    fn inst_name(&mut self) -> String {
+        let name = format!("g{}", self.next_inst);
+        self.next_inst += 1;
+        name
+    }
+
+    /// `!net`, sharing one inverter per source net.
+    fn invert(&mut self, id: NetId) -> String {
+        match id {
+            NET_CONST0 => self.net(NET_CONST1),
+            NET_CONST1 => self.net(NET_CONST0),
+            _ => {
+                let input = self.net(id);
+                self.invert_name(input)
+            }
+        }
+    }
+
+    fn invert_name(&mut self, input: String) -> String {
+        if let Some(n) = self.inverted.get(&input) {
+            return n.clone();
+        }
+        let output = self.scratch();
+        self.cell(CellKind::Not, std::slice::from_ref(&input), &output);
+        self.inverted.insert(input, output.clone());
+        output
+    }
+
+    /// Instantiate `kind`, decomposing it when the library has no master.
+    fn cell(&mut self, kind: CellKind, inputs: &[String], output: &str) {
+        if let Some(master) = self.library.netlist_cell(kind) {
+            let inst = self.inst_name();
+            let pins: Vec<(&str, &str)> = master
+                .inputs
+                .iter()
+                .zip(inputs)
+                .map(|(pin, net)| (*pin, net.as_str()))
+                .chain(std::iter::once((master.output, output)))
+                .collect();
+            self.instance(master.name, &inst, &pins);
+            return;
+        }
+        match kind {
+            // `sel ? d1 : d0` == `(d1 & sel) | (d0 & !sel)`.
+            CellKind::Mux2 if self.library.netlist_cell(CellKind::Ao22).is_some() => {
+                let nsel = self.invert_name(inputs[0].clone());
+                let ao = [
+                    inputs[2].clone(),
+                    inputs[0].clone(),
+                    inputs[1].clone(),
+                    nsel,
+                ];
+                self.cell(CellKind::Ao22, &ao, output);
+            }
+            _ => {
+                let _ = writeln!(
+                    self.body,
+                    "    assign {} = {};",
+                    output,
+                    expression(kind, inputs)
+                );
+            }
+        }
+    }
+
+    fn instance(&mut self, master: &str, inst: &str, pins: &[(&str, &str)]) {
+        let conns: Vec<String> = pins
+            .iter()
+            .map(|(pin, net)| format!(".{}({})", pin, net))
+            .collect();
+        let _ = writeln!(self.body, "    {} {} ({});", master, inst, conns.join(", "));
+    }
+
+    fn emit(&mut self) {
+        let module = self.module;
+        for port in &module.ports {
+            for (i, &id) in port.nets.iter().enumerate() {
+                let bit = port.bit_name(i);
+                let net = self.net(id);
+                let _ = match port.dir {
+                    PortDir::Input | PortDir::Inout => {
+                        writeln!(self.body, "    assign {} = {};", net, bit)
+                    }
+                    PortDir::Output => writeln!(self.body, "    assign {} = {};", bit, net),
+                };
+            }
+        }
+
+        for cell in &module.cells {
+            let inputs: Vec<String> = cell.inputs.iter().map(|&n| self.net(n)).collect();
+            let output = self.net(cell.output);
+            self.cell(cell.kind, &inputs, &output);
+        }
+
+        for (i, ff) in module.ffs.iter().enumerate() {
+            self.flip_flop(i, ff);
+        }
+
+        for (i, ram) in module.ram_blocks.iter().enumerate() {
+            self.ram(i, ram);
+        }
+    }
+
+    fn flip_flop(&mut self, index: usize, ff: &FfCell) {
+        let ffs = self.library.netlist_ffs();
+        let clock = match ff.clock_edge {
+            ClockEdge::Posedge => self.net(ff.clock),
+            ClockEdge::Negedge => self.invert(ff.clock),
+        };
+        let mut d = self.net(ff.d);
+
+        // (master, async reset net + its polarity, store inverted state)
+        let (master, reset, invert_state) = match &ff.reset {
+            Some(r) if !r.sync => {
+                let reset = Some((r.net, r.polarity));
+                match (ff.reset_value, ffs.set) {
+                    (false, _) => (ffs.clear, reset, false),
+                    (true, Some(set)) => (set, reset, false),
+                    // Clear-only library: keep `!q` in a clear FF so reset yields 1.
+                    (true, None) => (ffs.clear, reset, true),
+                }
+            }
+            r => {
+                if let Some(r) = r {
+                    // Sync reset: fold the reset value into D ahead of the FF.
+                    let sel = self.net(r.net);
+                    let value = self.net(if ff.reset_value {
+                        NET_CONST1
+                    } else {
+                        NET_CONST0
+                    });
+                    let inputs = match r.polarity {
+                        ResetPolarity::ActiveHigh => [sel, d, value],
+                        ResetPolarity::ActiveLow => [sel, value, d],
+                    };
+                    let muxed = self.scratch();
+                    self.cell(CellKind::Mux2, &inputs, &muxed);
+                    d = muxed;
+                }
+                match ffs.plain {
+                    Some(plain) => (plain, None, false),
+                    None => (ffs.clear, None, false),
+                }
+            }
+        };
+
+        if invert_state {
+            d = self.invert_name(d);
+        }
+
+        let q = self.net(ff.q);
+        let q_pin = if master.q_inverted != invert_state {
+            let raw = self.scratch();
+            self.cell(CellKind::Not, std::slice::from_ref(&raw), &q);
+            raw
+        } else {
+            q
+        };
+
+        let reset_conn = self.reset_connection(&master, reset);
+        let mut pins: Vec<(&str, &str)> = vec![(master.clock, &clock), (master.d, &d)];
+        if let Some((pin, net)) = &reset_conn {
+            pins.push((pin, net));
+        }
+        let ties: Vec<(&str, &str)> = master
+            .ties
+            .iter()
+            .map(|&(pin, value)| (pin, if value { "1'b1" } else { "1'b0" }))
+            .collect();
+        pins.extend(ties);
+        pins.push((master.q, &q_pin));
+
+        self.instance(master.name, &format!("ff{}", index), &pins);
+    }
+
+    /// Connection for `master`'s reset pin: the IR reset net, inverted when
+    /// its polarity disagrees with the pin, or tied inactive when the FF has
+    /// no async reset but the master does.
+    fn reset_connection(
+        &mut self,
+        master: &NetlistFf,
+        reset: Option<(NetId, ResetPolarity)>,
+    ) -> Option<(&'static str, String)> {
+        let pin = master.reset?;
+        let net = match reset {
+            Some((net, polarity)) => {
+                if (polarity == ResetPolarity::ActiveLow) == pin.active_low {
+                    self.net(net)
+                } else {
+                    self.invert(net)
+                }
+            }
+            None => self.net(if pin.active_low {
+                NET_CONST1
+            } else {
+                NET_CONST0
+            }),
+        };
+        Some((pin.name, net))
+    }
+
+    fn ram(&mut self, index: usize, ram: &RamBlock) {
+        let mut signature = format!("veryl_ram_{}x{}", ram.depth, ram.width);
+        let mut decl_ports = vec!["input CLK".to_string()];
+        let mut conns: Vec<(String, String)> = Vec::new();
+
+        let clock = match ram.clock_edge {
+            ClockEdge::Posedge => self.net(ram.clock),
+            ClockEdge::Negedge => self.invert(ram.clock),
+        };
+        conns.push(("CLK".into(), clock));
+
+        for (i, wp) in ram.write_ports.iter().enumerate() {
+            signature.push_str(if wp.mask.is_some() { "_wm" } else { "_w" });
+            let addr = self.bus(&wp.addr);
+            let data = self.bus(&wp.data);
+            let enable = self.net(wp.enable);
+            decl_ports.push(format!("input {}W{}_ADDR", range(wp.addr.len()), i));
+            decl_ports.push(format!("input {}W{}_DATA", range(wp.data.len()), i));
+            decl_ports.push(format!("input W{}_EN", i));
+            conns.push((format!("W{}_ADDR", i), addr));
+            conns.push((format!("W{}_DATA", i), data));
+            conns.push((format!("W{}_EN", i), enable));
+            if let Some(mask) = &wp.mask {
+                let mask_bus = self.bus(mask);
+                decl_ports.push(format!("input {}W{}_MASK", range(mask.len()), i));
+                conns.push((format!("W{}_MASK", i), mask_bus));
+            }
+        }
+        for (i, rp) in ram.read_ports.iter().enumerate() {
+            signature.push_str(if rp.sync { "_rs" } else { "_ra" });
+            let addr = self.bus(&rp.addr);
+            let data = self.bus(&rp.data);
+            decl_ports.push(format!("input {}R{}_ADDR", range(rp.addr.len()), i));
+            decl_ports.push(format!("output {}R{}_DATA", range(rp.data.len()), i));
+            conns.push((format!("R{}_ADDR", i), addr));
+            conns.push((format!("R{}_DATA", i), data));
+        }
+
+        let inst = format!("ram{}", index);
+        let pins: Vec<(&str, &str)> = conns
+            .iter()
+            .map(|(pin, net)| (pin.as_str(), net.as_str()))
+            .collect();
+        self.instance(&signature, &inst, &pins);
+
+        self.rams.entry(signature.clone()).or_insert_with(|| {
+            let mut decl = String::new();
+            let _ = writeln!(decl, "(* blackbox *)");
+            let _ = writeln!(decl, "module {} (", signature);
+            let _ = writeln!(decl, "    {}", decl_ports.join(",\n    "));
+            let _ = writeln!(decl, ");");
+            let _ = writeln!(decl, "endmodule");
+            decl
+        });
+    }
+
+    /// `{msb, ..., lsb}` concatenation of LSB-first `nets`.
+    fn bus(&mut self, nets: &[NetId]) -> String {
+        if nets.len() == 1 {
+            return self.net(nets[0]);
+        }
+        let bits: Vec<String> = nets.iter().rev().map(|&n| self.net(n)).collect();
+        format!("{{{}}}", bits.join(", "))
+    }
+
+    fn finish(self) -> String {
+        let name = match self.module.name {
+            Some(n) => n.to_string(),
+            None => "unnamed".into(),
+        };
+
+        let mut ret = String::new();
+        let _ = writeln!(ret, "// Generated by `veryl synth`.");
+        let _ = writeln!(ret, "// Library: {}", self.library.banner());
+        let ports: Vec<String> = self
+            .module
+            .ports
+            .iter()
+            .map(|p| format!("{} {}{}", p.dir, range(p.nets.len()), p.flat_name()))
+            .collect();
+        if ports.is_empty() {
+            let _ = writeln!(ret, "module {};", name);
+        } else {
+            let _ = writeln!(ret, "module {} (", name);
+            let _ = writeln!(ret, "    {}", ports.join(",\n    "));
+            let _ = writeln!(ret, ");");
+        }
+        for id in &self.wires {
+            let _ = writeln!(ret, "    wire n{};", id);
+        }
+        if !self.wires.is_empty() {
+            ret.push('\n');
+        }
+        ret.push_str(&self.body);
+        let _ = writeln!(ret, "endmodule");
+        for decl in self.rams.values() {
+            ret.push('\n');
+            ret.push_str(decl);
+        }
+        ret
+    }
+}
+
+fn range(width: usize) -> String {
+    if width > 1 {
+        format!("[{}:0] ", width - 1)
+    } else {
+        String::new()
+    }
+}
+
+/// Continuous-assign fallback for a kind the library has no master for.
+fn expression(kind: CellKind, i: &[String]) -> String {
+    match kind {
+        CellKind::Buf => i[0].clone(),
+        CellKind::Not => format!("~{}", i[0]),
+        CellKind::And2 => format!("{} & {}", i[0], i[1]),
+        CellKind::Or2 => format!("{} | {}", i[0], i[1]),
+        CellKind::Nand2 => format!("~({} & {})", i[0], i[1]),
+        CellKind::Nor2 => format!("~({} | {})", i[0], i[1]),
+        CellKind::Xor2 => format!("{} ^ {}", i[0], i[1]),
+        CellKind::Xnor2 => format!("~({} ^ {})", i[0], i[1]),
+        CellKind::And3 => format!("{} & {} & {}", i[0], i[1], i[2]),
+        CellKind::Or3 => format!("{} | {} | {}", i[0], i[1], i[2]),
+        CellKind::Nand3 => format!("~({} & {} & {})", i[0], i[1], i[2]),
+        CellKind::Nor3 => format!("~({} | {} | {})", i[0], i[1], i[2]),
+        CellKind::Ao21 => format!("({} & {}) | {}", i[0], i[1], i[2]),
+        CellKind::Aoi21 => format!("~(({} & {}) | {})", i[0], i[1], i[2]),
+        CellKind::Oa21 => format!("({} | {}) & {}", i[0], i[1], i[2]),
+        CellKind::Oai21 => format!("~(({} | {}) & {})", i[0], i[1], i[2]),
+        CellKind::Ao31 => format!("({} & {} & {}) | {}", i[0], i[1], i[2], i[3]),
+        CellKind::Aoi31 => format!("~(({} & {} & {}) | {})", i[0], i[1], i[2], i[3]),
+        CellKind::Ao22 => format!("({} & {}) | ({} & {})", i[0], i[1], i[2], i[3]),
+        CellKind::Aoi22 => format!("~(({} & {}) | ({} & {}))", i[0], i[1], i[2], i[3]),
+        CellKind::Oai22 => format!("~(({} | {}) & ({} | {}))", i[0], i[1], i[2], i[3]),
+        CellKind::Mux2 => format!("{} ? {} : {}", i[0], i[2], i[1]),
+    }
+}
@@ -44,6 +44,10 @@ pub enum SynthesizerError {
         token_source: TokenSource,
     },
 
+    #[diagnostic(severity(Error), code(synth::liberty))]
+    #[error("{path}: {message}")]
+    Liberty { path: String, message: String },
+
     #[diagnostic(severity(Error), code(synth::internal))]
     #[error("internal error: {message}")]
     Internal { message: String },
@@ -173,6 +177,13 @@ impl SynthesizerError {
         }
     }
 
+    pub fn liberty(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Liberty {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
     pub fn internal(message: impl Into<String>) -> Self {
         Self::Internal {
             message: message.into(),
@@ -0,0 +1,586 @@
+//! Formal equivalence between a synthesized module and the module as first
+//! built from the RTL (`veryl synth --verify`).
+//!
+//! The check is combinational per register cut: flip-flop Q outputs, input
+//! ports and RAM read data are shared free inputs, and every compare point —
+//! an output bit, a flip-flop's D / clock / reset, a RAM input — must be the
+//! same function of them in both modules. Optimization never renumbers
+//! nets, so the two sides line up by net id. Both are lowered into one
+//! hash-consed AIG, which settles most points structurally; random
+//! simulation then looks for cheap counterexamples, and the remaining
+//! miters go to the built-in SAT solver ([`sat`]).
+//!
+//! Registers the optimizer dropped as hold-forever (`eliminate_dq_ffs`) are
+//! assumed to sit at their reset value, and each such assumption is itself
+//! a compare point (the register's next state must keep it), so the cut
+//! stays inductive.
+
+pub mod sat;
+
+use crate::aig::convert::lower_net;
+use crate::aig::graph::{AigEdge, AigModule, AigNode};
+use crate::ir::{
+    FfCell, GateModule, NET_CONST0, NET_CONST1, NetDriver, NetId, PortDir, ResetPolarity,
+};
+use sat::{Lit, SatResult, Solver};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Conflict budget per compare point before it is reported undecided.
+const CONFLICT_LIMIT: u64 = 200_000;
+/// 64-pattern random-simulation rounds run before any SAT call.
+const SIM_ROUNDS: usize = 16;
+
+#[derive(Clone, Debug, Default)]
+pub struct VerifyReport {
+    /// Compare points checked.
+    pub points: usize,
+    /// Points proved equivalent by structural hashing alone.
+    pub structural: usize,
+    /// Points that needed the SAT solver to prove.
+    pub proved_by_sat: usize,
+    pub mismatches: Vec<Mismatch>,
+    /// Points whose SAT call ran out of budget.
+    pub undecided: Vec<String>,
+}
+
+impl VerifyReport {
+    pub fn equivalent(&self) -> bool {
+        self.mismatches.is_empty() && self.undecided.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Mismatch {
+    /// The compare point, e.g. `output y[3]` or `register cnt[2] D`.
+    pub point: String,
+    pub kind: MismatchKind,
+}
+
+#[derive(Clone, Debug)]
+pub enum MismatchKind {
+    /// The point computes a different function; the counterexample assigns
+    /// the free inputs in its cone, sorted by name.
+    Functional {
+        counterexample: Vec<(String, bool)>,
+        reference: bool,
+        synthesized: bool,
+    },
+    /// The point has no counterpart, or its flip-flop changed clocking or
+    /// reset behaviour.
+    Structural(String),
+}
+
+impl fmt::Display for VerifyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.equivalent() {
+            writeln!(
+                f,
+                "verify: equivalent  ({} compare points: {} structural, {} by SAT)",
+                self.points, self.structural, self.proved_by_sat
+            )?;
+        } else {
+            writeln!(
+                f,
+                "verify: NOT equivalent  ({} of {} compare points differ, {} undecided)",
+                self.mismatches.len(),
+                self.points,
+                self.undecided.len()
+            )?;
+        }
+        for m in &self.mismatches {
+            match &m.kind {
+                MismatchKind::Functional {
+                    counterexample,
+                    reference,
+                    synthesized,
+                } => {
+                    writeln!(
+                        f,
+                        "  {}: reference {}, synthesized {}",
+                        m.point, *reference as u8, *synthesized as u8
+                    )?;
+                    let inputs: Vec<String> = counterexample
+                        .iter()
+                        .map(|(name, v)| format!("{}={}", name, *v as u8))
+                        .collect();
+                    if inputs.is_empty() {
+                        writeln!(f, "    with no free inputs")?;
+                    } else {
+                        writeln!(f, "    when {}", inputs.join(" "))?;
+                    }
+                }
+                MismatchKind::Structural(reason) => {
+                    writeln!(f, "  {}: {}", m.point, reason)?;
+                }
+            }
+        }
+        for point in &self.undecided {
+            writeln!(f, "  {}: undecided (conflict limit)", point)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks `synthesized` against `reference`, the same module and its child
+/// instances built without optimization (see
+/// `conv::convert_module_with_reference`).
+pub fn check_equivalence(reference: &GateModule, synthesized: &GateModule) -> VerifyReport {
+    let mut report = VerifyReport::default();
+    let mut aig = AigModule::new();
+    aig.net_edge.insert(NET_CONST0, AigEdge::CONST0);
+    aig.net_edge.insert(NET_CONST1, AigEdge::CONST1);
+
+    let names = InputNames::new(reference, synthesized);
+    // (point, reference edge, synthesized edge)
+    let mut pairs: Vec<(String, AigEdge, AigEdge)> = Vec::new();
+
+    let kept: HashSet<NetId> = synthesized.ffs.iter().map(|ff| ff.q).collect();
+    let ff_by_q: HashMap<NetId, usize> = synthesized
+        .ffs
+        .iter()
+        .enumerate()
+        .map(|(i, ff)| (ff.q, i))
+        .collect();
+
+    // Dropped registers: pin Q to the reset value before lowering anything
+    // that reads it.
+    let mut dropped = Vec::new();
+    for (i, ff) in reference.ffs.iter().enumerate() {
+        if kept.contains(&ff.q) {
+            continue;
+        }
+        let point = format!("register {}", names.ff(reference, i));
+        if ff.reset.is_none() {
+            report.mismatches.push(Mismatch {
+                point,
+                kind: MismatchKind::Structural("removed by synthesis".into()),
+            });
+            continue;
+        }
+        aig.net_edge.insert(ff.q, const_edge(ff.reset_value));
+        dropped.push(i);
+    }
+
+    // Reference side. Every port bit, inputs included, so the synthesized
+    // side can share the input nodes.
+    let mut ref_outputs = Vec::new();
+    for port in &reference.ports {
+        for &net in &port.nets {
+            ref_outputs.push(lower_net(&mut aig, reference, net));
+        }
+    }
+    let mut ref_ffs = Vec::new();
+    for ff in &reference.ffs {
+        ref_ffs.push(lower_ff(&mut aig, reference, ff));
+    }
+    let mut ref_ram = Vec::new();
+    reference.for_each_ram_input_net(|net| ref_ram.push(net));
+    let ref_ram: Vec<AigEdge> = ref_ram
+        .into_iter()
+        .map(|net| lower_net(&mut aig, reference, net))
+        .collect();
+
+    for &i in &dropped {
+        let point = format!("register {} (held at reset value)", names.ff(reference, i));
+        let (d, _, reset) = ref_ffs[i];
+        let ff = &reference.ffs[i];
+        let reset = reset.expect("dropped registers have a reset");
+        let next = aig.mk_mux(reset, d, const_edge(ff.reset_value));
+        pairs.push((point, next, const_edge(ff.reset_value)));
+    }
+
+    // The synthesized side shares only the free inputs: the cache keeps an
+    // entry just when it names the net's own input node.
+    aig.net_edge.retain(|&net, edge| {
+        net < crate::ir::RESERVED_NETS
+            || (!edge.is_negated()
+                && matches!(aig.nodes[edge.node() as usize], AigNode::Input { origin } if origin == net))
+    });
+
+    // Ports line up by position.
+    if reference.ports.len() != synthesized.ports.len()
+        || reference
+            .ports
+            .iter()
+            .zip(&synthesized.ports)
+            .any(|(a, b)| a.dir != b.dir || a.nets.len() != b.nets.len())
+    {
+        report.mismatches.push(Mismatch {
+            point: "ports".into(),
+            kind: MismatchKind::Structural("the port list changed".into()),
+        });
+        report.points = report.mismatches.len();
+        return report;
+    }
+    let port_bits = || {
+        reference
+            .ports
+            .iter()
+            .zip(&synthesized.ports)
+            .flat_map(|(a, b)| (0..b.nets.len()).map(move |bit| (a, b, bit)))
+            .zip(ref_outputs.iter().copied())
+    };
+    for ((_, port, bit), edge) in port_bits() {
+        if port.dir == PortDir::Input {
+            aig.net_edge.insert(port.nets[bit], edge);
+        }
+    }
+    for ((ref_port, port, bit), ref_edge) in port_bits() {
+        if port.dir != PortDir::Input {
+            let edge = lower_net(&mut aig, synthesized, port.nets[bit]);
+            pairs.push((
+                format!("{} {}", port.dir, ref_port.bit_name(bit)),
+                ref_edge,
+                edge,
+            ));
+        }
+    }
+
+    for (i, ff) in reference.ffs.iter().enumerate() {
+        let Some(&j) = ff_by_q.get(&ff.q) else {
+            continue;
+        };
+        let name = names.ff(reference, i);
+        let other = &synthesized.ffs[j];
+        if let Some(reason) = clocking_change(ff, other) {
+            report.mismatches.push(Mismatch {
+                point: format!("register {}", name),
+                kind: MismatchKind::Structural(reason),
+            });
+            continue;
+        }
+        let (d, clock, reset) = ref_ffs[i];
+        let (d2, clock2, reset2) = lower_ff(&mut aig, synthesized, other);
+        pairs.push((format!("register {} D", name), d, d2));
+        pairs.push((format!("register {} clock", name), clock, clock2));
+        if let (Some(r), Some(r2)) = (reset, reset2) {
+            pairs.push((format!("register {} reset", name), r, r2));
+        }
+    }
+    if let Some(ff) = synthesized
+        .ffs
+        .iter()
+        .find(|ff| !reference.ffs.iter().any(|r| r.q == ff.q))
+    {
+        report.mismatches.push(Mismatch {
+            point: format!("register {}", names.net(ff.q)),
+            kind: MismatchKind::Structural("not present before optimization".into()),
+        });
+    }
+
+    let mut ram = Vec::new();
+    synthesized.for_each_ram_input_net(|net| ram.push(net));
+    if ram.len() != ref_ram.len() {
+        report.mismatches.push(Mismatch {
+            point: "ram".into(),
+            kind: MismatchKind::Structural("the inferred RAM ports changed".into()),
+        });
+    } else {
+        for (i, net) in ram.into_iter().enumerate() {
+            let edge = lower_net(&mut aig, synthesized, net);
+            pairs.push((format!("ram input {}", i), ref_ram[i], edge));
+        }
+    }
+
+    report.points = pairs.len() + report.mismatches.len();
+    let open: Vec<&(String, AigEdge, AigEdge)> = pairs.iter().filter(|(_, a, b)| a != b).collect();
+    report.structural = pairs.len() - open.len();
+
+    let counterexamples = simulate(&aig, &open);
+    for (pair, cex) in open.iter().zip(counterexamples) {
+        let (point, a, b) = pair;
+        let cex = match cex {
+            Some(cex) => Some(cex),
+            None => match prove(&aig, *a, *b) {
+                SatResult::Unsat => {
+                    report.proved_by_sat += 1;
+                    continue;
+                }
+                SatResult::Unknown => {
+                    report.undecided.push(point.clone());
+                    continue;
+                }
+                SatResult::Sat => solve_counterexample(&aig, *a, *b),
+            },
+        };
+        let assignment = cex.expect("a satisfiable miter has a model");
+        let reference = eval(&aig, *a, &assignment);
+        let synthesized = eval(&aig, *b, &assignment);
+        let mut counterexample: Vec<(String, bool)> = support(&aig, &[*a, *b])
+            .into_iter()
+            .map(|node| {
+                let AigNode::Input { origin } = aig.nodes[node as usize] else {
+                    unreachable!("support holds input nodes")
+                };
+                (names.net(origin), assignment[&node])
+            })
+            .collect();
+        counterexample.sort();
+        report.mismatches.push(Mismatch {
+            point: point.clone(),
+            kind: MismatchKind::Functional {
+                counterexample,
+                reference,
+                synthesized,
+            },
+        });
+    }
+    report
+}
+
+pub(crate) fn const_edge(value: bool) -> AigEdge {
+    if value {
+        AigEdge::CONST1
+    } else {
+        AigEdge::CONST0
+    }
+}
+
+/// D, clock and active-high reset edges of `ff`.
+fn lower_ff(
+    aig: &mut AigModule,
+    gate: &GateModule,
+    ff: &FfCell,
+) -> (AigEdge, AigEdge, Option<AigEdge>) {
+    let d = lower_net(aig, gate, ff.d);
+    let clock = lower_net(aig, gate, ff.clock);
+    let reset = ff
+        .reset
+        .as_ref()
+        .map(|r| lower_net(aig, gate, r.net).negate_if(r.polarity == ResetPolarity::ActiveLow));
+    (d, clock, reset)
+}
+
+fn clocking_change(reference: &FfCell, synthesized: &FfCell) -> Option<String> {
+    if reference.clock_edge != synthesized.clock_edge {
+        return Some("clock edge changed".into());
+    }
+    match (&reference.reset, &synthesized.reset) {
+        (None, None) => None,
+        (Some(_), None) | (None, Some(_)) => Some("reset added or removed".into()),
+        (Some(a), Some(b)) if a.sync != b.sync => {
+            Some("reset changed between synchronous and asynchronous".into())
+        }
+        _ if reference.reset_value != synthesized.reset_value => Some("reset value changed".into()),
+        _ => None,
+    }
+}
+
+/// Free-input node → value.
+pub(crate) type Assignment = HashMap<u32, bool>;
+
+pub(crate) fn eval(aig: &AigModule, edge: AigEdge, inputs: &Assignment) -> bool {
+    let mut memo: HashMap<u32, bool> = HashMap::new();
+    let mut stack = vec![edge.node()];
+    while let Some(&node) = stack.last() {
+        if memo.contains_key(&node) {
+            stack.pop();
+            continue;
+        }
+        match &aig.nodes[node as usize] {
+            AigNode::Const => {
+                memo.insert(node, false);
+                stack.pop();
+            }
+            AigNode::Input { .. } => {
+                memo.insert(node, inputs.get(&node).copied().unwrap_or(false));
+                stack.pop();
+            }
+            AigNode::And { fanin0, fanin1 } => {
+                let a = memo.get(&fanin0.node()).copied();
+                let b = memo.get(&fanin1.node()).copied();
+                match (a, b) {
+                    (Some(a), Some(b)) => {
+                        let v = (a != fanin0.is_negated()) && (b != fanin1.is_negated());
+                        memo.insert(node, v);
+                        stack.pop();
+                    }
+                    _ => {
+                        stack.push(fanin0.node());
+                        stack.push(fanin1.node());
+                    }
+                }
+            }
+        }
+    }
+    memo[&edge.node()] != edge.is_negated()
+}
+
+/// Nodes in the transitive fan-in of `roots`, in ascending (topological)
+/// order.
+fn cone(aig: &AigModule, roots: &[AigEdge]) -> Vec<u32> {
+    let mut seen: HashSet<u32> = HashSet::new();
+    let mut stack: Vec<u32> = roots.iter().map(|e| e.node()).collect();
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node) {
+            continue;
+        }
+        if let AigNode::And { fanin0, fanin1 } = &aig.nodes[node as usize] {
+            stack.push(fanin0.node());
+            stack.push(fanin1.node());
+        }
+    }
+    let mut nodes: Vec<u32> = seen.into_iter().collect();
+    nodes.sort_unstable();
+    nodes
+}
+
+pub(crate) fn support(aig: &AigModule, roots: &[AigEdge]) -> Vec<u32> {
+    cone(aig, roots)
+        .into_iter()
+        .filter(|&n| matches!(aig.nodes[n as usize], AigNode::Input { .. }))
+        .collect()
+}
+
+/// Bit-parallel random simulation of the whole AIG. Returns, per pair, an
+/// input assignment that tells the two edges apart, if one turned up.
+fn simulate(aig: &AigModule, pairs: &[&(String, AigEdge, AigEdge)]) -> Vec<Option<Assignment>> {
+    let mut found: Vec<Option<Assignment>> = vec![None; pairs.len()];
+    if pairs.is_empty() {
+        return found;
+    }
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    let mut words = vec![0u64; aig.nodes.len()];
+    let word = |words: &[u64], e: AigEdge| {
+        let w = words[e.node() as usize];
+        if e.is_negated() { !w } else { w }
+    };
+    for _ in 0..SIM_ROUNDS {
+        for (i, node) in aig.nodes.iter().enumerate() {
+            words[i] = match node {
+                AigNode::Const => 0,
+                AigNode::Input { .. } => rng.next(),
+                AigNode::And { fanin0, fanin1 } => word(&words, *fanin0) & word(&words, *fanin1),
+            };
+        }
+        for (k, (_, a, b)) in pairs.iter().enumerate() {
+            if found[k].is_some() {
+                continue;
+            }
+            let diff = word(&words, *a) ^ word(&words, *b);
+            if diff == 0 {
+                continue;
+            }
+            let bit = diff.trailing_zeros();
+            let assignment = support(aig, &[*a, *b])
+                .into_iter()
+                .map(|n| (n, (words[n as usize] >> bit) & 1 == 1))
+                .collect();
+            found[k] = Some(assignment);
+        }
+        if found.iter().all(Option::is_some) {
+            break;
+        }
+    }
+    found
+}
+
+/// Tseitin-encodes the cone of `a` and `b` and asks whether they can differ.
+fn miter(aig: &AigModule, a: AigEdge, b: AigEdge) -> (Solver, HashMap<u32, u32>) {
+    let (mut solver, var) = tseitin(aig, &[a, b]);
+    let la = Lit::new(var[&a.node()], a.is_negated());
+    let lb = Lit::new(var[&b.node()], b.is_negated());
+    solver.add_clause(&[la, lb]);
+    solver.add_clause(&[la.negate(), lb.negate()]);
+    (solver, var)
+}
+
+/// A solver holding the cone of `roots`, and the solver variable of each
+/// node in it.
+pub(crate) fn tseitin(aig: &AigModule, roots: &[AigEdge]) -> (Solver, HashMap<u32, u32>) {
+    let mut solver = Solver::new();
+    let mut var: HashMap<u32, u32> = HashMap::new();
+    for node in cone(aig, roots) {
+        let v = solver.new_var();
+        var.insert(node, v);
+        match &aig.nodes[node as usize] {
+            AigNode::Const => solver.add_clause(&[Lit::new(v, true)]),
+            AigNode::Input { .. } => {}
+            AigNode::And { fanin0, fanin1 } => {
+                let x = Lit::new(var[&fanin0.node()], fanin0.is_negated());
+                let y = Lit::new(var[&fanin1.node()], fanin1.is_negated());
+                let out = Lit::new(v, false);
+                solver.add_clause(&[out.negate(), x]);
+                solver.add_clause(&[out.negate(), y]);
+                solver.add_clause(&[out, x.negate(), y.negate()]);
+            }
+        }
+    }
+    (solver, var)
+}
+
+fn prove(aig: &AigModule, a: AigEdge, b: AigEdge) -> SatResult {
+    miter(aig, a, b).0.solve(CONFLICT_LIMIT)
+}
+
+fn solve_counterexample(aig: &AigModule, a: AigEdge, b: AigEdge) -> Option<Assignment> {
+    let (mut solver, var) = miter(aig, a, b);
+    if solver.solve(CONFLICT_LIMIT) != SatResult::Sat {
+        return None;
+    }
+    Some(
+        support(aig, &[a, b])
+            .into_iter()
+            .map(|n| (n, solver.value(var[&n]).unwrap_or(false)))
+            .collect(),
+    )
+}
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Display names for free inputs: port bits, registers by their RTL
+/// variable, RAM read data; anything else is `$net<id>`.
+struct InputNames {
+    names: HashMap<NetId, String>,
+}
+
+impl InputNames {
+    fn new(reference: &GateModule, synthesized: &GateModule) -> Self {
+        let mut names = HashMap::new();
+        for gate in [synthesized, reference] {
+            for port in &gate.ports {
+                for (i, &net) in port.nets.iter().enumerate() {
+                    names.insert(net, port.bit_name(i));
+                }
+            }
+            for (i, ff) in gate.ffs.iter().enumerate() {
+                names.insert(ff.q, Self::ff_name(ff, i));
+            }
+            // The exporters' naming for RAM read data.
+            for (net, info) in gate.nets.iter().enumerate() {
+                if let NetDriver::RamRead(r, p, b) = info.driver {
+                    names.insert(net as NetId, format!("$ram{}.r{}[{}]", r, p, b));
+                }
+            }
+        }
+        Self { names }
+    }
+
+    fn ff_name(ff: &FfCell, index: usize) -> String {
+        match ff.origin {
+            Some((name, bit)) => format!("{}[{}]", name, bit),
+            None => format!("$ff{}", index),
+        }
+    }
+
+    fn ff(&self, gate: &GateModule, index: usize) -> String {
+        Self::ff_name(&gate.ffs[index], index)
+    }
+
+    fn net(&self, net: NetId) -> String {
+        if let Some(name) = self.names.get(&net) {
+            return name.clone();
+        }
+        format!("$net{}", net)
+    }
+}
@@ -0,0 +1,510 @@
+//! A small CDCL SAT solver: two-watched-literal propagation, first-UIP
+//! clause learning, VSIDS branching with phase saving, and Luby restarts.
+//! Sized for the per-compare-point miters of [`crate::verify`], which are
+//! one output cone each, not for industrial CNF.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lit(u32);
+
+impl Lit {
+    #[inline]
+    pub fn new(var: u32, negated: bool) -> Self {
+        Lit((var << 1) | negated as u32)
+    }
+
+    #[inline]
+    pub fn var(self) -> u32 {
+        self.0 >> 1
+    }
+
+    #[inline]
+    pub fn is_negated(self) -> bool {
+        (self.0 & 1) == 1
+    }
+
+    #[inline]
+    pub fn negate(self) -> Self {
+        Lit(self.0 ^ 1)
+    }
+
+    #[inline]
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SatResult {
+    Sat,
+    Unsat,
+    /// The conflict budget ran out.
+    Unknown,
+}
+
+#[derive(Default)]
+pub struct Solver {
+    /// Each clause watches its first two literals.
+    clauses: Vec<Vec<Lit>>,
+    /// Per literal: clauses watching it, visited when it becomes false.
+    watches: Vec<Vec<usize>>,
+    values: Vec<Option<bool>>,
+    level: Vec<u32>,
+    /// The clause that implied a variable; its first literal is the
+    /// implied one. `None` for decisions and level-0 facts.
+    reason: Vec<Option<usize>>,
+    trail: Vec<Lit>,
+    trail_lim: Vec<usize>,
+    qhead: usize,
+    activity: Vec<f64>,
+    bump: f64,
+    phase: Vec<bool>,
+    heap: VarHeap,
+    seen: Vec<bool>,
+    /// Set once the clause set is known unsatisfiable.
+    unsat: bool,
+}
+
+const ACTIVITY_DECAY: f64 = 0.95;
+const RESTART_UNIT: u64 = 100;
+
+impl Solver {
+    pub fn new() -> Self {
+        Self {
+            bump: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn new_var(&mut self) -> u32 {
+        let var = self.values.len() as u32;
+        self.values.push(None);
+        self.level.push(0);
+        self.reason.push(None);
+        self.activity.push(0.0);
+        self.phase.push(false);
+        self.seen.push(false);
+        self.watches.push(Vec::new());
+        self.watches.push(Vec::new());
+        self.heap.insert(var, &self.activity);
+        var
+    }
+
+    /// Value of `var` in the model after [`Solver::solve`] returned
+    /// [`SatResult::Sat`].
+    pub fn value(&self, var: u32) -> Option<bool> {
+        self.values[var as usize]
+    }
+
+    fn lit_value(&self, lit: Lit) -> Option<bool> {
+        self.values[lit.var() as usize].map(|v| v != lit.is_negated())
+    }
+
+    fn decision_level(&self) -> u32 {
+        self.trail_lim.len() as u32
+    }
+
+    /// Adds a clause. Only valid before [`Solver::solve`].
+    pub fn add_clause(&mut self, lits: &[Lit]) {
+        if self.unsat {
+            return;
+        }
+        let mut clause: Vec<Lit> = Vec::with_capacity(lits.len());
+        for &lit in lits {
+            match self.lit_value(lit) {
+                Some(true) => return,
+                Some(false) => continue,
+                None => {}
+            }
+            if clause.contains(&lit.negate()) {
+                return;
+            }
+            if !clause.contains(&lit) {
+                clause.push(lit);
+            }
+        }
+        match clause.len() {
+            0 => self.unsat = true,
+            1 => {
+                self.enqueue(clause[0], None);
+                if self.propagate().is_some() {
+                    self.unsat = true;
+                }
+            }
+            _ => {
+                self.attach(clause);
+            }
+        }
+    }
+
+    fn attach(&mut self, clause: Vec<Lit>) -> usize {
+        let idx = self.clauses.len();
+        self.watches[clause[0].index()].push(idx);
+        self.watches[clause[1].index()].push(idx);
+        self.clauses.push(clause);
+        idx
+    }
+
+    fn enqueue(&mut self, lit: Lit, reason: Option<usize>) {
+        let var = lit.var() as usize;
+        self.values[var] = Some(!lit.is_negated());
+        self.level[var] = self.decision_level();
+        self.reason[var] = reason;
+        self.trail.push(lit);
+    }
+
+    /// Unit propagation; returns a falsified clause on conflict.
+    fn propagate(&mut self) -> Option<usize> {
+        while self.qhead < self.trail.len() {
+            let false_lit = self.trail[self.qhead].negate();
+            self.qhead += 1;
+            let mut watchers = std::mem::take(&mut self.watches[false_lit.index()]);
+            let mut i = 0;
+            let mut conflict = None;
+            while i < watchers.len() {
+                let ci = watchers[i];
+                let clause = &mut self.clauses[ci];
+                if clause[0] == false_lit {
+                    clause.swap(0, 1);
+                }
+                let first = clause[0];
+                let first_value =
+                    self.values[first.var() as usize].map(|v| v != first.is_negated());
+                if first_value == Some(true) {
+                    i += 1;
+                    continue;
+                }
+                let replacement = (2..clause.len()).find(|&k| {
+                    let lit = clause[k];
+                    self.values[lit.var() as usize].map(|v| v != lit.is_negated()) != Some(false)
+                });
+                if let Some(k) = replacement {
+                    clause.swap(1, k);
+                    let watch = clause[1];
+                    self.watches[watch.index()].push(ci);
+                    watchers.swap_remove(i);
+                    continue;
+                }
+                if first_value == Some(false) {
+                    conflict = Some(ci);
+                    break;
+                }
+                self.enqueue(first, Some(ci));
+                i += 1;
+            }
+            self.watches[false_lit.index()] = watchers;
+            if conflict.is_some() {
+                self.qhead = self.trail.len();
+                return conflict;
+            }
+        }
+        None
+    }
+
+    /// First-UIP conflict analysis: the learnt clause (asserting literal
+    /// first, a literal of the backjump level second) and that level.
+    fn analyze(&mut self, conflict: usize) -> (Vec<Lit>, u32) {
+        let mut learnt = vec![Lit(0)];
+        let mut pending = 0;
+        let mut idx = self.trail.len();
+        let mut clause = conflict;
+        let mut skip_first = false;
+        let uip = loop {
+            let start = skip_first as usize;
+            for k in start..self.clauses[clause].len() {
+                let lit = self.clauses[clause][k];
+                let var = lit.var() as usize;
+                if self.seen[var] || self.level[var] == 0 {
+                    continue;
+                }
+                self.seen[var] = true;
+                self.bump_var(lit.var());
+                if self.level[var] == self.decision_level() {
+                    pending += 1;
+                } else {
+                    learnt.push(lit);
+                }
+            }
+            loop {
+                idx -= 1;
+                if self.seen[self.trail[idx].var() as usize] {
+                    break;
+                }
+            }
+            let lit = self.trail[idx];
+            self.seen[lit.var() as usize] = false;
+            pending -= 1;
+            if pending == 0 {
+                break lit;
+            }
+            clause = self.reason[lit.var() as usize].expect("implied literal has a reason");
+            skip_first = true;
+        };
+        learnt[0] = uip.negate();
+        for lit in &learnt[1..] {
+            self.seen[lit.var() as usize] = false;
+        }
+
+        let mut backjump = 0;
+        if learnt.len() > 1 {
+            let (max_k, _) = learnt
+                .iter()
+                .enumerate()
+                .skip(1)
+                .max_by_key(|(_, lit)| self.level[lit.var() as usize])
+                .expect("learnt clause has a second literal");
+            learnt.swap(1, max_k);
+            backjump = self.level[learnt[1].var() as usize];
+        }
+        (learnt, backjump)
+    }
+
+    fn bump_var(&mut self, var: u32) {
+        let v = var as usize;
+        self.activity[v] += self.bump;
+        if self.activity[v] > 1e100 {
+            for a in self.activity.iter_mut() {
+                *a *= 1e-100;
+            }
+            self.bump *= 1e-100;
+        }
+        self.heap.increase(var, &self.activity);
+    }
+
+    fn cancel_until(&mut self, level: u32) {
+        if self.decision_level() <= level {
+            return;
+        }
+        let lim = self.trail_lim[level as usize];
+        for k in (lim..self.trail.len()).rev() {
+            let lit = self.trail[k];
+            let var = lit.var() as usize;
+            self.phase[var] = !lit.is_negated();
+            self.values[var] = None;
+            self.reason[var] = None;
+            self.heap.insert(lit.var(), &self.activity);
+        }
+        self.trail.truncate(lim);
+        self.trail_lim.truncate(level as usize);
+        self.qhead = self.trail.len();
+    }
+
+    fn pick_branch(&mut self) -> Option<Lit> {
+        while let Some(var) = self.heap.pop(&self.activity) {
+            if self.values[var as usize].is_none() {
+                return Some(Lit::new(var, !self.phase[var as usize]));
+            }
+        }
+        None
+    }
+
+    /// Solves the clause set, giving up after `max_conflicts` conflicts.
+    pub fn solve(&mut self, max_conflicts: u64) -> SatResult {
+        if self.unsat || self.propagate().is_some() {
+            self.unsat = true;
+            return SatResult::Unsat;
+        }
+        let mut conflicts = 0;
+        let mut restarts = 0;
+        let mut restart_at = luby(restarts) * RESTART_UNIT;
+        let mut since_restart = 0;
+        loop {
+            if let Some(conflict) = self.propagate() {
+                conflicts += 1;
+                since_restart += 1;
+                if self.decision_level() == 0 {
+                    self.unsat = true;
+                    return SatResult::Unsat;
+                }
+                let (learnt, backjump) = self.analyze(conflict);
+                self.cancel_until(backjump);
+                if learnt.len() == 1 {
+                    self.enqueue(learnt[0], None);
+                } else {
+                    let asserting = learnt[0];
+                    let idx = self.attach(learnt);
+                    self.enqueue(asserting, Some(idx));
+                }
+                self.bump /= ACTIVITY_DECAY;
+                if conflicts >= max_conflicts {
+                    self.cancel_until(0);
+                    return SatResult::Unknown;
+                }
+                if since_restart >= restart_at {
+                    self.cancel_until(0);
+                    restarts += 1;
+                    restart_at = luby(restarts) * RESTART_UNIT;
+                    since_restart = 0;
+                }
+            } else {
+                match self.pick_branch() {
+                    Some(lit) => {
+                        self.trail_lim.push(self.trail.len());
+                        self.enqueue(lit, None);
+                    }
+                    None => return SatResult::Sat,
+                }
+            }
+        }
+    }
+}
+
+/// The Luby sequence 1, 1, 2, 1, 1, 2, 4, ... (0-based).
+fn luby(mut i: u64) -> u64 {
+    let mut size = 1;
+    let mut seq = 0;
+    while size < i + 1 {
+        seq += 1;
+        size = 2 * size + 1;
+    }
+    while size - 1 != i {
+        size = (size - 1) >> 1;
+        seq -= 1;
+        i %= size;
+    }
+    1 << seq
+}
+
+/// Max-heap of variables by activity.
+#[derive(Default)]
+struct VarHeap {
+    heap: Vec<u32>,
+    /// Position of each variable in `heap`, if present.
+    pos: Vec<Option<usize>>,
+}
+
+impl VarHeap {
+    fn insert(&mut self, var: u32, activity: &[f64]) {
+        let v = var as usize;
+        if self.pos.len() <= v {
+            self.pos.resize(v + 1, None);
+        }
+        if self.pos[v].is_some() {
+            return;
+        }
+        self.pos[v] = Some(self.heap.len());
+        self.heap.push(var);
+        self.sift_up(self.heap.len() - 1, activity);
+    }
+
+    fn increase(&mut self, var: u32, activity: &[f64]) {
+        if let Some(Some(i)) = self.pos.get(var as usize) {
+            self.sift_up(*i, activity);
+        }
+    }
+
+    fn pop(&mut self, activity: &[f64]) -> Option<u32> {
+        let top = *self.heap.first()?;
+        let last = self.heap.pop().expect("heap is non-empty");
+        self.pos[top as usize] = None;
+        if !self.heap.is_empty() {
+            self.heap[0] = last;
+            self.pos[last as usize] = Some(0);
+            self.sift_down(0, activity);
+        }
+        Some(top)
+    }
+
+    fn sift_up(&mut self, mut i: usize, activity: &[f64]) {
+        let var = self.heap[i];
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if activity[self.heap[parent] as usize] >= activity[var as usize] {
+                break;
+            }
+            self.heap[i] = self.heap[parent];
+            self.pos[self.heap[i] as usize] = Some(i);
+            i = parent;
+        }
+        self.heap[i] = var;
+        self.pos[var as usize] = Some(i);
+    }
+
+    fn sift_down(&mut self, mut i: usize, activity: &[f64]) {
+        let var = self.heap[i];
+        loop {
+            let left = 2 * i + 1;
+            if left >= self.heap.len() {
+                break;
+            }
+            let right = left + 1;
+            let child = if right < self.heap.len()
+                && activity[self.heap[right] as usize] > activity[self.heap[left] as usize]
+            {
+                right
+            } else {
+                left
+            };
+            if activity[self.heap[child] as usize] <= activity[var as usize] {
+                break;
+            }
+            self.heap[i] = self.heap[child];
+            self.pos[self.heap[i] as usize] = Some(i);
+            i = child;
+        }
+        self.heap[i] = var;
+        self.pos[var as usize] = Some(i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn luby_sequence() {
+        let seq: Vec<u64> = (0..15).map(luby).collect();
+        assert_eq!(seq, vec![1, 1, 2, 1, 1, 2, 4, 1, 1, 2, 1, 1, 2, 4, 8]);
+    }
+
+    /// Pigeonhole: n + 1 pigeons never fit in n holes.
+    fn pigeonhole(holes: u32) -> Solver {
+        let mut s = Solver::new();
+        let p = |i: u32, j: u32| i * holes + j;
+        for _ in 0..(holes + 1) * holes {
+            s.new_var();
+        }
+        for i in 0..=holes {
+            let clause: Vec<Lit> = (0..holes).map(|j| Lit::new(p(i, j), false)).collect();
+            s.add_clause(&clause);
+        }
+        for j in 0..holes {
+            for a in 0..=holes {
+                for b in a + 1..=holes {
+                    s.add_clause(&[Lit::new(p(a, j), true), Lit::new(p(b, j), true)]);
+                }
+            }
+        }
+        s
+    }
+
+    #[test]
+    fn pigeonhole_is_unsat() {
+        assert_eq!(pigeonhole(5).solve(u64::MAX), SatResult::Unsat);
+    }
+
+    #[test]
+    fn model_satisfies_every_clause() {
+        // x0 ^ x1 ^ x2 = 1, x0 | x1, !x2
+        let mut s = Solver::new();
+        let v: Vec<u32> = (0..3).map(|_| s.new_var()).collect();
+        let l = |i: usize, neg: bool| Lit::new(v[i], neg);
+        let clauses = vec![
+            vec![l(0, false), l(1, false), l(2, false)],
+            vec![l(0, false), l(1, true), l(2, true)],
+            vec![l(0, true), l(1, false), l(2, true)],
+            vec![l(0, true), l(1, true), l(2, false)],
+            vec![l(0, false), l(1, false)],
+            vec![l(2, true)],
+        ];
+        for c in &clauses {
+            s.add_clause(c);
+        }
+        assert_eq!(s.solve(u64::MAX), SatResult::Sat);
+        for c in &clauses {
+            assert!(
+                c.iter()
+                    .any(|lit| s.value(lit.var()) == Some(!lit.is_negated()))
+            );
+        }
+    }
+}
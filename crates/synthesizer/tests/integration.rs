@@ -4,10 +4,13 @@ use veryl_analyzer::{Analyzer, Context, symbol_table};
 use veryl_metadata::Metadata;
 use veryl_parser::Parser;
 use veryl_parser::resource_table;
-use veryl_synthesizer::ir::{CellKind, NetDriver};
+use veryl_synthesizer::ir::{Cell, CellKind, GateModule, NetDriver, NetInfo};
 use veryl_synthesizer::{
-    Library, RamConfig, build_gate_ir, build_gate_ir_with, compute_power, library_for, synthesize,
-    synthesize_with,
+    BmcOutcome, CellLibrary, ClockConstraint, LibertyLibrary, Library, MismatchKind, RamConfig,
+    SequentialAig, TimingConstraints, build_gate_ir, build_gate_ir_with, build_proof_model,
+    check_equivalence, compute_clock_slack, compute_power, library_for, synthesize,
+    synthesize_and_verify, synthesize_with, synthesize_with_library, write_aiger, write_blif,
+    write_verilog,
 };
 
 #[track_caller]
@@ -5222,3 +5225,743 @@ fn inst_unpacked_array_slice_output_port_keeps_element_order() {
     }
     assert_eq!(checked, 2, "both outputs must be present");
 }
+
+#[test]
+fn netlist_instantiates_library_cells() {
+    let code = r#"
+        module Top (
+            clk: input  clock,
+            rst: input  reset,
+            a:   input  logic<2>,
+            q:   output logic,
+        ) {
+            always_ff (clk, rst) {
+                if_reset {
+                    q = 1;
+                } else {
+                    q = a[0] & a[1];
+                }
+            }
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    let result = synthesize(&ir, top, Library::Sky130).expect("synthesize");
+    let netlist = write_verilog(&result.gate_ir.module, library_for(Library::Sky130));
+
+    assert!(netlist.contains("module Top ("), "{netlist}");
+    assert!(netlist.contains("input [1:0] a"), "{netlist}");
+    // Default reset is async active-low, reset value 1 → async-set DFF.
+    assert!(
+        netlist.contains("sky130_fd_sc_hd__dfstp_1 ff0 (.CLK("),
+        "{netlist}"
+    );
+    assert!(netlist.contains("sky130_fd_sc_hd__and2_1"), "{netlist}");
+    assert!(netlist.trim_end().ends_with("endmodule"), "{netlist}");
+}
+
+#[test]
+fn netlist_declares_ram_black_box() {
+    let code = r#"
+        module Mem (
+            clk:   input  clock     ,
+            we:    input  logic     ,
+            waddr: input  logic<6>  ,
+            wdata: input  logic<32> ,
+            raddr: input  logic<6>  ,
+            rdata: output logic<32> ,
+        ) {
+            var mem: logic<32> [64];
+            always_ff (clk) {
+                if we {
+                    mem[waddr] = wdata;
+                }
+            }
+            assign rdata = mem[raddr];
+        }
+    "#;
+    let (ir, top) = analyze(code, "Mem");
+    let result = synthesize(&ir, top, Library::Asap7).expect("synthesize");
+    assert_eq!(result.gate_ir.module.ram_blocks.len(), 1);
+    let netlist = write_verilog(&result.gate_ir.module, library_for(Library::Asap7));
+
+    assert!(
+        netlist.contains("veryl_ram_64x32_w_ra ram0 (.CLK("),
+        "{netlist}"
+    );
+    assert!(
+        netlist.contains("(* blackbox *)\nmodule veryl_ram_64x32_w_ra ("),
+        "{netlist}"
+    );
+    assert!(netlist.contains("output [31:0] R0_DATA"), "{netlist}");
+}
+
+/// Evaluate the combinational outputs of an ASCII AIGER file with all
+/// latches at their current literal value `latch_values`.
+fn eval_aag(aag: &str, inputs: &[bool], latch_values: &[bool]) -> (Vec<bool>, Vec<bool>) {
+    let mut lines = aag.lines();
+    let header: Vec<usize> = lines
+        .next()
+        .unwrap()
+        .split_whitespace()
+        .skip(1)
+        .map(|x| x.parse().unwrap())
+        .collect();
+    let (m, i, l, o, a) = (header[0], header[1], header[2], header[3], header[4]);
+    let mut val = vec![false; m + 1];
+    for &input in inputs.iter().take(i) {
+        let lit: usize = lines.next().unwrap().parse().unwrap();
+        val[lit / 2] = input;
+    }
+    let mut nexts = Vec::new();
+    for &latch in latch_values.iter().take(l) {
+        let line: Vec<usize> = lines
+            .next()
+            .unwrap()
+            .split_whitespace()
+            .map(|x| x.parse().unwrap())
+            .collect();
+        val[line[0] / 2] = latch;
+        nexts.push(line[1]);
+    }
+    let outs: Vec<usize> = (0..o)
+        .map(|_| lines.next().unwrap().parse().unwrap())
+        .collect();
+    for _ in 0..a {
+        let line: Vec<usize> = lines
+            .next()
+            .unwrap()
+            .split_whitespace()
+            .map(|x| x.parse().unwrap())
+            .collect();
+        let get = |lit: usize, val: &[bool]| val[lit / 2] ^ (lit & 1 == 1);
+        val[line[0] / 2] = get(line[1], &val) && get(line[2], &val);
+    }
+    let get = |lit: usize| val[lit / 2] ^ (lit & 1 == 1);
+    (
+        outs.into_iter().map(get).collect(),
+        nexts.into_iter().map(get).collect(),
+    )
+}
+
+#[test]
+fn aiger_export_matches_rtl() {
+    let code = r#"
+        module Top (
+            clk: input  clock,
+            rst: input  reset,
+            a:   input  logic,
+            b:   input  logic,
+            c:   input  logic,
+            y:   output logic,
+            q:   output logic,
+        ) {
+            assign y = (a & b) | c;
+            always_ff (clk, rst) {
+                if_reset {
+                    q = 1;
+                } else {
+                    q = a ^ b;
+                }
+            }
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    let result = synthesize(&ir, top, Library::default()).expect("synthesize");
+    let seq = SequentialAig::from_gate(&result.gate_ir.module);
+    let aag = String::from_utf8(write_aiger(&seq, false)).unwrap();
+
+    assert!(aag.starts_with("aag "), "{aag}");
+    // Async reset FF starts at its reset value.
+    assert_eq!(seq.latches.len(), 1);
+    assert_eq!(seq.latches[0].init, Some(true));
+
+    let names: Vec<&str> = seq.inputs.iter().map(|x| x.name.as_str()).collect();
+    assert_eq!(names, ["clk", "rst", "a", "b", "c"]);
+    for bits in 0..8u32 {
+        let (a, b, c) = (bits & 1 != 0, bits & 2 != 0, bits & 4 != 0);
+        // rst is active low: deasserted (1) → next = a ^ b.
+        let (outs, nexts) = eval_aag(&aag, &[false, true, a, b, c], &[false]);
+        assert_eq!(outs[0], (a && b) || c, "y for a={a} b={b} c={c}");
+        assert!(!outs[1], "q follows the latch");
+        assert_eq!(nexts[0], a ^ b);
+        // rst asserted (0) → next = reset value.
+        let (_, nexts) = eval_aag(&aag, &[false, false, a, b, c], &[false]);
+        assert!(nexts[0]);
+    }
+
+    let binary = write_aiger(&seq, true);
+    assert!(binary.starts_with(b"aig "));
+
+    let blif = write_blif(&seq);
+    assert!(blif.contains(".model Top"), "{blif}");
+    assert!(blif.contains(".inputs clk rst a b c"), "{blif}");
+    assert!(blif.contains(".latch $ff0$d $ff0 re clk 1"), "{blif}");
+    assert!(blif.trim_end().ends_with(".end"), "{blif}");
+}
+
+/// A tiny Liberty library: enough to cost every kind and map both FFs.
+const TEST_LIBERTY: &str = r#"
+library (tiny) {
+  /* units */
+  time_unit : "1ps";
+  capacitive_load_unit (1, ff);
+  leakage_power_unit : "1pW";
+  voltage_unit : "1V";
+  lu_table_template (delay_2x2) {
+    variable_1 : input_net_transition;
+    variable_2 : total_output_net_capacitance;
+    index_1 ("10, 100");
+    index_2 ("1, 11");
+  }
+  cell (TINY_INV) {
+    area : 1.0;
+    cell_leakage_power : 100;
+    pin (A) { direction : input; capacitance : 1.0; }
+    pin (Y) {
+      direction : output;
+      function : "A'";
+      timing () {
+        related_pin : "A";
+        cell_rise (delay_2x2) { values ("10, 20", "50, 60"); }
+        cell_fall (delay_2x2) { values ("30, 40", \
+                                        "70, 80"); }
+      }
+    }
+  }
+  cell (TINY_NAND2) {
+    area : 2.0;
+    pin (A) { direction : input; capacitance : 1.5; }
+    pin (B) { direction : input; capacitance : 1.5; }
+    pin (Y) { direction : output; function : "!(A B)"; }
+  }
+  cell (TINY_MUX2) {
+    area : 5.0;
+    pin (I0) { direction : input; capacitance : 1.0; }
+    pin (I1) { direction : input; capacitance : 1.0; }
+    pin (S) { direction : input; capacitance : 2.0; }
+    pin (Z) { direction : output; function : "(I0 & !S) | (I1 & S)"; }
+  }
+  cell (TINY_DFFR) {
+    area : 20.0;
+    ff (IQ, IQN) { next_state : "D"; clocked_on : "CK"; clear : "!RN"; }
+    pin (CK) { direction : input; capacitance : 1.0; }
+    pin (D) {
+      direction : input;
+      capacitance : 1.0;
+      timing () {
+        related_pin : "CK";
+        timing_type : setup_rising;
+        rise_constraint (scalar) { values ("80"); }
+        fall_constraint (scalar) { values ("120"); }
+      }
+    }
+    pin (RN) { direction : input; capacitance : 1.0; }
+    pin (Q) { direction : output; function : "IQ"; }
+  }
+  cell (TINY_DFFRS) {
+    area : 24.0;
+    ff (IQ, IQN) { next_state : "D"; clocked_on : "CK"; clear : "!RN"; preset : "!SN"; }
+    pin (CK) { direction : input; capacitance : 1.0; }
+    pin (D) { direction : input; capacitance : 1.0; }
+    pin (RN) { direction : input; capacitance : 1.0; }
+    pin (SN) { direction : input; capacitance : 1.0; }
+    pin (QN) { direction : output; function : "IQN"; }
+  }
+}
+"#;
+
+#[test]
+fn liberty_library_maps_cells_by_function() {
+    let lib = LibertyLibrary::parse(&[("tiny.lib", TEST_LIBERTY)]).expect("liberty");
+    assert_eq!(lib.name(), "tiny");
+
+    let inv = lib.netlist_cell(CellKind::Not).expect("inverter");
+    assert_eq!(
+        (inv.name, inv.inputs, inv.output),
+        ("TINY_INV", &["A"][..], "Y")
+    );
+    // Pins come back in `[sel, d0, d1]` order whatever the cell declares.
+    let mux = lib.netlist_cell(CellKind::Mux2).expect("mux");
+    assert_eq!(mux.inputs, &["S", "I0", "I1"][..]);
+    assert!(lib.netlist_cell(CellKind::Xor2).is_none());
+
+    // FO4 = 4 fF; fastest slew row: rise 10 + 0.3·10, fall 30 + 0.3·10,
+    // averaged to 23 ps.
+    let info = lib.info(CellKind::Not);
+    assert!((info.delay - 0.023).abs() < 1e-9, "{}", info.delay);
+    assert!((info.leakage - 0.1).abs() < 1e-9, "{}", info.leakage);
+    // No AND2 cell: costed as NAND2 + INV.
+    assert!((lib.info(CellKind::And2).area - 3.0).abs() < 1e-9);
+    assert!((lib.ff_setup() - 0.12).abs() < 1e-9);
+    assert!((lib.ff_area() - 20.0).abs() < 1e-9);
+
+    let ffs = lib.netlist_ffs();
+    assert!(ffs.plain.is_none());
+    assert_eq!(ffs.clear.name, "TINY_DFFR");
+    let set = ffs.set.expect("set flip-flop");
+    assert_eq!(set.name, "TINY_DFFRS");
+    assert_eq!(
+        set.reset.map(|r| (r.name, r.active_low)),
+        Some(("SN", true))
+    );
+    assert_eq!(set.ties, &[("RN", true)][..]);
+    assert!(set.q_inverted);
+}
+
+#[test]
+fn liberty_library_drives_synthesis() {
+    let code = r#"
+        module Top (
+            clk: input  clock,
+            rst: input  reset,
+            s:   input  logic,
+            a:   input  logic<2>,
+            q:   output logic,
+        ) {
+            always_ff (clk, rst) {
+                if_reset {
+                    q = 0;
+                } else {
+                    q = if s ? a[1] : a[0];
+                }
+            }
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    let lib: &'static LibertyLibrary = Box::leak(Box::new(
+        LibertyLibrary::parse(&[("tiny.lib", TEST_LIBERTY)]).expect("liberty"),
+    ));
+    let result = synthesize_with_library(&ir, top, lib, RamConfig::default()).expect("synthesize");
+    assert!(result.area.total >= 20.0, "{}", result.area.total);
+    let netlist = write_verilog(&result.gate_ir.module, lib);
+    assert!(netlist.contains("TINY_DFFR ff0 (.CK("), "{netlist}");
+}
+
+#[test]
+fn liberty_library_reports_missing_inverter() {
+    let text = r#"library (empty) { cell (X) { area : 1; } }"#;
+    let err = LibertyLibrary::parse(&[("empty.lib", text)])
+        .err()
+        .expect("no inverter");
+    assert!(err.to_string().starts_with("empty.lib: "), "{err}");
+}
+
+fn constraints(clocks: &[(&str, f64)], output_delay: f64) -> TimingConstraints {
+    TimingConstraints {
+        clocks: clocks
+            .iter()
+            .map(|&(port, period)| ClockConstraint {
+                port: port.to_string(),
+                period,
+            })
+            .collect(),
+        output_delay,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn clock_slack_skips_asynchronous_domain_crossings() {
+    let code = r#"
+        module Top (
+            clk_a: input  'a clock,
+            clk_b: input  'b clock,
+            rst_a: input  'a reset,
+            rst_b: input  'b reset,
+            x:     input  'a logic<8>,
+            y:     output 'b logic<8>,
+        ) {
+            var ra: 'a logic<8>;
+            var rb: 'b logic<8>;
+            always_ff (clk_a, rst_a) {
+                if_reset { ra = 0; } else { ra = ra + x; }
+            }
+            unsafe (cdc) {
+                always_ff (clk_b, rst_b) {
+                    if_reset { rb = 0; } else { rb = ra * 3; }
+                }
+            }
+            assign y = rb;
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    let result = synthesize(&ir, top, Library::Sky130).expect("synthesize");
+    let library = library_for(Library::Sky130);
+    let setup = library.ff_setup();
+
+    let (clocks, unconstrained) = compute_clock_slack(
+        &result.gate_ir.module,
+        library,
+        &constraints(&[("clk_a", 5.0), ("clk_b", 5.0)], 1.0),
+    );
+    assert!(unconstrained.is_empty(), "{unconstrained:?}");
+    let (a, b) = (&clocks[0], &clocks[1]);
+    // clk_a captures ra from itself and from x.
+    assert_eq!(a.endpoints, 8);
+    assert!(a.met());
+    // ra → rb is a false path, so clk_b only checks the y outputs, which
+    // are straight FF outputs: 5 - 1 output delay.
+    assert_eq!(b.endpoints, 8);
+    assert!((b.slack.unwrap() - 4.0).abs() < 1e-9, "{:?}", b.slack);
+
+    // The worst clk_a path can't be faster than one cell; squeeze the
+    // period so it fails.
+    let tight = a.period - a.slack.unwrap() - setup;
+    let (clocks, _) = compute_clock_slack(
+        &result.gate_ir.module,
+        library,
+        &constraints(&[("clk_a", tight), ("clk_b", 5.0)], 1.0),
+    );
+    assert!(!clocks[0].met());
+    assert!(clocks[0].slack.unwrap() < 0.0);
+    assert!(clocks[1].met());
+}
+
+#[test]
+fn clock_slack_reports_unconstrained_clocks() {
+    let code = r#"
+        module Top (
+            clk_a: input  clock,
+            clk_b: input  clock,
+            rst:   input  reset,
+            q:     output logic,
+        ) {
+            var r: logic;
+            always_ff (clk_a, rst) {
+                if_reset { r = 0; } else { r = ~r; }
+            }
+            always_ff (clk_b, rst) {
+                if_reset { q = 0; } else { q = r; }
+            }
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    let result = synthesize(&ir, top, Library::Sky130).expect("synthesize");
+    let library = library_for(Library::Sky130);
+    let (clocks, unconstrained) = compute_clock_slack(
+        &result.gate_ir.module,
+        library,
+        &constraints(&[("clk_a", 2.0)], 0.0),
+    );
+    assert_eq!(unconstrained, vec!["clk_b".to_string()]);
+    // r → q is captured by the unconstrained clk_b: only r itself counts.
+    assert_eq!(clocks[0].endpoints, 1);
+    assert!(clocks[0].met());
+
+    // A constraint naming no input port (a typo) isn't met.
+    let (clocks, _) = compute_clock_slack(
+        &result.gate_ir.module,
+        library,
+        &constraints(&[("clk_a", 2.0), ("clk_c", 2.0)], 0.0),
+    );
+    assert!(clocks[0].found && clocks[0].met());
+    assert!(!clocks[1].found && !clocks[1].met());
+    assert!(clocks[1].to_string().contains("no such input port"));
+}
+
+#[test]
+fn verify_proves_optimized_netlist_equivalent() {
+    let code = r#"
+        module Top (
+            clk: input  clock,
+            rst: input  reset,
+            en:  input  logic,
+            a:   input  logic<32>,
+            b:   input  logic<32>,
+            sum: output logic<32>,
+            max: output logic<32>,
+            cnt: output logic<4>,
+        ) {
+            var held: logic<4>;
+            always_comb {
+                sum = a + b;
+                max = if a >: b ? a : b;
+            }
+            always_ff (clk, rst) {
+                if_reset {
+                    cnt  = 0;
+                    held = 4'd5;
+                } else if en {
+                    cnt = cnt + held;
+                }
+            }
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    let (result, report) =
+        synthesize_and_verify(&ir, top, library_for(Library::Sky130), RamConfig::default())
+            .expect("synthesize");
+    assert!(report.equivalent(), "{report}");
+    // 68 output bits, plus D / clock / reset of each surviving counter bit,
+    // plus the held register's four reset-value invariants.
+    assert_eq!(result.gate_ir.module.ffs.len(), 4);
+    assert_eq!(report.points, 68 + 4 * 3 + 4, "{report}");
+}
+
+/// The optimized module and its pre-optimization reference.
+fn convert_with_reference(code: &str) -> (GateModule, GateModule) {
+    let (ir, top) = analyze(code, "Top");
+    let module = ir
+        .components
+        .iter()
+        .find_map(|c| match c {
+            air::Component::Module(m) if m.name == top => Some(m),
+            _ => None,
+        })
+        .expect("top");
+    veryl_synthesizer::conv::convert_module_with_reference(
+        module,
+        RamConfig::default(),
+        library_for(Library::Sky130),
+    )
+    .expect("synthesize")
+}
+
+fn port_net(gate: &GateModule, name: &str) -> u32 {
+    gate.ports
+        .iter()
+        .find(|p| p.name.to_string() == name)
+        .expect("port")
+        .nets[0]
+}
+
+#[test]
+fn verify_proves_restructured_logic_with_sat() {
+    let code = r#"
+        module Top (
+            a: input  logic,
+            b: input  logic,
+            y: output logic,
+        ) {
+            always_comb {
+                y = a ^ b;
+            }
+        }
+    "#;
+    let (mut gate, reference) = convert_with_reference(code);
+    let (a, b) = (port_net(&gate, "a"), port_net(&gate, "b"));
+
+    // Re-derive y as (a | b) & !(a & b), which hashes to different AIG
+    // nodes than the XOR.
+    let mut push = |kind: CellKind, inputs: Vec<u32>| {
+        let output = gate.nets.len() as u32;
+        gate.nets.push(NetInfo {
+            driver: NetDriver::Cell(gate.cells.len()),
+            origin: None,
+        });
+        gate.cells.push(Cell {
+            kind,
+            inputs,
+            output,
+        });
+        output
+    };
+    let or = push(CellKind::Or2, vec![a, b]);
+    let nand = push(CellKind::Nand2, vec![a, b]);
+    let y = push(CellKind::And2, vec![or, nand]);
+    let port = gate.ports.iter_mut().find(|p| p.name.to_string() == "y");
+    port.expect("port").nets[0] = y;
+
+    let report = check_equivalence(&reference, &gate);
+    assert!(report.equivalent(), "{report}");
+    assert_eq!(report.proved_by_sat, 1, "{report}");
+}
+
+#[test]
+fn verify_reports_counterexample_for_broken_netlist() {
+    let code = r#"
+        module Top (
+            a: input  logic,
+            b: input  logic,
+            c: input  logic,
+            y: output logic,
+        ) {
+            always_comb {
+                y = (a & b) | c;
+            }
+        }
+    "#;
+    let (mut gate, reference) = convert_with_reference(code);
+    assert!(check_equivalence(&reference, &gate).equivalent());
+
+    // Tie the `b` operand to `a`: y becomes a | c.
+    let (a, b) = (port_net(&gate, "a"), port_net(&gate, "b"));
+    for cell in &mut gate.cells {
+        for input in &mut cell.inputs {
+            if *input == b {
+                *input = a;
+            }
+        }
+    }
+
+    let report = check_equivalence(&reference, &gate);
+    assert!(!report.equivalent());
+    assert_eq!(report.mismatches.len(), 1, "{report}");
+    let mismatch = &report.mismatches[0];
+    assert_eq!(mismatch.point, "output y");
+    let MismatchKind::Functional {
+        counterexample,
+        reference,
+        synthesized,
+    } = &mismatch.kind
+    else {
+        panic!("expected a functional mismatch: {report}");
+    };
+    // The only distinguishing input vector.
+    let expected: Vec<(String, bool)> =
+        vec![("a".into(), true), ("b".into(), false), ("c".into(), false)];
+    assert_eq!(counterexample, &expected);
+    assert!(!reference && *synthesized);
+}
+
+#[test]
+fn verify_reference_keeps_child_logic_unoptimized() {
+    let child = r#"
+        module Child (
+            a: input  logic,
+            b: input  logic,
+            y: output logic,
+        ) {
+            always_comb {
+                y = (a & b) | (a & b);
+            }
+        }
+    "#;
+    let code = format!(
+        r#"
+        module Top (
+            a: input  logic,
+            b: input  logic,
+            y: output logic,
+        ) {{
+            inst u: Child (a, b, y);
+        }}
+        {child}
+    "#
+    );
+    let (gate, reference) = convert_with_reference(&code);
+    assert!(check_equivalence(&reference, &gate).equivalent());
+
+    // The child is flattened as first built, not in its optimized form.
+    let (child_gate, child_reference) = convert_with_reference(&child.replace("Child", "Top"));
+    assert!(reference.cells.len() >= child_reference.cells.len());
+    assert!(child_reference.cells.len() > child_gate.cells.len());
+}
+
+#[test]
+fn prove_finds_shortest_counterexample() {
+    let code = r#"
+        module Top (
+            clk: input  clock,
+            rst: input  reset,
+            en : input  logic,
+            cnt: output logic<4>,
+        ) {
+            always_ff {
+                if_reset {
+                    cnt = 0;
+                } else if en {
+                    cnt = cnt + 1;
+                    $assert(cnt != 3, "cnt reached 3");
+                }
+            }
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    let model = build_proof_model(&ir, top).expect("synthesize");
+    assert_eq!(model.assertions.len(), 1);
+
+    let report = model.check(3);
+    assert!(matches!(report.outcome, BmcOutcome::Safe));
+
+    let report = model.check(8);
+    let BmcOutcome::Violated(cex) = report.outcome else {
+        panic!("expected a counterexample");
+    };
+    assert_eq!(cex.assertion, 0);
+    assert_eq!(cex.cycle, 3);
+    // `cnt` only moves on `en`, and the assertion only runs under it.
+    let en: Vec<bool> = cex
+        .inputs
+        .iter()
+        .map(|ports| {
+            assert_eq!(ports.len(), 1, "clock and reset are not free inputs");
+            assert_eq!(ports[0].0, "en");
+            ports[0].1[0]
+        })
+        .collect();
+    assert_eq!(en, vec![true; 4]);
+}
+
+#[test]
+fn prove_reads_registers_before_the_clock_edge() {
+    let code = r#"
+        module Top (
+            clk: input  clock,
+            rst: input  reset,
+            sat: input  logic,
+            out: output logic<8>,
+        ) {
+            var x: logic<8>;
+            always_ff {
+                if_reset {
+                    x = 99;
+                } else if x != 101 {
+                    x = x + 1;
+                    $assert(x <= 100);
+                }
+            }
+            always_comb {
+                $assert(sat || x != 100);
+            }
+            assign out = x;
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    let model = build_proof_model(&ir, top).expect("synthesize");
+    assert_eq!(model.assertions.len(), 2);
+    let report = model.check(16);
+    let BmcOutcome::Violated(cex) = report.outcome else {
+        panic!("expected a counterexample");
+    };
+    // x runs 99, 100, 101 and then holds. The always_ff assertion reads x
+    // before its update, so it never fails; the comb one fails at 100.
+    assert_eq!(cex.assertion, 1);
+    assert_eq!(cex.cycle, 1);
+    assert_eq!(cex.inputs[1], vec![("sat".to_string(), vec![false])]);
+}
+
+#[test]
+fn assertion_history_is_left_out_of_synthesis() {
+    let code = r#"
+        module Top (
+            clk: input  clock,
+            rst: input  reset,
+            d  : input  logic,
+            q  : output logic,
+            p  : output logic,
+        ) {
+            always_ff {
+                if_reset {
+                    q = 0;
+                    p = 0;
+                } else {
+                    q = d;
+                    p = $past(d, 2);
+                    $assert($rose(d) |=> q);
+                    $assert($stable(q) || $past(q) != q);
+                }
+            }
+        }
+    "#;
+    let (ir, top) = analyze(code, "Top");
+    // `q`, `p` and the two registers of `$past(d, 2)`; what only the
+    // assertions read is no part of the design.
+    let gate = build_gate_ir(&ir, top).expect("synthesize");
+    assert_eq!(gate.module.ffs.len(), 4);
+
+    // A proof checks the assertions, so it keeps their registers.
+    let model = build_proof_model(&ir, top).expect("synthesize");
+    assert_eq!(model.assertions.len(), 2);
+    assert!(matches!(model.check(6).outcome, BmcOutcome::Safe));
+}
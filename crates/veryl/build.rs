@@ -0,0 +1,21 @@
+//! `veryl test --sim native` runs cocotb in a `veryl vpi-host` process. The
+//! VPI application resolves the `vpi_*` functions of `veryl-cosim` against
+//! the executable, so the binary exports them.
+
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    match std::env::var("CARGO_CFG_TARGET_OS").as_deref() {
+        Ok("linux") => {
+            let list = Path::new(&std::env::var("OUT_DIR").unwrap()).join("vpi.list");
+            fs::write(&list, "{ vpi_*; };\n").expect("write vpi.list");
+            println!(
+                "cargo:rustc-link-arg-bins=-Wl,--dynamic-list={}",
+                list.display()
+            );
+        }
+        Ok("macos") => println!("cargo:rustc-link-arg-bins=-Wl,-export_dynamic"),
+        _ => {}
+    }
+}
@@ -1,8 +1,9 @@
-use crate::OptBuild;
 use crate::StopWatch;
+use crate::diagnostic_report::DiagnosticReport;
 use crate::diff::print_diff;
 use crate::pipeline::{self, AnalyzeOptions, AnalyzeOutput};
 use crate::utils;
+use crate::{DiagnosticFormat, OptBuild};
 use log::{debug, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use std::collections::{HashMap, HashSet};
@@ -56,12 +57,25 @@ impl CmdBuild {
             incremental: true,
             fail_fast: true,
         };
+        // A machine-readable report owns stdout, so diffs are not printed.
+        let machine = self.opt.format != DiagnosticFormat::Pretty;
+        let quiet = quiet || machine;
+        let mut report = DiagnosticReport::new(metadata);
+
         let AnalyzeOutput {
             mut contexts,
             incremental,
             check_error,
             filelist_excluded,
-        } = pipeline::analyze(metadata, &paths, options, ir, test_filter)?;
+        } = match pipeline::analyze(metadata, &paths, options, ir, test_filter) {
+            Ok(x) => x,
+            Err(x) if machine => {
+                report.push_report(&x);
+                report.print(self.opt.format);
+                return Ok(false);
+            }
+            Err(x) => return Err(x),
+        };
 
         let mut stopwatch = StopWatch::new();
 
@@ -171,6 +185,11 @@ impl CmdBuild {
             debug!("Saved fragment cache ({} milliseconds)", stopwatch.lap());
         }
 
+        if machine {
+            report.push_check_error(&check_error);
+            report.print(self.opt.format);
+        }
+
         // No-op (analyze already returned Ok), kept for symmetry with check.
         let _ = check_error.check_err()?;
 
@@ -423,6 +442,7 @@ exclude_std = true
             files: Vec::new(),
             check: false,
             out_dir,
+            format: DiagnosticFormat::Pretty,
         });
         build
             .exec(metadata, false, true, None, None, &[])
@@ -603,9 +623,16 @@ incremental = true
     "#;
 
     fn run_check(metadata: &mut Metadata) -> Result<bool> {
+        run_check_with_format(metadata, DiagnosticFormat::Pretty)
+    }
+
+    fn run_check_with_format(metadata: &mut Metadata, format: DiagnosticFormat) -> Result<bool> {
         Analyzer::new(metadata).clear();
 
-        let check = crate::cmd_check::CmdCheck::new(crate::OptCheck { files: Vec::new() });
+        let check = crate::cmd_check::CmdCheck::new(crate::OptCheck {
+            files: Vec::new(),
+            format,
+        });
         let ret = check.exec(metadata);
 
         Analyzer::new(metadata).clear();
@@ -663,6 +690,7 @@ exclude_std = true
             files: Vec::new(),
             check: true,
             out_dir: None,
+            format: DiagnosticFormat::Pretty,
         });
         let pass = build
             .exec(metadata, false, true, None, None, &[])
@@ -848,6 +876,7 @@ exclude_std = true
             files: Vec::new(),
             check: false,
             out_dir: None,
+            format: DiagnosticFormat::Pretty,
         });
         let mut ir = veryl_analyzer::ir::Ir::default();
         build
@@ -1059,6 +1088,32 @@ exclude_std = true
         run_check(&mut metadata).expect("check passes after the fix");
     }
 
+    #[test]
+    fn machine_format_check_reports_instead_of_failing() {
+        let _lock = BUILD_TEST_LOCK.lock().unwrap();
+        let tempdir = tempfile::tempdir().unwrap();
+        let (mut metadata, project_path) = write_incremental_project(
+            tempdir.path(),
+            "machine_format",
+            &[
+                ("clean.veryl", CLEAN_MODULE),
+                ("warn.veryl", WARNING_MODULE),
+            ],
+        );
+
+        // Diagnostics go to the report; the command just exits unsuccessfully.
+        let ret = run_check_with_format(&mut metadata, DiagnosticFormat::Json);
+        assert!(!ret.expect("warning is reported, not returned"));
+
+        fs::write(project_path.join("src/warn.veryl"), ERROR_MODULE).unwrap();
+        let ret = run_check_with_format(&mut metadata, DiagnosticFormat::Sarif);
+        assert!(!ret.expect("fatal error is reported, not returned"));
+
+        fs::write(project_path.join("src/warn.veryl"), FIXED_MODULE).unwrap();
+        let ret = run_check_with_format(&mut metadata, DiagnosticFormat::Json);
+        assert!(ret.expect("clean check passes"));
+    }
+
     const TF_DUT: &str = r#"
     module Dut (
         o: output logic,
@@ -1107,6 +1162,7 @@ exclude_std = true
             files: Vec::new(),
             check: false,
             out_dir: Some(out_dir.clone()),
+            format: DiagnosticFormat::Pretty,
         });
         let mut ir = veryl_analyzer::ir::Ir::default();
         build
@@ -1268,12 +1324,16 @@ exclude_std = true
             backend: crate::Backend::Interpret,
             backend_validate: None,
             disable_ff_opt: false,
+            coverage: None,
+            checkpoint_at: None,
+            restore: None,
             ignored: false,
             include_ignored: false,
             define: Vec::new(),
             no_capture: false,
             seed: None,
             four_state: false,
+            debug: false,
             format: crate::Format::Pretty,
             format_version: None,
         });
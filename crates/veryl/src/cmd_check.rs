@@ -1,5 +1,6 @@
-use crate::OptCheck;
+use crate::diagnostic_report::DiagnosticReport;
 use crate::pipeline::{self, AnalyzeOptions, AnalyzeOutput};
+use crate::{DiagnosticFormat, OptCheck};
 use miette::Result;
 use veryl_metadata::Metadata;
 
@@ -21,17 +22,34 @@ impl CmdCheck {
             incremental: true,
             fail_fast: true,
         };
+        let machine = self.opt.format != DiagnosticFormat::Pretty;
+        let mut report = DiagnosticReport::new(metadata);
+
         let AnalyzeOutput {
             incremental,
             check_error,
             ..
-        } = pipeline::analyze(metadata, &paths, options, None, None)?;
+        } = match pipeline::analyze(metadata, &paths, options, None, None) {
+            Ok(x) => x,
+            Err(x) if machine => {
+                report.push_report(&x);
+                report.print(self.opt.format);
+                return Ok(false);
+            }
+            Err(x) => return Err(x),
+        };
 
         // Save clean files before failing on warnings, so a second check warms.
         if let Some(mut inc) = incremental {
             inc.save(&pipeline::collect_diagnosed(&check_error));
         }
 
+        if machine {
+            report.push_check_error(&check_error);
+            report.print(self.opt.format);
+            return Ok(report.is_empty());
+        }
+
         // check fails on warnings, not just errors.
         let _ = check_error.check_all()?;
         Ok(true)
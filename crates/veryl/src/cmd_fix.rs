@@ -0,0 +1,128 @@
+use crate::OptFix;
+use crate::diff::print_diff;
+use crate::pipeline::{self, AnalyzeOptions, Diag};
+use crate::utils;
+use log::{debug, info};
+use miette::{IntoDiagnostic, Result, WrapErr};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use veryl_analyzer::Analyzer;
+use veryl_analyzer::fix::{self, TextEdit};
+use veryl_metadata::Metadata;
+use veryl_path::PathSet;
+
+/// Fixes can uncover or overlap others (e.g. a prefix and a case fix of one
+/// identifier), so analysis is repeated until nothing is left to apply.
+const MAX_ROUNDS: usize = 8;
+
+pub struct CmdFix {
+    opt: OptFix,
+}
+
+impl CmdFix {
+    pub fn new(opt: OptFix) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata, quiet: bool) -> Result<bool> {
+        // Renames reach references in every project file, so the whole
+        // project is analyzed; only the target files are rewritten.
+        let paths = metadata.paths::<&str>(&[], true, true)?;
+        let targets: HashSet<PathBuf> = metadata
+            .paths(&self.opt.files, true, false)?
+            .into_iter()
+            .map(|x| x.src)
+            .collect();
+
+        let mut fixed = 0;
+        let mut files = HashSet::new();
+        for _ in 0..MAX_ROUNDS {
+            let (count, edits) = self.collect_edits(metadata, &paths, &targets)?;
+            if count == 0 {
+                break;
+            }
+
+            for (path, edits) in &edits {
+                let input = fs::read_to_string(path).into_diagnostic().wrap_err("")?;
+                let (output, _) = fix::apply(&input, edits);
+
+                if self.opt.check {
+                    if !quiet {
+                        print_diff(path, &input, &output);
+                    }
+                } else if utils::write_file_if_changed(path, output.as_bytes())? {
+                    debug!("Overwritten file ({})", path.to_string_lossy());
+                }
+                files.insert(path.clone());
+            }
+
+            fixed += count;
+            if self.opt.check {
+                break;
+            }
+        }
+
+        if self.opt.check {
+            Ok(fixed == 0)
+        } else {
+            info!("Applied {} fixes to {} files", fixed, files.len());
+            Ok(true)
+        }
+    }
+
+    /// Edits of the current diagnostics' fixes, grouped by file, and the
+    /// number of fixes they make up. A fix applies whole or not at all: one
+    /// touching a non-target file, overlapping an earlier fix or writing a
+    /// name an earlier fix renames (or the other way round) waits.
+    fn collect_edits(
+        &self,
+        metadata: &Metadata,
+        paths: &[PathSet],
+        targets: &HashSet<PathBuf>,
+    ) -> Result<(usize, BTreeMap<PathBuf, Vec<TextEdit>>)> {
+        Analyzer::new(metadata).clear();
+
+        let options = AnalyzeOptions {
+            defines: &[],
+            emit_mode: false,
+            incremental: false,
+            fail_fast: false,
+        };
+        let output = pipeline::analyze(metadata, paths, options, None, None)?;
+
+        let mut count = 0;
+        let mut taken: Vec<TextEdit> = Vec::new();
+        let mut fixes: Vec<&fix::Fix> = Vec::new();
+        for diag in &output.check_error.related {
+            let Diag::Analyzer(error) = diag else {
+                continue;
+            };
+            let Some(x) = error.fix() else {
+                continue;
+            };
+            let edits = x.edits();
+            if edits.is_empty()
+                || edits.iter().any(|x| !targets.contains(&x.path))
+                || edits.iter().any(|x| fix::conflicts(&taken, x))
+                || fix::conflicts_by_name(fixes.iter().copied(), x)
+            {
+                continue;
+            }
+            debug!("Fix ({}): {}", x.title, error);
+            count += 1;
+            fixes.push(x);
+            for edit in edits {
+                if !taken.contains(&edit) {
+                    taken.push(edit);
+                }
+            }
+        }
+
+        let mut ret: BTreeMap<PathBuf, Vec<TextEdit>> = BTreeMap::new();
+        for edit in taken {
+            ret.entry(edit.path.clone()).or_default().push(edit);
+        }
+        Ok((count, ret))
+    }
+}
@@ -0,0 +1,196 @@
+use crate::OptProve;
+use crate::cmd_synth::select_top;
+use crate::pipeline::{self, AnalyzeOptions};
+use log::{info, warn};
+use miette::{IntoDiagnostic, Result};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use veryl_analyzer::ir::Ir;
+use veryl_analyzer::value::Value;
+use veryl_metadata::Metadata;
+use veryl_parser::resource_table::{self, PathId, StrId};
+use veryl_simulator::assert_buffer;
+use veryl_simulator::ir::{Config, Event};
+use veryl_simulator::simulator::Simulator;
+use veryl_simulator::wave_dumper::WaveDumper;
+use veryl_synthesizer::{BmcOutcome, Counterexample, build_proof_model};
+
+pub struct CmdProve {
+    opt: OptProve,
+}
+
+impl CmdProve {
+    pub fn new(opt: OptProve) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
+        // The counterexample is replayed with a dump; see `CmdTest::exec`.
+        veryl_simulator::backend::aot_c::force_disable_localize();
+        veryl_simulator::ir::force_disable_comb_fusion();
+
+        let paths = metadata.paths(&self.opt.files, true, true)?;
+        let user_paths: HashSet<PathId> = paths
+            .iter()
+            .filter(|path| path.prj != "$std")
+            .map(|path| resource_table::insert_path(&path.src))
+            .collect();
+
+        let options = AnalyzeOptions {
+            defines: &[],
+            emit_mode: false,
+            incremental: false,
+            fail_fast: true,
+        };
+        let mut ir = Ir::default();
+        let _ = pipeline::analyze(metadata, &paths, options, Some(&mut ir), None)?;
+
+        let Some(top) = select_top(&ir, self.opt.top.as_ref(), metadata, &user_paths) else {
+            warn!("No module found to prove");
+            return Ok(false);
+        };
+        let model = match build_proof_model(&ir, top) {
+            Ok(model) => model,
+            Err(err) => {
+                warn!("Cannot prove {top}: {err}");
+                return Ok(false);
+            }
+        };
+        if model.assertions.is_empty() {
+            println!("prove: {top} — no assertions");
+            return Ok(true);
+        }
+
+        let report = model.check(self.opt.depth);
+        let cex = match report.outcome {
+            BmcOutcome::Safe => {
+                let n = model.assertions.len();
+                println!(
+                    "prove: {top} — {n} assertion{} hold{} for {} cycles after reset",
+                    if n == 1 { "" } else { "s" },
+                    if n == 1 { "s" } else { "" },
+                    report.depth
+                );
+                return Ok(true);
+            }
+            BmcOutcome::Unknown { cycle } => {
+                warn!(
+                    "prove: {top} — undecided at cycle {cycle} (solver budget exhausted); \
+                     earlier cycles are safe"
+                );
+                return Ok(false);
+            }
+            BmcOutcome::Violated(cex) => cex,
+        };
+
+        let assertion = &model.assertions[cex.assertion];
+        let beg = &assertion.token.beg;
+        println!(
+            "prove: {top} — {} at {}:{}:{} fails at cycle {}",
+            assertion.kind.as_str(),
+            beg.source,
+            beg.line,
+            beg.column,
+            cex.cycle
+        );
+        print!("{cex}");
+
+        let path = self
+            .opt
+            .vcd
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("{top}.cex.vcd")));
+        match replay(&ir, top, &cex, metadata, &path)? {
+            Some(msg) => info!("Reproduced in simulation: {msg}"),
+            None => warn!("The counterexample did not fail in simulation"),
+        }
+        println!("Counterexample waveform written to {}", path.display());
+        Ok(false)
+    }
+}
+
+/// Drives `cex` through the native simulator with a VCD dump at `path`, in
+/// the cycle pattern of a WaveDrom test. Returns the assertion failure the
+/// simulator reported, if any.
+fn replay(
+    ir: &Ir,
+    top: StrId,
+    cex: &Counterexample,
+    metadata: &Metadata,
+    path: &Path,
+) -> Result<Option<String>> {
+    let config = Config {
+        use_jit: false,
+        abstract_reset_active_high: matches!(
+            metadata.build.reset_type,
+            veryl_metadata::ResetType::AsyncHigh | veryl_metadata::ResetType::SyncHigh
+        ),
+        abstract_reset_sync: matches!(
+            metadata.build.reset_type,
+            veryl_metadata::ResetType::SyncHigh | veryl_metadata::ResetType::SyncLow
+        ),
+        ..Config::default()
+    };
+    let sim_ir = veryl_simulator::ir::build_ir(ir, top, &config)?;
+    let file = std::fs::File::create(path).into_diagnostic()?;
+    let dumper = WaveDumper::new_vcd(Box::new(file)).with_path(path.to_path_buf());
+    let mut sim = Simulator::new(sim_ir, Some(dumper));
+
+    let mut clocks: Vec<_> = sim
+        .ir
+        .event_statements
+        .keys()
+        .filter_map(|e| match e {
+            Event::Clock(id) => Some(*id),
+            _ => None,
+        })
+        .collect();
+    clocks.sort_unstable();
+    let resets = sim.ir.reset_ports();
+
+    // One clock edge per domain, with the clocks shown high for the first
+    // half of the cycle.
+    let cycle = |sim: &mut Simulator, reset: Option<&Event>| {
+        for id in &clocks {
+            sim.set_var_by_id(id, Value::new(1, 1, false));
+        }
+        for (i, id) in clocks.iter().enumerate() {
+            match reset {
+                Some(reset) => sim.step_in_reset(&Event::Clock(*id), reset, i == 0),
+                None => sim.step(&Event::Clock(*id)),
+            }
+        }
+        sim.time += 1;
+        for id in &clocks {
+            sim.set_var_by_id(id, Value::new(0, 1, false));
+        }
+        sim.dump_variables();
+        sim.time += 1;
+    };
+
+    assert_buffer::reset();
+    if cex.reset_cycle && !resets.is_empty() {
+        for id in &resets {
+            sim.set_reset_level(id, true);
+        }
+        cycle(&mut sim, Some(&Event::Reset(resets[0])));
+        for id in &resets {
+            sim.set_reset_level(id, false);
+        }
+        // The proof only starts after reset.
+        assert_buffer::reset();
+    }
+    for inputs in &cex.inputs {
+        for (name, bits) in inputs {
+            // Whole words, as `Value::from_le_bytes` reads them.
+            let mut bytes = vec![0u8; bits.len().div_ceil(64) * 8];
+            for (i, &b) in bits.iter().enumerate() {
+                bytes[i / 8] |= (b as u8) << (i % 8);
+            }
+            let mask = vec![0u8; bytes.len()];
+            sim.set(name, Value::from_le_bytes(&bytes, &mask, bits.len(), false));
+        }
+        cycle(&mut sim, None);
+    }
+    Ok(assert_buffer::take_failure())
+}
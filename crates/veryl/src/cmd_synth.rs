@@ -1,18 +1,21 @@
 use crate::pipeline::{self, AnalyzeOptions};
 use crate::{Format, OptSynth, check_format_version};
 use log::warn;
-use miette::Result;
+use miette::{IntoDiagnostic, Result, WrapErr};
 use std::cmp::Reverse;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::env;
+use std::fs;
 use std::time::Instant;
 use veryl_analyzer::ir::{Component, Ir, Module};
 use veryl_metadata::Metadata;
-use veryl_parser::resource_table::{self, PathId};
+use veryl_parser::resource_table::{self, PathId, StrId};
 use veryl_parser::veryl_token::TokenSource;
 use veryl_synthesizer::{
-    RamConfig, SynthesizerError, compute_power, compute_timing_top_n, library_for, port_label,
-    synthesize_with,
+    CellLibrary, LibertyLibrary, MismatchKind, RamConfig, SequentialAig, SynthesizerError,
+    VerifyReport, compute_clock_slack, compute_power, compute_timing_top_n, library_for,
+    port_label, synthesize_and_verify, synthesize_with_library, write_aiger, write_blif,
+    write_verilog,
 };
 
 /// Emitted by `veryl synth --format json`.
@@ -34,6 +37,8 @@ struct SynthReport {
     timing: Option<TimingJson>,
     #[serde(skip_serializing_if = "Option::is_none")]
     power: Option<PowerJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verify: Option<VerifyJson>,
 }
 
 #[derive(serde::Serialize)]
@@ -50,6 +55,20 @@ struct TimingJson {
     depth: usize,
     from: String,
     to: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    clocks: Vec<ClockJson>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    unconstrained_clocks: Vec<String>,
+}
+
+#[derive(serde::Serialize)]
+struct ClockJson {
+    clock: String,
+    period_ns: f64,
+    slack_ns: Option<f64>,
+    endpoints: usize,
+    failing: usize,
+    met: bool,
 }
 
 #[derive(serde::Serialize)]
@@ -61,6 +80,52 @@ struct PowerJson {
     activity: f64,
 }
 
+#[derive(serde::Serialize)]
+struct VerifyJson {
+    equivalent: bool,
+    points: usize,
+    mismatches: Vec<MismatchJson>,
+    undecided: Vec<String>,
+}
+
+#[derive(serde::Serialize)]
+struct MismatchJson {
+    point: String,
+    /// Structural mismatches only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    /// Input name → value; functional mismatches only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counterexample: Option<BTreeMap<String, bool>>,
+}
+
+impl From<&VerifyReport> for VerifyJson {
+    fn from(report: &VerifyReport) -> Self {
+        let mismatches = report
+            .mismatches
+            .iter()
+            .map(|m| match &m.kind {
+                MismatchKind::Functional { counterexample, .. } => MismatchJson {
+                    point: m.point.clone(),
+                    reason: None,
+                    counterexample: Some(counterexample.iter().cloned().collect()),
+                },
+                MismatchKind::Structural(reason) => MismatchJson {
+                    point: m.point.clone(),
+                    reason: Some(reason.clone()),
+                    counterexample: None,
+                },
+            })
+            .collect();
+        Self {
+            equivalent: report.equivalent(),
+            points: report.points,
+            mismatches,
+            undecided: report.undecided.clone(),
+        }
+    }
+}
+
 fn print_synth_report_json(report: &SynthReport) {
     match serde_json::to_string_pretty(report) {
         Ok(s) => println!("{s}"),
@@ -82,11 +147,7 @@ impl CmdSynth {
 
         check_format_version(self.opt.format, self.opt.format_version)?;
         let json = matches!(self.opt.format, Format::Json);
-        // Short PDK id ("sky130" / "asap7" / ...) via the enum's serde rename.
-        let library_name = serde_json::to_value(metadata.synth.library)
-            .ok()
-            .and_then(|v| v.as_str().map(str::to_string))
-            .unwrap_or_default();
+        let (library, library_name) = load_library(metadata)?;
 
         // For the default-top heuristic below.
         let user_paths: HashSet<PathId> = paths
@@ -113,49 +174,37 @@ impl CmdSynth {
             );
         }
 
-        // CLI `--top` > toml default > first user module.
-        let top_override = self.opt.top.as_ref().or(metadata.synth.top.as_ref());
-        let top_id = match top_override {
-            Some(name) => resource_table::insert_str(name),
+        let top_id = match select_top(&ir, self.opt.top.as_ref(), metadata, &user_paths) {
+            Some(id) => id,
             None => {
-                let mut candidate = None;
-                for c in &ir.components {
-                    if let Component::Module(m) = c
-                        && is_user_module(m, &user_paths)
-                    {
-                        candidate = Some(m.name);
-                        break;
-                    }
-                }
-                match candidate {
-                    Some(id) => id,
-                    None => {
-                        if json {
-                            print_synth_report_json(&SynthReport {
-                                format_version: 1,
-                                top: String::new(),
-                                library: library_name,
-                                status: "no_top",
-                                message: None,
-                                cells: 0,
-                                ffs: 0,
-                                area: None,
-                                timing: None,
-                                power: None,
-                            });
-                            return Ok(true);
-                        }
-                        warn!("No module found to synthesize");
-                        return Ok(false);
-                    }
+                if json {
+                    print_synth_report_json(&SynthReport {
+                        format_version: 1,
+                        top: String::new(),
+                        library: library_name,
+                        status: "no_top",
+                        message: None,
+                        cells: 0,
+                        ffs: 0,
+                        area: None,
+                        timing: None,
+                        power: None,
+                        verify: None,
+                    });
+                    return Ok(true);
                 }
+                warn!("No module found to synthesize");
+                return Ok(false);
             }
         };
 
-        let library = library_for(metadata.synth.library);
-
         let ram_config = RamConfig::from(&metadata.synth);
-        let result = match synthesize_with(&ir, top_id, metadata.synth.library, ram_config) {
+        let synthesized = if self.opt.verify {
+            synthesize_and_verify(&ir, top_id, library, ram_config).map(|(r, v)| (r, Some(v)))
+        } else {
+            synthesize_with_library(&ir, top_id, library, ram_config).map(|r| (r, None))
+        };
+        let (mut result, verify) = match synthesized {
             Ok(r) => r,
             Err(err) => {
                 if json {
@@ -178,6 +227,7 @@ impl CmdSynth {
                         area: None,
                         timing: None,
                         power: None,
+                        verify: None,
                     });
                     return Ok(true);
                 }
@@ -185,6 +235,32 @@ impl CmdSynth {
                 return Ok(false);
             }
         };
+        let constraints = &metadata.synth.constraints;
+        (result.timing.clocks, result.timing.unconstrained_clocks) =
+            compute_clock_slack(&result.gate_ir.module, library, constraints);
+        for clock in result.timing.clocks.iter().filter(|c| !c.found) {
+            warn!(
+                "Constrained clock \"{}\" is not an input port of {}",
+                clock.clock, top_id
+            );
+        }
+        // The CI gate: any violated clock fails the command.
+        let timing_met = result.timing.clocks.iter().all(|c| c.met());
+        let equivalent = verify.as_ref().is_none_or(VerifyReport::equivalent);
+
+        if let Some(path) = &self.opt.netlist {
+            fs::write(path, write_verilog(&result.gate_ir.module, library)).into_diagnostic()?;
+        }
+        if self.opt.emit_aig.is_some() || self.opt.emit_blif.is_some() {
+            let seq = SequentialAig::from_gate(&result.gate_ir.module);
+            if let Some(path) = &self.opt.emit_aig {
+                let binary = path.extension().is_none_or(|ext| ext != "aag");
+                fs::write(path, write_aiger(&seq, binary)).into_diagnostic()?;
+            }
+            if let Some(path) = &self.opt.emit_blif {
+                fs::write(path, write_blif(&seq)).into_diagnostic()?;
+            }
+        }
         if json {
             let power = compute_power(
                 &result.gate_ir.module,
@@ -211,6 +287,20 @@ impl CmdSynth {
                     depth: result.timing.critical_path_depth,
                     from: port_label(result.timing.critical_path.first()).to_string(),
                     to: port_label(result.timing.critical_path.last()).to_string(),
+                    clocks: result
+                        .timing
+                        .clocks
+                        .iter()
+                        .map(|c| ClockJson {
+                            clock: c.clock.clone(),
+                            period_ns: c.period,
+                            slack_ns: c.slack,
+                            endpoints: c.endpoints,
+                            failing: c.failing,
+                            met: c.met(),
+                        })
+                        .collect(),
+                    unconstrained_clocks: result.timing.unconstrained_clocks.clone(),
                 }),
                 power: Some(PowerJson {
                     total_mw: power.total_mw,
@@ -219,8 +309,9 @@ impl CmdSynth {
                     clock_freq_mhz: power.clock_freq_mhz,
                     activity: power.activity,
                 }),
+                verify: verify.as_ref().map(VerifyJson::from),
             });
-            return Ok(true);
+            return Ok(timing_met && equivalent);
         }
 
         println!(
@@ -285,6 +376,21 @@ impl CmdSynth {
                 "  {:<8}{:>11} @ f_clk = {} MHz, activity = {:.2}",
                 "", "", power.clock_freq_mhz, power.activity,
             );
+            let worst = result
+                .timing
+                .clocks
+                .iter()
+                .filter_map(|c| c.slack.map(|s| (s, &c.clock)))
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+            if let Some((slack, clock)) = worst {
+                println!(
+                    "  {:<8}{:>11.3} ns   worst, {}  {}",
+                    "slack:",
+                    slack,
+                    clock,
+                    if timing_met { "MET" } else { "VIOLATED" },
+                );
+            }
         }
 
         if show_area {
@@ -357,6 +463,13 @@ impl CmdSynth {
                         println!("{}", line);
                     }
                 }
+                if !result.timing.clocks.is_empty() {
+                    println!();
+                    println!("clocks:");
+                    for clock in &result.timing.clocks {
+                        println!("  {}", clock);
+                    }
+                }
             }
         }
         if show_power {
@@ -369,8 +482,65 @@ impl CmdSynth {
             }
         }
 
-        Ok(true)
+        if let Some(verify) = &verify {
+            println!();
+            print!("{}", verify);
+        }
+
+        if !timing_met {
+            warn!("Timing constraints violated");
+        }
+        if !equivalent {
+            warn!("Synthesized netlist is not equivalent to the RTL");
+        }
+        Ok(timing_met && equivalent)
+    }
+}
+
+/// The `[synth] liberty` files when given, else the built-in `library`,
+/// with the name reported in JSON.
+fn load_library(metadata: &Metadata) -> Result<(&'static dyn CellLibrary, String)> {
+    if metadata.synth.liberty.is_empty() {
+        // Short PDK id ("sky130" / "asap7" / ...) via the enum's serde rename.
+        let name = serde_json::to_value(metadata.synth.library)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        return Ok((library_for(metadata.synth.library), name));
+    }
+
+    let root = metadata.project_path();
+    let mut files = Vec::new();
+    for path in &metadata.synth.liberty {
+        let path = root.join(path);
+        let text = fs::read_to_string(&path)
+            .into_diagnostic()
+            .wrap_err(format!("failed to read {}", path.display()))?;
+        files.push((path.to_string_lossy().into_owned(), text));
+    }
+    let sources: Vec<(&str, &str)> = files
+        .iter()
+        .map(|(path, text)| (path.as_str(), text.as_str()))
+        .collect();
+    // The synthesizer takes a `&'static` library; this one lives until exit.
+    let library: &'static LibertyLibrary = Box::leak(Box::new(LibertyLibrary::parse(&sources)?));
+    Ok((library, library.name().to_string()))
+}
+
+/// CLI `--top` > toml default > first user module.
+pub(crate) fn select_top(
+    ir: &Ir,
+    top: Option<&String>,
+    metadata: &Metadata,
+    user_paths: &HashSet<PathId>,
+) -> Option<StrId> {
+    if let Some(name) = top.or(metadata.synth.top.as_ref()) {
+        return Some(resource_table::insert_str(name));
     }
+    ir.components.iter().find_map(|c| match c {
+        Component::Module(m) if is_user_module(m, user_paths) => Some(m.name),
+        _ => None,
+    })
 }
 
 fn is_user_module(m: &Module, user_paths: &HashSet<PathId>) -> bool {
@@ -1,21 +1,25 @@
 use crate::cmd_build::CmdBuild;
-use crate::runner::{Cocotb, CocotbSource, Dsim, Vcs, Verilator, Vivado};
-use crate::{Format, OptBuild, OptTest, check_format_version};
+use crate::runner::{Cocotb, CocotbSource, Dsim, Runner, Vcs, Verilator, Vivado};
+use crate::{DiagnosticFormat, Format, OptBuild, OptTest, check_format_version};
 use log::{error, info, warn};
-use miette::Result;
-use std::path::PathBuf;
+use miette::{IntoDiagnostic, Result};
+use std::path::{Path, PathBuf};
 use veryl_analyzer::symbol::TestType;
 use veryl_analyzer::symbol_table;
 use veryl_metadata::WaveFormFormat;
 use veryl_metadata::{ComponentBackendKind, FilelistType, Metadata, SimType, WaveFormTarget};
 use veryl_parser::resource_table::{self, PathId};
 use veryl_parser::text_table;
+use veryl_simulator::cover_table::{self, CoverKind, CoverPoint};
+use veryl_simulator::debugger::{Debugger, Repl};
 use veryl_simulator::ir::{ComponentLibrary, Config, Ir, ProtoModuleCache, build_ir_cached};
 use veryl_simulator::output_buffer;
 use veryl_simulator::simulator::Simulator;
 use veryl_simulator::simulator_error::SimulatorError;
-use veryl_simulator::testbench::{TestResult, run_native_testbench_timed};
-use veryl_simulator::wave_dumper::WaveDumper;
+use veryl_simulator::testbench::{
+    CheckpointOptions, TestResult, run_native_testbench_debug, run_native_testbench_timed,
+};
+use veryl_simulator::wave_dumper::{WaveDumper, WaveFilter};
 use veryl_simulator::wavedrom::{self, SignalKind, classify_signals, parse_wavedrom};
 
 /// A fresh random base seed for a test run, used when neither `--seed` nor
@@ -43,6 +47,12 @@ struct TestSuiteReport {
     failed: i32,
     ignored: usize,
     tests: Vec<TestReport>,
+    /// `$cover` / `$cover_bins` points of every native test, merged by name.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cover: Vec<CoverReport>,
+    /// The `coverage.json` summary, with `--coverage`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coverage: Option<serde_json::Value>,
 }
 
 #[derive(serde::Serialize)]
@@ -70,6 +80,58 @@ struct TestReport {
     /// Captured `$display`/`$write` output.
     #[serde(skip_serializing_if = "Option::is_none")]
     output: Option<String>,
+    /// The `$cover` / `$cover_bins` points the test sampled.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cover: Vec<CoverReport>,
+}
+
+#[derive(serde::Serialize)]
+struct CoverReport {
+    name: String,
+    /// Times the point was sampled.
+    samples: u64,
+    /// `$cover`: samples that saw the condition true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hits: Option<u64>,
+    /// `$cover_bins`: the inclusive value range and hit count of each bin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bins: Option<Vec<CoverBinReport>>,
+    /// Percentage of bins hit; a `$cover` point is a single bin.
+    percent: f64,
+}
+
+#[derive(serde::Serialize)]
+struct CoverBinReport {
+    low: u64,
+    high: u64,
+    hits: u64,
+}
+
+impl From<&CoverPoint> for CoverReport {
+    fn from(point: &CoverPoint) -> Self {
+        let (hits, bins) = match &point.kind {
+            CoverKind::Point { hits } => (Some(*hits), None),
+            CoverKind::Bins(bins) => {
+                let bins = bins
+                    .iter()
+                    .map(|b| CoverBinReport {
+                        low: b.low,
+                        high: b.high,
+                        hits: b.hits,
+                    })
+                    .collect();
+                (None, Some(bins))
+            }
+        };
+        let (total, hit) = point.bins_hit();
+        CoverReport {
+            name: point.name.clone(),
+            samples: point.samples,
+            hits,
+            bins,
+            percent: hit as f64 * 100.0 / total.max(1) as f64,
+        }
+    }
 }
 
 /// Load per-test run times recorded by a prior `veryl test`, used to dispatch
@@ -121,6 +183,7 @@ struct NativeTestJob {
     module_name: String,
     sim_ir: Ir,
     dump: Option<WaveDumper>,
+    checkpoint: CheckpointOptions,
 }
 
 enum NativeOutcome {
@@ -137,6 +200,15 @@ struct PendingNativeTest {
     test_path: PathId,
 }
 
+fn cocotb_runner(source: CocotbSource, sim_type: SimType, four_state: bool) -> Box<dyn Runner> {
+    let cocotb = Cocotb::new(source);
+    if sim_type == SimType::Native {
+        cocotb.native(four_state).runner()
+    } else {
+        cocotb.runner()
+    }
+}
+
 fn wave_output_path(name: &str, test_path: PathId, metadata: &Metadata) -> PathBuf {
     let target_name = format!("{}.{}", name, metadata.test.waveform_format.extension());
     match &metadata.test.waveform_target {
@@ -148,6 +220,10 @@ fn wave_output_path(name: &str, test_path: PathId, metadata: &Metadata) -> PathB
     }
 }
 
+fn checkpoint_output_path(name: &str, test_path: PathId, metadata: &Metadata) -> PathBuf {
+    wave_output_path(name, test_path, metadata).with_extension("ckpt")
+}
+
 fn create_wave_dumper(
     name: &str,
     test_path: PathId,
@@ -172,7 +248,13 @@ fn create_wave_dumper(
         }
         WaveFormFormat::Fst => WaveDumper::new_fst(&path_str),
     };
-    Ok(dumper.with_path(path))
+    let filter = WaveFilter {
+        scopes: metadata.test.wave_scopes.clone(),
+        depth: metadata.test.wave_depth,
+        start: metadata.test.wave_start,
+        stop: metadata.test.wave_stop,
+    };
+    Ok(dumper.with_path(path).with_filter(filter))
 }
 
 impl CmdTest {
@@ -181,12 +263,13 @@ impl CmdTest {
     }
 
     pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
-        // A dump wants every comb word.  Localization and the comb fusion
-        // leave the words no later reader needs holding stale values, so
-        // waveforms would disagree with a full settle while the run still
-        // passes.  Before analysis: the blocklist is computed during conv and
-        // the fusion decision is baked into the memoised pipeline.
-        if self.opt.wave {
+        // A dump, and the toggle sampling of a coverage run, want every comb
+        // word.  Localization and the comb fusion leave the words no later
+        // reader needs holding stale values, so waveforms would disagree with
+        // a full settle while the run still passes.  Before analysis: the
+        // blocklist is computed during conv and the fusion decision is baked
+        // into the memoised pipeline.
+        if self.opt.wave || self.opt.coverage.is_some() {
             veryl_simulator::backend::aot_c::force_disable_localize();
             veryl_simulator::ir::force_disable_comb_fusion();
         }
@@ -198,6 +281,7 @@ impl CmdTest {
             files: self.opt.files.clone(),
             check: false,
             out_dir: None,
+            format: DiagnosticFormat::Pretty,
         });
 
         // Mutate metadata so external simulator runners (which read
@@ -259,7 +343,7 @@ impl CmdTest {
                 .filter(|(_, property)| !property.ignored)
                 .collect()
         };
-        let ignored_count = total_tests - tests.len();
+        let mut ignored_count = total_tests - tests.len();
 
         if ignored_count > 0 {
             info!("{ignored_count} test(s) ignored");
@@ -326,6 +410,8 @@ impl CmdTest {
                 .or(metadata.test.seed)
                 .unwrap_or_else(random_seed),
             use_4state: self.opt.four_state || metadata.test.four_state,
+            coverage: self.opt.coverage.is_some(),
+            debug: self.opt.debug,
             abstract_reset_active_high: matches!(
                 metadata.build.reset_type,
                 veryl_metadata::ResetType::AsyncHigh | veryl_metadata::ResetType::SyncHigh
@@ -349,6 +435,11 @@ impl CmdTest {
 
         check_format_version(self.opt.format, self.opt.format_version)?;
         let json = matches!(self.opt.format, Format::Json);
+        if json && self.opt.debug {
+            miette::bail!(
+                "--debug reads commands from the terminal and cannot be used with --format json"
+            );
+        }
         let backend_name = match self.opt.backend {
             Backend::Interpret => "interpret",
             Backend::Cranelift => "cranelift",
@@ -356,6 +447,7 @@ impl CmdTest {
         };
         // Native workers push concurrently, so guard the per-test results.
         let reports = std::sync::Mutex::new(Vec::<TestReport>::new());
+        let cover = std::sync::Mutex::new(Vec::<CoverPoint>::new());
 
         let mut success = 0;
         let mut failure = 0;
@@ -385,10 +477,15 @@ impl CmdTest {
                 config.component_file_base = Some(metadata.project_path());
             }
 
-            let num_threads = std::thread::available_parallelism()
-                .map(|n| n.get())
-                .unwrap_or(1)
-                .min(pending_native.len());
+            // The debugger owns the terminal, so tests under it run serially.
+            let num_threads = if self.opt.debug {
+                1
+            } else {
+                std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+                    .min(pending_native.len())
+            };
             // Buffer `$display` output to keep concurrent tests from interleaving.
             // A single worker can't interleave, so stream live; `--no-capture`
             // forces streaming even in parallel.
@@ -438,6 +535,7 @@ impl CmdTest {
                 let text_snap = &text_snapshot;
                 let print_lock = &print_lock;
                 let reports = &reports;
+                let cover = &cover;
                 let handles: Vec<_> = (0..num_threads)
                     .map(|_| {
                         // Workers conv and emit user designs (see
@@ -496,12 +594,28 @@ impl CmdTest {
                                             // run time is the stable per-test cost that
                                             // longest-first scheduling sorts on.
                                             let t_run_sched = std::time::Instant::now();
-                                            let timed = run_native_testbench_timed(
-                                                job.sim_ir,
-                                                job.dump,
-                                                job.module_name,
-                                                None,
-                                            );
+                                            let timed = if opt_ref.debug {
+                                                let repl = Repl::new(
+                                                    std::io::stdin().lock(),
+                                                    std::io::stdout(),
+                                                );
+                                                run_native_testbench_debug(
+                                                    job.sim_ir,
+                                                    job.dump,
+                                                    job.module_name,
+                                                    &job.checkpoint,
+                                                    Debugger::new(Box::new(repl)),
+                                                )
+                                                .map(|r| (r, std::time::Duration::ZERO))
+                                            } else {
+                                                run_native_testbench_timed(
+                                                    job.sim_ir,
+                                                    job.dump,
+                                                    job.module_name,
+                                                    None,
+                                                    &job.checkpoint,
+                                                )
+                                            };
                                             run_secs = Some(t_run_sched.elapsed().as_secs_f64());
                                             let result = timed.map(|(r, derive)| {
                                                 derive_secs = derive.as_secs_f64();
@@ -529,6 +643,7 @@ impl CmdTest {
                                     }
                                     let runtime_s = t0.elapsed().as_secs_f64();
                                     let output = output_buffer::take();
+                                    let cover_points = cover_table::take();
                                     let test_name = &pending.test_name;
                                     let _print = print_lock.lock().unwrap();
                                     let mut rep_status: &'static str = "error";
@@ -593,8 +708,13 @@ impl CmdTest {
                                             } else {
                                                 Some(output)
                                             },
+                                            cover: cover_points
+                                                .iter()
+                                                .map(CoverReport::from)
+                                                .collect(),
                                         });
                                     }
+                                    cover_table::merge(&mut cover.lock().unwrap(), cover_points);
                                     use std::io::Write;
                                     let _ = std::io::stdout().flush();
                                 }
@@ -624,6 +744,7 @@ impl CmdTest {
             veryl_simulator::wide_ops::dump_wide_op_profile();
         }
 
+        let four_state = config.use_4state;
         for (test, property) in non_native_tests {
             let mut runner = match property.r#type {
                 TestType::Inline => match sim_type {
@@ -631,11 +752,22 @@ impl CmdTest {
                     SimType::Vcs => Vcs::new().runner(),
                     SimType::Dsim => Dsim::new().runner(),
                     SimType::Vivado => Vivado::new().runner(),
+                    SimType::Native => {
+                        // Skipped alone, so the rest of the run still counts.
+                        warn!(
+                            "Skipping inline test ({test}): it needs an external simulator; \
+                             `native` runs native and cocotb tests only"
+                        );
+                        ignored_count += 1;
+                        continue;
+                    }
                 },
                 TestType::CocotbEmbed(ref x) => {
-                    Cocotb::new(CocotbSource::Embed(x.clone())).runner()
+                    cocotb_runner(CocotbSource::Embed(x.clone()), sim_type, four_state)
+                }
+                TestType::CocotbInclude(x) => {
+                    cocotb_runner(CocotbSource::Include(x), sim_type, four_state)
                 }
-                TestType::CocotbInclude(x) => Cocotb::new(CocotbSource::Include(x)).runner(),
                 TestType::Native => unreachable!(),
             };
 
@@ -662,6 +794,7 @@ impl CmdTest {
                     sim_s: None,
                     derive_s: None,
                     output: None,
+                    cover: Vec::new(),
                 });
             }
         }
@@ -708,6 +841,7 @@ impl CmdTest {
                     sim_s: None,
                     derive_s: None,
                     output: None,
+                    cover: Vec::new(),
                 });
             }
         }
@@ -725,6 +859,12 @@ impl CmdTest {
                 .map_err(|e| miette::miette!("{e}"))?;
         }
 
+        let coverage = match &self.opt.coverage {
+            Some(dir) => Some(write_coverage(dir, metadata)?),
+            None => None,
+        };
+        let cover = cover.into_inner().unwrap();
+
         if json {
             let report = TestSuiteReport {
                 format_version: 1,
@@ -734,6 +874,8 @@ impl CmdTest {
                 failed: failure,
                 ignored: ignored_count,
                 tests: reports.into_inner().unwrap(),
+                cover: cover.iter().map(CoverReport::from).collect(),
+                coverage,
             };
             match serde_json::to_string_pretty(&report) {
                 Ok(s) => println!("{s}"),
@@ -742,6 +884,14 @@ impl CmdTest {
             return Ok(failure == 0);
         }
 
+        for point in &cover {
+            let (total, hit) = point.bins_hit();
+            info!(
+                "Cover point ({}) : {hit}/{total} bins hit in {} samples",
+                point.name, point.samples
+            );
+        }
+
         if failure == 0 {
             info!("{summary}");
             Ok(true)
@@ -752,6 +902,32 @@ impl CmdTest {
     }
 }
 
+/// Writes the coverage the native tests of this run collected, restricted to
+/// the project's own sources, and returns the summary.
+fn write_coverage(dir: &Path, metadata: &Metadata) -> Result<serde_json::Value> {
+    let mut report = veryl_simulator::coverage::report();
+    let root = metadata.project_path();
+    report.files.retain(|f| f.path.starts_with(&root));
+
+    std::fs::create_dir_all(dir).into_diagnostic()?;
+    let mut lcov = Vec::new();
+    report.write_lcov(&mut lcov).into_diagnostic()?;
+    std::fs::write(dir.join("lcov.info"), lcov).into_diagnostic()?;
+    let summary = report.summary();
+    let json = serde_json::to_string_pretty(&summary).into_diagnostic()?;
+    std::fs::write(dir.join("coverage.json"), json).into_diagnostic()?;
+
+    let percent = |kind: &str| summary[kind]["percent"].as_f64().unwrap_or(0.0);
+    info!(
+        "Coverage: lines {:.1}%, branches {:.1}%, toggles {:.1}% (written to {})",
+        percent("lines"),
+        percent("branches"),
+        percent("toggles"),
+        dir.display()
+    );
+    Ok(summary)
+}
+
 /// Freshens the project's own `[[components]]` interface manifests before
 /// analysis, so `$comp` names resolve on the first run of any analyzing
 /// command. Dependencies are left to their committed manifests, which
@@ -1191,10 +1367,31 @@ fn prepare_native_test(
         None
     };
 
+    let save_at = match opt.checkpoint_at {
+        Some(cycle) => {
+            let path = checkpoint_output_path(test_name, test_path, metadata);
+            if let Some(parent) = path.parent()
+                && !parent.exists()
+            {
+                std::fs::create_dir_all(parent).map_err(|e| SimulatorError::IoError {
+                    message: format!("failed to create directory {}: {e}", parent.display()),
+                })?;
+            }
+            info!("  Saving checkpoint at cycle {cycle} to {}", path.display());
+            Some((cycle, path))
+        }
+        None => None,
+    };
+    let checkpoint = CheckpointOptions {
+        restore: opt.restore.clone(),
+        save_at,
+    };
+
     Ok(NativeTestJob {
         module_name,
         sim_ir,
         dump,
+        checkpoint,
     })
 }
 
@@ -1360,4 +1557,23 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn coverage_dir_requires_equals() {
+        use crate::{Commands, Opt};
+        use clap::Parser;
+
+        let parse = |args: &[&str]| match Opt::try_parse_from(args).unwrap().command {
+            Some(Commands::Test(x)) => (x.coverage, x.files),
+            _ => unreachable!(),
+        };
+
+        let (coverage, files) = parse(&["veryl", "test", "--coverage", "src/foo.veryl"]);
+        assert_eq!(coverage, Some(PathBuf::from("coverage")));
+        assert_eq!(files, vec![PathBuf::from("src/foo.veryl")]);
+
+        let (coverage, files) = parse(&["veryl", "test", "--coverage=cov"]);
+        assert_eq!(coverage, Some(PathBuf::from("cov")));
+        assert!(files.is_empty());
+    }
 }
@@ -0,0 +1,45 @@
+use crate::OptVpiHost;
+use miette::{IntoDiagnostic, Result};
+
+pub struct CmdVpiHost {
+    opt: OptVpiHost,
+}
+
+impl CmdVpiHost {
+    pub fn new(opt: OptVpiHost) -> Self {
+        Self { opt }
+    }
+
+    #[cfg(unix)]
+    pub fn exec(&self) -> Result<bool> {
+        use veryl_cosim::Cosim;
+        use veryl_cosim::vpi::{self, VpiConfig};
+        use veryl_simulator::Config;
+
+        let config = Config {
+            use_4state: self.opt.four_state,
+            force: self.opt.force,
+            ..Config::default()
+        };
+        let cosim = Cosim::open_project_with_config(&self.opt.project, &self.opt.top, config)
+            .into_diagnostic()?;
+
+        let mut args = vec!["veryl".to_string()];
+        args.extend(self.opt.args.iter().cloned());
+
+        let config = VpiConfig {
+            library: self.opt.library.clone(),
+            toplevel: self.opt.toplevel.clone(),
+            negedge_clock: self.opt.negedge_clock,
+            args,
+            wave: self.opt.wave.clone(),
+        };
+        vpi::run(cosim, &config).into_diagnostic()?;
+        Ok(true)
+    }
+
+    #[cfg(not(unix))]
+    pub fn exec(&self) -> Result<bool> {
+        miette::bail!("VPI applications are not supported on this platform");
+    }
+}
@@ -0,0 +1,381 @@
+//! Machine-readable diagnostics for `veryl check`/`veryl build --format
+//! json|sarif`, consumed by CI code scanning and review bots.
+//!
+//! Every diagnostic is read through the `miette::Diagnostic` trait, so fresh
+//! `AnalyzerError`s, cached warnings and parser errors share one shape. Spans
+//! are reported per file: byte offset/length into the file, and 1-based
+//! line/column (columns count Unicode code points).
+
+use crate::DiagnosticFormat;
+use crate::pipeline::CheckError;
+use miette::{Diagnostic, Report, Severity, SourceCode};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use veryl_metadata::Metadata;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Emitted by `--format json`.
+#[derive(serde::Serialize)]
+struct JsonReport<'a> {
+    /// Bump on any breaking change to the report shape.
+    format_version: u32,
+    diagnostics: &'a [DiagnosticJson],
+}
+
+#[derive(serde::Serialize)]
+struct DiagnosticJson {
+    code: Option<String>,
+    /// "error" | "warning" | "advice"
+    severity: &'static str,
+    message: String,
+    /// File of the primary (first) label, relative to the project root when
+    /// inside it.
+    file: Option<String>,
+    span: Option<SpanJson>,
+    labels: Vec<LabelJson>,
+    help: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+struct LabelJson {
+    message: Option<String>,
+    file: String,
+    span: SpanJson,
+}
+
+#[derive(Clone, Copy, serde::Serialize)]
+struct SpanJson {
+    offset: usize,
+    length: usize,
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+pub struct DiagnosticReport {
+    base: PathBuf,
+    diagnostics: Vec<DiagnosticJson>,
+}
+
+impl DiagnosticReport {
+    pub fn new(metadata: &Metadata) -> Self {
+        Self {
+            base: metadata.project_path(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn push_check_error(&mut self, check_error: &CheckError) {
+        for x in &check_error.related {
+            self.push(x);
+        }
+    }
+
+    /// An `Err` out of the analysis pipeline: the [`CheckError`] a fatal
+    /// diagnostic aborted with, or any other failure (e.g. a parse error).
+    pub fn push_report(&mut self, report: &Report) {
+        if let Some(x) = report.downcast_ref::<CheckError>() {
+            self.push_check_error(x);
+        } else {
+            self.push(&**report);
+            if let Some(related) = report.related() {
+                for x in related {
+                    self.push(x);
+                }
+            }
+        }
+    }
+
+    pub fn push(&mut self, diag: &dyn Diagnostic) {
+        let mut labels = Vec::new();
+        if let (Some(source), Some(spans)) = (diag.source_code(), diag.labels()) {
+            for x in spans {
+                if let Some((file, span)) = locate(source, x.offset(), x.len()) {
+                    labels.push(LabelJson {
+                        message: x.label().map(|x| x.to_string()),
+                        file: self.relative(&file),
+                        span,
+                    });
+                }
+            }
+        }
+
+        let severity = match diag.severity() {
+            Some(Severity::Advice) => "advice",
+            Some(Severity::Warning) => "warning",
+            Some(Severity::Error) | None => "error",
+        };
+
+        self.diagnostics.push(DiagnosticJson {
+            code: diag.code().map(|x| x.to_string()),
+            severity,
+            message: diag.to_string(),
+            file: labels.first().map(|x| x.file.clone()),
+            span: labels.first().map(|x| x.span),
+            labels,
+            help: diag.help().map(|x| x.to_string()).filter(|x| !x.is_empty()),
+            url: diag.url().map(|x| x.to_string()),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|x| x.severity == "error")
+    }
+
+    /// Prints the report on stdout; `Pretty` is rendered by miette instead.
+    pub fn print(&self, format: DiagnosticFormat) {
+        let text = match format {
+            DiagnosticFormat::Pretty => return,
+            DiagnosticFormat::Json => serde_json::to_string_pretty(&self.json_report()),
+            DiagnosticFormat::Sarif => serde_json::to_string_pretty(&self.to_sarif()),
+        };
+        match text {
+            Ok(s) => println!("{s}"),
+            Err(e) => eprintln!("failed to serialize diagnostics: {e}"),
+        }
+    }
+
+    fn json_report(&self) -> JsonReport<'_> {
+        JsonReport {
+            format_version: 1,
+            diagnostics: &self.diagnostics,
+        }
+    }
+
+    fn to_sarif(&self) -> Value {
+        // One rule per distinct code; diagnostics without a code share `veryl`.
+        let mut rules: BTreeMap<&str, Option<&str>> = BTreeMap::new();
+        let mut results = Vec::new();
+        for x in &self.diagnostics {
+            let rule_id = x.code.as_deref().unwrap_or("veryl");
+            rules.entry(rule_id).or_insert(x.url.as_deref());
+
+            let level = match x.severity {
+                "error" => "error",
+                "warning" => "warning",
+                _ => "note",
+            };
+            let mut result = json!({
+                "ruleId": rule_id,
+                "level": level,
+                "message": { "text": x.message },
+            });
+            if let Some(primary) = x.labels.first() {
+                result["locations"] = json!([sarif_location(primary)]);
+            }
+            if x.labels.len() > 1 {
+                let related: Vec<_> = x.labels[1..]
+                    .iter()
+                    .enumerate()
+                    .map(|(id, label)| {
+                        let mut location = sarif_location(label);
+                        location["id"] = json!(id + 1);
+                        location
+                    })
+                    .collect();
+                result["relatedLocations"] = json!(related);
+            }
+            if let Some(help) = &x.help {
+                result["properties"] = json!({ "help": help });
+            }
+            results.push(result);
+        }
+
+        let rules: Vec<_> = rules
+            .into_iter()
+            .map(|(id, url)| {
+                let mut rule = json!({ "id": id });
+                if let Some(url) = url {
+                    rule["helpUri"] = json!(url);
+                }
+                rule
+            })
+            .collect();
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "veryl",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": "https://veryl-lang.org/",
+                        "rules": rules,
+                    }
+                },
+                "originalUriBaseIds": {
+                    "%SRCROOT%": { "uri": self.base_uri() }
+                },
+                "columnKind": "unicodeCodePoints",
+                "results": results,
+            }]
+        })
+    }
+
+    fn base_uri(&self) -> String {
+        let base = self.base.to_string_lossy().replace('\\', "/");
+        let base = base.trim_end_matches('/');
+        if base.starts_with('/') {
+            format!("file://{base}/")
+        } else {
+            format!("file:///{base}/")
+        }
+    }
+
+    fn relative(&self, file: &str) -> String {
+        let path = Path::new(file);
+        path.strip_prefix(&self.base)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+fn sarif_location(label: &LabelJson) -> Value {
+    let span = &label.span;
+    let mut artifact = json!({ "uri": label.file });
+    if Path::new(&label.file).is_relative() {
+        artifact["uriBaseId"] = json!("%SRCROOT%");
+    }
+    let mut location = json!({
+        "physicalLocation": {
+            "artifactLocation": artifact,
+            "region": {
+                "startLine": span.start_line,
+                "startColumn": span.start_column,
+                "endLine": span.end_line,
+                "endColumn": span.end_column,
+                "byteOffset": span.offset,
+                "byteLength": span.length,
+            }
+        }
+    });
+    if let Some(message) = &label.message {
+        location["message"] = json!({ "text": message });
+    }
+    location
+}
+
+/// The file holding `offset..offset + len` and the span within it. Reading
+/// with unbounded context yields that whole file whatever the `SourceCode`
+/// (an `AnalyzerError`'s `MultiSources` concatenates several).
+fn locate(source: &dyn SourceCode, offset: usize, len: usize) -> Option<(String, SpanJson)> {
+    let contents = source
+        .read_span(&(offset, len).into(), usize::MAX, usize::MAX)
+        .ok()?;
+    let text = std::str::from_utf8(contents.data()).ok()?;
+    let offset = offset.checked_sub(contents.span().offset())?;
+    let (start_line, start_column) = line_column(text, offset)?;
+    let (end_line, end_column) = line_column(text, offset + len)?;
+    let span = SpanJson {
+        offset,
+        length: len,
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+    };
+    Some((contents.name().unwrap_or_default().to_string(), span))
+}
+
+/// 1-based line and column (in Unicode code points) of byte `offset`.
+fn line_column(text: &str, offset: usize) -> Option<(usize, usize)> {
+    let before = text.get(..offset)?;
+    let line_start = before.rfind('\n').map(|x| x + 1).unwrap_or(0);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    Some((line, column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miette::{LabeledSpan, MietteDiagnostic, NamedSource};
+
+    fn report() -> DiagnosticReport {
+        let mut report = DiagnosticReport {
+            base: PathBuf::from("/prj"),
+            diagnostics: Vec::new(),
+        };
+        let text = "module Foo {\n    var a: logic;\n}\n";
+        let diag = MietteDiagnostic::new("a is unused")
+            .with_code("unused_variable")
+            .with_severity(Severity::Warning)
+            .with_help("add prefix `_` to unused variable name")
+            .with_labels([
+                LabeledSpan::new(Some("Error location".to_string()), 21, 1),
+                LabeledSpan::new(None, 0, 6),
+            ]);
+        let diag = Report::new(diag).with_source_code(NamedSource::new("/prj/src/foo.veryl", text));
+        report.push_report(&diag);
+        report
+    }
+
+    #[test]
+    fn json() {
+        let report = report();
+        assert!(!report.is_empty());
+        assert!(!report.has_errors());
+
+        let json = serde_json::to_value(report.json_report()).unwrap();
+        assert_eq!(json["format_version"], 1);
+        let diag = &json["diagnostics"][0];
+        assert_eq!(diag["code"], "unused_variable");
+        assert_eq!(diag["severity"], "warning");
+        assert_eq!(diag["file"], "src/foo.veryl");
+        assert_eq!(diag["help"], "add prefix `_` to unused variable name");
+        assert_eq!(
+            diag["span"],
+            json!({
+                "offset": 21,
+                "length": 1,
+                "start_line": 2,
+                "start_column": 9,
+                "end_line": 2,
+                "end_column": 10,
+            })
+        );
+        assert_eq!(diag["labels"][0]["message"], "Error location");
+        assert_eq!(diag["labels"][1]["span"]["start_column"], 1);
+    }
+
+    #[test]
+    fn sarif() {
+        let sarif = report().to_sarif();
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "unused_variable");
+        assert_eq!(
+            run["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            "file:///prj/"
+        );
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "unused_variable");
+        assert_eq!(result["level"], "warning");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/foo.veryl");
+        assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["startColumn"], 9);
+        assert_eq!(result["relatedLocations"][0]["id"], 1);
+    }
+
+    #[test]
+    fn line_column_counts_code_points() {
+        let text = "a\nβc\n";
+        assert_eq!(line_column(text, 0), Some((1, 1)));
+        assert_eq!(line_column(text, 2), Some((2, 1)));
+        assert_eq!(line_column(text, 4), Some((2, 2)));
+        assert_eq!(line_column(text, 3), None);
+    }
+}
@@ -7,19 +7,23 @@ pub mod cmd_check;
 pub mod cmd_clean;
 pub mod cmd_doc;
 pub mod cmd_dump;
+pub mod cmd_fix;
 pub mod cmd_fmt;
 pub mod cmd_init;
 pub mod cmd_metadata;
 pub mod cmd_migrate;
 pub mod cmd_new;
+pub mod cmd_prove;
 pub mod cmd_publish;
 pub mod cmd_register;
 pub mod cmd_synth;
 pub mod cmd_test;
 pub mod cmd_translate;
 pub mod cmd_update;
+pub mod cmd_vpi_host;
 pub mod component_publish;
 pub mod context;
+pub mod diagnostic_report;
 pub mod diff;
 pub mod doc;
 pub mod external_subcommand;
@@ -81,6 +85,7 @@ pub enum Commands {
     New(OptNew),
     Init(OptInit),
     Fmt(OptFmt),
+    Fix(OptFix),
     Check(OptCheck),
     Build(OptBuild),
     Clean(OptClean),
@@ -93,7 +98,10 @@ pub enum Commands {
     Dump(OptDump),
     Test(OptTest),
     Synth(OptSynth),
+    Prove(OptProve),
     Translate(OptTranslate),
+    #[command(hide = true)]
+    VpiHost(OptVpiHost),
     #[command(external_subcommand)]
     External(Vec<OsString>),
 }
@@ -118,6 +126,46 @@ pub struct OptTranslate {
     pub no_format: bool,
 }
 
+/// Run a VPI application on the native simulator (spawned by `veryl test`)
+#[derive(Args)]
+pub struct OptVpiHost {
+    /// Project directory
+    #[arg(long)]
+    pub project: PathBuf,
+
+    /// Top module
+    #[arg(long)]
+    pub top: String,
+
+    /// Name of the top module in the VPI hierarchy
+    #[arg(long)]
+    pub toplevel: String,
+
+    /// VPI application to load
+    #[arg(long)]
+    pub library: PathBuf,
+
+    /// Simulate with 4-state values
+    #[arg(long = "4state")]
+    pub four_state: bool,
+
+    /// Plain `clock` is active on its falling edge
+    #[arg(long)]
+    pub negedge_clock: bool,
+
+    /// Allow forcing comb variables, at some cost in speed
+    #[arg(long)]
+    pub force: bool,
+
+    /// Dump waveform to this path
+    #[arg(long)]
+    pub wave: Option<PathBuf>,
+
+    /// Arguments reported to the application, plusargs included
+    #[arg(last = true)]
+    pub args: Vec<String>,
+}
+
 /// Create a new project
 #[derive(Args)]
 pub struct OptNew {
@@ -147,11 +195,27 @@ pub struct OptFmt {
     pub check: bool,
 }
 
+/// Apply suggested fixes of diagnostics
+#[derive(Args)]
+pub struct OptFix {
+    /// Target files
+    pub files: Vec<PathBuf>,
+
+    /// Show the fixes as a diff without applying them
+    #[arg(long)]
+    pub check: bool,
+}
+
 /// Analyze the current project
 #[derive(Args)]
 pub struct OptCheck {
     /// Target files
     pub files: Vec<PathBuf>,
+
+    /// Diagnostic output format: `pretty` (rendered on stderr, default),
+    /// `json` or `sarif` (machine-readable report on stdout)
+    #[arg(long, value_enum, default_value_t)]
+    pub format: DiagnosticFormat,
 }
 
 /// Build the target codes corresponding to the current project
@@ -169,6 +233,11 @@ pub struct OptBuild {
     /// working directory.
     #[arg(long, value_name = "DIR")]
     pub out_dir: Option<PathBuf>,
+
+    /// Diagnostic output format: `pretty` (rendered on stderr, default),
+    /// `json` or `sarif` (machine-readable report on stdout)
+    #[arg(long, value_enum, default_value_t)]
+    pub format: DiagnosticFormat,
 }
 
 /// Clean-up the current project
@@ -245,6 +314,29 @@ pub struct OptTest {
     #[arg(long)]
     pub disable_ff_opt: bool,
 
+    /// Collect line, branch and toggle coverage in the native simulator and
+    /// write `lcov.info` and `coverage.json` to DIR (default `coverage`).
+    /// The directory is given as `--coverage=DIR`, so a following file path
+    /// isn't taken for it.
+    #[arg(
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "coverage",
+        value_name = "DIR"
+    )]
+    pub coverage: Option<PathBuf>,
+
+    /// Save a checkpoint of each native test once it completes CYCLE clock
+    /// cycles, as `<test>.ckpt` next to its waveform
+    #[arg(long, value_name = "CYCLE")]
+    pub checkpoint_at: Option<u64>,
+
+    /// Start native tests from a checkpoint saved by `--checkpoint-at`
+    /// instead of the initial design state
+    #[arg(long, value_name = "FILE")]
+    pub restore: Option<PathBuf>,
+
     /// Run only ignored tests
     #[arg(long)]
     pub ignored: bool,
@@ -275,6 +367,12 @@ pub struct OptTest {
     #[arg(long = "4state")]
     pub four_state: bool,
 
+    /// Run native tests one at a time under an interactive debugger:
+    /// breakpoints on testbench lines, signal changes and conditions,
+    /// stepping by clock cycle, and inspecting or writing variables
+    #[arg(long)]
+    pub debug: bool,
+
     /// Output format: `pretty` (human-readable summary, default) or `json`
     /// (machine-readable report on stdout)
     #[arg(long, value_enum, default_value_t)]
@@ -311,6 +409,8 @@ pub enum SimType {
     Dsim,
     /// AMD Vivado Simulator
     Vivado,
+    /// Native simulator (cocotb tests only)
+    Native,
 }
 
 impl From<SimType> for veryl_metadata::SimType {
@@ -320,6 +420,7 @@ impl From<SimType> for veryl_metadata::SimType {
             SimType::Verilator => veryl_metadata::SimType::Verilator,
             SimType::Vcs => veryl_metadata::SimType::Vcs,
             SimType::Vivado => veryl_metadata::SimType::Vivado,
+            SimType::Native => veryl_metadata::SimType::Native,
         }
     }
 }
@@ -364,6 +465,17 @@ pub enum Format {
     Json,
 }
 
+/// Output format of `veryl check`/`veryl build` diagnostics.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DiagnosticFormat {
+    #[default]
+    Pretty,
+    /// One JSON document listing every diagnostic
+    Json,
+    /// SARIF 2.1.0, for code-scanning services
+    Sarif,
+}
+
 /// Mirrors `veryl metadata`'s `--format-version` so `synth`/`test` behave the
 /// same. Only version 1 exists yet.
 pub(crate) fn check_format_version(format: Format, version: Option<u32>) -> miette::Result<()> {
@@ -461,4 +573,46 @@ pub struct OptSynth {
     /// Dump the power estimate (leakage + dynamic breakdown)
     #[arg(long)]
     pub dump_power: bool,
+
+    /// Write the techmapped gate-level netlist as structural Verilog
+    #[arg(long, value_name = "PATH")]
+    pub netlist: Option<PathBuf>,
+
+    /// Write the synthesized logic as an AIGER And-Inverter Graph (ASCII
+    /// for a `.aag` extension, binary otherwise)
+    #[arg(long, value_name = "PATH")]
+    pub emit_aig: Option<PathBuf>,
+
+    /// Write the synthesized logic as BLIF
+    #[arg(long, value_name = "PATH")]
+    pub emit_blif: Option<PathBuf>,
+
+    /// Prove the optimized netlist equivalent to the unoptimized one with
+    /// the built-in SAT solver; fails with a counterexample on mismatch
+    #[arg(long)]
+    pub verify: bool,
+}
+
+/// Bounded model check of the top module's `$assert`s.
+///
+/// The module is unrolled for `--depth` cycles after reset and searched for
+/// an input sequence that fails an assertion. A counterexample is replayed
+/// in the simulator and written as a VCD waveform.
+#[derive(Args)]
+pub struct OptProve {
+    /// Target files
+    pub files: Vec<PathBuf>,
+
+    /// Top module name (overrides `synth.top` in Veryl.toml; otherwise
+    /// inferred from the first user module)
+    #[arg(long)]
+    pub top: Option<String>,
+
+    /// Number of cycles after reset to check
+    #[arg(long, default_value_t = 20)]
+    pub depth: usize,
+
+    /// Counterexample waveform path (default: `<top>.cex.vcd`)
+    #[arg(long, value_name = "PATH")]
+    pub vcd: Option<PathBuf>,
 }
@@ -98,12 +98,24 @@ fn main() -> Result<ExitCode> {
         return external_subcommand::dispatch(args.clone());
     }
 
+    // Spawned by `veryl test`, which holds the `.build` lock meanwhile.
+    let command = match command {
+        Commands::VpiHost(x) => {
+            return if cmd_vpi_host::CmdVpiHost::new(x).exec()? {
+                Ok(ExitCode::SUCCESS)
+            } else {
+                Ok(ExitCode::FAILURE)
+            };
+        }
+        x => x,
+    };
+
     let (mut metadata, dot_build_lock) = match command {
         Commands::New(_) | Commands::Init(_) | Commands::Translate(_) => {
             // dummy metadata
             (Metadata::create_default("dummy").unwrap(), None)
         }
-        Commands::External(_) => unreachable!(),
+        Commands::External(_) | Commands::VpiHost(_) => unreachable!(),
         _ => {
             let metadata_path = Metadata::search_from_current()?;
             let metadata = Metadata::load(metadata_path)?;
@@ -121,10 +133,12 @@ fn main() -> Result<ExitCode> {
     if matches!(
         command,
         Commands::Check(_)
+            | Commands::Fix(_)
             | Commands::Build(_)
             | Commands::Doc(_)
             | Commands::Dump(_)
             | Commands::Synth(_)
+            | Commands::Prove(_)
             | Commands::Publish(_)
     ) {
         cmd_test::build_component_manifests(&metadata);
@@ -134,6 +148,7 @@ fn main() -> Result<ExitCode> {
         Commands::New(x) => cmd_new::CmdNew::new(x).exec(),
         Commands::Init(x) => cmd_init::CmdInit::new(x).exec(),
         Commands::Fmt(x) => cmd_fmt::CmdFmt::new(x).exec(&mut metadata, opt.quiet),
+        Commands::Fix(x) => cmd_fix::CmdFix::new(x).exec(&mut metadata, opt.quiet),
         // check emits nothing, so it writes no info.toml.
         Commands::Check(x) => cmd_check::CmdCheck::new(x).exec(&mut metadata),
         Commands::Build(x) => {
@@ -156,8 +171,9 @@ fn main() -> Result<ExitCode> {
             ret
         }
         Commands::Synth(x) => cmd_synth::CmdSynth::new(x).exec(&mut metadata),
+        Commands::Prove(x) => cmd_prove::CmdProve::new(x).exec(&mut metadata),
         Commands::Translate(x) => cmd_translate::CmdTranslate::new(x).exec(),
-        Commands::External(_) => unreachable!(),
+        Commands::External(_) | Commands::VpiHost(_) => unreachable!(),
     };
 
     if let Some(dot_build_lock) = dot_build_lock {
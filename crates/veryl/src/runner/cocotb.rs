@@ -4,11 +4,12 @@ use log::{error, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
 use tokio_util::codec::{FramedRead, LinesCodec};
-use veryl_metadata::{Metadata, WaveFormFormat};
+use veryl_metadata::{ClockType, Metadata, WaveFormFormat};
 use veryl_parser::resource_table::{self, PathId, StrId};
 use veryl_parser::veryl_grammar_trait::{self as syntax_tree};
 
@@ -31,6 +32,7 @@ pub struct Cocotb {
     source: CocotbSource,
     state: State,
     success: bool,
+    native: Option<bool>,
 }
 
 impl Cocotb {
@@ -39,9 +41,17 @@ impl Cocotb {
             source,
             state: State::Idle,
             success: true,
+            native: None,
         }
     }
 
+    /// Runs the test against the native simulator instead of Verilator,
+    /// simulating with 4-state values if `four_state`.
+    pub fn native(mut self, four_state: bool) -> Self {
+        self.native = Some(four_state);
+        self
+    }
+
     pub fn runner(self) -> Box<dyn Runner> {
         Box::new(self) as Box<dyn Runner>
     }
@@ -159,6 +169,18 @@ impl Runner for Cocotb {
             }
         }
 
+        if let Some(four_state) = self.native {
+            self.run_native(
+                metadata,
+                test,
+                top.unwrap(),
+                temp_dir.path(),
+                wave,
+                four_state,
+            )?;
+            return self.finish(metadata, test, path, temp_dir.path(), wave);
+        }
+
         let file_list = fs::read_to_string(metadata.filelist_path()).into_diagnostic()?;
         let mut sources = String::new();
         for line in file_list.lines() {
@@ -268,18 +290,120 @@ else:
             self.parse(compile).await
         })?;
 
+        self.finish(metadata, test, path, temp_dir.path(), wave)
+    }
+
+    fn name(&self) -> &'static str {
+        "Cocotb"
+    }
+
+    fn failure(&mut self) {
+        self.success = false;
+    }
+}
+
+impl Cocotb {
+    /// Hosts cocotb's VPI library in a `veryl vpi-host` process, which loads
+    /// the project into the native simulator.
+    fn run_native(
+        &mut self,
+        metadata: &Metadata,
+        test: StrId,
+        top: StrId,
+        temp_dir: &Path,
+        wave: bool,
+        four_state: bool,
+    ) -> Result<()> {
+        let library = cocotb_config(&["--lib-name-path", "vpi", "icarus"])?;
+        let libpython = cocotb_config(&["--libpython"])?;
+        let python_bin = cocotb_config(&["--python-bin"])?;
+
+        let module = format!("{}_{}", metadata.project.name, top);
+        let results = temp_dir.join("results.xml");
+
+        let mut python_path = std::ffi::OsString::from(temp_dir);
+        if let Some(x) = std::env::var_os("PYTHONPATH") {
+            python_path.push(if cfg!(windows) { ";" } else { ":" });
+            python_path.push(x);
+        }
+
+        let exe = std::env::current_exe().into_diagnostic()?;
+        let mut cmd = Command::new(exe);
+        cmd.arg("vpi-host")
+            .arg("--project")
+            .arg(metadata.project_path())
+            .args(["--top", &top.to_string()])
+            .args(["--toplevel", &module])
+            .arg("--library")
+            .arg(&library);
+        if four_state {
+            cmd.arg("--4state");
+        }
+        if metadata.build.clock_type == ClockType::NegEdge {
+            cmd.arg("--negedge-clock");
+        }
+        if wave {
+            let dump = temp_dir
+                .join("sim_build")
+                .join("dump")
+                .with_extension(metadata.test.waveform_format.extension());
+            fs::create_dir_all(dump.parent().unwrap()).into_diagnostic()?;
+            cmd.arg("--wave").arg(dump);
+        }
+        cmd.current_dir(temp_dir)
+            .env("COCOTB_TEST_MODULES", test.to_string())
+            .env("MODULE", test.to_string())
+            .env("COCOTB_TOPLEVEL", &module)
+            .env("TOPLEVEL", &module)
+            .env("TOPLEVEL_LANG", "verilog")
+            .env("COCOTB_RESULTS_FILE", &results)
+            .env("PYTHONPATH", python_path)
+            .env("LIBPYTHON_LOC", libpython)
+            .env("PYGPI_PYTHON_BIN", python_bin)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let rt = Runtime::new().unwrap();
+        rt.block_on(async {
+            let child = cmd
+                .spawn()
+                .into_diagnostic()
+                .wrap_err("Failed to run \"veryl vpi-host\"")?;
+            self.parse(child).await
+        })?;
+
+        // cocotb exits cleanly even if tests fail; the verdict is in its
+        // JUnit results.
+        match fs::read_to_string(&results) {
+            Ok(x) if x.contains("<failure") || x.contains("<error") => self.failure(),
+            Ok(_) => {}
+            Err(_) => {
+                error!("cocotb wrote no results");
+                self.failure();
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(
+        &mut self,
+        metadata: &Metadata,
+        test: StrId,
+        path: PathId,
+        temp_dir: &Path,
+        wave: bool,
+    ) -> Result<bool> {
         if wave {
             // `copy_wave` expects the waveform at a certain position and format
             fs::copy(
                 temp_dir
-                    .path()
                     .join("sim_build")
                     .join("dump")
                     .with_extension(metadata.test.waveform_format.extension()),
-                temp_dir.path().join(test.to_string()).with_extension("vcd"),
+                temp_dir.join(test.to_string()).with_extension("vcd"),
             )
             .into_diagnostic()?;
-            copy_wave(test, path, metadata, temp_dir.path())?;
+            copy_wave(test, path, metadata, temp_dir)?;
         }
 
         if self.success {
@@ -290,14 +414,6 @@ else:
             Ok(false)
         }
     }
-
-    fn name(&self) -> &'static str {
-        "Cocotb"
-    }
-
-    fn failure(&mut self) {
-        self.success = false;
-    }
 }
 
 fn process_embed_content(embed_content: &syntax_tree::EmbedContent) -> String {
@@ -334,3 +450,19 @@ fn format_py_str_array<S: AsRef<str>>(items: impl Iterator<Item = S>) -> String
             .join(",")
     )
 }
+
+fn cocotb_config(args: &[&str]) -> Result<String> {
+    let output = std::process::Command::new("cocotb-config")
+        .args(args)
+        .output()
+        .into_diagnostic()
+        .wrap_err("Failed to run \"cocotb-config\"")?;
+    if !output.status.success() {
+        miette::bail!(
+            "\"cocotb-config {}\" failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}